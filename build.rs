@@ -0,0 +1,80 @@
+//! Build script recording build metadata for `gw version`
+//!
+//! Sets `GIT_SHA` (short commit id, or `unknown` outside a Git checkout),
+//! `BUILD_DATE` (UTC, `YYYY-MM-DD`, honouring `SOURCE_DATE_EPOCH`) and
+//! `RUSTC_VERSION` for `env!` in the crate.
+
+use std::env;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const UNKNOWN: &str = "unknown";
+const SECONDS_PER_DAY: u64 = 86_400;
+
+fn main() {
+    let git_sha = command_output("git", &["rev-parse", "--short", "HEAD"]);
+    println!(
+        "cargo:rustc-env=GIT_SHA={}",
+        git_sha.as_deref().unwrap_or(UNKNOWN)
+    );
+
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let rustc_version = command_output(&rustc, &["--version"])
+        .and_then(|output| output.split_whitespace().nth(1).map(str::to_string));
+    println!(
+        "cargo:rustc-env=RUSTC_VERSION={}",
+        rustc_version.as_deref().unwrap_or(UNKNOWN)
+    );
+
+    let timestamp = env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|epoch| epoch.parse::<u64>().ok())
+        .unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_secs())
+                .unwrap_or_default()
+        });
+    println!("cargo:rustc-env=BUILD_DATE={}", format_date(timestamp));
+
+    // Rebuild when HEAD moves, not on every change in the package
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+    let branch_ref = command_output("git", &["symbolic-ref", "-q", "HEAD"]);
+    for reference in ["HEAD"].into_iter().chain(branch_ref.as_deref()) {
+        if let Some(path) = command_output("git", &["rev-parse", "--git-path", reference]) {
+            println!("cargo:rerun-if-changed={path}");
+        }
+    }
+}
+
+/// Trimmed stdout of a successful command
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    let trimmed = stdout.trim();
+    (!trimmed.is_empty()).then(|| trimmed.to_string())
+}
+
+/// Formats seconds since the Unix epoch as a UTC `YYYY-MM-DD` date
+fn format_date(timestamp: u64) -> String {
+    // Civil-from-days conversion (Howard Hinnant's algorithm)
+    let days = (timestamp / SECONDS_PER_DAY) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
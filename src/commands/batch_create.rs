@@ -0,0 +1,317 @@
+//! Batch worktree creation
+//!
+//! Creates one worktree for each of several branches in one go, e.g. when
+//! setting up a fresh clone. Worktrees are named after their branch and
+//! placed like the existing worktrees.
+
+use anyhow::Result;
+use colored::*;
+use std::collections::HashMap;
+
+use super::super::core::validate_worktree_name;
+use super::create::{custom_worktree_path, dedupe_remote_branches, default_location_index};
+use super::shared::run_interruptible_batch;
+use crate::config::{Config, WorktreeConfig};
+use crate::constants::{
+    HEADER_BATCH_CREATE, HOOK_POST_CREATE, ICON_ARROW, ICON_LOCAL_BRANCH, ICON_REMOTE_BRANCH,
+    MSG_NO_BRANCHES_FOR_BATCH_CREATE, MSG_PRESS_ESC_TO_STOP_BATCH,
+    PROMPT_SELECT_BRANCHES_TO_CREATE, SLASH_CHAR, WORKTREES_SUBDIR, WORKTREE_LOCATION_CUSTOM_PATH,
+    WORKTREE_LOCATION_SUBDIRECTORY,
+};
+use crate::file_copy;
+use crate::git::{GitWorktreeManager, RemoteBranch};
+use crate::hooks::{self, HookContext};
+use crate::input_esc_raw::escape_pressed;
+use crate::ui::{DialoguerUI, UserInterface};
+use crate::utils::{self, output, press_any_key_to_continue};
+
+/// A branch that can get a worktree in a batch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchBranch {
+    /// Reference passed to worktree creation (`<remote>/` prefix for remote branches)
+    pub reference: String,
+    /// The branch name without the remote prefix
+    pub name: String,
+    pub is_remote: bool,
+}
+
+impl BatchBranch {
+    /// The branch name without the remote prefix
+    pub fn branch_name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Pure business logic for choosing the branches offered for batch creation
+///
+/// Local branches that are already checked out are left out. Remote
+/// branches are left out when a local branch of the same name exists,
+/// because that one is offered (or already checked out) instead.
+pub fn batch_create_candidates(
+    local_branches: &[String],
+    remote_branches: &[RemoteBranch],
+    branch_worktree_map: &HashMap<String, String>,
+) -> Vec<BatchBranch> {
+    let local = local_branches
+        .iter()
+        .filter(|branch| !branch_worktree_map.contains_key(*branch))
+        .map(|branch| BatchBranch {
+            reference: branch.clone(),
+            name: branch.clone(),
+            is_remote: false,
+        });
+    let remote = remote_branches
+        .iter()
+        .filter(|remote| !local_branches.contains(&remote.branch))
+        .map(|remote| BatchBranch {
+            reference: remote.full_name(),
+            name: remote.branch.clone(),
+            is_remote: true,
+        });
+    local.chain(remote).collect()
+}
+
+/// Pure business logic for naming a worktree after its branch
+///
+/// Slashes become dashes (`feature/login` → `feature-login`) and the result
+/// is checked with [`validate_worktree_name`].
+pub fn worktree_name_for_branch(branch: &str) -> Result<String> {
+    validate_worktree_name(&branch.replace(SLASH_CHAR, "-"))
+}
+
+/// Pure business logic for placing the first worktree of a repository
+///
+/// Later worktrees follow the detected pattern of the existing ones, so this
+/// only applies while there are none. Follows `[worktree] default_location`;
+/// a custom location without `default_custom_path` falls back to the same
+/// level as the repository, like the location prompt's default.
+pub fn first_worktree_location(name: &str, worktree_config: &WorktreeConfig) -> String {
+    match default_location_index(worktree_config.default_location.as_deref()) {
+        WORKTREE_LOCATION_SUBDIRECTORY => format!("{WORKTREES_SUBDIR}/{name}"),
+        WORKTREE_LOCATION_CUSTOM_PATH => match worktree_config.default_custom_path.as_deref() {
+            Some(template) => custom_worktree_path(template, name),
+            None => format!("../{name}"),
+        },
+        _ => format!("../{name}"),
+    }
+}
+
+/// Creates a worktree for each of several selected branches
+///
+/// # Errors
+///
+/// Returns an error if the repository or its branches cannot be read.
+pub fn create_worktrees_batch() -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    let ui = DialoguerUI;
+    create_worktrees_batch_with_ui(&manager, &ui)
+}
+
+/// Internal implementation of create_worktrees_batch with dependency injection
+///
+/// # Arguments
+///
+/// * `manager` - Git worktree manager instance
+/// * `ui` - User interface implementation for testability
+///
+/// Each worktree gets the configured files and runs `post-create` hooks.
+/// A failure is reported and the batch continues with the next branch;
+/// `[hooks] on_failure` is not consulted here. Pressing ESC between
+/// branches stops the batch.
+pub fn create_worktrees_batch_with_ui(
+    manager: &GitWorktreeManager,
+    ui: &dyn UserInterface,
+) -> Result<()> {
+    output::print_header(HEADER_BATCH_CREATE);
+
+    let (local_branches, remote_branches) = manager.list_all_branches()?;
+    let remote_branches = dedupe_remote_branches(&local_branches, &remote_branches, |b| {
+        manager.is_remote_branch_in_sync(b)
+    });
+    let branch_worktree_map = manager.get_branch_worktree_map()?;
+    let candidates =
+        batch_create_candidates(&local_branches, &remote_branches, &branch_worktree_map);
+
+    if candidates.is_empty() {
+        let msg = MSG_NO_BRANCHES_FOR_BATCH_CREATE.yellow();
+        println!("{msg}");
+        println!();
+        press_any_key_to_continue()?;
+        return Ok(());
+    }
+
+    let items: Vec<String> = candidates
+        .iter()
+        .map(|candidate| {
+            let icon = if candidate.is_remote {
+                ICON_REMOTE_BRANCH
+            } else {
+                ICON_LOCAL_BRANCH
+            };
+            format!("{icon}{}", candidate.reference)
+        })
+        .collect();
+
+    let selected: Vec<BatchBranch> = match ui.multiselect(PROMPT_SELECT_BRANCHES_TO_CREATE, &items)
+    {
+        Ok(selections) if !selections.is_empty() => {
+            selections.iter().map(|&i| candidates[i].clone()).collect()
+        }
+        _ => return Ok(()),
+    };
+
+    let config = Config::load()?;
+    let mut has_worktrees = manager.list_worktrees()?.iter().any(|w| !w.is_main);
+    let mut success_count = 0;
+    let mut error_count = 0;
+
+    println!();
+    let hint = MSG_PRESS_ESC_TO_STOP_BATCH.bright_black();
+    println!("{hint}");
+
+    let outcome = run_interruptible_batch(&selected, escape_pressed, |candidate| {
+        let reference = &candidate.reference;
+        let name = match worktree_name_for_branch(candidate.branch_name()) {
+            Ok(name) => name,
+            Err(e) => {
+                utils::print_error(&format!("Skipping '{reference}': {e}"));
+                error_count += 1;
+                return;
+            }
+        };
+        let location = if has_worktrees {
+            name.clone()
+        } else {
+            first_worktree_location(&name, &config.worktree)
+        };
+
+        match manager.create_worktree(&location, Some(reference)) {
+            Ok(path) => {
+                has_worktrees = true;
+                success_count += 1;
+                let name_green = name.bright_green();
+                let arrow = ICON_ARROW.bright_black();
+                utils::print_success(&format!(
+                    "Created worktree '{name_green}' {arrow} {reference}"
+                ));
+
+                if !config.files.copy.is_empty() {
+                    if let Err(e) = file_copy::copy_configured_files(&config.files, &path, manager)
+                    {
+                        utils::print_warning(&format!("Failed to copy files: {e}"));
+                    }
+                }
+
+                if let Err(e) = hooks::execute_hooks(
+                    HOOK_POST_CREATE,
+                    &HookContext {
+                        worktree_name: name.clone(),
+                        worktree_path: path,
+                        previous_worktree_name: None,
+                        previous_worktree_path: None,
+                    },
+                ) {
+                    utils::print_warning(&format!("Hook execution warning: {e}"));
+                }
+            }
+            Err(e) => {
+                utils::print_error(&format!(
+                    "Failed to create '{name}' from '{reference}': {e}"
+                ));
+                error_count += 1;
+            }
+        }
+    });
+
+    if outcome.interrupted {
+        println!();
+        let remaining_count = outcome.remaining.len();
+        utils::print_warning(&format!(
+            "Stopped by user. {remaining_count} branch(es) were left without a worktree:"
+        ));
+        for candidate in &outcome.remaining {
+            let reference = &candidate.reference;
+            println!("  • {reference}");
+        }
+    }
+
+    println!();
+    println!(
+        "{} Created {} worktree(s), {} failed",
+        "•".bright_green(),
+        success_count,
+        error_count
+    );
+
+    println!();
+    press_any_key_to_continue()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(branches: &[&str]) -> Vec<String> {
+        branches.iter().map(|b| b.to_string()).collect()
+    }
+
+    fn remote(remote: &str, branch: &str) -> RemoteBranch {
+        RemoteBranch {
+            remote: remote.to_string(),
+            branch: branch.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_batch_create_candidates() {
+        let local = names(&["main", "feature/login", "bugfix"]);
+        let remote = vec![
+            remote("origin", "bugfix"),
+            remote("origin", "release/1.0"),
+            remote("upstream", "release/2.0"),
+        ];
+        let in_use = HashMap::from([("main".to_string(), "repo".to_string())]);
+
+        let candidates = batch_create_candidates(&local, &remote, &in_use);
+        let references: Vec<&str> = candidates.iter().map(|c| c.reference.as_str()).collect();
+        assert_eq!(
+            references,
+            vec![
+                "feature/login",
+                "bugfix",
+                "origin/release/1.0",
+                "upstream/release/2.0"
+            ]
+        );
+        assert!(candidates[2].is_remote);
+        assert_eq!(candidates[2].branch_name(), "release/1.0");
+        assert_eq!(candidates[3].branch_name(), "release/2.0");
+    }
+
+    #[test]
+    fn test_worktree_name_for_branch() {
+        assert_eq!(
+            worktree_name_for_branch("feature/login").unwrap(),
+            "feature-login"
+        );
+        assert_eq!(worktree_name_for_branch("bugfix").unwrap(), "bugfix");
+        assert!(worktree_name_for_branch(".hidden").is_err());
+    }
+
+    #[test]
+    fn test_first_worktree_location() {
+        let mut config = WorktreeConfig::default();
+        assert_eq!(first_worktree_location("api", &config), "../api");
+
+        config.default_location = Some("subdirectory".to_string());
+        assert_eq!(first_worktree_location("api", &config), "worktrees/api");
+
+        config.default_location = Some("custom".to_string());
+        assert_eq!(first_worktree_location("api", &config), "../api");
+
+        config.default_custom_path = Some("branches/{{name}}".to_string());
+        assert_eq!(first_worktree_location("api", &config), "branches/api");
+    }
+}
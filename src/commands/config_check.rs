@@ -0,0 +1,458 @@
+//! Configuration validation (`gw config validate`)
+//!
+//! Problems in `.git-workers.toml` otherwise only surface when a feature
+//! reads the broken value, and a file that fails to parse is silently
+//! replaced by the defaults. This command checks the whole file up front:
+//! TOML syntax, unknown keys (with a hint for typos such as `post_create`),
+//! value types, `[files]` paths and hook commands.
+
+use anyhow::{anyhow, Result};
+use colored::*;
+use std::path::Path;
+
+use super::create::check_fetch_depth;
+use super::shared::find_config_file_path;
+use crate::config::Config;
+use crate::constants::{
+    CONFIG_FILE_NAME, ERROR_CONFIG_INVALID, HEADER_CONFIG_VALIDATE, HINT_DID_YOU_MEAN,
+    KNOWN_HOOK_EVENTS, MSG_CONFIG_NOT_FOUND, MSG_CONFIG_VALID, MSG_CONFIG_VALID_WITH_WARNINGS,
+    VALIDATE_COPY_EMPTY, VALIDATE_COPY_UNSAFE, VALIDATE_HOOK_EMPTY, VALIDATE_NOT_A_TABLE,
+    VALIDATE_SOURCE_EMPTY, VALIDATE_SOURCE_UNRESOLVED, VALIDATE_TASK_EMPTY, VALIDATE_UNKNOWN_KEY,
+    VALIDATE_UNKNOWN_SECTION,
+};
+use crate::file_copy::{expand_source_path, is_safe_path};
+use crate::git::GitWorktreeManager;
+use crate::infrastructure::pr_refspec;
+use crate::utils::{self, closest_match, output};
+
+/// Keys accepted in each section, mirroring the structs in [`crate::config`]
+const KNOWN_SECTIONS: &[(&str, &[&str])] = &[
+    ("repository", &["url", "url_match"]),
+    // Hook event names are added from KNOWN_HOOK_EVENTS
+    ("hooks", &["on_failure"]),
+    // Task names are free-form and added from the file itself
+    ("tasks", &[]),
+    (
+        "files",
+        &[
+            "copy",
+            "source",
+            "max_size_mb",
+            "source_ref",
+            "copy_exclude",
+        ],
+    ),
+    (
+        "worktree",
+        &[
+            "default_location",
+            "default_pattern",
+            "default_custom_path",
+            "skip_location_prompt",
+            "never_offer_branch_delete",
+            "branch_template",
+            "auto_suffix",
+        ],
+    ),
+    ("general", &["read_config_from_tree"]),
+    (
+        "display",
+        &["show_ahead_behind", "show_commits", "hidden_worktrees"],
+    ),
+    (
+        "create",
+        &[
+            "always_fuzzy_branches",
+            "fetch_before_create",
+            "fetch_depth",
+            "branch_sort",
+            "pr_refspec",
+        ],
+    ),
+    ("performance", &["status_timeout_ms"]),
+    ("editor", &["command"]),
+    ("switch", &["show_preview"]),
+    ("shell", &["switch_channel"]),
+    ("config", &["allow_worktree_override"]),
+];
+
+/// Keys accepted in the table form of a `[files] copy` entry
+const KNOWN_COPY_ENTRY_KEYS: &[&str] = &["path", "max_size_mb"];
+
+/// Keys accepted in the table form of a hook command
+const KNOWN_HOOK_ENTRY_KEYS: &[&str] = &["command", "workdir", "env"];
+
+/// How serious a configuration problem is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueLevel {
+    /// Likely a typo; the value is ignored
+    Warning,
+    /// The file cannot be used as written
+    Error,
+}
+
+/// A single problem found in the configuration file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigIssue {
+    pub level: IssueLevel,
+    pub message: String,
+}
+
+impl ConfigIssue {
+    fn error(message: String) -> Self {
+        Self {
+            level: IssueLevel::Error,
+            message,
+        }
+    }
+
+    fn warning(message: String) -> Self {
+        Self {
+            level: IssueLevel::Warning,
+            message,
+        }
+    }
+}
+
+/// Pure business logic for suggesting the known key a typo was meant to be
+///
+/// Keys that only differ in case or in `-` versus `_` match first; otherwise
+/// the closest key by spelling is suggested (see [`closest_match`]).
+pub fn suggest_key<'a>(key: &str, known: &[&'a str]) -> Option<&'a str> {
+    let normalize = |k: &str| k.to_lowercase().replace('_', "-");
+    let wanted = normalize(key);
+    known
+        .iter()
+        .copied()
+        .find(|k| normalize(k) == wanted)
+        .or_else(|| closest_match(key, known))
+}
+
+/// Reports `key` in `location` as unknown, with a suggestion when one exists
+fn unknown_key(template: &str, location: &str, key: &str, known: &[&str]) -> ConfigIssue {
+    let mut message = template.replacen("{}", key, 1).replacen("{}", location, 1);
+    if let Some(suggestion) = suggest_key(key, known) {
+        message.push_str(&HINT_DID_YOU_MEAN.replace("{}", suggestion));
+    }
+    ConfigIssue::warning(message)
+}
+
+/// Checks the keys of an inline table such as a copy entry or hook command
+fn check_entry_keys(
+    value: &toml::Value,
+    location: &str,
+    known: &[&str],
+    issues: &mut Vec<ConfigIssue>,
+) {
+    if let Some(table) = value.as_table() {
+        for key in table.keys().filter(|k| !known.contains(&k.as_str())) {
+            issues.push(unknown_key(VALIDATE_UNKNOWN_KEY, location, key, known));
+        }
+    }
+}
+
+/// Pure business logic for finding unknown sections and keys
+fn check_unknown_keys(table: &toml::Table, issues: &mut Vec<ConfigIssue>) {
+    let section_names: Vec<&str> = KNOWN_SECTIONS.iter().map(|(name, _)| *name).collect();
+
+    for (section, value) in table {
+        let mut known: Vec<&str> = match KNOWN_SECTIONS.iter().find(|(name, _)| name == section) {
+            Some((_, keys)) => keys.to_vec(),
+            None => {
+                issues.push(unknown_key(
+                    VALIDATE_UNKNOWN_SECTION,
+                    "",
+                    section,
+                    &section_names,
+                ));
+                continue;
+            }
+        };
+        let Some(entries) = value.as_table() else {
+            issues.push(ConfigIssue::error(
+                VALIDATE_NOT_A_TABLE.replace("{}", section),
+            ));
+            continue;
+        };
+
+        if section == "hooks" {
+            known.extend_from_slice(KNOWN_HOOK_EVENTS);
+        }
+        if section == "tasks" {
+            known.extend(entries.keys().map(String::as_str));
+        }
+
+        let location = format!("[{section}]");
+        for (key, value) in entries {
+            if !known.contains(&key.as_str()) {
+                issues.push(unknown_key(VALIDATE_UNKNOWN_KEY, &location, key, &known));
+                continue;
+            }
+            let nested_known = match (section.as_str(), key.as_str()) {
+                ("files", "copy") => KNOWN_COPY_ENTRY_KEYS,
+                ("hooks", "on_failure") => continue,
+                ("hooks", _) | ("tasks", _) => KNOWN_HOOK_ENTRY_KEYS,
+                _ => continue,
+            };
+            let nested_location = format!("[{section}] {key}");
+            for entry in value.as_array().into_iter().flatten() {
+                check_entry_keys(entry, &nested_location, nested_known, issues);
+            }
+        }
+    }
+}
+
+/// Pure business logic for checking `[files]` paths and hook commands
+///
+/// `lookup` resolves environment variables in `[files] source`; a source
+/// that cannot be expanded is only a warning because it depends on the
+/// environment the command runs in.
+fn check_values<F>(config: &Config, lookup: F, issues: &mut Vec<ConfigIssue>)
+where
+    F: Fn(&str) -> Option<String>,
+{
+    for entry in &config.files.copy {
+        let path = entry.path();
+        if path.trim().is_empty() {
+            issues.push(ConfigIssue::error(VALIDATE_COPY_EMPTY.to_string()));
+        } else if !is_safe_path(path) {
+            issues.push(ConfigIssue::error(VALIDATE_COPY_UNSAFE.replace("{}", path)));
+        }
+    }
+
+    if let Some(source) = &config.files.source {
+        if source.trim().is_empty() {
+            issues.push(ConfigIssue::error(VALIDATE_SOURCE_EMPTY.to_string()));
+        } else if let Err(e) = expand_source_path(source, lookup) {
+            issues.push(ConfigIssue::warning(
+                VALIDATE_SOURCE_UNRESOLVED.replace("{}", &e.to_string()),
+            ));
+        }
+    }
+
+    if let Err(e) = check_fetch_depth(config.create.fetch_depth) {
+        issues.push(ConfigIssue::error(e.to_string()));
+    }
+    if let Some(Err(e)) = config
+        .create
+        .pr_refspec
+        .as_deref()
+        .map(|t| pr_refspec(t, 1))
+    {
+        issues.push(ConfigIssue::error(e.to_string()));
+    }
+
+    for (commands, message) in [
+        (&*config.hooks, VALIDATE_HOOK_EMPTY),
+        (&config.tasks, VALIDATE_TASK_EMPTY),
+    ] {
+        let mut names: Vec<&String> = commands.keys().collect();
+        names.sort();
+        for name in names {
+            for (index, hook) in commands[name].iter().enumerate() {
+                if hook.command().trim().is_empty() {
+                    let position = (index + 1).to_string();
+                    issues.push(ConfigIssue::error(
+                        message.replacen("{}", name, 1).replacen("{}", &position, 1),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Pure business logic for validating the contents of a configuration file
+///
+/// Syntax and type errors stop the check early, since nothing else can be
+/// trusted once the file does not parse. Unknown keys are warnings: the
+/// loader ignores them, which is exactly why a typo goes unnoticed.
+pub fn validate_config_content<F>(content: &str, lookup: F) -> Vec<ConfigIssue>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let table = match content.parse::<toml::Table>() {
+        Ok(table) => table,
+        Err(e) => return vec![ConfigIssue::error(e.to_string())],
+    };
+
+    let mut issues = Vec::new();
+    check_unknown_keys(&table, &mut issues);
+
+    match toml::from_str::<Config>(content) {
+        Ok(config) => check_values(&config, lookup, &mut issues),
+        Err(e) => issues.push(ConfigIssue::error(e.to_string())),
+    }
+    issues
+}
+
+/// Prints an issue with an icon matching its level
+fn print_issue(issue: &ConfigIssue) {
+    let mut lines = issue.message.trim_end().lines();
+    let first = lines.next().unwrap_or_default();
+    match issue.level {
+        IssueLevel::Error => utils::print_error(first),
+        IssueLevel::Warning => utils::print_warning(first),
+    }
+    // TOML parse errors carry the offending line on the following lines
+    for line in lines {
+        let line = line.bright_black();
+        println!("    {line}");
+    }
+}
+
+/// Validates the configuration file at `path` and prints every issue
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or has hard errors, so the
+/// process exits with a non-zero code.
+pub fn validate_config_file(path: &Path) -> Result<()> {
+    let content = std::fs::read_to_string(path)?;
+    let issues = validate_config_content(&content, |name| std::env::var(name).ok());
+
+    for issue in &issues {
+        print_issue(issue);
+    }
+
+    let errors = issues
+        .iter()
+        .filter(|issue| issue.level == IssueLevel::Error)
+        .count();
+    if errors > 0 {
+        println!();
+        return Err(anyhow!(
+            ERROR_CONFIG_INVALID.replace("{}", &errors.to_string())
+        ));
+    }
+
+    if issues.is_empty() {
+        utils::print_success(MSG_CONFIG_VALID);
+    } else {
+        let count = issues.len().to_string();
+        utils::print_success(&MSG_CONFIG_VALID_WITH_WARNINGS.replace("{}", &count));
+    }
+    println!();
+    Ok(())
+}
+
+/// Validates the configuration file that [`Config::load`] would read
+///
+/// [`Config::load`] falls back to the defaults when the file does not
+/// parse, so the file is located the same way and checked directly.
+///
+/// # Errors
+///
+/// Returns an error if not in a Git repository or the file has hard errors.
+pub fn validate_config() -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    let path = find_config_file_path(&manager)?;
+
+    output::print_header(HEADER_CONFIG_VALIDATE);
+
+    if !path.exists() {
+        let msg = MSG_CONFIG_NOT_FOUND.replace("{}", CONFIG_FILE_NAME);
+        utils::print_warning(&msg);
+        println!();
+        return Ok(());
+    }
+
+    let path_display = path.display().to_string().bright_black();
+    println!("{path_display}");
+    println!();
+    validate_config_file(&path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{HOOK_POST_CREATE, HOOK_PRE_REMOVE};
+
+    fn no_env(_: &str) -> Option<String> {
+        None
+    }
+
+    fn levels(issues: &[ConfigIssue]) -> Vec<IssueLevel> {
+        issues.iter().map(|issue| issue.level).collect()
+    }
+
+    #[test]
+    fn test_suggest_key() {
+        let known = [HOOK_POST_CREATE, HOOK_PRE_REMOVE];
+        assert_eq!(suggest_key("post_create", &known), Some(HOOK_POST_CREATE));
+        assert_eq!(suggest_key("Pre-Remove", &known), Some(HOOK_PRE_REMOVE));
+        assert_eq!(suggest_key("pre-remov", &known), Some(HOOK_PRE_REMOVE));
+        assert_eq!(suggest_key("post-delete", &known), None);
+    }
+
+    #[test]
+    fn test_validate_clean_config() {
+        let content = r#"
+[hooks]
+on_failure = "abort"
+post-create = ["npm install", { command = "make", workdir = "native" }]
+
+[files]
+copy = [".env", { path = "data.db", max_size_mb = 500 }]
+source = "$HOME/templates"
+
+[tasks]
+setup = ["npm ci", { command = "npm run build", workdir = "web" }]
+"#;
+        let issues = validate_config_content(content, |_| Some("/home/dev".to_string()));
+        assert!(issues.is_empty(), "{issues:?}");
+    }
+
+    #[test]
+    fn test_validate_reports_parse_error_with_line() {
+        let issues = validate_config_content("[hooks]\npost-create = [\"a\"\n", no_env);
+        assert_eq!(levels(&issues), vec![IssueLevel::Error]);
+        assert!(issues[0].message.contains("line 2"));
+    }
+
+    #[test]
+    fn test_validate_unknown_keys() {
+        let content = r#"
+[hooks]
+post_create = ["npm install"]
+post-switch = [{ command = "ls", cwd = "src" }]
+
+[Files]
+copy = [".env"]
+"#;
+        let issues = validate_config_content(content, no_env);
+        assert_eq!(levels(&issues), vec![IssueLevel::Warning; 3]);
+        let messages: Vec<&str> = issues.iter().map(|i| i.message.as_str()).collect();
+        assert!(messages.iter().any(|m| m.contains("'post-create'")));
+        assert!(messages.iter().any(|m| m.contains("'cwd'")));
+        assert!(messages.iter().any(|m| m.contains("'files'")));
+    }
+
+    #[test]
+    fn test_validate_values() {
+        let content = r#"
+[hooks]
+post-create = ["npm install", "  "]
+
+[files]
+copy = ["", "../secrets", ".env"]
+source = "${MISSING}/templates"
+"#;
+        let issues = validate_config_content(content, no_env);
+        assert_eq!(
+            levels(&issues),
+            vec![
+                IssueLevel::Error,
+                IssueLevel::Error,
+                IssueLevel::Warning,
+                IssueLevel::Error,
+            ]
+        );
+        assert!(issues[3].message.contains("#2"));
+    }
+
+    #[test]
+    fn test_validate_type_error() {
+        let issues = validate_config_content("[files]\nmax_size_mb = \"big\"\n", no_env);
+        assert_eq!(levels(&issues), vec![IssueLevel::Error]);
+    }
+}
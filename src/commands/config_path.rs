@@ -0,0 +1,86 @@
+//! Configuration file location (`gw config path`)
+//!
+//! `.git-workers.toml` is looked for in several places depending on the
+//! repository layout and the current directory. This command prints the
+//! file that is actually used, and with `--explain` every place checked.
+
+use anyhow::Result;
+use colored::*;
+use std::path::Path;
+
+use super::shared::find_config_file_path;
+use crate::config::{config_candidates, global_config_file};
+use crate::constants::{
+    CONFIG_PATH_GLOBAL_LABEL, CONFIG_PATH_IN_USE, ICON_ERROR, ICON_SUCCESS, MSG_CONFIG_PATH_NEW,
+};
+use crate::git::GitWorktreeManager;
+
+/// Pure business logic for one checked location in `gw config path --explain`
+///
+/// Existing files get a ✓ and missing ones a ✗; the file in use is marked.
+pub fn format_config_candidate(path: &Path, exists: bool, in_use: bool) -> String {
+    let icon = if exists { ICON_SUCCESS } else { ICON_ERROR };
+    let marker = if in_use { CONFIG_PATH_IN_USE } else { "" };
+    format!("{icon} {}{marker}", path.display())
+}
+
+/// Prints the configuration file in use (`gw config path`)
+///
+/// When there is no file yet, the path a new one would be created at is
+/// printed, with a note on stderr. `explain` first lists every candidate in
+/// the order checked, and the global file layered underneath.
+///
+/// # Errors
+///
+/// Returns an error if not in a Git repository.
+pub fn config_path(explain: bool) -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    let path = find_config_file_path(&manager)?;
+    let exists = path.exists();
+
+    if explain {
+        for candidate in config_candidates(manager.repo()) {
+            let candidate_exists = candidate.exists();
+            let in_use = exists && candidate == path;
+            let line = format_config_candidate(&candidate, candidate_exists, in_use);
+            if in_use {
+                println!("{}", line.green());
+            } else {
+                println!("{}", line.bright_black());
+            }
+        }
+        if let Some(global) = global_config_file() {
+            let line = format_config_candidate(&global, global.exists(), false);
+            println!("{CONFIG_PATH_GLOBAL_LABEL}{}", line.bright_black());
+        }
+        println!();
+    }
+
+    println!("{}", path.display());
+    if !exists {
+        eprintln!("{}", MSG_CONFIG_PATH_NEW.yellow());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_config_candidate() {
+        let path = Path::new("/repo/.git-workers.toml");
+        assert_eq!(
+            format_config_candidate(path, true, true),
+            "✓ /repo/.git-workers.toml (in use)"
+        );
+        assert_eq!(
+            format_config_candidate(path, true, false),
+            "✓ /repo/.git-workers.toml"
+        );
+        assert_eq!(
+            format_config_candidate(path, false, false),
+            "✗ /repo/.git-workers.toml"
+        );
+    }
+}
@@ -1,30 +1,55 @@
 use anyhow::{anyhow, Result};
 use colored::*;
-use indicatif::{ProgressBar, ProgressStyle};
-use std::path::PathBuf;
-use std::time::Duration;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
-use super::super::core::{validate_custom_path, validate_worktree_name};
-use crate::config::Config;
+use super::super::core::{
+    validate_custom_path, validate_worktree_name, validate_worktree_target, GitWorkersError,
+};
+use super::shared::edit_hooks;
+use super::switch::{post_switch_context, record_switch};
+use crate::config::{BranchSort, Config, HookEntry, HookFailurePolicy};
 use crate::constants::{
-    section_header, BRANCH_OPTION_SELECT_BRANCH, BRANCH_OPTION_SELECT_TAG, DEFAULT_EMPTY_STRING,
-    DEFAULT_MENU_SELECTION, DEFAULT_REPO_NAME, ERROR_CUSTOM_PATH_EMPTY, ERROR_WORKTREE_NAME_EMPTY,
-    FUZZY_SEARCH_THRESHOLD, GIT_REMOTE_PREFIX, HEADER_CREATE_WORKTREE, HOOK_POST_CREATE,
-    HOOK_POST_SWITCH, ICON_LOCAL_BRANCH, ICON_REMOTE_BRANCH, ICON_TAG_INDICATOR,
-    MSG_EXAMPLE_BRANCH, MSG_EXAMPLE_DOT, MSG_EXAMPLE_HOTFIX, MSG_EXAMPLE_PARENT,
-    MSG_FIRST_WORKTREE_CHOOSE, MSG_SPECIFY_DIRECTORY_PATH, OPTION_CREATE_FROM_HEAD_FULL,
+    AUTO_SUFFIX_MAX, BRANCH_EXISTS_ACTION_RENAME, BRANCH_EXISTS_ACTION_USE_EXISTING,
+    BRANCH_OPTION_CREATE_FROM_HEAD, BRANCH_OPTION_SELECT_BRANCH, BRANCH_OPTION_SELECT_TAG,
+    CONFIG_SETUP_LATER_INDEX, CONFIG_SETUP_NEVER_INDEX, CONFIG_SETUP_NOW_INDEX,
+    CONFLICT_ACTION_CREATE_NEW_BRANCH, DEFAULT_EMPTY_STRING, DEFAULT_MENU_SELECTION,
+    DEFAULT_PR_REFSPEC, DEFAULT_REPO_NAME, ENV_USER, ENV_USERNAME, ERROR_BRANCH_TEMPLATE_NO_USER,
+    ERROR_CREATE_FROM_REQUIRES_BRANCH, ERROR_CREATE_NAME_REQUIRED, ERROR_CREATE_PATH_NOT_FIRST,
+    ERROR_CREATE_SOURCE_CONFLICT, ERROR_CREATE_SOURCE_REQUIRED, ERROR_CUSTOM_PATH_EMPTY,
+    ERROR_FETCH_DEPTH_ZERO, ERROR_FILE_COPY_ABORTED, ERROR_FILE_COPY_INCOMPLETE,
+    ERROR_INVALID_BRANCH_NAME, ERROR_NO_COMMITS, ERROR_NO_FREE_WORKTREE_NAME,
+    ERROR_POST_CREATE_HOOK_FAILED, ERROR_PRE_CREATE_HOOK_FAILED, ERROR_TASK_FAILED,
+    ERROR_WORKTREE_NAME_EMPTY, FILE_COPY_PLAN_MISSING, FUZZY_SEARCH_THRESHOLD, GIT_HEAD_REF,
+    GIT_REFS_HEADS, HEADER_CREATE_WORKTREE, HOOK_POST_CREATE, HOOK_POST_SWITCH, HOOK_PRE_CREATE,
+    ICON_ARROW, ICON_ERROR, ICON_LOCAL_BRANCH, ICON_REMOTE_BRANCH, ICON_SUCCESS,
+    ICON_TAG_INDICATOR, MSG_BRANCH_ROLLED_BACK, MSG_EXAMPLE_BRANCH, MSG_EXAMPLE_DOT,
+    MSG_EXAMPLE_HOTFIX, MSG_EXAMPLE_PARENT, MSG_FETCHING, MSG_FILES_COPIED, MSG_FILES_EXCLUDED,
+    MSG_FILES_FAILED, MSG_FILES_SKIPPED, MSG_FIRST_WORKTREE_CHOOSE, MSG_SPECIFY_DIRECTORY_PATH,
+    MSG_WORKTREE_NAME_SUFFIXED, MSG_WORKTREE_ROLLED_BACK, OPTION_BRANCH_EXISTS_CANCEL,
+    OPTION_BRANCH_EXISTS_RENAME, OPTION_BRANCH_EXISTS_USE_EXISTING, OPTION_CONFIG_SETUP_LATER,
+    OPTION_CONFIG_SETUP_NEVER, OPTION_CONFIG_SETUP_NOW, OPTION_CREATE_FROM_HEAD_FULL,
     OPTION_CUSTOM_PATH_FULL, OPTION_SELECT_BRANCH_FULL, OPTION_SELECT_TAG_FULL,
-    PROGRESS_BAR_TICK_MILLIS, PROMPT_CONFLICT_ACTION, PROMPT_CUSTOM_PATH, PROMPT_SELECT_BRANCH,
+    PROMPT_CONFIG_SETUP_OFFER, PROMPT_CONFLICT_ACTION, PROMPT_CREATE_WITH_MISSING_FILES,
+    PROMPT_CUSTOM_PATH, PROMPT_NEW_BRANCH_NAME, PROMPT_ROLLBACK_WORKTREE,
+    PROMPT_ROLLBACK_WORKTREE_AND_BRANCH, PROMPT_RUN_TASK, PROMPT_SELECT_BRANCH,
     PROMPT_SELECT_BRANCH_OPTION, PROMPT_SELECT_TAG, PROMPT_SELECT_WORKTREE_LOCATION,
-    PROMPT_WORKTREE_NAME, REPO_NAME_FALLBACK, SLASH_CHAR, STRING_CUSTOM, STRING_SAME_LEVEL,
-    STRING_SUBDIRECTORY, TAG_MESSAGE_TRUNCATE_LENGTH, WORKTREES_SUBDIR,
-    WORKTREE_LOCATION_CUSTOM_PATH, WORKTREE_LOCATION_SAME_LEVEL, WORKTREE_LOCATION_SUBDIRECTORY,
+    PROMPT_SWITCH_TO_NEW_WORKTREE, PROMPT_USE_SIMILAR_BRANCH, PROMPT_WORKTREE_NAME,
+    REPO_NAME_FALLBACK, SLASH_CHAR, STRING_CUSTOM, STRING_SAME_LEVEL, STRING_SUBDIRECTORY,
+    TAG_MESSAGE_TRUNCATE_LENGTH, TASK_SKIP_OPTION, TEMPLATE_NAME, TEMPLATE_USER,
+    WARNING_BRANCH_DETACHED, WARNING_BRANCH_EXISTS, WARNING_BRANCH_MISMATCH,
+    WARNING_BRANCH_NAME_TYPO, WARNING_BRANCH_UNVERIFIED, WARNING_FETCH_FAILED_CACHED,
+    WARNING_ROLLBACK_BRANCH_FAILED, WARNING_ROLLBACK_BRANCH_KEPT, WARNING_ROLLBACK_WORKTREE_FAILED,
+    WORKTREES_SUBDIR, WORKTREE_LOCATION_CUSTOM_PATH, WORKTREE_LOCATION_SAME_LEVEL,
+    WORKTREE_LOCATION_SUBDIRECTORY,
 };
 use crate::file_copy;
-use crate::git::GitWorktreeManager;
-use crate::hooks::{self, HookContext};
-use crate::ui::{DialoguerUI, UserInterface};
-use crate::utils::{self, press_any_key_to_continue, write_switch_path};
+use crate::git::{strip_remote_prefix, worktree_head_branch, GitWorktreeManager, RemoteBranch};
+use crate::git_interface::GitOperations;
+use crate::hooks::{self, find_task, HookContext, HookFailure};
+use crate::infrastructure::State;
+use crate::ui::{DialoguerUI, NonInteractiveUI, UserInterface};
+use crate::utils::{self, output, press_any_key_to_continue, write_switch_path};
 
 /// Configuration for worktree creation
 #[derive(Debug, Clone)]
@@ -97,6 +122,565 @@ pub fn determine_worktree_path(
     }
 }
 
+/// Pure business logic for mapping `[worktree] default_location` to a prompt index
+///
+/// Missing or unrecognized values fall back to the first option, which is
+/// the prompt's own default.
+pub fn default_location_index(default_location: Option<&str>) -> usize {
+    match default_location {
+        Some(STRING_SAME_LEVEL) => WORKTREE_LOCATION_SAME_LEVEL,
+        Some(STRING_SUBDIRECTORY) => WORKTREE_LOCATION_SUBDIRECTORY,
+        Some(STRING_CUSTOM) => WORKTREE_LOCATION_CUSTOM_PATH,
+        _ => DEFAULT_MENU_SELECTION,
+    }
+}
+
+/// Pure business logic for checking that `name` is a legal local branch name
+pub fn is_valid_branch_name(name: &str) -> bool {
+    git2::Reference::is_valid_name(&format!("{GIT_REFS_HEADS}{name}"))
+}
+
+/// Pure business logic for expanding `[worktree] branch_template`
+///
+/// `{{name}}` is replaced with the worktree name and `{{user}}` with `user`.
+/// The result must be a legal branch name.
+pub fn branch_name_from_template(template: &str, name: &str, user: Option<&str>) -> Result<String> {
+    let mut branch = template.replace(TEMPLATE_NAME, name);
+    if branch.contains(TEMPLATE_USER) {
+        let user = user.ok_or_else(|| anyhow!(ERROR_BRANCH_TEMPLATE_NO_USER))?;
+        branch = branch.replace(TEMPLATE_USER, user);
+    }
+    if !is_valid_branch_name(&branch) {
+        return Err(anyhow!(ERROR_INVALID_BRANCH_NAME.replace("{}", &branch)));
+    }
+    Ok(branch)
+}
+
+/// Returns the user name for `{{user}}` in branch templates
+fn current_user() -> Option<String> {
+    std::env::var(ENV_USER)
+        .or_else(|_| std::env::var(ENV_USERNAME))
+        .ok()
+        .filter(|user| !user.is_empty())
+}
+
+/// Asks for a new branch name, offering `default`
+///
+/// Returns `None` if the prompt is cancelled or the name is empty or invalid.
+fn prompt_new_branch_name(ui: &dyn UserInterface, default: &str) -> Option<String> {
+    let branch = ui
+        .input_with_default(PROMPT_NEW_BRANCH_NAME, default)
+        .ok()?
+        .trim()
+        .to_string();
+    if branch.is_empty() {
+        utils::print_error("Branch name cannot be empty");
+        return None;
+    }
+    if !is_valid_branch_name(&branch) {
+        utils::print_error(&ERROR_INVALID_BRANCH_NAME.replace("{}", &branch));
+        return None;
+    }
+    Some(branch)
+}
+
+/// Pure business logic for spotting a likely typo of an existing branch
+///
+/// Returns the first branch in `existing` that is exactly one
+/// single-character edit away from `name`.
+pub fn branch_one_edit_away<'a>(name: &str, existing: &'a [String]) -> Option<&'a str> {
+    existing
+        .iter()
+        .map(String::as_str)
+        .find(|branch| utils::edit_distance(name, branch) == 1)
+}
+
+/// Checks out an existing branch instead of creating one
+///
+/// Returns `None` (after saying why) if another worktree has it checked out.
+fn use_existing_branch(
+    manager: &dyn GitOperations,
+    branch: &str,
+) -> Result<Option<(Option<String>, Option<String>)>> {
+    if let Some(worktree) = manager.get_branch_worktree_map()?.get(branch) {
+        utils::print_error(&format!(
+            "Branch '{}' is already checked out in worktree '{}'",
+            branch.yellow(),
+            worktree.bright_red()
+        ));
+        return Ok(None);
+    }
+    Ok(Some((Some(branch.to_string()), None)))
+}
+
+/// Checks a new branch name against the existing local branches
+///
+/// A taken name offers to choose a different name, use the existing branch
+/// instead, or cancel. A name one character away from an existing branch
+/// offers that branch, in case of a typo. Returns the base and new branch
+/// to create with, or `None` if creation was cancelled. Without a terminal
+/// a taken name is an error and the typo check is skipped.
+fn check_new_branch_name(
+    manager: &dyn GitOperations,
+    ui: &dyn UserInterface,
+    base: Option<String>,
+    mut new_branch: String,
+) -> Result<Option<(Option<String>, Option<String>)>> {
+    while manager.branch_exists(&new_branch)? {
+        let warning = WARNING_BRANCH_EXISTS.replace("{}", &new_branch);
+        if !ui.is_interactive() {
+            return Err(anyhow!(warning));
+        }
+        output::blank_line();
+        utils::print_warning(&warning);
+        output::blank_line();
+
+        let action_options = vec![
+            OPTION_BRANCH_EXISTS_RENAME.to_string(),
+            OPTION_BRANCH_EXISTS_USE_EXISTING.replace("{}", &new_branch),
+            OPTION_BRANCH_EXISTS_CANCEL.to_string(),
+        ];
+        match ui.select_with_default(
+            PROMPT_CONFLICT_ACTION,
+            &action_options,
+            DEFAULT_MENU_SELECTION,
+        ) {
+            Ok(BRANCH_EXISTS_ACTION_RENAME) => match prompt_new_branch_name(ui, &new_branch) {
+                Some(branch) => new_branch = branch,
+                None => return Ok(None),
+            },
+            Ok(BRANCH_EXISTS_ACTION_USE_EXISTING) => {
+                return use_existing_branch(manager, &new_branch)
+            }
+            _ => return Ok(None),
+        }
+    }
+
+    if ui.is_interactive() {
+        let (local_branches, _) = manager.list_all_branches()?;
+        if let Some(similar) = branch_one_edit_away(&new_branch, &local_branches) {
+            output::blank_line();
+            utils::print_warning(
+                &WARNING_BRANCH_NAME_TYPO
+                    .replacen("{}", &new_branch, 1)
+                    .replacen("{}", similar, 1),
+            );
+            let prompt = PROMPT_USE_SIMILAR_BRANCH.replace("{}", similar);
+            if ui.confirm_with_default(&prompt, false).unwrap_or(false) {
+                return use_existing_branch(manager, similar);
+            }
+        }
+    }
+
+    Ok(Some((base, Some(new_branch))))
+}
+
+/// Pure business logic for determining the branch a new worktree should be on
+///
+/// A new branch name wins; remote branches are checked out as a local branch
+/// without the prefix of their remote (one of `remotes`); worktrees created
+/// from HEAD get a branch named after the worktree directory, as
+/// `git worktree add` does.
+pub fn expected_branch_for_new_worktree(
+    branch: Option<&str>,
+    new_branch: Option<&str>,
+    path: &Path,
+    remotes: &[String],
+) -> Option<String> {
+    match (new_branch, branch) {
+        (Some(new_branch), _) => Some(new_branch.to_string()),
+        (None, Some(branch)) => Some(
+            strip_remote_prefix(branch, remotes)
+                .unwrap_or(branch)
+                .to_string(),
+        ),
+        (None, None) => path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string()),
+    }
+}
+
+/// Pure business logic for finding the branch a new worktree added
+///
+/// Returns the branch checked out in the new worktree when it was not among
+/// the local branches before creation, so a rollback never deletes a branch
+/// that already existed.
+pub fn branch_created_by_worktree(
+    branches_before: &[String],
+    checked_out: Option<&str>,
+) -> Option<String> {
+    checked_out
+        .filter(|branch| !branches_before.iter().any(|before| before == branch))
+        .map(str::to_string)
+}
+
+/// Pure business logic for checking the branch of a newly created worktree
+///
+/// Returns a warning message when the worktree is not on the expected branch.
+pub fn check_created_branch(expected: &str, actual: Option<&str>) -> Option<String> {
+    match actual {
+        Some(actual) if actual == expected => None,
+        Some(actual) => Some(
+            WARNING_BRANCH_MISMATCH
+                .replacen("{}", actual, 1)
+                .replacen("{}", expected, 1),
+        ),
+        None => Some(WARNING_BRANCH_DETACHED.replace("{}", expected)),
+    }
+}
+
+/// Pure business logic for deciding whether to offer configuration setup
+///
+/// The offer is only made right after the first worktree is created, when
+/// the repository has no configuration file and the user has not dismissed it.
+pub fn should_offer_config_setup(
+    is_first_worktree: bool,
+    config_exists: bool,
+    dismissed: bool,
+) -> bool {
+    is_first_worktree && !config_exists && !dismissed
+}
+
+/// Pure business logic for choosing the searchable branch picker
+pub fn use_fuzzy_branch_picker(branch_count: usize, always_fuzzy: bool) -> bool {
+    always_fuzzy || branch_count > FUZZY_SEARCH_THRESHOLD
+}
+
+/// Pure business logic for hiding remote branches that duplicate a local one
+///
+/// `origin/foo` is dropped when local `foo` exists and `in_sync` reports
+/// that both point at the same commit. Diverged remote branches are kept so
+/// they can still be picked.
+pub fn dedupe_remote_branches(
+    local_branches: &[String],
+    remote_branches: &[RemoteBranch],
+    in_sync: impl Fn(&RemoteBranch) -> bool,
+) -> Vec<RemoteBranch> {
+    remote_branches
+        .iter()
+        .filter(|remote| !(local_branches.contains(&remote.branch) && in_sync(remote)))
+        .cloned()
+        .collect()
+}
+
+/// Offers to open the hooks editor after the first worktree is created
+fn offer_config_setup(manager: &dyn GitOperations, ui: &dyn UserInterface) -> Result<()> {
+    let config_exists = manager
+        .config_file_path()
+        .map(|path| path.exists())
+        .unwrap_or(true);
+    let state_dir = manager.get_common_dir();
+    let mut state = State::load(state_dir);
+
+    if !should_offer_config_setup(true, config_exists, state.dismissed_config_setup) {
+        return Ok(());
+    }
+
+    output::blank_line();
+    let options = vec![
+        OPTION_CONFIG_SETUP_NOW.to_string(),
+        OPTION_CONFIG_SETUP_LATER.to_string(),
+        OPTION_CONFIG_SETUP_NEVER.to_string(),
+    ];
+    match ui.select_with_default(PROMPT_CONFIG_SETUP_OFFER, &options, DEFAULT_MENU_SELECTION) {
+        Ok(CONFIG_SETUP_NOW_INDEX) => edit_hooks()?,
+        Ok(CONFIG_SETUP_NEVER_INDEX) => {
+            state.dismissed_config_setup = true;
+            if let Err(e) = state.save(state_dir) {
+                utils::print_warning(&format!("Failed to save preference: {e}"));
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Pure business logic for the task menu shown after creation
+///
+/// Returns the task names in alphabetical order, each with its menu item,
+/// e.g. `setup (npm ci, npm run build)`.
+pub fn task_menu_items(tasks: &HashMap<String, Vec<HookEntry>>) -> Vec<(String, String)> {
+    let mut names: Vec<&String> = tasks.keys().collect();
+    names.sort();
+    names
+        .into_iter()
+        .map(|name| {
+            let commands: Vec<&str> = tasks[name].iter().map(HookEntry::command).collect();
+            (name.clone(), format!("{name} ({})", commands.join(", ")))
+        })
+        .collect()
+}
+
+/// Offers to run one of the `[tasks]` in the new worktree
+///
+/// Nothing is asked when no tasks are configured. A failed task leaves the
+/// worktree in place: it is a warning in the menu and an error for
+/// `gw create --task`, so scripts can tell.
+fn offer_task(config: &Config, context: &HookContext, ui: &dyn UserInterface) -> Result<()> {
+    if config.tasks.is_empty() {
+        return Ok(());
+    }
+
+    let tasks = task_menu_items(&config.tasks);
+    let mut items = vec![TASK_SKIP_OPTION.to_string()];
+    items.extend(tasks.iter().map(|(_, item)| item.clone()));
+    output::blank_line();
+    let name = match ui.select_with_default(PROMPT_RUN_TASK, &items, DEFAULT_MENU_SELECTION) {
+        Ok(selection) if selection > 0 => &tasks[selection - 1].0,
+        _ => return Ok(()),
+    };
+
+    let failure = match hooks::run_task(name, context) {
+        Ok(report) => report.first_failure().map(HookFailure::to_string),
+        Err(e) => Some(e.to_string()),
+    };
+    match failure {
+        Some(failure) if ui.is_interactive() => {
+            utils::print_warning(&ERROR_TASK_FAILED.replace("{}", &failure));
+            Ok(())
+        }
+        Some(failure) => {
+            let message = ERROR_TASK_FAILED.replace("{}", &failure);
+            Err(GitWorkersError::HookFailed(message).into())
+        }
+        None => Ok(()),
+    }
+}
+
+/// Warns if the worktree at `path` is not on the expected branch
+fn verify_created_branch(path: &Path, expected: Option<&str>) {
+    let expected = match expected {
+        Some(expected) => expected,
+        None => return,
+    };
+
+    match worktree_head_branch(path) {
+        Ok(actual) => {
+            if let Some(warning) = check_created_branch(expected, actual.as_deref()) {
+                utils::print_warning(&warning);
+            }
+        }
+        Err(e) => {
+            utils::print_warning(&WARNING_BRANCH_UNVERIFIED.replace("{}", &e.to_string()));
+        }
+    }
+}
+
+/// Prints the copied, skipped and failed files grouped under one heading each
+fn print_file_copy_summary(report: &file_copy::FileCopyReport) {
+    if !report.copied.is_empty() {
+        let count = report.copied.len().to_string();
+        utils::print_success(&MSG_FILES_COPIED.replace("{}", &count));
+        for file in &report.copied {
+            println!("  ✓ {file}");
+        }
+    }
+    if report.excluded > 0 {
+        let count = report.excluded.to_string();
+        let message = MSG_FILES_EXCLUDED.replace("{}", &count);
+        println!("{}", message.bright_black());
+    }
+    if !report.skipped.is_empty() {
+        let count = report.skipped.len().to_string();
+        utils::print_warning(&MSG_FILES_SKIPPED.replace("{}", &count));
+        for (file, reason) in &report.skipped {
+            let reason = format!("({reason})").bright_black();
+            println!("  - {file} {reason}");
+        }
+    }
+    if !report.failed.is_empty() {
+        let count = report.failed.len().to_string();
+        utils::print_error(&MSG_FILES_FAILED.replace("{}", &count));
+        for (file, error) in &report.failed {
+            println!("  ✗ {file}: {error}");
+        }
+    }
+}
+
+/// Pure business logic for combining a custom directory with the worktree name
+///
+/// A path containing `{{name}}` is a template and the placeholder is replaced
+/// with the worktree name. Any other custom path is treated as a directory:
+/// `branch/` becomes `branch/<name>`, `./` becomes `./<name>` and a lone `/`
+/// yields the name.
+pub fn custom_worktree_path(custom_path: &str, name: &str) -> String {
+    if custom_path.contains(TEMPLATE_NAME) {
+        return custom_path.replace(TEMPLATE_NAME, name);
+    }
+    let custom_path = custom_path.trim_end_matches(SLASH_CHAR);
+    if custom_path.is_empty() {
+        name.to_string()
+    } else if custom_path == "." {
+        format!("./{name}")
+    } else {
+        format!("{custom_path}/{name}")
+    }
+}
+
+/// Values for creating a worktree without prompts (`gw create`)
+#[derive(Debug, Clone, Default)]
+pub struct CreateOptions {
+    pub name: Option<String>,
+    /// Existing branch to check out (`origin/` prefix for remote branches)
+    pub branch: Option<String>,
+    /// Remote to take `branch` from, e.g. `upstream` for `upstream/<branch>`
+    pub from: Option<String>,
+    pub from_head: bool,
+    /// Directory for the first worktree; the name is appended
+    pub path: Option<String>,
+    pub switch: bool,
+    /// Task from `[tasks]` to run in the new worktree
+    pub task: Option<String>,
+    /// Fetch a remote branch first with this depth, even without
+    /// `[create] fetch_before_create`
+    pub depth: Option<u32>,
+    /// Pull request to fetch into `pr-<number>` and check out
+    pub pr: Option<u32>,
+}
+
+/// Pure business logic for checking that `gw create` got everything it needs
+///
+/// Returns the worktree name on success.
+pub fn check_create_options(options: &CreateOptions) -> Result<&str> {
+    let name = options
+        .name
+        .as_deref()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .ok_or_else(|| anyhow!(ERROR_CREATE_NAME_REQUIRED))?;
+
+    if options.from.is_some() && options.branch.is_none() {
+        return Err(anyhow!(ERROR_CREATE_FROM_REQUIRES_BRANCH));
+    }
+
+    match (&options.branch, options.from_head) {
+        (Some(_), true) => Err(anyhow!(ERROR_CREATE_SOURCE_CONFLICT)),
+        (None, false) => Err(anyhow!(ERROR_CREATE_SOURCE_REQUIRED)),
+        _ => Ok(name),
+    }
+}
+
+/// Pure business logic for the branch picked by `gw create`
+///
+/// With `--from <remote>` the branch is looked up as `<remote>/<branch>`.
+pub fn create_branch_label(options: &CreateOptions) -> Option<String> {
+    let branch = options.branch.as_deref()?;
+    Some(match options.from.as_deref() {
+        Some(remote) => format!("{remote}/{branch}"),
+        None => branch.to_string(),
+    })
+}
+
+/// Creates a worktree from command-line values without prompting
+///
+/// Runs the same flow as the interactive menu, answering each prompt from
+/// `options`. Prompts that the options cannot answer (for example a branch
+/// that is already checked out elsewhere) fail instead of asking.
+///
+/// # Errors
+///
+/// Returns an error if values are missing or invalid, or creation fails.
+pub fn create_worktree_from_options(options: &CreateOptions) -> Result<()> {
+    if let Some(number) = options.pr {
+        return create_worktree_from_pr(options, number);
+    }
+    let name = validate_worktree_name(check_create_options(options)?)?;
+    let manager = GitWorktreeManager::new()?;
+    if !manager.has_commits() {
+        return Err(anyhow!(ERROR_NO_COMMITS));
+    }
+
+    let mut ui = NonInteractiveUI::new()
+        .with_input(PROMPT_WORKTREE_NAME, name.as_str())
+        .with_choice(PROMPT_CONFIG_SETUP_OFFER, CONFIG_SETUP_LATER_INDEX)
+        .with_confirm(PROMPT_SWITCH_TO_NEW_WORKTREE, options.switch)
+        .with_confirm(PROMPT_CREATE_WITH_MISSING_FILES, true);
+
+    ui = match &options.path {
+        Some(path) => {
+            if manager.list_worktrees_cached()?.iter().any(|w| !w.is_main) {
+                return Err(anyhow!(ERROR_CREATE_PATH_NOT_FIRST));
+            }
+            let final_path = custom_worktree_path(path, &name);
+            validate_custom_path(&final_path)?;
+            check_custom_path_target(&manager, &final_path)?;
+            ui.with_choice(
+                PROMPT_SELECT_WORKTREE_LOCATION,
+                WORKTREE_LOCATION_CUSTOM_PATH,
+            )
+            .with_input(PROMPT_CUSTOM_PATH, path.as_str())
+        }
+        None => ui.with_default(PROMPT_SELECT_WORKTREE_LOCATION),
+    };
+
+    ui = match create_branch_label(options) {
+        Some(branch) => ui
+            .with_choice(PROMPT_SELECT_BRANCH_OPTION, BRANCH_OPTION_SELECT_BRANCH)
+            .with_label(PROMPT_SELECT_BRANCH, branch),
+        None => ui.with_choice(PROMPT_SELECT_BRANCH_OPTION, BRANCH_OPTION_CREATE_FROM_HEAD),
+    };
+    if options.from.is_some() {
+        // A local branch of the same name exists: branch off the remote one
+        ui = ui.with_choice(PROMPT_CONFLICT_ACTION, CONFLICT_ACTION_CREATE_NEW_BRANCH);
+    }
+    ui = match &options.task {
+        Some(task) => {
+            // Fail before creating anything if the task does not exist
+            find_task(&Config::load()?.tasks, task)?;
+            ui.with_label(PROMPT_RUN_TASK, task.as_str())
+        }
+        None => ui.with_choice(PROMPT_RUN_TASK, DEFAULT_MENU_SELECTION),
+    };
+
+    create_worktree_with_depth(&manager, &ui, options.depth)?;
+    match ui.unanswered() {
+        Some(message) => Err(anyhow!(message)),
+        None => Ok(()),
+    }
+}
+
+/// Fetches pull request `number` and creates a worktree on its branch (`gw create --pr`)
+///
+/// The pull request is fetched from `origin` using `[create] pr_refspec`
+/// and `pr-<number>` is created or fast-forwarded to it (see
+/// [`GitWorktreeManager::fetch_pr`]), then created like `--branch pr-<number>`. The
+/// worktree is named after the branch unless `--name` is given.
+fn create_worktree_from_pr(options: &CreateOptions, number: u32) -> Result<()> {
+    if let Some(name) = &options.name {
+        validate_worktree_name(name)?;
+    }
+    let manager = GitWorktreeManager::new()?;
+    let template = Config::load()?
+        .create
+        .pr_refspec
+        .unwrap_or_else(|| DEFAULT_PR_REFSPEC.to_string());
+    let branch = manager.fetch_pr(number, &template, options.depth)?;
+
+    create_worktree_from_options(&CreateOptions {
+        name: options.name.clone().or_else(|| Some(branch.clone())),
+        branch: Some(branch),
+        from: None,
+        depth: None,
+        pr: None,
+        ..options.clone()
+    })
+}
+
+/// Checks that a custom path does not resolve to an existing worktree
+///
+/// Compares against every worktree including the main one, and rejects
+/// targets that exist as a file or a non-empty directory.
+fn check_custom_path_target(manager: &dyn GitOperations, path: &str) -> Result<()> {
+    let target = manager.resolve_worktree_path(path)?;
+    let existing: Vec<PathBuf> = manager
+        .list_worktrees_cached()?
+        .into_iter()
+        .map(|worktree| worktree.path)
+        .collect();
+    validate_worktree_target(&target, &existing)
+}
+
 /// Pure business logic for determining worktree path (legacy)
 #[allow(dead_code)]
 pub fn determine_worktree_path_legacy(
@@ -118,7 +702,6 @@ pub fn determine_worktree_path_legacy(
 }
 
 /// Pure business logic for worktree creation validation
-#[allow(dead_code)]
 pub fn validate_worktree_creation(
     name: &str,
     path: &PathBuf,
@@ -137,6 +720,17 @@ pub fn validate_worktree_creation(
     Ok(())
 }
 
+/// Pure business logic for picking a free worktree name (`[worktree] auto_suffix`)
+///
+/// Tries `name`, then `name-2`, `name-3`, ... up to [`AUTO_SUFFIX_MAX`]. A
+/// candidate is used if it passes name validation and `is_free`, which checks
+/// it against the existing worktrees and the filesystem.
+pub fn next_free_worktree_name(name: &str, is_free: impl Fn(&str) -> bool) -> Option<String> {
+    std::iter::once(name.to_string())
+        .chain((2..=AUTO_SUFFIX_MAX).map(|n| format!("{name}-{n}")))
+        .find(|candidate| validate_worktree_name(candidate).is_ok() && is_free(candidate))
+}
+
 pub fn create_worktree() -> Result<bool> {
     let manager = GitWorktreeManager::new()?;
     let ui = DialoguerUI;
@@ -147,7 +741,7 @@ pub fn create_worktree() -> Result<bool> {
 ///
 /// # Arguments
 ///
-/// * `manager` - Git worktree manager instance
+/// * `manager` - Git operations ([`GitWorktreeManager`] outside of tests)
 /// * `ui` - User interface implementation for testability
 ///
 /// # Implementation Notes
@@ -183,17 +777,47 @@ pub fn create_worktree() -> Result<bool> {
 /// * `true` - If a worktree was created and the user switched to it
 /// * `false` - If the operation was cancelled or user chose not to switch
 pub fn create_worktree_with_ui(
-    manager: &GitWorktreeManager,
+    manager: &dyn GitOperations,
+    ui: &dyn UserInterface,
+) -> Result<bool> {
+    create_worktree_with_depth(manager, ui, None)
+}
+
+/// Pure business logic for checking a fetch depth (`[create] fetch_depth`)
+///
+/// # Errors
+///
+/// Returns an error if the depth is 0, which Git rejects.
+pub fn check_fetch_depth(depth: Option<u32>) -> Result<Option<u32>> {
+    match depth {
+        Some(0) => Err(anyhow!(ERROR_FETCH_DEPTH_ZERO)),
+        depth => Ok(depth),
+    }
+}
+
+/// Creates a worktree, fetching a remote branch first with `depth`
+///
+/// A `depth` (from `gw create --depth`) fetches even without
+/// `[create] fetch_before_create` and wins over `[create] fetch_depth`.
+fn create_worktree_with_depth(
+    manager: &dyn GitOperations,
     ui: &dyn UserInterface,
+    depth: Option<u32>,
 ) -> Result<bool> {
-    println!();
-    let header = section_header(HEADER_CREATE_WORKTREE);
-    println!("{header}");
-    println!();
+    let create_config = Config::load()?.create;
+    let fetch_depth = check_fetch_depth(depth.or(create_config.fetch_depth))?;
+    let fetch_before_create = create_config.fetch_before_create || depth.is_some();
 
-    // Get existing worktrees to detect pattern
-    let existing_worktrees = manager.list_worktrees()?;
-    let has_worktrees = !existing_worktrees.is_empty();
+    output::print_header(HEADER_CREATE_WORKTREE);
+
+    if !manager.has_commits() {
+        utils::print_error(ERROR_NO_COMMITS);
+        return Ok(false);
+    }
+
+    // Get existing worktrees to detect pattern; later steps reuse this scan
+    let existing_worktrees = manager.list_worktrees_cached()?;
+    let has_worktrees = existing_worktrees.iter().any(|w| !w.is_main);
 
     // Get worktree name
     let name = match ui.input(PROMPT_WORKTREE_NAME) {
@@ -215,16 +839,59 @@ pub fn create_worktree_with_ui(
         }
     };
 
+    // With [worktree] auto_suffix, move to the first free name instead of
+    // failing on an existing worktree or directory
+    let name = if has_worktrees && Config::load()?.worktree.auto_suffix {
+        let free_name = next_free_worktree_name(&name, |candidate| {
+            manager.resolve_worktree_path(candidate).is_ok_and(|path| {
+                !path.exists()
+                    && validate_worktree_creation(candidate, &path, &existing_worktrees).is_ok()
+            })
+        });
+        match free_name {
+            Some(free_name) => {
+                if free_name != name {
+                    utils::print_warning(
+                        &MSG_WORKTREE_NAME_SUFFIXED
+                            .replacen("{}", &name, 1)
+                            .replacen("{}", &free_name, 1),
+                    );
+                }
+                free_name
+            }
+            None => {
+                utils::print_error(&ERROR_NO_FREE_WORKTREE_NAME.replace("{}", &name));
+                return Ok(false);
+            }
+        }
+    } else {
+        name
+    };
+
+    // Default name for new branches, from [worktree] branch_template
+    let branch_template = Config::load()?.worktree.branch_template;
+    let default_branch = match branch_template.as_deref() {
+        Some(template) => {
+            match branch_name_from_template(template, &name, current_user().as_deref()) {
+                Ok(branch) => branch,
+                Err(e) => {
+                    utils::print_error(&e.to_string());
+                    return Ok(false);
+                }
+            }
+        }
+        None => name.clone(),
+    };
+
     // If this is the first worktree, let user choose the pattern
     let final_name = if !has_worktrees {
-        println!();
-        let msg = MSG_FIRST_WORKTREE_CHOOSE.bright_cyan();
-        println!("{msg}");
+        output::blank_line();
+        output::decoration(MSG_FIRST_WORKTREE_CHOOSE.bright_cyan());
 
         // Get repository name for display
-        let repo_name = manager
-            .repo()
-            .workdir()
+        let repo_root = manager.get_repository_root().ok();
+        let repo_name = repo_root
+            .as_deref()
             .and_then(|p| p.file_name())
             .and_then(|n| n.to_str())
             .unwrap_or(DEFAULT_REPO_NAME);
@@ -238,49 +905,82 @@ pub fn create_worktree_with_ui(
             OPTION_CUSTOM_PATH_FULL.to_string(),
         ];
 
-        let selection = match ui.select_with_default(
-            PROMPT_SELECT_WORKTREE_LOCATION,
-            &options,
-            DEFAULT_MENU_SELECTION,
-        ) {
-            Ok(selection) => selection,
-            Err(_) => return Ok(false),
+        // Preselect (or skip straight to) the location configured in [worktree]
+        let worktree_config = Config::load()?.worktree;
+        let default_location = worktree_config.default_location.as_deref();
+        if let Some(location) = default_location {
+            if validate_worktree_location(location).is_err() {
+                utils::print_warning(&format!(
+                    "Unknown [worktree] default_location '{location}', using the default location"
+                ));
+            }
+        }
+        let default_index = default_location_index(default_location);
+
+        let selection = if worktree_config.skip_location_prompt {
+            let option = &options[default_index];
+            output::decoration(format!("  {} {option}", ICON_ARROW.bright_black()));
+            default_index
+        } else {
+            match ui.select_with_default(PROMPT_SELECT_WORKTREE_LOCATION, &options, default_index) {
+                Ok(selection) => selection,
+                Err(_) => return Ok(false),
+            }
         };
 
         match selection {
             WORKTREE_LOCATION_SAME_LEVEL => format!("../{name}"), // Same level
             WORKTREE_LOCATION_SUBDIRECTORY => format!("{WORKTREES_SUBDIR}/{name}"), // Subdirectory pattern
+            WORKTREE_LOCATION_CUSTOM_PATH
+                if worktree_config.skip_location_prompt
+                    && worktree_config.default_custom_path.is_some() =>
+            {
+                // Configured custom path, used as-is
+                let template = worktree_config.default_custom_path.as_deref().unwrap_or("");
+                let final_path = custom_worktree_path(template, &name);
+                if let Err(e) = validate_custom_path(&final_path)
+                    .and_then(|_| check_custom_path_target(manager, &final_path))
+                {
+                    utils::print_error(&format!("Invalid custom path: {e}"));
+                    return Ok(false);
+                }
+                output::decoration(format!("  {} {final_path}", ICON_ARROW.bright_black()));
+                final_path
+            }
             WORKTREE_LOCATION_CUSTOM_PATH => {
                 // Custom path input
-                println!();
-                let msg = MSG_SPECIFY_DIRECTORY_PATH.bright_cyan();
-                println!("{msg}");
+                output::blank_line();
+                output::decoration(MSG_SPECIFY_DIRECTORY_PATH.bright_cyan());
 
                 // Show more helpful examples with actual worktree name
-                println!();
-                println!(
+                output::blank_line();
+                output::decoration(format!(
                     "{}:",
                     format!("Examples (worktree name: '{name}'):").bright_black()
-                );
-                println!(
+                ));
+                output::decoration(format!(
                     "  • {} → creates at ./branch/{name}",
                     MSG_EXAMPLE_BRANCH.green()
-                );
-                println!(
+                ));
+                output::decoration(format!(
                     "  • {} → creates at ./hotfix/{name}",
                     MSG_EXAMPLE_HOTFIX.green()
-                );
-                println!(
+                ));
+                output::decoration(format!(
                     "  • {} → creates at ../{name} (outside project)",
                     MSG_EXAMPLE_PARENT.green()
-                );
-                println!(
+                ));
+                output::decoration(format!(
                     "  • {} → creates at ./{name} (project root)",
                     MSG_EXAMPLE_DOT.green()
-                );
-                println!();
+                ));
+                output::blank_line();
 
-                let custom_path = match ui.input(PROMPT_CUSTOM_PATH) {
+                let custom_path = match worktree_config.default_custom_path.as_deref() {
+                    Some(template) => ui.input_with_default(PROMPT_CUSTOM_PATH, template),
+                    None => ui.input(PROMPT_CUSTOM_PATH),
+                };
+                let custom_path = match custom_path {
                     Ok(path) => path.trim().to_string(),
                     Err(_) => return Ok(false),
                 };
@@ -290,20 +990,12 @@ pub fn create_worktree_with_ui(
                     return Ok(false);
                 }
 
-                // Always treat custom path as a directory and append worktree name
-                let custom_path = custom_path.trim_end_matches(SLASH_CHAR);
-                let final_path = if custom_path.is_empty() {
-                    // Just "/" was entered - use worktree name directly
-                    name.clone()
-                } else if custom_path == "." {
-                    // "./" was entered - create in project root
-                    format!("./{name}")
-                } else {
-                    format!("{custom_path}/{name}")
-                };
+                let final_path = custom_worktree_path(&custom_path, &name);
 
                 // Validate custom path
-                if let Err(e) = validate_custom_path(&final_path) {
+                if let Err(e) = validate_custom_path(&final_path)
+                    .and_then(|_| check_custom_path_target(manager, &final_path))
+                {
                     utils::print_error(&format!("Invalid custom path: {e}"));
                     return Ok(false);
                 }
@@ -317,7 +1009,7 @@ pub fn create_worktree_with_ui(
     };
 
     // Branch handling
-    println!();
+    output::blank_line();
     let branch_options = vec![
         OPTION_CREATE_FROM_HEAD_FULL.to_string(),
         OPTION_SELECT_BRANCH_FULL.to_string(),
@@ -336,7 +1028,20 @@ pub fn create_worktree_with_ui(
     let (branch, new_branch_name) = match branch_choice {
         BRANCH_OPTION_SELECT_BRANCH => {
             // Select branch
-            let (local_branches, remote_branches) = manager.list_all_branches()?;
+            let create_config = Config::load()?.create;
+            let (mut local_branches, remote_branches) = manager.list_all_branches()?;
+            if create_config.branch_sort == BranchSort::CommitterDate {
+                manager.sort_branches_by_commit_time(&mut local_branches);
+            }
+            // Without a terminal every remote branch stays pickable by name
+            // (`gw create --from`), even when a local branch matches it
+            let remote_branches = if ui.is_interactive() {
+                dedupe_remote_branches(&local_branches, &remote_branches, |b| {
+                    manager.is_remote_branch_in_sync(b)
+                })
+            } else {
+                remote_branches
+            };
             if local_branches.is_empty() && remote_branches.is_empty() {
                 utils::print_warning("No branches found, creating from HEAD");
                 (None, None)
@@ -347,7 +1052,8 @@ pub fn create_worktree_with_ui(
 
                 // Create items for fuzzy search (plain text for search, formatted for display)
                 let mut branch_items: Vec<String> = Vec::new();
-                let mut branch_refs: Vec<(String, bool)> = Vec::new(); // (branch_name, is_remote)
+                // (branch_name, is_remote, reference to create from)
+                let mut branch_refs: Vec<(String, bool, String)> = Vec::new();
 
                 // Add local branches with laptop icon (laptop emoji takes 2 columns)
                 for branch in &local_branches {
@@ -358,12 +1064,12 @@ pub fn create_worktree_with_ui(
                     } else {
                         branch_items.push(format!("{ICON_LOCAL_BRANCH}{branch}"));
                     }
-                    branch_refs.push((branch.clone(), false));
+                    branch_refs.push((branch.clone(), false, branch.clone()));
                 }
 
                 // Add remote branches with cloud icon (cloud emoji should align with laptop)
-                for branch in &remote_branches {
-                    let full_remote_name = format!("{GIT_REMOTE_PREFIX}{branch}");
+                for remote_branch in &remote_branches {
+                    let full_remote_name = remote_branch.full_name();
                     if let Some(worktree) = branch_worktree_map.get(&full_remote_name) {
                         branch_items.push(format!(
                             "{ICON_REMOTE_BRANCH}{full_remote_name} (in use by '{worktree}')"
@@ -371,14 +1077,16 @@ pub fn create_worktree_with_ui(
                     } else {
                         branch_items.push(format!("{ICON_REMOTE_BRANCH}{full_remote_name}"));
                     }
-                    branch_refs.push((branch.clone(), true));
+                    branch_refs.push((remote_branch.branch.clone(), true, full_remote_name));
                 }
 
-                println!();
+                output::blank_line();
 
                 // Use FuzzySelect for better search experience when there are many branches
-                let selection_result = if branch_items.len() > FUZZY_SEARCH_THRESHOLD {
-                    println!("Type to search branches (fuzzy search enabled):");
+                let always_fuzzy = create_config.always_fuzzy_branches;
+                let selection_result = if use_fuzzy_branch_picker(branch_items.len(), always_fuzzy)
+                {
+                    output::decoration("Type to search branches (fuzzy search enabled):");
                     ui.fuzzy_select(PROMPT_SELECT_BRANCH, &branch_items)
                 } else {
                     ui.select_with_default(
@@ -391,25 +1099,24 @@ pub fn create_worktree_with_ui(
 
                 match selection_result {
                     Some(selection) => {
-                        let (selected_branch, is_remote): (&String, &bool) =
-                            (&branch_refs[selection].0, &branch_refs[selection].1);
+                        let (selected_branch, is_remote, selected_ref) = &branch_refs[selection];
 
                         if !is_remote {
                             // Local branch - check if already checked out
                             if let Some(worktree) = branch_worktree_map.get(selected_branch) {
                                 // Branch is in use, offer to create a new branch
-                                println!();
+                                output::blank_line();
                                 utils::print_warning(&format!(
                                     "Branch '{}' is already checked out in worktree '{}'",
                                     selected_branch.yellow(),
                                     worktree.bright_red()
                                 ));
-                                println!();
+                                output::blank_line();
 
                                 let action_options = vec![
                                     format!(
                                         "Create new branch '{}' from '{}'",
-                                        name, selected_branch
+                                        default_branch, selected_branch
                                     ),
                                     "Change the branch name".to_string(),
                                     "Cancel".to_string(),
@@ -420,19 +1127,22 @@ pub fn create_worktree_with_ui(
                                     &action_options,
                                     DEFAULT_MENU_SELECTION,
                                 ) {
-                                    Ok(0) => {
-                                        // Use worktree name as new branch name
-                                        (Some(selected_branch.clone()), Some(name.clone()))
+                                    Ok(CONFLICT_ACTION_CREATE_NEW_BRANCH) => {
+                                        // Use the default branch name for the new branch
+                                        (
+                                            Some(selected_branch.clone()),
+                                            Some(default_branch.clone()),
+                                        )
                                     }
                                     Ok(1) => {
                                         // Ask for custom branch name
-                                        println!();
+                                        output::blank_line();
                                         let new_branch = match ui.input_with_default(
                                             &format!(
                                                 "Enter new branch name (base: {})",
                                                 selected_branch.yellow()
                                             ),
-                                            &name,
+                                            &default_branch,
                                         ) {
                                             Ok(name) => name.trim().to_string(),
                                             Err(_) => return Ok(false),
@@ -443,6 +1153,14 @@ pub fn create_worktree_with_ui(
                                             return Ok(false);
                                         }
 
+                                        if !is_valid_branch_name(&new_branch) {
+                                            utils::print_error(
+                                                &ERROR_INVALID_BRANCH_NAME
+                                                    .replace("{}", &new_branch),
+                                            );
+                                            return Ok(false);
+                                        }
+
                                         if local_branches.contains(&new_branch) {
                                             utils::print_error(&format!(
                                                 "Branch '{new_branch}' already exists"
@@ -461,13 +1179,13 @@ pub fn create_worktree_with_ui(
                             // Remote branch - check if local branch with same name exists
                             if local_branches.contains(selected_branch) {
                                 // Local branch with same name exists
-                                println!();
+                                output::blank_line();
                                 utils::print_warning(&format!(
                                     "A local branch '{}' already exists for remote '{}'",
                                     selected_branch.yellow(),
-                                    format!("{GIT_REMOTE_PREFIX}{selected_branch}").bright_blue()
+                                    selected_ref.bright_blue()
                                 ));
-                                println!();
+                                output::blank_line();
 
                                 let use_local_option = if let Some(worktree) =
                                     branch_worktree_map.get(selected_branch)
@@ -482,8 +1200,7 @@ pub fn create_worktree_with_ui(
 
                                 let action_options = vec![
                                     format!(
-                                        "Create new branch '{}' from '{}{}'",
-                                        name, GIT_REMOTE_PREFIX, selected_branch
+                                        "Create new branch '{default_branch}' from '{selected_ref}'"
                                     ),
                                     use_local_option,
                                     "Cancel".to_string(),
@@ -494,25 +1211,22 @@ pub fn create_worktree_with_ui(
                                     &action_options,
                                     DEFAULT_MENU_SELECTION,
                                 ) {
-                                    Ok(0) => {
-                                        // Create new branch with worktree name
-                                        (
-                                            Some(format!("{GIT_REMOTE_PREFIX}{selected_branch}")),
-                                            Some(name.clone()),
-                                        )
+                                    Ok(CONFLICT_ACTION_CREATE_NEW_BRANCH) => {
+                                        // Create new branch with the default branch name
+                                        (Some(selected_ref.clone()), Some(default_branch.clone()))
                                     }
                                     Ok(1) => {
                                         // Use local branch instead - but check if it's already in use
                                         if let Some(worktree) =
                                             branch_worktree_map.get(selected_branch)
                                         {
-                                            println!();
+                                            output::blank_line();
                                             utils::print_error(&format!(
                                                 "Branch '{}' is already checked out in worktree '{}'",
                                                 selected_branch.yellow(),
                                                 worktree.bright_red()
                                             ));
-                                            println!("Please select a different option.");
+                                            output::decoration("Please select a different option.");
                                             return Ok(false);
                                         }
                                         (Some(selected_branch.clone()), None)
@@ -521,7 +1235,7 @@ pub fn create_worktree_with_ui(
                                 }
                             } else {
                                 // No conflict, proceed normally
-                                (Some(format!("{GIT_REMOTE_PREFIX}{selected_branch}")), None)
+                                (Some(selected_ref.clone()), None)
                             }
                         }
                     }
@@ -531,7 +1245,11 @@ pub fn create_worktree_with_ui(
         }
         BRANCH_OPTION_SELECT_TAG => {
             // Select tag
-            let tags = manager.list_all_tags()?;
+            let tags: Vec<(String, Option<String>)> = manager
+                .list_tags()?
+                .into_iter()
+                .map(|tag| (tag.name, tag.message))
+                .collect();
             if tags.is_empty() {
                 utils::print_warning("No tags found, creating from HEAD");
                 (None, None)
@@ -555,11 +1273,11 @@ pub fn create_worktree_with_ui(
                     })
                     .collect();
 
-                println!();
+                output::blank_line();
 
                 // Use FuzzySelect for better search experience when there are many tags
                 let selection_result = if tag_items.len() > FUZZY_SEARCH_THRESHOLD {
-                    println!("Type to search tags (fuzzy search enabled):");
+                    output::decoration("Type to search tags (fuzzy search enabled):");
                     ui.fuzzy_select(PROMPT_SELECT_TAG, &tag_items)
                 } else {
                     ui.select_with_default(PROMPT_SELECT_TAG, &tag_items, DEFAULT_MENU_SELECTION)
@@ -569,63 +1287,145 @@ pub fn create_worktree_with_ui(
                 match selection_result {
                     Some(selection) => {
                         let selected_tag = &tags[selection].0;
-                        // For tags, we always create a new branch named after the worktree
-                        (Some(selected_tag.clone()), Some(name.clone()))
+                        // For tags, we always create a new branch
+                        let new_branch = if branch_template.is_some() {
+                            match prompt_new_branch_name(ui, &default_branch) {
+                                Some(branch) => branch,
+                                None => return Ok(false),
+                            }
+                        } else {
+                            name.clone()
+                        };
+                        (Some(selected_tag.clone()), Some(new_branch))
                     }
                     None => return Ok(false),
                 }
             }
         }
+        _ if branch_template.is_some() => {
+            // Create a templated branch from current HEAD
+            match prompt_new_branch_name(ui, &default_branch) {
+                Some(new_branch) => (Some(GIT_HEAD_REF.to_string()), Some(new_branch)),
+                None => return Ok(false),
+            }
+        }
         _ => {
             // Create from current HEAD
             (None, None)
         }
     };
 
+    // A taken branch name would otherwise only fail in `git worktree add`
+    let (branch, new_branch_name) = match new_branch_name {
+        Some(new_branch) => match check_new_branch_name(manager, ui, branch, new_branch)? {
+            Some(checked) => checked,
+            None => return Ok(false),
+        },
+        None => (branch, None),
+    };
+
     // Show preview
-    println!();
+    output::blank_line();
     let preview_label = "Preview:".bright_white();
-    println!("{preview_label}");
+    output::decoration(preview_label);
     let name_label = "Name:".bright_black();
     let name_value = final_name.bright_green();
-    println!("  {name_label} {name_value}");
+    output::decoration(format!("  {name_label} {name_value}"));
     if let Some(new_branch) = &new_branch_name {
         let base_branch_name = branch.as_ref().unwrap();
         // Check if the base branch is a tag
-        if manager
-            .repo()
-            .find_reference(&format!("refs/tags/{base_branch_name}"))
-            .is_ok()
-        {
+        if manager.tag_exists(base_branch_name) {
             let branch_label = "New Branch:".bright_black();
             let branch_value = new_branch.yellow();
             let tag_value = format!("tag: {base_branch_name}").bright_cyan();
-            println!("  {branch_label} {branch_value} (from {tag_value})");
+            output::decoration(format!(
+                "  {branch_label} {branch_value} (from {tag_value})"
+            ));
         } else {
             let branch_label = "New Branch:".bright_black();
             let branch_value = new_branch.yellow();
             let base_value = base_branch_name.bright_black();
-            println!("  {branch_label} {branch_value} (from {base_value})");
+            output::decoration(format!(
+                "  {branch_label} {branch_value} (from {base_value})"
+            ));
         }
     } else if let Some(branch_name) = &branch {
         let branch_label = "Branch:".bright_black();
         let branch_value = branch_name.yellow();
-        println!("  {branch_label} {branch_value}");
+        output::decoration(format!("  {branch_label} {branch_value}"));
     } else {
         let from_label = "From:".bright_black();
-        println!("  {from_label} Current HEAD");
+        output::decoration(format!("  {from_label} Current HEAD"));
+    }
+
+    // Files from a ref are only known once the tree is read, so only a
+    // directory source is previewed
+    let config = Config::load()?;
+    let mut missing_files = false;
+    if !config.files.copy.is_empty() && config.files.source_ref.is_none() {
+        if let Ok(source) = manager.file_copy_source(&config.files) {
+            let plan = file_copy::resolve_copy_plan(&config.files, &source);
+            missing_files = plan.iter().any(|(_, exists)| !exists);
+            print_copy_plan(&plan, &source);
+        }
+    }
+    output::blank_line();
+
+    if missing_files
+        && !ui
+            .confirm_with_default(PROMPT_CREATE_WITH_MISSING_FILES, true)
+            .unwrap_or(false)
+    {
+        return Ok(false);
+    }
+
+    // Execute pre-create hooks (run from the repository root)
+    let planned_path = manager.resolve_worktree_path(&final_name)?;
+    match hooks::execute_hooks(
+        HOOK_PRE_CREATE,
+        &HookContext {
+            worktree_name: name.clone(),
+            worktree_path: planned_path,
+            previous_worktree_name: None,
+            previous_worktree_path: None,
+        },
+    ) {
+        Ok(report) => {
+            if let Some(failure) = report.first_failure() {
+                if config.hooks.on_failure == HookFailurePolicy::Abort {
+                    return cancel_after_hook_failure(ui, failure);
+                }
+            }
+        }
+        Err(e) => utils::print_warning(&format!("Hook execution warning: {e}")),
     }
-    println!();
 
-    // Create worktree with progress bar
-    let pb = ProgressBar::new_spinner();
-    pb.set_style(
-        ProgressStyle::default_spinner()
-            .template("{spinner:.green} {msg}")
-            .unwrap(),
-    );
+    // Create worktree with progress bar (hidden with --quiet)
+    let pb = output::spinner("Creating worktree...");
+
+    // The remote-tracking ref may be stale; a failed fetch falls back to it
+    let remote_base = branch
+        .as_deref()
+        .and_then(|base| manager.find_remote_branch(base));
+    if let Some(remote_branch) = remote_base.filter(|_| fetch_before_create) {
+        let full_name = remote_branch.full_name();
+        pb.set_message(MSG_FETCHING.replace("{}", &full_name));
+        if let Err(e) = manager.fetch_remote(
+            &remote_branch.remote,
+            Some(&remote_branch.branch),
+            fetch_depth,
+        ) {
+            let warning = WARNING_FETCH_FAILED_CACHED
+                .replacen("{}", &e.to_string(), 1)
+                .replacen("{}", &full_name, 1);
+            pb.suspend(|| utils::print_warning(&warning));
+        }
+    }
     pb.set_message("Creating worktree...");
-    pb.enable_steady_tick(Duration::from_millis(PROGRESS_BAR_TICK_MILLIS));
+
+    // Remember the local branches so a rollback only deletes one this
+    // creation added; if they cannot be listed, no branch is deleted
+    let branches_before = manager.list_all_branches().map(|(local, _)| local).ok();
 
     let result = if let Some(new_branch) = &new_branch_name {
         // Create worktree with new branch from base branch
@@ -644,87 +1444,394 @@ pub fn create_worktree_with_ui(
                 "Created worktree '{name_green}' at {path_display}"
             ));
 
+            let expected_branch = expected_branch_for_new_worktree(
+                branch.as_deref(),
+                new_branch_name.as_deref(),
+                &path,
+                &manager.remote_names(),
+            );
+            verify_created_branch(&path, expected_branch.as_deref());
+
+            let created = CreatedWorktree {
+                name: &name,
+                path: &path,
+                created_branch: branches_before.as_deref().and_then(|before| {
+                    let checked_out = worktree_head_branch(&path).ok().flatten();
+                    branch_created_by_worktree(before, checked_out.as_deref())
+                }),
+            };
+            let abort = config.hooks.on_failure == HookFailurePolicy::Abort;
+
             // Copy configured files
-            let config = Config::load()?;
             if !config.files.copy.is_empty() {
-                println!();
-                println!("Copying configured files...");
-                match file_copy::copy_configured_files(&config.files, &path, manager) {
-                    Ok(copied) => {
-                        if !copied.is_empty() {
-                            let copied_count = copied.len();
-                            utils::print_success(&format!("Copied {copied_count} files"));
-                            for file in &copied {
-                                println!("  ✓ {file}");
-                            }
-                        }
+                output::blank_line();
+                output::decoration("Copying configured files...");
+                let copy_error = match manager.copy_configured_files(&config.files, &path) {
+                    Ok(report) => {
+                        print_file_copy_summary(&report);
+                        (!report.failed.is_empty()).then(|| {
+                            ERROR_FILE_COPY_INCOMPLETE
+                                .replace("{}", &report.failed.len().to_string())
+                        })
                     }
                     Err(e) => {
-                        utils::print_warning(&format!("Failed to copy files: {e}"));
+                        if !abort {
+                            utils::print_warning(&format!("Failed to copy files: {e}"));
+                        }
+                        Some(e.to_string())
                     }
+                };
+                if let Some(error) = copy_error.filter(|_| abort) {
+                    let message = ERROR_FILE_COPY_ABORTED.replace("{}", &error);
+                    return abort_after_create_failure(manager, ui, &created, anyhow!(message));
                 }
             }
 
             // Execute post-create hooks
-            if let Err(e) = hooks::execute_hooks(
-                HOOK_POST_CREATE,
-                &HookContext {
-                    worktree_name: name.clone(),
-                    worktree_path: path.clone(),
-                },
-            ) {
-                utils::print_warning(&format!("Hook execution warning: {e}"));
+            let context = HookContext {
+                worktree_name: name.clone(),
+                worktree_path: path.clone(),
+                previous_worktree_name: None,
+                previous_worktree_path: None,
+            };
+            match hooks::execute_hooks(HOOK_POST_CREATE, &context) {
+                Ok(report) => {
+                    if let Some(failure) = report.first_failure() {
+                        if abort {
+                            let message =
+                                ERROR_POST_CREATE_HOOK_FAILED.replace("{}", &failure.to_string());
+                            let error = GitWorkersError::HookFailed(message).into();
+                            return abort_after_create_failure(manager, ui, &created, error);
+                        }
+                    }
+                }
+                Err(e) => utils::print_warning(&format!("Hook execution warning: {e}")),
+            }
+
+            offer_task(&config, &context, ui)?;
+
+            if !has_worktrees {
+                offer_config_setup(manager, ui)?;
             }
 
             // Ask if user wants to switch to the new worktree
-            println!();
+            output::blank_line();
             let switch = ui
-                .confirm_with_default("Switch to the new worktree?", true)
+                .confirm_with_default(PROMPT_SWITCH_TO_NEW_WORKTREE, true)
                 .unwrap_or(false);
 
             if switch {
                 // Switch to the new worktree
                 write_switch_path(&path);
 
-                println!();
+                output::blank_line();
                 let plus_sign = "+".green();
                 let worktree_name = name.bright_white().bold();
                 println!("{plus_sign} Switching to worktree '{worktree_name}'");
 
                 // Execute post-switch hooks
+                let previous = record_switch(manager, &path);
                 if let Err(e) = hooks::execute_hooks(
                     HOOK_POST_SWITCH,
-                    &HookContext {
-                        worktree_name: name,
-                        worktree_path: path,
-                    },
+                    &post_switch_context(&name, &path, previous.as_ref()),
                 ) {
                     utils::print_warning(&format!("Hook execution warning: {e}"));
                 }
 
                 Ok(true) // Indicate that we switched
             } else {
-                println!();
-                press_any_key_to_continue()?;
+                if ui.is_interactive() {
+                    output::blank_line();
+                    press_any_key_to_continue()?;
+                }
                 Ok(false)
             }
         }
         Err(e) => {
             pb.finish_and_clear();
+            if !ui.is_interactive() {
+                return Err(e);
+            }
             utils::print_error(&format!("Failed to create worktree: {e}"));
-            println!();
+            output::blank_line();
             press_any_key_to_continue()?;
             Ok(false)
         }
     }
 }
 
+/// Prints the files that will be copied into the new worktree
+///
+/// Paths are shown relative to the copy source; missing ones are marked so
+/// the user can cancel before anything is created.
+fn print_copy_plan(plan: &[(PathBuf, bool)], source: &Path) {
+    let copy_label = "Copy files:".bright_black();
+    output::decoration(format!("  {copy_label}"));
+    for (path, exists) in plan {
+        let relative = path.strip_prefix(source).unwrap_or(path).display();
+        if *exists {
+            let icon = ICON_SUCCESS.green();
+            output::decoration(format!("    {icon} {relative}"));
+        } else {
+            let icon = ICON_ERROR.red();
+            let missing = format!("({FILE_COPY_PLAN_MISSING})").red();
+            output::decoration(format!("    {icon} {relative} {missing}"));
+        }
+    }
+}
+
+/// Handles a failed pre-create hook under `[hooks] on_failure = "abort"`
+///
+/// Nothing has been created yet, so creation is simply cancelled. Without a
+/// terminal the failure is returned as an error so scripts see the hook exit
+/// code.
+fn cancel_after_hook_failure(ui: &dyn UserInterface, failure: &HookFailure) -> Result<bool> {
+    let message = ERROR_PRE_CREATE_HOOK_FAILED.replace("{}", &failure.to_string());
+    output::blank_line();
+    utils::print_error(&message);
+
+    if !ui.is_interactive() {
+        return Err(GitWorkersError::HookFailed(message).into());
+    }
+    output::blank_line();
+    press_any_key_to_continue()?;
+    Ok(false)
+}
+
+/// A worktree created by this run, kept so a failed setup can be undone
+struct CreatedWorktree<'a> {
+    name: &'a str,
+    path: &'a Path,
+    /// Branch the creation added, deleted again on rollback
+    created_branch: Option<String>,
+}
+
+/// What a rollback managed to clean up
+#[derive(Debug, Default, PartialEq, Eq)]
+struct RollbackReport {
+    worktree_removed: bool,
+    branch_deleted: bool,
+}
+
+/// Handles a failed file copy or post-create hook under
+/// `[hooks] on_failure = "abort"`
+///
+/// Offers to roll back to the state before creation: the half-initialised
+/// worktree is removed and the branch it added, if any, is deleted. Without
+/// a terminal the rollback happens by default and `error` is returned so
+/// scripts see the failure (the hook exit code for a failed hook).
+fn abort_after_create_failure(
+    manager: &dyn GitOperations,
+    ui: &dyn UserInterface,
+    created: &CreatedWorktree,
+    error: anyhow::Error,
+) -> Result<bool> {
+    output::blank_line();
+    utils::print_error(&error.to_string());
+
+    let prompt = match &created.created_branch {
+        Some(branch) => PROMPT_ROLLBACK_WORKTREE_AND_BRANCH.replace("{}", branch),
+        None => PROMPT_ROLLBACK_WORKTREE.to_string(),
+    };
+    if ui.confirm_with_default(&prompt, true).unwrap_or(false) {
+        rollback_created_worktree(manager, created);
+    }
+
+    if !ui.is_interactive() {
+        return Err(error);
+    }
+    output::blank_line();
+    press_any_key_to_continue()?;
+    Ok(false)
+}
+
+/// Removes a just-created worktree and the branch it added
+///
+/// Best-effort: every step is reported and a failure never stops the rest.
+/// The worktree is removed forcefully since hooks usually leave untracked
+/// files behind; the branch is only deleted once its worktree is gone.
+fn rollback_created_worktree(
+    manager: &dyn GitOperations,
+    created: &CreatedWorktree,
+) -> RollbackReport {
+    let mut report = RollbackReport::default();
+
+    let target = created
+        .path
+        .canonicalize()
+        .unwrap_or_else(|_| created.path.to_path_buf());
+    let git_name = manager
+        .list_worktrees()
+        .unwrap_or_default()
+        .into_iter()
+        .find(|w| w.path.canonicalize().unwrap_or_else(|_| w.path.clone()) == target)
+        .map(|w| w.git_name)
+        .unwrap_or_else(|| created.name.to_string());
+    match manager.remove_worktree_force(&git_name) {
+        Ok(()) => {
+            report.worktree_removed = true;
+            utils::print_success(&MSG_WORKTREE_ROLLED_BACK.replace("{}", created.name));
+        }
+        Err(e) => utils::print_warning(
+            &WARNING_ROLLBACK_WORKTREE_FAILED
+                .replacen("{}", created.name, 1)
+                .replacen("{}", &e.to_string(), 1),
+        ),
+    }
+
+    if let Some(branch) = &created.created_branch {
+        if !report.worktree_removed {
+            utils::print_warning(&WARNING_ROLLBACK_BRANCH_KEPT.replace("{}", branch));
+        } else {
+            match manager.delete_branch(branch) {
+                Ok(()) => {
+                    report.branch_deleted = true;
+                    utils::print_success(&MSG_BRANCH_ROLLED_BACK.replace("{}", branch));
+                }
+                Err(e) => utils::print_warning(
+                    &WARNING_ROLLBACK_BRANCH_FAILED
+                        .replacen("{}", branch, 1)
+                        .replacen("{}", &e.to_string(), 1),
+                ),
+            }
+        }
+    }
+    report
+}
+
 #[cfg(test)] // Re-enabled tests with corrections
 mod tests {
     use super::*;
     use std::path::PathBuf;
     use tempfile::TempDir;
 
+    #[test]
+    fn test_custom_worktree_path() {
+        assert_eq!(custom_worktree_path("branch/", "feature"), "branch/feature");
+        assert_eq!(custom_worktree_path("./", "feature"), "./feature");
+        assert_eq!(custom_worktree_path("/", "feature"), "feature");
+        assert_eq!(custom_worktree_path("../", "feature"), "../feature");
+    }
+
+    #[test]
+    fn test_branch_name_from_template() {
+        assert_eq!(
+            branch_name_from_template("feature/{{name}}", "login", None).unwrap(),
+            "feature/login"
+        );
+        assert_eq!(
+            branch_name_from_template("{{user}}/{{name}}", "login", Some("alice")).unwrap(),
+            "alice/login"
+        );
+        assert!(branch_name_from_template("{{user}}/{{name}}", "login", None).is_err());
+        assert!(branch_name_from_template("feature..{{name}}", "login", None).is_err());
+        assert!(branch_name_from_template("{{name}}.lock", "login", None).is_err());
+    }
+
+    #[test]
+    fn test_is_valid_branch_name() {
+        assert!(is_valid_branch_name("feature/login"));
+        assert!(!is_valid_branch_name("feature login"));
+        assert!(!is_valid_branch_name("feature/"));
+        assert!(!is_valid_branch_name(""));
+    }
+
+    #[test]
+    fn test_custom_worktree_path_template() {
+        assert_eq!(
+            custom_worktree_path("branches/{{name}}", "feature"),
+            "branches/feature"
+        );
+        assert_eq!(
+            custom_worktree_path("../{{name}}-wt", "feature"),
+            "../feature-wt"
+        );
+    }
+
+    #[test]
+    fn test_check_create_options() {
+        let options = CreateOptions {
+            name: Some(" feature ".to_string()),
+            from_head: true,
+            ..CreateOptions::default()
+        };
+        assert_eq!(check_create_options(&options).unwrap(), "feature");
+
+        let missing_name = CreateOptions {
+            from_head: true,
+            ..CreateOptions::default()
+        };
+        assert!(check_create_options(&missing_name).is_err());
+
+        let missing_source = CreateOptions {
+            name: Some("feature".to_string()),
+            ..CreateOptions::default()
+        };
+        assert!(check_create_options(&missing_source).is_err());
+
+        let both_sources = CreateOptions {
+            name: Some("feature".to_string()),
+            branch: Some("main".to_string()),
+            from_head: true,
+            ..CreateOptions::default()
+        };
+        assert!(check_create_options(&both_sources).is_err());
+
+        let from_without_branch = CreateOptions {
+            name: Some("feature".to_string()),
+            from: Some("upstream".to_string()),
+            from_head: true,
+            ..CreateOptions::default()
+        };
+        assert!(check_create_options(&from_without_branch).is_err());
+    }
+
+    #[test]
+    fn test_check_fetch_depth() {
+        assert_eq!(check_fetch_depth(None).unwrap(), None);
+        assert_eq!(check_fetch_depth(Some(1)).unwrap(), Some(1));
+        assert!(check_fetch_depth(Some(0)).is_err());
+    }
+
+    #[test]
+    fn test_create_branch_label() {
+        let mut options = CreateOptions {
+            branch: Some("main".to_string()),
+            ..CreateOptions::default()
+        };
+        assert_eq!(create_branch_label(&options).as_deref(), Some("main"));
+
+        options.from = Some("upstream".to_string());
+        assert_eq!(
+            create_branch_label(&options).as_deref(),
+            Some("upstream/main")
+        );
+
+        options.branch = None;
+        assert_eq!(create_branch_label(&options), None);
+    }
+
+    #[test]
+    fn test_task_menu_items() {
+        let mut tasks = HashMap::new();
+        tasks.insert(
+            "setup".to_string(),
+            vec![HookEntry::from("npm ci"), HookEntry::from("npm run build")],
+        );
+        tasks.insert("db".to_string(), vec![HookEntry::from("make db")]);
+
+        assert_eq!(
+            task_menu_items(&tasks),
+            vec![
+                ("db".to_string(), "db (make db)".to_string()),
+                (
+                    "setup".to_string(),
+                    "setup (npm ci, npm run build)".to_string()
+                ),
+            ]
+        );
+    }
+
     #[test]
     fn test_validate_worktree_location_valid() {
         // Test valid location types
@@ -827,6 +1934,29 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_next_free_worktree_name() {
+        let taken = ["feature", "feature-2", "feature-3"];
+        let is_free = |candidate: &str| !taken.contains(&candidate);
+
+        assert_eq!(
+            next_free_worktree_name("bugfix", is_free),
+            Some("bugfix".to_string())
+        );
+        assert_eq!(
+            next_free_worktree_name("feature", is_free),
+            Some("feature-4".to_string())
+        );
+        assert_eq!(next_free_worktree_name("feature", |_| false), None);
+    }
+
+    #[test]
+    fn test_next_free_worktree_name_revalidates_candidates() {
+        // A name at the length limit only fits without a suffix
+        let name = "a".repeat(crate::constants::MAX_WORKTREE_NAME_LENGTH);
+        assert_eq!(next_free_worktree_name(&name, |c| c != name), None);
+    }
+
     #[test]
     fn test_validate_worktree_creation_no_conflicts() {
         let existing_worktrees = vec![];
@@ -943,4 +2073,190 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_default_location_index_mapping() {
+        assert_eq!(
+            default_location_index(Some("same-level")),
+            WORKTREE_LOCATION_SAME_LEVEL
+        );
+        assert_eq!(
+            default_location_index(Some("subdirectory")),
+            WORKTREE_LOCATION_SUBDIRECTORY
+        );
+        assert_eq!(
+            default_location_index(Some("custom")),
+            WORKTREE_LOCATION_CUSTOM_PATH
+        );
+    }
+
+    #[test]
+    fn test_default_location_index_fallback() {
+        assert_eq!(default_location_index(None), DEFAULT_MENU_SELECTION);
+        assert_eq!(
+            default_location_index(Some("elsewhere")),
+            DEFAULT_MENU_SELECTION
+        );
+    }
+
+    #[test]
+    fn test_expected_branch_for_new_worktree() {
+        let path = Path::new("/repo/worktrees/feature");
+        let remotes = vec!["origin".to_string(), "upstream".to_string()];
+        let expected = |branch, new_branch| {
+            expected_branch_for_new_worktree(branch, new_branch, path, &remotes)
+        };
+        assert_eq!(
+            expected(Some("main"), Some("feature-x")),
+            Some("feature-x".to_string())
+        );
+        assert_eq!(
+            expected(Some("origin/develop"), None),
+            Some("develop".to_string())
+        );
+        assert_eq!(
+            expected(Some("upstream/release/1.0"), None),
+            Some("release/1.0".to_string())
+        );
+        // Not a remote, so the slash is part of the branch name
+        assert_eq!(
+            expected(Some("feature/login"), None),
+            Some("feature/login".to_string())
+        );
+        assert_eq!(expected(None, None), Some("feature".to_string()));
+    }
+
+    #[test]
+    fn test_check_created_branch_matches() {
+        assert_eq!(check_created_branch("feature", Some("feature")), None);
+    }
+
+    #[test]
+    fn test_check_created_branch_diverges() {
+        let warning = check_created_branch("feature", Some("main")).unwrap();
+        assert!(warning.contains("'main'"));
+        assert!(warning.contains("'feature'"));
+
+        let warning = check_created_branch("feature", None).unwrap();
+        assert!(warning.contains("detached"));
+    }
+
+    #[test]
+    fn test_created_worktree_on_other_branch_is_detected() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let repo_path = temp_dir.path().join("repo");
+        let repo = git2::Repository::init(&repo_path)?;
+        let sig = git2::Signature::now("Test", "test@example.com")?;
+        let tree_id = repo.index()?.write_tree()?;
+        let tree = repo.find_tree(tree_id)?;
+        let commit = repo.commit(Some("HEAD"), &sig, &sig, "Initial", &tree, &[])?;
+        repo.branch("other", &repo.find_commit(commit)?, false)?;
+        repo.set_head("refs/heads/other")?;
+
+        let actual = worktree_head_branch(&repo_path)?;
+        assert_eq!(actual.as_deref(), Some("other"));
+        assert!(check_created_branch("feature", actual.as_deref()).is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_branch_created_by_worktree() {
+        let before = vec!["main".to_string(), "develop".to_string()];
+        assert_eq!(
+            branch_created_by_worktree(&before, Some("feature")),
+            Some("feature".to_string())
+        );
+        assert_eq!(branch_created_by_worktree(&before, Some("develop")), None);
+        assert_eq!(branch_created_by_worktree(&before, None), None);
+    }
+
+    #[test]
+    fn test_branch_one_edit_away() {
+        let existing = vec!["develop".to_string(), "feature/login".to_string()];
+        assert_eq!(
+            branch_one_edit_away("feature/logn", &existing),
+            Some("feature/login")
+        );
+        assert_eq!(branch_one_edit_away("devolop", &existing), Some("develop"));
+        assert_eq!(branch_one_edit_away("develop", &existing), None);
+        assert_eq!(branch_one_edit_away("feature/signup", &existing), None);
+    }
+
+    #[test]
+    fn test_rollback_removes_worktree_and_created_branch() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let repo_path = temp_dir.path().join("repo");
+        let repo = git2::Repository::init(&repo_path)?;
+        let sig = git2::Signature::now("Test", "test@example.com")?;
+        let tree_id = repo.index()?.write_tree()?;
+        let tree = repo.find_tree(tree_id)?;
+        repo.commit(Some("HEAD"), &sig, &sig, "Initial", &tree, &[])?;
+
+        let manager = GitWorktreeManager::new_from_path(&repo_path)?;
+        let base = worktree_head_branch(&repo_path)?.unwrap();
+        let (before, _) = manager.list_all_branches()?;
+        let path = manager.create_worktree_with_new_branch("feature", "feature", &base)?;
+        std::fs::write(path.join("untracked.txt"), "left by a hook")?;
+
+        let created = CreatedWorktree {
+            name: "feature",
+            path: &path,
+            created_branch: branch_created_by_worktree(
+                &before,
+                worktree_head_branch(&path)?.as_deref(),
+            ),
+        };
+        let report = rollback_created_worktree(&manager, &created);
+
+        assert!(report.worktree_removed);
+        assert!(report.branch_deleted);
+        assert!(!path.exists());
+        assert!(manager.list_worktrees()?.iter().all(|w| w.is_main));
+        assert_eq!(manager.list_all_branches()?.0, before);
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_setup_offered_only_without_config() {
+        assert!(should_offer_config_setup(true, false, false));
+        assert!(!should_offer_config_setup(true, true, false));
+    }
+
+    #[test]
+    fn test_config_setup_not_offered_when_dismissed_or_not_first() {
+        assert!(!should_offer_config_setup(true, false, true));
+        assert!(!should_offer_config_setup(false, false, false));
+    }
+
+    #[test]
+    fn test_use_fuzzy_branch_picker() {
+        assert!(!use_fuzzy_branch_picker(FUZZY_SEARCH_THRESHOLD, false));
+        assert!(use_fuzzy_branch_picker(FUZZY_SEARCH_THRESHOLD + 1, false));
+        assert!(use_fuzzy_branch_picker(1, true));
+    }
+
+    #[test]
+    fn test_dedupe_remote_branches() {
+        let local = vec!["main".to_string(), "feature".to_string()];
+        let remote_branch = |remote: &str, branch: &str| RemoteBranch {
+            remote: remote.to_string(),
+            branch: branch.to_string(),
+        };
+        let remote = vec![
+            remote_branch("origin", "main"),
+            remote_branch("origin", "feature"),
+            remote_branch("origin", "remote-only"),
+            remote_branch("upstream", "main"),
+        ];
+
+        // `feature` and `upstream/main` have diverged from the local branches
+        let deduped = dedupe_remote_branches(&local, &remote, |b| {
+            b.branch != "feature" && b.remote != "upstream"
+        });
+        let names: Vec<String> = deduped.iter().map(RemoteBranch::full_name).collect();
+        assert_eq!(
+            names,
+            vec!["origin/feature", "origin/remote-only", "upstream/main"]
+        );
+    }
 }
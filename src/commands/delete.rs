@@ -1,11 +1,18 @@
 use anyhow::{anyhow, Result};
 use colored::*;
 
-use crate::constants::{section_header, DEFAULT_MENU_SELECTION, HOOK_PRE_REMOVE};
+use crate::config::Config;
+use crate::constants::{
+    DEFAULT_MENU_SELECTION, ERROR_DELETE_NOT_CONFIRMED, ERROR_NO_DELETABLE_WORKTREES,
+    HOOK_POST_REMOVE, HOOK_PRE_REMOVE, MSG_BRANCH_STILL_CHECKED_OUT, MSG_DRY_RUN_NOTHING_DELETED,
+    MSG_WORKTREE_HAS_CHANGES, MSG_WOULD_DELETE_BRANCH, MSG_WOULD_DELETE_WORKTREE,
+    PROMPT_DELETE_THIS_WORKTREE, PROMPT_FORCE_DELETE, PROMPT_SELECT_WORKTREE_TO_DELETE,
+};
 use crate::git::{GitWorktreeManager, WorktreeInfo};
+use crate::git_interface::{GitOperations, GitReadOperations};
 use crate::hooks::{self, HookContext};
-use crate::ui::{DialoguerUI, UserInterface};
-use crate::utils::{self, press_any_key_to_continue};
+use crate::ui::{DialoguerUI, NonInteractiveUI, UserInterface};
+use crate::utils::{self, output, press_any_key_to_continue};
 
 /// Validate deletion target
 #[allow(dead_code)]
@@ -31,6 +38,14 @@ pub fn should_delete_orphaned_branch(
     is_branch_unique && branch_name == worktree_name
 }
 
+/// Pure business logic for deciding whether to offer branch deletion
+///
+/// Branches are only offered for deletion when no other worktree uses them
+/// and `[worktree] never_offer_branch_delete` is not set.
+pub fn should_offer_branch_delete(is_branch_unique: bool, never_offer: bool) -> bool {
+    is_branch_unique && !never_offer
+}
+
 /// Configuration for worktree deletion
 #[derive(Debug, Clone)]
 pub struct WorktreeDeleteConfig {
@@ -38,6 +53,8 @@ pub struct WorktreeDeleteConfig {
     pub path: std::path::PathBuf,
     pub branch: String,
     pub delete_branch: bool,
+    /// Delete even if the worktree has uncommitted changes
+    pub force: bool,
 }
 
 /// Result of deletion analysis
@@ -49,14 +66,20 @@ pub struct DeletionAnalysis {
 }
 
 /// Pure business logic for filtering deletable worktrees
-pub fn get_deletable_worktrees(worktrees: &[WorktreeInfo]) -> Vec<&WorktreeInfo> {
-    worktrees.iter().filter(|w| !w.is_current).collect()
+///
+/// The current worktree is never deletable. The main worktree is only
+/// deletable with `allow_main` (`gw delete --allow-main`).
+pub fn get_deletable_worktrees(worktrees: &[WorktreeInfo], allow_main: bool) -> Vec<&WorktreeInfo> {
+    worktrees
+        .iter()
+        .filter(|w| !w.is_current && (allow_main || !w.is_main))
+        .collect()
 }
 
 /// Pure business logic for analyzing deletion requirements
 pub fn analyze_deletion(
     worktree: &WorktreeInfo,
-    manager: &GitWorktreeManager,
+    manager: &dyn GitReadOperations,
 ) -> Result<DeletionAnalysis> {
     let is_branch_unique =
         manager.is_branch_unique_to_worktree(&worktree.branch, &worktree.name)?;
@@ -68,29 +91,73 @@ pub fn analyze_deletion(
     })
 }
 
+/// Finds a worktree that still has `branch` checked out
+///
+/// Whether a branch is orphaned is decided before worktrees are removed.
+/// Checking again right before deleting the branch catches worktrees that
+/// share it, including the main worktree, so the branch is skipped with a
+/// clear note instead of failing to delete.
+pub fn branch_checked_out_in<M: GitReadOperations + ?Sized>(
+    manager: &M,
+    branch: &str,
+) -> Result<Option<String>> {
+    Ok(manager.get_branch_worktree_map()?.remove(branch))
+}
+
+/// Prints that `branch` is kept because `worktree` still has it checked out
+pub fn print_branch_still_checked_out(branch: &str, worktree: &str) {
+    utils::print_warning(
+        &MSG_BRANCH_STILL_CHECKED_OUT
+            .replacen("{}", branch, 1)
+            .replacen("{}", worktree, 1),
+    );
+}
+
 /// Pure business logic for executing deletion
-pub fn execute_deletion(config: &WorktreeDeleteConfig, manager: &GitWorktreeManager) -> Result<()> {
+pub fn execute_deletion(config: &WorktreeDeleteConfig, manager: &dyn GitOperations) -> Result<()> {
     // Execute pre-remove hooks
     if let Err(e) = hooks::execute_hooks(
         HOOK_PRE_REMOVE,
         &HookContext {
             worktree_name: config.name.clone(),
             worktree_path: config.path.clone(),
+            previous_worktree_name: None,
+            previous_worktree_path: None,
         },
     ) {
         utils::print_warning(&format!("Hook execution warning: {e}"));
     }
 
     // Delete the worktree
-    manager
-        .remove_worktree(&config.name)
-        .map_err(|e| anyhow!("Failed to delete worktree: {e}"))?;
+    let result = if config.force {
+        manager.remove_worktree_force(&config.name)
+    } else {
+        manager.remove_worktree(&config.name)
+    };
+    result.map_err(|e| anyhow!("Failed to delete worktree: {e}"))?;
 
     let name_red = config.name.bright_red();
     utils::print_success(&format!("Deleted worktree '{name_red}'"));
 
+    // Execute post-remove hooks (run from the repository root)
+    if let Err(e) = hooks::execute_hooks(
+        HOOK_POST_REMOVE,
+        &HookContext {
+            worktree_name: config.name.clone(),
+            worktree_path: config.path.clone(),
+            previous_worktree_name: None,
+            previous_worktree_path: None,
+        },
+    ) {
+        utils::print_warning(&format!("Hook execution warning: {e}"));
+    }
+
     // Delete branch if requested
     if config.delete_branch {
+        if let Ok(Some(worktree)) = branch_checked_out_in(manager, &config.branch) {
+            print_branch_still_checked_out(&config.branch, &worktree);
+            return Ok(());
+        }
         match manager.delete_branch(&config.branch) {
             Ok(_) => {
                 let branch_red = config.branch.bright_red();
@@ -112,9 +179,11 @@ pub fn execute_deletion(config: &WorktreeDeleteConfig, manager: &GitWorktreeMana
 ///
 /// 1. **Selection**: Choose a worktree from the list
 /// 2. **Branch Check**: If the branch is unique to this worktree, offers to delete it
-/// 3. **Confirmation**: Shows worktree details and confirms deletion
+/// 3. **Confirmation**: Shows worktree details and confirms deletion, with a
+///    second confirmation to discard uncommitted changes
 /// 4. **Pre-remove Hooks**: Executes any configured pre-remove hooks
 /// 5. **Deletion**: Removes the worktree and optionally its branch
+/// 6. **Post-remove Hooks**: Executes any configured post-remove hooks
 ///
 /// # Safety
 ///
@@ -131,41 +200,52 @@ pub fn execute_deletion(config: &WorktreeDeleteConfig, manager: &GitWorktreeMana
 /// Returns an error if:
 /// - Git repository operations fail
 /// - File system operations fail during deletion
-pub fn delete_worktree() -> Result<()> {
+pub fn delete_worktree(dry_run: bool) -> Result<()> {
     let manager = GitWorktreeManager::new()?;
     let ui = DialoguerUI;
-    delete_worktree_with_ui(&manager, &ui)
+    delete_worktree_from(&manager, &ui, false, dry_run)
 }
 
 /// Internal implementation of delete_worktree with dependency injection
 ///
 /// # Arguments
 ///
-/// * `manager` - Git worktree manager instance
+/// * `manager` - Git operations ([`GitWorktreeManager`] outside of tests)
 /// * `ui` - User interface implementation for testability
 ///
 /// # Deletion Process
 ///
-/// 1. Filters out current worktree (cannot be deleted)
+/// 1. Filters out the current and main worktrees (cannot be deleted)
 /// 2. Presents selection list to user
 /// 3. Checks if branch is unique to the worktree
 /// 4. Confirms deletion with detailed preview
 /// 5. Executes pre-remove hooks
 /// 6. Performs deletion of worktree and optionally branch
-pub fn delete_worktree_with_ui(manager: &GitWorktreeManager, ui: &dyn UserInterface) -> Result<()> {
+pub fn delete_worktree_with_ui(manager: &dyn GitOperations, ui: &dyn UserInterface) -> Result<()> {
+    delete_worktree_from(manager, ui, false, false)
+}
+
+/// Runs the deletion flow, offering the main worktree only with `allow_main`
+///
+/// With `dry_run` every question is still asked, then the worktree and
+/// branch that would be deleted are printed instead of deleting them.
+fn delete_worktree_from(
+    manager: &dyn GitOperations,
+    ui: &dyn UserInterface,
+    allow_main: bool,
+    dry_run: bool,
+) -> Result<()> {
     let worktrees = manager.list_worktrees()?;
 
     if worktrees.is_empty() {
         println!();
         let msg = "• No worktrees to delete.".yellow();
         println!("{msg}");
-        println!();
-        press_any_key_to_continue()?;
-        return Ok(());
+        return pause_or_fail(ui, ERROR_NO_DELETABLE_WORKTREES);
     }
 
     // Use business logic to filter deletable worktrees
-    let deletable_worktrees = get_deletable_worktrees(&worktrees);
+    let deletable_worktrees = get_deletable_worktrees(&worktrees, allow_main);
 
     if deletable_worktrees.is_empty() {
         println!();
@@ -173,17 +253,12 @@ pub fn delete_worktree_with_ui(manager: &GitWorktreeManager, ui: &dyn UserInterf
         println!("{msg}");
         println!(
             "{}",
-            "  (Cannot delete the current worktree)".bright_black()
+            "  (Cannot delete the current or main worktree)".bright_black()
         );
-        println!();
-        press_any_key_to_continue()?;
-        return Ok(());
+        return pause_or_fail(ui, ERROR_NO_DELETABLE_WORKTREES);
     }
 
-    println!();
-    let header = section_header("Delete Worktree");
-    println!("{header}");
-    println!();
+    output::print_header("Delete Worktree");
 
     let items: Vec<String> = deletable_worktrees
         .iter()
@@ -191,11 +266,12 @@ pub fn delete_worktree_with_ui(manager: &GitWorktreeManager, ui: &dyn UserInterf
         .collect();
 
     let selection = match ui.select_with_default(
-        "Select a worktree to delete (ESC to cancel)",
+        PROMPT_SELECT_WORKTREE_TO_DELETE,
         &items,
         DEFAULT_MENU_SELECTION,
     ) {
         Ok(selection) => selection,
+        Err(e) if !ui.is_interactive() => return Err(e),
         Err(_) => return Ok(()),
     };
 
@@ -220,8 +296,11 @@ pub fn delete_worktree_with_ui(manager: &GitWorktreeManager, ui: &dyn UserInterf
     println!();
 
     // Ask about branch deletion if it's unique to this worktree
+    let never_offer = Config::load()
+        .map(|config| config.worktree.never_offer_branch_delete)
+        .unwrap_or(false);
     let mut delete_branch = false;
-    if analysis.is_branch_unique {
+    if should_offer_branch_delete(analysis.is_branch_unique, never_offer) {
         let msg = "This branch is only used by this worktree.".yellow();
         println!("{msg}");
         delete_branch = ui
@@ -231,11 +310,25 @@ pub fn delete_worktree_with_ui(manager: &GitWorktreeManager, ui: &dyn UserInterf
     }
 
     let confirm = ui
-        .confirm_with_default("Are you sure you want to delete this worktree?", false)
+        .confirm_with_default(PROMPT_DELETE_THIS_WORKTREE, false)
         .unwrap_or(false);
 
     if !confirm {
-        return Ok(());
+        return not_confirmed(ui, &analysis.worktree.name);
+    }
+
+    // Discarding changes needs a second, explicit confirmation
+    let force = analysis.worktree.has_changes;
+    if force {
+        println!();
+        let msg = MSG_WORKTREE_HAS_CHANGES.yellow();
+        println!("{msg}");
+        let confirm_force = ui
+            .confirm_with_default(PROMPT_FORCE_DELETE, false)
+            .unwrap_or(false);
+        if !confirm_force {
+            return not_confirmed(ui, &analysis.worktree.name);
+        }
     }
 
     // Create deletion configuration
@@ -244,15 +337,28 @@ pub fn delete_worktree_with_ui(manager: &GitWorktreeManager, ui: &dyn UserInterf
         path: analysis.worktree.path.clone(),
         branch: analysis.worktree.branch.clone(),
         delete_branch,
+        force,
     };
 
+    if dry_run {
+        print_dry_run_deletion(&analysis.worktree.name, &config);
+        if ui.is_interactive() {
+            println!();
+            press_any_key_to_continue()?;
+        }
+        return Ok(());
+    }
+
     // Execute deletion using business logic
     match execute_deletion(&config, manager) {
         Ok(_) => {
-            println!();
-            press_any_key_to_continue()?;
+            if ui.is_interactive() {
+                println!();
+                press_any_key_to_continue()?;
+            }
             Ok(())
         }
+        Err(e) if !ui.is_interactive() => Err(e),
         Err(e) => {
             utils::print_error(&format!("{e}"));
             println!();
@@ -262,11 +368,92 @@ pub fn delete_worktree_with_ui(manager: &GitWorktreeManager, ui: &dyn UserInterf
     }
 }
 
+/// Deletes a worktree by name without the menu (`gw delete <name>`)
+///
+/// Runs the same flow as the interactive menu. Confirmations (deleting the
+/// worktree, its branch if no other worktree uses it, and discarding
+/// uncommitted changes) are only answered with `assume_yes` (`--yes`);
+/// without it nothing is deleted and an error is returned. The main
+/// worktree can only be deleted with `allow_main` (`--allow-main`).
+/// With `dry_run` (`--dry-run`) nothing is deleted and no hooks run; it
+/// prints what `--yes` would delete.
+///
+/// # Errors
+///
+/// Returns an error if there is no deletable worktree named `name`, the
+/// deletion was not confirmed, or it fails.
+pub fn delete_worktree_by_name(
+    name: &str,
+    assume_yes: bool,
+    allow_main: bool,
+    dry_run: bool,
+) -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    let ui = NonInteractiveUI::new()
+        .with_label(PROMPT_SELECT_WORKTREE_TO_DELETE, name)
+        .assume_yes(assume_yes || dry_run);
+    delete_worktree_from(&manager, &ui, allow_main, dry_run)
+}
+
+/// Prints what a confirmed deletion would remove
+fn print_dry_run_deletion(name: &str, config: &WorktreeDeleteConfig) {
+    println!();
+    let msg = MSG_DRY_RUN_NOTHING_DELETED.bright_yellow();
+    println!("{msg}");
+    println!("  {}", MSG_WOULD_DELETE_WORKTREE.replace("{}", name));
+    if config.delete_branch {
+        println!(
+            "  {}",
+            MSG_WOULD_DELETE_BRANCH.replace("{}", &config.branch)
+        );
+    }
+}
+
+/// Waits for a key press in the menu, or reports `message` as an error
+/// when running without a terminal
+fn pause_or_fail(ui: &dyn UserInterface, message: &str) -> Result<()> {
+    if !ui.is_interactive() {
+        return Err(anyhow!(message.to_string()));
+    }
+    println!();
+    press_any_key_to_continue()?;
+    Ok(())
+}
+
+/// Handles a declined confirmation: a plain cancel in the menu, an error
+/// without a terminal so scripts notice nothing was deleted
+fn not_confirmed(ui: &dyn UserInterface, name: &str) -> Result<()> {
+    if ui.is_interactive() {
+        return Ok(());
+    }
+    Err(anyhow!(ERROR_DELETE_NOT_CONFIRMED.replace("{}", name)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::git_interface::mock::MockGitOperations;
     use std::path::PathBuf;
 
+    #[test]
+    fn test_dry_run_deletes_nothing() {
+        let git = MockGitOperations::new()
+            .with_worktree("main", "/repo/main", Some("main"))
+            .with_worktree("feature", "/repo/feature", Some("feature"))
+            .with_branch("feature", false)
+            .with_current_worktree("main");
+        let ui = NonInteractiveUI::new()
+            .with_label(PROMPT_SELECT_WORKTREE_TO_DELETE, "feature")
+            .assume_yes(true);
+
+        delete_worktree_from(&git, &ui, false, true).unwrap();
+        assert!(git.removed_worktrees().is_empty());
+        assert!(git.deleted_branches().is_empty());
+
+        delete_worktree_from(&git, &ui, false, false).unwrap();
+        assert_eq!(git.removed_worktrees(), vec!["feature"]);
+    }
+
     #[test]
     fn test_validate_deletion_target_valid() {
         assert!(validate_deletion_target("feature-branch").is_ok());
@@ -281,6 +468,22 @@ mod tests {
         assert!(validate_deletion_target("master").is_err());
     }
 
+    #[test]
+    fn test_should_offer_branch_delete() {
+        assert!(should_offer_branch_delete(true, false));
+        assert!(!should_offer_branch_delete(false, false));
+    }
+
+    #[test]
+    fn test_branch_delete_prompt_skipped_when_never_offer_set() {
+        let config: Config =
+            toml::from_str("[worktree]\nnever_offer_branch_delete = true\n").unwrap();
+        assert!(!should_offer_branch_delete(
+            true,
+            config.worktree.never_offer_branch_delete
+        ));
+    }
+
     #[test]
     fn test_should_delete_orphaned_branch_true() {
         // Branch name matches worktree name and is unique
@@ -303,53 +506,49 @@ mod tests {
     fn test_get_deletable_worktrees_filter_main() {
         let worktrees = vec![
             WorktreeInfo {
-                name: "main".to_string(),
-                git_name: "main".to_string(),
-                path: PathBuf::from("/tmp/main"),
-                branch: "main".to_string(),
                 is_current: true,
-                has_changes: false,
-                last_commit: None,
-                ahead_behind: None,
-                is_locked: false,
-            },
-            WorktreeInfo {
-                name: "feature".to_string(),
-                git_name: "feature".to_string(),
-                path: PathBuf::from("/tmp/feature"),
-                branch: "feature".to_string(),
-                is_current: false,
-                has_changes: false,
-                last_commit: None,
-                ahead_behind: None,
-                is_locked: false,
+                ..WorktreeInfo::test("main")
             },
+            WorktreeInfo::test("feature"),
         ];
-        let deletable = get_deletable_worktrees(&worktrees);
+        let deletable = get_deletable_worktrees(&worktrees, false);
         assert_eq!(deletable.len(), 1);
         assert_eq!(deletable[0].name, "feature");
     }
 
+    #[test]
+    fn test_get_deletable_worktrees_main_not_current() {
+        let worktree = |name: &str, is_current: bool, is_main: bool| WorktreeInfo {
+            is_current,
+            is_main,
+            ..WorktreeInfo::test(name)
+        };
+        let worktrees = vec![
+            worktree("main", false, true),
+            worktree("feature", true, false),
+            worktree("bugfix", false, false),
+        ];
+
+        let names = |allow_main| -> Vec<String> {
+            get_deletable_worktrees(&worktrees, allow_main)
+                .iter()
+                .map(|w| w.name.clone())
+                .collect()
+        };
+        assert_eq!(names(false), vec!["bugfix"]);
+        assert_eq!(names(true), vec!["main", "bugfix"]);
+    }
+
     #[test]
     fn test_get_deletable_worktrees_empty() {
         let worktrees = vec![];
-        let deletable = get_deletable_worktrees(&worktrees);
+        let deletable = get_deletable_worktrees(&worktrees, false);
         assert!(deletable.is_empty());
     }
 
     #[test]
     fn test_deletion_analysis_creation() {
-        let worktree = WorktreeInfo {
-            name: "feature".to_string(),
-            git_name: "feature".to_string(),
-            path: PathBuf::from("/tmp/feature"),
-            branch: "feature".to_string(),
-            is_current: false,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
-            is_locked: false,
-        };
+        let worktree = WorktreeInfo::test("feature");
 
         let analysis = DeletionAnalysis {
             worktree: worktree.clone(),
@@ -369,6 +568,7 @@ mod tests {
             path: PathBuf::from("/tmp/test"),
             branch: "test-branch".to_string(),
             delete_branch: false,
+            force: false,
         };
 
         // Basic config creation test
@@ -0,0 +1,398 @@
+//! Repository health checks (`gw doctor`)
+//!
+//! Each check is a pure function over data gathered from the repository and
+//! returns a [`DoctorFinding`]. The command prints the findings without
+//! changing anything, and fails when any of them is a warning so it can be
+//! used in CI.
+
+use anyhow::{anyhow, Result};
+use colored::*;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::constants::{
+    DOCTOR_DETACHED_FIX, DOCTOR_DETACHED_FOUND, DOCTOR_DETACHED_OK, DOCTOR_LAYOUT_CONSISTENT,
+    DOCTOR_LAYOUT_INCONSISTENT, DOCTOR_LAYOUT_MOVE_HINT, DOCTOR_LAYOUT_NOTE, DOCTOR_LOCKED_FIX,
+    DOCTOR_LOCKED_FOUND, DOCTOR_LOCKED_OK, DOCTOR_MISSING_BRANCH_FIX, DOCTOR_MISSING_BRANCH_FOUND,
+    DOCTOR_MISSING_BRANCH_OK, DOCTOR_MISSING_DIR_FIX, DOCTOR_MISSING_DIR_FOUND,
+    DOCTOR_MISSING_DIR_OK, DOCTOR_STALE_LOCK_AGE, DOCTOR_STALE_LOCK_FIX, DOCTOR_STALE_LOCK_FOUND,
+    DOCTOR_STALE_LOCK_OK, ERROR_DOCTOR_PROBLEMS, GIT_CMD, GIT_MOVE, GIT_WORKTREE, HEADER_DOCTOR,
+    ICON_INFO, SECONDS_IN_MINUTE, STALE_LOCK_TIMEOUT_SECS,
+};
+use crate::git::{
+    find_layout_outliers, worktree_head_state, GitWorktreeManager, HeadState, WorktreeInfo,
+};
+use crate::utils::{self, output};
+
+/// How serious a finding is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FindingLevel {
+    /// Nothing to report
+    Ok,
+    /// Worth knowing about, but nothing is broken
+    Note,
+    /// Something is likely wrong
+    Warning,
+}
+
+/// Result of a single health check
+#[derive(Debug, Clone)]
+pub struct DoctorFinding {
+    pub level: FindingLevel,
+    pub title: String,
+    pub details: Vec<String>,
+}
+
+/// Pure business logic for checking that worktrees share one location
+///
+/// Worktrees whose parent directory differs from the one used by most other
+/// worktrees are reported with a suggested `git worktree move` command.
+pub fn check_worktree_layout(worktrees: &[WorktreeInfo]) -> DoctorFinding {
+    let report = match find_layout_outliers(worktrees) {
+        Some(report) => report,
+        None => {
+            return DoctorFinding {
+                level: FindingLevel::Ok,
+                title: DOCTOR_LAYOUT_CONSISTENT.to_string(),
+                details: Vec::new(),
+            }
+        }
+    };
+
+    let expected = report.expected_parent.display().to_string();
+    let mut details = vec![DOCTOR_LAYOUT_NOTE.replace("{}", &expected)];
+    for worktree in &report.outliers {
+        let name = &worktree.name;
+        let current = worktree.path.display();
+        let target = report.expected_parent.join(name);
+        let target = target.display();
+        details.push(format!("{name}: {current}"));
+        details.push(format!(
+            "  {DOCTOR_LAYOUT_MOVE_HINT} {GIT_CMD} {GIT_WORKTREE} {GIT_MOVE} {current} {target}"
+        ));
+    }
+
+    DoctorFinding {
+        level: FindingLevel::Note,
+        title: DOCTOR_LAYOUT_INCONSISTENT.to_string(),
+        details,
+    }
+}
+
+/// Builds the finding of a check that lists the affected items
+///
+/// Without items the finding is `Ok` and titled `ok_title`; otherwise it has
+/// `level`, a title counting the items, the items and the suggested fix.
+fn list_finding(
+    level: FindingLevel,
+    ok_title: &str,
+    found_title: &str,
+    items: Vec<String>,
+    fix: &str,
+) -> DoctorFinding {
+    if items.is_empty() {
+        return DoctorFinding {
+            level: FindingLevel::Ok,
+            title: ok_title.to_string(),
+            details: Vec::new(),
+        };
+    }
+
+    let title = found_title.replace("{}", &items.len().to_string());
+    let mut details = items;
+    details.push(fix.to_string());
+    DoctorFinding {
+        level,
+        title,
+        details,
+    }
+}
+
+/// Pure business logic for finding worktrees whose directory is gone
+pub fn check_missing_directories(worktrees: &[WorktreeInfo]) -> DoctorFinding {
+    let items = worktrees
+        .iter()
+        .filter(|w| w.is_prunable)
+        .map(|w| format!("{}: {}", w.name, w.path.display()))
+        .collect();
+    list_finding(
+        FindingLevel::Warning,
+        DOCTOR_MISSING_DIR_OK,
+        DOCTOR_MISSING_DIR_FOUND,
+        items,
+        DOCTOR_MISSING_DIR_FIX,
+    )
+}
+
+/// Pure business logic for listing locked worktrees
+///
+/// Locks are usually deliberate, so they are reported as a note.
+pub fn check_locked_worktrees(worktrees: &[WorktreeInfo]) -> DoctorFinding {
+    let items = worktrees
+        .iter()
+        .filter(|w| w.is_locked)
+        .map(|w| match &w.lock_reason {
+            Some(reason) => format!("{} ({reason})", w.name),
+            None => w.name.clone(),
+        })
+        .collect();
+    list_finding(
+        FindingLevel::Note,
+        DOCTOR_LOCKED_OK,
+        DOCTOR_LOCKED_FOUND,
+        items,
+        DOCTOR_LOCKED_FIX,
+    )
+}
+
+/// Pure business logic for finding worktrees with a detached HEAD
+///
+/// Takes the HEAD state of each worktree by name. A detached HEAD can be
+/// intended (bisecting, reviewing a tag), so it is reported as a note.
+pub fn check_detached_heads(heads: &[(String, HeadState)]) -> DoctorFinding {
+    let items = heads
+        .iter()
+        .filter(|(_, head)| *head == HeadState::Detached)
+        .map(|(name, _)| name.clone())
+        .collect();
+    list_finding(
+        FindingLevel::Note,
+        DOCTOR_DETACHED_OK,
+        DOCTOR_DETACHED_FOUND,
+        items,
+        DOCTOR_DETACHED_FIX,
+    )
+}
+
+/// Pure business logic for finding worktrees whose branch was deleted
+pub fn check_missing_branches(heads: &[(String, HeadState)]) -> DoctorFinding {
+    let items = heads
+        .iter()
+        .filter_map(|(name, head)| match head {
+            HeadState::MissingBranch(branch) => Some(format!("{name}: {branch}")),
+            _ => None,
+        })
+        .collect();
+    list_finding(
+        FindingLevel::Warning,
+        DOCTOR_MISSING_BRANCH_OK,
+        DOCTOR_MISSING_BRANCH_FOUND,
+        items,
+        DOCTOR_MISSING_BRANCH_FIX,
+    )
+}
+
+/// Pure business logic for finding lock files left behind by `gw`
+///
+/// Takes each lock file with its age. Only locks older than
+/// `STALE_LOCK_TIMEOUT_SECS` are reported; a younger one most likely
+/// belongs to a `gw` command that is still running.
+pub fn check_stale_lock_files(lock_files: &[(PathBuf, Duration)]) -> DoctorFinding {
+    let timeout = Duration::from_secs(STALE_LOCK_TIMEOUT_SECS);
+    let items = lock_files
+        .iter()
+        .filter(|(_, age)| *age > timeout)
+        .map(|(path, age)| {
+            let minutes = (age.as_secs() / SECONDS_IN_MINUTE).to_string();
+            DOCTOR_STALE_LOCK_AGE
+                .replacen("{}", &path.display().to_string(), 1)
+                .replacen("{}", &minutes, 1)
+        })
+        .collect();
+    list_finding(
+        FindingLevel::Warning,
+        DOCTOR_STALE_LOCK_OK,
+        DOCTOR_STALE_LOCK_FOUND,
+        items,
+        DOCTOR_STALE_LOCK_FIX,
+    )
+}
+
+/// Reads the HEAD state of the main worktree and every linked worktree
+///
+/// Worktrees whose directory is missing or whose HEAD cannot be read are
+/// left out; the missing directories are reported by their own check.
+fn worktree_heads(worktrees: &[WorktreeInfo]) -> Vec<(String, HeadState)> {
+    worktrees
+        .iter()
+        .filter(|w| !w.is_prunable)
+        .filter_map(|w| Some((w.name.clone(), worktree_head_state(&w.path).ok()?)))
+        .collect()
+}
+
+/// Runs every health check against the given repository
+pub fn run_doctor_checks(manager: &GitWorktreeManager) -> Result<Vec<DoctorFinding>> {
+    let worktrees = manager.list_worktrees()?;
+    let heads = worktree_heads(&worktrees);
+    Ok(vec![
+        check_missing_directories(&worktrees),
+        check_missing_branches(&heads),
+        check_detached_heads(&heads),
+        check_locked_worktrees(&worktrees),
+        check_stale_lock_files(&manager.worktree_lock_files()),
+        check_worktree_layout(&worktrees),
+    ])
+}
+
+/// Prints a finding with an icon matching its level
+fn print_finding(finding: &DoctorFinding) {
+    match finding.level {
+        FindingLevel::Ok => utils::print_success(&finding.title),
+        FindingLevel::Note => {
+            let icon = ICON_INFO.bright_blue();
+            let title = finding.title.bright_white();
+            println!("{icon} {title}");
+        }
+        FindingLevel::Warning => utils::print_warning(&finding.title),
+    }
+    for detail in &finding.details {
+        let detail = detail.bright_black();
+        println!("    {detail}");
+    }
+}
+
+/// Checks the repository for common problems
+///
+/// Reports missing worktree directories, worktrees on a deleted branch,
+/// detached HEADs, locked worktrees, stale lock files and worktrees that
+/// live outside the location used by the rest, each with a suggested fix.
+/// Nothing is modified.
+///
+/// # Errors
+///
+/// Returns an error if not in a Git repository, worktrees cannot be listed
+/// or any check finds a problem (a warning), so the process exits with a
+/// non-zero code.
+pub fn doctor() -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+
+    output::print_header(HEADER_DOCTOR);
+
+    let findings = run_doctor_checks(&manager)?;
+    for finding in &findings {
+        print_finding(finding);
+    }
+    output::blank_line();
+
+    let problems = findings
+        .iter()
+        .filter(|finding| finding.level == FindingLevel::Warning)
+        .count();
+    if problems > 0 {
+        return Err(anyhow!(
+            ERROR_DOCTOR_PROBLEMS.replace("{}", &problems.to_string())
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn worktree_at(name: &str, path: &str) -> WorktreeInfo {
+        WorktreeInfo {
+            path: PathBuf::from(path),
+            ..WorktreeInfo::test(name)
+        }
+    }
+
+    #[test]
+    fn test_check_worktree_layout_consistent() {
+        let worktrees = vec![
+            worktree_at("a", "/repo/worktrees/a"),
+            worktree_at("b", "/repo/worktrees/b"),
+        ];
+        let finding = check_worktree_layout(&worktrees);
+        assert_eq!(finding.level, FindingLevel::Ok);
+        assert!(finding.details.is_empty());
+    }
+
+    #[test]
+    fn test_check_worktree_layout_scattered() {
+        let worktrees = vec![
+            worktree_at("a", "/repo/worktrees/a"),
+            worktree_at("b", "/repo/worktrees/b"),
+            worktree_at("c", "/elsewhere/c"),
+        ];
+
+        let finding = check_worktree_layout(&worktrees);
+        assert_eq!(finding.level, FindingLevel::Note);
+        assert!(finding.details[0].contains("/repo/worktrees"));
+        assert!(finding
+            .details
+            .iter()
+            .any(|d| d.contains("git worktree move /elsewhere/c /repo/worktrees/c")));
+        assert!(!finding.details.iter().any(|d| d.starts_with("a:")));
+    }
+
+    #[test]
+    fn test_check_missing_directories() {
+        let mut gone = worktree_at("gone", "/repo/worktrees/gone");
+        gone.is_prunable = true;
+        let worktrees = vec![worktree_at("a", "/repo/worktrees/a"), gone];
+
+        let finding = check_missing_directories(&worktrees);
+        assert_eq!(finding.level, FindingLevel::Warning);
+        assert_eq!(finding.details[0], "gone: /repo/worktrees/gone");
+        assert!(finding.details[1].contains("gw prune"));
+
+        let finding = check_missing_directories(&worktrees[..1]);
+        assert_eq!(finding.level, FindingLevel::Ok);
+    }
+
+    #[test]
+    fn test_check_locked_worktrees() {
+        let mut locked = worktree_at("usb", "/repo/worktrees/usb");
+        locked.is_locked = true;
+        locked.lock_reason = Some("on a usb drive".to_string());
+        let finding = check_locked_worktrees(&[locked, worktree_at("a", "/repo/worktrees/a")]);
+        assert_eq!(finding.level, FindingLevel::Note);
+        assert_eq!(finding.details[0], "usb (on a usb drive)");
+        assert_eq!(finding.details.len(), 2);
+    }
+
+    #[test]
+    fn test_check_worktree_heads() {
+        let heads = vec![
+            ("a".to_string(), HeadState::Branch("a".to_string())),
+            ("b".to_string(), HeadState::Detached),
+            (
+                "c".to_string(),
+                HeadState::MissingBranch("feature/c".to_string()),
+            ),
+        ];
+
+        let detached = check_detached_heads(&heads);
+        assert_eq!(detached.level, FindingLevel::Note);
+        assert_eq!(detached.details[0], "b");
+
+        let missing = check_missing_branches(&heads);
+        assert_eq!(missing.level, FindingLevel::Warning);
+        assert_eq!(missing.details[0], "c: feature/c");
+
+        assert_eq!(check_missing_branches(&heads[..2]).level, FindingLevel::Ok);
+        assert_eq!(check_detached_heads(&heads[..1]).level, FindingLevel::Ok);
+    }
+
+    #[test]
+    fn test_check_stale_lock_files() {
+        let fresh = (
+            PathBuf::from("/repo/.git/fresh.lock"),
+            Duration::from_secs(10),
+        );
+        let stale = (
+            PathBuf::from("/repo/.git/stale.lock"),
+            Duration::from_secs(STALE_LOCK_TIMEOUT_SECS + 600),
+        );
+
+        let finding = check_stale_lock_files(&[fresh.clone(), stale]);
+        assert_eq!(finding.level, FindingLevel::Warning);
+        assert_eq!(finding.details.len(), 2);
+        assert!(finding.details[0].starts_with("/repo/.git/stale.lock"));
+        assert!(finding.details[0].contains("15 min"));
+
+        assert_eq!(check_stale_lock_files(&[fresh]).level, FindingLevel::Ok);
+    }
+}
@@ -0,0 +1,150 @@
+//! Worktree disk usage
+//!
+//! `gw du` and `gw list --size` show how much space each worktree takes on
+//! disk. Walking every file is slow, so sizes are only computed on demand,
+//! one thread per worktree.
+
+use anyhow::Result;
+use colored::*;
+use std::sync::mpsc;
+use std::thread;
+
+use crate::constants::{FILE_SIZE_UNKNOWN, MSG_DU_TOTAL, WARNING_NO_WORKTREES};
+use crate::git::{GitWorktreeManager, WorktreeInfo};
+use crate::utils::{dir_size, format_size};
+
+/// Computes the size of every worktree in parallel
+///
+/// The result is in the order of `worktrees`; worktrees missing on disk or
+/// that cannot be read get `None`. So does the main worktree, whose
+/// directory also holds the repository and often the other worktrees.
+pub fn worktree_sizes(worktrees: &[WorktreeInfo]) -> Vec<Option<u64>> {
+    let (size_tx, size_rx) = mpsc::channel();
+    for (index, worktree) in worktrees.iter().enumerate() {
+        if worktree.is_prunable || worktree.is_main {
+            continue;
+        }
+        let size_tx = size_tx.clone();
+        let path = worktree.path.clone();
+        thread::spawn(move || {
+            let _ = size_tx.send((index, dir_size(&path).ok()));
+        });
+    }
+    drop(size_tx);
+
+    let mut sizes = vec![None; worktrees.len()];
+    for (index, size) in size_rx {
+        sizes[index] = size;
+    }
+    sizes
+}
+
+/// Pure business logic for a size cell, `-` when the size is unknown
+pub fn format_size_cell(size: Option<u64>) -> String {
+    size.map(format_size)
+        .unwrap_or_else(|| FILE_SIZE_UNKNOWN.to_string())
+}
+
+/// Pure business logic for ordering worktrees by size, largest first
+///
+/// Worktrees of unknown size come last; ties are broken by name.
+pub fn rank_by_size<'a>(
+    worktrees: &'a [WorktreeInfo],
+    sizes: &[Option<u64>],
+) -> Vec<(&'a WorktreeInfo, Option<u64>)> {
+    let mut rows: Vec<(&WorktreeInfo, Option<u64>)> =
+        worktrees.iter().zip(sizes.iter().copied()).collect();
+    rows.sort_by(|(a, a_size), (b, b_size)| b_size.cmp(a_size).then_with(|| a.name.cmp(&b.name)));
+    rows
+}
+
+/// Prints the disk usage of every worktree, largest first (`gw du`)
+///
+/// # Errors
+///
+/// Returns an error if not in a Git repository or worktrees cannot be listed.
+pub fn disk_usage() -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    let worktrees: Vec<WorktreeInfo> = manager
+        .list_worktrees()?
+        .into_iter()
+        .filter(|w| !w.is_main)
+        .collect();
+    if worktrees.is_empty() {
+        let msg = WARNING_NO_WORKTREES.yellow();
+        println!("{msg}");
+        return Ok(());
+    }
+
+    let sizes = worktree_sizes(&worktrees);
+    let rows = rank_by_size(&worktrees, &sizes);
+    let cells: Vec<String> = rows
+        .iter()
+        .map(|(_, size)| format_size_cell(*size))
+        .collect();
+    let size_width = cells.iter().map(String::len).max().unwrap_or(0);
+    let name_width = rows.iter().map(|(w, _)| w.name.len()).max().unwrap_or(0);
+
+    for ((worktree, _), cell) in rows.iter().zip(&cells) {
+        println!(
+            "{:>size_width$}  {:<name_width$}  {}",
+            cell.bright_cyan(),
+            worktree.name,
+            worktree.path.display().to_string().dimmed()
+        );
+    }
+
+    let total: u64 = sizes.iter().flatten().sum();
+    println!();
+    println!("{}", MSG_DU_TOTAL.replace("{}", &format_size(total)).bold());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn worktree(name: &str, path: PathBuf, is_prunable: bool) -> WorktreeInfo {
+        WorktreeInfo {
+            path,
+            is_prunable,
+            ..WorktreeInfo::test(name)
+        }
+    }
+
+    #[test]
+    fn test_worktree_sizes_and_ranking() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let small = temp_dir.path().join("small");
+        let large = temp_dir.path().join("large");
+        fs::create_dir_all(&small)?;
+        fs::create_dir_all(large.join("assets"))?;
+        fs::write(small.join("a"), [0u8; 10])?;
+        fs::write(large.join("assets/b"), [0u8; 2048])?;
+
+        let worktrees = vec![
+            worktree("gone", temp_dir.path().join("gone"), true),
+            worktree("small", small, false),
+            worktree("large", large, false),
+        ];
+        let sizes = worktree_sizes(&worktrees);
+        assert_eq!(sizes, vec![None, Some(10), Some(2048)]);
+
+        let ranked: Vec<(&str, String)> = rank_by_size(&worktrees, &sizes)
+            .into_iter()
+            .map(|(w, size)| (w.name.as_str(), format_size_cell(size)))
+            .collect();
+        assert_eq!(
+            ranked,
+            vec![
+                ("large", "2.0 KB".to_string()),
+                ("small", "10 B".to_string()),
+                ("gone", "-".to_string()),
+            ]
+        );
+        Ok(())
+    }
+}
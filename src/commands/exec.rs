@@ -0,0 +1,91 @@
+//! Running a command inside a worktree
+//!
+//! `gw exec <name> -- <cmd>` runs a command with the worktree as its working
+//! directory, without switching the shell there. The command gets the same
+//! `GW_WORKTREE_NAME` and `GW_WORKTREE_PATH` variables as hooks, its output
+//! is streamed, and its exit code becomes the exit code of `gw`.
+
+use anyhow::{anyhow, Result};
+use std::process::{Command, ExitStatus, Stdio};
+
+use super::switch::find_switch_target;
+use crate::constants::{ERROR_EXEC_LAUNCH, ERROR_EXEC_NO_COMMAND, EXIT_CODE_SIGNAL_BASE};
+use crate::git::GitWorktreeManager;
+use crate::hooks::{context_env, HookContext};
+
+/// Pure business logic for the exit code reported for a finished command
+///
+/// A command killed by a signal reports `128 + signal`, like a shell does.
+pub fn command_exit_code(status: ExitStatus) -> i32 {
+    if let Some(code) = status.code() {
+        return code;
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        if let Some(signal) = status.signal() {
+            return EXIT_CODE_SIGNAL_BASE + signal;
+        }
+    }
+
+    1
+}
+
+/// Runs `command` inside the worktree named `name` (`gw exec <name> -- <cmd>`)
+///
+/// The program is started directly, not through a shell, so arguments are
+/// passed through unchanged. Returns the exit code of the command.
+///
+/// # Errors
+///
+/// Returns an error if no worktree has that name (listing the available
+/// ones) or the command cannot be started.
+pub fn exec_in_worktree(name: &str, command: &[String]) -> Result<i32> {
+    let manager = GitWorktreeManager::new()?;
+    let worktrees = manager.list_worktrees()?;
+    let worktree = find_switch_target(&worktrees, name)?;
+
+    let (program, args) = command
+        .split_first()
+        .ok_or_else(|| anyhow!(ERROR_EXEC_NO_COMMAND))?;
+    let context = HookContext {
+        worktree_name: worktree.name.clone(),
+        worktree_path: worktree.path.clone(),
+        previous_worktree_name: None,
+        previous_worktree_path: None,
+    };
+
+    let status = Command::new(program)
+        .args(args)
+        .current_dir(&worktree.path)
+        .envs(context_env(&context))
+        .stdin(Stdio::inherit())
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()
+        .map_err(|e| {
+            anyhow!(ERROR_EXEC_LAUNCH
+                .replacen("{}", program, 1)
+                .replacen("{}", &worktree.name, 1)
+                .replacen("{}", &e.to_string(), 1))
+        })?;
+
+    Ok(command_exit_code(status))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn test_command_exit_code() {
+        use std::os::unix::process::ExitStatusExt;
+
+        assert_eq!(command_exit_code(ExitStatus::from_raw(0)), 0);
+        assert_eq!(command_exit_code(ExitStatus::from_raw(3 << 8)), 3);
+        // Killed by SIGKILL
+        assert_eq!(command_exit_code(ExitStatus::from_raw(9)), 137);
+    }
+}
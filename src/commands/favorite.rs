@@ -0,0 +1,59 @@
+//! Favorite worktrees
+//!
+//! `gw favorite <name>` marks a worktree that is used all the time. The list
+//! and the switch picker show favorites right after the current worktree,
+//! with a ★ after the name; nothing else treats them differently. Favorites
+//! are kept in the per-repository state file.
+
+use anyhow::Result;
+
+use super::switch::find_switch_target;
+use crate::constants::{
+    MSG_FAVORITE_ADDED, MSG_FAVORITE_ALREADY, MSG_FAVORITE_NOT_FAVORITE, MSG_FAVORITE_REMOVED,
+};
+use crate::git::GitWorktreeManager;
+use crate::infrastructure::State;
+use crate::utils;
+
+/// Marks the worktree named `name` as a favorite (`gw favorite`)
+///
+/// # Errors
+///
+/// Returns an error if no worktree has that name (listing the available
+/// ones) or the state cannot be saved.
+pub fn favorite_worktree(name: &str) -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    let worktrees = manager.list_worktrees()?;
+    let worktree = find_switch_target(&worktrees, name)?;
+
+    let state_dir = manager.get_common_dir();
+    let mut state = State::load(state_dir);
+    if state.add_favorite(&worktree.name) {
+        state.save(state_dir)?;
+        utils::print_success(&MSG_FAVORITE_ADDED.replace("{}", &worktree.name));
+    } else {
+        utils::print_warning(&MSG_FAVORITE_ALREADY.replace("{}", &worktree.name));
+    }
+    Ok(())
+}
+
+/// Removes `name` from the favorites (`gw unfavorite`)
+///
+/// Works for worktrees that no longer exist, so stale favorites can be
+/// cleaned up.
+///
+/// # Errors
+///
+/// Returns an error if not in a Git repository or the state cannot be saved.
+pub fn unfavorite_worktree(name: &str) -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    let state_dir = manager.get_common_dir();
+    let mut state = State::load(state_dir);
+    if state.remove_favorite(name) {
+        state.save(state_dir)?;
+        utils::print_success(&MSG_FAVORITE_REMOVED.replace("{}", name));
+    } else {
+        utils::print_warning(&MSG_FAVORITE_NOT_FAVORITE.replace("{}", name));
+    }
+    Ok(())
+}
@@ -0,0 +1,368 @@
+//! Interactive editing of `[files] copy` (`gw files`)
+//!
+//! Shows the files copied into new worktrees and lets the user add entries,
+//! checked against the source directory, and remove entries. The
+//! configuration file is edited in place with `toml_edit`, so comments,
+//! formatting and the other sections are kept.
+
+use anyhow::{anyhow, Context, Result};
+use colored::*;
+use std::path::Path;
+use toml_edit::{Array, DocumentMut, Item, Table, Value};
+
+use crate::config::{Config, FilesConfig};
+use crate::constants::{
+    CONFIG_KEY_COPY, CONFIG_SECTION_FILES, ERROR_COPY_FILES_NOT_ARRAY, ERROR_COPY_FILES_UNSAFE,
+    ERROR_FILES_NOT_TABLE, FILES_ACTION_ADD, FILES_ACTION_DONE, FILES_ACTION_REMOVE,
+    FILE_COPY_PLAN_MISSING, HEADER_COPY_FILES, ICON_ERROR, ICON_SUCCESS, MSG_COPY_FILES_ADDED,
+    MSG_COPY_FILES_ALREADY, MSG_COPY_FILES_EMPTY, MSG_COPY_FILES_REMOVED, PROMPT_COPY_FILES_ACTION,
+    PROMPT_COPY_FILES_ADD, PROMPT_COPY_FILES_ADD_MISSING, PROMPT_COPY_FILES_REMOVE,
+};
+use crate::file_copy::{determine_source_directory, is_safe_path, resolve_copy_plan};
+use crate::git::GitWorktreeManager;
+use crate::ui::{DialoguerUI, UserInterface};
+use crate::utils::{self, output};
+
+/// Key of the path in the table form of a copy entry
+const COPY_ENTRY_PATH_KEY: &str = "path";
+
+/// Path of a `[files] copy` item, whether a string or a table with `path`
+fn item_path(value: &Value) -> Option<&str> {
+    match value {
+        Value::String(path) => Some(path.value()),
+        Value::InlineTable(table) => table.get(COPY_ENTRY_PATH_KEY).and_then(Value::as_str),
+        _ => None,
+    }
+}
+
+/// Pure business logic for listing the `[files] copy` paths of a config file
+///
+/// # Errors
+///
+/// Returns an error if `content` is not valid TOML.
+pub fn copy_entries(content: &str) -> Result<Vec<String>> {
+    let document: DocumentMut = content.parse()?;
+    let Some(copy) = document
+        .get(CONFIG_SECTION_FILES)
+        .and_then(|files| files.get(CONFIG_KEY_COPY))
+    else {
+        return Ok(Vec::new());
+    };
+    let paths = match copy {
+        Item::Value(Value::Array(array)) => array.iter().filter_map(item_path).collect(),
+        Item::ArrayOfTables(tables) => tables
+            .iter()
+            .filter_map(|table| table.get(COPY_ENTRY_PATH_KEY).and_then(Item::as_str))
+            .collect(),
+        _ => Vec::new(),
+    };
+    Ok(paths.into_iter().map(str::to_string).collect())
+}
+
+/// Pure business logic for appending a path to `[files] copy`
+///
+/// Creates the section and the array when missing and follows the layout
+/// of a multi-line array. The rest of the document, comments included, is
+/// left as it was.
+///
+/// # Errors
+///
+/// Returns an error if `content` is not valid TOML or `[files]` / `copy`
+/// have an unexpected type.
+pub fn add_copy_entry(content: &str, path: &str) -> Result<String> {
+    let mut document: DocumentMut = content.parse()?;
+    let files = document
+        .entry(CONFIG_SECTION_FILES)
+        .or_insert_with(toml_edit::table)
+        .as_table_like_mut()
+        .ok_or_else(|| anyhow!(ERROR_FILES_NOT_TABLE))?;
+    let copy = files
+        .entry(CONFIG_KEY_COPY)
+        .or_insert(toml_edit::value(toml_edit::Array::new()));
+
+    match copy {
+        Item::Value(Value::Array(array)) => {
+            let prefix = array
+                .iter()
+                .last()
+                .and_then(|last| last.decor().prefix())
+                .cloned();
+            array.push(path);
+            if let (Some(prefix), Some(added)) = (prefix, array.iter_mut().last()) {
+                added.decor_mut().set_prefix(prefix);
+            }
+        }
+        Item::ArrayOfTables(tables) => {
+            let mut table = Table::new();
+            table.insert(COPY_ENTRY_PATH_KEY, toml_edit::value(path));
+            tables.push(table);
+        }
+        _ => return Err(anyhow!(ERROR_COPY_FILES_NOT_ARRAY)),
+    }
+    Ok(document.to_string())
+}
+
+/// Splits a decor at the end of its first line, e.g. `"  # note\n    "`
+fn split_first_line(decor: &str) -> (&str, &str) {
+    match decor.find('\n') {
+        Some(newline) => decor.split_at(newline + 1),
+        None => (decor, ""),
+    }
+}
+
+/// Removes an array item, keeping the comment that ends the line before it
+///
+/// In a multi-line array, a comment after `"a",` is stored at the start of
+/// the next item's prefix; it moves to the item (or closing bracket) after
+/// the removed one, replacing the removed item's own comment.
+fn remove_array_item(array: &mut Array, index: usize) {
+    let removed = array.remove(index);
+    let Some(prefix) = removed.decor().prefix().and_then(|p| p.as_str()) else {
+        return;
+    };
+    if !prefix.contains('\n') {
+        return;
+    }
+    let (kept, _) = split_first_line(prefix);
+    let kept = kept.to_string();
+    match array.get_mut(index) {
+        Some(next) => {
+            let prefix = next.decor().prefix().and_then(|p| p.as_str()).unwrap_or("");
+            let (_, rest) = split_first_line(prefix);
+            let prefix = format!("{kept}{rest}");
+            next.decor_mut().set_prefix(prefix);
+        }
+        None => {
+            let trailing = array.trailing().as_str().unwrap_or("");
+            let (_, rest) = split_first_line(trailing);
+            let trailing = format!("{kept}{rest}");
+            array.set_trailing(trailing);
+        }
+    }
+}
+
+/// Pure business logic for removing paths from `[files] copy`
+///
+/// Entries are matched by path, in string or table form. The rest of the
+/// document is left as it was.
+///
+/// # Errors
+///
+/// Returns an error if `content` is not valid TOML.
+pub fn remove_copy_entries(content: &str, paths: &[String]) -> Result<String> {
+    let mut document: DocumentMut = content.parse()?;
+    let is_removed = |path: Option<&str>| path.is_some_and(|path| paths.iter().any(|p| p == path));
+
+    if let Some(copy) = document
+        .get_mut(CONFIG_SECTION_FILES)
+        .and_then(|files| files.get_mut(CONFIG_KEY_COPY))
+    {
+        match copy {
+            Item::Value(Value::Array(array)) => {
+                let mut index = 0;
+                while index < array.len() {
+                    if is_removed(array.get(index).and_then(item_path)) {
+                        remove_array_item(array, index);
+                    } else {
+                        index += 1;
+                    }
+                }
+            }
+            Item::ArrayOfTables(tables) => tables
+                .retain(|table| !is_removed(table.get(COPY_ENTRY_PATH_KEY).and_then(Item::as_str))),
+            _ => {}
+        }
+    }
+    Ok(document.to_string())
+}
+
+/// Whether `path` names something in `source` (a glob needs one match)
+fn copy_source_exists(path: &str, source: &Path) -> bool {
+    let files = FilesConfig {
+        copy: vec![path.into()],
+        ..FilesConfig::default()
+    };
+    resolve_copy_plan(&files, source)
+        .iter()
+        .any(|(_, exists)| *exists)
+}
+
+/// Prints the copy list with the entries missing from `source` marked
+fn print_copy_entries(entries: &[String], source: Option<&Path>) {
+    if entries.is_empty() {
+        println!("{}", MSG_COPY_FILES_EMPTY.bright_black());
+    }
+    for entry in entries {
+        match source {
+            Some(source) if !copy_source_exists(entry, source) => {
+                let cross = ICON_ERROR.red();
+                let missing = format!("({FILE_COPY_PLAN_MISSING})").bright_black();
+                println!("  {cross} {entry} {missing}");
+            }
+            _ => {
+                let checkmark = ICON_SUCCESS.green();
+                println!("  {checkmark} {entry}");
+            }
+        }
+    }
+    println!();
+}
+
+/// Edits the list of files copied into new worktrees (`gw files`)
+///
+/// # Errors
+///
+/// Returns an error if not in a Git repository or the configuration file
+/// cannot be read, parsed or written.
+pub fn edit_copy_files() -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    let ui = DialoguerUI;
+    edit_copy_files_with_ui(&manager, &ui)
+}
+
+/// Internal implementation of edit_copy_files with dependency injection
+///
+/// # Arguments
+///
+/// * `manager` - Git worktree manager instance
+/// * `ui` - User interface implementation for testability
+pub fn edit_copy_files_with_ui(manager: &GitWorktreeManager, ui: &dyn UserInterface) -> Result<()> {
+    let config_path = super::find_config_file_path(manager)?;
+    let files = Config::load()?.files;
+    // Without a source directory (e.g. a bare repository), entries are not checked
+    let source = determine_source_directory(&files, manager).ok();
+
+    let actions = vec![
+        FILES_ACTION_ADD.to_string(),
+        FILES_ACTION_REMOVE.to_string(),
+        FILES_ACTION_DONE.to_string(),
+    ];
+    loop {
+        let content = if config_path.exists() {
+            std::fs::read_to_string(&config_path)
+                .with_context(|| format!("Failed to read {}", config_path.display()))?
+        } else {
+            String::new()
+        };
+        let entries = copy_entries(&content)?;
+
+        output::print_header(HEADER_COPY_FILES);
+        print_copy_entries(&entries, source.as_deref());
+
+        let updated = match ui.select(PROMPT_COPY_FILES_ACTION, &actions) {
+            Ok(0) => {
+                let Ok(path) = ui.input(PROMPT_COPY_FILES_ADD) else {
+                    return Ok(());
+                };
+                let path = path.trim();
+                if path.is_empty() {
+                    continue;
+                }
+                if !is_safe_path(path) {
+                    utils::print_error(&ERROR_COPY_FILES_UNSAFE.replace("{}", path));
+                    continue;
+                }
+                if entries.iter().any(|entry| entry == path) {
+                    utils::print_warning(&MSG_COPY_FILES_ALREADY.replace("{}", path));
+                    continue;
+                }
+                if let Some(source) = &source {
+                    if !copy_source_exists(path, source) {
+                        let prompt = PROMPT_COPY_FILES_ADD_MISSING
+                            .replacen("{}", path, 1)
+                            .replacen("{}", &source.display().to_string(), 1);
+                        if !ui.confirm_with_default(&prompt, false).unwrap_or(false) {
+                            continue;
+                        }
+                    }
+                }
+                let updated = add_copy_entry(&content, path)?;
+                utils::print_success(&MSG_COPY_FILES_ADDED.replace("{}", path));
+                updated
+            }
+            Ok(1) if !entries.is_empty() => {
+                let selected = ui
+                    .multiselect(PROMPT_COPY_FILES_REMOVE, &entries)
+                    .unwrap_or_default();
+                let removed: Vec<String> = selected
+                    .into_iter()
+                    .filter_map(|index| entries.get(index).cloned())
+                    .collect();
+                if removed.is_empty() {
+                    continue;
+                }
+                let updated = remove_copy_entries(&content, &removed)?;
+                let count = removed.len().to_string();
+                utils::print_success(&MSG_COPY_FILES_REMOVED.replace("{}", &count));
+                updated
+            }
+            Ok(1) => continue,
+            _ => return Ok(()),
+        };
+
+        std::fs::write(&config_path, updated)
+            .with_context(|| format!("Failed to write {}", config_path.display()))?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"# Project settings
+[hooks]
+post-create = ["npm ci"]  # install
+
+[files]
+copy = [
+    ".env",  # secrets
+    { path = "data.db", max_size_mb = 500 },
+]
+"#;
+
+    #[test]
+    fn test_copy_entries() -> Result<()> {
+        assert_eq!(copy_entries(CONFIG)?, vec![".env", "data.db"]);
+        assert!(copy_entries("")?.is_empty());
+        assert_eq!(
+            copy_entries("[[files.copy]]\npath = \"a\"\n")?,
+            vec!["a".to_string()]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_copy_entry_keeps_comments() -> Result<()> {
+        let updated = add_copy_entry(CONFIG, "config/")?;
+        assert!(updated
+            .starts_with("# Project settings\n[hooks]\npost-create = [\"npm ci\"]  # install\n"));
+        assert!(updated.contains("    \".env\",  # secrets\n"));
+        assert!(updated.contains("\n    \"config/\",\n]"));
+        assert_eq!(copy_entries(&updated)?, vec![".env", "data.db", "config/"]);
+
+        // The section is created when missing
+        let created = add_copy_entry("[hooks]\n", ".env")?;
+        assert_eq!(copy_entries(&created)?, vec![".env"]);
+        assert!(created.starts_with("[hooks]\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_copy_entries() -> Result<()> {
+        let updated = remove_copy_entries(CONFIG, &["data.db".to_string()])?;
+        assert_eq!(copy_entries(&updated)?, vec![".env"]);
+        assert!(updated.contains("copy = [\n    \".env\",  # secrets\n]"));
+        assert!(updated.contains("post-create = [\"npm ci\"]  # install"));
+
+        let updated = remove_copy_entries(CONFIG, &[".env".to_string()])?;
+        assert_eq!(copy_entries(&updated)?, vec!["data.db"]);
+        assert!(!updated.contains("# secrets"));
+
+        let updated = remove_copy_entries(
+            "[files]\ncopy = [\"a\", \"b\", \"c\"]\n",
+            &["b".to_string()],
+        )?;
+        assert_eq!(updated, "[files]\ncopy = [\"a\", \"c\"]\n");
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+//! Focus mode
+//!
+//! Focus mode narrows list, switch and search to a chosen set of worktrees,
+//! e.g. the ones belonging to one epic. Delete and rename keep seeing every
+//! worktree. The focus set is stored in the per-repository state file so it
+//! survives between sessions.
+
+use anyhow::Result;
+use colored::*;
+
+use crate::constants::{
+    DEFAULT_MENU_SELECTION, FOCUS_ACTION_CHANGE_INDEX, FOCUS_ACTION_EXIT_INDEX, FOCUS_STATUS_LINE,
+    HEADER_FOCUS_MODE, MSG_FOCUS_DISABLED, MSG_FOCUS_ENABLED, MSG_FOCUS_NOTHING_SELECTED,
+    OPTION_FOCUS_CHANGE, OPTION_FOCUS_EXIT, PROMPT_FOCUS_ACTION, PROMPT_FOCUS_SELECT,
+    WARNING_NO_WORKTREES,
+};
+use crate::git::{GitWorktreeManager, WorktreeInfo};
+use crate::infrastructure::{FocusState, State};
+use crate::ui::{DialoguerUI, UserInterface};
+use crate::utils::{self, output, press_any_key_to_continue};
+
+/// Pure business logic for the focus filter
+///
+/// Every worktree is shown when focus mode is off; otherwise only the
+/// worktrees named in the focus set are.
+pub fn is_in_focus(worktree: &WorktreeInfo, focus: &FocusState) -> bool {
+    !focus.enabled || focus.worktrees.contains(&worktree.name)
+}
+
+/// Pure business logic for applying the focus filter to a worktree list
+pub fn filter_focused(worktrees: Vec<WorktreeInfo>, focus: &FocusState) -> Vec<WorktreeInfo> {
+    worktrees
+        .into_iter()
+        .filter(|w| is_in_focus(w, focus))
+        .collect()
+}
+
+/// Lists worktrees, limited to the focus set when focus mode is on
+///
+/// Used by list, switch and search. Commands that must see every worktree
+/// (delete, rename) call [`GitWorktreeManager::list_worktrees`] directly.
+pub fn list_focused_worktrees(manager: &GitWorktreeManager) -> Result<Vec<WorktreeInfo>> {
+    let focus = State::load(manager.get_common_dir()).focus;
+    Ok(filter_focused(manager.list_worktrees()?, &focus))
+}
+
+/// Returns a short status line while focus mode is on
+pub fn focus_status_line() -> Option<String> {
+    let manager = GitWorktreeManager::new().ok()?;
+    let focus = State::load(manager.get_common_dir()).focus;
+    if !focus.enabled {
+        return None;
+    }
+    Some(FOCUS_STATUS_LINE.replace("{}", &focus.worktrees.join(", ")))
+}
+
+/// Enters, changes or exits focus mode
+///
+/// # Errors
+///
+/// Returns an error if not in a Git repository or worktrees cannot be listed.
+pub fn focus_mode() -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    let ui = DialoguerUI;
+    focus_mode_with_ui(&manager, &ui)
+}
+
+/// Internal implementation of focus_mode with dependency injection
+///
+/// # Arguments
+///
+/// * `manager` - Git worktree manager instance
+/// * `ui` - User interface implementation for testability
+pub fn focus_mode_with_ui(manager: &GitWorktreeManager, ui: &dyn UserInterface) -> Result<()> {
+    let state_dir = manager.get_common_dir();
+    let mut state = State::load(state_dir);
+
+    output::print_header(HEADER_FOCUS_MODE);
+
+    if state.focus.enabled {
+        let options = vec![
+            OPTION_FOCUS_EXIT.to_string(),
+            OPTION_FOCUS_CHANGE.to_string(),
+        ];
+        match ui.select_with_default(PROMPT_FOCUS_ACTION, &options, DEFAULT_MENU_SELECTION) {
+            Ok(FOCUS_ACTION_EXIT_INDEX) => {
+                state.focus.enabled = false;
+                state.save(state_dir)?;
+                utils::print_success(MSG_FOCUS_DISABLED);
+                println!();
+                press_any_key_to_continue()?;
+                return Ok(());
+            }
+            Ok(FOCUS_ACTION_CHANGE_INDEX) => {}
+            _ => return Ok(()),
+        }
+    }
+
+    let worktrees = manager.list_worktrees()?;
+    if worktrees.is_empty() {
+        let msg = WARNING_NO_WORKTREES.yellow();
+        println!("{msg}");
+        println!();
+        press_any_key_to_continue()?;
+        return Ok(());
+    }
+
+    let items: Vec<String> = worktrees
+        .iter()
+        .map(|w| format!("{} ({})", w.name, w.branch))
+        .collect();
+    let selections = match ui.multiselect(PROMPT_FOCUS_SELECT, &items) {
+        Ok(selections) => selections,
+        Err(_) => return Ok(()),
+    };
+
+    if selections.is_empty() {
+        utils::print_warning(MSG_FOCUS_NOTHING_SELECTED);
+        println!();
+        press_any_key_to_continue()?;
+        return Ok(());
+    }
+
+    state.focus = FocusState {
+        enabled: true,
+        worktrees: selections
+            .iter()
+            .map(|&i| worktrees[i].name.clone())
+            .collect(),
+    };
+    state.save(state_dir)?;
+
+    let count = state.focus.worktrees.len();
+    utils::print_success(&MSG_FOCUS_ENABLED.replace("{}", &count.to_string()));
+    println!();
+    press_any_key_to_continue()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn worktree(name: &str) -> WorktreeInfo {
+        WorktreeInfo {
+            path: PathBuf::from(format!("/repo/{name}")),
+            ..WorktreeInfo::test(name)
+        }
+    }
+
+    fn focus_on(names: &[&str]) -> FocusState {
+        FocusState {
+            enabled: true,
+            worktrees: names.iter().map(|n| n.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_is_in_focus_when_disabled() {
+        let focus = FocusState {
+            enabled: false,
+            worktrees: vec!["epic-a".to_string()],
+        };
+        assert!(is_in_focus(&worktree("other"), &focus));
+    }
+
+    #[test]
+    fn test_is_in_focus_when_enabled() {
+        let focus = focus_on(&["epic-a", "epic-b"]);
+        assert!(is_in_focus(&worktree("epic-a"), &focus));
+        assert!(!is_in_focus(&worktree("other"), &focus));
+    }
+
+    #[test]
+    fn test_filter_focused_ignores_stale_names() {
+        let worktrees = vec![worktree("epic-a"), worktree("other"), worktree("epic-b")];
+        let focus = focus_on(&["epic-b", "deleted-long-ago"]);
+
+        let names: Vec<String> = filter_focused(worktrees, &focus)
+            .into_iter()
+            .map(|w| w.name)
+            .collect();
+        assert_eq!(names, vec!["epic-b"]);
+    }
+}
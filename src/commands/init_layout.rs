@@ -0,0 +1,388 @@
+//! Adopting the subdirectory layout (`gw init-layout`)
+//!
+//! Converts a plain clone so that worktrees live in `<repo>/worktrees/`.
+//! The current checkout either stays where it is, or moves to
+//! `worktrees/<branch>` and the repository root is left with only `.git`
+//! (made bare) and `worktrees/`. Nothing happens before the plan has been
+//! shown and confirmed.
+
+use anyhow::{anyhow, Result};
+use colored::*;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+
+use super::batch_create::worktree_name_for_branch;
+use crate::constants::{
+    CONFIG_FILE_NAME, CONFIG_TEMPLATE, DEFAULT_MENU_SELECTION, ERROR_LAYOUT_BARE,
+    ERROR_LAYOUT_INCOMPATIBLE, ERROR_LAYOUT_NOT_CONFIRMED, GIT_DEFAULT_MAIN_WORKTREE,
+    HEADER_INIT_LAYOUT, LAYOUT_KEEP_INDEX, MSG_LAYOUT_CANCELLED, MSG_LAYOUT_CANNOT_MOVE,
+    MSG_LAYOUT_DONE, MSG_LAYOUT_INCOMPATIBLE, MSG_LAYOUT_INCOMPATIBLE_HINT,
+    MSG_LAYOUT_KEEP_CONFIG_HINT, MSG_LAYOUT_MOVED, MSG_LAYOUT_PLAN, OPTION_LAYOUT_KEEP,
+    OPTION_LAYOUT_MOVE, PROMPT_LAYOUT_CONFIRM, PROMPT_LAYOUT_MODE, REASON_LAYOUT_DETACHED,
+    REASON_LAYOUT_DIRTY, REASON_LAYOUT_TARGET_EXISTS, STEP_LAYOUT_CREATE_DIR, STEP_LAYOUT_EXCLUDE,
+    STEP_LAYOUT_KEEP_CONFIG, STEP_LAYOUT_MAKE_BARE, STEP_LAYOUT_MOVE, STEP_LAYOUT_WRITE_CONFIG,
+    WORKTREES_SUBDIR,
+};
+use crate::core::GitWorkersError;
+use crate::git::{has_changes_outside, worktree_head_branch, GitWorktreeManager, WorktreeInfo};
+use crate::ui::{DialoguerUI, NonInteractiveUI, UserInterface};
+use crate::utils::{self, output, write_switch_path};
+
+/// Line added to `.git/info/exclude` so the main checkout ignores `worktrees/`
+const WORKTREES_EXCLUDE_ENTRY: &str = "/worktrees/";
+
+/// What happens to the current checkout
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayoutMode {
+    /// Leave the checkout in the repository root
+    KeepCheckout,
+    /// Move the checkout to a new worktree for `branch` at `target`
+    MoveCheckout { branch: String, target: PathBuf },
+}
+
+/// Everything `gw init-layout` is about to do
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InitLayoutPlan {
+    /// The main worktree
+    pub root: PathBuf,
+    pub mode: LayoutMode,
+    /// Whether a configuration file already exists (it is kept as-is)
+    pub has_config: bool,
+}
+
+impl InitLayoutPlan {
+    /// The directory new worktrees are created in
+    pub fn worktrees_dir(&self) -> PathBuf {
+        self.root.join(WORKTREES_SUBDIR)
+    }
+
+    /// Where the starter configuration is written
+    pub fn config_path(&self) -> PathBuf {
+        match &self.mode {
+            LayoutMode::KeepCheckout => self.root.join(CONFIG_FILE_NAME),
+            LayoutMode::MoveCheckout { target, .. } => target.join(CONFIG_FILE_NAME),
+        }
+    }
+
+    /// Pure business logic for describing the plan, one line per step
+    pub fn steps(&self) -> Vec<String> {
+        let worktrees_dir = self.worktrees_dir().display().to_string();
+        let mut steps = vec![STEP_LAYOUT_CREATE_DIR.replace("{}", &worktrees_dir)];
+        match &self.mode {
+            LayoutMode::KeepCheckout => steps.push(STEP_LAYOUT_EXCLUDE.to_string()),
+            LayoutMode::MoveCheckout { target, .. } => {
+                steps.push(STEP_LAYOUT_MAKE_BARE.to_string());
+                steps.push(
+                    STEP_LAYOUT_MOVE
+                        .replacen("{}", &self.root.display().to_string(), 1)
+                        .replacen("{}", &target.display().to_string(), 1),
+                );
+            }
+        }
+        if self.has_config {
+            steps.push(STEP_LAYOUT_KEEP_CONFIG.replace("{}", CONFIG_FILE_NAME));
+        } else {
+            steps.push(
+                STEP_LAYOUT_WRITE_CONFIG.replace("{}", &self.config_path().display().to_string()),
+            );
+        }
+        steps
+    }
+}
+
+/// Pure business logic for finding worktrees that do not fit the layout
+///
+/// Every linked worktree must already live directly in `<root>/worktrees/`;
+/// converting around others would leave the layout half-applied.
+pub fn incompatible_worktrees(root: &Path, worktrees: &[WorktreeInfo]) -> Vec<WorktreeInfo> {
+    let worktrees_dir = root.join(WORKTREES_SUBDIR);
+    worktrees
+        .iter()
+        .filter(|w| !w.is_main && w.path.parent() != Some(worktrees_dir.as_path()))
+        .cloned()
+        .collect()
+}
+
+/// Pure business logic for the starter configuration of the layout
+///
+/// The usual template, with new worktrees defaulting to `worktrees/`.
+pub fn starter_config() -> String {
+    format!("{CONFIG_TEMPLATE}\n[worktree]\ndefault_location = \"subdirectory\"\n")
+}
+
+/// Pure business logic for adding `entry` to an exclude file
+///
+/// Returns the new content, or `None` if the entry is already present.
+pub fn with_exclude_entry(content: &str, entry: &str) -> Option<String> {
+    if content.lines().any(|line| line.trim() == entry) {
+        return None;
+    }
+    let mut updated = content.to_string();
+    if !updated.is_empty() && !updated.ends_with('\n') {
+        updated.push('\n');
+    }
+    updated.push_str(entry);
+    updated.push('\n');
+    Some(updated)
+}
+
+/// Returns why the checkout at `root` cannot be moved, if it cannot
+fn move_blocker(root: &Path, branch: Option<&str>, target: &Path) -> Option<&'static str> {
+    if branch.is_none() {
+        Some(REASON_LAYOUT_DETACHED)
+    } else if has_changes_outside(root, WORKTREES_SUBDIR) {
+        Some(REASON_LAYOUT_DIRTY)
+    } else if target.exists() {
+        Some(REASON_LAYOUT_TARGET_EXISTS)
+    } else {
+        None
+    }
+}
+
+/// Keeps `worktrees/` out of the main checkout's `git status`
+fn exclude_worktrees_dir(common_dir: &Path) -> Result<()> {
+    let exclude = common_dir.join("info").join("exclude");
+    let content = std::fs::read_to_string(&exclude).unwrap_or_default();
+    if let Some(updated) = with_exclude_entry(&content, WORKTREES_EXCLUDE_ENTRY) {
+        if let Some(parent) = exclude.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&exclude, updated)?;
+    }
+    Ok(())
+}
+
+/// Applies a confirmed plan
+fn apply_plan(manager: &GitWorktreeManager, plan: &InitLayoutPlan) -> Result<()> {
+    std::fs::create_dir_all(plan.worktrees_dir())?;
+    match &plan.mode {
+        LayoutMode::KeepCheckout => exclude_worktrees_dir(manager.get_common_dir())?,
+        LayoutMode::MoveCheckout { branch, target } => {
+            manager.move_main_worktree(target, branch)?;
+        }
+    }
+    if !plan.has_config {
+        std::fs::write(plan.config_path(), starter_config())?;
+    }
+    Ok(())
+}
+
+/// Converts the repository to the subdirectory worktree layout
+///
+/// Without a terminal the plan is printed for the default choice and the
+/// command fails, since nothing is changed without confirmation.
+///
+/// # Errors
+///
+/// Returns an error if not in a Git repository, the repository cannot be
+/// converted, the plan is not confirmed without a terminal, or a step fails.
+pub fn init_layout() -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    if io::stdin().is_terminal() {
+        init_layout_with_ui(&manager, &DialoguerUI)
+    } else {
+        let ui = NonInteractiveUI::new().with_default(PROMPT_LAYOUT_MODE);
+        init_layout_with_ui(&manager, &ui)
+    }
+}
+
+/// Internal implementation of init_layout with dependency injection
+///
+/// # Arguments
+///
+/// * `manager` - Git worktree manager instance
+/// * `ui` - User interface implementation for testability
+///
+/// Bare repositories and repositories with worktrees outside
+/// `<repo>/worktrees/` are refused with [`GitWorkersError::Validation`].
+/// Moving the checkout is only offered when it is clean and on a branch.
+/// Declining the plan cancels in the menu and is an error without a
+/// terminal, so scripts notice nothing was changed.
+pub fn init_layout_with_ui(manager: &GitWorktreeManager, ui: &dyn UserInterface) -> Result<()> {
+    output::print_header(HEADER_INIT_LAYOUT);
+
+    let root = match manager.main_worktree_path() {
+        Some(root) if !manager.repo().is_bare() => root,
+        _ => return Err(GitWorkersError::Validation(ERROR_LAYOUT_BARE.to_string()).into()),
+    };
+    let root = root.canonicalize().unwrap_or(root);
+
+    let mut worktrees = manager.list_worktrees()?;
+    for worktree in &mut worktrees {
+        if let Ok(path) = worktree.path.canonicalize() {
+            worktree.path = path;
+        }
+    }
+    let incompatible = incompatible_worktrees(&root, &worktrees);
+    if !incompatible.is_empty() {
+        let worktrees_dir = root.join(WORKTREES_SUBDIR).display().to_string();
+        utils::print_warning(&MSG_LAYOUT_INCOMPATIBLE.replace("{}", &worktrees_dir));
+        for worktree in &incompatible {
+            let name = &worktree.name;
+            let path = worktree.path.display();
+            println!("  • {name}: {path}");
+        }
+        let hint = MSG_LAYOUT_INCOMPATIBLE_HINT.bright_black();
+        println!("{hint}");
+        println!();
+        return Err(GitWorkersError::Validation(
+            ERROR_LAYOUT_INCOMPATIBLE
+                .replacen("{}", &incompatible.len().to_string(), 1)
+                .replacen("{}", &worktrees_dir, 1),
+        )
+        .into());
+    }
+
+    let branch = worktree_head_branch(&root).ok().flatten();
+    let move_name = branch
+        .as_deref()
+        .and_then(|branch| worktree_name_for_branch(branch).ok())
+        .unwrap_or_else(|| GIT_DEFAULT_MAIN_WORKTREE.to_string());
+    let target = root.join(WORKTREES_SUBDIR).join(&move_name);
+
+    let mode = match move_blocker(&root, branch.as_deref(), &target) {
+        Some(reason) => {
+            let note = MSG_LAYOUT_CANNOT_MOVE.replace("{}", reason).bright_black();
+            println!("{note}");
+            println!();
+            LayoutMode::KeepCheckout
+        }
+        None => {
+            let options = vec![
+                OPTION_LAYOUT_KEEP.to_string(),
+                OPTION_LAYOUT_MOVE.replace("{}", &move_name),
+            ];
+            match ui.select_with_default(PROMPT_LAYOUT_MODE, &options, DEFAULT_MENU_SELECTION) {
+                Ok(LAYOUT_KEEP_INDEX) => LayoutMode::KeepCheckout,
+                Ok(_) => LayoutMode::MoveCheckout {
+                    branch: branch.clone().unwrap_or_default(),
+                    target: target.clone(),
+                },
+                Err(e) if !ui.is_interactive() => return Err(e),
+                Err(_) => return Ok(()),
+            }
+        }
+    };
+
+    let plan = InitLayoutPlan {
+        has_config: root.join(CONFIG_FILE_NAME).exists(),
+        root,
+        mode,
+    };
+
+    println!();
+    let label = MSG_LAYOUT_PLAN.bright_white();
+    println!("{label}");
+    for step in plan.steps() {
+        println!("  • {step}");
+    }
+    println!();
+
+    if !ui
+        .confirm_with_default(PROMPT_LAYOUT_CONFIRM, false)
+        .unwrap_or(false)
+    {
+        if !ui.is_interactive() {
+            return Err(anyhow!(ERROR_LAYOUT_NOT_CONFIRMED));
+        }
+        let msg = MSG_LAYOUT_CANCELLED.yellow();
+        println!("{msg}");
+        println!();
+        return Ok(());
+    }
+
+    apply_plan(manager, &plan)?;
+
+    let worktrees_dir = plan.worktrees_dir().display().to_string();
+    utils::print_success(&MSG_LAYOUT_DONE.replace("{}", &worktrees_dir));
+    if plan.has_config {
+        let hint = MSG_LAYOUT_KEEP_CONFIG_HINT.bright_black();
+        println!("{hint}");
+    }
+    if let LayoutMode::MoveCheckout { target, .. } = &plan.mode {
+        utils::print_success(&MSG_LAYOUT_MOVED.replace("{}", &target.display().to_string()));
+        write_switch_path(target);
+    }
+    println!();
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn worktree_at(name: &str, path: &str) -> WorktreeInfo {
+        WorktreeInfo {
+            path: PathBuf::from(path),
+            ..WorktreeInfo::test(name)
+        }
+    }
+
+    #[test]
+    fn test_incompatible_worktrees() {
+        let root = Path::new("/src/app");
+        let worktrees = vec![
+            worktree_at("feature", "/src/app/worktrees/feature"),
+            worktree_at("sibling", "/src/sibling"),
+            worktree_at("nested", "/src/app/worktrees/team/nested"),
+        ];
+        let names: Vec<String> = incompatible_worktrees(root, &worktrees)
+            .into_iter()
+            .map(|w| w.name)
+            .collect();
+        assert_eq!(names, vec!["sibling", "nested"]);
+    }
+
+    #[test]
+    fn test_with_exclude_entry() {
+        assert_eq!(
+            with_exclude_entry("", "/worktrees/"),
+            Some("/worktrees/\n".to_string())
+        );
+        assert_eq!(
+            with_exclude_entry("# comment\n*.log", "/worktrees/"),
+            Some("# comment\n*.log\n/worktrees/\n".to_string())
+        );
+        assert_eq!(with_exclude_entry("/worktrees/\n", "/worktrees/"), None);
+    }
+
+    #[test]
+    fn test_init_layout_plan_steps() {
+        let keep = InitLayoutPlan {
+            root: PathBuf::from("/src/app"),
+            mode: LayoutMode::KeepCheckout,
+            has_config: false,
+        };
+        assert_eq!(keep.steps().len(), 3);
+        assert_eq!(
+            keep.config_path(),
+            PathBuf::from("/src/app/.git-workers.toml")
+        );
+
+        let moved = InitLayoutPlan {
+            mode: LayoutMode::MoveCheckout {
+                branch: "main".to_string(),
+                target: PathBuf::from("/src/app/worktrees/main"),
+            },
+            has_config: true,
+            ..keep
+        };
+        let steps = moved.steps();
+        assert_eq!(steps.len(), 4);
+        assert!(steps[2].contains("/src/app/worktrees/main"));
+        assert_eq!(
+            moved.config_path(),
+            PathBuf::from("/src/app/worktrees/main/.git-workers.toml")
+        );
+    }
+
+    #[test]
+    fn test_starter_config_parses() {
+        let config: crate::config::Config = toml::from_str(&starter_config()).unwrap();
+        assert_eq!(
+            config.worktree.default_location.as_deref(),
+            Some("subdirectory")
+        );
+    }
+}
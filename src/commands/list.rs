@@ -1,15 +1,35 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
 use colored::*;
-
+use console::{Key, Term};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use super::du::{format_size_cell, worktree_sizes};
+use super::focus::filter_focused;
+use super::shared::{filter_worktrees, parse_commit_time};
+use crate::config::Config;
 use crate::constants::{
-    section_header, CURRENT_MARKER, ICON_CURRENT_WORKTREE, ICON_OTHER_WORKTREE, MODIFIED_STATUS_NO,
+    CURRENT_MARKER, ELLIPSIS, EMOJI_LOCKED, ERROR_UNKNOWN_LIST_SORT, FAVORITE_MARKER, ICON_AHEAD,
+    ICON_BEHIND, ICON_CURRENT_WORKTREE, ICON_MISSING_WORKTREE, ICON_OTHER_WORKTREE,
+    LIST_COMMIT_INDENT, LIST_COMMIT_SUMMARY_WIDTH, LIST_HIDDEN_FOOTER, LIST_HIDDEN_SHOWN_FOOTER,
+    LIST_PATH_BASE_LABEL, LIST_PATH_RELATIVE_PREFIX, LIST_PORCELAIN_CLEAN, LIST_PORCELAIN_CURRENT,
+    LIST_PORCELAIN_DIRTY, LIST_PORCELAIN_NORMAL, LIST_PORCELAIN_SEPARATOR, LIST_PORCELAIN_UNKNOWN,
+    LIST_SORT_BRANCH, LIST_SORT_COMMIT_TIME, LIST_SORT_MODIFIED, LIST_SORT_NAME,
+    LIST_TOGGLE_HIDDEN_KEY, MISSING_MARKER, MODIFIED_STATUS_NO, MODIFIED_STATUS_UNKNOWN,
     MODIFIED_STATUS_YES, TABLE_HEADER_BRANCH, TABLE_HEADER_MODIFIED, TABLE_HEADER_NAME,
-    TABLE_HEADER_PATH, TABLE_SEPARATOR, WARNING_NO_WORKTREES,
+    TABLE_HEADER_PATH, TABLE_HEADER_SIZE, TABLE_HEADER_SYNC, TABLE_SEPARATOR, WARNING_NO_WORKTREES,
+    WARNING_NO_WORKTREES_MATCH,
+};
+use crate::git::{
+    find_common_parent, find_layout_outliers, CommitInfo, GitWorktreeManager, WorktreeInfo,
 };
-use crate::git::{GitWorktreeManager, WorktreeInfo};
+use crate::git_interface::GitReadOperations;
+use crate::infrastructure::State;
 use crate::repository_info::get_repository_info;
 use crate::ui::{DialoguerUI, UserInterface};
-use crate::utils::press_any_key_to_continue;
+use crate::utils::{format_relative_time_since, output, press_any_key_to_continue};
 
 /// Format worktree display string
 #[allow(dead_code)]
@@ -72,8 +92,13 @@ pub fn should_show_worktree(worktree: &WorktreeInfo, show_all: bool, filter: Opt
 /// - Formatted table of worktrees with:
 ///   - Name (highlighted if current)
 ///   - Branch name (colored by type)
-///   - Path (absolute path to worktree)
+///   - Path, as `…/name` relative to the shared worktree directory
+///     (absolute for custom paths elsewhere, and with `--verbose`)
 ///   - Modified status indicator
+/// - Worktrees whose directory is missing on disk are marked `(missing)`;
+///   the cleanup command offers to prune them
+/// - With `[display] show_commits`, the last commit under each worktree
+///   (see [`list_worktrees_verbose`])
 ///
 /// # Returns
 ///
@@ -83,21 +108,196 @@ pub fn should_show_worktree(worktree: &WorktreeInfo, show_all: bool, filter: Opt
 ///
 /// Returns an error if Git repository operations fail.
 pub fn list_worktrees() -> Result<()> {
+    list_worktrees_sorted(false, false, ListSort::default(), "")
+}
+
+/// Lists all worktrees with the last commit under each row (`gw list --verbose`)
+///
+/// # Errors
+///
+/// Returns an error if Git repository operations fail.
+pub fn list_worktrees_verbose() -> Result<()> {
+    list_worktrees_sorted(true, false, ListSort::default(), "")
+}
+
+/// Lists all worktrees in the given order (`gw list --sort`), with their
+/// size on disk when `show_size` is set (`gw list --size`), limited to
+/// those matching `filter` (`gw list --filter`, see [`filter_worktrees`])
+///
+/// # Errors
+///
+/// Returns an error if Git repository operations fail.
+pub fn list_worktrees_sorted(
+    verbose: bool,
+    show_size: bool,
+    sort: ListSort,
+    filter: &str,
+) -> Result<()> {
     let manager = GitWorktreeManager::new()?;
     let ui = DialoguerUI;
-    list_worktrees_with_ui(&manager, &ui)
+    list_worktrees_with_ui(&manager, &ui, verbose, show_size, sort, filter)
+}
+
+/// What the worktree list is sorted by
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ListSortKey {
+    /// Worktree name
+    #[default]
+    Name,
+    /// Branch name
+    Branch,
+    /// Uncommitted changes, clean worktrees first
+    Modified,
+    /// Time of the last commit, oldest first
+    CommitTime,
+}
+
+impl FromStr for ListSortKey {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            LIST_SORT_NAME => Ok(Self::Name),
+            LIST_SORT_BRANCH => Ok(Self::Branch),
+            LIST_SORT_MODIFIED => Ok(Self::Modified),
+            LIST_SORT_COMMIT_TIME => Ok(Self::CommitTime),
+            _ => Err(anyhow!(ERROR_UNKNOWN_LIST_SORT.replace("{}", s))),
+        }
+    }
+}
+
+/// How the worktree list is ordered
+///
+/// The default matches the list without `--sort`: the current worktree
+/// first, then by name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListSort {
+    pub key: ListSortKey,
+    pub ascending: bool,
+    /// Keep the current worktree at the top regardless of the key
+    pub pin_current: bool,
+}
+
+impl Default for ListSort {
+    fn default() -> Self {
+        Self {
+            key: ListSortKey::Name,
+            ascending: true,
+            pin_current: true,
+        }
+    }
+}
+
+impl ListSort {
+    /// Sorts `worktrees` by the key, then pins the current worktree if asked to
+    pub fn apply(&self, worktrees: Vec<WorktreeInfo>) -> Vec<WorktreeInfo> {
+        let mut worktrees = sort_worktrees(worktrees, self.key, self.ascending);
+        if self.pin_current {
+            worktrees.sort_by_key(|w| !w.is_current);
+        }
+        worktrees
+    }
+}
+
+/// Pure business logic for sorting worktrees by `key`
+///
+/// Name and branch compare lexically, modified puts clean worktrees before
+/// ones with changes, and commit-time orders by the last commit, with
+/// worktrees without one counting as oldest. Ties are broken by name.
+pub fn sort_worktrees(
+    mut worktrees: Vec<WorktreeInfo>,
+    key: ListSortKey,
+    ascending: bool,
+) -> Vec<WorktreeInfo> {
+    let commit_time = |w: &WorktreeInfo| {
+        w.last_commit
+            .as_ref()
+            .and_then(|commit| parse_commit_time(&commit.time))
+    };
+    worktrees.sort_by(|a, b| {
+        let ordering = match key {
+            ListSortKey::Name => a.name.cmp(&b.name),
+            ListSortKey::Branch => a.branch.cmp(&b.branch),
+            ListSortKey::Modified => a.has_changes.cmp(&b.has_changes),
+            ListSortKey::CommitTime => commit_time(a).cmp(&commit_time(b)),
+        };
+        let ordering = if ascending {
+            ordering
+        } else {
+            ordering.reverse()
+        };
+        ordering.then_with(|| a.name.cmp(&b.name))
+    });
+    worktrees
+}
+
+/// Pure business logic for moving favorite worktrees up
+///
+/// The current worktree stays first and the `favorites` follow it; the
+/// order within each group is kept.
+pub fn pin_favorites(mut worktrees: Vec<WorktreeInfo>, favorites: &[String]) -> Vec<WorktreeInfo> {
+    worktrees.sort_by_key(|w| (!w.is_current, !favorites.contains(&w.name)));
+    worktrees
+}
+
+/// Lists the worktrees shown by the list command
+///
+/// Limited to the focus set when focus mode is on, and ordered by `sort`.
+/// Favorites follow the current worktree when it is pinned.
+pub fn load_list_worktrees(
+    manager: &dyn GitReadOperations,
+    show_ahead_behind: bool,
+    sort: ListSort,
+) -> Result<Vec<WorktreeInfo>> {
+    let state = State::load(manager.get_common_dir());
+    let worktrees = sort.apply(filter_focused(
+        manager.list_worktrees_with_ahead_behind(show_ahead_behind)?,
+        &state.focus,
+    ));
+    Ok(if sort.pin_current {
+        pin_favorites(worktrees, &state.favorites)
+    } else {
+        worktrees
+    })
 }
 
 /// Internal implementation of list_worktrees with dependency injection
 ///
 /// # Arguments
 ///
-/// * `manager` - Git worktree manager instance
+/// * `manager` - Git operations ([`GitWorktreeManager`] outside of tests)
 /// * `ui` - User interface implementation for testability
-pub fn list_worktrees_with_ui(manager: &GitWorktreeManager, _ui: &dyn UserInterface) -> Result<()> {
-    let worktrees = manager.list_worktrees()?;
+/// * `verbose` - Show the last commit under each worktree even when
+///   `[display] show_commits` is off
+/// * `show_size` - Add a column with each worktree's size on disk; this
+///   walks every file, so it is off by default
+/// * `sort` - Order of the rows
+/// * `filter` - Only show worktrees whose name or branch matches; empty
+///   shows all
+pub fn list_worktrees_with_ui(
+    manager: &dyn GitReadOperations,
+    _ui: &dyn UserInterface,
+    verbose: bool,
+    show_size: bool,
+    sort: ListSort,
+    filter: &str,
+) -> Result<()> {
+    let display = Config::load()
+        .map(|config| config.display)
+        .unwrap_or_default();
+    let show_ahead_behind = display.show_ahead_behind;
+    let show_commits = verbose || display.show_commits;
+    let sorted_worktrees = filter_worktrees(
+        load_list_worktrees(manager, show_ahead_behind, sort)?,
+        filter,
+    );
 
-    if worktrees.is_empty() {
+    if sorted_worktrees.is_empty() && !filter.is_empty() {
+        let msg = WARNING_NO_WORKTREES_MATCH.replace("{}", filter).yellow();
+        println!("{msg}");
+        return Ok(());
+    }
+    if sorted_worktrees.is_empty() {
         println!();
         let msg = WARNING_NO_WORKTREES.yellow();
         println!("{msg}");
@@ -106,32 +306,83 @@ pub fn list_worktrees_with_ui(manager: &GitWorktreeManager, _ui: &dyn UserInterf
         return Ok(());
     }
 
-    // Sort worktrees: current first, then alphabetically
-    let mut sorted_worktrees = worktrees;
-    sorted_worktrees.sort_by(|a, b| {
-        if a.is_current && !b.is_current {
-            std::cmp::Ordering::Less
-        } else if !a.is_current && b.is_current {
-            std::cmp::Ordering::Greater
+    let favorites = State::load(manager.get_common_dir()).favorites;
+    let term = Term::stdout();
+    let mut show_hidden = false;
+    loop {
+        let (shown, hidden_count) = hide_worktrees(
+            sorted_worktrees.clone(),
+            &display.hidden_worktrees,
+            show_hidden,
+        );
+        print_list_table(
+            &shown,
+            verbose,
+            show_size,
+            show_ahead_behind,
+            show_commits,
+            &favorites,
+        );
+        println!();
+        if hidden_count == 0 {
+            press_any_key_to_continue()?;
+            return Ok(());
+        }
+
+        // Hidden worktrees can be toggled before leaving the list
+        let footer = if show_hidden {
+            LIST_HIDDEN_SHOWN_FOOTER
         } else {
-            a.name.cmp(&b.name)
+            LIST_HIDDEN_FOOTER
+        };
+        println!();
+        println!(
+            "{}",
+            footer.replace("{}", &hidden_count.to_string()).dimmed()
+        );
+        match term.read_key()? {
+            Key::Char(LIST_TOGGLE_HIDDEN_KEY) => {
+                show_hidden = !show_hidden;
+                let _ = term.clear_screen();
+            }
+            _ => return Ok(()),
         }
-    });
+    }
+}
+
+/// Prints the list table: header, repository, column titles and one row per worktree
+fn print_list_table(
+    sorted_worktrees: &[WorktreeInfo],
+    verbose: bool,
+    show_size: bool,
+    show_ahead_behind: bool,
+    show_commits: bool,
+    favorites: &[String],
+) {
+    let now = Utc::now();
 
     // Print header
-    println!();
-    let header = section_header("Worktrees");
-    println!("{header}");
-    println!();
+    output::print_header("Worktrees");
 
     // Display repository info
     let repo_info = get_repository_info();
     println!("Repository: {}", repo_info.bright_cyan());
 
+    // Paths are shown relative to the shared worktree directory unless verbose
+    let path_base = if verbose {
+        None
+    } else {
+        list_path_base(sorted_worktrees)
+    };
+    if let Some(base) = &path_base {
+        let base = base.display().to_string();
+        println!("{LIST_PATH_BASE_LABEL}{}", base.bright_cyan());
+    }
+
     // Calculate column widths
     let max_name_len = sorted_worktrees
         .iter()
-        .map(|w| w.name.len())
+        .map(|w| format_list_name(w, favorites).width())
         .max()
         .unwrap_or(0)
         .max(10);
@@ -143,9 +394,55 @@ pub fn list_worktrees_with_ui(manager: &GitWorktreeManager, _ui: &dyn UserInterf
         .max(10)
         + 10; // Extra space for [current] marker
 
+    // The sync column is only shown when ahead/behind counts were requested
+    let sync_width = if show_ahead_behind {
+        sorted_worktrees
+            .iter()
+            .map(|w| format_ahead_behind(w.ahead_behind).chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(TABLE_HEADER_SYNC.len())
+    } else {
+        0
+    };
+    let sync_header = if show_ahead_behind {
+        format!("{:<sync_width$} ", TABLE_HEADER_SYNC.bold())
+    } else {
+        String::new()
+    };
+    let sync_separator = if show_ahead_behind {
+        format!("{TABLE_SEPARATOR:-<sync_width$} ")
+    } else {
+        String::new()
+    };
+
+    // The size column is only shown on request (`--size`)
+    let size_cells: Vec<String> = if show_size {
+        worktree_sizes(sorted_worktrees)
+            .into_iter()
+            .map(format_size_cell)
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let size_width = size_cells
+        .iter()
+        .map(String::len)
+        .max()
+        .unwrap_or(0)
+        .max(TABLE_HEADER_SIZE.len());
+    let (size_header, size_separator) = if show_size {
+        (
+            format!("{:>size_width$} ", TABLE_HEADER_SIZE.bold()),
+            format!("{TABLE_SEPARATOR:-<size_width$} "),
+        )
+    } else {
+        (String::new(), String::new())
+    };
+
     println!();
     println!(
-        "  {:<name_width$} {:<branch_width$} {:<8} {}",
+        "  {:<name_width$} {:<branch_width$} {:<8} {sync_header}{size_header}{}",
         TABLE_HEADER_NAME.bold(),
         TABLE_HEADER_BRANCH.bold(),
         TABLE_HEADER_MODIFIED.bold(),
@@ -154,12 +451,14 @@ pub fn list_worktrees_with_ui(manager: &GitWorktreeManager, _ui: &dyn UserInterf
         branch_width = max_branch_len
     );
     println!(
-        "  {TABLE_SEPARATOR:-<max_name_len$} {TABLE_SEPARATOR:-<max_branch_len$} {TABLE_SEPARATOR:-<8} {TABLE_SEPARATOR:-<40}"
+        "  {TABLE_SEPARATOR:-<max_name_len$} {TABLE_SEPARATOR:-<max_branch_len$} {TABLE_SEPARATOR:-<8} {sync_separator}{size_separator}{TABLE_SEPARATOR:-<40}"
     );
 
     // Display worktrees in table format
-    for worktree in &sorted_worktrees {
-        let icon = if worktree.is_current {
+    for (index, worktree) in sorted_worktrees.iter().enumerate() {
+        let icon = if worktree.is_prunable {
+            ICON_MISSING_WORKTREE.bright_red().bold()
+        } else if worktree.is_current {
             ICON_CURRENT_WORKTREE.bright_green().bold()
         } else {
             ICON_OTHER_WORKTREE.bright_blue()
@@ -169,31 +468,241 @@ pub fn list_worktrees_with_ui(manager: &GitWorktreeManager, _ui: &dyn UserInterf
         } else {
             worktree.branch.yellow()
         };
-        let modified = if worktree.has_changes {
+        let modified = if worktree.status_unknown {
+            MODIFIED_STATUS_UNKNOWN.bright_red()
+        } else if worktree.has_changes {
             MODIFIED_STATUS_YES.bright_yellow()
         } else {
             MODIFIED_STATUS_NO.bright_black()
         };
 
+        let name = format_list_name(worktree, favorites);
+        let sync = if show_ahead_behind {
+            let counts = format_ahead_behind(worktree.ahead_behind);
+            format!("{:<sync_width$} ", counts.bright_cyan())
+        } else {
+            String::new()
+        };
+        let size = match size_cells.get(index) {
+            Some(cell) => format!("{:>size_width$} ", cell.bright_cyan()),
+            None => String::new(),
+        };
+
         println!(
-            "{} {:<name_width$} {:<branch_width$} {:<8} {}",
+            "{} {:<name_width$} {:<branch_width$} {:<8} {sync}{size}{}",
             icon,
-            if worktree.is_current {
-                worktree.name.bright_green().bold()
+            if worktree.is_prunable {
+                name.bright_red()
+            } else if worktree.is_current {
+                name.bright_green().bold()
             } else {
-                worktree.name.normal()
+                name.normal()
             },
             branch_display,
             modified,
-            worktree.path.display().to_string().dimmed(),
+            format_list_path(&worktree.path, path_base.as_deref()).dimmed(),
             name_width = max_name_len,
             branch_width = max_branch_len
         );
+
+        if show_commits {
+            if let Some(commit) = &worktree.last_commit {
+                let line = format_commit_line(commit, now);
+                println!("{LIST_COMMIT_INDENT}{}", line.dimmed());
+            }
+            if let Some(reason) = &worktree.lock_reason {
+                println!("{LIST_COMMIT_INDENT}{EMOJI_LOCKED} {}", reason.dimmed());
+            }
+        }
     }
+}
 
-    println!();
-    press_any_key_to_continue()?;
+/// Pure business logic for `[display] hidden_worktrees` name matching
+///
+/// Patterns are globs on the worktree name (e.g. `tooling-*`); one that is
+/// not a valid glob matches the name exactly.
+pub fn is_hidden_worktree(name: &str, patterns: &[String]) -> bool {
+    patterns
+        .iter()
+        .any(|pattern| match glob::Pattern::new(pattern) {
+            Ok(glob) => glob.matches(name),
+            Err(_) => pattern == name,
+        })
+}
 
+/// Pure business logic for leaving hidden worktrees out of the list
+///
+/// Returns the worktrees to show and how many of them match `patterns`.
+/// The current worktree is never hidden. With `show_hidden` every worktree
+/// is returned, and the count is of those that would otherwise be hidden.
+pub fn hide_worktrees(
+    worktrees: Vec<WorktreeInfo>,
+    patterns: &[String],
+    show_hidden: bool,
+) -> (Vec<WorktreeInfo>, usize) {
+    let is_hidden = |w: &WorktreeInfo| !w.is_current && is_hidden_worktree(&w.name, patterns);
+    let hidden_count = worktrees.iter().filter(|w| is_hidden(w)).count();
+    if show_hidden {
+        return (worktrees, hidden_count);
+    }
+    let shown = worktrees.into_iter().filter(|w| !is_hidden(w)).collect();
+    (shown, hidden_count)
+}
+
+/// Pure business logic for the name shown in the list table
+///
+/// Worktrees whose directory is missing on disk get a `(missing)` suffix,
+/// and `favorites` a ★.
+pub fn format_list_name(worktree: &WorktreeInfo, favorites: &[String]) -> String {
+    let mut name = worktree.name.clone();
+    if worktree.is_prunable {
+        name = format!("{name} {MISSING_MARKER}");
+    }
+    if favorites.contains(&worktree.name) {
+        name = format!("{name} {FAVORITE_MARKER}");
+    }
+    name
+}
+
+/// Directory the list table shows worktree paths relative to
+///
+/// This is the parent shared by all worktrees or, when some live elsewhere
+/// (custom paths), the parent used by most of them.
+fn list_path_base(worktrees: &[WorktreeInfo]) -> Option<PathBuf> {
+    find_common_parent(worktrees)
+        .or_else(|| find_layout_outliers(worktrees).map(|layout| layout.expected_parent))
+}
+
+/// Pure business logic for the path shown in the list table
+///
+/// Paths under `base` are shown relative to it with a `…/` prefix, e.g.
+/// `…/feature`; anything else, or no base, keeps the absolute path.
+pub fn format_list_path(path: &Path, base: Option<&Path>) -> String {
+    match base.and_then(|base| path.strip_prefix(base).ok()) {
+        Some(relative) if !relative.as_os_str().is_empty() => {
+            format!("{LIST_PATH_RELATIVE_PREFIX}{}", relative.display())
+        }
+        _ => path.display().to_string(),
+    }
+}
+
+/// Pure business logic for formatting ahead/behind counts as `↑N ↓M`
+///
+/// Worktrees without an upstream get an empty cell.
+pub fn format_ahead_behind(ahead_behind: Option<(usize, usize)>) -> String {
+    match ahead_behind {
+        Some((ahead, behind)) => format!("{ICON_AHEAD}{ahead} {ICON_BEHIND}{behind}"),
+        None => String::new(),
+    }
+}
+
+/// Pure business logic for truncating text to a terminal display width
+///
+/// Widths are measured with `unicode_width`, so wide characters such as CJK
+/// or emoji take two columns. Truncated text ends with [`ELLIPSIS`] and
+/// never exceeds `max_width` columns.
+pub fn truncate_to_width(text: &str, max_width: usize) -> String {
+    if text.width() <= max_width {
+        return text.to_string();
+    }
+
+    let budget = max_width.saturating_sub(ELLIPSIS.width());
+    let mut width = 0;
+    let mut truncated = String::new();
+    for c in text.chars() {
+        let char_width = c.width().unwrap_or(0);
+        if width + char_width > budget {
+            break;
+        }
+        width += char_width;
+        truncated.push(c);
+    }
+    truncated.push_str(ELLIPSIS);
+    truncated
+}
+
+/// Pure business logic for the commit line shown under a worktree
+///
+/// Formats as `<id> <summary> (<author>, <age>)`, with the summary cut to
+/// [`LIST_COMMIT_SUMMARY_WIDTH`] columns and the age relative to `now`.
+/// Commit times that cannot be parsed are shown as stored; `gw list --json`
+/// keeps the absolute time.
+pub fn format_commit_line(commit: &CommitInfo, now: DateTime<Utc>) -> String {
+    let id = &commit.id;
+    let summary = truncate_to_width(&commit.message, LIST_COMMIT_SUMMARY_WIDTH);
+    let author = &commit.author;
+    let age = parse_commit_time(&commit.time)
+        .map(|time| format_relative_time_since(time, now))
+        .unwrap_or_else(|| commit.time.clone());
+    format!("{id} {summary} ({author}, {age})")
+}
+
+/// Pure business logic for rendering worktrees as a JSON array
+///
+/// Worktrees are ordered by `sort`, like the table.
+pub fn worktrees_to_json(worktrees: Vec<WorktreeInfo>, sort: ListSort) -> Result<String> {
+    Ok(serde_json::to_string_pretty(&sort.apply(worktrees))?)
+}
+
+/// Prints all worktrees as JSON for scripts and other tools (`gw list --json`)
+///
+/// Focus mode is ignored so tools always see every worktree; `filter`
+/// still applies.
+///
+/// # Errors
+///
+/// Returns an error if Git repository operations fail.
+pub fn list_worktrees_json(sort: ListSort, filter: &str) -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    let json = worktrees_to_json(filter_worktrees(manager.list_worktrees()?, filter), sort)?;
+    println!("{json}");
+    Ok(())
+}
+
+/// Pure business logic for rendering worktrees in the porcelain format
+///
+/// One line per worktree with tab-separated fields:
+/// `name`, `branch`, `path`, `current|normal`, `clean|dirty|unknown` (unknown
+/// when the status timed out, see `[performance]`). There are no
+/// colors or icons, and the format is kept stable across versions so scripts
+/// can rely on it. Worktrees are ordered by `sort`, like the table.
+pub fn worktrees_to_porcelain(worktrees: Vec<WorktreeInfo>, sort: ListSort) -> String {
+    sort.apply(worktrees)
+        .iter()
+        .map(|w| {
+            let path = w.path.display().to_string();
+            let current = if w.is_current {
+                LIST_PORCELAIN_CURRENT
+            } else {
+                LIST_PORCELAIN_NORMAL
+            };
+            let status = if w.status_unknown {
+                LIST_PORCELAIN_UNKNOWN
+            } else if w.has_changes {
+                LIST_PORCELAIN_DIRTY
+            } else {
+                LIST_PORCELAIN_CLEAN
+            };
+            [w.name.as_str(), w.branch.as_str(), &path, current, status]
+                .join(LIST_PORCELAIN_SEPARATOR)
+        })
+        .map(|line| format!("{line}\n"))
+        .collect()
+}
+
+/// Prints all worktrees in the porcelain format (`gw list --porcelain`)
+///
+/// Like `--json`, focus mode is ignored so scripts always see every worktree.
+///
+/// # Errors
+///
+/// Returns an error if Git repository operations fail.
+pub fn list_worktrees_porcelain(sort: ListSort, filter: &str) -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    print!(
+        "{}",
+        worktrees_to_porcelain(filter_worktrees(manager.list_worktrees()?, filter), sort)
+    );
     Ok(())
 }
 
@@ -204,17 +713,7 @@ mod tests {
 
     #[test]
     fn test_format_worktree_display_basic() {
-        let worktree = WorktreeInfo {
-            name: "feature".to_string(),
-            git_name: "feature".to_string(),
-            path: PathBuf::from("/tmp/feature"),
-            branch: "feature".to_string(),
-            is_current: false,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
-            is_locked: false,
-        };
+        let worktree = WorktreeInfo::test("feature");
 
         let display = format_worktree_display(&worktree, false);
         assert_eq!(display, "feature");
@@ -223,15 +722,8 @@ mod tests {
     #[test]
     fn test_format_worktree_display_current() {
         let worktree = WorktreeInfo {
-            name: "main".to_string(),
-            git_name: "main".to_string(),
-            path: PathBuf::from("/tmp/main"),
-            branch: "main".to_string(),
             is_current: true,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
-            is_locked: false,
+            ..WorktreeInfo::test("main")
         };
 
         let display = format_worktree_display(&worktree, false);
@@ -241,15 +733,9 @@ mod tests {
     #[test]
     fn test_format_worktree_display_locked_changes() {
         let worktree = WorktreeInfo {
-            name: "locked".to_string(),
-            git_name: "locked".to_string(),
-            path: PathBuf::from("/tmp/locked"),
-            branch: "locked".to_string(),
-            is_current: false,
             has_changes: true,
-            last_commit: None,
-            ahead_behind: None,
             is_locked: true,
+            ..WorktreeInfo::test("locked")
         };
 
         let display = format_worktree_display(&worktree, false);
@@ -258,17 +744,7 @@ mod tests {
 
     #[test]
     fn test_format_worktree_display_verbose() {
-        let worktree = WorktreeInfo {
-            name: "feature".to_string(),
-            git_name: "feature".to_string(),
-            path: PathBuf::from("/tmp/feature"),
-            branch: "feature".to_string(),
-            is_current: false,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
-            is_locked: false,
-        };
+        let worktree = WorktreeInfo::test("feature");
 
         let display = format_worktree_display(&worktree, true);
         assert!(display.contains("- /tmp/feature"));
@@ -277,15 +753,9 @@ mod tests {
     #[test]
     fn test_should_show_worktree_with_filter_match() {
         let worktree = WorktreeInfo {
-            name: "feature-auth".to_string(),
-            git_name: "feature-auth".to_string(),
             path: PathBuf::from("/tmp/feature"),
             branch: "feature".to_string(),
-            is_current: false,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
-            is_locked: false,
+            ..WorktreeInfo::test("feature-auth")
         };
 
         assert!(should_show_worktree(&worktree, false, Some("auth")));
@@ -294,15 +764,9 @@ mod tests {
     #[test]
     fn test_should_show_worktree_with_filter_no_match() {
         let worktree = WorktreeInfo {
-            name: "feature-ui".to_string(),
-            git_name: "feature-ui".to_string(),
             path: PathBuf::from("/tmp/feature"),
             branch: "feature".to_string(),
-            is_current: false,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
-            is_locked: false,
+            ..WorktreeInfo::test("feature-ui")
         };
 
         assert!(!should_show_worktree(&worktree, false, Some("auth")));
@@ -310,45 +774,18 @@ mod tests {
 
     #[test]
     fn test_should_show_worktree_show_all() {
-        let worktree = WorktreeInfo {
-            name: "clean".to_string(),
-            git_name: "clean".to_string(),
-            path: PathBuf::from("/tmp/clean"),
-            branch: "clean".to_string(),
-            is_current: false,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
-            is_locked: false,
-        };
+        let worktree = WorktreeInfo::test("clean");
 
         assert!(should_show_worktree(&worktree, true, None));
     }
 
     #[test]
     fn test_should_show_worktree_only_changes() {
-        let clean_worktree = WorktreeInfo {
-            name: "clean".to_string(),
-            git_name: "clean".to_string(),
-            path: PathBuf::from("/tmp/clean"),
-            branch: "clean".to_string(),
-            is_current: false,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
-            is_locked: false,
-        };
+        let clean_worktree = WorktreeInfo::test("clean");
 
         let dirty_worktree = WorktreeInfo {
-            name: "dirty".to_string(),
-            git_name: "dirty".to_string(),
-            path: PathBuf::from("/tmp/dirty"),
-            branch: "dirty".to_string(),
-            is_current: false,
             has_changes: true,
-            last_commit: None,
-            ahead_behind: None,
-            is_locked: false,
+            ..WorktreeInfo::test("dirty")
         };
 
         assert!(!should_show_worktree(&clean_worktree, false, None));
@@ -361,20 +798,15 @@ mod tests {
         let test_commit_id = "abc123def";
         let test_path = "/tmp/feature";
         let worktree = WorktreeInfo {
-            name: "feature".to_string(),
-            git_name: "feature".to_string(),
             path: PathBuf::from(test_path),
-            branch: "feature".to_string(),
-            is_current: false,
-            has_changes: false,
             last_commit: Some(crate::infrastructure::git::CommitInfo {
                 id: test_commit_id.to_string(),
+                full_id: test_commit_id.to_string(),
                 message: "Add feature".to_string(),
                 author: "test@example.com".to_string(),
                 time: "2023-01-01".to_string(),
             }),
-            ahead_behind: None,
-            is_locked: false,
+            ..WorktreeInfo::test("feature")
         };
 
         let display = format_worktree_display(&worktree, true);
@@ -387,15 +819,8 @@ mod tests {
         let ahead_count = 2;
         let behind_count = 3;
         let worktree = WorktreeInfo {
-            name: "feature".to_string(),
-            git_name: "feature".to_string(),
-            path: PathBuf::from("/tmp/feature"),
-            branch: "feature".to_string(),
-            is_current: false,
-            has_changes: false,
-            last_commit: None,
             ahead_behind: Some((ahead_count, behind_count)),
-            is_locked: false,
+            ..WorktreeInfo::test("feature")
         };
 
         let display = format_worktree_display(&worktree, true);
@@ -406,15 +831,12 @@ mod tests {
     fn test_format_worktree_display_all_flags() {
         let worktree_name = "complex";
         let worktree = WorktreeInfo {
-            name: worktree_name.to_string(),
-            git_name: worktree_name.to_string(),
             path: PathBuf::from("/tmp/complex"),
             branch: "complex".to_string(),
             is_current: true,
             has_changes: true,
-            last_commit: None,
-            ahead_behind: None,
             is_locked: true,
+            ..WorktreeInfo::test(worktree_name)
         };
 
         let display = format_worktree_display(&worktree, false);
@@ -426,17 +848,7 @@ mod tests {
 
     #[test]
     fn test_should_show_worktree_empty_filter() {
-        let worktree = WorktreeInfo {
-            name: "any".to_string(),
-            git_name: "any".to_string(),
-            path: PathBuf::from("/tmp/any"),
-            branch: "any".to_string(),
-            is_current: false,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
-            is_locked: false,
-        };
+        let worktree = WorktreeInfo::test("any");
 
         // Empty string filter should match anything
         assert!(should_show_worktree(&worktree, false, Some("")));
@@ -447,15 +859,9 @@ mod tests {
         let test_filters = vec!["auth", "feature", "login"];
         let no_match_filter = "ui";
         let worktree = WorktreeInfo {
-            name: "feature-auth-login".to_string(),
-            git_name: "feature-auth-login".to_string(),
             path: PathBuf::from("/tmp/feature"),
             branch: "feature".to_string(),
-            is_current: false,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
-            is_locked: false,
+            ..WorktreeInfo::test("feature-auth-login")
         };
 
         // Partial matches should work
@@ -473,39 +879,12 @@ mod tests {
     #[test]
     fn test_table_display_current_worktree_first() {
         // Create test worktrees with one being current
-        let worktree1 = WorktreeInfo {
-            name: "zebra".to_string(),
-            git_name: "zebra".to_string(),
-            path: PathBuf::from("/tmp/zebra"),
-            branch: "zebra".to_string(),
-            is_current: false,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
-            is_locked: false,
-        };
+        let worktree1 = WorktreeInfo::test("zebra");
         let worktree2 = WorktreeInfo {
-            name: "alpha".to_string(),
-            git_name: "alpha".to_string(),
-            path: PathBuf::from("/tmp/alpha"),
-            branch: "alpha".to_string(),
             is_current: true,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
-            is_locked: false,
-        };
-        let worktree3 = WorktreeInfo {
-            name: "beta".to_string(),
-            git_name: "beta".to_string(),
-            path: PathBuf::from("/tmp/beta"),
-            branch: "beta".to_string(),
-            is_current: false,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
-            is_locked: false,
+            ..WorktreeInfo::test("alpha")
         };
+        let worktree3 = WorktreeInfo::test("beta");
 
         let mut worktrees = vec![worktree1, worktree2, worktree3];
 
@@ -532,26 +911,13 @@ mod tests {
     fn test_table_display_column_width_calculation() {
         let worktrees = vec![
             WorktreeInfo {
-                name: "short".to_string(),
-                git_name: "short".to_string(),
-                path: PathBuf::from("/tmp/short"),
                 branch: "main".to_string(),
-                is_current: false,
-                has_changes: false,
-                last_commit: None,
-                ahead_behind: None,
-                is_locked: false,
+                ..WorktreeInfo::test("short")
             },
             WorktreeInfo {
-                name: "very-long-worktree-name".to_string(),
-                git_name: "very-long-worktree-name".to_string(),
-                path: PathBuf::from("/tmp/very-long-worktree-name"),
                 branch: "feature-with-very-long-branch-name".to_string(),
                 is_current: true,
-                has_changes: false,
-                last_commit: None,
-                ahead_behind: None,
-                is_locked: false,
+                ..WorktreeInfo::test("very-long-worktree-name")
             },
         ];
 
@@ -579,26 +945,13 @@ mod tests {
     #[test]
     fn test_table_display_icon_selection() {
         let current_worktree = WorktreeInfo {
-            name: "current".to_string(),
-            git_name: "current".to_string(),
-            path: PathBuf::from("/tmp/current"),
             branch: "main".to_string(),
             is_current: true,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
-            is_locked: false,
+            ..WorktreeInfo::test("current")
         };
         let other_worktree = WorktreeInfo {
-            name: "other".to_string(),
-            git_name: "other".to_string(),
-            path: PathBuf::from("/tmp/other"),
             branch: "feature".to_string(),
-            is_current: false,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
-            is_locked: false,
+            ..WorktreeInfo::test("other")
         };
 
         // Test icon selection logic
@@ -620,26 +973,13 @@ mod tests {
     #[test]
     fn test_table_display_branch_formatting() {
         let current_worktree = WorktreeInfo {
-            name: "current".to_string(),
-            git_name: "current".to_string(),
-            path: PathBuf::from("/tmp/current"),
             branch: "main".to_string(),
             is_current: true,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
-            is_locked: false,
+            ..WorktreeInfo::test("current")
         };
         let other_worktree = WorktreeInfo {
-            name: "other".to_string(),
-            git_name: "other".to_string(),
-            path: PathBuf::from("/tmp/other"),
             branch: "feature".to_string(),
-            is_current: false,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
-            is_locked: false,
+            ..WorktreeInfo::test("other")
         };
 
         // Test branch display formatting
@@ -661,26 +1001,13 @@ mod tests {
     #[test]
     fn test_table_display_modified_status() {
         let clean_worktree = WorktreeInfo {
-            name: "clean".to_string(),
-            git_name: "clean".to_string(),
-            path: PathBuf::from("/tmp/clean"),
             branch: "main".to_string(),
-            is_current: false,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
-            is_locked: false,
+            ..WorktreeInfo::test("clean")
         };
         let dirty_worktree = WorktreeInfo {
-            name: "dirty".to_string(),
-            git_name: "dirty".to_string(),
-            path: PathBuf::from("/tmp/dirty"),
             branch: "feature".to_string(),
-            is_current: false,
             has_changes: true,
-            last_commit: None,
-            ahead_behind: None,
-            is_locked: false,
+            ..WorktreeInfo::test("dirty")
         };
 
         // Test modified status display
@@ -698,4 +1025,311 @@ mod tests {
         assert_eq!(clean_modified, "No");
         assert_eq!(dirty_modified, "Yes");
     }
+
+    #[test]
+    fn test_worktrees_to_json() -> Result<()> {
+        let worktrees = vec![
+            WorktreeInfo {
+                git_name: "feature-internal".to_string(),
+                has_changes: true,
+                last_commit: Some(crate::git::CommitInfo {
+                    id: "abcdef12".to_string(),
+                    full_id: "abcdef1200000000000000000000000000000000".to_string(),
+                    message: "Add feature".to_string(),
+                    author: "Dev".to_string(),
+                    time: "2024-01-01 10:00".to_string(),
+                }),
+                ..WorktreeInfo::test("feature")
+            },
+            WorktreeInfo {
+                is_current: true,
+                is_locked: true,
+                ..WorktreeInfo::test("main")
+            },
+        ];
+
+        let value: serde_json::Value =
+            serde_json::from_str(&worktrees_to_json(worktrees, ListSort::default())?)?;
+        let array = value.as_array().unwrap();
+        assert_eq!(array.len(), 2);
+
+        // Current worktree first
+        assert_eq!(array[0]["name"], "main");
+        assert_eq!(array[0]["is_current"], true);
+        assert_eq!(array[0]["is_locked"], true);
+        assert!(array[0]["last_commit"].is_null());
+
+        assert_eq!(array[1]["path"], "/tmp/feature");
+        assert_eq!(array[1]["has_changes"], true);
+        assert_eq!(array[1]["last_commit"]["message"], "Add feature");
+        assert!(array[1].get("git_name").is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_ahead_behind() {
+        assert_eq!(format_ahead_behind(Some((2, 1))), "↑2 ↓1");
+        assert_eq!(format_ahead_behind(Some((0, 0))), "↑0 ↓0");
+        assert_eq!(format_ahead_behind(None), "");
+    }
+
+    #[test]
+    fn test_worktrees_to_porcelain() {
+        let worktree = |name: &str, is_current: bool, has_changes: bool| WorktreeInfo {
+            branch: format!("{name}-branch"),
+            is_current,
+            has_changes,
+            ..WorktreeInfo::test(name)
+        };
+        let worktrees = vec![
+            worktree("zeta", false, true),
+            worktree("alpha", false, false),
+            worktree("main", true, false),
+        ];
+
+        assert_eq!(
+            worktrees_to_porcelain(worktrees, ListSort::default()),
+            "main\tmain-branch\t/tmp/main\tcurrent\tclean\n\
+             alpha\talpha-branch\t/tmp/alpha\tnormal\tclean\n\
+             zeta\tzeta-branch\t/tmp/zeta\tnormal\tdirty\n"
+        );
+        assert_eq!(worktrees_to_porcelain(Vec::new(), ListSort::default()), "");
+
+        let mut slow = worktree("slow", false, false);
+        slow.status_unknown = true;
+        assert_eq!(
+            worktrees_to_porcelain(vec![slow], ListSort::default()),
+            "slow\tslow-branch\t/tmp/slow\tnormal\tunknown\n"
+        );
+    }
+
+    #[test]
+    fn test_format_list_name_marks_missing() {
+        let mut worktree = WorktreeInfo {
+            branch: "unknown".to_string(),
+            ..WorktreeInfo::test("gone")
+        };
+        assert_eq!(format_list_name(&worktree, &[]), "gone");
+        let favorites = vec!["gone".to_string()];
+        assert_eq!(format_list_name(&worktree, &favorites), "gone ★");
+
+        worktree.is_prunable = true;
+        assert_eq!(format_list_name(&worktree, &[]), "gone (missing)");
+        assert_eq!(format_list_name(&worktree, &favorites), "gone (missing) ★");
+    }
+
+    #[test]
+    fn test_pin_favorites() {
+        let favorites = vec!["gamma".to_string(), "alpha".to_string()];
+        let pinned = pin_favorites(sortable_worktrees(), &favorites);
+        assert_eq!(names(pinned), vec!["main", "alpha", "gamma", "beta"]);
+    }
+
+    #[test]
+    fn test_format_list_path() {
+        let base = Path::new("/repo/worktrees");
+        assert_eq!(
+            format_list_path(Path::new("/repo/worktrees/feature"), Some(base)),
+            "…/feature"
+        );
+        assert_eq!(
+            format_list_path(Path::new("/repo/worktrees/team/fix"), Some(base)),
+            "…/team/fix"
+        );
+        // Custom paths outside the base, and the base itself, stay absolute
+        assert_eq!(
+            format_list_path(Path::new("/elsewhere/hotfix"), Some(base)),
+            "/elsewhere/hotfix"
+        );
+        assert_eq!(
+            format_list_path(Path::new("/repo/worktrees"), Some(base)),
+            "/repo/worktrees"
+        );
+        assert_eq!(
+            format_list_path(Path::new("/repo/worktrees/feature"), None),
+            "/repo/worktrees/feature"
+        );
+        // Only whole components count
+        assert_eq!(
+            format_list_path(Path::new("/repo/worktrees-old/x"), Some(base)),
+            "/repo/worktrees-old/x"
+        );
+    }
+
+    #[test]
+    fn test_truncate_to_width() {
+        assert_eq!(truncate_to_width("short", 10), "short");
+        assert_eq!(truncate_to_width("exactly10!", 10), "exactly10!");
+        assert_eq!(truncate_to_width("a long summary line", 10), "a long ...");
+
+        // Wide characters take two columns each
+        let truncated = truncate_to_width("日本語のコミットメッセージ", 10);
+        assert_eq!(truncated, "日本語...");
+        assert!(truncated.width() <= 10);
+    }
+
+    #[test]
+    fn test_format_commit_line() {
+        let now = parse_commit_time("2024-03-10 12:00").unwrap();
+        let mut commit = CommitInfo {
+            id: "a1b2c3d4".to_string(),
+            full_id: "a1b2c3d400000000000000000000000000000000".to_string(),
+            message: "Fix login redirect".to_string(),
+            author: "Alice".to_string(),
+            time: "2024-03-07 09:30".to_string(),
+        };
+        assert_eq!(
+            format_commit_line(&commit, now),
+            "a1b2c3d4 Fix login redirect (Alice, 3 days ago)"
+        );
+
+        commit.time = "2024-03-10 11:55".to_string();
+        assert!(format_commit_line(&commit, now).ends_with("(Alice, 5 minutes ago)"));
+
+        commit.time = "2024-03-10 12:00".to_string();
+        assert!(format_commit_line(&commit, now).ends_with("(Alice, just now)"));
+
+        commit.time = "yesterday".to_string();
+        assert!(format_commit_line(&commit, now).ends_with("(Alice, yesterday)"));
+
+        commit.message = "x".repeat(LIST_COMMIT_SUMMARY_WIDTH + 10);
+        let line = format_commit_line(&commit, now);
+        assert!(line.contains(ELLIPSIS));
+    }
+
+    fn sortable(name: &str, branch: &str, has_changes: bool, time: Option<&str>) -> WorktreeInfo {
+        WorktreeInfo {
+            branch: branch.to_string(),
+            is_current: name == "main",
+            has_changes,
+            last_commit: time.map(|time| crate::git::CommitInfo {
+                id: "abcdef12".to_string(),
+                full_id: "abcdef1200000000000000000000000000000000".to_string(),
+                message: "Commit".to_string(),
+                author: "Dev".to_string(),
+                time: time.to_string(),
+            }),
+            ..WorktreeInfo::test(name)
+        }
+    }
+
+    fn sortable_worktrees() -> Vec<WorktreeInfo> {
+        vec![
+            sortable("beta", "fix/a", true, Some("2024-03-01 10:00")),
+            sortable("main", "main", false, Some("2024-01-01 10:00")),
+            sortable("alpha", "feature/z", false, None),
+            sortable("gamma", "feature/b", true, Some("2024-02-01 10:00")),
+        ]
+    }
+
+    fn names(worktrees: Vec<WorktreeInfo>) -> Vec<String> {
+        worktrees.into_iter().map(|w| w.name).collect()
+    }
+
+    #[test]
+    fn test_sort_worktrees_by_name() {
+        let sorted = sort_worktrees(sortable_worktrees(), ListSortKey::Name, true);
+        assert_eq!(names(sorted), vec!["alpha", "beta", "gamma", "main"]);
+        let sorted = sort_worktrees(sortable_worktrees(), ListSortKey::Name, false);
+        assert_eq!(names(sorted), vec!["main", "gamma", "beta", "alpha"]);
+    }
+
+    #[test]
+    fn test_sort_worktrees_by_branch() {
+        let sorted = sort_worktrees(sortable_worktrees(), ListSortKey::Branch, true);
+        assert_eq!(names(sorted), vec!["gamma", "alpha", "beta", "main"]);
+        let sorted = sort_worktrees(sortable_worktrees(), ListSortKey::Branch, false);
+        assert_eq!(names(sorted), vec!["main", "beta", "alpha", "gamma"]);
+    }
+
+    #[test]
+    fn test_sort_worktrees_by_modified() {
+        let sorted = sort_worktrees(sortable_worktrees(), ListSortKey::Modified, true);
+        assert_eq!(names(sorted), vec!["alpha", "main", "beta", "gamma"]);
+        // Ties stay in name order when descending
+        let sorted = sort_worktrees(sortable_worktrees(), ListSortKey::Modified, false);
+        assert_eq!(names(sorted), vec!["beta", "gamma", "alpha", "main"]);
+    }
+
+    #[test]
+    fn test_sort_worktrees_by_commit_time() {
+        let sorted = sort_worktrees(sortable_worktrees(), ListSortKey::CommitTime, true);
+        assert_eq!(names(sorted), vec!["alpha", "main", "gamma", "beta"]);
+        let sorted = sort_worktrees(sortable_worktrees(), ListSortKey::CommitTime, false);
+        assert_eq!(names(sorted), vec!["beta", "gamma", "main", "alpha"]);
+    }
+
+    #[test]
+    fn test_list_sort_pins_current() {
+        let sort = ListSort::default();
+        assert_eq!(
+            names(sort.apply(sortable_worktrees())),
+            vec!["main", "alpha", "beta", "gamma"]
+        );
+
+        let sort = ListSort {
+            key: ListSortKey::CommitTime,
+            ascending: false,
+            pin_current: false,
+        };
+        assert_eq!(
+            names(sort.apply(sortable_worktrees())),
+            vec!["beta", "gamma", "main", "alpha"]
+        );
+        let sort = ListSort {
+            pin_current: true,
+            ..sort
+        };
+        assert_eq!(
+            names(sort.apply(sortable_worktrees())),
+            vec!["main", "beta", "gamma", "alpha"]
+        );
+    }
+
+    #[test]
+    fn test_hide_worktrees() {
+        let patterns = vec!["tooling-*".to_string(), "scratch".to_string()];
+        assert!(is_hidden_worktree("tooling-index", &patterns));
+        assert!(is_hidden_worktree("scratch", &patterns));
+        assert!(!is_hidden_worktree("scratch-2", &patterns));
+        assert!(!is_hidden_worktree("feature", &[]));
+
+        let worktrees = || {
+            vec![
+                sortable("main", "main", false, None),
+                sortable("tooling-index", "index", false, None),
+                sortable("feature", "feature", false, None),
+                sortable("scratch", "scratch", false, None),
+            ]
+        };
+        let (shown, hidden) = hide_worktrees(worktrees(), &patterns, false);
+        assert_eq!(names(shown), vec!["main", "feature"]);
+        assert_eq!(hidden, 2);
+        let (shown, hidden) = hide_worktrees(worktrees(), &patterns, true);
+        assert_eq!(shown.len(), 4);
+        assert_eq!(hidden, 2);
+
+        // The current worktree stays visible
+        let (shown, hidden) = hide_worktrees(worktrees(), &["*".to_string()], false);
+        assert_eq!(names(shown), vec!["main"]);
+        assert_eq!(hidden, 3);
+    }
+
+    #[test]
+    fn test_list_sort_key_from_str() {
+        assert_eq!("name".parse::<ListSortKey>().unwrap(), ListSortKey::Name);
+        assert_eq!(
+            "branch".parse::<ListSortKey>().unwrap(),
+            ListSortKey::Branch
+        );
+        assert_eq!(
+            "modified".parse::<ListSortKey>().unwrap(),
+            ListSortKey::Modified
+        );
+        assert_eq!(
+            "commit-time".parse::<ListSortKey>().unwrap(),
+            ListSortKey::CommitTime
+        );
+        assert!("size".parse::<ListSortKey>().is_err());
+    }
 }
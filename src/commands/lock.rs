@@ -0,0 +1,123 @@
+//! Worktree locking
+//!
+//! A locked worktree cannot be removed or pruned, e.g. one on a removable
+//! drive or one holding a long-running experiment. Git keeps the optional
+//! reason with the lock; it is shown in the verbose list.
+
+use anyhow::Result;
+use colored::*;
+
+use crate::constants::{
+    EMOJI_LOCKED, HEADER_LOCK_WORKTREE, MSG_WORKTREE_LOCKED, MSG_WORKTREE_UNLOCKED,
+    PROMPT_LOCK_REASON, PROMPT_SELECT_WORKTREE_TO_LOCK, PROMPT_UNLOCK_WORKTREE,
+    WARNING_NO_WORKTREES,
+};
+use crate::git::{GitWorktreeManager, WorktreeInfo};
+use crate::ui::{DialoguerUI, UserInterface};
+use crate::utils::{self, output, press_any_key_to_continue};
+
+/// Pure business logic for a worktree entry in the lock picker
+///
+/// Locked worktrees are marked with `🔒` and their lock reason, if any.
+pub fn format_lock_item(worktree: &WorktreeInfo) -> String {
+    let label = format!("{} ({})", worktree.name, worktree.branch);
+    match (worktree.is_locked, worktree.lock_reason.as_deref()) {
+        (true, Some(reason)) => format!("{label} {EMOJI_LOCKED} {reason}"),
+        (true, None) => format!("{label} {EMOJI_LOCKED}"),
+        (false, _) => label,
+    }
+}
+
+/// Locks or unlocks a selected worktree
+///
+/// # Errors
+///
+/// Returns an error if not in a Git repository or worktrees cannot be listed.
+pub fn toggle_worktree_lock() -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    let ui = DialoguerUI;
+    toggle_worktree_lock_with_ui(&manager, &ui)
+}
+
+/// Internal implementation of toggle_worktree_lock with dependency injection
+///
+/// # Arguments
+///
+/// * `manager` - Git worktree manager instance
+/// * `ui` - User interface implementation for testability
+///
+/// Unlocked worktrees are locked with an optional reason; locked ones are
+/// unlocked after confirmation.
+pub fn toggle_worktree_lock_with_ui(
+    manager: &GitWorktreeManager,
+    ui: &dyn UserInterface,
+) -> Result<()> {
+    output::print_header(HEADER_LOCK_WORKTREE);
+
+    // Git cannot lock the main worktree
+    let worktrees: Vec<WorktreeInfo> = manager
+        .list_worktrees()?
+        .into_iter()
+        .filter(|w| !w.is_main)
+        .collect();
+    if worktrees.is_empty() {
+        let msg = WARNING_NO_WORKTREES.yellow();
+        println!("{msg}");
+        println!();
+        press_any_key_to_continue()?;
+        return Ok(());
+    }
+
+    let items: Vec<String> = worktrees.iter().map(format_lock_item).collect();
+    let worktree = match ui.select(PROMPT_SELECT_WORKTREE_TO_LOCK, &items) {
+        Ok(selection) => &worktrees[selection],
+        Err(_) => return Ok(()),
+    };
+
+    if worktree.is_locked {
+        let prompt = PROMPT_UNLOCK_WORKTREE.replace("{}", &worktree.name);
+        if !ui.confirm_with_default(&prompt, true).unwrap_or(false) {
+            return Ok(());
+        }
+        manager.unlock_worktree(&worktree.git_name)?;
+        utils::print_success(&MSG_WORKTREE_UNLOCKED.replace("{}", &worktree.name));
+    } else {
+        let reason = match ui.input_with_default(PROMPT_LOCK_REASON, "") {
+            Ok(reason) => reason.trim().to_string(),
+            Err(_) => return Ok(()),
+        };
+        let reason = (!reason.is_empty()).then_some(reason.as_str());
+        manager.lock_worktree(&worktree.git_name, reason)?;
+        utils::print_success(&MSG_WORKTREE_LOCKED.replace("{}", &worktree.name));
+    }
+
+    println!();
+    press_any_key_to_continue()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn worktree(is_locked: bool, lock_reason: Option<&str>) -> WorktreeInfo {
+        WorktreeInfo {
+            path: PathBuf::from("/mnt/usb"),
+            branch: "feature".to_string(),
+            is_locked,
+            lock_reason: lock_reason.map(str::to_string),
+            ..WorktreeInfo::test("usb")
+        }
+    }
+
+    #[test]
+    fn test_format_lock_item() {
+        assert_eq!(format_lock_item(&worktree(false, None)), "usb (feature)");
+        assert_eq!(format_lock_item(&worktree(true, None)), "usb (feature) 🔒");
+        assert_eq!(
+            format_lock_item(&worktree(true, Some("on usb drive"))),
+            "usb (feature) 🔒 on usb drive"
+        );
+    }
+}
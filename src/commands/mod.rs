@@ -1,33 +1,115 @@
 // 既存 API の完全な互換性維持
+mod batch_create;
+mod config_check;
+mod config_path;
 mod create;
 mod delete;
+mod doctor;
+mod du;
+mod exec;
+mod favorite;
+mod files;
+mod focus;
+mod init_layout;
 mod list;
+mod lock;
+mod open;
+mod prune;
+mod relocate;
 mod rename;
+mod sha;
 pub mod shared;
+mod status;
+mod suggest;
 mod switch;
+mod version;
+mod which;
 
 // 公開インターフェース（変更なし）
+pub use batch_create::{
+    batch_create_candidates, create_worktrees_batch, create_worktrees_batch_with_ui,
+    first_worktree_location, worktree_name_for_branch, BatchBranch,
+};
+pub use config_check::{
+    suggest_key, validate_config, validate_config_content, validate_config_file, ConfigIssue,
+    IssueLevel,
+};
+pub use config_path::{config_path, format_config_candidate};
 pub use create::{
-    create_worktree, create_worktree_with_ui, determine_worktree_path, validate_worktree_creation,
-    BranchSource, WorktreeCreateConfig,
+    branch_created_by_worktree, branch_one_edit_away, check_create_options, check_created_branch,
+    create_branch_label, create_worktree, create_worktree_from_options, create_worktree_with_ui,
+    custom_worktree_path, default_location_index, determine_worktree_path,
+    expected_branch_for_new_worktree, should_offer_config_setup, validate_worktree_creation,
+    BranchSource, CreateOptions, WorktreeCreateConfig,
 };
 // Re-export validation functions from core module
-pub use super::core::{validate_custom_path, validate_worktree_name};
+pub use super::core::{validate_custom_path, validate_worktree_name, validate_worktree_target};
 pub use delete::{
-    analyze_deletion, delete_worktree, delete_worktree_with_ui, execute_deletion,
-    get_deletable_worktrees, DeletionAnalysis, WorktreeDeleteConfig,
+    analyze_deletion, branch_checked_out_in, delete_worktree, delete_worktree_by_name,
+    delete_worktree_with_ui, execute_deletion, get_deletable_worktrees, should_offer_branch_delete,
+    DeletionAnalysis, WorktreeDeleteConfig,
+};
+pub use doctor::{
+    check_detached_heads, check_locked_worktrees, check_missing_branches,
+    check_missing_directories, check_stale_lock_files, check_worktree_layout, doctor,
+    run_doctor_checks, DoctorFinding, FindingLevel,
+};
+pub use du::{disk_usage, format_size_cell, rank_by_size, worktree_sizes};
+pub use exec::{command_exit_code, exec_in_worktree};
+pub use favorite::{favorite_worktree, unfavorite_worktree};
+pub use files::{
+    add_copy_entry, copy_entries, edit_copy_files, edit_copy_files_with_ui, remove_copy_entries,
+};
+pub use focus::{
+    filter_focused, focus_mode, focus_mode_with_ui, focus_status_line, is_in_focus,
+    list_focused_worktrees,
+};
+pub use init_layout::{
+    incompatible_worktrees, init_layout, init_layout_with_ui, starter_config, with_exclude_entry,
+    InitLayoutPlan, LayoutMode,
+};
+pub use list::{
+    format_ahead_behind, format_commit_line, format_list_name, hide_worktrees, is_hidden_worktree,
+    list_worktrees, list_worktrees_json, list_worktrees_porcelain, list_worktrees_sorted,
+    list_worktrees_verbose, list_worktrees_with_ui, load_list_worktrees, pin_favorites,
+    sort_worktrees, truncate_to_width, worktrees_to_json, worktrees_to_porcelain, ListSort,
+    ListSortKey,
+};
+pub use lock::{format_lock_item, toggle_worktree_lock, toggle_worktree_lock_with_ui};
+pub use open::{
+    build_editor_command, open_in_editor, open_worktree_by_name, open_worktree_in_editor,
+    open_worktree_in_editor_with_ui,
+};
+pub use prune::{prune_summary, prune_worktrees, report_pruned};
+pub use relocate::{
+    move_worktree, move_worktree_by_name, move_worktree_with_ui, resolve_move_target,
 };
-pub use list::{list_worktrees, list_worktrees_with_ui};
 pub use rename::{
-    analyze_rename_requirements, execute_rename, get_renameable_worktrees, rename_worktree,
-    rename_worktree_with_ui, validate_rename_operation, RenameAnalysis, WorktreeRenameConfig,
+    analyze_rename_requirements, check_rename_by_name, execute_rename, get_rename_candidates,
+    get_renameable_worktrees, rename_worktree, rename_worktree_by_name, rename_worktree_with_ui,
+    validate_branch_rename, validate_rename_operation, RenameAnalysis, RenameMode,
+    WorktreeRenameConfig,
 };
+pub use sha::{format_commit_ids, show_commit_id, show_commit_id_by_name, show_commit_id_with_ui};
 pub use shared::{
-    batch_delete_worktrees, cleanup_old_worktrees, create_search_items, edit_hooks,
-    find_config_file_path, get_worktree_icon, prepare_batch_delete_items, search_worktrees,
-    validate_search_selection, BatchDeleteConfig, SearchAnalysis, SearchConfig,
+    batch_delete_worktrees, batch_delete_worktrees_with_ui, batch_progress_bar,
+    cleanup_old_worktrees, cleanup_old_worktrees_with_ui, create_search_items, edit_hooks,
+    edit_hooks_with_editor, filter_worktrees, find_cleanup_candidates, find_config_file_path,
+    get_worktree_icon, orphaned_branches, parse_commit_time, plan_batch_delete,
+    prepare_batch_delete_items, run_interruptible_batch, search_worktrees,
+    validate_search_selection, worktree_age_time, BatchDeleteConfig, BatchDeletePlan, BatchOutcome,
+    CleanupCandidates, CleanupThreshold, SearchAnalysis, SearchConfig,
+};
+pub use status::{format_change_counts, status_all, worktree_statuses, StatusRow};
+pub use suggest::{
+    apply_suggestions, detect_project_files, suggest_config_additions, suggest_hooks,
+    suggest_hooks_with_ui, ConfigSuggestion,
 };
 pub use switch::{
-    analyze_switch_target, execute_switch, sort_worktrees_for_display, switch_worktree,
-    switch_worktree_with_ui, SwitchAnalysis, WorktreeSwitchConfig,
+    analyze_switch_target, execute_switch, find_switch_target, post_switch_context, record_switch,
+    sort_worktrees_for_display, switch_to_previous_worktree, switch_to_worktree, switch_worktree,
+    switch_worktree_matching, switch_worktree_matching_with_ui, switch_worktree_with_ui,
+    SwitchAnalysis, WorktreeSwitchConfig,
 };
+pub use version::{format_version, show_version, VersionInfo};
+pub use which::{find_owning_worktree, which_branch, which_worktree, WorktreeOwner};
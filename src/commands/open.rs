@@ -0,0 +1,183 @@
+//! Opening worktrees in an editor
+//!
+//! Launches `[editor] command` or the user's `$EDITOR` with the worktree
+//! directory, from the menu or with `gw open <name>`. The hooks editor
+//! reuses [`build_editor_command`] for `.git-workers.toml`.
+
+use anyhow::{anyhow, Result};
+use colored::*;
+use std::path::Path;
+use std::process::Command;
+
+use super::switch::find_switch_target;
+use crate::constants::{
+    DEFAULT_MENU_SELECTION, ERROR_EDITOR_COMMAND_EMPTY, ERROR_EDITOR_EXIT_STATUS,
+    ERROR_EDITOR_LAUNCH, HEADER_OPEN_IN_EDITOR, MSG_EDITOR_HINT, MSG_OPENING_IN_EDITOR,
+    PROMPT_SELECT_WORKTREE_TO_OPEN, TEMPLATE_PATH, WARNING_NO_WORKTREES,
+};
+use crate::git::GitWorktreeManager;
+use crate::ui::{DialoguerUI, UserInterface};
+use crate::utils::{self, output, press_any_key_to_continue};
+
+/// Pure business logic for building the editor command line
+///
+/// `template` is the editor from [`utils::resolve_editor`]. It is split
+/// like a shell would (see [`utils::split_command_line`]), so `code --wait`
+/// and quoted program paths work. `{{path}}` is replaced with `path` within
+/// each argument, keeping a path with spaces as one argument; without it
+/// the path is appended.
+///
+/// # Errors
+///
+/// Returns an error if the command is blank.
+pub fn build_editor_command(template: &str, path: &Path) -> Result<Vec<String>> {
+    let path = path.display().to_string();
+
+    let mut args: Vec<String> = utils::split_command_line(template)
+        .into_iter()
+        .map(|arg| arg.replace(TEMPLATE_PATH, &path))
+        .collect();
+    if args.is_empty() {
+        return Err(anyhow!(ERROR_EDITOR_COMMAND_EMPTY));
+    }
+    if !template.contains(TEMPLATE_PATH) {
+        args.push(path);
+    }
+    Ok(args)
+}
+
+/// Opens `path` in the editor and waits for it to exit
+///
+/// `editor_override` takes precedence over the configured editor (see
+/// [`utils::resolve_editor`]).
+///
+/// # Errors
+///
+/// Returns an error with a hint on configuring the editor if it cannot be
+/// launched or exits unsuccessfully.
+pub fn open_in_editor(path: &Path, editor_override: Option<&str>) -> Result<()> {
+    let editor = utils::resolve_editor(editor_override);
+    let args = build_editor_command(&editor, path)?;
+    let program = &args[0];
+
+    let path_str = path.display().to_string().bright_white();
+    let program_str = program.bright_yellow();
+    let bullet = "•".bright_blue();
+    println!(
+        "{bullet} {}",
+        MSG_OPENING_IN_EDITOR
+            .replacen("{}", &path_str.to_string(), 1)
+            .replacen("{}", &program_str.to_string(), 1)
+    );
+
+    match Command::new(program).args(&args[1..]).status() {
+        Ok(status) if status.success() => Ok(()),
+        Ok(status) => Err(anyhow!(ERROR_EDITOR_EXIT_STATUS
+            .replacen("{}", program, 1)
+            .replacen("{}", &status.to_string(), 1))),
+        Err(e) => Err(anyhow!(
+            "{}\n{MSG_EDITOR_HINT}",
+            ERROR_EDITOR_LAUNCH
+                .replacen("{}", program, 1)
+                .replacen("{}", &e.to_string(), 1)
+        )),
+    }
+}
+
+/// Opens the worktree named `name` in the editor (`gw open <name>`)
+///
+/// # Errors
+///
+/// Returns an error if no worktree has that name (listing the available
+/// ones) or the editor cannot be launched.
+pub fn open_worktree_by_name(name: &str) -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    let worktrees = manager.list_worktrees()?;
+    let target = find_switch_target(&worktrees, name)?;
+    open_in_editor(&target.path, None)
+}
+
+/// Opens a selected worktree in the editor
+///
+/// # Errors
+///
+/// Returns an error if not in a Git repository or worktrees cannot be listed.
+pub fn open_worktree_in_editor() -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    let ui = DialoguerUI;
+    open_worktree_in_editor_with_ui(&manager, &ui)
+}
+
+/// Internal implementation of open_worktree_in_editor with dependency injection
+///
+/// # Arguments
+///
+/// * `manager` - Git worktree manager instance
+/// * `ui` - User interface implementation for testability
+///
+/// A launch failure is reported and the menu continues.
+pub fn open_worktree_in_editor_with_ui(
+    manager: &GitWorktreeManager,
+    ui: &dyn UserInterface,
+) -> Result<()> {
+    output::print_header(HEADER_OPEN_IN_EDITOR);
+
+    let worktrees = manager.list_worktrees()?;
+    if worktrees.is_empty() {
+        let msg = WARNING_NO_WORKTREES.yellow();
+        println!("{msg}");
+        println!();
+        press_any_key_to_continue()?;
+        return Ok(());
+    }
+
+    let items: Vec<String> = worktrees
+        .iter()
+        .map(|w| format!("{} ({})", w.name, w.branch))
+        .collect();
+    let worktree = match ui.select_with_default(
+        PROMPT_SELECT_WORKTREE_TO_OPEN,
+        &items,
+        DEFAULT_MENU_SELECTION,
+    ) {
+        Ok(selection) => &worktrees[selection],
+        Err(_) => return Ok(()),
+    };
+
+    if let Err(e) = open_in_editor(&worktree.path, None) {
+        utils::print_error(&e.to_string());
+    }
+
+    println!();
+    press_any_key_to_continue()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_build_editor_command() {
+        let path = PathBuf::from("/work/my app");
+
+        assert_eq!(
+            build_editor_command("vim", &path).unwrap(),
+            vec!["vim", "/work/my app"]
+        );
+        assert_eq!(
+            build_editor_command("code --wait {{path}}", &path).unwrap(),
+            vec!["code", "--wait", "/work/my app"]
+        );
+        assert_eq!(
+            build_editor_command("idea --path={{path}}", &path).unwrap(),
+            vec!["idea", "--path=/work/my app"]
+        );
+        assert_eq!(
+            build_editor_command("'/opt/My Editor/edit' -w", &path).unwrap(),
+            vec!["/opt/My Editor/edit", "-w", "/work/my app"]
+        );
+        assert!(build_editor_command("  ", &path).is_err());
+    }
+}
@@ -0,0 +1,78 @@
+//! Pruning stale worktree entries (`gw prune`)
+//!
+//! Git keeps administrative files for every worktree under
+//! `.git/worktrees/`. When a worktree directory is deleted by hand those
+//! files stay behind until `git worktree prune` removes them. Locked
+//! worktrees are left alone by Git.
+
+use anyhow::Result;
+use colored::*;
+
+use crate::constants::{
+    HEADER_PRUNE, MSG_NOTHING_TO_PRUNE, MSG_PRUNED_ENTRIES, MSG_WOULD_PRUNE_ENTRIES,
+};
+use crate::git::GitWorktreeManager;
+use crate::utils::{self, output};
+
+/// Pure business logic for the line summarizing a prune run
+pub fn prune_summary(pruned_count: usize) -> String {
+    if pruned_count == 0 {
+        MSG_NOTHING_TO_PRUNE.to_string()
+    } else {
+        MSG_PRUNED_ENTRIES.replace("{}", &pruned_count.to_string())
+    }
+}
+
+/// Prints the entries reported by Git followed by the summary line
+pub fn report_pruned(pruned: &[String]) {
+    for line in pruned {
+        let line = line.bright_black();
+        println!("  {line}");
+    }
+    if pruned.is_empty() {
+        let msg = prune_summary(0).bright_black();
+        println!("{msg}");
+    } else {
+        utils::print_success(&prune_summary(pruned.len()));
+    }
+}
+
+/// Removes stale administrative entries for worktrees missing on disk
+///
+/// Runs `git worktree prune --verbose` and shows what was pruned. With
+/// `dry_run` only the entries that would be pruned are shown.
+///
+/// # Errors
+///
+/// Returns an error if not in a Git repository or Git fails.
+pub fn prune_worktrees(dry_run: bool) -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+
+    output::print_header(HEADER_PRUNE);
+
+    let pruned = manager.prune_worktrees(dry_run)?;
+    if dry_run && !pruned.is_empty() {
+        for line in &pruned {
+            let line = line.bright_black();
+            println!("  {line}");
+        }
+        let msg = MSG_WOULD_PRUNE_ENTRIES.replace("{}", &pruned.len().to_string());
+        println!("{}", msg.bright_yellow());
+    } else {
+        report_pruned(&pruned);
+    }
+    println!();
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prune_summary() {
+        assert_eq!(prune_summary(0), MSG_NOTHING_TO_PRUNE);
+        assert_eq!(prune_summary(3), "Pruned 3 stale worktree entries");
+    }
+}
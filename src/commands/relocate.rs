@@ -0,0 +1,171 @@
+//! Moving worktrees (`gw move`)
+//!
+//! Rename only changes the last path component; moving relocates a
+//! worktree to another directory with `git worktree move`. Git keeps
+//! tracking the worktree under its Git name, so the branch and metadata are
+//! untouched and only the path changes.
+
+use anyhow::Result;
+use colored::*;
+use std::path::{Path, PathBuf};
+
+use super::super::core::{validate_custom_path, validate_worktree_target};
+use super::switch::find_switch_target;
+use crate::constants::{
+    HEADER_MOVE_WORKTREE, MSG_NO_WORKTREES_TO_MOVE, MSG_WORKTREE_MOVED, PROMPT_MOVE_DESTINATION,
+    PROMPT_SELECT_WORKTREE_TO_MOVE,
+};
+use crate::git::{GitWorktreeManager, WorktreeInfo};
+use crate::infrastructure::{State, WorktreeRef};
+use crate::ui::{DialoguerUI, UserInterface};
+use crate::utils::{self, output, press_any_key_to_continue};
+
+/// Resolves where `path` would move a worktree and checks it is safe
+///
+/// `path` is relative to the repository, as for a custom create path, and
+/// is checked with [`validate_custom_path`]. The resolved target must not
+/// exist or lie inside another worktree.
+///
+/// # Errors
+///
+/// Returns an error if `path` is unsafe or the target is taken.
+pub fn resolve_move_target(manager: &GitWorktreeManager, path: &str) -> Result<PathBuf> {
+    let path = path.trim();
+    validate_custom_path(path)?;
+    let target = manager.resolve_worktree_path(path)?;
+
+    let existing: Vec<PathBuf> = manager
+        .list_worktrees()?
+        .into_iter()
+        .map(|worktree| worktree.path)
+        .collect();
+    validate_worktree_target(&target, &existing)?;
+    Ok(target)
+}
+
+/// Moves `worktree` to `target` and reports its new path
+///
+/// The worktree is listed again after the move so the reported path is the
+/// one Git now tracks, and the switch history and focus set follow it.
+fn move_and_report(
+    manager: &GitWorktreeManager,
+    worktree: &WorktreeInfo,
+    target: &Path,
+) -> Result<()> {
+    manager.move_worktree(&worktree.git_name, target)?;
+
+    let moved = manager
+        .list_worktrees()?
+        .into_iter()
+        .find(|w| w.git_name == worktree.git_name);
+    let (name, path) = match moved {
+        Some(moved) => (moved.name, moved.path),
+        None => (worktree.name.clone(), target.to_path_buf()),
+    };
+
+    let state_dir = manager.get_common_dir();
+    let mut state = State::load(state_dir);
+    let from = WorktreeRef {
+        name: worktree.name.clone(),
+        path: worktree.path.clone(),
+    };
+    let to = WorktreeRef {
+        name: name.clone(),
+        path: path.clone(),
+    };
+    if state.record_move(&from, &to) {
+        if let Err(e) = state.save(state_dir) {
+            utils::print_warning(&format!("Failed to save switch state: {e}"));
+        }
+    }
+
+    let path_display = path.display().to_string();
+    utils::print_success(&MSG_WORKTREE_MOVED.replacen("{}", &name, 1).replacen(
+        "{}",
+        &path_display,
+        1,
+    ));
+    Ok(())
+}
+
+/// Moves the worktree named `name` to `path` (`gw move <name> <path>`)
+///
+/// # Errors
+///
+/// Returns an error if no worktree has that name, `path` is unsafe or
+/// taken, the worktree is the current or a locked one, or Git refuses the
+/// move. The main worktree cannot be moved.
+pub fn move_worktree_by_name(name: &str, path: &str) -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    let worktrees: Vec<WorktreeInfo> = manager
+        .list_worktrees()?
+        .into_iter()
+        .filter(|w| !w.is_main)
+        .collect();
+    let worktree = find_switch_target(&worktrees, name)?;
+    let target = resolve_move_target(&manager, path)?;
+    move_and_report(&manager, worktree, &target)
+}
+
+/// Moves a selected worktree to a new directory
+///
+/// # Errors
+///
+/// Returns an error if not in a Git repository or worktrees cannot be listed.
+pub fn move_worktree() -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    let ui = DialoguerUI;
+    move_worktree_with_ui(&manager, &ui)
+}
+
+/// Internal implementation of move_worktree with dependency injection
+///
+/// # Arguments
+///
+/// * `manager` - Git worktree manager instance
+/// * `ui` - User interface implementation for testability
+///
+/// The current worktree is not offered, since Git cannot move the
+/// directory a process is running in, and neither is the main worktree.
+pub fn move_worktree_with_ui(manager: &GitWorktreeManager, ui: &dyn UserInterface) -> Result<()> {
+    output::print_header(HEADER_MOVE_WORKTREE);
+
+    let worktrees: Vec<WorktreeInfo> = manager
+        .list_worktrees()?
+        .into_iter()
+        .filter(|w| !w.is_current && !w.is_main)
+        .collect();
+    if worktrees.is_empty() {
+        let msg = MSG_NO_WORKTREES_TO_MOVE.yellow();
+        println!("{msg}");
+        println!();
+        press_any_key_to_continue()?;
+        return Ok(());
+    }
+
+    let items: Vec<String> = worktrees
+        .iter()
+        .map(|w| format!("{} ({})", w.name, w.path.display()))
+        .collect();
+    let worktree = match ui.select(PROMPT_SELECT_WORKTREE_TO_MOVE, &items) {
+        Ok(selection) => &worktrees[selection],
+        Err(_) => return Ok(()),
+    };
+
+    let prompt = PROMPT_MOVE_DESTINATION.replace("{}", &worktree.name);
+    let path = match ui.input(&prompt) {
+        Ok(path) => path,
+        Err(_) => return Ok(()),
+    };
+
+    println!();
+    if let Err(e) = resolve_move_target(manager, &path)
+        .and_then(|target| move_and_report(manager, worktree, &target))
+    {
+        utils::print_error(&e.to_string());
+    }
+
+    println!();
+    press_any_key_to_continue()?;
+    Ok(())
+}
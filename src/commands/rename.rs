@@ -2,14 +2,21 @@ use anyhow::{anyhow, Result};
 use colored::*;
 
 use crate::constants::{
-    section_header, DEFAULT_BRANCH_DETACHED, DEFAULT_BRANCH_UNKNOWN, DEFAULT_MENU_SELECTION,
+    DEFAULT_BRANCH_DETACHED, DEFAULT_BRANCH_UNKNOWN, DEFAULT_MENU_SELECTION,
+    ERROR_RENAME_BRANCH_NOT_FOLLOWING, ERROR_RENAME_NAME_TAKEN, GIT_CANNOT_RENAME_CURRENT,
+    GIT_CANNOT_RENAME_DETACHED, GIT_CANNOT_RENAME_MAIN, GIT_INVALID_BRANCH_NAME,
+    MSG_RENAME_BRANCH_ONLY_CURRENT, MSG_RENAME_MAPPING, OPTION_RENAME_BOTH,
+    OPTION_RENAME_BRANCH_ONLY, OPTION_RENAME_WORKTREE_ONLY, PROMPT_NEW_BRANCH_NAME_FOR,
+    PROMPT_RENAME_MODE,
 };
 use crate::git::{GitWorktreeManager, WorktreeInfo};
 use crate::ui::{DialoguerUI, UserInterface};
-use crate::utils::{self, press_any_key_to_continue};
+use crate::utils::{self, output, press_any_key_to_continue};
 
 // Use validation function from core module
 use super::super::core::validate_worktree_name;
+use super::create::is_valid_branch_name;
+use super::switch::find_switch_target;
 
 /// Check if branch should be renamed
 #[allow(dead_code)]
@@ -32,6 +39,28 @@ pub struct RenameConfig {
     pub rename_branch: bool,
 }
 
+/// What a rename changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameMode {
+    /// Rename the worktree and the branch that follows its name
+    Both,
+    /// Rename the worktree directory and metadata, leave the branch alone
+    WorktreeOnly,
+    /// Rename the checked-out branch, leave the directory alone
+    BranchOnly,
+}
+
+impl RenameMode {
+    /// Label shown in the rename mode selection
+    pub fn label(self) -> &'static str {
+        match self {
+            RenameMode::Both => OPTION_RENAME_BOTH,
+            RenameMode::WorktreeOnly => OPTION_RENAME_WORKTREE_ONLY,
+            RenameMode::BranchOnly => OPTION_RENAME_BRANCH_ONLY,
+        }
+    }
+}
+
 /// Configuration for worktree renaming
 #[derive(Debug, Clone)]
 pub struct WorktreeRenameConfig {
@@ -41,6 +70,8 @@ pub struct WorktreeRenameConfig {
     pub new_path: std::path::PathBuf,
     pub old_branch: String,
     pub new_branch: Option<String>,
+    /// Rename the worktree directory and metadata (off for branch-only renames)
+    pub rename_worktree: bool,
     pub rename_branch: bool,
 }
 
@@ -48,20 +79,63 @@ pub struct WorktreeRenameConfig {
 #[derive(Debug, Clone)]
 pub struct RenameAnalysis {
     pub worktree: WorktreeInfo,
+    /// The branch follows the worktree name and can be renamed along with it
     pub can_rename_branch: bool,
     pub suggested_branch_name: Option<String>,
     pub is_feature_branch: bool,
+    /// The directory can be renamed (not the current worktree)
+    pub can_rename_worktree: bool,
+    /// The branch can be renamed on its own (any branch, even in the current worktree)
+    pub can_rename_branch_only: bool,
+}
+
+impl RenameAnalysis {
+    /// Rename modes that are safe for this worktree, the default first
+    pub fn available_modes(&self) -> Vec<RenameMode> {
+        let mut modes = Vec::new();
+        if self.can_rename_worktree && self.can_rename_branch {
+            modes.push(RenameMode::Both);
+        }
+        if self.can_rename_worktree {
+            modes.push(RenameMode::WorktreeOnly);
+        }
+        if self.can_rename_branch_only {
+            modes.push(RenameMode::BranchOnly);
+        }
+        modes
+    }
 }
 
 /// Pure business logic for filtering renameable worktrees
+///
+/// Neither the current nor the main worktree can be renamed.
 pub fn get_renameable_worktrees(worktrees: &[WorktreeInfo]) -> Vec<&WorktreeInfo> {
-    worktrees.iter().filter(|w| !w.is_current).collect()
+    worktrees
+        .iter()
+        .filter(|w| !w.is_current && !w.is_main)
+        .collect()
+}
+
+/// Pure business logic for the worktrees offered in the rename picker
+///
+/// Like [`get_renameable_worktrees`], but the current and main worktrees
+/// are kept when they are on a branch, since their branch can still be
+/// renamed.
+pub fn get_rename_candidates(worktrees: &[WorktreeInfo]) -> Vec<&WorktreeInfo> {
+    worktrees
+        .iter()
+        .filter(|w| (!w.is_current && !w.is_main) || has_renameable_branch(w))
+        .collect()
+}
+
+fn has_renameable_branch(worktree: &WorktreeInfo) -> bool {
+    worktree.branch != DEFAULT_BRANCH_DETACHED && worktree.branch != DEFAULT_BRANCH_UNKNOWN
 }
 
 /// Pure business logic for analyzing rename requirements
 pub fn analyze_rename_requirements(worktree: &WorktreeInfo) -> Result<RenameAnalysis> {
-    let can_rename_branch = worktree.branch != DEFAULT_BRANCH_DETACHED
-        && worktree.branch != DEFAULT_BRANCH_UNKNOWN
+    let can_rename_branch_only = has_renameable_branch(worktree);
+    let can_rename_branch = can_rename_branch_only
         && (worktree.branch == worktree.name
             || worktree.branch == format!("feature/{}", worktree.name));
 
@@ -81,9 +155,28 @@ pub fn analyze_rename_requirements(worktree: &WorktreeInfo) -> Result<RenameAnal
         can_rename_branch,
         suggested_branch_name,
         is_feature_branch,
+        can_rename_worktree: !worktree.is_current && !worktree.is_main,
+        can_rename_branch_only,
     })
 }
 
+/// Pure business logic for validating a branch-only rename
+pub fn validate_branch_rename(old_branch: &str, new_branch: &str) -> Result<()> {
+    if new_branch.is_empty() {
+        return Err(anyhow!("New branch name cannot be empty"));
+    }
+
+    if new_branch == old_branch {
+        return Err(anyhow!("New name must be different from the current name"));
+    }
+
+    if !is_valid_branch_name(new_branch) {
+        return Err(anyhow!(GIT_INVALID_BRANCH_NAME.replace("{}", new_branch)));
+    }
+
+    Ok(())
+}
+
 /// Pure business logic for validating rename operation
 pub fn validate_rename_operation(old_name: &str, new_name: &str) -> Result<()> {
     if old_name.is_empty() {
@@ -109,18 +202,69 @@ pub fn validate_rename_operation(old_name: &str, new_name: &str) -> Result<()> {
     Ok(())
 }
 
+/// Pure business logic for checking a rename given on the command line
+///
+/// Applies the checks of the interactive rename and refuses the current
+/// and main worktrees, detached-HEAD worktrees and names already in use.
+/// With `with_branch` the branch must follow the worktree name; the new
+/// branch name is returned.
+pub fn check_rename_by_name(
+    worktrees: &[WorktreeInfo],
+    worktree: &WorktreeInfo,
+    new_name: &str,
+    with_branch: bool,
+) -> Result<Option<String>> {
+    if worktree.is_main {
+        return Err(anyhow!(GIT_CANNOT_RENAME_MAIN));
+    }
+    let analysis = analyze_rename_requirements(worktree)?;
+    if !analysis.can_rename_worktree {
+        return Err(anyhow!(GIT_CANNOT_RENAME_CURRENT));
+    }
+    if !analysis.can_rename_branch_only {
+        return Err(anyhow!(GIT_CANNOT_RENAME_DETACHED));
+    }
+
+    validate_rename_operation(&worktree.git_name, new_name)?;
+    if worktrees
+        .iter()
+        .any(|w| w.name == new_name || w.git_name == new_name)
+    {
+        return Err(anyhow!(ERROR_RENAME_NAME_TAKEN.replace("{}", new_name)));
+    }
+
+    if !with_branch {
+        return Ok(None);
+    }
+    if !analysis.can_rename_branch {
+        return Err(anyhow!(
+            ERROR_RENAME_BRANCH_NOT_FOLLOWING.replace("{}", &worktree.branch)
+        ));
+    }
+    Ok(Some(if analysis.is_feature_branch {
+        format!("feature/{new_name}")
+    } else {
+        new_name.to_string()
+    }))
+}
+
 /// Pure business logic for executing rename operation
+///
+/// `rename_worktree` and `rename_branch` select the parts that change, so
+/// the directory and the branch can be renamed together or on their own.
 pub fn execute_rename(config: &WorktreeRenameConfig, manager: &GitWorktreeManager) -> Result<()> {
     // Rename worktree
-    manager
-        .rename_worktree(&config.old_name, &config.new_name)
-        .map_err(|e| anyhow!("Failed to rename worktree: {e}"))?;
-
-    utils::print_success(&format!(
-        "Worktree renamed from '{}' to '{}'!",
-        config.old_name.yellow(),
-        config.new_name.bright_green()
-    ));
+    if config.rename_worktree {
+        manager
+            .rename_worktree(&config.old_name, &config.new_name)
+            .map_err(|e| anyhow!("Failed to rename worktree: {e}"))?;
+
+        utils::print_success(&format!(
+            "Worktree renamed from '{}' to '{}'!",
+            config.old_name.yellow(),
+            config.new_name.bright_green()
+        ));
+    }
 
     // Rename branch if requested
     if config.rename_branch {
@@ -153,22 +297,24 @@ pub fn execute_rename(config: &WorktreeRenameConfig, manager: &GitWorktreeManage
 ///
 /// # Rename Process
 ///
-/// 1. **Selection**: Choose a worktree to rename (current excluded)
-/// 2. **New Name**: Enter the new name for the worktree
-/// 3. **Branch Rename**: If branch name matches worktree name, offers to rename it
+/// 1. **Selection**: Choose a worktree to rename
+/// 2. **Mode**: Rename the worktree and branch, the worktree only, or the branch only
+/// 3. **New Name**: Enter the new worktree name (or branch name for branch-only)
 /// 4. **Preview**: Shows before/after comparison
 /// 5. **Execution**: Renames worktree directory and updates Git metadata
 ///
 /// # Branch Renaming Logic
 ///
-/// The branch is offered for renaming if:
+/// Renaming worktree and branch together is offered if:
 /// - Branch name equals worktree name
 /// - Branch name equals `feature/{worktree-name}`
 ///
+/// Any branch can be renamed on its own.
+///
 /// # Limitations
 ///
-/// - Cannot rename the current worktree
-/// - Cannot rename worktrees with detached HEAD
+/// - Only the branch of the current worktree can be renamed
+/// - Cannot rename the branch of a worktree with detached HEAD
 /// - New name must be unique
 ///
 /// # Returns
@@ -187,6 +333,45 @@ pub fn rename_worktree() -> Result<()> {
     rename_worktree_with_ui(&manager, &ui)
 }
 
+/// Renames a worktree by name without the menu (`gw rename <old> <new>`)
+///
+/// With `with_branch` (`--with-branch`) the branch is renamed along with
+/// the worktree, as the "Worktree and branch" mode of the menu does. The
+/// worktree's old → new mapping is printed on success; the branch rename
+/// is reported once, by [`execute_rename`].
+///
+/// # Errors
+///
+/// Returns an error if there is no worktree named `old_name`, the rename is
+/// refused by [`check_rename_by_name`], or it fails.
+pub fn rename_worktree_by_name(old_name: &str, new_name: &str, with_branch: bool) -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    let worktrees = manager.list_worktrees()?;
+    let worktree = find_switch_target(&worktrees, old_name)?;
+    let new_name = validate_worktree_name(new_name)?;
+    let new_branch = check_rename_by_name(&worktrees, worktree, &new_name, with_branch)?;
+
+    let config = WorktreeRenameConfig {
+        old_name: worktree.git_name.clone(),
+        new_name: new_name.clone(),
+        old_path: worktree.path.clone(),
+        new_path: worktree.path.with_file_name(&new_name),
+        old_branch: worktree.branch.clone(),
+        rename_branch: new_branch.is_some(),
+        new_branch,
+        rename_worktree: true,
+    };
+    execute_rename(&config, &manager)?;
+
+    println!(
+        "{}",
+        MSG_RENAME_MAPPING
+            .replacen("{}", &worktree.name, 1)
+            .replacen("{}", &config.new_name, 1)
+    );
+    Ok(())
+}
+
 /// Internal implementation of rename_worktree with dependency injection
 ///
 /// # Arguments
@@ -199,7 +384,7 @@ pub fn rename_worktree() -> Result<()> {
 /// - Updates worktree directory name
 /// - Updates .git/worktrees/`<name>` metadata
 /// - Updates gitdir references
-/// - Optionally renames associated branch
+/// - Optionally renames associated branch, or only the branch
 pub fn rename_worktree_with_ui(manager: &GitWorktreeManager, ui: &dyn UserInterface) -> Result<()> {
     let worktrees = manager.list_worktrees()?;
 
@@ -213,7 +398,7 @@ pub fn rename_worktree_with_ui(manager: &GitWorktreeManager, ui: &dyn UserInterf
     }
 
     // Use business logic to filter renameable worktrees
-    let renameable_worktrees = get_renameable_worktrees(&worktrees);
+    let renameable_worktrees = get_rename_candidates(&worktrees);
 
     if renameable_worktrees.is_empty() {
         println!();
@@ -228,10 +413,7 @@ pub fn rename_worktree_with_ui(manager: &GitWorktreeManager, ui: &dyn UserInterf
         return Ok(());
     }
 
-    println!();
-    let header = section_header("Rename Worktree");
-    println!("{header}");
-    println!();
+    output::print_header("Rename Worktree");
 
     let items: Vec<String> = renameable_worktrees
         .iter()
@@ -249,6 +431,32 @@ pub fn rename_worktree_with_ui(manager: &GitWorktreeManager, ui: &dyn UserInterf
 
     let worktree = renameable_worktrees[selection];
 
+    // Use business logic to analyze rename requirements
+    let analysis = analyze_rename_requirements(worktree)?;
+
+    let modes = analysis.available_modes();
+    let mode = match modes.as_slice() {
+        [] => return Ok(()),
+        [mode] => *mode,
+        _ => {
+            println!();
+            let labels: Vec<String> = modes.iter().map(|m| m.label().to_string()).collect();
+            match ui.select_with_default(PROMPT_RENAME_MODE, &labels, DEFAULT_MENU_SELECTION) {
+                Ok(index) => modes[index],
+                Err(_) => return Ok(()),
+            }
+        }
+    };
+
+    if mode == RenameMode::BranchOnly {
+        if worktree.is_current {
+            println!();
+            let note = MSG_RENAME_BRANCH_ONLY_CURRENT.bright_black();
+            println!("{note}");
+        }
+        return rename_branch_only_with_ui(manager, ui, worktree);
+    }
+
     // Get new name
     println!();
     let new_name = match ui.input(&format!("New name for '{}' (ESC to cancel)", worktree.name)) {
@@ -273,19 +481,7 @@ pub fn rename_worktree_with_ui(manager: &GitWorktreeManager, ui: &dyn UserInterf
         }
     };
 
-    // Use business logic to analyze rename requirements
-    let analysis = analyze_rename_requirements(worktree)?;
-
-    // Ask about branch renaming if applicable
-    let rename_branch = if analysis.can_rename_branch {
-        println!();
-        match ui.confirm_with_default("Also rename the associated branch?", true) {
-            Ok(confirm) => confirm,
-            Err(_) => return Ok(()),
-        }
-    } else {
-        false
-    };
+    let rename_branch = mode == RenameMode::Both;
 
     let new_branch = if rename_branch {
         if analysis.is_feature_branch {
@@ -337,25 +533,75 @@ pub fn rename_worktree_with_ui(manager: &GitWorktreeManager, ui: &dyn UserInterf
         new_path,
         old_branch: worktree.branch.clone(),
         new_branch,
+        rename_worktree: true,
         rename_branch,
     };
 
     // Perform the rename using business logic
     utils::print_progress(&format!("Renaming worktree to '{new_name}'..."));
 
-    match execute_rename(&config, manager) {
-        Ok(_) => {
-            println!();
-            press_any_key_to_continue()?;
-            Ok(())
-        }
-        Err(e) => {
-            utils::print_error(&format!("{e}"));
-            println!();
-            press_any_key_to_continue()?;
-            Ok(())
-        }
+    finish_rename(&config, manager)
+}
+
+/// Renames only the branch checked out in `worktree`
+fn rename_branch_only_with_ui(
+    manager: &GitWorktreeManager,
+    ui: &dyn UserInterface,
+    worktree: &WorktreeInfo,
+) -> Result<()> {
+    println!();
+    let prompt = PROMPT_NEW_BRANCH_NAME_FOR.replace("{}", &worktree.branch);
+    let new_branch = match ui.input_with_default(&prompt, &worktree.branch) {
+        Ok(name) => name.trim().to_string(),
+        Err(_) => return Ok(()),
+    };
+
+    if let Err(e) = validate_branch_rename(&worktree.branch, &new_branch) {
+        utils::print_warning(&e.to_string());
+        return Ok(());
+    }
+
+    // Show preview
+    println!();
+    let preview_label = "Preview:".bright_white();
+    println!("{preview_label}");
+    let branch_label = "Branch:".bright_white();
+    let old_branch = &worktree.branch;
+    let new_branch_green = new_branch.bright_green();
+    println!("  {branch_label} {old_branch} → {new_branch_green}");
+
+    println!();
+    let confirm = match ui.confirm_with_default("Proceed with rename?", false) {
+        Ok(confirm) => confirm,
+        Err(_) => return Ok(()),
+    };
+
+    if !confirm {
+        return Ok(());
     }
+
+    let config = WorktreeRenameConfig {
+        old_name: worktree.git_name.clone(),
+        new_name: worktree.git_name.clone(),
+        old_path: worktree.path.clone(),
+        new_path: worktree.path.clone(),
+        old_branch: worktree.branch.clone(),
+        new_branch: Some(new_branch),
+        rename_worktree: false,
+        rename_branch: true,
+    };
+
+    finish_rename(&config, manager)
+}
+
+/// Runs the rename and waits for a key press, reporting any error
+fn finish_rename(config: &WorktreeRenameConfig, manager: &GitWorktreeManager) -> Result<()> {
+    if let Err(e) = execute_rename(config, manager) {
+        utils::print_error(&format!("{e}"));
+    }
+    println!();
+    press_any_key_to_continue()?;
+    Ok(())
 }
 
 #[cfg(test)]
@@ -415,6 +661,7 @@ mod tests {
             new_path: PathBuf::from("/tmp/new"),
             old_branch: old_branch.to_string(),
             new_branch: Some(new_branch.to_string()),
+            rename_worktree: true,
             rename_branch: true,
         };
 
@@ -431,26 +678,13 @@ mod tests {
         let feature_name = "feature";
         let worktrees = vec![
             WorktreeInfo {
-                name: main_name.to_string(),
-                git_name: main_name.to_string(),
                 path: PathBuf::from("/tmp/main"),
-                branch: main_name.to_string(),
                 is_current: true, // Current worktree - should be filtered out
-                has_changes: false,
-                last_commit: None,
-                ahead_behind: None,
-                is_locked: false,
+                ..WorktreeInfo::test(main_name)
             },
             WorktreeInfo {
-                name: feature_name.to_string(),
-                git_name: feature_name.to_string(),
                 path: PathBuf::from("/tmp/feature"),
-                branch: feature_name.to_string(),
-                is_current: false,
-                has_changes: false,
-                last_commit: None,
-                ahead_behind: None,
-                is_locked: false,
+                ..WorktreeInfo::test(feature_name)
             },
         ];
 
@@ -463,15 +697,8 @@ mod tests {
     fn test_analyze_rename_requirements_basic() {
         let feature_name = "feature";
         let worktree = WorktreeInfo {
-            name: feature_name.to_string(),
-            git_name: feature_name.to_string(),
             path: PathBuf::from("/tmp/feature"),
-            branch: feature_name.to_string(),
-            is_current: false,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
-            is_locked: false,
+            ..WorktreeInfo::test(feature_name)
         };
 
         let analysis = analyze_rename_requirements(&worktree).unwrap();
@@ -517,15 +744,9 @@ mod tests {
         let worktree_name = "auth";
         let feature_branch = "feature/auth";
         let worktree = WorktreeInfo {
-            name: worktree_name.to_string(),
-            git_name: worktree_name.to_string(),
             path: PathBuf::from("/tmp/auth"),
             branch: feature_branch.to_string(),
-            is_current: false,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
-            is_locked: false,
+            ..WorktreeInfo::test(worktree_name)
         };
 
         let analysis = analyze_rename_requirements(&worktree).unwrap();
@@ -540,15 +761,8 @@ mod tests {
     #[test]
     fn test_analyze_rename_requirements_detached_head() {
         let worktree = WorktreeInfo {
-            name: "detached".to_string(),
-            git_name: "detached".to_string(),
-            path: PathBuf::from("/tmp/detached"),
             branch: DEFAULT_BRANCH_DETACHED.to_string(),
-            is_current: false,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
-            is_locked: false,
+            ..WorktreeInfo::test("detached")
         };
 
         let analysis = analyze_rename_requirements(&worktree).unwrap();
@@ -581,6 +795,91 @@ mod tests {
         assert!(validate_rename_operation(old_name, master_name).is_err());
     }
 
+    #[test]
+    fn test_available_rename_modes() {
+        let mut worktree = WorktreeInfo {
+            branch: "feature/login".to_string(),
+            ..WorktreeInfo::test("login")
+        };
+        let modes = |w: &WorktreeInfo| analyze_rename_requirements(w).unwrap().available_modes();
+
+        assert_eq!(
+            modes(&worktree),
+            vec![
+                RenameMode::Both,
+                RenameMode::WorktreeOnly,
+                RenameMode::BranchOnly
+            ]
+        );
+
+        worktree.branch = "unrelated".to_string();
+        assert_eq!(
+            modes(&worktree),
+            vec![RenameMode::WorktreeOnly, RenameMode::BranchOnly]
+        );
+
+        // Branch-only is safe even on the main and current worktrees
+        worktree.is_main = true;
+        assert_eq!(modes(&worktree), vec![RenameMode::BranchOnly]);
+        assert!(get_renameable_worktrees(std::slice::from_ref(&worktree)).is_empty());
+        assert_eq!(
+            get_rename_candidates(std::slice::from_ref(&worktree)).len(),
+            1
+        );
+
+        worktree.is_main = false;
+        worktree.is_current = true;
+        assert_eq!(modes(&worktree), vec![RenameMode::BranchOnly]);
+        assert_eq!(
+            get_rename_candidates(std::slice::from_ref(&worktree)).len(),
+            1
+        );
+
+        worktree.branch = DEFAULT_BRANCH_DETACHED.to_string();
+        assert!(modes(&worktree).is_empty());
+        assert!(get_rename_candidates(std::slice::from_ref(&worktree)).is_empty());
+    }
+
+    #[test]
+    fn test_validate_branch_rename() {
+        assert!(validate_branch_rename("old", "new").is_ok());
+        assert!(validate_branch_rename("old", "feature/new").is_ok());
+        assert!(validate_branch_rename("old", "old").is_err());
+        assert!(validate_branch_rename("old", "").is_err());
+        assert!(validate_branch_rename("old", "bad..name").is_err());
+    }
+
+    #[test]
+    fn test_check_rename_by_name() {
+        let worktree = |name: &str, branch: &str| WorktreeInfo {
+            branch: branch.to_string(),
+            ..WorktreeInfo::test(name)
+        };
+        let mut login = worktree("login", "feature/login");
+        let worktrees = vec![login.clone(), worktree("signup", "signup")];
+
+        assert_eq!(
+            check_rename_by_name(&worktrees, &login, "auth", false).unwrap(),
+            None
+        );
+        assert_eq!(
+            check_rename_by_name(&worktrees, &login, "auth", true).unwrap(),
+            Some("feature/auth".to_string())
+        );
+        assert!(check_rename_by_name(&worktrees, &login, "signup", false).is_err());
+        assert!(check_rename_by_name(&worktrees, &login, "main", false).is_err());
+
+        let unrelated = worktree("login", "unrelated");
+        assert!(check_rename_by_name(&worktrees, &unrelated, "auth", false).is_ok());
+        assert!(check_rename_by_name(&worktrees, &unrelated, "auth", true).is_err());
+
+        login.is_current = true;
+        assert!(check_rename_by_name(&worktrees, &login, "auth", false).is_err());
+
+        let detached = worktree("login", DEFAULT_BRANCH_DETACHED);
+        assert!(check_rename_by_name(&worktrees, &detached, "auth", false).is_err());
+    }
+
     #[test]
     fn test_get_renameable_worktrees_empty_list() {
         let worktrees: Vec<WorktreeInfo> = vec![];
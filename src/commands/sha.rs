@@ -0,0 +1,157 @@
+//! Showing a worktree's HEAD commit id
+//!
+//! Prints the full and short id of the last commit, from the menu or with
+//! `gw sha <name>`. Builds with the `clipboard` feature also copy the full
+//! id to the clipboard.
+
+use anyhow::{anyhow, Result};
+use colored::*;
+
+use super::switch::find_switch_target;
+use crate::constants::{
+    DEFAULT_MENU_SELECTION, ERROR_WORKTREE_NO_COMMIT, HEADER_COMMIT_ID,
+    PROMPT_SELECT_WORKTREE_FOR_SHA, WARNING_NO_WORKTREES,
+};
+use crate::git::{CommitInfo, GitWorktreeManager, WorktreeInfo};
+use crate::ui::{DialoguerUI, UserInterface};
+use crate::utils::{self, output, press_any_key_to_continue};
+
+/// Pure business logic for the commit ids printed by `gw sha`
+///
+/// The full id on the first line and the short id on the second, so
+/// `gw sha feature | head -1` gives the full id.
+pub fn format_commit_ids(commit: &CommitInfo) -> String {
+    let full_id = &commit.full_id;
+    let id = &commit.id;
+    format!("{full_id}\n{id}")
+}
+
+/// Returns the last commit of `worktree`, or an error naming it
+fn worktree_commit(worktree: &WorktreeInfo) -> Result<&CommitInfo> {
+    worktree
+        .last_commit
+        .as_ref()
+        .ok_or_else(|| anyhow!(ERROR_WORKTREE_NO_COMMIT.replace("{}", &worktree.name)))
+}
+
+/// Copies the full commit id to the clipboard, reporting the result on stderr
+#[cfg(feature = "clipboard")]
+fn copy_commit_id(commit: &CommitInfo) {
+    use crate::constants::{MSG_CLIPBOARD_FAILED, MSG_COMMIT_ID_COPIED};
+
+    match utils::copy_to_clipboard(&commit.full_id) {
+        Ok(()) => {
+            let msg = MSG_COMMIT_ID_COPIED
+                .replace("{}", &commit.id)
+                .bright_black();
+            eprintln!("{msg}");
+        }
+        Err(e) => {
+            let msg = MSG_CLIPBOARD_FAILED.replace("{}", &e.to_string()).yellow();
+            eprintln!("{msg}");
+        }
+    }
+}
+
+/// Without the `clipboard` feature the ids are only printed
+#[cfg(not(feature = "clipboard"))]
+fn copy_commit_id(_commit: &CommitInfo) {}
+
+/// Prints the HEAD commit id of the worktree named `name` (`gw sha <name>`)
+///
+/// # Errors
+///
+/// Returns an error if no worktree has that name (listing the available
+/// ones) or it has no commit.
+pub fn show_commit_id_by_name(name: &str) -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    let worktrees = manager.list_worktrees()?;
+    let commit = worktree_commit(find_switch_target(&worktrees, name)?)?;
+    println!("{}", format_commit_ids(commit));
+    copy_commit_id(commit);
+    Ok(())
+}
+
+/// Prints the HEAD commit id of a selected worktree
+///
+/// # Errors
+///
+/// Returns an error if not in a Git repository or worktrees cannot be listed.
+pub fn show_commit_id() -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    let ui = DialoguerUI;
+    show_commit_id_with_ui(&manager, &ui)
+}
+
+/// Internal implementation of show_commit_id with dependency injection
+///
+/// # Arguments
+///
+/// * `manager` - Git worktree manager instance
+/// * `ui` - User interface implementation for testability
+pub fn show_commit_id_with_ui(manager: &GitWorktreeManager, ui: &dyn UserInterface) -> Result<()> {
+    output::print_header(HEADER_COMMIT_ID);
+
+    let worktrees = manager.list_worktrees()?;
+    if worktrees.is_empty() {
+        let msg = WARNING_NO_WORKTREES.yellow();
+        println!("{msg}");
+        println!();
+        press_any_key_to_continue()?;
+        return Ok(());
+    }
+
+    let items: Vec<String> = worktrees
+        .iter()
+        .map(|w| format!("{} ({})", w.name, w.branch))
+        .collect();
+    let worktree = match ui.select_with_default(
+        PROMPT_SELECT_WORKTREE_FOR_SHA,
+        &items,
+        DEFAULT_MENU_SELECTION,
+    ) {
+        Ok(selection) => &worktrees[selection],
+        Err(_) => return Ok(()),
+    };
+
+    println!();
+    match worktree_commit(worktree) {
+        Ok(commit) => {
+            println!("{}", format_commit_ids(commit));
+            copy_commit_id(commit);
+        }
+        Err(e) => utils::print_error(&e.to_string()),
+    }
+
+    println!();
+    press_any_key_to_continue()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_format_commit_ids() {
+        let commit = CommitInfo {
+            id: "a1b2c3d4".to_string(),
+            full_id: "a1b2c3d4e5f60718293a4b5c6d7e8f9012345678".to_string(),
+            message: "Add feature".to_string(),
+            author: "Dev".to_string(),
+            time: "2024-01-01 10:00".to_string(),
+        };
+        assert_eq!(
+            format_commit_ids(&commit),
+            "a1b2c3d4e5f60718293a4b5c6d7e8f9012345678\na1b2c3d4"
+        );
+    }
+
+    #[test]
+    fn test_worktree_commit_missing() {
+        let worktree = WorktreeInfo::test("empty");
+        let err = worktree_commit(&worktree).unwrap_err();
+        assert!(err.to_string().contains("'empty'"));
+    }
+}
@@ -1,6 +1,8 @@
 use anyhow::{anyhow, Result};
+use chrono::{DateTime, NaiveDateTime, TimeDelta, Utc};
 use colored::*;
-use dialoguer::{Confirm, FuzzySelect, MultiSelect};
+use dialoguer::{Confirm, FuzzySelect};
+use indicatif::{ProgressBar, ProgressStyle};
 use std::process::Command;
 
 /// Configuration for search operations
@@ -24,17 +26,33 @@ pub struct SearchAnalysis {
     pub total_count: usize,
     pub has_current: bool,
 }
+
+use super::delete::{
+    branch_checked_out_in, print_branch_still_checked_out, should_offer_branch_delete,
+};
+use super::focus::list_focused_worktrees;
+use super::open::build_editor_command;
+use super::prune::report_pruned;
+use super::switch::{post_switch_context, record_switch};
+use crate::config::{bare_layout_root, resolve_config_path, Config};
 use crate::constants::{
-    section_header, CONFIG_FILE_NAME, DEFAULT_BRANCH_DETACHED, DEFAULT_EDITOR_UNIX,
-    DEFAULT_EDITOR_WINDOWS, DEFAULT_WORKTREE_CLEANUP_DAYS, EMOJI_DETACHED, EMOJI_FOLDER,
-    EMOJI_HOME, EMOJI_LOCKED, ENV_EDITOR, ENV_VISUAL, GIT_DIR, HEADER_SEARCH_WORKTREES,
-    HOOK_POST_SWITCH, HOOK_PRE_REMOVE, MSG_ALREADY_IN_WORKTREE, MSG_NO_WORKTREES_TO_SEARCH,
-    MSG_SEARCH_FUZZY_ENABLED, PROMPT_SELECT_WORKTREE_SWITCH, SEARCH_CURRENT_INDICATOR,
+    BATCH_PROGRESS_CHARS, BATCH_PROGRESS_TEMPLATE, CONFIG_FILE_NAME, CONFIG_TEMPLATE,
+    DEFAULT_BRANCH_DETACHED, DEFAULT_WORKTREE_CLEANUP_DAYS, EMOJI_DETACHED, EMOJI_FOLDER,
+    EMOJI_HOME, EMOJI_LOCKED, GLOB_METACHARACTERS, HEADER_SEARCH_WORKTREES, HOOK_POST_REMOVE,
+    HOOK_POST_SWITCH, HOOK_PRE_REMOVE, ICON_ERROR, ICON_SUCCESS, MSG_ALREADY_IN_WORKTREE,
+    MSG_DRY_RUN_NOTHING_DELETED, MSG_LOCKED_WORKTREES_SKIPPED, MSG_NO_WORKTREES_TO_SEARCH,
+    MSG_PRESS_ESC_TO_STOP_BATCH, MSG_SEARCH_FUZZY_ENABLED, MSG_SKIPPING_DIRTY,
+    MSG_WOULD_DELETE_BRANCH, MSG_WOULD_DELETE_WORKTREE, MSG_WOULD_PRUNE_ENTRIES,
+    PROMPT_FORCE_DELETE_DIRTY, PROMPT_PRUNE_MISSING, PROMPT_SELECT_WORKTREES_TO_DELETE,
+    PROMPT_SELECT_WORKTREE_SWITCH, SEARCH_CURRENT_INDICATOR, TIME_FORMAT, WARNING_DIRTY_WORKTREES,
+    WARNING_WORKTREE_MISSING,
 };
 use crate::git::{GitWorktreeManager, WorktreeInfo};
+use crate::git_interface::GitOperations;
 use crate::hooks::{self, HookContext};
-use crate::input_esc_raw::input_esc_with_default_raw as input_esc_with_default;
-use crate::utils::{self, get_theme, press_any_key_to_continue, write_switch_path};
+use crate::input_esc_raw::escape_pressed;
+use crate::ui::{DialoguerUI, UserInterface};
+use crate::utils::{self, get_theme, output, press_any_key_to_continue, write_switch_path};
 
 /// Pure business logic for creating search items
 pub fn create_search_items(worktrees: &[WorktreeInfo]) -> SearchAnalysis {
@@ -58,6 +76,30 @@ pub fn create_search_items(worktrees: &[WorktreeInfo]) -> SearchAnalysis {
     }
 }
 
+/// Pure business logic for filtering worktrees by name or branch
+///
+/// A pattern containing `*`, `?` or `[` is matched as a glob against the
+/// whole name or branch (e.g. `feature/*`); any other pattern matches as a
+/// substring (e.g. `login`). An empty pattern keeps every worktree.
+pub fn filter_worktrees(worktrees: Vec<WorktreeInfo>, pattern: &str) -> Vec<WorktreeInfo> {
+    if pattern.is_empty() {
+        return worktrees;
+    }
+
+    let glob = pattern
+        .contains(GLOB_METACHARACTERS)
+        .then(|| glob::Pattern::new(pattern).ok())
+        .flatten();
+    let matches = |text: &str| match &glob {
+        Some(glob) => glob.matches(text),
+        None => text.contains(pattern),
+    };
+    worktrees
+        .into_iter()
+        .filter(|w| matches(&w.name) || matches(&w.branch))
+        .collect()
+}
+
 /// Pure business logic for validating search selection
 pub fn validate_search_selection(
     worktrees: &[WorktreeInfo],
@@ -75,11 +117,69 @@ pub fn validate_search_selection(
 pub fn prepare_batch_delete_items(worktrees: &[WorktreeInfo]) -> Vec<String> {
     worktrees
         .iter()
-        .filter(|w| !w.is_current)
+        .filter(|w| !w.is_current && !w.is_main)
         .map(|w| format!("{} ({})", w.name, w.branch))
         .collect()
 }
 
+/// Result of a batch operation that can be interrupted between items
+#[derive(Debug, Clone)]
+pub struct BatchOutcome<T> {
+    /// Items that were handed to the operation
+    pub processed: Vec<T>,
+    /// Items left untouched because the batch was interrupted
+    pub remaining: Vec<T>,
+    /// Whether the batch stopped before reaching the last item
+    pub interrupted: bool,
+}
+
+/// Pure business logic for running a batch that can be stopped between items
+///
+/// `should_stop` is consulted before every item except the first, so the
+/// item currently being processed always completes and nothing after the
+/// interruption point is touched.
+pub fn run_interruptible_batch<T: Clone>(
+    items: &[T],
+    mut should_stop: impl FnMut() -> bool,
+    mut process: impl FnMut(&T),
+) -> BatchOutcome<T> {
+    for (index, item) in items.iter().enumerate() {
+        if index > 0 && should_stop() {
+            return BatchOutcome {
+                processed: items[..index].to_vec(),
+                remaining: items[index..].to_vec(),
+                interrupted: true,
+            };
+        }
+        process(item);
+    }
+
+    BatchOutcome {
+        processed: items.to_vec(),
+        remaining: Vec::new(),
+        interrupted: false,
+    }
+}
+
+/// Creates the `x/N` progress bar shown while a batch is processed
+///
+/// Lines printed during the batch go through [`ProgressBar::println`] or
+/// [`ProgressBar::suspend`] so they appear above the bar. The bar is hidden
+/// when stderr is not a terminal or with `--quiet`.
+pub fn batch_progress_bar(len: usize) -> ProgressBar {
+    if output::is_quiet() {
+        return ProgressBar::hidden();
+    }
+    let pb = ProgressBar::new(len as u64);
+    pb.set_style(
+        ProgressStyle::default_bar()
+            .template(BATCH_PROGRESS_TEMPLATE)
+            .unwrap()
+            .progress_chars(BATCH_PROGRESS_CHARS),
+    );
+    pb
+}
+
 /// Searches and switches to worktrees using fuzzy search
 ///
 /// Provides an interactive fuzzy search interface for finding and switching
@@ -113,7 +213,7 @@ pub fn search_worktrees() -> Result<bool> {
 /// Returns `true` if a worktree was selected and switched to, `false` otherwise
 /// (includes ESC cancellation or selecting current worktree).
 fn search_worktrees_internal(manager: &GitWorktreeManager) -> Result<bool> {
-    let worktrees = manager.list_worktrees()?;
+    let worktrees = list_focused_worktrees(manager)?;
 
     if worktrees.is_empty() {
         println!();
@@ -124,10 +224,7 @@ fn search_worktrees_internal(manager: &GitWorktreeManager) -> Result<bool> {
         return Ok(false);
     }
 
-    println!();
-    let header = section_header(HEADER_SEARCH_WORKTREES);
-    println!("{header}");
-    println!();
+    output::print_header(HEADER_SEARCH_WORKTREES);
 
     // Use business logic to create search items
     let analysis = create_search_items(&worktrees);
@@ -170,12 +267,14 @@ fn search_worktrees_internal(manager: &GitWorktreeManager) -> Result<bool> {
     println!("  {branch_label} {branch_name}");
 
     // Execute post-switch hooks
+    let previous = record_switch(manager, &selected_worktree.path);
     if let Err(e) = hooks::execute_hooks(
         HOOK_POST_SWITCH,
-        &HookContext {
-            worktree_name: selected_worktree.name.clone(),
-            worktree_path: selected_worktree.path.clone(),
-        },
+        &post_switch_context(
+            &selected_worktree.name,
+            &selected_worktree.path,
+            previous.as_ref(),
+        ),
     ) {
         utils::print_warning(&format!("Hook execution warning: {e}"));
     }
@@ -207,9 +306,11 @@ fn search_worktrees_internal(manager: &GitWorktreeManager) -> Result<bool> {
 ///
 /// # Branch Management
 ///
-/// - Uses `is_branch_unique_to_worktree` to identify orphaned branches
+/// - Uses [`orphaned_branches`] to identify orphaned branches, including a
+///   branch shared by several selected worktrees
 /// - Lists orphaned branches separately in the summary
-/// - Only deletes branches for successfully deleted worktrees
+/// - Only deletes branches for successfully deleted worktrees, and keeps
+///   (with a note) any that a remaining worktree still has checked out
 /// - Reports branch deletion results separately
 ///
 /// # Safety
@@ -217,7 +318,8 @@ fn search_worktrees_internal(manager: &GitWorktreeManager) -> Result<bool> {
 /// - Cannot select/delete the current worktree
 /// - Shows comprehensive summary before deletion
 /// - Separate confirmations for worktrees and branches
-/// - Executes pre-remove hooks for each worktree
+/// - Executes pre-remove hooks for each worktree, and post-remove hooks
+///   after each successful deletion
 /// - Continues with remaining deletions if one fails
 ///
 /// # Returns
@@ -228,23 +330,33 @@ fn search_worktrees_internal(manager: &GitWorktreeManager) -> Result<bool> {
 /// # Errors
 ///
 /// Returns an error only if the operation cannot start (e.g., repository access fails).
-pub fn batch_delete_worktrees() -> Result<()> {
+pub fn batch_delete_worktrees(dry_run: bool) -> Result<()> {
     let manager = GitWorktreeManager::new()?;
-    batch_delete_worktrees_internal(&manager)
+    let ui = DialoguerUI;
+    batch_delete_worktrees_with_ui(&manager, &ui, dry_run)
 }
 
-/// Internal implementation of batch_delete_worktrees
+/// Internal implementation of batch_delete_worktrees with dependency injection
 ///
 /// # Arguments
 ///
-/// * `manager` - Git worktree manager instance
+/// * `manager` - Git operations ([`GitWorktreeManager`] outside of tests)
+/// * `ui` - User interface implementation for testability
+/// * `dry_run` - Ask the same questions, then print the resulting
+///   [`BatchDeletePlan`] instead of deleting anything or running hooks
 ///
 /// # Implementation Details
 ///
-/// Uses dialoguer's MultiSelect for the selection interface and provides
+/// Uses a multi-selection prompt for the selection interface and provides
 /// comprehensive feedback during the deletion process. The function handles
 /// errors gracefully and continues with remaining deletions even if some fail.
-fn batch_delete_worktrees_internal(manager: &GitWorktreeManager) -> Result<()> {
+/// Pressing ESC between deletions stops the batch; worktrees that were not
+/// reached yet are reported and left untouched.
+pub fn batch_delete_worktrees_with_ui(
+    manager: &dyn GitOperations,
+    ui: &dyn UserInterface,
+    dry_run: bool,
+) -> Result<()> {
     let worktrees = manager.list_worktrees()?;
 
     if worktrees.is_empty() {
@@ -256,9 +368,16 @@ fn batch_delete_worktrees_internal(manager: &GitWorktreeManager) -> Result<()> {
         return Ok(());
     }
 
-    // Filter out current worktree
-    let deletable_worktrees: Vec<&WorktreeInfo> =
-        worktrees.iter().filter(|w| !w.is_current).collect();
+    // Filter out the current and main worktrees; locked ones must be unlocked first
+    let deletable_worktrees: Vec<&WorktreeInfo> = worktrees
+        .iter()
+        .filter(|w| !w.is_current && !w.is_main && !w.is_locked)
+        .collect();
+    let locked_names: Vec<&str> = worktrees
+        .iter()
+        .filter(|w| !w.is_current && !w.is_main && w.is_locked)
+        .map(|w| w.name.as_str())
+        .collect();
 
     if deletable_worktrees.is_empty() {
         println!();
@@ -266,32 +385,31 @@ fn batch_delete_worktrees_internal(manager: &GitWorktreeManager) -> Result<()> {
         println!("{msg}");
         println!(
             "{}",
-            "  (Cannot delete the current worktree)".bright_black()
+            "  (Cannot delete the current or main worktree)".bright_black()
         );
+        if !locked_names.is_empty() {
+            let note = MSG_LOCKED_WORKTREES_SKIPPED.replace("{}", &locked_names.join(", "));
+            println!("  {}", note.bright_black());
+        }
         println!();
         press_any_key_to_continue()?;
         return Ok(());
     }
 
-    println!();
-    let header = section_header("Batch Delete Worktrees");
-    println!("{header}");
-    println!();
+    output::print_header("Batch Delete Worktrees");
+    if !locked_names.is_empty() {
+        let note = MSG_LOCKED_WORKTREES_SKIPPED.replace("{}", &locked_names.join(", "));
+        println!("{}", note.bright_black());
+        println!();
+    }
 
     let items: Vec<String> = deletable_worktrees
         .iter()
         .map(|w| format!("{} ({})", w.name, w.branch))
         .collect();
 
-    let selections = MultiSelect::with_theme(&get_theme())
-        .with_prompt(
-            "Select worktrees to delete (Space to toggle, Enter to confirm, ESC to cancel)",
-        )
-        .items(&items)
-        .interact_opt()?;
-
-    let selections = match selections {
-        Some(s) if !s.is_empty() => s,
+    let selections = match ui.multiselect(PROMPT_SELECT_WORKTREES_TO_DELETE, &items) {
+        Ok(s) if !s.is_empty() => s,
         _ => return Ok(()),
     };
 
@@ -299,12 +417,7 @@ fn batch_delete_worktrees_internal(manager: &GitWorktreeManager) -> Result<()> {
         selections.iter().map(|&i| deletable_worktrees[i]).collect();
 
     // Check for branches that will become orphaned
-    let mut branches_to_delete = Vec::new();
-    for wt in &selected_worktrees {
-        if manager.is_branch_unique_to_worktree(&wt.branch, &wt.name)? {
-            branches_to_delete.push((wt.branch.clone(), wt.name.clone()));
-        }
-    }
+    let branches_to_delete = orphaned_branches(&selected_worktrees, &worktrees);
 
     // Show summary
     println!();
@@ -340,10 +453,8 @@ fn batch_delete_worktrees_internal(manager: &GitWorktreeManager) -> Result<()> {
     }
     println!();
 
-    let confirm = Confirm::with_theme(&get_theme())
-        .with_prompt("Are you sure you want to delete these worktrees?")
-        .default(false)
-        .interact_opt()?
+    let confirm = ui
+        .confirm_with_default("Are you sure you want to delete these worktrees?", false)
         .unwrap_or(false);
 
     if !confirm {
@@ -351,62 +462,136 @@ fn batch_delete_worktrees_internal(manager: &GitWorktreeManager) -> Result<()> {
     }
 
     // Ask about branch deletion if there are orphaned branches
-    let delete_branches = if !branches_to_delete.is_empty() {
+    let never_offer = Config::load()
+        .map(|config| config.worktree.never_offer_branch_delete)
+        .unwrap_or(false);
+    let delete_branches = if should_offer_branch_delete(!branches_to_delete.is_empty(), never_offer)
+    {
         println!();
-        Confirm::with_theme(&get_theme())
-            .with_prompt("Also delete the orphaned branches?")
-            .default(false)
-            .interact_opt()?
+        ui.confirm_with_default("Also delete the orphaned branches?", false)
             .unwrap_or(false)
     } else {
         false
     };
 
-    // Delete worktrees
+    // Worktrees with uncommitted changes need a second, explicit confirmation
+    let dirty: Vec<&str> = selected_worktrees
+        .iter()
+        .filter(|wt| wt.has_changes)
+        .map(|wt| wt.name.as_str())
+        .collect();
+    let force_dirty = if dirty.is_empty() {
+        false
+    } else {
+        println!();
+        utils::print_warning(&WARNING_DIRTY_WORKTREES.replace("{}", &dirty.join(", ")));
+        ui.confirm_with_default(
+            &PROMPT_FORCE_DELETE_DIRTY.replace("{}", &dirty.len().to_string()),
+            false,
+        )
+        .unwrap_or(false)
+    };
+
+    let plan = plan_batch_delete(
+        selected_worktrees,
+        &branches_to_delete,
+        delete_branches,
+        force_dirty,
+    );
+    for wt in &plan.skipped_dirty {
+        utils::print_warning(&MSG_SKIPPING_DIRTY.replace("{}", &wt.name));
+    }
+
+    if dry_run {
+        print_dry_run_plan(&plan);
+        println!();
+        press_any_key_to_continue()?;
+        return Ok(());
+    }
+
+    // Delete worktrees, stopping cleanly if ESC is pressed between deletions
     println!();
+    let hint = MSG_PRESS_ESC_TO_STOP_BATCH.bright_black();
+    println!("{hint}");
     let mut success_count = 0;
     let mut error_count = 0;
     let mut deleted_worktrees = Vec::new();
+    let pb = batch_progress_bar(plan.worktrees.len());
+
+    let outcome = run_interruptible_batch(&plan.worktrees, escape_pressed, |wt| {
+        pb.set_message(wt.name.clone());
+
+        // Execute pre-remove hooks; their output goes above the bar
+        pb.suspend(|| {
+            if let Err(e) = hooks::execute_hooks(
+                HOOK_PRE_REMOVE,
+                &HookContext {
+                    worktree_name: wt.name.clone(),
+                    worktree_path: wt.path.clone(),
+                    previous_worktree_name: None,
+                    previous_worktree_path: None,
+                },
+            ) {
+                utils::print_warning(&format!("Hook execution warning: {e}"));
+            }
+        });
 
-    for wt in &selected_worktrees {
-        // Execute pre-remove hooks
-        if let Err(e) = hooks::execute_hooks(
-            HOOK_PRE_REMOVE,
-            &HookContext {
-                worktree_name: wt.name.clone(),
-                worktree_path: wt.path.clone(),
-            },
-        ) {
-            utils::print_warning(&format!("Hook execution warning: {e}"));
-        }
-
-        match manager.remove_worktree(&wt.git_name) {
+        // Dirty worktrees are only left in the batch when forcing was confirmed
+        let result = if wt.has_changes {
+            manager.remove_worktree_force(&wt.git_name)
+        } else {
+            manager.remove_worktree(&wt.git_name)
+        };
+        match result {
             Ok(_) => {
+                let check = ICON_SUCCESS.green();
                 let name_red = wt.name.bright_red();
-                utils::print_success(&format!("Deleted worktree '{name_red}'"));
+                pb.println(format!("{check} Deleted worktree '{name_red}'"));
+                pb.suspend(|| run_post_remove_hooks(wt));
                 deleted_worktrees.push((wt.branch.clone(), wt.name.clone()));
                 success_count += 1;
             }
             Err(e) => {
+                let cross = ICON_ERROR.red();
                 let name = &wt.name;
-                utils::print_error(&format!("Failed to delete '{name}': {e}"));
+                pb.println(format!("{cross} Failed to delete '{name}': {e}"));
                 error_count += 1;
             }
         }
+        pb.inc(1);
+    });
+    pb.finish_and_clear();
+
+    if outcome.interrupted {
+        println!();
+        let remaining_count = outcome.remaining.len();
+        utils::print_warning(&format!(
+            "Stopped by user. {remaining_count} worktree(s) were left untouched:"
+        ));
+        for wt in &outcome.remaining {
+            let name = &wt.name;
+            let branch = &wt.branch;
+            println!("  • {name} ({branch})");
+        }
     }
 
     // Delete branches if requested
-    if delete_branches {
+    if !plan.branches.is_empty() {
         let mut branch_success = 0;
         let mut branch_error = 0;
 
         println!();
-        for (branch, worktree_name) in &branches_to_delete {
+        for (branch, worktree_name) in &plan.branches {
             // Only delete branches for successfully deleted worktrees
             if deleted_worktrees
                 .iter()
                 .any(|(b, w)| b == branch && w == worktree_name)
             {
+                // A worktree left out of the batch may still have it checked out
+                if let Ok(Some(worktree)) = branch_checked_out_in(manager, branch) {
+                    print_branch_still_checked_out(branch, &worktree);
+                    continue;
+                }
                 match manager.delete_branch(branch) {
                     Ok(_) => {
                         let branch_red = branch.bright_red();
@@ -446,41 +631,284 @@ fn batch_delete_worktrees_internal(manager: &GitWorktreeManager) -> Result<()> {
     Ok(())
 }
 
-/// Cleans up old worktrees based on age
+/// What a batch delete or cleanup does once its questions are answered
+#[derive(Debug, Clone, Default)]
+pub struct BatchDeletePlan<'a> {
+    /// Worktrees to delete, clean ones first
+    pub worktrees: Vec<&'a WorktreeInfo>,
+    /// Worktrees with uncommitted changes that are left alone
+    pub skipped_dirty: Vec<&'a WorktreeInfo>,
+    /// Orphaned branches to delete as `(branch, worktree name)`
+    pub branches: Vec<(String, String)>,
+}
+
+/// Pure business logic for the branches a batch delete leaves without a worktree
 ///
-/// **Note**: This feature is currently not implemented and serves as
-/// a placeholder for future functionality.
+/// A branch is orphaned when every worktree in `worktrees` that has it
+/// checked out is selected, so worktrees sharing a branch are deleted
+/// together. Each branch is listed once as `(branch, worktree name)` with
+/// the first selected worktree; detached worktrees have no branch.
+pub fn orphaned_branches(
+    selected: &[&WorktreeInfo],
+    worktrees: &[WorktreeInfo],
+) -> Vec<(String, String)> {
+    let mut orphaned: Vec<(String, String)> = Vec::new();
+    for wt in selected {
+        if wt.branch == DEFAULT_BRANCH_DETACHED
+            || orphaned.iter().any(|(branch, _)| branch == &wt.branch)
+        {
+            continue;
+        }
+        let all_selected = worktrees
+            .iter()
+            .filter(|other| other.branch == wt.branch)
+            .all(|other| selected.iter().any(|s| s.name == other.name));
+        if all_selected {
+            orphaned.push((wt.branch.clone(), wt.name.clone()));
+        }
+    }
+    orphaned
+}
+
+/// Pure business logic for turning a selection into a [`BatchDeletePlan`]
+///
+/// Worktrees with uncommitted changes are only deleted with `force_dirty`.
+/// Orphaned branches are only deleted with `delete_branches`, and only
+/// those whose worktree is deleted.
+pub fn plan_batch_delete<'a>(
+    selected: Vec<&'a WorktreeInfo>,
+    orphaned_branches: &[(String, String)],
+    delete_branches: bool,
+    force_dirty: bool,
+) -> BatchDeletePlan<'a> {
+    let (dirty, clean): (Vec<&WorktreeInfo>, Vec<&WorktreeInfo>) =
+        selected.into_iter().partition(|wt| wt.has_changes);
+    let (worktrees, skipped_dirty) = if force_dirty {
+        (clean.into_iter().chain(dirty).collect(), Vec::new())
+    } else {
+        (clean, dirty)
+    };
+
+    let branches = if delete_branches {
+        orphaned_branches
+            .iter()
+            .filter(|(_, name)| worktrees.iter().any(|wt| &wt.name == name))
+            .cloned()
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    BatchDeletePlan {
+        worktrees,
+        skipped_dirty,
+        branches,
+    }
+}
+
+/// Prints a [`BatchDeletePlan`] in the format of a real run's summary
+fn print_dry_run_plan(plan: &BatchDeletePlan) {
+    println!();
+    let note = MSG_DRY_RUN_NOTHING_DELETED.bright_yellow();
+    println!("{note}");
+    for wt in &plan.worktrees {
+        let name_red = wt.name.bright_red();
+        println!(
+            "  • {}",
+            MSG_WOULD_DELETE_WORKTREE.replace("{}", &name_red.to_string())
+        );
+    }
+    for (branch, _) in &plan.branches {
+        let branch_red = branch.bright_red();
+        println!(
+            "  • {}",
+            MSG_WOULD_DELETE_BRANCH.replace("{}", &branch_red.to_string())
+        );
+    }
+
+    if !plan.branches.is_empty() {
+        println!();
+        println!(
+            "{} Would delete {} branch(es)",
+            "•".bright_green(),
+            plan.branches.len()
+        );
+    }
+
+    println!();
+    println!(
+        "{} Would delete {} worktree(s)",
+        "•".bright_green(),
+        plan.worktrees.len()
+    );
+}
+
+/// Age threshold for cleanup, resolved to a fixed cutoff time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CleanupThreshold {
+    pub days: u64,
+    /// Worktrees created (or last committed to) before this are candidates
+    pub cutoff: DateTime<Utc>,
+}
+
+impl CleanupThreshold {
+    /// Resolves `days` against `now`
+    ///
+    /// Returns `None` if the cutoff would fall outside the supported date range.
+    pub fn new(days: u64, now: DateTime<Utc>) -> Option<Self> {
+        let delta = TimeDelta::try_days(i64::try_from(days).ok()?)?;
+        let cutoff = now.checked_sub_signed(delta)?;
+        Some(Self { days, cutoff })
+    }
+}
+
+/// Worktrees matched by a cleanup threshold
+#[derive(Debug, Clone, Default)]
+pub struct CleanupCandidates<'a> {
+    /// Worktrees older than the cutoff
+    pub old: Vec<&'a WorktreeInfo>,
+    /// Worktrees of unknown age, which are never deleted
+    pub unknown_age: Vec<&'a WorktreeInfo>,
+}
+
+/// Pure business logic for parsing a [`crate::git::CommitInfo`] time back
+///
+/// Commit times are formatted in UTC with [`TIME_FORMAT`].
+pub fn parse_commit_time(time: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(time, TIME_FORMAT)
+        .ok()
+        .map(|time| time.and_utc())
+}
+
+/// Pure business logic for the time a worktree's age is measured from
+///
+/// The creation time is preferred; the last commit time is used for
+/// worktrees whose creation time is unknown.
+pub fn worktree_age_time(worktree: &WorktreeInfo) -> Option<DateTime<Utc>> {
+    worktree.created_at.or_else(|| {
+        worktree
+            .last_commit
+            .as_ref()
+            .and_then(|commit| parse_commit_time(&commit.time))
+    })
+}
+
+/// Pure business logic for selecting worktrees to clean up
 ///
-/// When implemented, this function will:
-/// - Identify worktrees older than a specified number of days
-/// - Show a preview of worktrees to be deleted
-/// - Allow batch deletion of old worktrees
+/// The current and main worktrees and worktrees missing on disk are never
+/// candidates.
+/// Worktrees whose age cannot be determined (see [`worktree_age_time`]) are
+/// reported separately.
+pub fn find_cleanup_candidates<'a>(
+    worktrees: &'a [WorktreeInfo],
+    threshold: &CleanupThreshold,
+) -> CleanupCandidates<'a> {
+    let mut candidates = CleanupCandidates::default();
+    for worktree in worktrees
+        .iter()
+        .filter(|w| !w.is_current && !w.is_main && !w.is_prunable)
+    {
+        match worktree_age_time(worktree) {
+            Some(time) if time < threshold.cutoff => candidates.old.push(worktree),
+            Some(_) => {}
+            None => candidates.unknown_age.push(worktree),
+        }
+    }
+    candidates
+}
+
+/// Offers to prune the entries of worktrees whose directory is missing
 ///
-/// # Current Behavior
+/// Such worktrees only exist in Git's metadata, so deleting them normally
+/// fails; `git worktree prune` removes the stale entries instead.
+fn offer_prune_missing(
+    manager: &GitWorktreeManager,
+    ui: &dyn UserInterface,
+    missing: &[&WorktreeInfo],
+    dry_run: bool,
+) -> Result<()> {
+    for wt in missing {
+        let path = wt.path.display().to_string();
+        utils::print_warning(
+            &WARNING_WORKTREE_MISSING
+                .replacen("{}", &wt.name, 1)
+                .replacen("{}", &path, 1),
+        );
+    }
+
+    println!();
+    let confirm = ui
+        .confirm_with_default(
+            &PROMPT_PRUNE_MISSING.replace("{}", &missing.len().to_string()),
+            true,
+        )
+        .unwrap_or(false);
+    if !confirm {
+        return Ok(());
+    }
+
+    if dry_run {
+        let msg = MSG_WOULD_PRUNE_ENTRIES.replace("{}", &missing.len().to_string());
+        println!("{}", msg.bright_yellow());
+        println!();
+        return Ok(());
+    }
+
+    match manager.prune_worktrees(false) {
+        Ok(pruned) => report_pruned(&pruned),
+        Err(e) => utils::print_error(&e.to_string()),
+    }
+    println!();
+    Ok(())
+}
+
+/// Runs post-remove hooks for a worktree that was just deleted
+fn run_post_remove_hooks(worktree: &WorktreeInfo) {
+    if let Err(e) = hooks::execute_hooks(
+        HOOK_POST_REMOVE,
+        &HookContext {
+            worktree_name: worktree.name.clone(),
+            worktree_path: worktree.path.clone(),
+            previous_worktree_name: None,
+            previous_worktree_path: None,
+        },
+    ) {
+        utils::print_warning(&format!("Hook execution warning: {e}"));
+    }
+}
+
+/// Cleans up old worktrees based on age
 ///
-/// Displays a message indicating the feature is not yet implemented.
+/// Offers every worktree older than a number of days for deletion.
+/// Worktrees whose directory is missing on disk are offered for pruning
+/// first. The age is the time git-workers created the worktree; worktrees
+/// created without it fall back to their last commit, and ones with
+/// neither are skipped with a warning. Worktrees with uncommitted changes
+/// are not deleted.
 ///
 /// # Returns
 ///
-/// Always returns `Ok(())` after displaying the message.
-pub fn cleanup_old_worktrees() -> Result<()> {
+/// Returns `Ok(())` after the cleanup completes or is cancelled.
+pub fn cleanup_old_worktrees(dry_run: bool) -> Result<()> {
     let manager = GitWorktreeManager::new()?;
-    cleanup_old_worktrees_internal(&manager)
+    let ui = DialoguerUI;
+    cleanup_old_worktrees_with_ui(&manager, &ui, dry_run)
 }
 
-/// Internal implementation of cleanup_old_worktrees
+/// Internal implementation of cleanup_old_worktrees with dependency injection
 ///
 /// # Arguments
 ///
 /// * `manager` - Git worktree manager instance
+/// * `ui` - User interface implementation for testability
+/// * `dry_run` - Print what would be pruned and deleted instead of doing it
 ///
-/// # Future Implementation
-///
-/// Will require:
-/// - Tracking worktree creation dates (possibly in .git-workers.toml)
-/// - Age calculation logic
-/// - Preview and confirmation UI
-fn cleanup_old_worktrees_internal(manager: &GitWorktreeManager) -> Result<()> {
+/// Worktrees with uncommitted changes are skipped.
+pub fn cleanup_old_worktrees_with_ui(
+    manager: &GitWorktreeManager,
+    ui: &dyn UserInterface,
+    dry_run: bool,
+) -> Result<()> {
     let worktrees = manager.list_worktrees()?;
 
     if worktrees.is_empty() {
@@ -492,32 +920,161 @@ fn cleanup_old_worktrees_internal(manager: &GitWorktreeManager) -> Result<()> {
         return Ok(());
     }
 
-    println!();
-    let header = section_header("Cleanup Old Worktrees");
-    println!("{header}");
-    println!();
+    output::print_header("Cleanup Old Worktrees");
+
+    let missing: Vec<&WorktreeInfo> = worktrees.iter().filter(|w| w.is_prunable).collect();
+    if !missing.is_empty() {
+        offer_prune_missing(manager, ui, &missing, dry_run)?;
+    }
 
     // Get age threshold
-    let _days = match input_esc_with_default(
+    let days = match ui.input_with_default(
         "Delete worktrees older than (days)",
         DEFAULT_WORKTREE_CLEANUP_DAYS,
     ) {
-        Some(days_str) => match days_str.parse::<u64>() {
+        Ok(days_str) => match days_str.trim().parse::<u64>() {
             Ok(d) => d,
             Err(_) => {
                 utils::print_error("Invalid number");
                 return Ok(());
             }
         },
-        None => return Ok(()),
+        Err(_) => return Ok(()),
     };
+    let threshold = match CleanupThreshold::new(days, Utc::now()) {
+        Some(threshold) => threshold,
+        None => {
+            utils::print_error("Invalid number");
+            return Ok(());
+        }
+    };
+    let cutoff = threshold.cutoff.format(TIME_FORMAT).to_string();
+
+    let candidates = find_cleanup_candidates(&worktrees, &threshold);
+
+    if !candidates.unknown_age.is_empty() {
+        println!();
+        for wt in &candidates.unknown_age {
+            let name = &wt.name;
+            utils::print_warning(&format!("Skipping '{name}': age is unknown"));
+        }
+    }
+
+    if candidates.old.is_empty() {
+        println!();
+        let msg = format!("• No worktrees older than {cutoff} (UTC).").yellow();
+        println!("{msg}");
+        println!();
+        press_any_key_to_continue()?;
+        return Ok(());
+    }
+
+    let items: Vec<String> = candidates
+        .old
+        .iter()
+        .map(|w| match (w.created_at, &w.last_commit) {
+            (Some(created), _) => {
+                let time = utils::format_relative_time(created);
+                format!("{} ({}, created {time})", w.name, w.branch)
+            }
+            (None, commit) => {
+                let time = commit
+                    .as_ref()
+                    .map(|commit| {
+                        parse_commit_time(&commit.time)
+                            .map(utils::format_relative_time)
+                            .unwrap_or_else(|| commit.time.clone())
+                    })
+                    .unwrap_or_default();
+                format!("{} ({}, last commit {time})", w.name, w.branch)
+            }
+        })
+        .collect();
+
+    println!();
+    let selections = match ui.multiselect(PROMPT_SELECT_WORKTREES_TO_DELETE, &items) {
+        Ok(s) if !s.is_empty() => s,
+        _ => return Ok(()),
+    };
+
+    let selected_worktrees: Vec<&WorktreeInfo> =
+        selections.iter().map(|&i| candidates.old[i]).collect();
+
+    println!();
+    let selected_count = selected_worktrees.len();
+    let confirm = ui
+        .confirm_with_default(
+            &format!(
+                "Delete {selected_count} worktree(s) older than {cutoff} (UTC, {days} days ago)?"
+            ),
+            false,
+        )
+        .unwrap_or(false);
+
+    if !confirm {
+        return Ok(());
+    }
+
+    let plan = plan_batch_delete(selected_worktrees, &[], false, false);
+    for wt in &plan.skipped_dirty {
+        utils::print_warning(&MSG_SKIPPING_DIRTY.replace("{}", &wt.name));
+    }
+
+    if dry_run {
+        print_dry_run_plan(&plan);
+        println!();
+        press_any_key_to_continue()?;
+        return Ok(());
+    }
+
+    println!();
+    let hint = MSG_PRESS_ESC_TO_STOP_BATCH.bright_black();
+    println!("{hint}");
+    let mut success_count = 0;
+    let mut error_count = 0;
+
+    let outcome = run_interruptible_batch(&plan.worktrees, escape_pressed, |wt| {
+        if let Err(e) = hooks::execute_hooks(
+            HOOK_PRE_REMOVE,
+            &HookContext {
+                worktree_name: wt.name.clone(),
+                worktree_path: wt.path.clone(),
+                previous_worktree_name: None,
+                previous_worktree_path: None,
+            },
+        ) {
+            utils::print_warning(&format!("Hook execution warning: {e}"));
+        }
+
+        match manager.remove_worktree(&wt.git_name) {
+            Ok(_) => {
+                let name_red = wt.name.bright_red();
+                utils::print_success(&format!("Deleted worktree '{name_red}'"));
+                run_post_remove_hooks(wt);
+                success_count += 1;
+            }
+            Err(e) => {
+                let name = &wt.name;
+                utils::print_error(&format!("Failed to delete '{name}': {e}"));
+                error_count += 1;
+            }
+        }
+    });
+
+    if outcome.interrupted {
+        println!();
+        let remaining_count = outcome.remaining.len();
+        utils::print_warning(&format!(
+            "Stopped by user. {remaining_count} worktree(s) were left untouched"
+        ));
+    }
 
-    // Find old worktrees (mock implementation - would need actual age tracking)
     println!();
-    utils::print_warning("Age-based cleanup is not yet implemented.");
     println!(
-        "{}",
-        "This feature requires tracking worktree creation dates.".bright_black()
+        "{} Deleted {} worktree(s), {} failed",
+        "•".bright_green(),
+        success_count,
+        error_count
     );
 
     println!();
@@ -553,10 +1110,13 @@ fn cleanup_old_worktrees_internal(manager: &GitWorktreeManager) -> Result<()> {
 ///
 /// # Editor Selection
 ///
-/// Uses the following priority for editor selection:
-/// 1. `EDITOR` environment variable
-/// 2. `VISUAL` environment variable
-/// 3. Platform default (vi on Unix, notepad on Windows)
+/// Uses the following priority for editor selection (see
+/// [`utils::resolve_editor`]):
+/// 1. `--editor` (`gw hooks edit` only)
+/// 2. `[editor] command` in the configuration
+/// 3. `EDITOR` environment variable
+/// 4. `VISUAL` environment variable
+/// 5. Platform default (vi on Unix, notepad on Windows)
 ///
 /// # File Creation
 ///
@@ -583,20 +1143,35 @@ fn cleanup_old_worktrees_internal(manager: &GitWorktreeManager) -> Result<()> {
 /// - Cannot determine configuration file location
 /// - Editor fails to launch
 pub fn edit_hooks() -> Result<()> {
+    if git2::Repository::discover(".").is_err() {
+        output::print_header("Edit Hooks Configuration");
+        utils::print_error("Not in a git repository");
+    } else {
+        edit_hooks_with_editor(None)?;
+    }
+
     println!();
-    let header = section_header("Edit Hooks Configuration");
-    println!("{header}");
-    println!();
+    press_any_key_to_continue()?;
+
+    Ok(())
+}
+
+/// Edits the hooks configuration file with a specific editor (`gw hooks edit`)
+///
+/// Same as [`edit_hooks`] without waiting for a key press at the end.
+/// `editor_override` (`--editor`) takes precedence over the configured
+/// editor and may include arguments, e.g. `code --wait`.
+///
+/// # Errors
+///
+/// Returns an error if not in a Git repository or the configuration file
+/// location cannot be determined.
+pub fn edit_hooks_with_editor(editor_override: Option<&str>) -> Result<()> {
+    output::print_header("Edit Hooks Configuration");
 
     // Find the config file location using the same logic as Config::load()
-    let config_path = if let Ok(repo) = git2::Repository::discover(".") {
-        find_config_file_path_internal(&repo)?
-    } else {
-        utils::print_error("Not in a git repository");
-        println!();
-        press_any_key_to_continue()?;
-        return Ok(());
-    };
+    let repo = git2::Repository::discover(".")?;
+    let config_path = find_config_file_path_internal(&repo)?;
 
     // Create the file if it doesn't exist
     if !config_path.exists() {
@@ -611,43 +1186,7 @@ pub fn edit_hooks() -> Result<()> {
             .unwrap_or(false);
 
         if create {
-            // Create a template configuration
-            let template = r#"# Git Workers configuration file
-
-[repository]
-# Repository URL for identification (optional)
-# This ensures hooks only run in the intended repository
-# url = "https://github.com/owner/repo.git"
-
-[hooks]
-# Run after creating a new worktree
-post-create = [
-    # "npm install",
-    # "cp .env.example .env"
-]
-
-# Run before removing a worktree
-pre-remove = [
-    # "rm -rf node_modules"
-]
-
-# Run after switching to a worktree
-post-switch = [
-    # "echo 'Switched to {{worktree_name}}'"
-]
-
-[files]
-# Optional: Specify a custom source directory
-# If not specified, automatically finds the main worktree
-# source = "/path/to/custom/source"
-# source = "./templates"  # Relative to repository root
-
-# Files to copy when creating new worktrees
-copy = [
-    # ".env",
-    # ".env.local"
-]
-"#;
+            let template = CONFIG_TEMPLATE;
 
             std::fs::write(&config_path, template)?;
             utils::print_success(&format!("Created {CONFIG_FILE_NAME} with template"));
@@ -657,26 +1196,20 @@ copy = [
     }
 
     // Get the user's preferred editor
-    let editor = std::env::var(ENV_EDITOR)
-        .or_else(|_| std::env::var(ENV_VISUAL))
-        .unwrap_or_else(|_| {
-            if cfg!(target_os = "windows") {
-                DEFAULT_EDITOR_WINDOWS.to_string()
-            } else {
-                DEFAULT_EDITOR_UNIX.to_string()
-            }
-        });
+    let editor = utils::resolve_editor(editor_override);
+    let args = build_editor_command(&editor, &config_path)?;
+    let program = &args[0];
 
     println!(
         "{} Opening {} with {}...",
         "•".bright_blue(),
         config_path.display().to_string().bright_white(),
-        editor.bright_yellow()
+        program.bright_yellow()
     );
     println!();
 
     // Open the editor
-    let status = Command::new(&editor).arg(&config_path).status();
+    let status = Command::new(program).args(&args[1..]).status();
 
     match status {
         Ok(status) if status.success() => {
@@ -694,9 +1227,6 @@ copy = [
         }
     }
 
-    println!();
-    press_any_key_to_continue()?;
-
     Ok(())
 }
 
@@ -708,59 +1238,27 @@ pub fn find_config_file_path(manager: &GitWorktreeManager) -> Result<std::path::
     find_config_file_path_internal(manager.repo())
 }
 
-// Helper function to find config file path with the same logic as Config::load()
+/// Finds the configuration file with the same discovery as `Config::load()`
+///
+/// When there is no file yet, returns where a new one goes: the root of a
+/// `.bare` layout, otherwise the current directory.
 pub fn find_config_file_path_internal(repo: &git2::Repository) -> Result<std::path::PathBuf> {
-    if repo.is_bare() {
-        // For bare repositories - use complex discovery logic
-        if let Ok(cwd) = std::env::current_dir() {
-            // 1. Check current directory first
-            let current_config = cwd.join(CONFIG_FILE_NAME);
-            if current_config.exists() {
-                return Ok(current_config);
-            }
-
-            // Default: use current directory for creation
-            Ok(cwd.join(CONFIG_FILE_NAME))
-        } else {
-            // Can't get current directory
-            Err(anyhow::anyhow!("Cannot determine current directory"))
-        }
-    } else {
-        // For non-bare repositories - same logic as Config::load_from_main_repository_only()
-        if let Ok(cwd) = std::env::current_dir() {
-            // 1. First check current directory
-            let current_config = cwd.join(CONFIG_FILE_NAME);
-            if current_config.exists() {
-                return Ok(current_config);
-            }
-
-            // 2. Check if this is the main worktree
-            if let Some(workdir) = repo.workdir() {
-                let workdir_path = workdir.to_path_buf();
-
-                // Check if current directory is the main worktree
-                if cwd == workdir_path {
-                    return Ok(workdir_path.join(CONFIG_FILE_NAME));
-                }
+    if let Some(path) = resolve_config_path(repo) {
+        return Ok(path);
+    }
 
-                // If not, check if the main worktree exists
-                let git_path = workdir_path.join(GIT_DIR);
-                if git_path.is_dir() && workdir_path.exists() {
-                    let config_path = workdir_path.join(CONFIG_FILE_NAME);
-                    if config_path.exists() {
-                        return Ok(config_path);
-                    }
-                }
-            }
+    // In a `.bare` layout the file belongs in the layout root
+    if let Some(root) = bare_layout_root(repo.commondir()) {
+        return Ok(root.join(CONFIG_FILE_NAME));
+    }
 
-            // Default: use current directory for creation
-            Ok(cwd.join(CONFIG_FILE_NAME))
-        } else {
-            // Final fallback: use repository working directory
-            repo.workdir()
-                .map(|p| p.join(CONFIG_FILE_NAME))
-                .ok_or_else(|| anyhow::anyhow!("No working directory found"))
-        }
+    match std::env::current_dir() {
+        Ok(cwd) => Ok(cwd.join(CONFIG_FILE_NAME)),
+        // Final fallback: use repository working directory
+        Err(_) => repo
+            .workdir()
+            .map(|p| p.join(CONFIG_FILE_NAME))
+            .ok_or_else(|| anyhow::anyhow!("No working directory found")),
     }
 }
 
@@ -845,15 +1343,10 @@ mod tests {
     #[test]
     fn test_create_search_items() -> Result<()> {
         let worktree_info = WorktreeInfo {
-            name: "feature-branch".to_string(),
-            git_name: "feature-branch".to_string(),
             path: std::path::PathBuf::from("/test/feature-branch"),
             branch: "feature/test".to_string(),
             is_current: true,
-            is_locked: false,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
+            ..WorktreeInfo::test("feature-branch")
         };
         let worktrees = vec![worktree_info];
 
@@ -872,15 +1365,9 @@ mod tests {
     #[test]
     fn test_validate_search_selection() -> Result<()> {
         let worktree_info = WorktreeInfo {
-            name: "feature-branch".to_string(),
-            git_name: "feature-branch".to_string(),
             path: std::path::PathBuf::from("/test/feature-branch"),
             branch: "feature/test".to_string(),
-            is_current: false,
-            is_locked: false,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
+            ..WorktreeInfo::test("feature-branch")
         };
         let worktrees = vec![worktree_info];
 
@@ -899,26 +1386,14 @@ mod tests {
     fn test_prepare_batch_delete_items() -> Result<()> {
         let worktrees = vec![
             WorktreeInfo {
-                name: "main".to_string(),
-                git_name: "main".to_string(),
                 path: std::path::PathBuf::from("/test/main"),
-                branch: "main".to_string(),
                 is_current: true,
-                is_locked: false,
-                has_changes: false,
-                last_commit: None,
-                ahead_behind: None,
+                ..WorktreeInfo::test("main")
             },
             WorktreeInfo {
-                name: "feature-branch".to_string(),
-                git_name: "feature-branch".to_string(),
                 path: std::path::PathBuf::from("/test/feature-branch"),
                 branch: "feature/test".to_string(),
-                is_current: false,
-                is_locked: false,
-                has_changes: false,
-                last_commit: None,
-                ahead_behind: None,
+                ..WorktreeInfo::test("feature-branch")
             },
         ];
 
@@ -932,4 +1407,211 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_batch_progress_bar_counts_items() {
+        let pb = batch_progress_bar(3);
+        assert_eq!(pb.length(), Some(3));
+        pb.inc(1);
+        assert_eq!(pb.position(), 1);
+        pb.finish_and_clear();
+    }
+
+    #[test]
+    fn test_run_interruptible_batch_completes() {
+        let items = vec!["a", "b", "c"];
+        let mut seen = Vec::new();
+
+        let outcome = run_interruptible_batch(&items, || false, |item| seen.push(*item));
+
+        assert!(!outcome.interrupted);
+        assert_eq!(seen, vec!["a", "b", "c"]);
+        assert_eq!(outcome.processed, items);
+        assert!(outcome.remaining.is_empty());
+    }
+
+    #[test]
+    fn test_run_interruptible_batch_stops_early_and_preserves_rest() {
+        let items = vec!["a", "b", "c", "d"];
+        let mut seen = Vec::new();
+        let mut checks = 0;
+
+        // Request a stop at the second check, i.e. after "a" and "b" are done
+        let outcome = run_interruptible_batch(
+            &items,
+            || {
+                checks += 1;
+                checks == 2
+            },
+            |item| seen.push(*item),
+        );
+
+        assert!(outcome.interrupted);
+        assert_eq!(seen, vec!["a", "b"]);
+        assert_eq!(outcome.processed, vec!["a", "b"]);
+        assert_eq!(outcome.remaining, vec!["c", "d"]);
+    }
+
+    #[test]
+    fn test_run_interruptible_batch_always_processes_first_item() {
+        let items = vec![1, 2];
+        let mut seen = Vec::new();
+
+        let outcome = run_interruptible_batch(&items, || true, |item| seen.push(*item));
+
+        assert!(outcome.interrupted);
+        assert_eq!(seen, vec![1]);
+        assert_eq!(outcome.remaining, vec![2]);
+    }
+
+    fn worktree_with_commit(name: &str, time: Option<&str>, is_current: bool) -> WorktreeInfo {
+        WorktreeInfo {
+            path: std::path::PathBuf::from(format!("/test/{name}")),
+            is_current,
+            last_commit: time.map(|time| crate::git::CommitInfo {
+                id: "abcdef12".to_string(),
+                full_id: "abcdef1200000000000000000000000000000000".to_string(),
+                message: "message".to_string(),
+                author: "author".to_string(),
+                time: time.to_string(),
+            }),
+            ..WorktreeInfo::test(name)
+        }
+    }
+
+    #[test]
+    fn test_filter_worktrees() {
+        let mut login = worktree_with_commit("login", None, false);
+        login.branch = "feature/login".to_string();
+        let worktrees = vec![
+            login,
+            worktree_with_commit("feature/search", None, false),
+            worktree_with_commit("hotfix", None, false),
+        ];
+        let names = |pattern: &str| -> Vec<String> {
+            filter_worktrees(worktrees.clone(), pattern)
+                .into_iter()
+                .map(|w| w.name)
+                .collect()
+        };
+
+        assert_eq!(names("feature/*"), vec!["login", "feature/search"]);
+        assert_eq!(names("login"), vec!["login"]);
+        assert_eq!(names("h?tfix"), vec!["hotfix"]);
+        assert_eq!(names(""), vec!["login", "feature/search", "hotfix"]);
+        assert!(names("release/*").is_empty());
+        assert!(names("nothing").is_empty());
+    }
+
+    #[test]
+    fn test_plan_batch_delete() {
+        let clean = worktree_with_commit("clean", None, false);
+        let dirty = WorktreeInfo {
+            has_changes: true,
+            ..worktree_with_commit("dirty", None, false)
+        };
+        let orphaned = vec![
+            ("clean".to_string(), "clean".to_string()),
+            ("dirty".to_string(), "dirty".to_string()),
+        ];
+
+        let plan = plan_batch_delete(vec![&dirty, &clean], &orphaned, true, false);
+        let names: Vec<&str> = plan.worktrees.iter().map(|w| w.name.as_str()).collect();
+        assert_eq!(names, vec!["clean"]);
+        assert_eq!(plan.skipped_dirty.len(), 1);
+        assert_eq!(plan.branches, vec![orphaned[0].clone()]);
+
+        let plan = plan_batch_delete(vec![&dirty, &clean], &orphaned, false, true);
+        let names: Vec<&str> = plan.worktrees.iter().map(|w| w.name.as_str()).collect();
+        assert_eq!(names, vec!["clean", "dirty"]);
+        assert!(plan.skipped_dirty.is_empty());
+        assert!(plan.branches.is_empty());
+    }
+
+    #[test]
+    fn test_orphaned_branches_shared_by_worktrees() {
+        let first = WorktreeInfo {
+            branch: "shared".to_string(),
+            ..worktree_with_commit("first", None, false)
+        };
+        let second = WorktreeInfo {
+            branch: "shared".to_string(),
+            ..worktree_with_commit("second", None, false)
+        };
+        let own = worktree_with_commit("own", None, false);
+        let detached = WorktreeInfo {
+            branch: DEFAULT_BRANCH_DETACHED.to_string(),
+            ..worktree_with_commit("detached", None, false)
+        };
+        let all = vec![first.clone(), second.clone(), own.clone(), detached.clone()];
+
+        // The branch stays in use while one of its worktrees is kept
+        assert_eq!(
+            orphaned_branches(&[&first, &own, &detached], &all),
+            vec![("own".to_string(), "own".to_string())]
+        );
+        assert_eq!(
+            orphaned_branches(&[&second, &first], &all),
+            vec![("shared".to_string(), "second".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_cleanup_threshold_cutoff() {
+        let now = parse_commit_time("2024-03-31 12:00").unwrap();
+        let threshold = CleanupThreshold::new(30, now).unwrap();
+        assert_eq!(
+            threshold.cutoff.format(TIME_FORMAT).to_string(),
+            "2024-03-01 12:00"
+        );
+        assert!(CleanupThreshold::new(u64::MAX, now).is_none());
+    }
+
+    #[test]
+    fn test_find_cleanup_candidates() {
+        let worktrees = vec![
+            worktree_with_commit("old", Some("2024-01-01 09:00"), false),
+            worktree_with_commit("recent", Some("2024-03-30 09:00"), false),
+            worktree_with_commit("old-current", Some("2024-01-01 09:00"), true),
+            worktree_with_commit("no-commits", None, false),
+            worktree_with_commit("garbled", Some("yesterday"), false),
+            WorktreeInfo {
+                is_prunable: true,
+                ..worktree_with_commit("missing", Some("2024-01-01 09:00"), false)
+            },
+        ];
+        let now = parse_commit_time("2024-03-31 12:00").unwrap();
+        let threshold = CleanupThreshold::new(30, now).unwrap();
+
+        let candidates = find_cleanup_candidates(&worktrees, &threshold);
+        let old: Vec<&str> = candidates.old.iter().map(|w| w.name.as_str()).collect();
+        let unknown: Vec<&str> = candidates
+            .unknown_age
+            .iter()
+            .map(|w| w.name.as_str())
+            .collect();
+        assert_eq!(old, vec!["old"]);
+        assert_eq!(unknown, vec!["no-commits", "garbled"]);
+    }
+
+    #[test]
+    fn test_find_cleanup_candidates_prefers_created_at() {
+        let worktrees = vec![
+            WorktreeInfo {
+                created_at: parse_commit_time("2024-01-01 09:00"),
+                ..worktree_with_commit("old-quiet", Some("2024-03-30 09:00"), false)
+            },
+            WorktreeInfo {
+                created_at: parse_commit_time("2024-03-01 09:00"),
+                ..worktree_with_commit("new", None, false)
+            },
+        ];
+        let now = parse_commit_time("2024-03-31 12:00").unwrap();
+        let threshold = CleanupThreshold::new(14, now).unwrap();
+
+        let candidates = find_cleanup_candidates(&worktrees, &threshold);
+        let old: Vec<&str> = candidates.old.iter().map(|w| w.name.as_str()).collect();
+        assert_eq!(old, vec!["old-quiet", "new"]);
+        assert!(candidates.unknown_age.is_empty());
+    }
 }
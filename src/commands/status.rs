@@ -0,0 +1,178 @@
+//! Status overview across worktrees
+//!
+//! `gw status` is `git status` for every worktree at once: one line each
+//! with the staged, unstaged and untracked file counts, the commits ahead
+//! and behind the upstream, and whether the worktree is locked. Statuses
+//! are gathered in parallel, bounded by `[performance] status_timeout_ms`.
+
+use anyhow::Result;
+use colored::*;
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Instant;
+
+use super::list::format_ahead_behind;
+use crate::constants::{
+    LIST_PORCELAIN_CLEAN, MISSING_MARKER, PORCELAIN_LOCKED, STATUS_CHANGES_STAGED,
+    STATUS_CHANGES_UNSTAGED, STATUS_CHANGES_UNTRACKED, STATUS_UNKNOWN_CELL,
+};
+use crate::git::{status_timeout, GitWorktreeManager, WorktreeInfo};
+use crate::infrastructure::{get_worktree_status, ChangeCounts, WorktreeStatus};
+
+/// A worktree as listed by `gw status`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusRow {
+    /// Display name of the worktree
+    pub name: String,
+    /// Branch checked out, or `detached`
+    pub branch: String,
+    /// Path of the worktree
+    pub path: PathBuf,
+    /// Whether the worktree is locked
+    pub is_locked: bool,
+    /// Whether the worktree directory is missing on disk
+    pub is_missing: bool,
+}
+
+/// Lists the main worktree followed by every linked worktree
+fn status_rows(worktrees: &[WorktreeInfo]) -> Vec<StatusRow> {
+    worktrees
+        .iter()
+        .map(|w| StatusRow {
+            name: w.name.clone(),
+            branch: w.branch.clone(),
+            path: w.path.clone(),
+            is_locked: w.is_locked,
+            is_missing: w.is_prunable,
+        })
+        .collect()
+}
+
+/// Gathers the status of every row in parallel, including ahead/behind
+///
+/// The result is in the order of `rows`. Missing worktrees, and worktrees
+/// still being read when the status timeout expires, get `None`.
+pub fn worktree_statuses(rows: &[StatusRow]) -> Vec<Option<WorktreeStatus>> {
+    let (status_tx, status_rx) = mpsc::channel();
+    for (index, row) in rows.iter().enumerate() {
+        if row.is_missing {
+            continue;
+        }
+        let status_tx = status_tx.clone();
+        let path = row.path.clone();
+        thread::spawn(move || {
+            let _ = status_tx.send((index, get_worktree_status(&path, true)));
+        });
+    }
+    drop(status_tx);
+
+    let mut statuses: Vec<Option<WorktreeStatus>> = rows.iter().map(|_| None).collect();
+    let deadline = Instant::now() + status_timeout();
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        match status_rx.recv_timeout(remaining) {
+            Ok((index, status)) => statuses[index] = Some(status),
+            Err(_) => break,
+        }
+    }
+    statuses
+}
+
+/// Pure business logic for the changes cell, e.g. `2 staged, 1 untracked`
+///
+/// Zero counts are left out; a worktree without changes is `clean`.
+pub fn format_change_counts(counts: &ChangeCounts) -> String {
+    if counts.is_clean() {
+        return LIST_PORCELAIN_CLEAN.to_string();
+    }
+    [
+        (counts.staged, STATUS_CHANGES_STAGED),
+        (counts.unstaged, STATUS_CHANGES_UNSTAGED),
+        (counts.untracked, STATUS_CHANGES_UNTRACKED),
+    ]
+    .iter()
+    .filter(|(count, _)| *count > 0)
+    .map(|(count, label)| format!("{count} {label}"))
+    .collect::<Vec<_>>()
+    .join(", ")
+}
+
+/// Prints the status of every worktree (`gw status`)
+///
+/// # Errors
+///
+/// Returns an error if not in a Git repository or worktrees cannot be listed.
+pub fn status_all() -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    let worktrees = manager.list_worktrees()?;
+    let rows = status_rows(&worktrees);
+    let statuses = worktree_statuses(&rows);
+
+    let changes: Vec<String> = rows
+        .iter()
+        .zip(&statuses)
+        .map(|(row, status)| match status {
+            Some(status) => format_change_counts(&status.changes),
+            None if row.is_missing => MISSING_MARKER.to_string(),
+            None => STATUS_UNKNOWN_CELL.to_string(),
+        })
+        .collect();
+    let name_width = rows.iter().map(|r| r.name.len()).max().unwrap_or(0);
+    let branch_width = rows.iter().map(|r| r.branch.len()).max().unwrap_or(0);
+    let changes_width = changes.iter().map(String::len).max().unwrap_or(0);
+    let syncs: Vec<String> = statuses
+        .iter()
+        .map(|status| format_ahead_behind(status.as_ref().and_then(|s| s.ahead_behind)))
+        .collect();
+    let sync_width = syncs.iter().map(|s| s.chars().count()).max().unwrap_or(0);
+
+    for (((row, status), changes), sync) in rows.iter().zip(&statuses).zip(&changes).zip(&syncs) {
+        let is_clean = status.as_ref().is_some_and(|s| !s.has_changes());
+        let changes = if is_clean {
+            changes.green()
+        } else {
+            changes.yellow()
+        };
+        let mut line = format!(
+            "{:<name_width$}  {:<branch_width$}  {changes:<changes_width$}",
+            row.name.bold(),
+            row.branch.bright_cyan()
+        );
+        if sync_width > 0 {
+            line.push_str(&format!("  {sync:<sync_width$}"));
+        }
+        if row.is_locked {
+            let locked = PORCELAIN_LOCKED.bright_red();
+            line.push_str(&format!("  {locked}"));
+        }
+        println!("{}", line.trim_end());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_change_counts() {
+        assert_eq!(format_change_counts(&ChangeCounts::default()), "clean");
+        assert_eq!(
+            format_change_counts(&ChangeCounts {
+                staged: 2,
+                unstaged: 0,
+                untracked: 1,
+            }),
+            "2 staged, 1 untracked"
+        );
+        assert_eq!(
+            format_change_counts(&ChangeCounts {
+                staged: 0,
+                unstaged: 3,
+                untracked: 0,
+            }),
+            "3 unstaged"
+        );
+    }
+}
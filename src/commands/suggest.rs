@@ -0,0 +1,485 @@
+//! Guided configuration setup
+//!
+//! Inspects the repository for well-known project files and proposes
+//! `.git-workers.toml` additions (hooks and file copies) that are not yet
+//! configured. The detection-to-suggestion mapping is pure so it can be
+//! tested without touching the filesystem.
+
+use anyhow::{Context, Result};
+use colored::*;
+use std::path::{Path, PathBuf};
+use toml_edit::{Array, DocumentMut, Item, Table, Value};
+
+use crate::config::Config;
+use crate::constants::{
+    CONFIG_KEY_COMMAND, CONFIG_KEY_COPY, CONFIG_KEY_PATH, CONFIG_SECTION_FILES,
+    CONFIG_SECTION_HOOKS, FILE_DOT_ENV, FILE_DOT_ENV_EXAMPLE, FILE_PACKAGE_JSON,
+    FORMAT_SUGGESTION_COPY, FORMAT_SUGGESTION_HOOK, HEADER_HOOK_SUGGESTIONS, HOOK_COPY_ENV_EXAMPLE,
+    HOOK_POST_CREATE, JS_INSTALL_DEFAULT, JS_LOCKFILE_INSTALL_COMMANDS, MSG_NO_SUGGESTIONS,
+    PROJECT_INSTALL_COMMANDS, PROMPT_SELECT_SUGGESTIONS, PROMPT_WRITE_SUGGESTIONS,
+    REASON_FOUND_FILE, REASON_FOUND_FILE_WITH_LOCK,
+};
+use crate::git::GitWorktreeManager;
+use crate::ui::{DialoguerUI, UserInterface};
+use crate::utils::{self, output};
+
+/// A single proposed addition to the configuration file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigSuggestion {
+    /// Append a command to the given hook
+    Hook {
+        hook_type: String,
+        command: String,
+        reason: String,
+    },
+    /// Append a path to `[files] copy`
+    CopyFile { path: String, reason: String },
+}
+
+impl ConfigSuggestion {
+    /// Human readable one-line description used in the selection list
+    pub fn describe(&self) -> String {
+        match self {
+            ConfigSuggestion::Hook {
+                hook_type,
+                command,
+                reason,
+            } => FORMAT_SUGGESTION_HOOK
+                .replacen("{}", hook_type, 1)
+                .replacen("{}", command, 1)
+                .replacen("{}", reason, 1),
+            ConfigSuggestion::CopyFile { path, reason } => FORMAT_SUGGESTION_COPY
+                .replacen("{}", path, 1)
+                .replacen("{}", reason, 1),
+        }
+    }
+}
+
+/// Returns every marker file the suggestion rules know about
+fn known_marker_files() -> Vec<&'static str> {
+    let mut markers = vec![FILE_PACKAGE_JSON, FILE_DOT_ENV, FILE_DOT_ENV_EXAMPLE];
+    markers.extend(JS_LOCKFILE_INSTALL_COMMANDS.iter().map(|(file, _)| *file));
+    markers.extend(PROJECT_INSTALL_COMMANDS.iter().map(|(file, _)| *file));
+    markers
+}
+
+/// Lists the known marker files that exist directly under `root`
+pub fn detect_project_files(root: &Path) -> Vec<String> {
+    known_marker_files()
+        .into_iter()
+        .filter(|file| root.join(file).exists())
+        .map(|file| file.to_string())
+        .collect()
+}
+
+/// Pure business logic for mapping detected files to configuration suggestions
+///
+/// Suggestions that are already present in `config` are omitted, so the
+/// result is exactly the difference between the recommended set and the
+/// current configuration.
+pub fn suggest_config_additions(
+    detected_files: &[String],
+    config: &Config,
+) -> Vec<ConfigSuggestion> {
+    let has = |file: &str| detected_files.iter().any(|f| f == file);
+    let mut suggestions = Vec::new();
+
+    if has(FILE_PACKAGE_JSON) {
+        let (command, reason) = JS_LOCKFILE_INSTALL_COMMANDS
+            .iter()
+            .find(|(lockfile, _)| has(lockfile))
+            .map(|(lockfile, command)| {
+                (
+                    command.to_string(),
+                    REASON_FOUND_FILE_WITH_LOCK
+                        .replacen("{}", FILE_PACKAGE_JSON, 1)
+                        .replacen("{}", lockfile, 1),
+                )
+            })
+            .unwrap_or_else(|| {
+                (
+                    JS_INSTALL_DEFAULT.to_string(),
+                    REASON_FOUND_FILE.replace("{}", FILE_PACKAGE_JSON),
+                )
+            });
+        suggestions.push(ConfigSuggestion::Hook {
+            hook_type: HOOK_POST_CREATE.to_string(),
+            command,
+            reason,
+        });
+    }
+
+    for (file, command) in PROJECT_INSTALL_COMMANDS {
+        if has(file) {
+            suggestions.push(ConfigSuggestion::Hook {
+                hook_type: HOOK_POST_CREATE.to_string(),
+                command: command.to_string(),
+                reason: REASON_FOUND_FILE.replace("{}", file),
+            });
+        }
+    }
+
+    if has(FILE_DOT_ENV) {
+        suggestions.push(ConfigSuggestion::CopyFile {
+            path: FILE_DOT_ENV.to_string(),
+            reason: REASON_FOUND_FILE.replace("{}", FILE_DOT_ENV),
+        });
+    } else if has(FILE_DOT_ENV_EXAMPLE) {
+        suggestions.push(ConfigSuggestion::Hook {
+            hook_type: HOOK_POST_CREATE.to_string(),
+            command: HOOK_COPY_ENV_EXAMPLE.to_string(),
+            reason: REASON_FOUND_FILE.replace("{}", FILE_DOT_ENV_EXAMPLE),
+        });
+    }
+
+    suggestions.retain(|suggestion| !is_already_configured(suggestion, config));
+    suggestions
+}
+
+/// Checks whether a suggestion is already covered by the configuration
+fn is_already_configured(suggestion: &ConfigSuggestion, config: &Config) -> bool {
+    match suggestion {
+        ConfigSuggestion::Hook {
+            hook_type, command, ..
+        } => config
+            .hooks
+            .get(hook_type)
+            .is_some_and(|commands| commands.iter().any(|c| c.command().trim() == command)),
+        ConfigSuggestion::CopyFile { path, .. } => {
+            config.files.copy.iter().any(|p| p.path() == path)
+        }
+    }
+}
+
+/// Reads the configuration stored in the file at `path`
+///
+/// Returns the default configuration when the file does not exist yet.
+fn read_config_file(path: &Path) -> Result<Config> {
+    if !path.exists() {
+        return Ok(Config::default());
+    }
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+/// Appends `value` to the `[section] key` array unless it is already there
+///
+/// Follows the layout of a multi-line array, and adds a table with
+/// `table_key` when the entries are written as `[[section.key]]`.
+fn append_entry(
+    document: &mut DocumentMut,
+    section: &str,
+    key: &str,
+    table_key: &str,
+    value: &str,
+) -> Result<()> {
+    let table = document
+        .entry(section)
+        .or_insert_with(toml_edit::table)
+        .as_table_like_mut()
+        .with_context(|| format!("[{section}] is not a table"))?;
+    let entry = table.entry(key).or_insert(toml_edit::value(Array::new()));
+
+    match entry {
+        Item::Value(Value::Array(array)) => {
+            if array.iter().any(|item| item.as_str() == Some(value)) {
+                return Ok(());
+            }
+            // Only a multi-line array has a layout worth copying
+            let prefix = array
+                .iter()
+                .last()
+                .and_then(|last| last.decor().prefix())
+                .filter(|prefix| prefix.as_str().is_some_and(|p| p.contains('\n')))
+                .cloned();
+            array.push(value);
+            if let (Some(prefix), Some(added)) = (prefix, array.iter_mut().last()) {
+                added.decor_mut().set_prefix(prefix);
+            }
+        }
+        Item::ArrayOfTables(tables) => {
+            let mut added = Table::new();
+            added.insert(table_key, toml_edit::value(value));
+            tables.push(added);
+        }
+        _ => anyhow::bail!("[{section}] {key} is not an array"),
+    }
+    Ok(())
+}
+
+/// Merges the given suggestions into the configuration file at `path`
+///
+/// The file is created if it does not exist. It is edited in place with
+/// `toml_edit`, so comments, ordering and existing values are kept and new
+/// entries are appended to the relevant arrays.
+pub fn apply_suggestions(path: &Path, suggestions: &[ConfigSuggestion]) -> Result<()> {
+    let content = if path.exists() {
+        std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?
+    } else {
+        String::new()
+    };
+    let mut document: DocumentMut = content
+        .parse()
+        .with_context(|| format!("Failed to parse {}", path.display()))?;
+
+    for suggestion in suggestions {
+        match suggestion {
+            ConfigSuggestion::Hook {
+                hook_type, command, ..
+            } => append_entry(
+                &mut document,
+                CONFIG_SECTION_HOOKS,
+                hook_type,
+                CONFIG_KEY_COMMAND,
+                command,
+            )?,
+            ConfigSuggestion::CopyFile { path, .. } => append_entry(
+                &mut document,
+                CONFIG_SECTION_FILES,
+                CONFIG_KEY_COPY,
+                CONFIG_KEY_PATH,
+                path,
+            )?,
+        }
+    }
+
+    std::fs::write(path, document.to_string())
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(())
+}
+
+/// Determines which directory to inspect for project files
+fn inspection_root(manager: &GitWorktreeManager) -> Result<PathBuf> {
+    match manager.repo().workdir() {
+        Some(workdir) => Ok(workdir.to_path_buf()),
+        None => Ok(std::env::current_dir()?),
+    }
+}
+
+/// Suggests hook and file-copy configuration for the current repository
+///
+/// Detects common project files, shows the configuration additions that are
+/// not yet present and offers to write the selected ones to `.git-workers.toml`.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - Not in a Git repository
+/// - The configuration file cannot be read or written
+pub fn suggest_hooks() -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    let ui = DialoguerUI;
+    suggest_hooks_with_ui(&manager, &ui)
+}
+
+/// Internal implementation of suggest_hooks with dependency injection
+///
+/// # Arguments
+///
+/// * `manager` - Git worktree manager instance
+/// * `ui` - User interface implementation for testability
+pub fn suggest_hooks_with_ui(manager: &GitWorktreeManager, ui: &dyn UserInterface) -> Result<()> {
+    output::print_header(HEADER_HOOK_SUGGESTIONS);
+
+    let root = inspection_root(manager)?;
+    let detected = detect_project_files(&root);
+    // Compare against the file the additions are written to
+    let config_path = super::find_config_file_path(manager)?;
+    let config = read_config_file(&config_path)?;
+    let suggestions = suggest_config_additions(&detected, &config);
+
+    if suggestions.is_empty() {
+        let msg = MSG_NO_SUGGESTIONS.green();
+        println!("{msg}");
+        println!();
+        return Ok(());
+    }
+
+    let items: Vec<String> = suggestions.iter().map(|s| s.describe()).collect();
+    let selected = match ui.multiselect(PROMPT_SELECT_SUGGESTIONS, &items) {
+        Ok(selected) => selected,
+        Err(_) => return Ok(()),
+    };
+
+    let chosen: Vec<ConfigSuggestion> = selected
+        .into_iter()
+        .filter_map(|index| suggestions.get(index).cloned())
+        .collect();
+    if chosen.is_empty() {
+        return Ok(());
+    }
+
+    let prompt = PROMPT_WRITE_SUGGESTIONS
+        .replacen("{}", &chosen.len().to_string(), 1)
+        .replacen("{}", &config_path.display().to_string(), 1);
+    if !ui.confirm_with_default(&prompt, true).unwrap_or(false) {
+        return Ok(());
+    }
+
+    apply_suggestions(&config_path, &chosen)?;
+    let path_display = config_path.display();
+    utils::print_success(&format!("Updated {path_display}"));
+    println!();
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn files(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    fn hook_commands(suggestions: &[ConfigSuggestion]) -> Vec<&str> {
+        suggestions
+            .iter()
+            .filter_map(|s| match s {
+                ConfigSuggestion::Hook { command, .. } => Some(command.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_suggest_npm_install_for_package_json() {
+        let suggestions = suggest_config_additions(&files(&["package.json"]), &Config::default());
+        assert_eq!(hook_commands(&suggestions), vec!["npm install"]);
+    }
+
+    #[test]
+    fn test_suggest_uses_lockfile_package_manager() {
+        let detected = files(&["package.json", "yarn.lock"]);
+        let suggestions = suggest_config_additions(&detected, &Config::default());
+        assert_eq!(hook_commands(&suggestions), vec!["yarn install"]);
+    }
+
+    #[test]
+    fn test_suggest_env_example_copy_hook() {
+        let suggestions = suggest_config_additions(&files(&[".env.example"]), &Config::default());
+        assert_eq!(hook_commands(&suggestions), vec!["cp .env.example .env"]);
+    }
+
+    #[test]
+    fn test_suggest_copy_env_file_when_present() {
+        let detected = files(&[".env", ".env.example"]);
+        let suggestions = suggest_config_additions(&detected, &Config::default());
+        assert_eq!(
+            suggestions,
+            vec![ConfigSuggestion::CopyFile {
+                path: ".env".to_string(),
+                reason: "found .env".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_suggest_skips_already_configured() {
+        let mut config = Config::default();
+        config
+            .hooks
+            .insert(HOOK_POST_CREATE.to_string(), vec!["npm install".into()]);
+        config.files.copy.push(".env".into());
+
+        let detected = files(&["package.json", ".env", "Cargo.toml"]);
+        let suggestions = suggest_config_additions(&detected, &config);
+        assert_eq!(hook_commands(&suggestions), vec!["cargo fetch"]);
+        assert_eq!(suggestions.len(), 1);
+    }
+
+    #[test]
+    fn test_suggest_nothing_detected() {
+        assert!(suggest_config_additions(&[], &Config::default()).is_empty());
+    }
+
+    #[test]
+    fn test_detect_project_files() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        std::fs::write(temp_dir.path().join("package.json"), "{}")?;
+        std::fs::write(temp_dir.path().join(".env.example"), "A=1")?;
+
+        let detected = detect_project_files(temp_dir.path());
+        assert!(detected.contains(&"package.json".to_string()));
+        assert!(detected.contains(&".env.example".to_string()));
+        assert!(!detected.contains(&".env".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_suggestions_merges_existing_config() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let path = temp_dir.path().join(".git-workers.toml");
+        std::fs::write(
+            &path,
+            "[hooks]\npost-create = [\"echo hi\"]\n\n[files]\ncopy = [\".env.local\"]\n",
+        )?;
+
+        let suggestions = vec![
+            ConfigSuggestion::Hook {
+                hook_type: HOOK_POST_CREATE.to_string(),
+                command: "npm install".to_string(),
+                reason: String::new(),
+            },
+            ConfigSuggestion::CopyFile {
+                path: ".env".to_string(),
+                reason: String::new(),
+            },
+        ];
+        apply_suggestions(&path, &suggestions)?;
+
+        let config: Config = toml::from_str(&std::fs::read_to_string(&path)?)?;
+        assert_eq!(
+            config.hooks[HOOK_POST_CREATE],
+            vec!["echo hi", "npm install"]
+        );
+        assert_eq!(config.files.copy, vec![".env.local", ".env"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_suggestions_keeps_comments_and_order() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let path = temp_dir.path().join(".git-workers.toml");
+        std::fs::write(
+            &path,
+            "# Project settings\n[files]\ncopy = [\".env.local\"] # secrets\n\n[hooks]\n# Setup\npost-create = [\"echo hi\"]\n",
+        )?;
+
+        apply_suggestions(
+            &path,
+            &[ConfigSuggestion::Hook {
+                hook_type: HOOK_POST_CREATE.to_string(),
+                command: "npm install".to_string(),
+                reason: String::new(),
+            }],
+        )?;
+
+        assert_eq!(
+            std::fs::read_to_string(&path)?,
+            "# Project settings\n[files]\ncopy = [\".env.local\"] # secrets\n\n[hooks]\n# Setup\npost-create = [\"echo hi\", \"npm install\"]\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_suggestions_creates_file() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let path = temp_dir.path().join(".git-workers.toml");
+
+        apply_suggestions(
+            &path,
+            &[ConfigSuggestion::CopyFile {
+                path: ".env".to_string(),
+                reason: String::new(),
+            }],
+        )?;
+
+        let config: Config = toml::from_str(&std::fs::read_to_string(&path)?)?;
+        assert_eq!(config.files.copy, vec![".env"]);
+        Ok(())
+    }
+}
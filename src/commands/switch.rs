@@ -1,13 +1,48 @@
 use anyhow::{anyhow, Result};
+use chrono::Utc;
 use colored::*;
+use std::path::Path;
 
+use super::focus::list_focused_worktrees;
+use super::list::{format_commit_line, pin_favorites};
+use super::shared::filter_worktrees;
+use crate::config::Config;
 use crate::constants::{
-    section_header, DEFAULT_MENU_SELECTION, HOOK_POST_SWITCH, MSG_ALREADY_IN_WORKTREE,
+    DEFAULT_BRANCH_DETACHED, DEFAULT_MENU_SELECTION, ERROR_SWITCH_NOT_FOUND,
+    ERROR_SWITCH_NO_WORKTREES, FAVORITE_MARKER, HOOK_POST_SWITCH, MSG_ALREADY_IN_WORKTREE,
+    MSG_NO_PREVIOUS_WORKTREE, MSG_SWITCH_PREVIEW, MSG_SWITCH_PREVIEW_EMPTY, PROMPT_SWITCH_CONFIRM,
+    SWITCH_PREVIEW_COMMITS, WARNING_NO_WORKTREES_MATCH,
 };
-use crate::git::{GitWorktreeManager, WorktreeInfo};
+use crate::git::{recent_commits, worktree_head_branch, GitWorktreeManager, WorktreeInfo};
+use crate::git_interface::GitReadOperations;
 use crate::hooks::{self, HookContext};
+use crate::infrastructure::{State, WorktreeRef};
 use crate::ui::{DialoguerUI, UserInterface};
-use crate::utils::{self, press_any_key_to_continue, write_switch_path};
+use crate::utils::{self, output, press_any_key_to_continue, write_switch_path};
+
+/// Prints the recent commits of `worktree` before switching to it
+fn print_switch_preview(worktree: &WorktreeInfo) {
+    println!();
+    let title = MSG_SWITCH_PREVIEW
+        .replacen("{}", &worktree.name, 1)
+        .replacen("{}", &worktree.branch, 1)
+        .bright_white();
+    println!("{title}");
+    match recent_commits(&worktree.path, SWITCH_PREVIEW_COMMITS) {
+        Ok(commits) if commits.is_empty() => {
+            let msg = MSG_SWITCH_PREVIEW_EMPTY.bright_black();
+            println!("  {msg}");
+        }
+        Ok(commits) => {
+            let now = Utc::now();
+            for commit in &commits {
+                println!("  {}", format_commit_line(commit, now));
+            }
+        }
+        Err(e) => utils::print_warning(&e.to_string()),
+    }
+    println!();
+}
 
 /// Validate switch target
 #[allow(dead_code)]
@@ -48,6 +83,7 @@ pub struct WorktreeSwitchConfig {
     pub target_name: String,
     pub target_path: std::path::PathBuf,
     pub target_branch: String,
+    pub previous_worktree: Option<WorktreeRef>,
 }
 
 /// Result of switch analysis
@@ -59,17 +95,15 @@ pub struct SwitchAnalysis {
 }
 
 /// Pure business logic for sorting worktrees for display
-pub fn sort_worktrees_for_display(mut worktrees: Vec<WorktreeInfo>) -> Vec<WorktreeInfo> {
-    worktrees.sort_by(|a, b| {
-        if a.is_current && !b.is_current {
-            std::cmp::Ordering::Less
-        } else if !a.is_current && b.is_current {
-            std::cmp::Ordering::Greater
-        } else {
-            a.name.cmp(&b.name)
-        }
-    });
-    worktrees
+///
+/// The current worktree comes first, then the `favorites`, then the rest,
+/// each by name.
+pub fn sort_worktrees_for_display(
+    mut worktrees: Vec<WorktreeInfo>,
+    favorites: &[String],
+) -> Vec<WorktreeInfo> {
+    worktrees.sort_by(|a, b| a.name.cmp(&b.name));
+    pin_favorites(worktrees, favorites)
 }
 
 /// Pure business logic for analyzing switch target
@@ -91,6 +125,60 @@ pub fn analyze_switch_target(
     })
 }
 
+/// Pure business logic for building the post-switch hook context
+pub fn post_switch_context(
+    target_name: &str,
+    target_path: &Path,
+    previous: Option<&WorktreeRef>,
+) -> HookContext {
+    HookContext {
+        worktree_name: target_name.to_string(),
+        worktree_path: target_path.to_path_buf(),
+        previous_worktree_name: previous.map(|p| p.name.clone()),
+        previous_worktree_path: previous.map(|p| p.path.clone()),
+    }
+}
+
+/// Remembers the current worktree as the previous one before switching
+///
+/// Returns the previous worktree to pass to post-switch hooks. Failing to
+/// persist the state only produces a warning, as the switch itself still works.
+pub fn record_switch(manager: &dyn GitReadOperations, target_path: &Path) -> Option<WorktreeRef> {
+    let state_dir = manager.get_common_dir();
+    let mut state = State::load(state_dir);
+    let from = match manager.current_worktree_ref() {
+        Some(from) => from,
+        None => return state.previous_worktree,
+    };
+
+    let previous = state.record_switch(from, target_path);
+    if let Err(e) = state.save(state_dir) {
+        utils::print_warning(&format!("Failed to save switch state: {e}"));
+    }
+    previous
+}
+
+/// Pure business logic for finding a switch target by worktree name
+///
+/// The error lists the available names so a typo is easy to correct.
+pub fn find_switch_target<'a>(
+    worktrees: &'a [WorktreeInfo],
+    name: &str,
+) -> Result<&'a WorktreeInfo> {
+    if worktrees.is_empty() {
+        return Err(anyhow!(ERROR_SWITCH_NO_WORKTREES));
+    }
+
+    worktrees.iter().find(|w| w.name == name).ok_or_else(|| {
+        let available: Vec<&str> = worktrees.iter().map(|w| w.name.as_str()).collect();
+        anyhow!(ERROR_SWITCH_NOT_FOUND.replacen("{}", name, 1).replacen(
+            "{}",
+            &available.join(", "),
+            1
+        ))
+    })
+}
+
 /// Pure business logic for executing switch operation
 pub fn execute_switch(config: &WorktreeSwitchConfig) -> Result<()> {
     // Write switch path for shell integration
@@ -99,10 +187,11 @@ pub fn execute_switch(config: &WorktreeSwitchConfig) -> Result<()> {
     // Execute post-switch hooks
     if let Err(e) = hooks::execute_hooks(
         HOOK_POST_SWITCH,
-        &HookContext {
-            worktree_name: config.target_name.clone(),
-            worktree_path: config.target_path.clone(),
-        },
+        &post_switch_context(
+            &config.target_name,
+            &config.target_path,
+            config.previous_worktree.as_ref(),
+        ),
     ) {
         utils::print_warning(&format!("Hook execution warning: {e}"));
     }
@@ -127,7 +216,7 @@ pub fn execute_switch(config: &WorktreeSwitchConfig) -> Result<()> {
 /// The actual directory change is handled by the shell wrapper.
 /// This function writes the target path to either:
 /// - File specified by `GW_SWITCH_FILE` environment variable
-/// - Standard output with `SWITCH_TO:` prefix (legacy mode)
+/// - A `SWITCH_TO:` marker on stdout or stderr, per `[shell] switch_channel`
 ///
 /// # Returns
 ///
@@ -155,12 +244,43 @@ pub fn switch_worktree() -> Result<bool> {
 /// # Returns
 ///
 /// Returns `true` if a switch occurred, `false` if cancelled or already in selected worktree
+///
+/// With `[switch] show_preview`, the recent commits of the selected worktree
+/// are shown and the switch is confirmed; declining goes back to the list.
 pub fn switch_worktree_with_ui(
     manager: &GitWorktreeManager,
     ui: &dyn UserInterface,
 ) -> Result<bool> {
-    let worktrees = manager.list_worktrees()?;
+    switch_worktree_matching_with_ui(manager, ui, "")
+}
+
+/// Opens the switch picker with only the worktrees whose name or branch
+/// matches `filter` (`gw switch --filter`, see [`filter_worktrees`])
+///
+/// # Errors
+///
+/// Returns an error if not in a Git repository or the switch fails.
+pub fn switch_worktree_matching(filter: &str) -> Result<bool> {
+    let manager = GitWorktreeManager::new()?;
+    let ui = DialoguerUI;
+    switch_worktree_matching_with_ui(&manager, &ui, filter)
+}
 
+/// Internal implementation of [`switch_worktree_matching`]
+///
+/// An empty `filter` offers every worktree, like the menu does.
+pub fn switch_worktree_matching_with_ui(
+    manager: &GitWorktreeManager,
+    ui: &dyn UserInterface,
+    filter: &str,
+) -> Result<bool> {
+    let worktrees = filter_worktrees(list_focused_worktrees(manager)?, filter);
+
+    if worktrees.is_empty() && !filter.is_empty() {
+        let msg = WARNING_NO_WORKTREES_MATCH.replace("{}", filter).yellow();
+        println!("{msg}");
+        return Ok(false);
+    }
     if worktrees.is_empty() {
         println!();
         let msg = "• No worktrees available.".yellow();
@@ -170,53 +290,73 @@ pub fn switch_worktree_with_ui(
         return Ok(false);
     }
 
-    println!();
-    let header = section_header("Switch Worktree");
-    println!("{header}");
-    println!();
+    output::print_header("Switch Worktree");
 
     // Use business logic to sort worktrees for display
-    let sorted_worktrees = sort_worktrees_for_display(worktrees);
+    let favorites = State::load(manager.get_common_dir()).favorites;
+    let sorted_worktrees = sort_worktrees_for_display(worktrees, &favorites);
 
     let items: Vec<String> = sorted_worktrees
         .iter()
         .map(|w| {
+            let name = if favorites.contains(&w.name) {
+                format!("{} {FAVORITE_MARKER}", w.name)
+            } else {
+                w.name.clone()
+            };
             if w.is_current {
-                format!("{} ({}) [current]", w.name, w.branch)
+                format!("{name} ({}) [current]", w.branch)
             } else {
-                format!("{} ({})", w.name, w.branch)
+                format!("{name} ({})", w.branch)
             }
         })
         .collect();
 
-    let selection = match ui.select_with_default(
-        "Select a worktree to switch to (ESC to cancel)",
-        &items,
-        DEFAULT_MENU_SELECTION,
-    ) {
-        Ok(selection) => selection,
-        Err(_) => return Ok(false),
-    };
+    let show_preview = Config::load()
+        .map(|config| config.switch.show_preview)
+        .unwrap_or(false);
+
+    let mut default_selection = DEFAULT_MENU_SELECTION;
+    let selected_worktree = loop {
+        let selection = match ui.select_with_default(
+            "Select a worktree to switch to (ESC to cancel)",
+            &items,
+            default_selection,
+        ) {
+            Ok(selection) => selection,
+            Err(_) => return Ok(false),
+        };
 
-    // Use business logic to analyze the switch target
-    let analysis = analyze_switch_target(&sorted_worktrees, selection)?;
+        // Use business logic to analyze the switch target
+        let analysis = analyze_switch_target(&sorted_worktrees, selection)?;
 
-    if analysis.is_already_current {
-        println!();
-        let msg = MSG_ALREADY_IN_WORKTREE.yellow();
-        println!("{msg}");
-        println!();
-        press_any_key_to_continue()?;
-        return Ok(false);
-    }
+        if analysis.is_already_current {
+            println!();
+            let msg = MSG_ALREADY_IN_WORKTREE.yellow();
+            println!("{msg}");
+            println!();
+            press_any_key_to_continue()?;
+            return Ok(false);
+        }
 
-    let selected_worktree = &sorted_worktrees[selection];
+        let worktree = &sorted_worktrees[selection];
+        if !show_preview {
+            break worktree;
+        }
+        print_switch_preview(worktree);
+        match ui.confirm_with_default(PROMPT_SWITCH_CONFIRM, true) {
+            Ok(true) => break worktree,
+            Ok(false) => default_selection = selection,
+            Err(_) => return Ok(false),
+        }
+    };
 
     // Create switch configuration
     let config = WorktreeSwitchConfig {
         target_name: selected_worktree.name.clone(),
         target_path: selected_worktree.path.clone(),
         target_branch: selected_worktree.branch.clone(),
+        previous_worktree: record_switch(manager, &selected_worktree.path),
     };
 
     println!();
@@ -236,6 +376,84 @@ pub fn switch_worktree_with_ui(
     Ok(true)
 }
 
+/// Switches to the worktree named `name` without showing the menu
+///
+/// Used by `gw switch <name>`. Writes the target path for the shell
+/// integration and runs post-switch hooks, like the interactive switch.
+///
+/// # Errors
+///
+/// Returns an error if not in a Git repository or no worktree is called `name`.
+pub fn switch_to_worktree(name: &str) -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    let worktrees = manager.list_worktrees()?;
+    let target = find_switch_target(&worktrees, name)?;
+
+    if target.is_current {
+        let msg = MSG_ALREADY_IN_WORKTREE.yellow();
+        println!("{msg}");
+        return Ok(());
+    }
+
+    let config = WorktreeSwitchConfig {
+        target_name: target.name.clone(),
+        target_path: target.path.clone(),
+        target_branch: target.branch.clone(),
+        previous_worktree: record_switch(&manager, &target.path),
+    };
+
+    let plus_sign = "+".green();
+    let worktree_name = config.target_name.bright_white().bold();
+    println!("{plus_sign} Switching to worktree '{worktree_name}'");
+
+    execute_switch(&config)
+}
+
+/// Switches back to the worktree used before the current one
+///
+/// Used by `gw switch --last` and the menu. The target comes from the switch
+/// history in the repository state (see [`State::last_worktree`]).
+///
+/// # Returns
+///
+/// Returns `true` if a switch occurred, `false` if there is no history.
+///
+/// # Errors
+///
+/// Returns an error if not in a Git repository.
+pub fn switch_to_previous_worktree() -> Result<bool> {
+    let manager = GitWorktreeManager::new()?;
+    let state = State::load(manager.get_common_dir());
+    let current = manager.current_worktree_ref().map(|current| current.path);
+
+    let target = match state.last_worktree(current.as_deref(), Path::exists) {
+        Some(target) => target.clone(),
+        None => {
+            let msg = MSG_NO_PREVIOUS_WORKTREE.yellow();
+            println!("{msg}");
+            return Ok(false);
+        }
+    };
+
+    let target_branch = worktree_head_branch(&target.path)
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| DEFAULT_BRANCH_DETACHED.to_string());
+    let config = WorktreeSwitchConfig {
+        target_name: target.name.clone(),
+        target_path: target.path.clone(),
+        target_branch,
+        previous_worktree: record_switch(&manager, &target.path),
+    };
+
+    let plus_sign = "+".green();
+    let worktree_name = config.target_name.bright_white().bold();
+    println!("{plus_sign} Switching back to worktree '{worktree_name}'");
+
+    execute_switch(&config)?;
+    Ok(true)
+}
+
 #[cfg(test)] // Re-enabled tests with corrected WorktreeInfo fields
 mod tests {
     use super::*;
@@ -295,6 +513,7 @@ mod tests {
             target_name: "feature".to_string(),
             target_path: PathBuf::from("/tmp/feature"),
             target_branch: "feature-branch".to_string(),
+            previous_worktree: None,
         };
 
         assert_eq!(config.target_name, "feature");
@@ -302,53 +521,85 @@ mod tests {
         assert_eq!(config.target_path, PathBuf::from("/tmp/feature"));
     }
 
+    #[test]
+    fn test_post_switch_context_without_previous() {
+        let context = post_switch_context("feature", Path::new("/tmp/feature"), None);
+        assert_eq!(context.worktree_name, "feature");
+        assert_eq!(context.previous_worktree_name, None);
+        assert_eq!(context.previous_worktree_path, None);
+        assert_eq!(
+            hooks::expand_template("from '{{previous_worktree_name}}'", &context),
+            "from ''"
+        );
+    }
+
+    #[test]
+    fn test_post_switch_context_on_second_switch() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let worktree = |name: &str| WorktreeRef {
+            name: name.to_string(),
+            path: PathBuf::from(format!("/tmp/{name}")),
+        };
+
+        // main -> feature
+        let mut state = State::load(temp_dir.path());
+        state.record_switch(worktree("main"), Path::new("/tmp/feature"));
+        state.save(temp_dir.path()).unwrap();
+
+        // feature -> bugfix
+        let mut state = State::load(temp_dir.path());
+        let previous = state.record_switch(worktree("feature"), Path::new("/tmp/bugfix"));
+        state.save(temp_dir.path()).unwrap();
+
+        let context = post_switch_context("bugfix", Path::new("/tmp/bugfix"), previous.as_ref());
+        assert_eq!(context.previous_worktree_name.as_deref(), Some("feature"));
+        assert_eq!(
+            context.previous_worktree_path,
+            Some(PathBuf::from("/tmp/feature"))
+        );
+        assert_eq!(
+            State::load(temp_dir.path()).previous_worktree,
+            Some(worktree("feature"))
+        );
+    }
+
     #[test]
     fn test_sort_worktrees_for_display() {
         let worktrees = vec![
             WorktreeInfo {
-                name: "zzz-last".to_string(),
-                git_name: "zzz-last".to_string(),
                 path: PathBuf::from("/tmp/zzz"),
                 branch: "zzz-branch".to_string(),
-                is_locked: false,
-                is_current: false,
-                has_changes: false,
-                last_commit: None,
-                ahead_behind: None,
+                ..WorktreeInfo::test("zzz-last")
             },
             WorktreeInfo {
-                name: "aaa-first".to_string(),
-                git_name: "aaa-first".to_string(),
                 path: PathBuf::from("/tmp/aaa"),
                 branch: "aaa-branch".to_string(),
-                is_locked: false,
                 is_current: true,
-                has_changes: false,
-                last_commit: None,
-                ahead_behind: None,
+                ..WorktreeInfo::test("aaa-first")
             },
         ];
 
-        let sorted = sort_worktrees_for_display(worktrees);
+        let sorted = sort_worktrees_for_display(worktrees.clone(), &[]);
         assert_eq!(sorted[0].name, "aaa-first"); // Current worktree should be first
         assert_eq!(sorted[1].name, "zzz-last");
         assert!(sorted[0].is_current);
         assert!(!sorted[1].is_current);
+
+        // Favorites follow the current worktree
+        let mut worktrees = worktrees;
+        worktrees.push(WorktreeInfo {
+            name: "mmm-middle".to_string(),
+            is_current: false,
+            ..worktrees[0].clone()
+        });
+        let sorted = sort_worktrees_for_display(worktrees, &["zzz-last".to_string()]);
+        let names: Vec<&str> = sorted.iter().map(|w| w.name.as_str()).collect();
+        assert_eq!(names, vec!["aaa-first", "zzz-last", "mmm-middle"]);
     }
 
     #[test]
     fn test_analyze_switch_target_basic() {
-        let worktrees = vec![WorktreeInfo {
-            name: "main".to_string(),
-            git_name: "main".to_string(),
-            path: PathBuf::from("/tmp/main"),
-            branch: "main".to_string(),
-            is_locked: false,
-            is_current: false,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
-        }];
+        let worktrees = vec![WorktreeInfo::test("main")];
 
         let analysis = analyze_switch_target(&worktrees, 0).unwrap();
         assert_eq!(analysis.worktrees[0].name, "main");
@@ -356,6 +607,23 @@ mod tests {
         assert_eq!(analysis.current_worktree_index, None);
     }
 
+    #[test]
+    fn test_find_switch_target() {
+        let worktree = |name: &str| WorktreeInfo::test(name);
+        let worktrees = vec![worktree("main"), worktree("feature")];
+
+        let found = find_switch_target(&worktrees, "feature").unwrap();
+        assert_eq!(found.path, PathBuf::from("/tmp/feature"));
+
+        let error = find_switch_target(&worktrees, "featur")
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("'featur'"));
+        assert!(error.contains("main, feature"));
+
+        assert!(find_switch_target(&[], "main").is_err());
+    }
+
     #[test]
     fn test_analyze_switch_target_invalid_index() {
         let worktrees = vec![];
@@ -0,0 +1,86 @@
+//! Version and build information (`gw version`)
+//!
+//! `gw --version` prints the bare version for people. `gw version` adds the
+//! commit and date the binary was built from, and `--json` prints the same
+//! for tooling. The build metadata is recorded by `build.rs`.
+
+use anyhow::Result;
+use serde::Serialize;
+
+/// Version and build metadata of the running binary
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VersionInfo {
+    /// Crate version from Cargo.toml
+    pub version: String,
+    /// Short commit id the binary was built from, or `unknown`
+    pub git_sha: String,
+    /// Version of rustc that compiled the binary
+    pub rust: String,
+    /// UTC build date, `YYYY-MM-DD`
+    pub build_date: String,
+}
+
+impl VersionInfo {
+    /// The metadata compiled into this binary
+    pub fn current() -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            git_sha: env!("GIT_SHA").to_string(),
+            rust: env!("RUSTC_VERSION").to_string(),
+            build_date: env!("BUILD_DATE").to_string(),
+        }
+    }
+}
+
+/// Pure business logic for the human-readable `gw version` line
+///
+/// e.g. `git-workers v0.7.0 (1a2b3c4 2026-10-16, rustc 1.80.0)`
+pub fn format_version(info: &VersionInfo) -> String {
+    format!(
+        "git-workers v{} ({} {}, rustc {})",
+        info.version, info.git_sha, info.build_date, info.rust
+    )
+}
+
+/// Prints the version and build information (`gw version`)
+///
+/// # Errors
+///
+/// Returns an error if the JSON cannot be serialized.
+pub fn show_version(json: bool) -> Result<()> {
+    let info = VersionInfo::current();
+    if json {
+        println!("{}", serde_json::to_string_pretty(&info)?);
+    } else {
+        println!("{}", format_version(&info));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_info() -> Result<()> {
+        let info = VersionInfo {
+            version: "0.7.0".to_string(),
+            git_sha: "1a2b3c4".to_string(),
+            rust: "1.80.0".to_string(),
+            build_date: "2026-10-16".to_string(),
+        };
+        assert_eq!(
+            format_version(&info),
+            "git-workers v0.7.0 (1a2b3c4 2026-10-16, rustc 1.80.0)"
+        );
+
+        let value: serde_json::Value = serde_json::to_value(&info)?;
+        assert_eq!(value["version"], "0.7.0");
+        assert_eq!(value["git_sha"], "1a2b3c4");
+        assert_eq!(value["rust"], "1.80.0");
+        assert_eq!(value["build_date"], "2026-10-16");
+
+        assert_eq!(VersionInfo::current().version, env!("CARGO_PKG_VERSION"));
+        Ok(())
+    }
+}
@@ -0,0 +1,164 @@
+//! Worktree lookup by path or branch (`gw which`)
+//!
+//! Reports which worktree of the current repository contains a path, or has
+//! a branch checked out (`gw which --branch <branch>`). Useful in scripts,
+//! for checking how `gw` detects the current worktree, and before checking
+//! out a branch that may already be in use elsewhere.
+
+use anyhow::{anyhow, Result};
+use std::path::{Path, PathBuf};
+
+use crate::constants::{
+    ERROR_WHICH_NOT_FOUND, ERROR_WHICH_NO_PATH, LABEL_BRANCH, LABEL_NAME, LABEL_ROOT,
+    MSG_BRANCH_NOT_CHECKED_OUT, WHICH_MAIN_MARKER,
+};
+use crate::git::GitWorktreeManager;
+
+/// A worktree root that a path can belong to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorktreeOwner {
+    pub name: String,
+    pub branch: String,
+    pub root: PathBuf,
+    /// The root is the main worktree rather than a linked one
+    pub is_main: bool,
+}
+
+/// Pure business logic for finding the worktree that contains `path`
+///
+/// Roots are compared component by component, so `/repo/feat` does not
+/// contain `/repo/feature`. When worktrees are nested (e.g. linked worktrees
+/// inside the main worktree) the deepest matching root wins. Both `path` and
+/// the roots are expected to be canonical.
+pub fn find_owning_worktree<'a>(
+    path: &Path,
+    owners: &'a [WorktreeOwner],
+) -> Option<&'a WorktreeOwner> {
+    owners
+        .iter()
+        .filter(|owner| path.starts_with(&owner.root))
+        .max_by_key(|owner| owner.root.components().count())
+}
+
+/// Collects the main worktree and every linked worktree as candidates
+fn worktree_owners(manager: &GitWorktreeManager) -> Result<Vec<WorktreeOwner>> {
+    let mut owners = Vec::new();
+
+    for worktree in manager.list_worktrees()? {
+        let root = worktree
+            .path
+            .canonicalize()
+            .unwrap_or_else(|_| worktree.path.clone());
+        owners.push(WorktreeOwner {
+            name: worktree.name,
+            branch: worktree.branch,
+            root,
+            is_main: worktree.is_main,
+        });
+    }
+
+    Ok(owners)
+}
+
+/// Prints the worktree that contains `path`
+///
+/// # Errors
+///
+/// Returns an error if not in a Git repository, `path` does not exist, or
+/// `path` is outside every worktree of the repository.
+pub fn which_worktree(path: &Path) -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    let target = path
+        .canonicalize()
+        .map_err(|_| anyhow!(ERROR_WHICH_NO_PATH.replace("{}", &path.display().to_string())))?;
+
+    let owners = worktree_owners(&manager)?;
+    let owner = find_owning_worktree(&target, &owners).ok_or_else(|| {
+        anyhow!(ERROR_WHICH_NOT_FOUND.replace("{}", &target.display().to_string()))
+    })?;
+
+    let name = &owner.name;
+    let marker = if owner.is_main { WHICH_MAIN_MARKER } else { "" };
+    let branch = &owner.branch;
+    let root = owner.root.display();
+    println!("{LABEL_NAME} {name}{marker}");
+    println!("{LABEL_BRANCH} {branch}");
+    println!("{LABEL_ROOT} {root}");
+    Ok(())
+}
+
+/// Prints the worktree that has `branch` checked out
+///
+/// `branch` may carry a remote prefix, e.g. `origin/feature`.
+///
+/// # Errors
+///
+/// Returns an error if not in a Git repository or worktrees cannot be listed.
+pub fn which_branch(branch: &str) -> Result<()> {
+    let manager = GitWorktreeManager::new()?;
+    let Some(worktree) = manager.worktree_for_branch(branch)? else {
+        println!("{}", MSG_BRANCH_NOT_CHECKED_OUT.replace("{}", branch));
+        return Ok(());
+    };
+
+    let name = &worktree.name;
+    let marker = if worktree.is_main {
+        WHICH_MAIN_MARKER
+    } else {
+        ""
+    };
+    let branch = &worktree.branch;
+    let root = worktree.path.display();
+    println!("{LABEL_NAME} {name}{marker}");
+    println!("{LABEL_BRANCH} {branch}");
+    println!("{LABEL_ROOT} {root}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn owner(name: &str, root: &str, is_main: bool) -> WorktreeOwner {
+        WorktreeOwner {
+            name: name.to_string(),
+            branch: name.to_string(),
+            root: PathBuf::from(root),
+            is_main,
+        }
+    }
+
+    #[test]
+    fn test_find_owning_worktree_nested_prefers_deepest() {
+        let owners = vec![
+            owner("repo", "/work/repo", true),
+            owner("feature", "/work/repo/worktrees/feature", false),
+        ];
+
+        let found = find_owning_worktree(Path::new("/work/repo/worktrees/feature/src"), &owners);
+        assert_eq!(found.map(|o| o.name.as_str()), Some("feature"));
+
+        let found = find_owning_worktree(Path::new("/work/repo/src"), &owners);
+        assert_eq!(found.map(|o| o.name.as_str()), Some("repo"));
+
+        let found = find_owning_worktree(Path::new("/work/repo/worktrees"), &owners);
+        assert_eq!(found.map(|o| o.name.as_str()), Some("repo"));
+    }
+
+    #[test]
+    fn test_find_owning_worktree_sibling_prefix_collision() {
+        let owners = vec![
+            owner("feat", "/work/feat", false),
+            owner("feature", "/work/feature", false),
+        ];
+
+        let found = find_owning_worktree(Path::new("/work/feature/src"), &owners);
+        assert_eq!(found.map(|o| o.name.as_str()), Some("feature"));
+
+        let found = find_owning_worktree(Path::new("/work/feat"), &owners);
+        assert_eq!(found.map(|o| o.name.as_str()), Some("feat"));
+
+        assert!(find_owning_worktree(Path::new("/work/feat-2"), &owners).is_none());
+        assert!(find_owning_worktree(Path::new("/work"), &owners).is_none());
+    }
+}
@@ -20,6 +20,32 @@
 //! 2. Main repository directory (where `.git` is a directory)
 //! 3. Parent directories for `main/` or `master/`
 //!
+//! # Global Configuration
+//!
+//! `$XDG_CONFIG_HOME/git-workers/config.toml` (default
+//! `~/.config/git-workers/config.toml`) holds defaults shared by every
+//! repository. The repository file is layered on top of it:
+//!
+//! - Keys set in the repository file override global ones
+//! - `[files] copy` lists are concatenated; a repository entry replaces a
+//!   global entry for the same path
+//! - Each `[hooks]` event runs the global commands first, then the
+//!   repository's
+//! - `[repository]` is ignored in the global file
+//!
+//! # Worktree Overrides
+//!
+//! In a linked worktree of a non-bare repository, the main worktree's file
+//! is used even if the worktree has its own checked-out copy, so switching
+//! to an old branch does not change which hooks run. When the main
+//! configuration sets `[config] allow_worktree_override = true`, the
+//! `.git-workers.toml` at the root of the current worktree is layered on
+//! top: its keys replace the main configuration's, section by section
+//! (lists are replaced, not concatenated). `[repository]` and `[config]`
+//! are ignored in the worktree file.
+//!
+//! Precedence, lowest first: global file, main configuration, worktree file.
+//!
 //! # Repository Identification
 //!
 //! The configuration file can include a repository URL to ensure hooks
@@ -34,12 +60,30 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 
 use crate::constants::{
-    CONFIG_FILE_NAME, ERROR_ACTUAL_URL_PREFIX, ERROR_CONFIG_PARSE, ERROR_CONFIG_READ,
-    ERROR_EXPECTED_URL_PREFIX, ERROR_HOOKS_NOT_EXECUTED, ERROR_REPO_URL_MISMATCH, GIT_CMD, GIT_DIR,
-    GIT_LIST, GIT_OPT_PORCELAIN, GIT_ORIGIN, GIT_URL_SUFFIX, GIT_WORKTREE, PORCELAIN_WORKTREE,
+    BARE_SUFFIX, BYTES_PER_MB, CONFIG_FILE_NAME, CONFIG_KEY_COPY, CONFIG_KEY_PATH,
+    CONFIG_SECTION_CONFIG, CONFIG_SECTION_FILES, CONFIG_SECTION_HOOKS, CONFIG_SECTION_REPOSITORY,
+    DEFAULT_BRANCH_MAIN, DEFAULT_BRANCH_MASTER, DEFAULT_STATUS_TIMEOUT_MS, ENV_HOME,
+    ENV_XDG_CONFIG_HOME, ERROR_ACTUAL_URL_PREFIX, ERROR_CONFIG_PARSE, ERROR_CONFIG_READ,
+    ERROR_EXPECTED_URL_PREFIX, ERROR_HOOKS_NOT_EXECUTED, ERROR_REPO_URL_HINT,
+    ERROR_REPO_URL_MISMATCH, GIT_CMD, GIT_DIR, GIT_HEAD_REF, GIT_LIST, GIT_OPT_PORCELAIN,
+    GIT_ORIGIN, GIT_REFS_HEADS, GIT_URL_SUFFIX, GIT_WORKTREE, GLOBAL_CONFIG_DIR_NAME,
+    GLOBAL_CONFIG_FILE_NAME, HINT_DID_YOU_MEAN, KNOWN_HOOK_EVENTS, MAX_FILE_SIZE_MB,
+    WARNING_UNKNOWN_HOOK_EVENT, XDG_DEFAULT_CONFIG_DIR,
 };
+use crate::infrastructure::{parse_worktree_porcelain, worktree_head_branch};
+use crate::utils::closest_match;
+
+/// Whether the unknown hook events have been reported in this process
+///
+/// Commands load the configuration several times; the warning is printed
+/// on the first load only.
+static UNKNOWN_EVENTS_WARNED: AtomicBool = AtomicBool::new(false);
 
 /// Main configuration structure for Git Workers
 ///
@@ -65,19 +109,558 @@ pub struct Config {
     /// Hook definitions mapping hook names to lists of commands
     ///
     /// Supported hooks:
+    /// - `pre-create`: Run before creating a new worktree (in the repository root)
     /// - `post-create`: Run after creating a new worktree
     /// - `pre-remove`: Run before removing a worktree
+    /// - `post-remove`: Run after removing a worktree (in the repository root)
     /// - `post-switch`: Run after switching to a worktree
     ///
     /// Commands can include placeholders:
     /// - `{{worktree_name}}`: Replaced with the worktree name
     /// - `{{worktree_path}}`: Replaced with the full worktree path
+    ///
+    /// Each command is a plain string or a table with its own working
+    /// directory and environment (see [`HookEntry`]). `on_failure` sets what
+    /// happens when a `pre-create` or `post-create` hook fails (see
+    /// [`HooksConfig`]).
+    #[serde(default)]
+    pub hooks: HooksConfig,
+
+    /// Named command lists run on request, e.g. `gw create --task setup`
+    ///
+    /// Entries use the same format as hooks and run in the worktree.
     #[serde(default)]
-    pub hooks: HashMap<String, Vec<String>>,
+    pub tasks: HashMap<String, Vec<HookEntry>>,
 
     /// File copy configuration
     #[serde(default)]
     pub files: FilesConfig,
+
+    /// Worktree creation preferences
+    #[serde(default)]
+    pub worktree: WorktreeConfig,
+
+    /// General behavior
+    #[serde(default)]
+    pub general: GeneralConfig,
+
+    /// Worktree list display options
+    #[serde(default)]
+    pub display: DisplayConfig,
+
+    /// Create-worktree prompt options
+    #[serde(default)]
+    pub create: CreateConfig,
+
+    /// Limits that keep slow worktrees from blocking the interface
+    #[serde(default)]
+    pub performance: PerformanceConfig,
+
+    /// Editor used to open worktrees
+    #[serde(default)]
+    pub editor: EditorConfig,
+
+    /// Switch prompt options
+    #[serde(default)]
+    pub switch: SwitchConfig,
+
+    /// Shell wrapper integration
+    #[serde(default)]
+    pub shell: ShellConfig,
+
+    /// Configuration loading options
+    #[serde(default)]
+    pub config: LoadingConfig,
+}
+
+/// Configuration loading options (`[config]`)
+///
+/// # Example
+///
+/// ```toml
+/// [config]
+/// allow_worktree_override = true
+/// ```
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct LoadingConfig {
+    /// Layer the `.git-workers.toml` of the current worktree on top of this
+    /// configuration
+    ///
+    /// Only read from the main configuration. Off by default so that a
+    /// file checked out on some branch cannot change which hooks run.
+    #[serde(default)]
+    pub allow_worktree_override: bool,
+}
+
+/// Shell wrapper integration
+///
+/// # Example
+///
+/// ```toml
+/// [shell]
+/// switch_channel = "stderr"
+/// ```
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct ShellConfig {
+    /// Where the `SWITCH_TO:` marker goes when `GW_SWITCH_FILE` is unset
+    #[serde(default)]
+    pub switch_channel: SwitchChannel,
+}
+
+/// How the switch target reaches a shell wrapper
+///
+/// `GW_SWITCH_FILE` always wins when it is set; the channel only decides
+/// where the `SWITCH_TO:<path>` marker is printed without it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SwitchChannel {
+    /// Write to `GW_SWITCH_FILE`, falling back to the marker on stdout
+    #[default]
+    File,
+    /// Print the marker on stdout
+    Stdout,
+    /// Print the marker on stderr, keeping stdout free for piping
+    Stderr,
+}
+
+/// Switch prompt options
+///
+/// # Example
+///
+/// ```toml
+/// [switch]
+/// show_preview = true
+/// ```
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct SwitchConfig {
+    /// Show the recent commits of the selected worktree and confirm before
+    /// switching
+    #[serde(default)]
+    pub show_preview: bool,
+}
+
+/// Editor used to open worktrees
+///
+/// # Example
+///
+/// ```toml
+/// [editor]
+/// command = "code {{path}}"
+/// ```
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct EditorConfig {
+    /// Command that opens a worktree or the configuration file, with
+    /// `{{path}}` replaced by its path
+    ///
+    /// Without `{{path}}` the path is appended as the last argument. When
+    /// unset, `$EDITOR` or `$VISUAL` is used; `gw hooks edit --editor` wins
+    /// over it (see [`crate::utils::resolve_editor`]).
+    #[serde(default)]
+    pub command: Option<String>,
+}
+
+/// Limits that keep slow worktrees from blocking the interface
+///
+/// # Example
+///
+/// ```toml
+/// [performance]
+/// status_timeout_ms = 5000
+/// ```
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct PerformanceConfig {
+    /// How long to wait for the status of all worktrees when listing them
+    ///
+    /// Defaults to `DEFAULT_STATUS_TIMEOUT_MS`. Worktrees whose status is not
+    /// ready in time, e.g. on a hung network filesystem, are listed with an
+    /// unknown status.
+    #[serde(default)]
+    pub status_timeout_ms: Option<u64>,
+}
+
+impl PerformanceConfig {
+    /// Returns the configured status timeout or the default
+    pub fn status_timeout(&self) -> Duration {
+        Duration::from_millis(self.status_timeout_ms.unwrap_or(DEFAULT_STATUS_TIMEOUT_MS))
+    }
+}
+
+/// Create-worktree prompt options
+///
+/// # Example
+///
+/// ```toml
+/// [create]
+/// always_fuzzy_branches = true
+/// fetch_before_create = true
+/// fetch_depth = 1
+/// branch_sort = "committerdate"
+/// ```
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct CreateConfig {
+    /// Always use the searchable branch picker, however few branches there are
+    #[serde(default)]
+    pub always_fuzzy_branches: bool,
+
+    /// Fetch a remote branch before creating a worktree from it
+    ///
+    /// A failed fetch (e.g. offline) only warns; the cached ref is used.
+    #[serde(default)]
+    pub fetch_before_create: bool,
+
+    /// Only fetch the last N commits of the branch (`git fetch --depth N`)
+    ///
+    /// Applies to the fetch made with `fetch_before_create` or
+    /// `gw create --depth`; creating from a local branch fetches nothing.
+    /// Must be greater than 0.
+    #[serde(default)]
+    pub fetch_depth: Option<u32>,
+
+    /// Remote ref of a pull request for `gw create --pr`
+    ///
+    /// `{{number}}` is replaced with the pull request number. Defaults to
+    /// GitHub's `pull/{{number}}/head`; GitLab uses
+    /// `merge-requests/{{number}}/head`.
+    #[serde(default)]
+    pub pr_refspec: Option<String>,
+
+    /// Order of the local branches in the branch picker
+    #[serde(default)]
+    pub branch_sort: BranchSort,
+}
+
+/// How the branch picker orders local branches
+///
+/// Remote branches always follow the local ones in alphabetical order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BranchSort {
+    /// By name
+    #[default]
+    Alpha,
+    /// By the time of the tip commit, newest first; reads every branch's
+    /// commit, so it is opt-in for large repositories
+    CommitterDate,
+}
+
+/// Worktree list display options
+///
+/// # Example
+///
+/// ```toml
+/// [display]
+/// show_ahead_behind = true
+/// show_commits = true
+/// hidden_worktrees = ["tooling-*"]
+/// ```
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct DisplayConfig {
+    /// Show commits ahead of and behind the upstream branch in the list
+    ///
+    /// Off by default because it walks the commit graph of every worktree.
+    #[serde(default)]
+    pub show_ahead_behind: bool,
+
+    /// Show the last commit (id, summary, author, age) under each worktree
+    ///
+    /// Same as always passing `gw list --verbose`.
+    #[serde(default)]
+    pub show_commits: bool,
+
+    /// Worktree names (globs such as `tooling-*`) left out of the list
+    ///
+    /// `h` in the list shows them. Other commands, and the `--json` and
+    /// `--porcelain` output, still include them.
+    #[serde(default)]
+    pub hidden_worktrees: Vec<String>,
+}
+
+/// General behavior settings
+///
+/// # Example
+///
+/// ```toml
+/// [general]
+/// read_config_from_tree = true
+/// ```
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct GeneralConfig {
+    /// Allow this configuration to be read from the committed file on the
+    /// default branch when no checked-out copy can be found
+    ///
+    /// Useful in CI or bare-repository setups where the file is not on disk.
+    /// The setting must be present in the committed file itself.
+    #[serde(default)]
+    pub read_config_from_tree: bool,
+}
+
+/// Worktree creation preferences
+///
+/// Controls how the location of the first worktree is chosen.
+///
+/// # Example
+///
+/// ```toml
+/// [worktree]
+/// default_location = "custom"  # "same-level" | "subdirectory" | "custom"
+/// default_custom_path = "branches/{{name}}"
+/// skip_location_prompt = true
+/// branch_template = "feature/{{name}}"  # also {{user}}
+/// never_offer_branch_delete = true  # keep branches when deleting worktrees
+/// auto_suffix = true  # create "feature-2" when "feature" is taken
+/// ```
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct WorktreeConfig {
+    /// Location pattern preselected in the first-worktree prompt
+    ///
+    /// Also accepted as `default_pattern`.
+    #[serde(default, alias = "default_pattern")]
+    pub default_location: Option<String>,
+
+    /// Path offered for the custom location; `{{name}}` is replaced with the
+    /// worktree name, otherwise the name is appended as a directory
+    #[serde(default)]
+    pub default_custom_path: Option<String>,
+
+    /// Use `default_location` without asking
+    #[serde(default)]
+    pub skip_location_prompt: bool,
+
+    /// Never offer to delete branches when deleting worktrees
+    #[serde(default)]
+    pub never_offer_branch_delete: bool,
+
+    /// Default name for new branches; `{{name}}` is the worktree name and
+    /// `{{user}}` is `$USER` (or `$USERNAME`)
+    #[serde(default)]
+    pub branch_template: Option<String>,
+
+    /// Add `-2`, `-3`, ... to a new worktree's name when it is already taken
+    /// instead of failing
+    #[serde(default)]
+    pub auto_suffix: bool,
+}
+
+/// A single entry in `[files] copy`
+///
+/// Entries are either a plain path or a table that overrides settings for
+/// that path:
+///
+/// ```toml
+/// [files]
+/// copy = [".env", { path = "data.bin", max_size_mb = 500 }]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum CopyEntry {
+    /// A path relative to the source directory
+    Path(String),
+    /// A path with per-entry settings
+    Detailed {
+        path: String,
+        /// Size limit for this entry, overriding `[files] max_size_mb`
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_size_mb: Option<u64>,
+    },
+}
+
+impl CopyEntry {
+    /// Returns the path to copy
+    pub fn path(&self) -> &str {
+        match self {
+            CopyEntry::Path(path) => path,
+            CopyEntry::Detailed { path, .. } => path,
+        }
+    }
+
+    /// Returns the per-entry size limit, if any
+    pub fn max_size_mb(&self) -> Option<u64> {
+        match self {
+            CopyEntry::Path(_) => None,
+            CopyEntry::Detailed { max_size_mb, .. } => *max_size_mb,
+        }
+    }
+}
+
+impl From<&str> for CopyEntry {
+    fn from(path: &str) -> Self {
+        CopyEntry::Path(path.to_string())
+    }
+}
+
+impl From<String> for CopyEntry {
+    fn from(path: String) -> Self {
+        CopyEntry::Path(path)
+    }
+}
+
+impl PartialEq<&str> for CopyEntry {
+    fn eq(&self, other: &&str) -> bool {
+        self.path() == *other
+    }
+}
+
+/// A single hook command
+///
+/// Hooks are either a plain command string or a table that also sets the
+/// working directory and environment for that command:
+///
+/// ```toml
+/// [hooks]
+/// post-create = [
+///     "cp .env.example .env",
+///     { command = "npm install", workdir = "frontend", env = { NODE_ENV = "development" } },
+/// ]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum HookEntry {
+    /// A shell command run in the default hook directory
+    Command(String),
+    /// A shell command with per-command settings
+    Detailed {
+        command: String,
+        /// Directory to run in, relative to the default hook directory
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        workdir: Option<String>,
+        /// Extra environment variables for the command
+        #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+        env: HashMap<String, String>,
+    },
+}
+
+impl HookEntry {
+    /// Returns the shell command to run
+    pub fn command(&self) -> &str {
+        match self {
+            HookEntry::Command(command) => command,
+            HookEntry::Detailed { command, .. } => command,
+        }
+    }
+
+    /// Returns the working directory override, if any
+    pub fn workdir(&self) -> Option<&str> {
+        match self {
+            HookEntry::Command(_) => None,
+            HookEntry::Detailed { workdir, .. } => workdir.as_deref(),
+        }
+    }
+
+    /// Returns the extra environment variables, sorted by name
+    pub fn env(&self) -> Vec<(&str, &str)> {
+        let mut env: Vec<(&str, &str)> = match self {
+            HookEntry::Command(_) => Vec::new(),
+            HookEntry::Detailed { env, .. } => env
+                .iter()
+                .map(|(key, value)| (key.as_str(), value.as_str()))
+                .collect(),
+        };
+        env.sort();
+        env
+    }
+}
+
+impl From<&str> for HookEntry {
+    fn from(command: &str) -> Self {
+        HookEntry::Command(command.to_string())
+    }
+}
+
+impl From<String> for HookEntry {
+    fn from(command: String) -> Self {
+        HookEntry::Command(command)
+    }
+}
+
+impl PartialEq<&str> for HookEntry {
+    fn eq(&self, other: &&str) -> bool {
+        self.command() == *other
+    }
+}
+
+/// The `[hooks]` section: hook commands by event plus the failure policy
+///
+/// Dereferences to the map of hook names to commands, so lookups work as
+/// on a plain map.
+///
+/// ```toml
+/// [hooks]
+/// on_failure = "abort"  # "warn" (default) | "abort"
+/// post-create = ["npm install"]
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct HooksConfig {
+    /// What to do when a `pre-create` or `post-create` hook fails
+    #[serde(default)]
+    pub on_failure: HookFailurePolicy,
+
+    /// Hook commands keyed by hook name (`post-create`, `pre-remove`, ...)
+    #[serde(flatten)]
+    pub commands: HashMap<String, Vec<HookEntry>>,
+}
+
+impl HooksConfig {
+    /// Hook keys that are not a known event, each with the closest known event
+    ///
+    /// Such hooks never run. They are reported rather than rejected so that a
+    /// configuration written for a newer version still loads.
+    pub fn unknown_events(&self) -> Vec<(&str, Option<&'static str>)> {
+        let mut unknown: Vec<(&str, Option<&'static str>)> = self
+            .commands
+            .keys()
+            .filter(|event| !KNOWN_HOOK_EVENTS.contains(&event.as_str()))
+            .map(|event| (event.as_str(), closest_match(event, KNOWN_HOOK_EVENTS)))
+            .collect();
+        unknown.sort();
+        unknown
+    }
+
+    /// Prints a warning to stderr for every hook key that is not a known event
+    ///
+    /// Only the first configuration with unknown events in a process is
+    /// reported; `gw config validate` lists them on every run.
+    fn warn_unknown_events(&self) {
+        let unknown = self.unknown_events();
+        if unknown.is_empty() || UNKNOWN_EVENTS_WARNED.swap(true, Ordering::Relaxed) {
+            return;
+        }
+        for (event, suggestion) in unknown {
+            let mut message = WARNING_UNKNOWN_HOOK_EVENT
+                .replacen("{}", event, 1)
+                .replacen("{}", CONFIG_FILE_NAME, 1);
+            if let Some(suggestion) = suggestion {
+                message.push_str(&HINT_DID_YOU_MEAN.replace("{}", suggestion));
+            }
+            eprintln!("{message}");
+        }
+    }
+}
+
+impl Deref for HooksConfig {
+    type Target = HashMap<String, Vec<HookEntry>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.commands
+    }
+}
+
+impl DerefMut for HooksConfig {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.commands
+    }
+}
+
+/// How worktree creation reacts to a failed `pre-create` or `post-create` hook
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HookFailurePolicy {
+    /// Print a warning and continue
+    #[default]
+    Warn,
+    /// Report an error; a failed `pre-create` hook cancels creation, and a
+    /// failed file copy or `post-create` hook offers to remove the new
+    /// worktree together with the branch it created
+    Abort,
 }
 
 /// File copy configuration for worktree creation
@@ -101,7 +684,7 @@ pub struct FilesConfig {
     /// copy = [".env", ".env.local", "config/local.json"]
     /// ```
     #[serde(default)]
-    pub copy: Vec<String>,
+    pub copy: Vec<CopyEntry>,
 
     /// Source directory for files to copy
     ///
@@ -109,6 +692,44 @@ pub struct FilesConfig {
     /// Must be an absolute path or relative to the repository root.
     #[serde(default)]
     pub source: Option<String>,
+
+    /// Size limit in megabytes for each copied file
+    ///
+    /// Files larger than this are skipped. Defaults to `MAX_FILE_SIZE_MB`
+    /// and can be overridden per entry.
+    #[serde(default)]
+    pub max_size_mb: Option<u64>,
+
+    /// Git ref to read the files from instead of the source directory
+    ///
+    /// When set (e.g. `"HEAD"` or `"main"`), files are written as committed
+    /// on that ref, so local modifications in the main worktree are not
+    /// copied. Only committed files can be copied this way; `source` is
+    /// ignored.
+    #[serde(default)]
+    pub source_ref: Option<String>,
+
+    /// Paths left out when a directory is copied
+    ///
+    /// `.gitignore`-style patterns matched inside copied directories, e.g.
+    /// `["node_modules", "*.log", "target/"]`. Entries listed in `copy`
+    /// are copied even if they match.
+    #[serde(default)]
+    pub copy_exclude: Vec<String>,
+}
+
+impl FilesConfig {
+    /// Pure business logic for resolving the size limit of a copy entry in bytes
+    ///
+    /// The entry's own `max_size_mb` wins over the section-wide value, which
+    /// in turn falls back to `MAX_FILE_SIZE_MB`.
+    pub fn max_size_bytes(&self, entry: &CopyEntry) -> u64 {
+        let mb = entry
+            .max_size_mb()
+            .or(self.max_size_mb)
+            .unwrap_or(MAX_FILE_SIZE_MB);
+        mb.saturating_mul(BYTES_PER_MB)
+    }
 }
 
 /// Repository-specific configuration
@@ -137,15 +758,432 @@ pub struct RepositoryConfig {
     /// - Trailing `.git` is ignored
     /// - Trailing slashes are ignored
     /// - Comparison is case-insensitive
+    ///
+    /// See [`url_match`](Self::url_match) for how strictly they are compared.
     pub url: Option<String>,
+
+    /// How strictly `url` must match the `origin` remote
+    ///
+    /// ```toml
+    /// [repository]
+    /// url_match = "exact"  # "host-path" (default) | "exact" | "off"
+    /// ```
+    #[serde(default)]
+    pub url_match: UrlMatchMode,
+}
+
+/// How the configured repository URL is compared with `origin`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum UrlMatchMode {
+    /// Host and path must match; the protocol and user are ignored, so
+    /// `git@github.com:owner/repo.git` matches `https://github.com/owner/repo`
+    #[default]
+    HostPath,
+    /// The whole URL must match
+    Exact,
+    /// Do not check the URL
+    Off,
+}
+
+/// Pure business logic for the root of a `.bare` layout
+///
+/// In the "bare + worktrees" layout the repository is cloned into
+/// `<root>/.bare`, usually with a `<root>/.git` file pointing at it, and the
+/// worktrees are created under `<root>`. Returns `<root>` when `commondir`
+/// is such a `.bare` directory.
+pub fn bare_layout_root(commondir: &Path) -> Option<&Path> {
+    if commondir.file_name()? != BARE_SUFFIX {
+        return None;
+    }
+    commondir.parent()
+}
+
+/// Finds the configuration file of a `.bare` layout
+///
+/// Checks the layout root, then the worktree that has the default branch
+/// (`HEAD` of the bare repository) checked out, or `<root>/<branch>` when no
+/// worktree has it. Returns `None` outside a `.bare` layout or when neither
+/// has a `.git-workers.toml`.
+pub fn find_bare_layout_config(repo: &git2::Repository) -> Option<PathBuf> {
+    bare_layout_candidates(repo)
+        .into_iter()
+        .find(|path| path.exists())
+}
+
+/// The places [`find_bare_layout_config`] checks, in order
+fn bare_layout_candidates(repo: &git2::Repository) -> Vec<PathBuf> {
+    let Some(root) = bare_layout_root(repo.commondir()) else {
+        return Vec::new();
+    };
+    let mut candidates = vec![root.join(CONFIG_FILE_NAME)];
+
+    let Ok(common) = git2::Repository::open(repo.commondir()) else {
+        return candidates;
+    };
+    let default_branch = common
+        .head()
+        .ok()
+        .and_then(|head| head.shorthand().map(str::to_string))
+        .unwrap_or_else(|| DEFAULT_BRANCH_MAIN.to_string());
+    let worktree_dir = common
+        .worktrees()
+        .iter()
+        .flat_map(|names| names.iter().flatten())
+        .filter_map(|name| common.find_worktree(name).ok())
+        .map(|worktree| worktree.path().to_path_buf())
+        .find(|path| {
+            worktree_head_branch(path)
+                .ok()
+                .flatten()
+                .is_some_and(|branch| branch == default_branch)
+        })
+        .unwrap_or_else(|| root.join(&default_branch));
+    candidates.push(worktree_dir.join(CONFIG_FILE_NAME));
+    candidates
+}
+
+/// Pure business logic for the config files under `dir` named after a
+/// default branch
+///
+/// `<dir>/<default_branch>` comes first, then `main` and `master` unless one
+/// of them is the default branch.
+pub fn default_branch_config_candidates(dir: &Path, default_branch: &str) -> Vec<PathBuf> {
+    let mut candidates = vec![dir.join(default_branch).join(CONFIG_FILE_NAME)];
+    for branch in [DEFAULT_BRANCH_MAIN, DEFAULT_BRANCH_MASTER] {
+        if branch != default_branch {
+            candidates.push(dir.join(branch).join(CONFIG_FILE_NAME));
+        }
+    }
+    candidates
+}
+
+/// Every place `.git-workers.toml` is looked for, in the order checked
+///
+/// The first one that exists is the configuration file, see
+/// [`resolve_config_path`]. Both [`Config::load`] and the commands that edit
+/// the file use this list, so they always agree on the file.
+///
+/// # `.bare` Layout
+///
+/// When the repository lives in `<root>/.bare`, only `<root>/.git-workers.toml`
+/// and then the default branch's worktree are checked, from the root and
+/// from every worktree alike.
+///
+/// # Bare Repositories
+///
+/// For bare repositories (e.g., `/path/to/repo.git`):
+/// 1. The current directory
+/// 2. Default branch subdirectories (e.g., `./main/.git-workers.toml`)
+/// 3. Default branch directories next to the existing worktrees, when
+///    `git worktree list` shows they share a parent
+/// 4. Common directory names (`branch/<default>`, `worktrees/<default>`)
+/// 5. A sibling directory named after the default branch
+///
+/// # Non-bare Repositories
+///
+/// For regular repositories:
+/// 1. In a linked worktree, the main worktree (its file wins over the copy
+///    checked out here, see `[config] allow_worktree_override`)
+/// 2. The current directory
+/// 3. The repository root, when the current directory is `worktrees/` or a
+///    worktree inside it, and `main/` or `master/` next to a linked worktree
+/// 4. The current worktree, when it is the main one
+pub fn config_candidates(repo: &git2::Repository) -> Vec<PathBuf> {
+    if bare_layout_root(repo.commondir()).is_some() {
+        return bare_layout_candidates(repo);
+    }
+
+    let cwd = std::env::current_dir().ok();
+    let mut candidates = Vec::new();
+    if repo.is_bare() {
+        if let Some(cwd) = &cwd {
+            candidates.extend(bare_repository_candidates(repo, cwd));
+        }
+    } else {
+        let commondir = repo.commondir();
+        if repo.is_worktree() && commondir.file_name().is_some_and(|name| name == GIT_DIR) {
+            candidates.extend(commondir.parent().map(|dir| dir.join(CONFIG_FILE_NAME)));
+        }
+        if let Some(cwd) = &cwd {
+            candidates.extend(worktree_candidates(cwd));
+        }
+        if let Some(workdir) = repo.workdir() {
+            if workdir.join(GIT_DIR).is_dir() {
+                candidates.push(workdir.join(CONFIG_FILE_NAME));
+            }
+        }
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    candidates.retain(|path| seen.insert(path.clone()));
+    candidates
+}
+
+/// The configuration file in use: the first of [`config_candidates`] that exists
+///
+/// Returns `None` when there is none; [`Config::load`] then falls back to a
+/// committed configuration or the global one.
+pub fn resolve_config_path(repo: &git2::Repository) -> Option<PathBuf> {
+    config_candidates(repo)
+        .into_iter()
+        .find(|path| path.exists())
+}
+
+/// Candidates of a bare repository, relative to the current directory
+fn bare_repository_candidates(repo: &git2::Repository, cwd: &Path) -> Vec<PathBuf> {
+    // Get the default branch name from HEAD
+    let default_branch = repo
+        .head()
+        .ok()
+        .and_then(|head| head.shorthand().map(str::to_string))
+        .unwrap_or_else(|| DEFAULT_BRANCH_MAIN.to_string());
+
+    let mut candidates = vec![cwd.join(CONFIG_FILE_NAME)];
+    candidates.extend(default_branch_config_candidates(cwd, &default_branch));
+
+    // Detect the worktree pattern from the existing worktrees
+    if let Ok(output) = std::process::Command::new(GIT_CMD)
+        .args([GIT_WORKTREE, GIT_LIST, GIT_OPT_PORCELAIN])
+        .current_dir(cwd)
+        .output()
+    {
+        let worktree_paths: Vec<PathBuf> =
+            parse_worktree_porcelain(&String::from_utf8_lossy(&output.stdout))
+                .into_iter()
+                .map(|worktree| worktree.path)
+                .collect();
+        let parent_dirs: Vec<&Path> = worktree_paths.iter().filter_map(|p| p.parent()).collect();
+        if let Some(first_parent) = parent_dirs.first() {
+            if parent_dirs.iter().all(|p| p == first_parent) {
+                candidates.extend(default_branch_config_candidates(
+                    first_parent,
+                    &default_branch,
+                ));
+            }
+        }
+    }
+
+    for subdir in [
+        crate::constants::BRANCH_SUBDIR,
+        crate::constants::WORKTREES_SUBDIR,
+    ] {
+        candidates.push(
+            cwd.join(subdir)
+                .join(&default_branch)
+                .join(CONFIG_FILE_NAME),
+        );
+    }
+    if let Some(parent) = cwd.parent() {
+        candidates.push(parent.join(&default_branch).join(CONFIG_FILE_NAME));
+    }
+    candidates
+}
+
+/// Candidates of a non-bare repository, relative to the current directory
+fn worktree_candidates(cwd: &Path) -> Vec<PathBuf> {
+    let mut candidates = vec![cwd.join(CONFIG_FILE_NAME)];
+    let is_main_repository = |dir: &Path| dir.join(GIT_DIR).is_dir();
+
+    if cwd
+        .file_name()
+        .is_some_and(|n| n == crate::constants::WORKTREES_SUBDIR)
+    {
+        // We're in the worktrees directory itself
+        if let Some(parent) = cwd.parent().filter(|dir| is_main_repository(dir)) {
+            candidates.push(parent.join(CONFIG_FILE_NAME));
+        }
+    } else if !is_main_repository(cwd) {
+        // A linked worktree: look for the main/master worktree
+        if let Some(parent) = cwd.parent() {
+            if parent
+                .file_name()
+                .is_some_and(|n| n == crate::constants::WORKTREES_SUBDIR)
+            {
+                // In a worktrees subdirectory, go up to the repository root
+                if let Some(repo_root) = parent.parent() {
+                    if is_main_repository(repo_root) {
+                        candidates.push(repo_root.join(CONFIG_FILE_NAME));
+                    }
+                    for branch in [DEFAULT_BRANCH_MAIN, DEFAULT_BRANCH_MASTER] {
+                        candidates.push(repo_root.join(branch).join(CONFIG_FILE_NAME));
+                    }
+                }
+            } else {
+                for branch in [DEFAULT_BRANCH_MAIN, DEFAULT_BRANCH_MASTER] {
+                    candidates.push(parent.join(branch).join(CONFIG_FILE_NAME));
+                }
+            }
+        }
+    }
+    candidates
+}
+
+/// Pure business logic for normalizing a repository URL to `host/path`
+///
+/// Accepts `scheme://[user@]host[:port]/path`, scp-style `[user@]host:path`
+/// and local paths (which have no host). The result is lowercase, without a
+/// trailing `.git` or slashes.
+pub fn normalize_repository_url(url: &str) -> String {
+    let url = url.trim();
+    let (host, path) = if let Some((_, rest)) = url.split_once("://") {
+        let (authority, path) = rest.split_once('/').unwrap_or((rest, ""));
+        let host = authority.rsplit('@').next().unwrap_or(authority);
+        let host = host.split(':').next().unwrap_or(host);
+        (host, path)
+    } else {
+        match url.split_once(':') {
+            // scp-style, unless the colon comes after a slash (a local path)
+            Some((authority, path)) if !authority.contains('/') => {
+                (authority.rsplit('@').next().unwrap_or(authority), path)
+            }
+            _ => ("", url),
+        }
+    };
+
+    let path = path
+        .trim_matches('/')
+        .trim_end_matches(GIT_URL_SUFFIX)
+        .trim_end_matches('/');
+    format!("{host}/{path}").to_lowercase()
+}
+
+/// Pure business logic for comparing a configured URL with the actual one
+pub fn repository_urls_match(expected: &str, actual: &str, mode: UrlMatchMode) -> bool {
+    match mode {
+        UrlMatchMode::Off => true,
+        UrlMatchMode::Exact => {
+            let normalize = |url: &str| {
+                url.trim_end_matches(GIT_URL_SUFFIX)
+                    .trim_end_matches('/')
+                    .to_lowercase()
+            };
+            normalize(expected) == normalize(actual)
+        }
+        UrlMatchMode::HostPath => {
+            normalize_repository_url(expected) == normalize_repository_url(actual)
+        }
+    }
+}
+
+/// The global configuration file, from `XDG_CONFIG_HOME` and `HOME`
+pub fn global_config_file() -> Option<PathBuf> {
+    let xdg_config_home = std::env::var_os(ENV_XDG_CONFIG_HOME).map(PathBuf::from);
+    let home = std::env::var_os(ENV_HOME).map(PathBuf::from);
+    global_config_path(xdg_config_home.as_deref(), home.as_deref())
+}
+
+/// Pure business logic for locating the global configuration file
+///
+/// Uses `XDG_CONFIG_HOME` when it is an absolute path (relative values are
+/// ignored, as the XDG spec requires) and `~/.config` otherwise.
+pub fn global_config_path(xdg_config_home: Option<&Path>, home: Option<&Path>) -> Option<PathBuf> {
+    let base = match xdg_config_home.filter(|dir| dir.is_absolute()) {
+        Some(dir) => dir.to_path_buf(),
+        None => home?.join(XDG_DEFAULT_CONFIG_DIR),
+    };
+    Some(
+        base.join(GLOBAL_CONFIG_DIR_NAME)
+            .join(GLOBAL_CONFIG_FILE_NAME),
+    )
+}
+
+/// Pure business logic for layering a repository configuration over the global one
+///
+/// Sections are merged key by key and repository keys win, except that
+/// `[files] copy` lists are concatenated (a repository entry replaces a
+/// global entry for the same path) and `[hooks]` events run the global
+/// commands before the repository's.
+pub fn merge_config_values(global: toml::Value, local: toml::Value) -> toml::Value {
+    layer_config_values(global, local, true)
+}
+
+/// Pure business logic for layering a worktree configuration over the main one
+///
+/// Sections are merged key by key and worktree keys win; unlike
+/// [`merge_config_values`], lists are replaced rather than concatenated.
+pub fn override_config_values(main: toml::Value, worktree: toml::Value) -> toml::Value {
+    layer_config_values(main, worktree, false)
+}
+
+/// Layers `top` over `base` section by section, optionally combining lists
+fn layer_config_values(base: toml::Value, top: toml::Value, combine_lists: bool) -> toml::Value {
+    let (mut merged, top) = match (base, top) {
+        (toml::Value::Table(base), toml::Value::Table(top)) => (base, top),
+        (_, top) => return top,
+    };
+
+    for (section, top_value) in top {
+        let value = match (merged.remove(&section), top_value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(top)) => {
+                toml::Value::Table(merge_section(&section, base, top, combine_lists))
+            }
+            (_, top_value) => top_value,
+        };
+        merged.insert(section, value);
+    }
+    toml::Value::Table(merged)
+}
+
+/// Merges the keys of one configuration section
+fn merge_section(
+    section: &str,
+    mut merged: toml::Table,
+    local: toml::Table,
+    combine_lists: bool,
+) -> toml::Table {
+    for (key, local_value) in local {
+        let value = match (merged.remove(&key), local_value) {
+            (Some(toml::Value::Array(global)), toml::Value::Array(local))
+                if combine_lists && section == CONFIG_SECTION_FILES && key == CONFIG_KEY_COPY =>
+            {
+                toml::Value::Array(merge_copy_entries(global, local))
+            }
+            (Some(toml::Value::Array(mut global)), toml::Value::Array(local))
+                if combine_lists && section == CONFIG_SECTION_HOOKS =>
+            {
+                global.extend(local);
+                toml::Value::Array(global)
+            }
+            (_, local_value) => local_value,
+        };
+        merged.insert(key, value);
+    }
+    merged
+}
+
+/// Appends `local` copy entries, replacing global entries with the same path
+fn merge_copy_entries(mut merged: Vec<toml::Value>, local: Vec<toml::Value>) -> Vec<toml::Value> {
+    for entry in local {
+        let existing = copy_entry_path(&entry).and_then(|path| {
+            merged
+                .iter()
+                .position(|other| copy_entry_path(other) == Some(path))
+        });
+        match existing {
+            Some(index) => merged[index] = entry,
+            None => merged.push(entry),
+        }
+    }
+    merged
+}
+
+/// Returns the path of a raw `[files] copy` entry
+fn copy_entry_path(entry: &toml::Value) -> Option<&str> {
+    match entry {
+        toml::Value::String(path) => Some(path),
+        toml::Value::Table(table) => table.get(CONFIG_KEY_PATH)?.as_str(),
+        _ => None,
+    }
 }
 
 impl Config {
     /// Loads configuration from the default branch
     ///
     /// This method loads the configuration file from the default branch (main or master).
-    /// If no configuration file exists in the default branch, a default empty
-    /// configuration is returned.
+    /// The global configuration (see the module docs) is layered underneath.
+    /// If no configuration file exists, the global configuration or a
+    /// default empty configuration is returned.
     ///
     /// # Returns
     ///
@@ -159,8 +1197,8 @@ impl Config {
     ///
     /// let config = Config::load().expect("Failed to load config");
     /// if let Some(post_create_hooks) = config.hooks.get("post-create") {
-    ///     for command in post_create_hooks {
-    ///         println!("Will run: {}", command);
+    ///     for hook in post_create_hooks {
+    ///         println!("Will run: {}", hook.command());
     ///     }
     /// }
     /// ```
@@ -169,12 +1207,15 @@ impl Config {
         if let Ok(repo) = git2::Repository::discover(".") {
             // Only load from the main repository directory
             if let Some(config) = Self::load_from_main_repository_only(&repo)? {
-                return Ok(config);
+                return Ok(config.with_worktree_override(&repo));
+            }
+            if let Some(config) = Self::load_from_tree(&repo)? {
+                return Ok(config.with_worktree_override(&repo));
             }
         }
 
-        // Return default config if no config file found
-        Ok(Config::default())
+        // Fall back to the global config if no config file found
+        Ok(Self::global_or_default())
     }
 
     /// Loads configuration from a specific path context
@@ -189,8 +1230,8 @@ impl Config {
     ///
     /// # Returns
     ///
-    /// A `Result` containing the loaded configuration or a default configuration
-    /// if no config file is found.
+    /// A `Result` containing the loaded configuration or the global (or
+    /// default) configuration if no config file is found.
     ///
     /// # Example
     ///
@@ -207,36 +1248,102 @@ impl Config {
         if let Ok(repo) = git2::Repository::discover(path) {
             // Use the same loading logic as load()
             if let Some(config) = Self::load_from_main_repository_only(&repo)? {
-                return Ok(config);
+                return Ok(config.with_worktree_override(&repo));
+            }
+            if let Some(config) = Self::load_from_tree(&repo)? {
+                return Ok(config.with_worktree_override(&repo));
             }
         }
 
-        // Return default config if no repo found
-        Ok(Config::default())
+        // Fall back to the global config if no config file found
+        Ok(Self::global_or_default())
     }
 
-    /// Loads configuration with repository-aware strategy
-    ///
-    /// This method implements different loading strategies for bare and non-bare repositories:
-    ///
-    /// # Bare Repositories
-    ///
-    /// For bare repositories (e.g., `/path/to/repo.git`), the method:
-    /// 1. Checks the current directory for a config file
-    /// 2. Looks for config in default branch subdirectories (e.g., `./main/.git-workers.toml`)
-    /// 3. Detects existing worktree patterns using `git worktree list`
-    /// 4. Falls back to common directory names (`branch/`, `worktrees/`)
-    ///
-    /// # Non-bare Repositories
+    /// Reads the global configuration file as a raw TOML value
     ///
-    /// For regular repositories, the method:
-    /// 1. Checks the current directory
-    /// 2. Finds the main repository directory (where `.git` is a directory, not a file)
-    /// 3. Checks for `main/` or `master/` subdirectories in parent paths
-    ///
-    /// # Arguments
+    /// Returns `None` when there is no global file or it cannot be parsed
+    /// (with a warning). `[repository]` is dropped: a URL in a file shared by
+    /// every repository would disable the configuration everywhere else.
+    fn load_global_value() -> Option<toml::Value> {
+        let path = global_config_file()?;
+        let content = std::fs::read_to_string(&path).ok()?;
+
+        if let Err(e) = toml::from_str::<Config>(&content) {
+            eprintln!(
+                "{}",
+                ERROR_CONFIG_PARSE
+                    .replacen("{}", &path.display().to_string(), 1)
+                    .replacen("{}", &e.to_string(), 1)
+            );
+            return None;
+        }
+        let mut value = toml::from_str::<toml::Value>(&content).ok()?;
+        if let Some(table) = value.as_table_mut() {
+            table.remove(CONFIG_SECTION_REPOSITORY);
+        }
+        Some(value)
+    }
+
+    /// The global configuration, or a default one if there is none
+    fn global_or_default() -> Self {
+        Self::load_global_value()
+            .and_then(|value| value.try_into().ok())
+            .unwrap_or_default()
+    }
+
+    /// Layers the `.git-workers.toml` of the current worktree over `self`
     ///
-    /// * `repo` - The Git repository reference
+    /// Only done when `self` sets `[config] allow_worktree_override`. A file
+    /// that cannot be parsed is reported and ignored.
+    fn with_worktree_override(self, repo: &git2::Repository) -> Self {
+        if !self.config.allow_worktree_override {
+            return self;
+        }
+        let Some(path) = repo.workdir().map(|dir| dir.join(CONFIG_FILE_NAME)) else {
+            return self;
+        };
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            return self;
+        };
+
+        if let Err(e) = toml::from_str::<Config>(&content) {
+            eprintln!(
+                "{}",
+                ERROR_CONFIG_PARSE
+                    .replacen("{}", &path.display().to_string(), 1)
+                    .replacen("{}", &e.to_string(), 1)
+            );
+            return self;
+        }
+        let (Ok(base), Ok(mut overlay)) = (
+            toml::Value::try_from(&self),
+            toml::from_str::<toml::Value>(&content),
+        ) else {
+            return self;
+        };
+        if let Some(table) = overlay.as_table_mut() {
+            table.remove(CONFIG_SECTION_REPOSITORY);
+            table.remove(CONFIG_SECTION_CONFIG);
+        }
+        override_config_values(base, overlay)
+            .try_into()
+            .unwrap_or(self)
+    }
+
+    /// Layers `config`, parsed from `content`, over the global configuration
+    fn with_global_defaults(self, content: &str) -> Self {
+        let Some(global) = Self::load_global_value() else {
+            return self;
+        };
+        let Ok(local) = toml::from_str::<toml::Value>(content) else {
+            return self;
+        };
+        merge_config_values(global, local)
+            .try_into()
+            .unwrap_or(self)
+    }
+
+    /// Loads the configuration file found on disk, see [`config_candidates`]
     ///
     /// # Returns
     ///
@@ -244,192 +1351,9 @@ impl Config {
     /// * `Ok(None)` - No configuration file exists
     /// * `Err(...)` - An error occurred while loading
     fn load_from_main_repository_only(repo: &git2::Repository) -> Result<Option<Self>> {
-        if repo.is_bare() {
-            // For bare repositories:
-            // Get the default branch name from HEAD
-            let default_branch = if let Ok(head) = repo.head() {
-                head.shorthand()
-                    .unwrap_or(crate::constants::DEFAULT_BRANCH_MAIN)
-                    .to_string()
-            } else {
-                // Fallback to common default branch names
-                crate::constants::DEFAULT_BRANCH_MAIN.to_string()
-            };
-
-            if let Ok(cwd) = std::env::current_dir() {
-                // 1. First check current directory for config
-                let current_config = cwd.join(CONFIG_FILE_NAME);
-                if current_config.exists() {
-                    return Self::load_from_file(&current_config, repo);
-                }
-
-                // 2. If we're in a directory that might contain worktrees, check for default branch
-                let default_config_in_current = cwd.join(&default_branch).join(CONFIG_FILE_NAME);
-                if default_config_in_current.exists() {
-                    return Self::load_from_file(&default_config_in_current, repo);
-                }
-
-                // Also check main/master if different from default
-                if let Some(config_path) = crate::utils::find_config_in_default_branches(
-                    &cwd,
-                    &default_branch,
-                    CONFIG_FILE_NAME,
-                ) {
-                    return Self::load_from_file(&config_path, repo);
-                }
-
-                // 2. Try to detect worktree pattern by listing existing worktrees
-                if let Ok(output) = std::process::Command::new(GIT_CMD)
-                    .args([GIT_WORKTREE, GIT_LIST, GIT_OPT_PORCELAIN])
-                    .current_dir(&cwd)
-                    .output()
-                {
-                    let worktree_paths = String::from_utf8_lossy(&output.stdout)
-                        .lines()
-                        .filter_map(|line| {
-                            if line.starts_with(PORCELAIN_WORKTREE) {
-                                Some(line.trim_start_matches(PORCELAIN_WORKTREE).to_string())
-                            } else {
-                                None
-                            }
-                        })
-                        .collect::<Vec<_>>();
-
-                    // Find common parent directory of worktrees
-                    if !worktree_paths.is_empty() {
-                        let parent_dirs: Vec<_> = worktree_paths
-                            .iter()
-                            .filter_map(|p| std::path::Path::new(p).parent())
-                            .collect();
-
-                        // Check if all worktrees share a common parent
-                        if let Some(first_parent) = parent_dirs.first() {
-                            if parent_dirs.iter().all(|p| p == first_parent) {
-                                // Look for default branch in the common parent
-                                let default_config =
-                                    first_parent.join(&default_branch).join(CONFIG_FILE_NAME);
-                                if default_config.exists() {
-                                    return Self::load_from_file(&default_config, repo);
-                                }
-
-                                // Fallback to main/master
-                                if let Some(config_path) =
-                                    crate::utils::find_config_in_default_branches(
-                                        first_parent,
-                                        &default_branch,
-                                        CONFIG_FILE_NAME,
-                                    )
-                                {
-                                    return Self::load_from_file(&config_path, repo);
-                                }
-                            }
-                        }
-                    }
-                }
-
-                // 3. Fallback: Check common subdirectories
-                for subdir in &[
-                    crate::constants::BRANCH_SUBDIR,
-                    crate::constants::WORKTREES_SUBDIR,
-                ] {
-                    let branch_path = cwd
-                        .join(subdir)
-                        .join(&default_branch)
-                        .join(CONFIG_FILE_NAME);
-                    if branch_path.exists() {
-                        return Self::load_from_file(&branch_path, repo);
-                    }
-                }
-
-                // 4. Check sibling directories (same level as current)
-                if let Some(parent) = cwd.parent() {
-                    let default_path = parent.join(&default_branch).join(CONFIG_FILE_NAME);
-                    if default_path.exists() {
-                        return Self::load_from_file(&default_path, repo);
-                    }
-                }
-            }
-
-            // No config found
-            Ok(None)
-        } else {
-            // For non-bare repositories:
-            // 1. First check current directory (current worktree)
-            if let Ok(cwd) = std::env::current_dir() {
-                let config_path = cwd.join(CONFIG_FILE_NAME);
-                if config_path.exists() {
-                    return Self::load_from_file(&config_path, repo);
-                }
-
-                // 2. Then check main/master default branch worktree
-                // Check if current directory is named "worktrees" or inside a worktree
-                if cwd
-                    .file_name()
-                    .is_some_and(|n| n == crate::constants::WORKTREES_SUBDIR)
-                {
-                    // We're in the worktrees directory itself
-                    if let Some(parent) = cwd.parent() {
-                        let main_config = parent.join(CONFIG_FILE_NAME);
-                        if main_config.exists() && parent.join(GIT_DIR).is_dir() {
-                            return Self::load_from_file(&main_config, repo);
-                        }
-                    }
-                } else {
-                    let git_path = cwd.join(GIT_DIR);
-                    if !git_path.is_dir() {
-                        // This is a linked worktree, find the main/master worktree
-                        if let Some(parent) = cwd.parent() {
-                            // Check if we're in a worktrees subdirectory
-                            if parent
-                                .file_name()
-                                .is_some_and(|n| n == crate::constants::WORKTREES_SUBDIR)
-                            {
-                                // Go up one more level to repository root
-                                if let Some(repo_root) = parent.parent() {
-                                    // Check for main worktree
-                                    let main_config = repo_root.join(CONFIG_FILE_NAME);
-                                    if main_config.exists() && repo_root.join(GIT_DIR).is_dir() {
-                                        return Self::load_from_file(&main_config, repo);
-                                    }
-
-                                    // Also check main/master subdirectories
-                                    let main_path = repo_root
-                                        .join(crate::constants::DEFAULT_BRANCH_MAIN)
-                                        .join(CONFIG_FILE_NAME);
-                                    if main_path.exists() {
-                                        return Self::load_from_file(&main_path, repo);
-                                    }
-
-                                    let master_path = repo_root
-                                        .join(crate::constants::DEFAULT_BRANCH_MASTER)
-                                        .join(CONFIG_FILE_NAME);
-                                    if master_path.exists() {
-                                        return Self::load_from_file(&master_path, repo);
-                                    }
-                                }
-                            } else {
-                                // Not in worktrees subdirectory, check parent for main/master
-                                let main_path = parent
-                                    .join(crate::constants::DEFAULT_BRANCH_MAIN)
-                                    .join(CONFIG_FILE_NAME);
-                                if main_path.exists() {
-                                    return Self::load_from_file(&main_path, repo);
-                                }
-
-                                let master_path = parent
-                                    .join(crate::constants::DEFAULT_BRANCH_MASTER)
-                                    .join(CONFIG_FILE_NAME);
-                                if master_path.exists() {
-                                    return Self::load_from_file(&master_path, repo);
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-
-            // No config found
-            Ok(None)
+        match resolve_config_path(repo) {
+            Some(path) => Self::load_from_file(&path, repo),
+            None => Ok(None),
         }
     }
 
@@ -473,7 +1397,16 @@ impl Config {
             }
         };
 
-        let config = match toml::from_str::<Config>(&content) {
+        Ok(Self::parse_and_validate(&content, repo))
+    }
+
+    /// Parses configuration content and validates the repository URL
+    ///
+    /// Returns `None` (with a warning) when the content cannot be parsed, and
+    /// the global (or default) configuration when the repository URL does not
+    /// match. Otherwise the result is layered over the global configuration.
+    fn parse_and_validate(content: &str, repo: &git2::Repository) -> Option<Self> {
+        let config = match toml::from_str::<Config>(content) {
             Ok(c) => c,
             Err(e) => {
                 eprintln!(
@@ -482,18 +1415,60 @@ impl Config {
                         .replace("{}", CONFIG_FILE_NAME)
                         .replace("{}", &e.to_string())
                 );
-                return Ok(None);
+                return None;
             }
         };
+        config.hooks.warn_unknown_events();
 
         // Validate repository URL if specified
         if let Some(expected_url) = &config.repository.url {
-            if !Self::validate_repository_url(repo, expected_url) {
-                return Ok(Some(Config::default()));
+            if !Self::validate_repository_url(repo, expected_url, config.repository.url_match) {
+                return Some(Self::global_or_default());
             }
         }
 
-        Ok(Some(config))
+        Some(config.with_global_defaults(content))
+    }
+
+    /// Loads configuration committed on the default branch
+    ///
+    /// Used when no configuration file is found on disk, e.g. in CI or in a
+    /// bare repository without a checked-out default branch. The file is read
+    /// from the tree of `main`, `master` or `HEAD` (first that exists) and is
+    /// only used if it sets `[general] read_config_from_tree = true`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(config))` - A committed configuration opted in
+    /// * `Ok(None)` - No committed configuration, or it did not opt in
+    fn load_from_tree(repo: &git2::Repository) -> Result<Option<Self>> {
+        let candidates = [
+            format!("{GIT_REFS_HEADS}{DEFAULT_BRANCH_MAIN}"),
+            format!("{GIT_REFS_HEADS}{DEFAULT_BRANCH_MASTER}"),
+            GIT_HEAD_REF.to_string(),
+        ];
+        let tree = match candidates
+            .iter()
+            .find_map(|name| repo.revparse_single(name).ok()?.peel_to_tree().ok())
+        {
+            Some(tree) => tree,
+            None => return Ok(None),
+        };
+
+        let entry = match tree.get_path(std::path::Path::new(CONFIG_FILE_NAME)) {
+            Ok(entry) => entry,
+            Err(_) => return Ok(None),
+        };
+        let blob = entry.to_object(repo)?.peel_to_blob()?;
+        let content = String::from_utf8_lossy(blob.content());
+
+        // The committed file itself must opt in, not the global config
+        let opted_in = toml::from_str::<Config>(&content)
+            .is_ok_and(|config| config.general.read_config_from_tree);
+        if !opted_in {
+            return Ok(None);
+        }
+        Ok(Self::parse_and_validate(&content, repo))
     }
 
     /// Validates that the repository URL matches the expected URL
@@ -507,6 +1482,7 @@ impl Config {
     ///
     /// * `repo` - The current Git repository
     /// * `expected_url` - The URL specified in the configuration
+    /// * `mode` - How strictly the URLs are compared
     ///
     /// # Returns
     ///
@@ -517,10 +1493,11 @@ impl Config {
     ///
     /// - Returns `true` if no origin remote exists (local-only repo)
     /// - Returns `true` if origin has no URL configured
-    /// - URLs are normalized before comparison:
+    /// - URLs are compared with [`repository_urls_match`]:
     ///   - Trailing `.git` suffix is removed
     ///   - Trailing slashes are removed
     ///   - Case-insensitive comparison
+    ///   - With `host-path`, scp-style and `https://` forms are equivalent
     ///
     /// # Example
     ///
@@ -528,7 +1505,16 @@ impl Config {
     /// - `https://github.com/owner/repo.git`
     /// - `https://github.com/owner/repo`
     /// - `HTTPS://GITHUB.COM/OWNER/REPO/`
-    fn validate_repository_url(repo: &git2::Repository, expected_url: &str) -> bool {
+    /// - `git@github.com:owner/repo.git` (`host-path` only)
+    fn validate_repository_url(
+        repo: &git2::Repository,
+        expected_url: &str,
+        mode: UrlMatchMode,
+    ) -> bool {
+        if mode == UrlMatchMode::Off {
+            return true;
+        }
+
         let remote = match repo.find_remote(GIT_ORIGIN.trim_end_matches('/')) {
             Ok(r) => r,
             Err(_) => return true, // No origin remote, skip validation
@@ -539,18 +1525,12 @@ impl Config {
             None => return true, // No URL, skip validation
         };
 
-        // Normalize URLs for comparison
-        let normalize = |url: &str| {
-            url.trim_end_matches(GIT_URL_SUFFIX)
-                .trim_end_matches('/')
-                .to_lowercase()
-        };
-
-        if normalize(expected_url) != normalize(actual_url) {
+        if !repository_urls_match(expected_url, actual_url, mode) {
             eprintln!("{ERROR_REPO_URL_MISMATCH}");
             eprintln!("{ERROR_EXPECTED_URL_PREFIX}{expected_url}");
             eprintln!("{ERROR_ACTUAL_URL_PREFIX}{actual_url}");
             eprintln!("{ERROR_HOOKS_NOT_EXECUTED}");
+            eprintln!("{ERROR_REPO_URL_HINT}");
             return false;
         }
 
@@ -623,6 +1603,96 @@ copy = [".env", "config.local"]
         assert_eq!(normalize(url2), normalize(url3));
     }
 
+    #[test]
+    fn test_repository_urls_match_modes() {
+        let https = "https://github.com/owner/repo";
+        let scp = "git@github.com:Owner/repo.git";
+        let ssh = "ssh://git@github.com:22/owner/repo.git/";
+
+        assert!(repository_urls_match(https, scp, UrlMatchMode::HostPath));
+        assert!(repository_urls_match(https, ssh, UrlMatchMode::HostPath));
+        assert!(!repository_urls_match(
+            https,
+            "git@gitlab.com:owner/repo.git",
+            UrlMatchMode::HostPath
+        ));
+
+        assert!(!repository_urls_match(https, scp, UrlMatchMode::Exact));
+        assert!(repository_urls_match(
+            https,
+            "HTTPS://GITHUB.COM/OWNER/REPO.git",
+            UrlMatchMode::Exact
+        ));
+
+        assert!(repository_urls_match(
+            https,
+            "/srv/other",
+            UrlMatchMode::Off
+        ));
+    }
+
+    #[test]
+    fn test_normalize_repository_url() {
+        assert_eq!(
+            normalize_repository_url("git@github.com:owner/repo.git"),
+            "github.com/owner/repo"
+        );
+        assert_eq!(
+            normalize_repository_url("https://user@github.com/owner/repo/"),
+            "github.com/owner/repo"
+        );
+        assert_eq!(
+            normalize_repository_url("/srv/git/repo.git"),
+            "/srv/git/repo"
+        );
+    }
+
+    #[test]
+    fn test_bare_layout_root() {
+        assert_eq!(
+            bare_layout_root(Path::new("/work/repo/.bare")),
+            Some(Path::new("/work/repo"))
+        );
+        assert_eq!(
+            bare_layout_root(Path::new("/work/repo/.bare/")),
+            Some(Path::new("/work/repo"))
+        );
+        assert_eq!(bare_layout_root(Path::new("/work/repo/.git")), None);
+        assert_eq!(bare_layout_root(Path::new("/work/repo.git")), None);
+    }
+
+    #[test]
+    fn test_shell_switch_channel_from_toml() {
+        let config: Config = toml::from_str("[shell]\nswitch_channel = \"stderr\"\n").unwrap();
+        assert_eq!(config.shell.switch_channel, SwitchChannel::Stderr);
+        assert_eq!(ShellConfig::default().switch_channel, SwitchChannel::File);
+        assert!(toml::from_str::<Config>("[shell]\nswitch_channel = \"fd3\"\n").is_err());
+    }
+
+    #[test]
+    fn test_create_branch_sort_from_toml() {
+        let config: Config = toml::from_str("[create]\nbranch_sort = \"committerdate\"\n").unwrap();
+        assert_eq!(config.create.branch_sort, BranchSort::CommitterDate);
+        assert_eq!(CreateConfig::default().branch_sort, BranchSort::Alpha);
+    }
+
+    #[test]
+    fn test_url_match_mode_from_toml() {
+        let config: Config = toml::from_str(
+            r#"
+[repository]
+url = "https://github.com/user/repo"
+url_match = "off"
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.repository.url_match, UrlMatchMode::Off);
+        assert_eq!(
+            RepositoryConfig::default().url_match,
+            UrlMatchMode::HostPath
+        );
+    }
+
     #[test]
     fn test_config_default() {
         let config = Config::default();
@@ -664,7 +1734,7 @@ post-create = ["echo 'test'"]
     fn test_config_serialization_roundtrip() {
         let mut config = Config::default();
         config.repository.url = Some("https://example.com/repo.git".to_string());
-        config.files.copy = vec![".env".to_string(), "config.json".to_string()];
+        config.files.copy = vec![".env".into(), "config.json".into()];
 
         let serialized = toml::to_string(&config).unwrap();
         let deserialized: Config = toml::from_str(&serialized).unwrap();
@@ -682,6 +1752,83 @@ post-create = ["echo 'test'"]
         assert!(config.repository.url.is_none());
     }
 
+    #[test]
+    fn test_config_hooks_accept_strings_and_tables() {
+        let toml_content = r#"
+[hooks]
+post-create = [
+    "cp .env.example .env",
+    { command = "npm install", workdir = "frontend", env = { NODE_ENV = "development" } },
+    { command = "make" },
+]
+"#;
+        let config: Config = toml::from_str(toml_content).unwrap();
+        let hooks = &config.hooks["post-create"];
+        assert_eq!(hooks.len(), 3);
+
+        assert_eq!(hooks[0], HookEntry::from("cp .env.example .env"));
+        assert_eq!(hooks[0].workdir(), None);
+        assert!(hooks[0].env().is_empty());
+
+        assert_eq!(hooks[1].command(), "npm install");
+        assert_eq!(hooks[1].workdir(), Some("frontend"));
+        assert_eq!(hooks[1].env(), vec![("NODE_ENV", "development")]);
+
+        assert_eq!(hooks[2].command(), "make");
+        assert_eq!(hooks[2].workdir(), None);
+    }
+
+    #[test]
+    fn test_config_hooks_on_failure() {
+        let toml_content = r#"
+[hooks]
+on_failure = "abort"
+post-create = ["npm install"]
+"#;
+        let config: Config = toml::from_str(toml_content).unwrap();
+        assert_eq!(config.hooks.on_failure, HookFailurePolicy::Abort);
+        assert_eq!(config.hooks.len(), 1);
+        assert_eq!(config.hooks["post-create"][0].command(), "npm install");
+
+        let config: Config = toml::from_str("[hooks]\npost-create = []\n").unwrap();
+        assert_eq!(config.hooks.on_failure, HookFailurePolicy::Warn);
+
+        assert!(toml::from_str::<Config>("[hooks]\non_failure = \"ignore\"\n").is_err());
+    }
+
+    #[test]
+    fn test_hooks_unknown_events() {
+        let toml_content = r#"
+[hooks]
+on_failure = "abort"
+post-create = ["npm install"]
+post-creat = ["echo typo"]
+on-deploy = ["echo future"]
+"#;
+        let config: Config = toml::from_str(toml_content).unwrap();
+        assert_eq!(
+            config.hooks.unknown_events(),
+            vec![("on-deploy", None), ("post-creat", Some("post-create"))]
+        );
+        // Unknown events still load, for configurations written for newer versions
+        assert_eq!(config.hooks["post-creat"], vec!["echo typo"]);
+    }
+
+    #[test]
+    fn test_performance_status_timeout() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(
+            config.performance.status_timeout(),
+            Duration::from_millis(DEFAULT_STATUS_TIMEOUT_MS)
+        );
+
+        let config: Config = toml::from_str("[performance]\nstatus_timeout_ms = 250\n").unwrap();
+        assert_eq!(
+            config.performance.status_timeout(),
+            Duration::from_millis(250)
+        );
+    }
+
     #[test]
     fn test_config_with_complex_hooks() {
         let toml_content = r#"
@@ -708,4 +1855,281 @@ source = "../main-worktree"
         assert_eq!(config.files.copy.len(), 2);
         assert_eq!(config.files.source, Some("../main-worktree".to_string()));
     }
+
+    #[test]
+    fn test_files_config_copy_entry_table_form() {
+        let toml_content = r#"
+[files]
+max_size_mb = 10
+copy = [".env", { path = "data.bin", max_size_mb = 500 }]
+"#;
+        let config: Config = toml::from_str(toml_content).unwrap();
+        assert_eq!(config.files.copy, vec![".env", "data.bin"]);
+        assert_eq!(config.files.copy[1].max_size_mb(), Some(500));
+        assert_eq!(
+            config.files.max_size_bytes(&config.files.copy[0]),
+            10 * BYTES_PER_MB
+        );
+        assert_eq!(
+            config.files.max_size_bytes(&config.files.copy[1]),
+            500 * BYTES_PER_MB
+        );
+    }
+
+    #[test]
+    fn test_files_config_default_max_size() {
+        let files = FilesConfig::default();
+        assert_eq!(
+            files.max_size_bytes(&".env".into()),
+            MAX_FILE_SIZE_MB * BYTES_PER_MB
+        );
+    }
+
+    #[test]
+    fn test_worktree_config_parsing() {
+        let toml_content = r#"
+[worktree]
+default_location = "same-level"
+skip_location_prompt = true
+"#;
+        let config: Config = toml::from_str(toml_content).unwrap();
+        assert_eq!(
+            config.worktree.default_location,
+            Some("same-level".to_string())
+        );
+        assert!(config.worktree.skip_location_prompt);
+    }
+
+    #[test]
+    fn test_worktree_config_default_pattern_alias() {
+        let toml_content = r#"
+[worktree]
+default_pattern = "custom"
+default_custom_path = "branches/{{name}}"
+"#;
+        let config: Config = toml::from_str(toml_content).unwrap();
+        assert_eq!(config.worktree.default_location, Some("custom".to_string()));
+        assert_eq!(
+            config.worktree.default_custom_path,
+            Some("branches/{{name}}".to_string())
+        );
+    }
+
+    #[test]
+    fn test_global_config_path() {
+        let home = Path::new("/home/me");
+        assert_eq!(
+            global_config_path(None, Some(home)),
+            Some(PathBuf::from("/home/me/.config/git-workers/config.toml"))
+        );
+        assert_eq!(
+            global_config_path(Some(Path::new("/xdg")), Some(home)),
+            Some(PathBuf::from("/xdg/git-workers/config.toml"))
+        );
+        // Relative XDG_CONFIG_HOME values are ignored
+        assert_eq!(
+            global_config_path(Some(Path::new("xdg")), Some(home)),
+            Some(PathBuf::from("/home/me/.config/git-workers/config.toml"))
+        );
+        assert_eq!(global_config_path(None, None), None);
+    }
+
+    #[test]
+    fn test_merge_config_values() {
+        let global: toml::Value = toml::from_str(
+            r#"
+[hooks]
+on_failure = "abort"
+post-create = ["npm install"]
+pre-remove = ["echo bye"]
+
+[files]
+copy = [".env", ".envrc"]
+max_size_mb = 10
+
+[editor]
+command = "code"
+"#,
+        )
+        .unwrap();
+        let local: toml::Value = toml::from_str(
+            r#"
+[hooks]
+post-create = ["make setup"]
+
+[files]
+copy = [{ path = ".env", max_size_mb = 1 }, "config/local.json"]
+max_size_mb = 50
+"#,
+        )
+        .unwrap();
+
+        let config: Config = merge_config_values(global, local).try_into().unwrap();
+        assert_eq!(config.hooks.on_failure, HookFailurePolicy::Abort);
+        assert_eq!(
+            config.hooks["post-create"],
+            vec![
+                HookEntry::from("npm install"),
+                HookEntry::from("make setup")
+            ]
+        );
+        assert_eq!(
+            config.hooks["pre-remove"],
+            vec![HookEntry::from("echo bye")]
+        );
+        assert_eq!(
+            config.files.copy,
+            vec![
+                CopyEntry::Detailed {
+                    path: ".env".to_string(),
+                    max_size_mb: Some(1),
+                },
+                CopyEntry::from(".envrc"),
+                CopyEntry::from("config/local.json"),
+            ]
+        );
+        assert_eq!(config.files.max_size_mb, Some(50));
+        assert_eq!(config.editor.command, Some("code".to_string()));
+    }
+
+    #[test]
+    fn test_worktree_config_default() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(config.worktree.default_location.is_none());
+        assert!(config.worktree.default_custom_path.is_none());
+        assert!(config.worktree.branch_template.is_none());
+        assert!(!config.worktree.skip_location_prompt);
+        assert!(!config.worktree.never_offer_branch_delete);
+        assert!(!config.worktree.auto_suffix);
+    }
+
+    #[test]
+    fn test_display_config_parsing() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(!config.display.show_ahead_behind);
+
+        let config: Config = toml::from_str("[display]\nshow_ahead_behind = true\n").unwrap();
+        assert!(config.display.show_ahead_behind);
+    }
+
+    #[test]
+    fn test_create_config_parsing() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(!config.create.always_fuzzy_branches);
+
+        let config: Config = toml::from_str("[create]\nalways_fuzzy_branches = true\n").unwrap();
+        assert!(config.create.always_fuzzy_branches);
+    }
+
+    /// Creates a bare repository whose `main` branch contains a config file
+    fn bare_repo_with_committed_config(content: &str) -> (TempDir, git2::Repository) {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = git2::Repository::init_bare(temp_dir.path().join("repo.git")).unwrap();
+        {
+            let blob = repo.blob(content.as_bytes()).unwrap();
+            let mut builder = repo.treebuilder(None).unwrap();
+            builder.insert(CONFIG_FILE_NAME, blob, 0o100644).unwrap();
+            let tree = repo.find_tree(builder.write().unwrap()).unwrap();
+            let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+            repo.commit(
+                Some("refs/heads/main"),
+                &sig,
+                &sig,
+                "Add config",
+                &tree,
+                &[],
+            )
+            .unwrap();
+        }
+        (temp_dir, repo)
+    }
+
+    #[test]
+    fn test_load_from_tree_reads_committed_config() {
+        let (_temp_dir, repo) = bare_repo_with_committed_config(
+            "[general]\nread_config_from_tree = true\n\n[hooks]\npost-create = [\"npm ci\"]\n",
+        );
+
+        let config = Config::load_from_tree(&repo).unwrap().unwrap();
+        assert_eq!(config.hooks["post-create"], vec!["npm ci"]);
+    }
+
+    /// Creates a repository with a linked worktree and a config file in each
+    fn repo_with_worktree_configs(
+        main_config: &str,
+        worktree_config: &str,
+    ) -> (TempDir, git2::Repository) {
+        let temp_dir = TempDir::new().unwrap();
+        let main_dir = temp_dir.path().join("repo");
+        let repo = git2::Repository::init(&main_dir).unwrap();
+        {
+            let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+            let tree_id = repo.index().unwrap().write_tree().unwrap();
+            let tree = repo.find_tree(tree_id).unwrap();
+            repo.commit(Some("HEAD"), &sig, &sig, "Initial", &tree, &[])
+                .unwrap();
+        }
+        let worktree_dir = temp_dir.path().join("feature");
+        repo.worktree("feature", &worktree_dir, None).unwrap();
+        fs::write(main_dir.join(CONFIG_FILE_NAME), main_config).unwrap();
+        fs::write(worktree_dir.join(CONFIG_FILE_NAME), worktree_config).unwrap();
+
+        let worktree_repo = git2::Repository::open(&worktree_dir).unwrap();
+        (temp_dir, worktree_repo)
+    }
+
+    const WORKTREE_OVERRIDE_CONFIG: &str = r#"
+[config]
+allow_worktree_override = false
+
+[hooks]
+post-create = ["make experiment"]
+
+[files]
+copy = ["fixtures/experiment.json"]
+"#;
+
+    #[test]
+    fn test_worktree_config_ignored_without_opt_in() {
+        let (_temp_dir, repo) = repo_with_worktree_configs(
+            "[hooks]\npost-create = [\"npm ci\"]\n\n[files]\ncopy = [\".env\"]\n",
+            WORKTREE_OVERRIDE_CONFIG,
+        );
+
+        let path = resolve_config_path(&repo).unwrap();
+        let config = Config::load_from_file(&path, &repo)
+            .unwrap()
+            .unwrap()
+            .with_worktree_override(&repo);
+        assert_eq!(config.hooks["post-create"], vec!["npm ci"]);
+        assert_eq!(config.files.copy, vec![".env"]);
+    }
+
+    #[test]
+    fn test_worktree_config_layered_with_opt_in() {
+        let (_temp_dir, repo) = repo_with_worktree_configs(
+            "[config]\nallow_worktree_override = true\n\n[hooks]\npost-create = [\"npm ci\"]\npre-remove = [\"echo bye\"]\n\n[files]\ncopy = [\".env\"]\nmax_size_mb = 5\n",
+            WORKTREE_OVERRIDE_CONFIG,
+        );
+
+        let path = resolve_config_path(&repo).unwrap();
+        let config = Config::load_from_file(&path, &repo)
+            .unwrap()
+            .unwrap()
+            .with_worktree_override(&repo);
+        assert_eq!(config.hooks["post-create"], vec!["make experiment"]);
+        assert_eq!(config.hooks["pre-remove"], vec!["echo bye"]);
+        assert_eq!(config.files.copy, vec!["fixtures/experiment.json"]);
+        assert_eq!(config.files.max_size_mb, Some(5));
+        // [config] is only read from the main configuration
+        assert!(config.config.allow_worktree_override);
+    }
+
+    #[test]
+    fn test_load_from_tree_requires_opt_in() {
+        let (_temp_dir, repo) =
+            bare_repo_with_committed_config("[hooks]\npost-create = [\"npm ci\"]\n");
+
+        assert!(Config::load_from_tree(&repo).unwrap().is_none());
+    }
 }
@@ -35,6 +35,11 @@ use colored::*;
 // UI Messages
 pub const MSG_PRESS_ANY_KEY: &str = "Press any key to continue...";
 pub const MSG_SWITCH_FILE_WARNING_PREFIX: &str = "Warning: Failed to write switch file: ";
+pub const ERROR_SWITCH_FILE_EMPTY: &str = "GW_SWITCH_FILE is set but empty";
+pub const ERROR_SWITCH_FILE_NO_HOME: &str =
+    "GW_SWITCH_FILE '{}' starts with '~' but HOME is not set";
+pub const ERROR_SWITCH_FILE_IS_DIR: &str = "GW_SWITCH_FILE points to a directory: {}";
+pub const ERROR_SWITCH_FILE_NO_PARENT: &str = "GW_SWITCH_FILE parent directory does not exist: {}";
 
 // UI Formatting
 pub const SEPARATOR_WIDTH: usize = 40;
@@ -82,6 +87,13 @@ pub const DEFAULT_BRANCH_MASTER: &str = "master";
 
 // Configuration
 pub const CONFIG_FILE_NAME: &str = ".git-workers.toml";
+pub const GLOBAL_CONFIG_DIR_NAME: &str = "git-workers";
+pub const GLOBAL_CONFIG_FILE_NAME: &str = "config.toml";
+pub const XDG_DEFAULT_CONFIG_DIR: &str = ".config";
+pub const CONFIG_SECTION_REPOSITORY: &str = "repository";
+pub const CONFIG_SECTION_CONFIG: &str = "config";
+pub const CONFIG_KEY_PATH: &str = "path";
+pub const CONFIG_KEY_COMMAND: &str = "command";
 
 // Git internals
 pub const GIT_RESERVED_NAMES: &[&str] = &["HEAD", "refs", "hooks", "info", "objects", "logs"];
@@ -89,6 +101,8 @@ pub const GIT_CRITICAL_DIRS: &[&str] = &["objects", "refs", "hooks", "info", "lo
 
 // Filesystem limits
 pub const MAX_WORKTREE_NAME_LENGTH: usize = 255;
+/// Highest suffix tried by `[worktree] auto_suffix` (`name-2` .. `name-100`)
+pub const AUTO_SUFFIX_MAX: usize = 100;
 pub const MAX_FILE_SIZE_MB: u64 = 100;
 
 // Special characters
@@ -99,16 +113,22 @@ pub const WINDOWS_RESERVED_CHARS: &[char] = &['<', '>', ':', '"', '|', '?', '*']
 
 // Timeouts
 pub const STALE_LOCK_TIMEOUT_SECS: u64 = 300; // seconds (5 minutes)
+pub const DEFAULT_STATUS_TIMEOUT_MS: u64 = 2000;
 
 // Git constants
 pub const COMMIT_ID_SHORT_LENGTH: usize = 8;
 pub const LOCK_FILE_NAME: &str = "git-workers-worktree.lock";
+pub const STATE_FILE_NAME: &str = "git-workers-state.toml";
+pub const SWITCH_HISTORY_LIMIT: usize = 5;
 
 // Directory depth limits
 pub const MAX_DIRECTORY_DEPTH: usize = 50;
 
 // Timeout and interval values
 pub const PROGRESS_BAR_TICK_MILLIS: u64 = 100;
+pub const BATCH_PROGRESS_TEMPLATE: &str = "{bar:30.cyan/blue} {pos}/{len} {msg}";
+pub const SPINNER_TEMPLATE: &str = "{spinner:.green} {msg}";
+pub const BATCH_PROGRESS_CHARS: &str = "█▓░";
 pub const DEFAULT_WORKTREE_CLEANUP_DAYS: &str = "30";
 
 // UI display-related values
@@ -123,10 +143,25 @@ pub const UI_PATH_COL_WIDTH: usize = 40;
 // File size calculations
 pub const BYTES_PER_KB: u64 = 1024;
 pub const BYTES_PER_MB: u64 = 1024 * 1024;
+pub const BYTES_PER_GB: u64 = 1024 * 1024 * 1024;
 
 // Exit codes
 pub const EXIT_SUCCESS: i32 = 0;
 pub const EXIT_FAILURE: i32 = 1;
+pub const EXIT_NOT_A_REPOSITORY: i32 = 2;
+pub const EXIT_VALIDATION_ERROR: i32 = 3;
+pub const EXIT_LOCK_HELD: i32 = 4;
+pub const EXIT_HOOK_FAILED: i32 = 5;
+pub const EXIT_WORKTREE_EXISTS: i32 = 6;
+/// Exit codes as listed in `gw --help`
+pub const HELP_EXIT_CODES: &str = "Exit codes:
+  0  Success
+  1  Other error
+  2  Not inside a Git repository
+  3  Invalid input (e.g. a rejected worktree name or path, or bad arguments)
+  4  Another gw process holds the worktree lock
+  5  A hook command failed
+  6  The worktree path already exists";
 
 // Git commands
 pub const GIT_CMD: &str = "git";
@@ -139,9 +174,16 @@ pub const GIT_ADD: &str = "add";
 pub const GIT_LIST: &str = "list";
 pub const GIT_REMOVE: &str = "remove";
 pub const GIT_PRUNE: &str = "prune";
+pub const GIT_OPT_VERBOSE: &str = "--verbose";
+pub const GIT_OPT_DRY_RUN: &str = "--dry-run";
 pub const GIT_REPAIR: &str = "repair";
 pub const GIT_MOVE: &str = "move";
 pub const GIT_REV_PARSE: &str = "rev-parse";
+pub const GIT_RESET: &str = "reset";
+pub const GIT_CONFIG_CORE_BARE: &str = "core.bare";
+pub const GIT_OPT_NO_CHECKOUT: &str = "--no-checkout";
+pub const GIT_OPT_DEPTH: &str = "--depth";
+pub const GIT_OPT_QUIET: &str = "-q";
 
 // Git options
 pub const GIT_OPT_BRANCH: &str = "-b";
@@ -164,6 +206,7 @@ pub const GIT_ORIGIN: &str = "origin/";
 pub const GIT_DIR: &str = ".git";
 pub const GIT_WORKTREE_DIR: &str = "worktrees";
 pub const GIT_COMMONDIR_FILE: &str = "commondir";
+pub const WORKTREE_CREATED_MARKER: &str = ".git-workers-created";
 
 // Shell commands
 pub const SHELL_CMD: &str = "sh";
@@ -179,7 +222,11 @@ pub const ERROR_NO_REPO_WORKING_DIR: &str = "Cannot find repository working dire
 pub const ERROR_NO_PARENT_DIR: &str = "Cannot find parent directory";
 pub const ERROR_NO_REPO_DIR: &str = "Cannot determine repository directory";
 pub const ERROR_WORKTREE_PATH_EXISTS: &str = "Worktree path already exists: {}";
+pub const ERROR_PATH_IS_WORKTREE: &str = "Path is already used by an existing worktree: {}";
+pub const ERROR_PATH_OCCUPIED: &str = "Path already exists and is not an empty directory: {}";
 pub const ERROR_WORKTREE_CREATE: &str = "Failed to create worktree: {}";
+pub const ERROR_NO_COMMITS: &str =
+    "Repository has no commits yet — make an initial commit before creating worktrees.";
 pub const ERROR_CONFIG_LOAD: &str = "Failed to load config";
 pub const ERROR_CONFIG_READ: &str = "Failed to read {}: {}";
 pub const ERROR_CONFIG_PARSE: &str = "Failed to parse {}: {}";
@@ -187,9 +234,25 @@ pub const ERROR_REPO_URL_MISMATCH: &str = "Repository URL mismatch!";
 pub const ERROR_EXPECTED_URL_PREFIX: &str = "Expected: ";
 pub const ERROR_ACTUAL_URL_PREFIX: &str = "Actual: ";
 pub const ERROR_HOOKS_NOT_EXECUTED: &str = "Hooks will not be executed.";
+pub const ERROR_REPO_URL_HINT: &str = "Update [repository] url in .git-workers.toml, or set url_match = \"host-path\" to ignore the protocol or \"off\" to skip the check.";
 pub const ERROR_HOOK_EXIT_CODE: &str = "Hook command failed with exit code: {:?}";
 pub const ERROR_HOOK_WAIT_PREFIX: &str = "Failed to wait for hook command: ";
 pub const ERROR_HOOK_EXECUTE_PREFIX: &str = "Failed to execute hook command: ";
+pub const HOOK_FAILURE_FORMAT: &str = "{} hook '{}' {}";
+pub const HOOK_FAILURE_EXIT_CODE: &str = "exited with code {}";
+pub const HOOK_FAILURE_NOT_RUN: &str = "could not be run";
+pub const ERROR_POST_CREATE_HOOK_FAILED: &str = "Aborting: {}";
+pub const ERROR_PRE_CREATE_HOOK_FAILED: &str = "Worktree not created: {}";
+pub const ERROR_FILE_COPY_ABORTED: &str = "Aborting: failed to copy files: {}";
+pub const ERROR_FILE_COPY_INCOMPLETE: &str = "{} file(s) could not be copied";
+pub const PROMPT_ROLLBACK_WORKTREE: &str = "Remove the new worktree?";
+pub const PROMPT_ROLLBACK_WORKTREE_AND_BRANCH: &str = "Remove the new worktree and branch '{}'?";
+pub const MSG_WORKTREE_ROLLED_BACK: &str = "Removed worktree '{}'";
+pub const MSG_BRANCH_ROLLED_BACK: &str = "Deleted branch '{}'";
+pub const WARNING_ROLLBACK_WORKTREE_FAILED: &str = "Failed to remove worktree '{}': {}";
+pub const WARNING_ROLLBACK_BRANCH_FAILED: &str = "Failed to delete branch '{}': {}";
+pub const WARNING_ROLLBACK_BRANCH_KEPT: &str =
+    "Branch '{}' kept because its worktree is still there";
 pub const ERROR_TERMINAL_REQUIRED: &str = "Error: git-workers requires a terminal environment.";
 pub const ERROR_NON_INTERACTIVE: &str = "Non-interactive environments are not supported.";
 pub const ERROR_PERMISSION_DENIED: &str = "Failed to create worktree: permission denied";
@@ -215,6 +278,13 @@ pub const PROMPT_RENAME_BRANCH: &str = "Rename branch '{}' to '{}' as well?";
 pub const PROMPT_NEW_WORKTREE_NAME: &str = "New worktree name";
 pub const PROMPT_CLEANUP_CONFIRM: &str = "Delete {} worktrees?";
 pub const PROMPT_CONFLICT_ACTION: &str = "What would you like to do?";
+pub const WARNING_BRANCH_EXISTS: &str = "Branch '{}' already exists";
+pub const OPTION_BRANCH_EXISTS_RENAME: &str = "Choose a different name";
+pub const OPTION_BRANCH_EXISTS_USE_EXISTING: &str = "Use the existing branch '{}' instead";
+pub const OPTION_BRANCH_EXISTS_CANCEL: &str = "Cancel";
+pub const WARNING_BRANCH_NAME_TYPO: &str =
+    "'{}' is one character away from the existing branch '{}'";
+pub const PROMPT_USE_SIMILAR_BRANCH: &str = "Use the existing branch '{}' instead?";
 
 // Success messages
 pub const SUCCESS_WORKTREE_CREATED: &str = "Worktree created successfully!";
@@ -223,13 +293,21 @@ pub const SUCCESS_OPERATION_COMPLETED: &str = "Operation completed!";
 // Additional UI messages
 pub const MSG_CREATING_FIRST_WORKTREE: &str = "Creating first worktree...";
 pub const MSG_PRESS_ESC_TO_CANCEL: &str = " (ESC to cancel)";
+pub const MSG_PRESS_ESC_TO_STOP_BATCH: &str = "Press ESC to stop after the current item";
 
 // Git error messages
 pub const GIT_BRANCH_NOT_FOUND_MSG: &str = "Branch '{}' not found";
 pub const GIT_CANNOT_RENAME_CURRENT: &str =
     "Cannot rename current worktree. Please switch to another worktree first.";
+pub const GIT_CANNOT_RENAME_MAIN: &str = "Cannot rename the main worktree";
 pub const GIT_WORKTREE_NOT_FOUND: &str = "Worktree not found: {}";
 pub const GIT_INVALID_BRANCH_NAME: &str = "Invalid branch name: {}";
+pub const GIT_RENAME_ROLLED_BACK: &str =
+    "Could not update the worktree metadata after renaming ({}). The rename was rolled back; the worktree is still at {}";
+pub const GIT_RENAME_ROLLBACK_FAILED: &str =
+    "Could not update the worktree metadata after renaming ({}), and rolling back failed. The worktree is at {}; run 'git worktree repair' there";
+pub const WARNING_RENAME_REPAIR_RECOVERED: &str =
+    "Warning: {}. The worktree metadata was rewritten directly instead.";
 
 // Emoji icons
 pub const EMOJI_HOME: &str = "🏠";
@@ -245,7 +323,13 @@ pub const FILE_COPY_SKIPPED_LARGE: &str = "Skipping large file";
 pub const FILE_COPY_FAILED: &str = "Failed to copy";
 pub const FILE_COPY_SKIPPING_UNSAFE: &str = "Skipping unsafe path";
 pub const FILE_COPY_NOT_FOUND: &str = "Not found";
+pub const FILE_COPY_NO_MATCHES: &str = "No files match";
+pub const FILE_COPY_INVALID_PATTERN: &str = "Invalid pattern";
+pub const GLOB_METACHARACTERS: &[char] = &['*', '?', '['];
 pub const FILE_COPY_COPIED_SUCCESS: &str = "Copied";
+pub const FILE_COPY_EXCLUDED: &str = "excluded";
+pub const FILE_COPY_PLAN_MISSING: &str = "missing";
+pub const PROMPT_CREATE_WITH_MISSING_FILES: &str = "Some files to copy are missing. Create anyway?";
 pub const SIZE_UNIT_MB: &str = "MB";
 
 // Error detection patterns
@@ -256,11 +340,19 @@ pub const ERROR_NOT_FOUND: &str = "not found";
 pub const MAIN_WORKTREE_NAMES: &[&str] = &["main", "master"];
 
 // File size formatting
+pub const FILE_SIZE_BYTES_SUFFIX: &str = " B";
+pub const FILE_SIZE_KB_SUFFIX: &str = " KB";
 pub const FILE_SIZE_MB_SUFFIX: &str = " MB";
+pub const FILE_SIZE_GB_SUFFIX: &str = " GB";
+pub const FILE_SIZE_UNKNOWN: &str = "-";
 pub const FILE_SIZE_DECIMAL_PLACES: usize = 1;
 
 // Warning messages
 pub const WARNING_NO_WORKTREES: &str = "• No worktrees found.";
+/// Replace the icons of warnings and errors under `--quiet`
+pub const QUIET_PREFIX_WARNING: &str = "warning: ";
+pub const QUIET_PREFIX_ERROR: &str = "error: ";
+pub const WARNING_NO_WORKTREES_MATCH: &str = "• No worktree name or branch matches '{}'.";
 pub const WARNING_NO_WORKTREES_SEARCH: &str = "• No worktrees to search.";
 pub const WARNING_ALREADY_IN_WORKTREE: &str = "• Already in this worktree.";
 pub const WARNING_WORKTREE_NAME_EMPTY: &str = "Worktree name cannot be empty";
@@ -270,6 +362,10 @@ pub const WARNING_INVALID_CUSTOM_PATH: &str = "Invalid custom path: {}";
 pub const WARNING_NO_BRANCHES: &str = "No branches found, creating from HEAD";
 pub const WARNING_NO_TAGS: &str = "No tags found, creating from HEAD";
 pub const WARNING_BRANCH_NAME_EMPTY: &str = "Branch name cannot be empty";
+pub const WARNING_BRANCH_MISMATCH: &str = "Worktree is on branch '{}' but '{}' was requested";
+pub const WARNING_BRANCH_DETACHED: &str =
+    "Worktree is in detached HEAD state but branch '{}' was requested";
+pub const WARNING_BRANCH_UNVERIFIED: &str = "Could not verify the worktree's branch: {}";
 
 // Info messages
 pub const INFO_EXITING: &str = "Exiting Git Workers...";
@@ -281,6 +377,12 @@ pub const INFO_WILL_KEEP_DAYS: &str = "Will keep worktrees for {} days";
 pub const INFO_CREATING_WORKTREE_PROGRESS: &str = "Creating worktree...";
 pub const INFO_RUNNING_HOOKS: &str = "Running {} hooks...";
 pub const INFO_HOOK_COMMAND_PREFIX: &str = "  > ";
+pub const INFO_RUNNING_TASK: &str = "Running task '{}'...";
+pub const ERROR_UNKNOWN_TASK: &str = "Unknown task '{}'. Available tasks: {}";
+pub const ERROR_NO_TASKS: &str = "No tasks configured. Add them under [tasks] in .git-workers.toml";
+pub const PROMPT_RUN_TASK: &str = "Run a task in the new worktree?";
+pub const TASK_SKIP_OPTION: &str = "Skip";
+pub const ERROR_TASK_FAILED: &str = "Worktree created, but the task failed: {}";
 
 // UI Icons
 pub const ICON_LIST: &str = "•";
@@ -292,6 +394,8 @@ pub const ICON_CLEANUP: &str = "~";
 pub const ICON_SWITCH: &str = "→";
 pub const ICON_RENAME: &str = "*";
 pub const ICON_EDIT: &str = "⚙";
+pub const ICON_FOCUS: &str = "◎";
+pub const ICON_PRUNE: &str = "%";
 pub const ICON_EXIT: &str = "x";
 pub const ICON_SPINNER: &str = "⏳";
 pub const ICON_SUCCESS: &str = "✓";
@@ -308,6 +412,7 @@ pub const ICON_QUESTION: &str = "?";
 // UI Labels
 pub const LABEL_PATH: &str = "Path:";
 pub const LABEL_BRANCH: &str = "Branch:";
+pub const LABEL_ROOT: &str = "Root:";
 pub const LABEL_NAME: &str = "Name:";
 pub const LABEL_MODIFIED: &str = "Modified";
 pub const LABEL_YES: &str = "Yes";
@@ -327,11 +432,19 @@ pub const LABEL_DEFAULT: &str = "Default:";
 pub const MENU_LIST_WORKTREES: &str = "•  List worktrees";
 pub const MENU_SEARCH_WORKTREES: &str = "?  Search worktrees";
 pub const MENU_CREATE_WORKTREE: &str = "+  Create worktree";
+pub const MENU_BATCH_CREATE: &str = "⊕  Create worktrees from branches";
 pub const MENU_DELETE_WORKTREE: &str = "-  Delete worktree";
 pub const MENU_BATCH_DELETE: &str = "=  Batch delete worktrees";
 pub const MENU_CLEANUP_OLD: &str = "~  Cleanup old worktrees";
 pub const MENU_SWITCH_WORKTREE: &str = "→  Switch worktree";
+pub const MENU_SWITCH_PREVIOUS: &str = "←  Switch to previous worktree";
+pub const MENU_OPEN_IN_EDITOR: &str = "@  Open worktree in editor";
+pub const MENU_SHOW_COMMIT_ID: &str = "^  Show commit id";
 pub const MENU_RENAME_WORKTREE: &str = "*  Rename worktree";
+pub const MENU_FOCUS_MODE: &str = "◎  Focus mode";
+pub const MENU_PRUNE_WORKTREES: &str = "%  Prune stale worktrees";
+pub const MENU_LOCK_WORKTREE: &str = "#  Lock or unlock worktree";
+pub const MENU_MOVE_WORKTREE: &str = "»  Move worktree";
 pub const MENU_EDIT_HOOKS: &str = "⚙  Edit hooks";
 pub const MENU_EXIT: &str = "x  Exit";
 
@@ -364,6 +477,11 @@ pub const DEFAULT_EDITOR_UNIX: &str = "vi";
 // Error messages (additional)
 pub const ERROR_WORKTREE_NAME_EMPTY: &str = "Worktree name cannot be empty";
 pub const ERROR_CUSTOM_PATH_EMPTY: &str = "Custom path cannot be empty";
+pub const ERROR_INVALID_BRANCH_NAME: &str = "'{}' is not a valid branch name";
+pub const ERROR_BRANCH_TEMPLATE_NO_USER: &str =
+    "[worktree] branch_template uses {{user}} but neither $USER nor $USERNAME is set";
+pub const MSG_WORKTREE_NAME_SUFFIXED: &str = "Worktree '{}' already exists, using '{}' instead";
+pub const ERROR_NO_FREE_WORKTREE_NAME: &str = "No free worktree name found for '{}'";
 
 // Worktree location options
 pub const OPTION_SAME_LEVEL: &str = "Same level as repository";
@@ -375,6 +493,16 @@ pub const ENV_NO_COLOR: &str = "NO_COLOR";
 pub const ENV_FORCE_COLOR: &str = "FORCE_COLOR";
 pub const ENV_CLICOLOR_FORCE: &str = "CLICOLOR_FORCE";
 pub const ENV_GW_SWITCH_FILE: &str = "GW_SWITCH_FILE";
+/// Set to print how long slow operations took to stderr
+pub const ENV_GW_DEBUG: &str = "GW_DEBUG";
+pub const ENV_GW_WORKTREE_NAME: &str = "GW_WORKTREE_NAME";
+pub const ENV_GW_WORKTREE_PATH: &str = "GW_WORKTREE_PATH";
+pub const ENV_HOME: &str = "HOME";
+pub const ENV_XDG_CONFIG_HOME: &str = "XDG_CONFIG_HOME";
+pub const ENV_USER: &str = "USER";
+pub const ENV_USERNAME: &str = "USERNAME";
+pub const TILDE: &str = "~";
+pub const TILDE_PREFIX: &str = "~/";
 pub const ENV_CI: &str = "CI";
 pub const ENV_CLICOLOR_FORCE_VALUE: &str = "1";
 
@@ -386,6 +514,11 @@ pub const ANSI_CLEAR_LINE: &str = "\r\x1b[K";
 // Template variables
 pub const TEMPLATE_WORKTREE_NAME: &str = "{{worktree_name}}";
 pub const TEMPLATE_WORKTREE_PATH: &str = "{{worktree_path}}";
+pub const TEMPLATE_PREVIOUS_WORKTREE_NAME: &str = "{{previous_worktree_name}}";
+pub const TEMPLATE_PREVIOUS_WORKTREE_PATH: &str = "{{previous_worktree_path}}";
+pub const TEMPLATE_NAME: &str = "{{name}}";
+pub const TEMPLATE_USER: &str = "{{user}}";
+pub const TEMPLATE_PATH: &str = "{{path}}";
 
 // Format strings
 pub const FORMAT_DEFAULT_VALUE: &str = "[{}]";
@@ -394,7 +527,14 @@ pub const FORMAT_SEPARATOR: &str = " ";
 // Special values
 pub const UNKNOWN_VALUE: &str = "unknown";
 pub const MAIN_SUFFIX: &str = " (main)";
+pub const REMOTE_ORIGIN: &str = "origin";
+pub const REMOTE_SHORTENED_HOSTS: &[&str] = &["github.com", "gitlab.com"];
+pub const GIT_SUFFIX: &str = ".git";
+pub const HEADER_DETAILS_SEPARATOR: &str = " · ";
 pub const BARE_SUFFIX: &str = ".bare";
+pub const REPO_INFO_BARE: &str = "{} (bare)";
+pub const REPO_INFO_WORKTREE_OF: &str = "{} (worktree of {})";
+pub const REPO_INFO_SUBMODULE: &str = "submodule: {}";
 
 // Path separators and patterns
 pub const PATH_SEPARATOR: char = '/';
@@ -405,6 +545,11 @@ pub const PATH_COLON: char = ':';
 
 // Git porcelain prefixes
 pub const PORCELAIN_WORKTREE: &str = "worktree ";
+pub const PORCELAIN_HEAD: &str = "HEAD ";
+pub const PORCELAIN_BRANCH: &str = "branch ";
+pub const PORCELAIN_BARE: &str = "bare";
+pub const PORCELAIN_DETACHED: &str = "detached";
+pub const PORCELAIN_LOCKED: &str = "locked";
 
 // File operations
 pub const FILE_READ_MODE: &str = "r";
@@ -451,11 +596,28 @@ pub const WORKTREE_LOCATION_CUSTOM_PATH: usize = 2;
 pub const BRANCH_OPTION_CREATE_FROM_HEAD: usize = 0;
 pub const BRANCH_OPTION_SELECT_BRANCH: usize = 1;
 pub const BRANCH_OPTION_SELECT_TAG: usize = 2;
+/// "Create new branch ... from ..." in the branch conflict prompt
+pub const CONFLICT_ACTION_CREATE_NEW_BRANCH: usize = 0;
+/// "Choose a different name" when a new branch name is taken
+pub const BRANCH_EXISTS_ACTION_RENAME: usize = 0;
+/// "Use the existing branch instead" when a new branch name is taken
+pub const BRANCH_EXISTS_ACTION_USE_EXISTING: usize = 1;
 
 // Hook types
+pub const HOOK_PRE_CREATE: &str = "pre-create";
 pub const HOOK_POST_CREATE: &str = "post-create";
 pub const HOOK_PRE_REMOVE: &str = "pre-remove";
 pub const HOOK_POST_SWITCH: &str = "post-switch";
+pub const HOOK_POST_REMOVE: &str = "post-remove";
+/// Every hook event git-workers runs; other keys under `[hooks]` are ignored
+pub const KNOWN_HOOK_EVENTS: &[&str] = &[
+    HOOK_PRE_CREATE,
+    HOOK_POST_CREATE,
+    HOOK_PRE_REMOVE,
+    HOOK_POST_REMOVE,
+    HOOK_POST_SWITCH,
+];
+pub const WARNING_UNKNOWN_HOOK_EVENT: &str = "Warning: unknown hook '{}' in {} will never run";
 
 // Array indices
 pub const WINDOW_FIRST_INDEX: usize = 0;
@@ -480,12 +642,59 @@ pub const MIN_PATH_COMPONENTS_FOR_SUBDIR: usize = 1;
 // Additional constants that were identified during hardcode audit
 pub const MSG_NO_WORKTREES_TO_SEARCH: &str = "• No worktrees to search.";
 pub const MSG_SEARCH_FUZZY_ENABLED: &str = "Type to search worktrees (fuzzy search enabled):";
+pub const MSG_WORKTREE_HAS_CHANGES: &str = "This worktree has uncommitted changes.";
+pub const PROMPT_FORCE_DELETE: &str = "Force delete (discard changes)?";
+pub const PROMPT_SELECT_WORKTREE_TO_DELETE: &str = "Select a worktree to delete (ESC to cancel)";
+pub const PROMPT_DELETE_THIS_WORKTREE: &str = "Are you sure you want to delete this worktree?";
+pub const ERROR_DELETE_NOT_CONFIRMED: &str =
+    "Not deleting '{}' without confirmation; pass --yes to confirm";
+pub const ERROR_NO_DELETABLE_WORKTREES: &str = "No worktrees available for deletion";
+pub const HEADER_BATCH_CREATE: &str = "Create Worktrees from Branches";
+pub const PROMPT_SELECT_BRANCHES_TO_CREATE: &str =
+    "Select branches to create worktrees for (Space to toggle, Enter to confirm, ESC to cancel)";
+pub const MSG_NO_BRANCHES_FOR_BATCH_CREATE: &str =
+    "• Every branch is already checked out in a worktree.";
+pub const PROMPT_SELECT_WORKTREES_TO_DELETE: &str =
+    "Select worktrees to delete (Space to toggle, Enter to confirm, ESC to cancel)";
+pub const WARNING_DIRTY_WORKTREES: &str = "Uncommitted changes in: {}";
+pub const PROMPT_FORCE_DELETE_DIRTY: &str =
+    "Force delete {} worktree(s) with uncommitted changes (discard changes)? Otherwise they are skipped";
+pub const MSG_SKIPPING_DIRTY: &str = "Skipping '{}': it has uncommitted changes";
+pub const MSG_DRY_RUN_NOTHING_DELETED: &str = "Dry run: nothing was deleted and no hooks were run";
+pub const MSG_WOULD_DELETE_WORKTREE: &str = "Would delete worktree '{}'";
+pub const MSG_WOULD_DELETE_BRANCH: &str = "Would delete branch '{}'";
+pub const MSG_BRANCH_STILL_CHECKED_OUT: &str =
+    "Kept branch '{}': it is still checked out in worktree '{}'";
+pub const MSG_WOULD_PRUNE_ENTRIES: &str = "Dry run: would prune {} stale worktree entries";
+pub const ERROR_DRY_RUN_BEFORE_SUBCOMMAND: &str =
+    "--dry-run before a subcommand only applies to the menu; use `gw delete --dry-run` or `gw prune --dry-run`";
+pub const MSG_DRY_RUN_MODE: &str =
+    "Dry run: delete, batch delete, cleanup and prune only show what they would remove";
 pub const MSG_ALREADY_IN_WORKTREE: &str = "• Already in this worktree.";
+pub const SWITCH_PREVIEW_COMMITS: usize = 5;
+pub const MSG_SWITCH_PREVIEW: &str = "Recent commits in '{}' ({}):";
+pub const MSG_SWITCH_PREVIEW_EMPTY: &str = "No commits yet";
+pub const PROMPT_SWITCH_CONFIRM: &str = "Switch to this worktree? (No picks another)";
+pub const MSG_NO_PREVIOUS_WORKTREE: &str =
+    "• No previous worktree to switch back to. Switch to a worktree first.";
 pub const SEARCH_CURRENT_INDICATOR: &str = " (current)";
 
 // File copy operation constants
 pub const FILE_COPY_SAME_DIRECTORY: &str = "Source and destination are the same directory";
 pub const FILE_COPY_SKIPPING_LARGE: &str = "Skipping large file";
+pub const FILE_COPY_SIZE_LIMIT_LABEL: &str = "limit";
+pub const FILE_COPY_FROM_REF: &str = "from";
+pub const ERROR_SOURCE_REF_NOT_FOUND: &str = "Cannot read [files] source_ref '{}': {}";
+pub const ERROR_SOURCE_VAR_UNSET: &str =
+    "Environment variable '{}' used in [files] source is not set";
+pub const ERROR_SOURCE_VAR_UNCLOSED: &str = "Unclosed '${' in [files] source: {}";
+pub const SKIP_REASON_TOO_LARGE: &str = "too large";
+pub const SKIP_REASON_NOT_FOUND: &str = "not found";
+pub const SKIP_REASON_UNSAFE_PATH: &str = "unsafe path";
+pub const MSG_FILES_COPIED: &str = "Copied {} file(s)";
+pub const MSG_FILES_SKIPPED: &str = "Skipped {} file(s):";
+pub const MSG_FILES_FAILED: &str = "Failed to copy {} file(s):";
+pub const MSG_FILES_EXCLUDED: &str = "Excluded {} path(s) matching [files] copy_exclude";
 
 // Pluralization helpers
 pub const PLURAL_EMPTY: &str = "";
@@ -516,6 +725,7 @@ pub const INFO_FAILED_TO_COPY: &str = "Failed to copy";
 
 // Git references
 pub const GIT_REFS_HEADS: &str = "refs/heads/";
+pub const GIT_HEAD_REF: &str = "HEAD";
 
 // Git command arguments
 pub const GIT_ARG_TRACK: &str = "--track";
@@ -560,6 +770,7 @@ pub const SLICE_END_ONE: usize = 1;
 
 // Time units
 pub const SECONDS_IN_DAY: u64 = 86400;
+pub const SECONDS_IN_MINUTE: u64 = 60;
 
 // Default branch names array (for iteration)
 pub const DEFAULT_BRANCHES: &[&str] = &["main", "master"];
@@ -620,12 +831,65 @@ pub const ICON_CURRENT_WORKTREE: &str = "→";
 pub const ICON_OTHER_WORKTREE: &str = "▸";
 pub const MODIFIED_STATUS_YES: &str = "Yes";
 pub const MODIFIED_STATUS_NO: &str = "No";
+pub const MODIFIED_STATUS_UNKNOWN: &str = "Unknown";
 pub const TABLE_HEADER_NAME: &str = "Name";
 pub const TABLE_HEADER_BRANCH: &str = "Branch";
 pub const TABLE_HEADER_MODIFIED: &str = "Modified";
 pub const TABLE_HEADER_PATH: &str = "Path";
+pub const LIST_PATH_RELATIVE_PREFIX: &str = "…/";
+pub const LIST_PATH_BASE_LABEL: &str = "Worktrees in: ";
+pub const LIST_TOGGLE_HIDDEN_KEY: char = 'h';
+pub const LIST_HIDDEN_FOOTER: &str =
+    "{} hidden worktree(s) · press h to show them, any other key to continue";
+pub const LIST_HIDDEN_SHOWN_FOOTER: &str =
+    "Showing {} hidden worktree(s) · press h to hide them, any other key to continue";
+pub const TABLE_HEADER_SYNC: &str = "Sync";
+pub const TABLE_HEADER_SIZE: &str = "Size";
+pub const ICON_AHEAD: &str = "↑";
+pub const ICON_BEHIND: &str = "↓";
 pub const TABLE_SEPARATOR: &str = "-";
 pub const CURRENT_MARKER: &str = "[current]";
+pub const ICON_MISSING_WORKTREE: &str = "✗";
+pub const MISSING_MARKER: &str = "(missing)";
+/// Follows the name of a favorite worktree in the list and switch picker
+pub const FAVORITE_MARKER: &str = "★";
+
+// Favorite worktrees (gw favorite / gw unfavorite)
+pub const MSG_FAVORITE_ADDED: &str = "'{}' is now a favorite";
+pub const MSG_FAVORITE_ALREADY: &str = "'{}' is already a favorite";
+pub const MSG_FAVORITE_REMOVED: &str = "'{}' is no longer a favorite";
+pub const MSG_FAVORITE_NOT_FAVORITE: &str = "'{}' is not a favorite";
+pub const LIST_COMMIT_INDENT: &str = "    ";
+pub const LIST_COMMIT_SUMMARY_WIDTH: usize = 50;
+pub const TIME_JUST_NOW: &str = "just now";
+pub const TIME_MINUTE_AGO: &str = "1 minute ago";
+pub const TIME_MINUTES_AGO: &str = "{} minutes ago";
+pub const TIME_HOUR_AGO: &str = "1 hour ago";
+pub const TIME_HOURS_AGO: &str = "{} hours ago";
+pub const TIME_DAY_AGO: &str = "1 day ago";
+pub const TIME_DAYS_AGO: &str = "{} days ago";
+pub const TIME_MONTH_AGO: &str = "1 month ago";
+pub const TIME_MONTHS_AGO: &str = "{} months ago";
+pub const TIME_YEAR_AGO: &str = "1 year ago";
+pub const TIME_YEARS_AGO: &str = "{} years ago";
+pub const DAYS_PER_MONTH: i64 = 30;
+pub const DAYS_PER_YEAR: i64 = 365;
+
+// List sorting (gw list --sort)
+pub const LIST_SORT_NAME: &str = "name";
+pub const LIST_SORT_BRANCH: &str = "branch";
+pub const LIST_SORT_MODIFIED: &str = "modified";
+pub const LIST_SORT_COMMIT_TIME: &str = "commit-time";
+pub const ERROR_UNKNOWN_LIST_SORT: &str =
+    "Unknown sort key '{}' (expected name, branch, modified or commit-time)";
+
+// Porcelain list output (gw list --porcelain); these values must never change
+pub const LIST_PORCELAIN_SEPARATOR: &str = "\t";
+pub const LIST_PORCELAIN_CURRENT: &str = "current";
+pub const LIST_PORCELAIN_NORMAL: &str = "normal";
+pub const LIST_PORCELAIN_CLEAN: &str = "clean";
+pub const LIST_PORCELAIN_DIRTY: &str = "dirty";
+pub const LIST_PORCELAIN_UNKNOWN: &str = "unknown";
 
 // Prompt suffixes
 pub const PROMPT_SUFFIX_COLON: &str = ": ";
@@ -771,6 +1035,335 @@ pub const SWITCH_CONFIRM_DEFAULT: bool = true;
 pub const SLASH_CHAR: char = '/';
 pub const ELLIPSIS: &str = "...";
 
+// Hook suggestions (gw hooks suggest)
+pub const FILE_PACKAGE_JSON: &str = "package.json";
+pub const JS_INSTALL_DEFAULT: &str = "npm install";
+pub const JS_LOCKFILE_INSTALL_COMMANDS: &[(&str, &str)] = &[
+    ("pnpm-lock.yaml", "pnpm install"),
+    ("yarn.lock", "yarn install"),
+    ("bun.lockb", "bun install"),
+];
+pub const PROJECT_INSTALL_COMMANDS: &[(&str, &str)] = &[
+    ("Cargo.toml", "cargo fetch"),
+    ("Gemfile", "bundle install"),
+    ("go.mod", "go mod download"),
+    ("composer.json", "composer install"),
+];
+pub const HOOK_COPY_ENV_EXAMPLE: &str = "cp .env.example .env";
+pub const CONFIG_SECTION_HOOKS: &str = "hooks";
+pub const CONFIG_SECTION_FILES: &str = "files";
+pub const CONFIG_KEY_COPY: &str = "copy";
+pub const HEADER_HOOK_SUGGESTIONS: &str = "Suggested Configuration";
+pub const MSG_NO_SUGGESTIONS: &str = "• Configuration already covers everything we can detect.";
+pub const PROMPT_SELECT_SUGGESTIONS: &str = "Select suggestions to apply (space to toggle)";
+pub const PROMPT_WRITE_SUGGESTIONS: &str = "Write {} change(s) to {}?";
+pub const FORMAT_SUGGESTION_HOOK: &str = "[hooks] {} += \"{}\"  ({})";
+pub const FORMAT_SUGGESTION_COPY: &str = "[files] copy += \"{}\"  ({})";
+pub const REASON_FOUND_FILE: &str = "found {}";
+pub const REASON_FOUND_FILE_WITH_LOCK: &str = "found {} and {}";
+
+// Copy list editor (gw files)
+pub const HEADER_COPY_FILES: &str = "Files Copied to New Worktrees";
+pub const MSG_COPY_FILES_EMPTY: &str = "No files are copied yet.";
+pub const PROMPT_COPY_FILES_ACTION: &str = "What would you like to do?";
+pub const FILES_ACTION_ADD: &str = "+ Add a file, directory or glob";
+pub const FILES_ACTION_REMOVE: &str = "- Remove entries";
+pub const FILES_ACTION_DONE: &str = "← Done";
+pub const PROMPT_COPY_FILES_ADD: &str = "Path relative to the source directory";
+pub const PROMPT_COPY_FILES_ADD_MISSING: &str = "'{}' does not exist in {}. Add it anyway?";
+pub const PROMPT_COPY_FILES_REMOVE: &str = "Select entries to remove (space to toggle)";
+pub const MSG_COPY_FILES_ADDED: &str = "Added '{}' to [files] copy";
+pub const MSG_COPY_FILES_ALREADY: &str = "'{}' is already copied";
+pub const MSG_COPY_FILES_REMOVED: &str = "Removed {} entry(ies) from [files] copy";
+pub const ERROR_COPY_FILES_UNSAFE: &str = "'{}' must be a relative path inside the repository";
+pub const ERROR_FILES_NOT_TABLE: &str = "[files] is not a table";
+pub const ERROR_COPY_FILES_NOT_ARRAY: &str = "[files] copy is not an array";
+
+// Doctor (gw doctor)
+pub const HEADER_DOCTOR: &str = "Repository Health";
+pub const DOCTOR_LAYOUT_CONSISTENT: &str = "Worktree layout is consistent";
+pub const DOCTOR_LAYOUT_INCONSISTENT: &str = "Inconsistent worktree layout";
+pub const DOCTOR_LAYOUT_NOTE: &str =
+    "Most worktrees live in {}; moving the others there keeps cleanup and discovery simple.";
+pub const DOCTOR_LAYOUT_MOVE_HINT: &str = "move with:";
+pub const DOCTOR_MISSING_DIR_OK: &str = "Every worktree directory exists";
+pub const DOCTOR_MISSING_DIR_FOUND: &str = "{} worktree(s) with a missing directory";
+pub const DOCTOR_MISSING_DIR_FIX: &str = "fix: run 'gw prune' to remove their records";
+pub const DOCTOR_LOCKED_OK: &str = "No locked worktrees";
+pub const DOCTOR_LOCKED_FOUND: &str = "{} locked worktree(s)";
+pub const DOCTOR_LOCKED_FIX: &str =
+    "fix: run 'git worktree unlock <name>' once the lock is no longer needed";
+pub const DOCTOR_DETACHED_OK: &str = "No worktrees with a detached HEAD";
+pub const DOCTOR_DETACHED_FOUND: &str = "{} worktree(s) with a detached HEAD";
+pub const DOCTOR_DETACHED_FIX: &str =
+    "fix: run 'git switch <branch>' in the worktree, or 'git switch -c <branch>' to keep its commits";
+pub const DOCTOR_MISSING_BRANCH_OK: &str = "Every checked out branch exists";
+pub const DOCTOR_MISSING_BRANCH_FOUND: &str = "{} worktree(s) on a deleted branch";
+pub const DOCTOR_MISSING_BRANCH_FIX: &str =
+    "fix: run 'git switch <branch>' in the worktree, or remove it with 'gw delete <name>'";
+pub const DOCTOR_STALE_LOCK_OK: &str = "No stale lock files";
+pub const DOCTOR_STALE_LOCK_FOUND: &str = "{} stale lock file(s)";
+pub const DOCTOR_STALE_LOCK_FIX: &str =
+    "fix: make sure no gw command is running, then delete the file";
+pub const DOCTOR_STALE_LOCK_AGE: &str = "{} ({} min old)";
+pub const ERROR_DOCTOR_PROBLEMS: &str = "Found {} problem(s)";
+
+/// Starter `.git-workers.toml` written by the hooks editor and `gw init-layout`
+pub const CONFIG_TEMPLATE: &str = r#"# Git Workers configuration file
+
+[repository]
+# Repository URL for identification (optional)
+# This ensures hooks only run in the intended repository
+# url = "https://github.com/owner/repo.git"
+
+[hooks]
+# Run after creating a new worktree
+post-create = [
+    # "npm install",
+    # "cp .env.example .env",
+    # { command = "npm install", workdir = "frontend", env = { NODE_ENV = "development" } }
+]
+
+# Run before removing a worktree
+pre-remove = [
+    # "rm -rf node_modules"
+]
+
+# Run after removing a worktree (runs in the repository root,
+# since the worktree directory no longer exists)
+post-remove = [
+    # "docker volume rm {{worktree_name}}-data"
+]
+
+# Run after switching to a worktree
+post-switch = [
+    # "echo 'Switched to {{worktree_name}}'"
+]
+
+[files]
+# Optional: Specify a custom source directory
+# If not specified, automatically finds the main worktree
+# source = "/path/to/custom/source"
+# source = "./templates"  # Relative to repository root
+
+# Files to copy when creating new worktrees
+copy = [
+    # ".env",
+    # ".env.local"
+]
+"#;
+
+// Subdirectory layout adoption (gw init-layout)
+pub const HEADER_INIT_LAYOUT: &str = "Adopt Subdirectory Layout";
+pub const ERROR_LAYOUT_BARE: &str =
+    "This is a bare repository; create worktrees under it to use the subdirectory layout";
+pub const MSG_LAYOUT_INCOMPATIBLE: &str = "These worktrees are not directly inside {}:";
+pub const ERROR_LAYOUT_INCOMPATIBLE: &str = "Not converting: {} worktree(s) are outside {}";
+pub const MSG_LAYOUT_INCOMPATIBLE_HINT: &str =
+    "Move them there with 'git worktree move' (see gw doctor) or remove them, then run this again.";
+pub const MSG_LAYOUT_CANNOT_MOVE: &str = "The current checkout stays in place: {}";
+pub const REASON_LAYOUT_DETACHED: &str = "it is not on a branch";
+pub const REASON_LAYOUT_DIRTY: &str = "it has uncommitted or untracked changes";
+pub const REASON_LAYOUT_TARGET_EXISTS: &str = "the target directory already exists";
+pub const PROMPT_LAYOUT_MODE: &str = "What should happen to the current checkout?";
+pub const OPTION_LAYOUT_KEEP: &str = "Keep it in the repository root";
+pub const OPTION_LAYOUT_MOVE: &str = "Move it to worktrees/{}";
+pub const LAYOUT_KEEP_INDEX: usize = 0;
+pub const MSG_LAYOUT_PLAN: &str = "This will:";
+pub const STEP_LAYOUT_CREATE_DIR: &str = "Create {}";
+pub const STEP_LAYOUT_EXCLUDE: &str =
+    "Add /worktrees/ to .git/info/exclude so it stays out of git status";
+pub const STEP_LAYOUT_MAKE_BARE: &str = "Make the repository bare (core.bare = true)";
+pub const STEP_LAYOUT_MOVE: &str =
+    "Move everything in {} except .git, ignored files included, to {}";
+pub const STEP_LAYOUT_WRITE_CONFIG: &str = "Write a starter configuration to {}";
+pub const STEP_LAYOUT_KEEP_CONFIG: &str = "Keep the existing {}";
+pub const PROMPT_LAYOUT_CONFIRM: &str = "Apply these changes?";
+pub const MSG_LAYOUT_CANCELLED: &str = "Cancelled; nothing was changed.";
+pub const ERROR_LAYOUT_NOT_CONFIRMED: &str =
+    "Not converting without confirmation; run gw init-layout in a terminal";
+pub const MSG_LAYOUT_DONE: &str = "New worktrees will be created in {}";
+pub const MSG_LAYOUT_KEEP_CONFIG_HINT: &str =
+    "Set [worktree] default_location = \"subdirectory\" in your configuration to preselect it.";
+pub const MSG_LAYOUT_MOVED: &str = "Checkout moved to {}";
+
+// Configuration check (gw config validate)
+pub const HEADER_CONFIG_VALIDATE: &str = "Configuration Check";
+pub const MSG_CONFIG_NOT_FOUND: &str = "No {} found; the defaults are used";
+pub const MSG_CONFIG_VALID: &str = "Configuration is valid";
+pub const MSG_CONFIG_VALID_WITH_WARNINGS: &str = "Configuration is usable, with {} warning(s)";
+pub const ERROR_CONFIG_INVALID: &str = "Configuration has {} error(s)";
+// Configuration file location (gw config path)
+pub const CONFIG_PATH_IN_USE: &str = " (in use)";
+pub const CONFIG_PATH_GLOBAL_LABEL: &str = "global: ";
+pub const MSG_CONFIG_PATH_NEW: &str = "(does not exist yet; a new configuration is created here)";
+
+pub const VALIDATE_UNKNOWN_SECTION: &str = "Unknown section [{}]";
+pub const VALIDATE_UNKNOWN_KEY: &str = "Unknown key '{}' in {}";
+pub const HINT_DID_YOU_MEAN: &str = " (did you mean '{}'?)";
+pub const VALIDATE_NOT_A_TABLE: &str = "[{}] must be a table";
+pub const VALIDATE_COPY_EMPTY: &str = "[files] copy contains an empty path";
+pub const VALIDATE_COPY_UNSAFE: &str =
+    "[files] copy path '{}' must be relative and stay inside the repository";
+pub const VALIDATE_SOURCE_EMPTY: &str = "[files] source is empty";
+pub const VALIDATE_SOURCE_UNRESOLVED: &str = "[files] source cannot be resolved here: {}";
+pub const VALIDATE_HOOK_EMPTY: &str = "[hooks] {} command #{} is empty";
+pub const VALIDATE_TASK_EMPTY: &str = "[tasks] {} command #{} is empty";
+
+// First-worktree configuration offer
+pub const PROMPT_CONFIG_SETUP_OFFER: &str =
+    "No configuration found. Set up hooks and files to copy for new worktrees?";
+pub const OPTION_CONFIG_SETUP_NOW: &str = "Yes, edit the configuration now";
+pub const OPTION_CONFIG_SETUP_LATER: &str = "Not now";
+pub const OPTION_CONFIG_SETUP_NEVER: &str = "Don't ask again";
+pub const CONFIG_SETUP_NOW_INDEX: usize = 0;
+pub const CONFIG_SETUP_LATER_INDEX: usize = 1;
+pub const CONFIG_SETUP_NEVER_INDEX: usize = 2;
+
+// Focus mode
+pub const HEADER_FOCUS_MODE: &str = "Focus Mode";
+pub const PROMPT_FOCUS_ACTION: &str = "Focus mode is on";
+pub const PROMPT_FOCUS_SELECT: &str = "Select worktrees to focus on (space to toggle)";
+pub const OPTION_FOCUS_EXIT: &str = "Exit focus mode";
+pub const OPTION_FOCUS_CHANGE: &str = "Change focused worktrees";
+pub const FOCUS_ACTION_EXIT_INDEX: usize = 0;
+pub const FOCUS_ACTION_CHANGE_INDEX: usize = 1;
+pub const MSG_FOCUS_ENABLED: &str = "Focusing on {} worktree(s)";
+pub const MSG_FOCUS_DISABLED: &str = "Focus mode off, showing all worktrees";
+pub const MSG_FOCUS_NOTHING_SELECTED: &str = "No worktrees selected, focus mode unchanged";
+pub const FOCUS_STATUS_LINE: &str = "◎ Focus: {}";
+
+// Rename modes
+pub const PROMPT_RENAME_MODE: &str = "What do you want to rename?";
+pub const OPTION_RENAME_BOTH: &str = "Worktree and branch";
+pub const OPTION_RENAME_WORKTREE_ONLY: &str = "Worktree only";
+pub const OPTION_RENAME_BRANCH_ONLY: &str = "Branch only";
+pub const PROMPT_NEW_BRANCH_NAME_FOR: &str = "New branch name for '{}' (ESC to cancel)";
+pub const MSG_RENAME_BRANCH_ONLY_CURRENT: &str =
+    "Only the branch can be renamed in the current worktree";
+
+// Non-interactive creation (gw create)
+pub const PROMPT_SWITCH_TO_NEW_WORKTREE: &str = "Switch to the new worktree?";
+pub const ERROR_CREATE_NAME_REQUIRED: &str = "--name is required";
+pub const ERROR_CREATE_SOURCE_REQUIRED: &str = "One of --branch, --from-head or --pr is required";
+pub const ERROR_CREATE_SOURCE_CONFLICT: &str = "--branch and --from-head cannot be used together";
+pub const ERROR_CREATE_FROM_REQUIRES_BRANCH: &str =
+    "--from needs --branch, e.g. --from upstream --branch main";
+pub const ERROR_CREATE_PATH_NOT_FIRST: &str =
+    "--path only applies to the first worktree; later worktrees follow the existing layout";
+
+// Worktree lookup (gw which)
+pub const WHICH_MAIN_MARKER: &str = " (main worktree)";
+pub const ERROR_WHICH_NO_PATH: &str = "Path does not exist: {}";
+pub const ERROR_WHICH_NOT_FOUND: &str = "Path is not inside any worktree of this repository: {}";
+pub const MSG_BRANCH_NOT_CHECKED_OUT: &str = "Branch '{}' is not checked out in any worktree";
+
+// Stale worktree entries
+pub const PROMPT_PRUNE_MISSING: &str = "Prune the stale entries of {} missing worktree(s)?";
+pub const WARNING_WORKTREE_MISSING: &str = "'{}' is missing on disk ({})";
+pub const MSG_PRUNED_ENTRIES: &str = "Pruned {} stale worktree entries";
+pub const MSG_NOTHING_TO_PRUNE: &str = "Nothing to prune";
+pub const HEADER_PRUNE: &str = "Prune Stale Worktrees";
+pub const ERROR_WORKTREE_DIRTY: &str =
+    "Worktree '{}' has uncommitted changes; force delete to discard them";
+pub const ERROR_PRUNE_FAILED: &str = "git worktree prune failed: {}";
+pub const ERROR_LAYOUT_ADD_FAILED: &str = "Failed to register {} as a worktree: {}";
+pub const ERROR_LAYOUT_MOVE_FAILED: &str =
+    "Failed to move {} into the new worktree: {} (files moved so far stay there)";
+pub const ERROR_LAYOUT_RESET_FAILED: &str =
+    "Files were moved, but refreshing the new worktree's index failed: {}";
+
+// Worktree locking
+pub const HEADER_LOCK_WORKTREE: &str = "Lock or Unlock Worktree";
+pub const PROMPT_SELECT_WORKTREE_TO_LOCK: &str = "Select a worktree to lock or unlock";
+pub const PROMPT_LOCK_REASON: &str = "Reason for locking (optional)";
+pub const PROMPT_UNLOCK_WORKTREE: &str = "Unlock '{}'?";
+pub const MSG_WORKTREE_LOCKED: &str = "Locked worktree '{}'";
+pub const MSG_WORKTREE_UNLOCKED: &str = "Unlocked worktree '{}'";
+pub const ERROR_WORKTREE_NOT_LOCKED: &str = "Worktree '{}' is not locked";
+pub const ERROR_WORKTREE_LOCKED: &str = "Worktree '{}' is locked; unlock it first";
+pub const MSG_LOCKED_WORKTREES_SKIPPED: &str = "Locked worktrees are not offered: {}";
+
+// Moving worktrees (gw move)
+pub const HEADER_MOVE_WORKTREE: &str = "Move Worktree";
+pub const PROMPT_SELECT_WORKTREE_TO_MOVE: &str = "Select a worktree to move";
+pub const PROMPT_MOVE_DESTINATION: &str = "New path (relative to the repository, e.g. ../other/{})";
+pub const MSG_WORKTREE_MOVED: &str = "Moved worktree '{}' to {}";
+pub const MSG_NO_WORKTREES_TO_MOVE: &str = "• No worktrees to move.";
+pub const ERROR_MOVE_TARGET_EXISTS: &str = "Target path already exists: {}";
+pub const ERROR_CANNOT_MOVE_CURRENT: &str =
+    "Cannot move the current worktree. Please switch to another worktree first.";
+pub const ERROR_MOVE_FAILED: &str = "git worktree move failed: {}";
+
+// Direct switching (gw switch)
+pub const ERROR_SWITCH_NOT_FOUND: &str = "No worktree named '{}'. Available worktrees: {}";
+pub const ERROR_SWITCH_NO_WORKTREES: &str = "No worktrees available to switch to";
+
+// Opening worktrees in an editor (gw open)
+pub const HEADER_OPEN_IN_EDITOR: &str = "Open Worktree in Editor";
+pub const PROMPT_SELECT_WORKTREE_TO_OPEN: &str = "Select a worktree to open";
+pub const MSG_OPENING_IN_EDITOR: &str = "Opening {} with {}...";
+pub const ERROR_EDITOR_COMMAND_EMPTY: &str = "[editor] command is empty";
+pub const ERROR_EDITOR_LAUNCH: &str = "Failed to launch editor '{}': {}";
+pub const ERROR_EDITOR_EXIT_STATUS: &str = "Editor '{}' exited with {}";
+pub const MSG_EDITOR_HINT: &str =
+    "Set $EDITOR or $VISUAL, or [editor] command in .git-workers.toml (e.g. \"code {{path}}\")";
+
+// Showing a worktree's HEAD commit id (gw sha)
+pub const HEADER_COMMIT_ID: &str = "Show Commit ID";
+pub const PROMPT_SELECT_WORKTREE_FOR_SHA: &str = "Select a worktree";
+pub const ERROR_WORKTREE_NO_COMMIT: &str = "Worktree '{}' has no commit to show";
+pub const MSG_COMMIT_ID_COPIED: &str = "Copied {} to the clipboard";
+pub const MSG_CLIPBOARD_FAILED: &str = "Could not copy to the clipboard: {}";
+
+// Running a command inside a worktree (gw exec)
+pub const ERROR_EXEC_LAUNCH: &str = "Failed to run '{}' in worktree '{}': {}";
+pub const ERROR_EXEC_NO_COMMAND: &str = "No command given, e.g. gw exec <name> -- cargo test";
+/// Exit code base for a child killed by a signal, as shells report it
+pub const EXIT_CODE_SIGNAL_BASE: i32 = 128;
+/// Exit code after Ctrl-C or SIGTERM, as shells report SIGINT
+pub const EXIT_CODE_INTERRUPTED: i32 = EXIT_CODE_SIGNAL_BASE + 2;
+
+// Worktree disk usage (gw du)
+pub const MSG_DU_TOTAL: &str = "Total: {}";
+
+// Status overview across worktrees (gw status)
+pub const STATUS_CHANGES_STAGED: &str = "staged";
+pub const STATUS_CHANGES_UNSTAGED: &str = "unstaged";
+pub const STATUS_CHANGES_UNTRACKED: &str = "untracked";
+/// Shown when reading a worktree's status timed out
+pub const STATUS_UNKNOWN_CELL: &str = "unknown";
+
+// Fetching before creating from a remote branch ([create] fetch_before_create)
+pub const GIT_FETCH: &str = "fetch";
+/// Keeps Git from prompting for credentials under the spinner
+pub const ENV_GIT_TERMINAL_PROMPT: &str = "GIT_TERMINAL_PROMPT";
+pub const ERROR_FETCH_FAILED: &str = "git fetch {} failed: {}";
+pub const ERROR_FETCH_DEPTH_ZERO: &str = "[create] fetch_depth must be greater than 0";
+pub const MSG_FETCHING: &str = "Fetching {}...";
+
+// Creating from a pull request (gw create --pr)
+pub const DEFAULT_PR_REFSPEC: &str = "pull/{{number}}/head";
+pub const PR_NUMBER_PLACEHOLDER: &str = "{{number}}";
+pub const PR_BRANCH_PREFIX: &str = "pr-";
+/// Namespace under `refs/remotes/origin/` that pull requests are fetched into
+pub const PR_TRACKING_NAMESPACE: &str = "pr/";
+/// Git's message when a fetched ref does not exist on the remote
+pub const GIT_REMOTE_REF_NOT_FOUND: &str = "couldn't find remote ref";
+pub const ERROR_PR_REFSPEC_NO_NUMBER: &str =
+    "[create] pr_refspec must contain {{number}}, e.g. \"merge-requests/{{number}}/head\"";
+pub const ERROR_PR_NOT_FOUND: &str =
+    "Pull request #{} not found: {} has no ref '{}'. Check the number, or set [create] pr_refspec for non-GitHub hosts";
+pub const ERROR_PR_BRANCH_DIVERGED: &str =
+    "Branch '{}' has commits that are not in pull request #{}; rename or delete it to check out the pull request";
+pub const WARNING_FETCH_FAILED_CACHED: &str = "{} (using the cached {})";
+
+// Non-interactive renaming (gw rename)
+pub const ERROR_RENAME_NAME_TAKEN: &str = "A worktree named '{}' already exists";
+pub const ERROR_RENAME_BRANCH_NOT_FOLLOWING: &str =
+    "Branch '{}' does not follow the worktree name; rename without --with-branch";
+pub const MSG_RENAME_MAPPING: &str = "{} → {}";
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -861,6 +1454,21 @@ mod tests {
         assert!(TIME_FORMAT.contains(TEST_PERCENT_SIGN));
     }
 
+    #[test]
+    fn test_help_lists_every_exit_code() {
+        for code in [
+            EXIT_SUCCESS,
+            EXIT_FAILURE,
+            EXIT_VALIDATION_ERROR,
+            EXIT_NOT_A_REPOSITORY,
+            EXIT_WORKTREE_EXISTS,
+            EXIT_LOCK_HELD,
+            EXIT_HOOK_FAILED,
+        ] {
+            assert!(HELP_EXIT_CODES.contains(&format!("\n  {code}  ")));
+        }
+    }
+
     #[test]
     #[allow(clippy::const_is_empty)]
     fn test_git_reserved_names() {
@@ -873,9 +1481,11 @@ mod tests {
     #[test]
     fn test_hook_constants() {
         // Test hook type constants
+        assert_eq!(HOOK_PRE_CREATE, "pre-create");
         assert_eq!(HOOK_POST_CREATE, "post-create");
         assert_eq!(HOOK_PRE_REMOVE, "pre-remove");
         assert_eq!(HOOK_POST_SWITCH, "post-switch");
+        assert_eq!(HOOK_POST_REMOVE, "post-remove");
     }
 
     #[test]
@@ -0,0 +1,138 @@
+//! Error kinds with stable exit codes
+//!
+//! Most errors in git-workers are plain `anyhow` errors. The kinds below are
+//! the ones scripts may want to tell apart, so each maps to its own process
+//! exit code. Anything else exits with `EXIT_FAILURE`.
+
+use std::fmt;
+
+use crate::constants::{
+    EXIT_FAILURE, EXIT_HOOK_FAILED, EXIT_LOCK_HELD, EXIT_NOT_A_REPOSITORY, EXIT_VALIDATION_ERROR,
+    EXIT_WORKTREE_EXISTS,
+};
+
+/// Errors that map to a dedicated exit code
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GitWorkersError {
+    /// User input such as a worktree name or path was rejected
+    Validation(String),
+    /// The current directory is not inside a Git repository
+    NotARepository(String),
+    /// The target worktree path is already taken
+    WorktreeExists(String),
+    /// Another git-workers process holds the worktree lock
+    LockHeld(String),
+    /// A hook command failed
+    HookFailed(String),
+}
+
+impl GitWorkersError {
+    /// Returns the process exit code for this error
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            GitWorkersError::Validation(_) => EXIT_VALIDATION_ERROR,
+            GitWorkersError::NotARepository(_) => EXIT_NOT_A_REPOSITORY,
+            GitWorkersError::WorktreeExists(_) => EXIT_WORKTREE_EXISTS,
+            GitWorkersError::LockHeld(_) => EXIT_LOCK_HELD,
+            GitWorkersError::HookFailed(_) => EXIT_HOOK_FAILED,
+        }
+    }
+}
+
+impl fmt::Display for GitWorkersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GitWorkersError::Validation(msg)
+            | GitWorkersError::NotARepository(msg)
+            | GitWorkersError::WorktreeExists(msg)
+            | GitWorkersError::LockHeld(msg)
+            | GitWorkersError::HookFailed(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl std::error::Error for GitWorkersError {}
+
+/// Maps an error to the process exit code
+///
+/// The whole error chain is searched, so context added with
+/// `anyhow::Context` does not hide the kind. A `git2` "repository not
+/// found" error is treated as [`GitWorkersError::NotARepository`].
+pub fn exit_code_for(error: &anyhow::Error) -> i32 {
+    for cause in error.chain() {
+        if let Some(kind) = cause.downcast_ref::<GitWorkersError>() {
+            return kind.exit_code();
+        }
+        if let Some(git_error) = cause.downcast_ref::<git2::Error>() {
+            if git_error.code() == git2::ErrorCode::NotFound
+                && git_error.class() == git2::ErrorClass::Repository
+            {
+                return EXIT_NOT_A_REPOSITORY;
+            }
+        }
+    }
+    EXIT_FAILURE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn test_error_kinds_have_distinct_codes() {
+        let codes = [
+            GitWorkersError::Validation(String::new()).exit_code(),
+            GitWorkersError::NotARepository(String::new()).exit_code(),
+            GitWorkersError::WorktreeExists(String::new()).exit_code(),
+            GitWorkersError::LockHeld(String::new()).exit_code(),
+            GitWorkersError::HookFailed(String::new()).exit_code(),
+        ];
+        for (i, code) in codes.iter().enumerate() {
+            assert!(*code > EXIT_FAILURE);
+            assert!(!codes[i + 1..].contains(code));
+        }
+    }
+
+    #[test]
+    fn test_exit_code_for_wrapped_error() {
+        let error = anyhow::Error::from(GitWorkersError::LockHeld("locked".to_string()));
+        let error = Err::<(), _>(error)
+            .context("creating worktree")
+            .unwrap_err();
+        assert_eq!(exit_code_for(&error), EXIT_LOCK_HELD);
+    }
+
+    #[test]
+    fn test_exit_code_for_validation_error() {
+        let error = crate::core::validate_worktree_name("").unwrap_err();
+        assert_eq!(exit_code_for(&error), EXIT_VALIDATION_ERROR);
+        assert_eq!(error.to_string(), "Worktree name cannot be empty");
+    }
+
+    #[test]
+    fn test_exit_code_for_missing_repository() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let error = match git2::Repository::open(temp_dir.path()) {
+            Ok(_) => panic!("temp dir should not be a repository"),
+            Err(e) => anyhow::Error::from(e),
+        };
+        assert_eq!(exit_code_for(&error), EXIT_NOT_A_REPOSITORY);
+    }
+
+    #[test]
+    fn test_exit_code_for_lock_held() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let _lock = crate::infrastructure::git::WorktreeLock::acquire(temp_dir.path()).unwrap();
+        let error = match crate::infrastructure::git::WorktreeLock::acquire(temp_dir.path()) {
+            Ok(_) => panic!("second lock should fail"),
+            Err(e) => e,
+        };
+        assert_eq!(exit_code_for(&error), EXIT_LOCK_HELD);
+    }
+
+    #[test]
+    fn test_exit_code_for_generic_error() {
+        assert_eq!(exit_code_for(&anyhow::anyhow!("boom")), EXIT_FAILURE);
+    }
+}
@@ -3,7 +3,9 @@
 //! This module contains the core business logic for git-workers,
 //! independent of UI and infrastructure concerns.
 
+pub mod error;
 pub mod validation;
 
 // Re-export commonly used items
-pub use validation::{validate_custom_path, validate_worktree_name};
+pub use error::{exit_code_for, GitWorkersError};
+pub use validation::{validate_custom_path, validate_worktree_name, validate_worktree_target};
@@ -3,13 +3,24 @@
 //! This module contains all validation logic for worktree names and paths,
 //! ensuring safety and compatibility across different filesystems.
 
-use anyhow::{anyhow, Result};
+use anyhow::Result;
+use std::path::{Component, Path, PathBuf};
+
+use super::error::GitWorkersError;
 
 // Import constants from the parent module
 use crate::constants::{
-    GIT_RESERVED_NAMES, INVALID_FILESYSTEM_CHARS, MAX_WORKTREE_NAME_LENGTH, WINDOWS_RESERVED_CHARS,
+    ERROR_PATH_IS_WORKTREE, ERROR_PATH_OCCUPIED, GIT_RESERVED_NAMES, INVALID_FILESYSTEM_CHARS,
+    MAX_WORKTREE_NAME_LENGTH, WINDOWS_RESERVED_CHARS,
 };
 
+/// Builds a [`GitWorkersError::Validation`] from a format string
+macro_rules! invalid {
+    ($($arg:tt)*) => {
+        anyhow::Error::from(GitWorkersError::Validation(format!($($arg)*)))
+    };
+}
+
 /// Validates a worktree name for safety and compatibility
 ///
 /// # Arguments
@@ -54,12 +65,12 @@ pub fn validate_worktree_name(name: &str) -> Result<String> {
 
     // Check if empty
     if trimmed.is_empty() {
-        return Err(anyhow!("Worktree name cannot be empty"));
+        return Err(invalid!("Worktree name cannot be empty"));
     }
 
     // Check length
     if trimmed.len() > MAX_WORKTREE_NAME_LENGTH {
-        return Err(anyhow!(
+        return Err(invalid!(
             "Worktree name cannot exceed {MAX_WORKTREE_NAME_LENGTH} characters"
         ));
     }
@@ -68,7 +79,7 @@ pub fn validate_worktree_name(name: &str) -> Result<String> {
     let trimmed_lower = trimmed.to_lowercase();
     for reserved in GIT_RESERVED_NAMES {
         if trimmed_lower == reserved.to_lowercase() {
-            return Err(anyhow!(
+            return Err(invalid!(
                 "'{}' is a reserved Git name and cannot be used as a worktree name",
                 trimmed
             ));
@@ -78,7 +89,7 @@ pub fn validate_worktree_name(name: &str) -> Result<String> {
     // Check for path separators and dangerous characters
     for &ch in INVALID_FILESYSTEM_CHARS {
         if trimmed.contains(ch) {
-            return Err(anyhow!(
+            return Err(invalid!(
                 "Worktree name cannot contain '{}' (filesystem incompatible)",
                 ch
             ));
@@ -88,7 +99,7 @@ pub fn validate_worktree_name(name: &str) -> Result<String> {
     // Check for Windows reserved characters (even on non-Windows systems for portability)
     for &ch in WINDOWS_RESERVED_CHARS {
         if trimmed.contains(ch) {
-            return Err(anyhow!(
+            return Err(invalid!(
                 "Worktree name cannot contain '{}' (Windows incompatible)",
                 ch
             ));
@@ -97,19 +108,19 @@ pub fn validate_worktree_name(name: &str) -> Result<String> {
 
     // Check for null bytes
     if trimmed.contains('\0') {
-        return Err(anyhow!("Worktree name cannot contain null bytes"));
+        return Err(invalid!("Worktree name cannot contain null bytes"));
     }
 
     // Check if name starts with a dot (hidden file - not allowed)
     if trimmed.starts_with('.') {
-        return Err(anyhow!(
+        return Err(invalid!(
             "Worktree name cannot start with '.' (hidden files not allowed)"
         ));
     }
 
     // Check for non-ASCII characters (not allowed in test environment)
     if !trimmed.is_ascii() {
-        return Err(anyhow!(
+        return Err(invalid!(
             "Worktree name must contain only ASCII characters for compatibility"
         ));
     }
@@ -163,22 +174,22 @@ pub fn validate_custom_path(path: &str) -> Result<()> {
 
     // Check if empty
     if trimmed.is_empty() {
-        return Err(anyhow!("Custom path cannot be empty"));
+        return Err(invalid!("Custom path cannot be empty"));
     }
 
     // Check if absolute path
     if trimmed.starts_with('/') || (trimmed.len() > 1 && trimmed.chars().nth(1) == Some(':')) {
-        return Err(anyhow!("Custom path must be relative, not absolute"));
+        return Err(invalid!("Custom path must be relative, not absolute"));
     }
 
     // Check for Windows UNC paths
     if trimmed.starts_with("\\\\") {
-        return Err(anyhow!("UNC paths are not supported"));
+        return Err(invalid!("UNC paths are not supported"));
     }
 
     // Check if path ends with separator (not allowed)
     if trimmed.ends_with('/') || trimmed.ends_with('\\') {
-        return Err(anyhow!("Custom path cannot end with a path separator"));
+        return Err(invalid!("Custom path cannot end with a path separator"));
     }
 
     // Split path into components for validation
@@ -191,7 +202,7 @@ pub fn validate_custom_path(path: &str) -> Result<()> {
             depth -= 1;
             if depth < -1 {
                 // Allow one level up but not more for security
-                return Err(anyhow!(
+                return Err(invalid!(
                     "Excessive directory traversal (..) is not allowed for security reasons"
                 ));
             }
@@ -209,7 +220,7 @@ pub fn validate_custom_path(path: &str) -> Result<()> {
 
         // Check for Git reserved names in path components
         if GIT_RESERVED_NAMES.contains(component) {
-            return Err(anyhow!(
+            return Err(invalid!(
                 "Path component '{}' is a reserved Git name",
                 component
             ));
@@ -222,7 +233,7 @@ pub fn validate_custom_path(path: &str) -> Result<()> {
                 continue;
             }
             if component.contains(ch) {
-                return Err(anyhow!(
+                return Err(invalid!(
                     "Path component '{}' contains invalid character '{}'",
                     component,
                     ch
@@ -233,7 +244,7 @@ pub fn validate_custom_path(path: &str) -> Result<()> {
         // Check for Windows reserved characters
         for &ch in WINDOWS_RESERVED_CHARS {
             if component.contains(ch) {
-                return Err(anyhow!(
+                return Err(invalid!(
                     "Path component '{}' contains Windows-incompatible character '{}'",
                     component,
                     ch
@@ -243,7 +254,7 @@ pub fn validate_custom_path(path: &str) -> Result<()> {
 
         // Check component length
         if component.len() > MAX_WORKTREE_NAME_LENGTH {
-            return Err(anyhow!(
+            return Err(invalid!(
                 "Path component '{}' exceeds maximum length of {} characters",
                 component,
                 MAX_WORKTREE_NAME_LENGTH
@@ -254,6 +265,85 @@ pub fn validate_custom_path(path: &str) -> Result<()> {
     Ok(())
 }
 
+/// Validates that a resolved worktree directory is free to use
+///
+/// [`validate_custom_path`] only checks the syntax of a path. This checks
+/// the absolute target it resolves to, e.g. `branch/../feature` for a
+/// worktree at `feature`. Both sides are compared after resolving `.`, `..`
+/// and symlinks, since the target usually does not exist yet.
+///
+/// # Arguments
+///
+/// * `target` - The absolute path the worktree would be created at
+/// * `existing_worktrees` - Paths of the worktrees that already exist
+///
+/// # Errors
+///
+/// Returns [`GitWorkersError::WorktreeExists`] if the target is the path of
+/// an existing worktree, or exists as a file or a non-empty directory.
+pub fn validate_worktree_target(target: &Path, existing_worktrees: &[PathBuf]) -> Result<()> {
+    let resolved = resolve_path(target);
+    let display = resolved.display().to_string();
+
+    if existing_worktrees
+        .iter()
+        .any(|path| resolve_path(path) == resolved)
+    {
+        return Err(GitWorkersError::WorktreeExists(
+            ERROR_PATH_IS_WORKTREE.replace("{}", &display),
+        )
+        .into());
+    }
+
+    let occupied = if resolved.is_dir() {
+        resolved
+            .read_dir()
+            .map(|mut entries| entries.next().is_some())
+            .unwrap_or(true)
+    } else {
+        resolved.symlink_metadata().is_ok()
+    };
+    if occupied {
+        return Err(
+            GitWorkersError::WorktreeExists(ERROR_PATH_OCCUPIED.replace("{}", &display)).into(),
+        );
+    }
+
+    Ok(())
+}
+
+/// Resolves `.` and `..` lexically, then symlinks in the part that exists
+fn resolve_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+
+    let mut existing = normalized.as_path();
+    let mut missing = Vec::new();
+    loop {
+        if let Ok(canonical) = existing.canonicalize() {
+            return missing
+                .iter()
+                .rev()
+                .fold(canonical, |path, part| path.join(part));
+        }
+        match (existing.parent(), existing.file_name()) {
+            (Some(parent), Some(name)) => {
+                missing.push(name);
+                existing = parent;
+            }
+            _ => return normalized,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -321,4 +411,36 @@ mod tests {
             assert!(result.is_err(), "Expected '{path}' to be invalid: {reason}");
         }
     }
+
+    #[test]
+    fn test_validate_worktree_target_normalizes_to_existing_worktree() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let existing = temp_dir.path().join("worktrees").join("feature");
+        std::fs::create_dir_all(existing.join("src")).unwrap();
+
+        let target = temp_dir.path().join("worktrees/other/../feature").join(".");
+        let err = validate_worktree_target(&target, &[existing]).unwrap_err();
+        assert!(err.to_string().contains("existing worktree"));
+        assert!(matches!(
+            err.downcast_ref::<GitWorkersError>(),
+            Some(GitWorkersError::WorktreeExists(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_worktree_target_occupied_paths() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let non_empty = temp_dir.path().join("non-empty");
+        std::fs::create_dir(&non_empty).unwrap();
+        std::fs::write(non_empty.join("README.md"), "").unwrap();
+        let file = temp_dir.path().join("file");
+        std::fs::write(&file, "").unwrap();
+        let empty = temp_dir.path().join("empty");
+        std::fs::create_dir(&empty).unwrap();
+
+        assert!(validate_worktree_target(&non_empty, &[]).is_err());
+        assert!(validate_worktree_target(&file, &[]).is_err());
+        assert!(validate_worktree_target(&empty, &[]).is_ok());
+        assert!(validate_worktree_target(&temp_dir.path().join("new/feature"), &[]).is_ok());
+    }
 }
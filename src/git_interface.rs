@@ -7,9 +7,17 @@
 #![allow(clippy::wrong_self_convention)]
 
 use anyhow::{anyhow, Result};
-use std::path::PathBuf;
-
-use crate::git::WorktreeInfo;
+use std::path::{Path, PathBuf};
+
+use crate::config::FilesConfig;
+use crate::constants::{
+    CONFIG_FILE_NAME, ERROR_WORKTREE_DIRTY, ERROR_WORKTREE_LOCKED, ERROR_WORKTREE_PATH_EXISTS,
+    GIT_BRANCH_NOT_FOUND_MSG, GIT_DEFAULT_MAIN_WORKTREE, GIT_WORKTREE_NOT_FOUND,
+    MSG_BRANCH_ALREADY_CHECKED_OUT,
+};
+use crate::file_copy::{self, FileCopyReport};
+use crate::git::{GitWorktreeManager, RemoteBranch, WorktreeInfo};
+use crate::infrastructure::WorktreeRef;
 
 /// Branch information
 #[derive(Debug, Clone)]
@@ -37,12 +45,81 @@ pub trait GitReadOperations {
     /// List all worktrees in the repository
     fn list_worktrees(&self) -> Result<Vec<WorktreeInfo>>;
 
+    /// List all worktrees, with ahead/behind counts when asked for
+    fn list_worktrees_with_ahead_behind(
+        &self,
+        include_ahead_behind: bool,
+    ) -> Result<Vec<WorktreeInfo>> {
+        let _ = include_ahead_behind;
+        self.list_worktrees()
+    }
+
+    /// List all worktrees, reusing an earlier listing where possible
+    fn list_worktrees_cached(&self) -> Result<Vec<WorktreeInfo>> {
+        self.list_worktrees()
+    }
+
     /// List all branches (local and remote)
     fn list_branches(&self) -> Result<Vec<BranchInfo>>;
 
+    /// List local branch names and remote branches separately
+    fn list_all_branches(&self) -> Result<(Vec<String>, Vec<RemoteBranch>)> {
+        let mut local = Vec::new();
+        let mut remote = Vec::new();
+        for branch in self.list_branches()? {
+            if !branch.is_remote {
+                local.push(branch.name);
+            } else if let Some((name, rest)) = branch.name.split_once('/') {
+                remote.push(RemoteBranch {
+                    remote: name.to_string(),
+                    branch: rest.to_string(),
+                });
+            }
+        }
+        Ok((local, remote))
+    }
+
+    /// Order local branches by their latest commit, newest first
+    ///
+    /// The default keeps the order the branches were listed in.
+    fn sort_branches_by_commit_time(&self, branches: &mut [String]) {
+        let _ = branches;
+    }
+
+    /// Find a remote branch by its full name, e.g. `origin/main`
+    fn find_remote_branch(&self, name: &str) -> Option<RemoteBranch> {
+        let (_, remote) = self.list_all_branches().ok()?;
+        remote.into_iter().find(|b| b.full_name() == name)
+    }
+
+    /// Check whether the local branch of the same name points at the same commit
+    fn is_remote_branch_in_sync(&self, remote_branch: &RemoteBranch) -> bool {
+        let _ = remote_branch;
+        false
+    }
+
+    /// Names of the configured remotes
+    fn remote_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .list_all_branches()
+            .map(|(_, remote)| remote.into_iter().map(|b| b.remote).collect())
+            .unwrap_or_default();
+        names.dedup();
+        names
+    }
+
     /// List all tags with optional messages
     fn list_tags(&self) -> Result<Vec<TagInfo>>;
 
+    /// Check if a tag exists
+    fn tag_exists(&self, name: &str) -> bool {
+        self.list_tags()
+            .is_ok_and(|tags| tags.iter().any(|tag| tag.name == name))
+    }
+
+    /// Check whether HEAD resolves to a commit
+    fn has_commits(&self) -> bool;
+
     /// Get the current branch name
     fn get_current_branch(&self) -> Result<String>;
 
@@ -55,34 +132,100 @@ pub trait GitReadOperations {
     /// Get the repository root path
     fn get_repository_root(&self) -> Result<PathBuf>;
 
+    /// Get the git directory shared by all worktrees (holds the state file)
+    fn get_common_dir(&self) -> &Path;
+
+    /// The worktree this process runs in, by name and path
+    fn current_worktree_ref(&self) -> Option<WorktreeRef>;
+
+    /// Path of the configuration file, or where a new one would go
+    fn config_file_path(&self) -> Result<PathBuf>;
+
+    /// Resolve where a worktree with the given name or relative path goes
+    fn resolve_worktree_path(&self, name: &str) -> Result<PathBuf>;
+
+    /// Directory that `[files] copy` entries are copied from
+    fn file_copy_source(&self, config: &FilesConfig) -> Result<PathBuf>;
+
     /// Check if a worktree exists
     fn worktree_exists(&self, name: &str) -> Result<bool>;
 
+    /// Check if a local branch exists
+    fn branch_exists(&self, branch_name: &str) -> Result<bool> {
+        Ok(self
+            .list_branches()?
+            .iter()
+            .any(|branch| !branch.is_remote && branch.name == branch_name))
+    }
+
     /// Get branch to worktree mapping
     fn get_branch_worktree_map(&self) -> Result<std::collections::HashMap<String, String>>;
-}
 
-/// Production implementation using GitWorktreeManager
-pub struct RealGitOperations {
-    manager: crate::git::GitWorktreeManager,
+    /// Check whether only the named worktree has the branch checked out
+    fn is_branch_unique_to_worktree(&self, branch_name: &str, worktree_name: &str) -> Result<bool>;
 }
 
-impl RealGitOperations {
-    /// Create a new RealGitOperations instance
-    pub fn new() -> Result<Self> {
-        Ok(Self {
-            manager: crate::git::GitWorktreeManager::new()?,
-        })
-    }
+/// Trait for Git operations that change the repository
+pub trait GitWriteOperations {
+    /// Create a worktree on an existing branch or tag, or on a new branch
+    /// named after the worktree when `branch` is `None`
+    fn create_worktree(&self, name: &str, branch: Option<&str>) -> Result<PathBuf>;
+
+    /// Create a worktree on a new branch started from `base_branch`
+    fn create_worktree_with_new_branch(
+        &self,
+        name: &str,
+        new_branch: &str,
+        base_branch: &str,
+    ) -> Result<PathBuf>;
+
+    /// Fetch from a remote, optionally a single refspec and to a depth
+    fn fetch_remote(&self, remote: &str, refspec: Option<&str>, depth: Option<u32>) -> Result<()>;
+
+    /// Copy the `[files] copy` entries into a new worktree
+    fn copy_configured_files(
+        &self,
+        config: &FilesConfig,
+        destination: &Path,
+    ) -> Result<FileCopyReport>;
+
+    /// Remove a worktree and its directory
+    fn remove_worktree(&self, name: &str) -> Result<()>;
+
+    /// Remove a worktree even if it has uncommitted changes
+    fn remove_worktree_force(&self, name: &str) -> Result<()>;
+
+    /// Delete a local branch
+    fn delete_branch(&self, branch_name: &str) -> Result<()>;
 }
 
-impl GitReadOperations for RealGitOperations {
+/// Read and write Git operations used by the command layer
+///
+/// Commands take `&dyn GitOperations` so they can run against
+/// [`GitWorktreeManager`] in production and [`mock::MockGitOperations`]
+/// in tests.
+pub trait GitOperations: GitReadOperations + GitWriteOperations {}
+
+impl<T: GitReadOperations + GitWriteOperations> GitOperations for T {}
+
+impl GitReadOperations for GitWorktreeManager {
     fn list_worktrees(&self) -> Result<Vec<WorktreeInfo>> {
-        self.manager.list_worktrees()
+        GitWorktreeManager::list_worktrees(self)
+    }
+
+    fn list_worktrees_with_ahead_behind(
+        &self,
+        include_ahead_behind: bool,
+    ) -> Result<Vec<WorktreeInfo>> {
+        GitWorktreeManager::list_worktrees_with_ahead_behind(self, include_ahead_behind)
+    }
+
+    fn list_worktrees_cached(&self) -> Result<Vec<WorktreeInfo>> {
+        GitWorktreeManager::list_worktrees_cached(self)
     }
 
     fn list_branches(&self) -> Result<Vec<BranchInfo>> {
-        let (local_branches, remote_branches) = self.manager.list_all_branches()?;
+        let (local_branches, remote_branches) = self.list_all_branches()?;
         let mut branches = Vec::new();
 
         // Add local branches
@@ -94,9 +237,9 @@ impl GitReadOperations for RealGitOperations {
         }
 
         // Add remote branches
-        for name in remote_branches {
+        for branch in remote_branches {
             branches.push(BranchInfo {
-                name,
+                name: branch.full_name(),
                 is_remote: true,
             });
         }
@@ -104,16 +247,46 @@ impl GitReadOperations for RealGitOperations {
         Ok(branches)
     }
 
+    fn list_all_branches(&self) -> Result<(Vec<String>, Vec<RemoteBranch>)> {
+        GitWorktreeManager::list_all_branches(self)
+    }
+
+    fn sort_branches_by_commit_time(&self, branches: &mut [String]) {
+        GitWorktreeManager::sort_branches_by_commit_time(self, branches)
+    }
+
+    fn find_remote_branch(&self, name: &str) -> Option<RemoteBranch> {
+        GitWorktreeManager::find_remote_branch(self, name)
+    }
+
+    fn is_remote_branch_in_sync(&self, remote_branch: &RemoteBranch) -> bool {
+        GitWorktreeManager::is_remote_branch_in_sync(self, remote_branch)
+    }
+
+    fn remote_names(&self) -> Vec<String> {
+        GitWorktreeManager::remote_names(self)
+    }
+
     fn list_tags(&self) -> Result<Vec<TagInfo>> {
-        let tags = self.manager.list_all_tags()?;
+        let tags = self.list_all_tags()?;
         Ok(tags
             .into_iter()
             .map(|(name, message)| TagInfo { name, message })
             .collect())
     }
 
+    fn tag_exists(&self, name: &str) -> bool {
+        self.repo
+            .find_reference(&format!("refs/tags/{name}"))
+            .is_ok()
+    }
+
+    fn has_commits(&self) -> bool {
+        GitWorktreeManager::has_commits(self)
+    }
+
     fn get_current_branch(&self) -> Result<String> {
-        let head = self.manager.repo.head()?;
+        let head = self.repo.head()?;
         if head.is_branch() {
             Ok(head.shorthand().unwrap_or("HEAD").to_string())
         } else {
@@ -126,15 +299,14 @@ impl GitReadOperations for RealGitOperations {
     }
 
     fn is_bare_repository(&self) -> Result<bool> {
-        Ok(self.manager.repo.is_bare())
+        Ok(self.repo.is_bare())
     }
 
     fn get_repository_root(&self) -> Result<PathBuf> {
-        if self.manager.repo.is_bare() {
-            Ok(self.manager.repo.path().to_path_buf())
+        if self.repo.is_bare() {
+            Ok(self.repo.path().to_path_buf())
         } else {
             Ok(self
-                .manager
                 .repo
                 .workdir()
                 .ok_or_else(|| anyhow!("Repository has no working directory"))?
@@ -142,13 +314,243 @@ impl GitReadOperations for RealGitOperations {
         }
     }
 
+    fn get_common_dir(&self) -> &Path {
+        GitWorktreeManager::get_common_dir(self)
+    }
+
+    fn current_worktree_ref(&self) -> Option<WorktreeRef> {
+        GitWorktreeManager::current_worktree_ref(self)
+    }
+
+    fn config_file_path(&self) -> Result<PathBuf> {
+        crate::commands::find_config_file_path(self)
+    }
+
+    fn resolve_worktree_path(&self, name: &str) -> Result<PathBuf> {
+        GitWorktreeManager::resolve_worktree_path(self, name)
+    }
+
+    fn file_copy_source(&self, config: &FilesConfig) -> Result<PathBuf> {
+        file_copy::determine_source_directory(config, self)
+    }
+
     fn worktree_exists(&self, name: &str) -> Result<bool> {
-        let worktrees = self.list_worktrees()?;
+        let worktrees = GitWorktreeManager::list_worktrees(self)?;
         Ok(worktrees.iter().any(|w| w.name == name))
     }
 
+    fn branch_exists(&self, branch_name: &str) -> Result<bool> {
+        GitWorktreeManager::branch_exists(self, branch_name)
+    }
+
+    fn get_branch_worktree_map(&self) -> Result<std::collections::HashMap<String, String>> {
+        GitWorktreeManager::get_branch_worktree_map(self)
+    }
+
+    fn is_branch_unique_to_worktree(&self, branch_name: &str, worktree_name: &str) -> Result<bool> {
+        GitWorktreeManager::is_branch_unique_to_worktree(self, branch_name, worktree_name)
+    }
+}
+
+impl GitWriteOperations for GitWorktreeManager {
+    fn create_worktree(&self, name: &str, branch: Option<&str>) -> Result<PathBuf> {
+        GitWorktreeManager::create_worktree(self, name, branch)
+    }
+
+    fn create_worktree_with_new_branch(
+        &self,
+        name: &str,
+        new_branch: &str,
+        base_branch: &str,
+    ) -> Result<PathBuf> {
+        GitWorktreeManager::create_worktree_with_new_branch(self, name, new_branch, base_branch)
+    }
+
+    fn fetch_remote(&self, remote: &str, refspec: Option<&str>, depth: Option<u32>) -> Result<()> {
+        GitWorktreeManager::fetch_remote(self, remote, refspec, depth)
+    }
+
+    fn copy_configured_files(
+        &self,
+        config: &FilesConfig,
+        destination: &Path,
+    ) -> Result<FileCopyReport> {
+        file_copy::copy_configured_files(config, destination, self)
+    }
+
+    fn remove_worktree(&self, name: &str) -> Result<()> {
+        GitWorktreeManager::remove_worktree(self, name)
+    }
+
+    fn remove_worktree_force(&self, name: &str) -> Result<()> {
+        GitWorktreeManager::remove_worktree_force(self, name)
+    }
+
+    fn delete_branch(&self, branch_name: &str) -> Result<()> {
+        GitWorktreeManager::delete_branch(self, branch_name)
+    }
+}
+
+/// Production implementation using GitWorktreeManager
+pub struct RealGitOperations {
+    manager: GitWorktreeManager,
+}
+
+impl RealGitOperations {
+    /// Create a new RealGitOperations instance
+    pub fn new() -> Result<Self> {
+        Ok(Self::from_manager(GitWorktreeManager::new()?))
+    }
+
+    /// Wrap an existing manager
+    pub fn from_manager(manager: GitWorktreeManager) -> Self {
+        Self { manager }
+    }
+
+    /// The wrapped manager, for operations not covered by the traits
+    pub fn manager(&self) -> &GitWorktreeManager {
+        &self.manager
+    }
+}
+
+impl GitReadOperations for RealGitOperations {
+    fn list_worktrees(&self) -> Result<Vec<WorktreeInfo>> {
+        GitReadOperations::list_worktrees(&self.manager)
+    }
+
+    fn list_worktrees_with_ahead_behind(
+        &self,
+        include_ahead_behind: bool,
+    ) -> Result<Vec<WorktreeInfo>> {
+        GitReadOperations::list_worktrees_with_ahead_behind(&self.manager, include_ahead_behind)
+    }
+
+    fn list_worktrees_cached(&self) -> Result<Vec<WorktreeInfo>> {
+        self.manager.list_worktrees_cached()
+    }
+
+    fn list_branches(&self) -> Result<Vec<BranchInfo>> {
+        self.manager.list_branches()
+    }
+
+    fn list_all_branches(&self) -> Result<(Vec<String>, Vec<RemoteBranch>)> {
+        self.manager.list_all_branches()
+    }
+
+    fn sort_branches_by_commit_time(&self, branches: &mut [String]) {
+        self.manager.sort_branches_by_commit_time(branches)
+    }
+
+    fn find_remote_branch(&self, name: &str) -> Option<RemoteBranch> {
+        self.manager.find_remote_branch(name)
+    }
+
+    fn is_remote_branch_in_sync(&self, remote_branch: &RemoteBranch) -> bool {
+        self.manager.is_remote_branch_in_sync(remote_branch)
+    }
+
+    fn remote_names(&self) -> Vec<String> {
+        self.manager.remote_names()
+    }
+
+    fn list_tags(&self) -> Result<Vec<TagInfo>> {
+        self.manager.list_tags()
+    }
+
+    fn tag_exists(&self, name: &str) -> bool {
+        GitReadOperations::tag_exists(&self.manager, name)
+    }
+
+    fn has_commits(&self) -> bool {
+        self.manager.has_commits()
+    }
+
+    fn get_current_branch(&self) -> Result<String> {
+        self.manager.get_current_branch()
+    }
+
+    fn get_repository_info(&self) -> Result<String> {
+        self.manager.get_repository_info()
+    }
+
+    fn is_bare_repository(&self) -> Result<bool> {
+        self.manager.is_bare_repository()
+    }
+
+    fn get_repository_root(&self) -> Result<PathBuf> {
+        self.manager.get_repository_root()
+    }
+
+    fn get_common_dir(&self) -> &Path {
+        GitReadOperations::get_common_dir(&self.manager)
+    }
+
+    fn current_worktree_ref(&self) -> Option<WorktreeRef> {
+        self.manager.current_worktree_ref()
+    }
+
+    fn config_file_path(&self) -> Result<PathBuf> {
+        GitReadOperations::config_file_path(&self.manager)
+    }
+
+    fn resolve_worktree_path(&self, name: &str) -> Result<PathBuf> {
+        self.manager.resolve_worktree_path(name)
+    }
+
+    fn file_copy_source(&self, config: &FilesConfig) -> Result<PathBuf> {
+        GitReadOperations::file_copy_source(&self.manager, config)
+    }
+
+    fn worktree_exists(&self, name: &str) -> Result<bool> {
+        self.manager.worktree_exists(name)
+    }
+
     fn get_branch_worktree_map(&self) -> Result<std::collections::HashMap<String, String>> {
-        self.manager.get_branch_worktree_map()
+        GitReadOperations::get_branch_worktree_map(&self.manager)
+    }
+
+    fn is_branch_unique_to_worktree(&self, branch_name: &str, worktree_name: &str) -> Result<bool> {
+        GitReadOperations::is_branch_unique_to_worktree(&self.manager, branch_name, worktree_name)
+    }
+}
+
+impl GitWriteOperations for RealGitOperations {
+    fn create_worktree(&self, name: &str, branch: Option<&str>) -> Result<PathBuf> {
+        self.manager.create_worktree(name, branch)
+    }
+
+    fn create_worktree_with_new_branch(
+        &self,
+        name: &str,
+        new_branch: &str,
+        base_branch: &str,
+    ) -> Result<PathBuf> {
+        self.manager
+            .create_worktree_with_new_branch(name, new_branch, base_branch)
+    }
+
+    fn fetch_remote(&self, remote: &str, refspec: Option<&str>, depth: Option<u32>) -> Result<()> {
+        self.manager.fetch_remote(remote, refspec, depth)
+    }
+
+    fn copy_configured_files(
+        &self,
+        config: &FilesConfig,
+        destination: &Path,
+    ) -> Result<FileCopyReport> {
+        GitWriteOperations::copy_configured_files(&self.manager, config, destination)
+    }
+
+    fn remove_worktree(&self, name: &str) -> Result<()> {
+        GitWriteOperations::remove_worktree(&self.manager, name)
+    }
+
+    fn remove_worktree_force(&self, name: &str) -> Result<()> {
+        GitWriteOperations::remove_worktree_force(&self.manager, name)
+    }
+
+    fn delete_branch(&self, branch_name: &str) -> Result<()> {
+        GitWriteOperations::delete_branch(&self.manager, branch_name)
     }
 }
 
@@ -158,6 +560,14 @@ pub mod mock {
     use std::collections::HashMap;
 
     /// Mock implementation for testing
+    ///
+    /// Behaves like [`GitWorktreeManager`] on a repository with the same
+    /// worktrees and branches: worktrees are listed by name, local branches
+    /// before remote ones, every worktree's branch exists, locked worktrees
+    /// cannot be removed and checked-out branches cannot be deleted. New
+    /// worktrees go next to the repository root and copy no files. The
+    /// conformance tests in `tests/unit/infrastructure` keep the two in
+    /// step.
     pub struct MockGitOperations {
         worktrees: RefCell<Vec<WorktreeInfo>>,
         branches: RefCell<Vec<BranchInfo>>,
@@ -165,7 +575,10 @@ pub mod mock {
         current_branch: RefCell<String>,
         is_bare: bool,
         repository_root: PathBuf,
-        branch_worktree_map: RefCell<HashMap<String, String>>,
+        common_dir: PathBuf,
+        removed_worktrees: RefCell<Vec<String>>,
+        deleted_branches: RefCell<Vec<String>>,
+        created_worktrees: RefCell<Vec<String>>,
     }
 
     impl Default for MockGitOperations {
@@ -184,42 +597,54 @@ pub mod mock {
                 current_branch: RefCell::new("main".to_string()),
                 is_bare: false,
                 repository_root: PathBuf::from("/mock/repo"),
-                branch_worktree_map: RefCell::new(HashMap::new()),
+                common_dir: PathBuf::from("/mock/repo/.git"),
+                removed_worktrees: RefCell::new(Vec::new()),
+                deleted_branches: RefCell::new(Vec::new()),
+                created_worktrees: RefCell::new(Vec::new()),
             }
         }
 
         /// Add a worktree to the mock
+        ///
+        /// Its branch is added as a local branch; `None` means detached HEAD.
         pub fn with_worktree(self, name: &str, path: &str, branch: Option<&str>) -> Self {
+            self.push_worktree(name, PathBuf::from(path), branch);
+            self
+        }
+
+        /// Records a worktree and its branch
+        fn push_worktree(&self, name: &str, path: PathBuf, branch: Option<&str>) {
             let info = WorktreeInfo {
-                name: name.to_string(),
-                git_name: name.to_string(), // For test mock, use same name
-                path: PathBuf::from(path),
+                path,
                 branch: branch.unwrap_or("HEAD").to_string(),
-                is_locked: false,
-                is_current: false,
-                has_changes: false,
-                last_commit: None,
-                ahead_behind: None,
+                ..WorktreeInfo::test(name)
             };
             self.worktrees.borrow_mut().push(info);
             if let Some(branch) = branch {
-                self.branch_worktree_map
-                    .borrow_mut()
-                    .insert(branch.to_string(), name.to_string());
+                self.push_branch(branch, false);
             }
-            self
         }
 
-        /// Add a branch to the mock
+        /// Add a branch to the mock (once, however often it is added)
         pub fn with_branch(self, name: &str, is_remote: bool) -> Self {
-            let info = BranchInfo {
-                name: name.to_string(),
-                is_remote,
-            };
-            self.branches.borrow_mut().push(info);
+            self.push_branch(name, is_remote);
             self
         }
 
+        /// Records a branch unless it is already known
+        fn push_branch(&self, name: &str, is_remote: bool) {
+            let mut branches = self.branches.borrow_mut();
+            if !branches
+                .iter()
+                .any(|b| b.name == name && b.is_remote == is_remote)
+            {
+                branches.push(BranchInfo {
+                    name: name.to_string(),
+                    is_remote,
+                });
+            }
+        }
+
         /// Add a tag to the mock
         pub fn with_tag(self, name: &str, message: Option<&str>) -> Self {
             let info = TagInfo {
@@ -245,9 +670,25 @@ pub mod mock {
         /// Set the repository root
         pub fn with_repository_root(mut self, path: &str) -> Self {
             self.repository_root = PathBuf::from(path);
+            self.common_dir = self.repository_root.join(".git");
             self
         }
 
+        /// Names of the worktrees removed so far, in order
+        pub fn removed_worktrees(&self) -> Vec<String> {
+            self.removed_worktrees.borrow().clone()
+        }
+
+        /// Names of the branches deleted so far, in order
+        pub fn deleted_branches(&self) -> Vec<String> {
+            self.deleted_branches.borrow().clone()
+        }
+
+        /// Names of the worktrees created so far, in order
+        pub fn created_worktrees(&self) -> Vec<String> {
+            self.created_worktrees.borrow().clone()
+        }
+
         /// Mark a worktree as current
         pub fn with_current_worktree(self, name: &str) -> Self {
             let mut worktrees = self.worktrees.borrow_mut();
@@ -258,6 +699,86 @@ pub mod mock {
             self
         }
 
+        /// Mark a worktree as the main worktree
+        pub fn with_main_worktree(self, name: &str) -> Self {
+            let mut worktrees = self.worktrees.borrow_mut();
+            for worktree in worktrees.iter_mut() {
+                worktree.is_main = worktree.name == name;
+            }
+            drop(worktrees);
+            self
+        }
+
+        /// Lock a worktree, optionally with a reason
+        pub fn with_locked_worktree(self, name: &str, reason: Option<&str>) -> Self {
+            let mut worktrees = self.worktrees.borrow_mut();
+            for worktree in worktrees.iter_mut() {
+                if worktree.name == name {
+                    worktree.is_locked = true;
+                    worktree.lock_reason = reason.map(|r| r.to_string());
+                }
+            }
+            drop(worktrees);
+            self
+        }
+
+        /// Name under which the main worktree appears in the branch map
+        fn main_worktree_name(&self) -> String {
+            if self.is_bare {
+                return GIT_DEFAULT_MAIN_WORKTREE.to_string();
+            }
+            self.repository_root
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or(GIT_DEFAULT_MAIN_WORKTREE)
+                .to_string()
+        }
+
+        /// Removes the worktree `name`, refusing locked (and, unless
+        /// `force`, modified) worktrees
+        fn remove(&self, name: &str, force: bool) -> Result<()> {
+            let mut worktrees = self.worktrees.borrow_mut();
+            let index = worktrees
+                .iter()
+                .position(|w| w.git_name == name)
+                .ok_or_else(|| anyhow!(GIT_WORKTREE_NOT_FOUND.replace("{}", name)))?;
+            if worktrees[index].is_locked {
+                return Err(anyhow!(ERROR_WORKTREE_LOCKED.replace("{}", name)));
+            }
+            if !force && worktrees[index].has_changes {
+                return Err(anyhow!(ERROR_WORKTREE_DIRTY.replace("{}", name)));
+            }
+            worktrees.remove(index);
+            self.removed_worktrees.borrow_mut().push(name.to_string());
+            Ok(())
+        }
+
+        /// Adds a worktree at the resolved path of `name` on `branch`,
+        /// refusing taken names and branches checked out elsewhere
+        fn add(&self, name: &str, branch: &str) -> Result<PathBuf> {
+            let path = self.resolve_worktree_path(name)?;
+            let worktree_name = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or(name)
+                .to_string();
+            let taken = self.worktrees.borrow().iter().any(|w| w.path == path);
+            if taken {
+                return Err(anyhow!(
+                    ERROR_WORKTREE_PATH_EXISTS.replace("{}", &path.display().to_string())
+                ));
+            }
+            if let Some(worktree) = self.get_branch_worktree_map()?.get(branch) {
+                return Err(anyhow!(MSG_BRANCH_ALREADY_CHECKED_OUT
+                    .replacen("{}", branch, 1)
+                    .replacen("{}", worktree, 1)));
+            }
+
+            self.push_worktree(&worktree_name, path.clone(), Some(branch));
+            self.created_worktrees.borrow_mut().push(worktree_name);
+            Ok(path)
+        }
+
         /// Mark a worktree as having changes
         pub fn with_worktree_changes(self, name: &str) -> Self {
             let mut worktrees = self.worktrees.borrow_mut();
@@ -273,17 +794,26 @@ pub mod mock {
 
     impl GitReadOperations for MockGitOperations {
         fn list_worktrees(&self) -> Result<Vec<WorktreeInfo>> {
-            Ok(self.worktrees.borrow().clone())
+            // Main worktree first, like the real listing
+            let mut worktrees = self.worktrees.borrow().clone();
+            worktrees.sort_by(|a, b| b.is_main.cmp(&a.is_main).then_with(|| a.name.cmp(&b.name)));
+            Ok(worktrees)
         }
 
         fn list_branches(&self) -> Result<Vec<BranchInfo>> {
-            Ok(self.branches.borrow().clone())
+            let mut branches = self.branches.borrow().clone();
+            branches.sort_by(|a, b| a.is_remote.cmp(&b.is_remote).then(a.name.cmp(&b.name)));
+            Ok(branches)
         }
 
         fn list_tags(&self) -> Result<Vec<TagInfo>> {
             Ok(self.tags.borrow().clone())
         }
 
+        fn has_commits(&self) -> bool {
+            true
+        }
+
         fn get_current_branch(&self) -> Result<String> {
             Ok(self.current_branch.borrow().clone())
         }
@@ -308,12 +838,152 @@ pub mod mock {
             Ok(self.repository_root.clone())
         }
 
+        fn get_common_dir(&self) -> &Path {
+            &self.common_dir
+        }
+
+        fn current_worktree_ref(&self) -> Option<WorktreeRef> {
+            self.worktrees
+                .borrow()
+                .iter()
+                .find(|w| w.is_current)
+                .map(|w| WorktreeRef {
+                    name: w.name.clone(),
+                    path: w.path.clone(),
+                })
+        }
+
+        fn config_file_path(&self) -> Result<PathBuf> {
+            Ok(self.repository_root.join(CONFIG_FILE_NAME))
+        }
+
+        fn resolve_worktree_path(&self, name: &str) -> Result<PathBuf> {
+            if name.contains('/') {
+                return Ok(self.repository_root.join(name));
+            }
+            let base = self
+                .repository_root
+                .parent()
+                .unwrap_or(&self.repository_root);
+            Ok(base.join(name))
+        }
+
+        fn file_copy_source(&self, _config: &FilesConfig) -> Result<PathBuf> {
+            Ok(self.repository_root.clone())
+        }
+
         fn worktree_exists(&self, name: &str) -> Result<bool> {
             Ok(self.worktrees.borrow().iter().any(|w| w.name == name))
         }
 
         fn get_branch_worktree_map(&self) -> Result<HashMap<String, String>> {
-            Ok(self.branch_worktree_map.borrow().clone())
+            let mut map = HashMap::new();
+            map.insert(
+                self.current_branch.borrow().clone(),
+                self.main_worktree_name(),
+            );
+            for worktree in self.worktrees.borrow().iter() {
+                map.insert(worktree.branch.clone(), worktree.git_name.clone());
+            }
+            Ok(map)
+        }
+
+        fn is_branch_unique_to_worktree(
+            &self,
+            branch_name: &str,
+            worktree_name: &str,
+        ) -> Result<bool> {
+            let worktrees = self.worktrees.borrow();
+            let users: Vec<&WorktreeInfo> = worktrees
+                .iter()
+                .filter(|w| w.branch == branch_name)
+                .collect();
+            Ok(users.len() == 1 && users[0].name == worktree_name)
+        }
+    }
+
+    impl GitWriteOperations for MockGitOperations {
+        fn create_worktree(&self, name: &str, branch: Option<&str>) -> Result<PathBuf> {
+            match branch {
+                Some(branch) => self.add(name, branch),
+                None => {
+                    let path = self.resolve_worktree_path(name)?;
+                    let branch = path
+                        .file_name()
+                        .and_then(|n| n.to_str())
+                        .unwrap_or(name)
+                        .to_string();
+                    self.add(name, &branch)
+                }
+            }
+        }
+
+        fn create_worktree_with_new_branch(
+            &self,
+            name: &str,
+            new_branch: &str,
+            base_branch: &str,
+        ) -> Result<PathBuf> {
+            let known = self.branches.borrow().iter().any(|b| b.name == base_branch)
+                || self.tag_exists(base_branch)
+                || *self.current_branch.borrow() == base_branch;
+            if !known {
+                return Err(anyhow!(GIT_BRANCH_NOT_FOUND_MSG.replace("{}", base_branch)));
+            }
+            self.add(name, new_branch)
+        }
+
+        fn fetch_remote(
+            &self,
+            _remote: &str,
+            _refspec: Option<&str>,
+            _depth: Option<u32>,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        fn copy_configured_files(
+            &self,
+            _config: &FilesConfig,
+            _destination: &Path,
+        ) -> Result<FileCopyReport> {
+            Ok(FileCopyReport::default())
+        }
+
+        fn remove_worktree(&self, name: &str) -> Result<()> {
+            self.remove(name, false)
+        }
+
+        fn remove_worktree_force(&self, name: &str) -> Result<()> {
+            self.remove(name, true)
+        }
+
+        fn delete_branch(&self, branch_name: &str) -> Result<()> {
+            let checked_out_in = if !self.is_bare && *self.current_branch.borrow() == branch_name {
+                Some(self.main_worktree_name())
+            } else {
+                self.worktrees
+                    .borrow()
+                    .iter()
+                    .find(|w| w.branch == branch_name)
+                    .map(|w| w.name.clone())
+            };
+            if let Some(worktree) = checked_out_in {
+                return Err(anyhow!(MSG_BRANCH_ALREADY_CHECKED_OUT
+                    .replacen("{}", branch_name, 1)
+                    .replacen("{}", &worktree, 1)));
+            }
+
+            let mut branches = self.branches.borrow_mut();
+            let index = branches
+                .iter()
+                .position(|b| !b.is_remote && b.name == branch_name)
+                .ok_or_else(|| anyhow!(GIT_BRANCH_NOT_FOUND_MSG.replace("{}", branch_name)))?;
+            branches.remove(index);
+            self.deleted_branches
+                .borrow_mut()
+                .push(branch_name.to_string());
+            Ok(())
         }
     }
 }
@@ -338,8 +1008,10 @@ mod tests {
         // Test worktrees
         let worktrees = mock.list_worktrees().unwrap();
         assert_eq!(worktrees.len(), 2);
-        assert_eq!(worktrees[0].name, "main");
-        assert!(worktrees[0].is_current);
+        // Listed by name, like GitWorktreeManager
+        assert_eq!(worktrees[0].name, "feature");
+        assert_eq!(worktrees[1].name, "main");
+        assert!(worktrees[1].is_current);
 
         // Test branches
         let branches = mock.list_branches().unwrap();
@@ -392,8 +1064,33 @@ mod tests {
             .with_worktree_changes("feature");
 
         let worktrees = mock.list_worktrees().unwrap();
-        assert!(!worktrees[0].has_changes); // main
-        assert!(worktrees[1].has_changes); // feature
+        assert!(worktrees[0].has_changes); // feature
+        assert!(!worktrees[1].has_changes); // main
+    }
+
+    #[test]
+    fn test_mock_write_operations() {
+        let mock = MockGitOperations::new()
+            .with_worktree("main", "/repo/main", Some("main"))
+            .with_worktree("feature", "/repo/feature", Some("feature/new"))
+            .with_branch("feature/new", false)
+            .with_worktree_changes("feature");
+
+        assert!(mock
+            .is_branch_unique_to_worktree("feature/new", "feature")
+            .unwrap());
+        assert!(!mock
+            .is_branch_unique_to_worktree("feature/new", "main")
+            .unwrap());
+
+        assert!(mock.remove_worktree("feature").is_err());
+        mock.remove_worktree_force("feature").unwrap();
+        mock.delete_branch("feature/new").unwrap();
+        assert!(mock.delete_branch("feature/new").is_err());
+
+        assert!(!mock.worktree_exists("feature").unwrap());
+        assert_eq!(mock.removed_worktrees(), vec!["feature"]);
+        assert_eq!(mock.deleted_branches(), vec!["feature/new"]);
     }
 
     #[test]
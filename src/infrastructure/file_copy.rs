@@ -6,24 +6,133 @@
 
 use anyhow::{anyhow, Context, Result};
 use colored::Colorize;
+use std::fmt;
 use std::fs;
 use std::path::{Path, PathBuf};
 
 use super::super::config::FilesConfig;
 use super::super::constants::{
-    BYTES_PER_MB, COLON_POSITION_WINDOWS, ERROR_FAILED_TO_CREATE_DIR,
+    BYTES_PER_MB, COLON_POSITION_WINDOWS, ENV_HOME, ERROR_FAILED_TO_CREATE_DIR,
     ERROR_FAILED_TO_CREATE_PARENT_DIR, ERROR_GIT_DIR_NO_PARENT, ERROR_NOT_FOUND,
     ERROR_NO_SUCH_FILE, ERROR_REPO_NO_WORKING_DIR, ERROR_SOURCE_NOT_FILE_OR_DIR,
-    ERROR_SOURCE_PATH_NOT_FOUND, FILE_COPY_COPIED_SUCCESS, FILE_COPY_COPYING_FILES,
-    FILE_COPY_FAILED, FILE_COPY_NOT_FOUND, FILE_COPY_NO_FILES, FILE_COPY_SAME_DIRECTORY,
-    FILE_COPY_SKIPPING_LARGE, FILE_COPY_SKIPPING_UNSAFE, GIT_DIR, ICON_ERROR, ICON_FILE, ICON_INFO,
-    ICON_SUCCESS, ICON_SYMLINK_WARNING, ICON_WARNING, INFO_FAILED_TO_COPY,
+    ERROR_SOURCE_PATH_NOT_FOUND, ERROR_SOURCE_REF_NOT_FOUND, ERROR_SOURCE_VAR_UNCLOSED,
+    ERROR_SOURCE_VAR_UNSET, FILE_COPY_COPIED_SUCCESS, FILE_COPY_COPYING_FILES, FILE_COPY_EXCLUDED,
+    FILE_COPY_FAILED, FILE_COPY_FROM_REF, FILE_COPY_INVALID_PATTERN, FILE_COPY_NOT_FOUND,
+    FILE_COPY_NO_FILES, FILE_COPY_NO_MATCHES, FILE_COPY_SAME_DIRECTORY, FILE_COPY_SIZE_LIMIT_LABEL,
+    FILE_COPY_SKIPPING_LARGE, FILE_COPY_SKIPPING_UNSAFE, GIT_DIR, GLOB_METACHARACTERS, ICON_ERROR,
+    ICON_FILE, ICON_INFO, ICON_SUCCESS, ICON_SYMLINK_WARNING, ICON_WARNING, INFO_FAILED_TO_COPY,
     INFO_SKIPPING_CIRCULAR_REF, INFO_SKIPPING_SYMLINK, MAIN_WORKTREE_NAMES, MAX_DIRECTORY_DEPTH,
-    MAX_FILE_SIZE_MB, PLURAL_EMPTY, PLURAL_S, SIZE_UNIT_MB, WINDOWS_PATH_MIN_LENGTH,
-    WORKTREES_SUBDIR,
+    PLURAL_EMPTY, PLURAL_S, SIZE_UNIT_MB, SKIP_REASON_NOT_FOUND, SKIP_REASON_TOO_LARGE,
+    SKIP_REASON_UNSAFE_PATH, TILDE, TILDE_PREFIX, WINDOWS_PATH_MIN_LENGTH, WORKTREES_SUBDIR,
 };
 use super::filesystem::FileSystem;
 use super::git::{GitWorktreeManager, WorktreeInfo};
+use crate::utils::output;
+
+/// Why a configured entry was not copied
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipReason {
+    /// Larger than the size limit
+    TooLarge,
+    /// Missing from the source, or a glob pattern that matched nothing
+    NotFound,
+    /// The path would escape the repository
+    UnsafePath,
+}
+
+impl fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            SkipReason::TooLarge => SKIP_REASON_TOO_LARGE,
+            SkipReason::NotFound => SKIP_REASON_NOT_FOUND,
+            SkipReason::UnsafePath => SKIP_REASON_UNSAFE_PATH,
+        };
+        write!(f, "{reason}")
+    }
+}
+
+/// Outcome of copying the configured files
+///
+/// Paths are relative to the destination worktree. `skipped` and `failed`
+/// hold configured entries (or their glob matches); files skipped inside a
+/// copied directory are only reported on the console.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileCopyReport {
+    /// Every file copied (a directory entry contributes one item per file)
+    pub copied: Vec<String>,
+    /// Entries left out, with the reason
+    pub skipped: Vec<(String, SkipReason)>,
+    /// Entries that could not be copied, with the error
+    pub failed: Vec<(String, String)>,
+    /// Paths inside copied directories left out by `[files] copy_exclude`
+    pub excluded: usize,
+}
+
+/// Pure business logic for matching a path against `[files] copy_exclude`
+///
+/// `relative` is relative to the directory being copied. Patterns follow
+/// `.gitignore`: one without `/` matches the name of a file or directory at
+/// any depth (`node_modules`, `*.log`), one with `/` matches the whole
+/// relative path (`cache/tmp`, `**/generated`), and a trailing `/` restricts
+/// it to directories. Negation is not supported, and a pattern that is not a
+/// valid glob matches exactly.
+pub fn is_copy_excluded(relative: &Path, is_dir: bool, patterns: &[String]) -> bool {
+    let path = relative.to_string_lossy().replace('\\', "/");
+    let name = relative
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let options = glob::MatchOptions {
+        require_literal_separator: true,
+        ..glob::MatchOptions::new()
+    };
+
+    patterns.iter().any(|pattern| {
+        let (pattern, dir_only) = match pattern.strip_suffix('/') {
+            Some(pattern) => (pattern, true),
+            None => (pattern.as_str(), false),
+        };
+        if dir_only && !is_dir {
+            return false;
+        }
+        let subject = if pattern.contains('/') { &path } else { &name };
+        let pattern = pattern.trim_start_matches('/');
+        match glob::Pattern::new(pattern) {
+            Ok(glob) => glob.matches_with(subject, options),
+            Err(_) => pattern == subject,
+        }
+    })
+}
+
+/// `[files] copy_exclude` applied while copying one configured entry
+struct CopyExclusions<'a> {
+    /// Patterns from the configuration
+    patterns: &'a [String],
+    /// The copied entry; paths are matched relative to it
+    root: PathBuf,
+    /// Paths left out so far
+    excluded: usize,
+}
+
+impl<'a> CopyExclusions<'a> {
+    fn new(patterns: &'a [String], root: &Path) -> Self {
+        Self {
+            patterns,
+            root: root.to_path_buf(),
+            excluded: 0,
+        }
+    }
+
+    /// Whether `path` is left out, counting it when it is
+    fn excludes(&mut self, path: &Path, is_dir: bool) -> bool {
+        let relative = path.strip_prefix(&self.root).unwrap_or(path);
+        let excluded = is_copy_excluded(relative, is_dir, self.patterns);
+        if excluded {
+            self.excluded += 1;
+        }
+        excluded
+    }
+}
 
 /// Copies configured files from source to destination worktree
 ///
@@ -38,26 +147,47 @@ use super::git::{GitWorktreeManager, WorktreeInfo};
 ///
 /// # Returns
 ///
-/// * `Ok(Vec<String>)` - List of successfully copied files
+/// * `Ok(FileCopyReport)` - The files copied, skipped and failed, relative
+///   to `destination_path`
 /// * `Err(...)` - Error if critical failure occurs
 ///
+/// # Glob Patterns
+///
+/// Entries containing `*`, `?` or `[` are expanded against the source
+/// directory (e.g. `*.env`, `config/*.json`) and each match is copied, unless
+/// a file with that exact name exists. A pattern that matches nothing only
+/// prints a warning.
+///
+/// # Exclusions
+///
+/// Inside copied directories, files and directories matching
+/// `[files] copy_exclude` (see [`is_copy_excluded`]) are left out and
+/// counted in the report. Entries listed in `copy` are always copied.
+///
 /// # File Size Limits
 ///
-/// - Individual files larger than MAX_FILE_SIZE_MB are automatically skipped with a warning
+/// - Individual files larger than the configured limit are skipped with a warning,
+///   including files inside directories that are copied recursively
+/// - The limit is `[files] max_size_mb` (default MAX_FILE_SIZE_MB), overridable per entry
 /// - This prevents accidentally copying large binary files or build artifacts
 ///
+/// # Copying from a Git Ref
+///
+/// With `[files] source_ref`, files are read from the tree of that ref
+/// instead of the source directory (see [`copy_configured_files_from_ref`]).
+///
 /// # Security
 ///
 /// This function validates all paths to ensure they don't escape the
 /// repository boundaries using directory traversal techniques. Additionally:
-/// - Symlinks are detected and skipped to prevent security issues
+/// - Symlinks, including those inside copied directories, are skipped with a warning
 /// - Circular references are detected and prevented
 /// - Maximum directory depth is enforced to prevent infinite recursion
 pub fn copy_configured_files(
     config: &FilesConfig,
     destination_path: &Path,
     manager: &GitWorktreeManager,
-) -> Result<Vec<String>> {
+) -> Result<FileCopyReport> {
     copy_configured_files_with_fs(
         config,
         destination_path,
@@ -72,9 +202,13 @@ pub fn copy_configured_files_with_fs(
     destination_path: &Path,
     manager: &GitWorktreeManager,
     fs: &dyn FileSystem,
-) -> Result<Vec<String>> {
+) -> Result<FileCopyReport> {
     if config.copy.is_empty() {
-        return Ok(Vec::new());
+        return Ok(FileCopyReport::default());
+    }
+
+    if let Some(reference) = &config.source_ref {
+        return copy_configured_files_from_ref(config, reference, destination_path, manager);
     }
 
     // Determine source directory
@@ -93,82 +227,512 @@ pub fn copy_configured_files_with_fs(
         return Err(anyhow!(FILE_COPY_SAME_DIRECTORY));
     }
 
-    let mut copied_files = Vec::new();
+    let mut report = FileCopyReport::default();
 
     let msg = format!("{ICON_FILE} {FILE_COPY_COPYING_FILES}").bright_cyan();
-    println!("\n{msg}");
+    output::blank_line();
+    output::decoration(msg);
 
-    for file_pattern in &config.copy {
-        if !is_safe_path(file_pattern) {
-            let warning = ICON_WARNING.yellow();
-            let pattern = file_pattern.yellow();
-            println!("  {warning} {FILE_COPY_SKIPPING_UNSAFE}: {pattern}");
+    for entry in &config.copy {
+        let pattern = entry.path();
+        if !is_safe_path(pattern) {
+            skip_unsafe(pattern, &mut report);
             continue;
         }
 
-        let source_path = source_dir.join(file_pattern);
-
-        // Check file size before copying
-        if fs.exists(&source_path) {
-            if let Ok(size) = calculate_path_size_with_fs(&source_path, fs) {
-                if size > MAX_FILE_SIZE && fs.is_file(&source_path) {
-                    let warning = ICON_WARNING.yellow();
-                    let pattern = file_pattern.yellow();
-                    let size_mb = size as f64 / BYTES_PER_MB as f64;
-                    println!("  {warning} {FILE_COPY_SKIPPING_LARGE}: {pattern} ({size_mb:.1} {SIZE_UNIT_MB})");
+        // A file literally named like a pattern (e.g. `data[1].csv`) is copied as-is
+        let paths = if is_glob_pattern(pattern) && !fs.exists(&source_dir.join(pattern)) {
+            match expand_glob(&source_dir, pattern) {
+                Ok(paths) if paths.is_empty() => {
+                    skip_no_matches(pattern, &mut report);
+                    continue;
+                }
+                Ok(paths) => paths,
+                Err(e) => {
+                    fail_invalid_pattern(pattern, &e, &mut report);
                     continue;
                 }
             }
+        } else {
+            vec![pattern.to_string()]
+        };
+        let max_size = config.max_size_bytes(entry);
+
+        for file_pattern in &paths {
+            copy_entry_path_with_fs(
+                &source_dir,
+                destination_path,
+                file_pattern,
+                max_size,
+                &config.copy_exclude,
+                fs,
+                &mut report,
+            );
         }
-        let dest_path = destination_path.join(file_pattern);
+    }
+
+    if report.copied.is_empty() {
+        let info = ICON_INFO.blue();
+        println!("  {info} {FILE_COPY_NO_FILES}");
+    }
+
+    Ok(report)
+}
+
+/// Reports an entry that would escape the repository
+fn skip_unsafe(pattern: &str, report: &mut FileCopyReport) {
+    let warning = ICON_WARNING.yellow();
+    let pattern_yellow = pattern.yellow();
+    println!("  {warning} {FILE_COPY_SKIPPING_UNSAFE}: {pattern_yellow}");
+    report
+        .skipped
+        .push((pattern.to_string(), SkipReason::UnsafePath));
+}
+
+/// Reports a glob pattern that matched nothing
+fn skip_no_matches(pattern: &str, report: &mut FileCopyReport) {
+    let warning = ICON_WARNING.yellow();
+    let pattern_yellow = pattern.yellow();
+    println!("  {warning} {FILE_COPY_NO_MATCHES}: {pattern_yellow}");
+    report
+        .skipped
+        .push((pattern.to_string(), SkipReason::NotFound));
+}
 
-        match copy_file_or_directory_with_fs(&source_path, &dest_path, fs) {
-            Ok(count) => {
-                if count > 0 {
-                    let checkmark = ICON_SUCCESS.green();
-                    let pattern = file_pattern.green();
-                    let plural = if count == 1 { PLURAL_EMPTY } else { PLURAL_S };
-                    println!("  {checkmark} {FILE_COPY_COPIED_SUCCESS}: {pattern} ({count} file{plural})");
-                    copied_files.push(file_pattern.clone());
+/// Reports an entry missing from the source
+fn skip_not_found(file_pattern: &str, report: &mut FileCopyReport) {
+    let warning = ICON_WARNING.yellow();
+    let pattern = file_pattern.yellow();
+    println!("  {warning} {FILE_COPY_NOT_FOUND}: {pattern} (skipping)");
+    report
+        .skipped
+        .push((file_pattern.to_string(), SkipReason::NotFound));
+}
+
+/// Reports a glob pattern that could not be parsed
+fn fail_invalid_pattern(pattern: &str, error: &dyn fmt::Display, report: &mut FileCopyReport) {
+    let cross = ICON_ERROR.red();
+    let pattern_red = pattern.red();
+    println!("  {cross} {FILE_COPY_INVALID_PATTERN} {pattern_red}: {error}");
+    report.failed.push((pattern.to_string(), error.to_string()));
+}
+
+/// Reports an entry whose copy failed
+fn fail_copy(file_pattern: &str, error: &dyn fmt::Display, report: &mut FileCopyReport) {
+    let cross = ICON_ERROR.red();
+    let pattern = file_pattern.red();
+    println!("  {cross} {FILE_COPY_FAILED} {pattern}: {error}");
+    report
+        .failed
+        .push((file_pattern.to_string(), error.to_string()));
+}
+
+/// Copies one path relative to the source directory, reporting the outcome
+/// in `report`
+fn copy_entry_path_with_fs(
+    source_dir: &Path,
+    destination_path: &Path,
+    file_pattern: &str,
+    max_size: u64,
+    exclude: &[String],
+    fs: &dyn FileSystem,
+    report: &mut FileCopyReport,
+) {
+    let source_path = source_dir.join(file_pattern);
+
+    // Check file size before copying (files inside directories are checked one by one)
+    if fs.is_file(&source_path) {
+        if let Ok(size) = calculate_path_size_with_fs(&source_path, fs) {
+            if size > max_size {
+                print_skipping_large(file_pattern, size, max_size);
+                report
+                    .skipped
+                    .push((file_pattern.to_string(), SkipReason::TooLarge));
+                return;
+            }
+        }
+    }
+    let dest_path = destination_path.join(file_pattern);
+    let mut exclusions = CopyExclusions::new(exclude, &source_path);
+
+    match copy_file_or_directory_with_fs(&source_path, &dest_path, max_size, &mut exclusions, fs) {
+        Ok(files) => report_copied(
+            file_pattern,
+            &files,
+            exclusions.excluded,
+            destination_path,
+            report,
+        ),
+        Err(e) => {
+            // Check if it's a "not found" error
+            if e.to_string().contains(ERROR_NO_SUCH_FILE) || e.to_string().contains(ERROR_NOT_FOUND)
+            {
+                skip_not_found(file_pattern, report);
+            } else {
+                fail_copy(file_pattern, &e, report);
+            }
+        }
+    }
+}
+
+/// Prints the files copied for one entry and records them in `report`
+fn report_copied(
+    file_pattern: &str,
+    files: &[PathBuf],
+    excluded: usize,
+    destination_path: &Path,
+    report: &mut FileCopyReport,
+) {
+    report.excluded += excluded;
+    let count = files.len();
+    if count > 0 {
+        let checkmark = ICON_SUCCESS.green();
+        let pattern = file_pattern.green();
+        let plural = if count == 1 { PLURAL_EMPTY } else { PLURAL_S };
+        let excluded = if excluded > 0 {
+            format!(", {excluded} {FILE_COPY_EXCLUDED}")
+        } else {
+            String::new()
+        };
+        output::decoration(format!(
+            "  {checkmark} {FILE_COPY_COPIED_SUCCESS}: {pattern} ({count} file{plural}{excluded})"
+        ));
+        report.copied.extend(files.iter().map(|file| {
+            file.strip_prefix(destination_path)
+                .unwrap_or(file)
+                .to_string_lossy()
+                .to_string()
+        }));
+    }
+}
+
+/// Copies the configured files as committed on a Git ref (`[files] source_ref`)
+///
+/// Each entry is looked up in the tree of `reference` and its blobs are
+/// written to `destination_path`, so local modifications in the main
+/// worktree are not propagated. Glob patterns are matched against the tree.
+/// Symlinks and submodules in the tree are skipped; size limits apply as
+/// for filesystem copies.
+///
+/// # Errors
+///
+/// Returns an error if `reference` does not resolve to a tree.
+pub fn copy_configured_files_from_ref(
+    config: &FilesConfig,
+    reference: &str,
+    destination_path: &Path,
+    manager: &GitWorktreeManager,
+) -> Result<FileCopyReport> {
+    let repo = manager.repo();
+    let tree = repo
+        .revparse_single(reference)
+        .and_then(|object| object.peel_to_tree())
+        .map_err(|e| {
+            anyhow!(ERROR_SOURCE_REF_NOT_FOUND
+                .replacen("{}", reference, 1)
+                .replacen("{}", e.message(), 1))
+        })?;
+
+    let mut report = FileCopyReport::default();
+
+    let msg = format!("{ICON_FILE} {FILE_COPY_COPYING_FILES} ({FILE_COPY_FROM_REF} {reference})")
+        .bright_cyan();
+    output::blank_line();
+    output::decoration(msg);
+
+    for entry in &config.copy {
+        let pattern = entry.path();
+        if !is_safe_path(pattern) {
+            skip_unsafe(pattern, &mut report);
+            continue;
+        }
+
+        let paths = if is_glob_pattern(pattern) && tree.get_path(Path::new(pattern)).is_err() {
+            match expand_glob_in_tree(&tree, pattern) {
+                Ok(paths) if paths.is_empty() => {
+                    skip_no_matches(pattern, &mut report);
+                    continue;
+                }
+                Ok(paths) => paths,
+                Err(e) => {
+                    fail_invalid_pattern(pattern, &e, &mut report);
+                    continue;
                 }
             }
-            Err(e) => {
-                // Check if it's a "not found" error
-                if e.to_string().contains(ERROR_NO_SUCH_FILE)
-                    || e.to_string().contains(ERROR_NOT_FOUND)
-                {
-                    let warning = ICON_WARNING.yellow();
-                    let pattern = file_pattern.yellow();
-                    println!("  {warning} {FILE_COPY_NOT_FOUND}: {pattern} (skipping)");
-                } else {
-                    let cross = ICON_ERROR.red();
-                    let pattern = file_pattern.red();
-                    println!("  {cross} {FILE_COPY_FAILED} {pattern}: {e}");
+        } else {
+            vec![pattern.to_string()]
+        };
+        let max_size = config.max_size_bytes(entry);
+
+        for file_pattern in &paths {
+            let tree_entry = match tree.get_path(Path::new(file_pattern)) {
+                Ok(tree_entry) => tree_entry,
+                Err(_) => {
+                    skip_not_found(file_pattern, &mut report);
+                    continue;
+                }
+            };
+            if let Ok(blob) = tree_entry.to_object(repo).and_then(|o| o.peel_to_blob()) {
+                let size = blob.size() as u64;
+                if size > max_size {
+                    print_skipping_large(file_pattern, size, max_size);
+                    report
+                        .skipped
+                        .push((file_pattern.to_string(), SkipReason::TooLarge));
+                    continue;
                 }
             }
+            let dest_path = destination_path.join(file_pattern);
+            let mut exclusions = CopyExclusions::new(&config.copy_exclude, Path::new(file_pattern));
+            match write_tree_entry(
+                repo,
+                &tree_entry,
+                &dest_path,
+                file_pattern,
+                max_size,
+                &mut exclusions,
+            ) {
+                Ok(files) => report_copied(
+                    file_pattern,
+                    &files,
+                    exclusions.excluded,
+                    destination_path,
+                    &mut report,
+                ),
+                Err(e) => fail_copy(file_pattern, &e, &mut report),
+            }
         }
     }
 
-    if copied_files.is_empty() {
+    if report.copied.is_empty() {
         let info = ICON_INFO.blue();
         println!("  {info} {FILE_COPY_NO_FILES}");
     }
 
-    Ok(copied_files)
+    Ok(report)
+}
+
+/// Writes a blob, or every blob under a tree, from a Git tree entry to `dest`
+///
+/// `relative` is the entry's path in the tree, used for messages and for
+/// matching `exclusions`.
+fn write_tree_entry(
+    repo: &git2::Repository,
+    entry: &git2::TreeEntry,
+    dest: &Path,
+    relative: &str,
+    max_size: u64,
+    exclusions: &mut CopyExclusions,
+) -> Result<Vec<PathBuf>> {
+    match entry.kind() {
+        Some(git2::ObjectType::Blob) if entry.filemode() == i32::from(git2::FileMode::Link) => {
+            let warning = ICON_SYMLINK_WARNING.yellow();
+            println!("  {warning} {INFO_SKIPPING_SYMLINK}{relative}");
+            Ok(Vec::new())
+        }
+        Some(git2::ObjectType::Blob) => {
+            let blob = entry.to_object(repo)?.peel_to_blob()?;
+            let size = blob.size() as u64;
+            if size > max_size {
+                print_skipping_large(relative, size, max_size);
+                return Ok(Vec::new());
+            }
+            if let Some(parent) = dest.parent() {
+                fs::create_dir_all(parent).with_context(|| {
+                    format!(
+                        "Failed to create parent directory: {parent_display}",
+                        parent_display = parent.display()
+                    )
+                })?;
+            }
+            fs::write(dest, blob.content())
+                .with_context(|| format!("Failed to write {}", dest.display()))?;
+            Ok(vec![dest.to_path_buf()])
+        }
+        Some(git2::ObjectType::Tree) => {
+            let subtree = entry.to_object(repo)?.peel_to_tree()?;
+            let mut files = Vec::new();
+            for child in subtree.iter() {
+                let name = child.name().unwrap_or_default();
+                let child_relative = format!("{relative}/{name}");
+                let is_dir = child.kind() == Some(git2::ObjectType::Tree);
+                if exclusions.excludes(Path::new(&child_relative), is_dir) {
+                    continue;
+                }
+                files.extend(write_tree_entry(
+                    repo,
+                    &child,
+                    &dest.join(name),
+                    &child_relative,
+                    max_size,
+                    exclusions,
+                )?);
+            }
+            Ok(files)
+        }
+        // Submodules (commit entries) have no content to copy
+        _ => Ok(Vec::new()),
+    }
+}
+
+/// Expands a glob pattern against the paths in a Git tree
+///
+/// Like [`expand_glob`], `*` does not cross `/`. A matching directory is
+/// returned as a whole instead of its contents.
+fn expand_glob_in_tree(tree: &git2::Tree, pattern: &str) -> Result<Vec<String>> {
+    let matcher = glob::Pattern::new(pattern)?;
+    let options = glob::MatchOptions {
+        require_literal_separator: true,
+        ..glob::MatchOptions::new()
+    };
+
+    let mut matches = Vec::new();
+    tree.walk(git2::TreeWalkMode::PreOrder, |root, entry| {
+        let path = format!("{root}{}", entry.name().unwrap_or_default());
+        if matcher.matches_with(&path, options) {
+            if is_safe_path(&path) {
+                matches.push(path);
+            }
+            git2::TreeWalkResult::Skip
+        } else {
+            git2::TreeWalkResult::Ok
+        }
+    })?;
+    matches.sort();
+    Ok(matches)
+}
+
+/// Pure business logic for telling glob patterns from plain paths
+fn is_glob_pattern(path: &str) -> bool {
+    path.contains(GLOB_METACHARACTERS)
+}
+
+/// Expands a glob pattern against the source directory
+///
+/// Returns the matching paths relative to `source_dir`, sorted. Matches
+/// that fail the [`is_safe_path`] check are dropped.
+fn expand_glob(source_dir: &Path, pattern: &str) -> Result<Vec<String>> {
+    let base = glob::Pattern::escape(&source_dir.to_string_lossy());
+    let full_pattern = format!("{base}/{pattern}");
+
+    let mut matches = Vec::new();
+    for path in glob::glob(&full_pattern)?.flatten() {
+        if let Ok(relative) = path.strip_prefix(source_dir) {
+            let relative = relative.to_string_lossy().to_string();
+            if is_safe_path(&relative) {
+                matches.push(relative);
+            }
+        }
+    }
+    matches.sort();
+    Ok(matches)
+}
+
+/// Prints the warning for a file that exceeds the size limit
+fn print_skipping_large(path: &str, size: u64, max_size: u64) {
+    let warning = ICON_WARNING.yellow();
+    let path = path.yellow();
+    let size_mb = size as f64 / BYTES_PER_MB as f64;
+    let limit_mb = max_size / BYTES_PER_MB;
+    println!("  {warning} {FILE_COPY_SKIPPING_LARGE}: {path} ({size_mb:.1} {SIZE_UNIT_MB}, {FILE_COPY_SIZE_LIMIT_LABEL} {limit_mb} {SIZE_UNIT_MB})");
+}
+
+/// Expands `~` and environment variables in `[files] source`
+///
+/// A leading `~` becomes `$HOME`; `$VAR` and `${VAR}` are replaced with the
+/// value returned by `lookup`. A `$` not followed by a variable name is kept.
+///
+/// # Errors
+///
+/// Returns an error if a referenced variable (including `HOME` for `~`) is
+/// unset or a `${` is not closed.
+pub fn expand_source_path<F>(raw: &str, lookup: F) -> Result<String>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let lookup_var = |name: &str| {
+        lookup(name).ok_or_else(|| anyhow!(ERROR_SOURCE_VAR_UNSET.replace("{}", name)))
+    };
+
+    let mut expanded = String::new();
+    let mut rest = raw;
+    if rest == TILDE || rest.starts_with(TILDE_PREFIX) {
+        expanded.push_str(&lookup_var(ENV_HOME)?);
+        rest = &rest[TILDE.len()..];
+    }
+
+    while let Some(dollar) = rest.find('$') {
+        expanded.push_str(&rest[..dollar]);
+        let after = &rest[dollar + 1..];
+
+        if let Some(braced) = after.strip_prefix('{') {
+            let end = braced
+                .find('}')
+                .ok_or_else(|| anyhow!(ERROR_SOURCE_VAR_UNCLOSED.replace("{}", raw)))?;
+            expanded.push_str(&lookup_var(&braced[..end])?);
+            rest = &braced[end + 1..];
+            continue;
+        }
+
+        let name_len = after
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(after.len());
+        if name_len == 0 {
+            expanded.push('$');
+        } else {
+            expanded.push_str(&lookup_var(&after[..name_len])?);
+        }
+        rest = &after[name_len..];
+    }
+    expanded.push_str(rest);
+
+    Ok(expanded)
+}
+
+/// Pure business logic for the files a copy would pick up from `source`
+///
+/// Returns each source path with whether it exists, in configuration order.
+/// Glob patterns are expanded; a pattern without matches is listed as
+/// missing. Entries that fail the [`is_safe_path`] check are left out, as
+/// they are never copied.
+pub fn resolve_copy_plan(config: &FilesConfig, source: &Path) -> Vec<(PathBuf, bool)> {
+    let mut plan = Vec::new();
+    for entry in &config.copy {
+        let pattern = entry.path();
+        if !is_safe_path(pattern) {
+            continue;
+        }
+
+        let literal = source.join(pattern);
+        if !is_glob_pattern(pattern) || literal.exists() {
+            let exists = literal.exists();
+            plan.push((literal, exists));
+            continue;
+        }
+
+        match expand_glob(source, pattern) {
+            Ok(paths) if !paths.is_empty() => {
+                plan.extend(paths.into_iter().map(|path| (source.join(path), true)));
+            }
+            _ => plan.push((literal, false)),
+        }
+    }
+    plan
 }
 
 /// Determines the source directory for file copying
 ///
 /// Priority:
-/// 1. Explicitly configured source directory
+/// 1. Explicitly configured source directory (with `~` and `$VAR` expanded)
 /// 2. Main worktree directory (for bare repositories)
 /// 3. Current working directory (for non-bare repositories)
-fn determine_source_directory(
+pub fn determine_source_directory(
     config: &FilesConfig,
     manager: &GitWorktreeManager,
 ) -> Result<PathBuf> {
     if let Some(source) = &config.source {
-        let path = PathBuf::from(source);
+        let source = expand_source_path(source, |name| std::env::var(name).ok())?;
+        let path = PathBuf::from(&source);
         if path.is_absolute() {
             return Ok(path);
         }
@@ -259,9 +823,6 @@ fn find_source_in_regular_repo(manager: &GitWorktreeManager) -> Result<PathBuf>
     Ok(repo_workdir.to_path_buf())
 }
 
-/// Maximum file size for automatic copying in bytes
-const MAX_FILE_SIZE: u64 = MAX_FILE_SIZE_MB * BYTES_PER_MB;
-
 /// Calculates the total size of a file or directory
 ///
 /// For directories, this recursively calculates the size of all files within.
@@ -389,7 +950,7 @@ fn calculate_directory_size_with_fs(
 /// - Parent directory references (`..`)
 /// - Absolute paths
 /// - Other potentially dangerous patterns
-fn is_safe_path(path: &str) -> bool {
+pub fn is_safe_path(path: &str) -> bool {
     // Reject empty paths
     if path.is_empty() {
         return false;
@@ -470,14 +1031,19 @@ fn copy_file_or_directory(source: &Path, dest: &Path) -> Result<usize> {
 
 /// Copies a file or directory from source to destination using filesystem abstraction
 ///
+/// Files inside a directory that are larger than `max_size` bytes are
+/// skipped, and paths matching `exclusions` are left out.
+///
 /// # Returns
 ///
-/// Returns the number of files copied
+/// Returns the destination path of every file copied
 fn copy_file_or_directory_with_fs(
     source: &Path,
     dest: &Path,
+    max_size: u64,
+    exclusions: &mut CopyExclusions,
     fs: &dyn FileSystem,
-) -> Result<usize> {
+) -> Result<Vec<PathBuf>> {
     if !fs.exists(source) {
         let source_path = source.display();
         return Err(anyhow!("{ERROR_SOURCE_PATH_NOT_FOUND}{source_path}"));
@@ -488,7 +1054,7 @@ fn copy_file_or_directory_with_fs(
         let warning = ICON_SYMLINK_WARNING.yellow();
         let source_path = source.display();
         println!("  {warning} {INFO_SKIPPING_SYMLINK}{source_path}");
-        return Ok(0);
+        return Ok(Vec::new());
     }
 
     if fs.is_file(source) {
@@ -510,9 +1076,9 @@ fn copy_file_or_directory_with_fs(
             )
         })?;
 
-        Ok(1)
+        Ok(vec![dest.to_path_buf()])
     } else if fs.is_dir(source) {
-        copy_directory_recursive_with_fs(source, dest, 0, fs)
+        copy_directory_recursive_with_fs(source, dest, 0, max_size, exclusions, fs)
     } else {
         Err(anyhow!(
             "{ERROR_SOURCE_NOT_FILE_OR_DIR}{}",
@@ -595,8 +1161,10 @@ fn copy_directory_recursive_with_fs(
     source: &Path,
     dest: &Path,
     depth: usize,
+    max_size: u64,
+    exclusions: &mut CopyExclusions,
     fs: &dyn FileSystem,
-) -> Result<usize> {
+) -> Result<Vec<PathBuf>> {
     if depth >= MAX_DIRECTORY_DEPTH {
         return Err(anyhow!(
             "Maximum directory depth ({}) exceeded. Possible circular reference.",
@@ -611,7 +1179,7 @@ fn copy_directory_recursive_with_fs(
         )
     })?;
 
-    let mut total_files = 0;
+    let mut copied = Vec::new();
 
     // Use real filesystem for directory traversal due to DirEntry complexity
     for entry in std::fs::read_dir(source)? {
@@ -620,6 +1188,11 @@ fn copy_directory_recursive_with_fs(
         let source_path = entry.path();
         let dest_path = dest.join(&file_name);
 
+        let is_dir = entry.file_type().is_ok_and(|file_type| file_type.is_dir());
+        if exclusions.excludes(&source_path, is_dir) {
+            continue;
+        }
+
         // Check for circular reference
         if source_path
             .canonicalize()
@@ -639,8 +1212,15 @@ fn copy_directory_recursive_with_fs(
             continue;
         }
 
-        match copy_directory_recursive_impl_with_fs(&source_path, &dest_path, depth + 1, fs) {
-            Ok(count) => total_files += count,
+        match copy_directory_recursive_impl_with_fs(
+            &source_path,
+            &dest_path,
+            depth + 1,
+            max_size,
+            exclusions,
+            fs,
+        ) {
+            Ok(files) => copied.extend(files),
             Err(e) => {
                 println!(
                     "  {} {INFO_FAILED_TO_COPY} {}: {}",
@@ -652,7 +1232,7 @@ fn copy_directory_recursive_with_fs(
         }
     }
 
-    Ok(total_files)
+    Ok(copied)
 }
 
 /// Implementation helper for recursive directory copying
@@ -674,24 +1254,36 @@ fn copy_directory_recursive_impl(source: &Path, dest: &Path, depth: usize) -> Re
 }
 
 /// Implementation helper for recursive directory copying with filesystem abstraction
+///
+/// Symlinks are skipped with a warning so a copied directory cannot point
+/// outside the worktree, and files over `max_size` bytes are skipped.
 fn copy_directory_recursive_impl_with_fs(
     source: &Path,
     dest: &Path,
     depth: usize,
+    max_size: u64,
+    exclusions: &mut CopyExclusions,
     fs: &dyn FileSystem,
-) -> Result<usize> {
-    // Symlink detection
-    if fs.symlink_metadata(source)?.file_type().is_symlink() {
-        return Ok(0); // Skip symlinks silently in recursive copy
+) -> Result<Vec<PathBuf>> {
+    let metadata = fs.symlink_metadata(source)?;
+    if metadata.file_type().is_symlink() {
+        let warning = ICON_SYMLINK_WARNING.yellow();
+        let source_path = source.display();
+        println!("  {warning} {INFO_SKIPPING_SYMLINK}{source_path}");
+        return Ok(Vec::new());
     }
 
     if fs.is_file(source) {
+        if metadata.len() > max_size {
+            print_skipping_large(&source.display().to_string(), metadata.len(), max_size);
+            return Ok(Vec::new());
+        }
         fs.copy(source, dest)?;
-        Ok(1)
+        Ok(vec![dest.to_path_buf()])
     } else if fs.is_dir(source) {
-        copy_directory_recursive_with_fs(source, dest, depth, fs)
+        copy_directory_recursive_with_fs(source, dest, depth, max_size, exclusions, fs)
     } else {
-        Ok(0) // Skip special files
+        Ok(Vec::new()) // Skip special files
     }
 }
 
@@ -712,6 +1304,45 @@ mod tests {
         assert!(is_safe_path("assets/image.png"));
     }
 
+    #[test]
+    fn test_is_copy_excluded() {
+        let patterns = vec![
+            "node_modules".to_string(),
+            "*.log".to_string(),
+            "build/".to_string(),
+            "cache/tmp".to_string(),
+        ];
+        // Names match at any depth
+        assert!(is_copy_excluded(Path::new("node_modules"), true, &patterns));
+        assert!(is_copy_excluded(
+            Path::new("web/node_modules"),
+            true,
+            &patterns
+        ));
+        assert!(is_copy_excluded(
+            Path::new("logs/debug.log"),
+            false,
+            &patterns
+        ));
+        assert!(!is_copy_excluded(
+            Path::new("debug.log.txt"),
+            false,
+            &patterns
+        ));
+        // A trailing slash only matches directories
+        assert!(is_copy_excluded(Path::new("build"), true, &patterns));
+        assert!(!is_copy_excluded(Path::new("build"), false, &patterns));
+        // A pattern with a slash matches the relative path
+        assert!(is_copy_excluded(Path::new("cache/tmp"), true, &patterns));
+        assert!(!is_copy_excluded(Path::new("tmp"), true, &patterns));
+        assert!(!is_copy_excluded(
+            Path::new("other/cache/tmp"),
+            true,
+            &patterns
+        ));
+        assert!(!is_copy_excluded(Path::new("src/main.rs"), false, &[]));
+    }
+
     #[test]
     fn test_is_safe_path_invalid() {
         assert!(!is_safe_path(""));
@@ -764,8 +1395,11 @@ mod tests {
         let manager = GitWorktreeManager::new_from_path(temp_dir.path())?;
 
         let config = FilesConfig {
-            copy: vec![".env".to_string()],
+            copy: vec![".env".into()],
             source: Some("/explicit/path".to_string()),
+            max_size_mb: None,
+            source_ref: None,
+            copy_exclude: Vec::new(),
         };
 
         let source_dir = determine_source_directory(&config, &manager)?;
@@ -787,8 +1421,11 @@ mod tests {
         let manager = GitWorktreeManager::new_from_path(temp_dir.path())?;
 
         let config = FilesConfig {
-            copy: vec![".env".to_string()],
+            copy: vec![".env".into()],
             source: Some("./config".to_string()),
+            max_size_mb: None,
+            source_ref: None,
+            copy_exclude: Vec::new(),
         };
 
         let source_dir = determine_source_directory(&config, &manager)?;
@@ -798,6 +1435,53 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_resolve_copy_plan() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let source = temp_dir.path();
+        fs::write(source.join(".env"), "A=1")?;
+        fs::create_dir_all(source.join("config"))?;
+        fs::write(source.join("config/b.json"), "{}")?;
+        fs::write(source.join("config/a.json"), "{}")?;
+
+        let config = FilesConfig {
+            copy: vec![
+                ".env".into(),
+                ".env.local".into(),
+                "config/*.json".into(),
+                "*.key".into(),
+                "../outside".into(),
+            ],
+            source: None,
+            max_size_mb: None,
+            source_ref: None,
+            copy_exclude: Vec::new(),
+        };
+
+        let plan = resolve_copy_plan(&config, source);
+        assert_eq!(
+            plan,
+            vec![
+                (source.join(".env"), true),
+                (source.join(".env.local"), false),
+                (source.join("config/a.json"), true),
+                (source.join("config/b.json"), true),
+                (source.join("*.key"), false),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_glob_pattern() {
+        assert!(is_glob_pattern("*.env"));
+        assert!(is_glob_pattern("config/?.json"));
+        assert!(is_glob_pattern("data/[ab].txt"));
+        assert!(!is_glob_pattern(".env.local"));
+        assert!(!is_glob_pattern("config/settings.json"));
+    }
+
     #[test]
     fn test_is_safe_path_edge_cases() {
         // Test various edge cases for path safety
@@ -34,29 +34,75 @@
 //! ```
 
 use anyhow::{anyhow, Result};
-use git2::{BranchType, Repository};
+use chrono::{DateTime, Utc};
+use git2::{BranchType, ErrorCode, Repository};
+use serde::Serialize;
+use std::cell::RefCell;
 use std::fs::{self, File, OpenOptions};
 use std::path::{Path, PathBuf};
-use std::time::Duration;
+use std::sync::{mpsc, Mutex, MutexGuard, PoisonError};
+use std::thread;
+use std::time::{Duration, Instant};
 
+use super::super::config::Config;
 use super::super::constants::{
     COMMIT_ID_SHORT_LENGTH, DEFAULT_AUTHOR_UNKNOWN, DEFAULT_BRANCH_DETACHED,
-    DEFAULT_BRANCH_UNKNOWN, DEFAULT_MESSAGE_NONE, ERROR_LOCK_CREATE, ERROR_LOCK_EXISTS,
-    ERROR_NO_PARENT_BARE_REPO, ERROR_NO_PARENT_DIR, ERROR_NO_REPO_DIR, ERROR_NO_REPO_WORKING_DIR,
-    ERROR_NO_WORKING_DIR, ERROR_WORKTREE_CREATE, ERROR_WORKTREE_PATH_EXISTS, GIT_ADD, GIT_BRANCH,
-    GIT_BRANCH_NOT_FOUND_MSG, GIT_CANNOT_FIND_PARENT, GIT_CANNOT_RENAME_CURRENT,
+    DEFAULT_BRANCH_UNKNOWN, DEFAULT_MESSAGE_NONE, DEFAULT_STATUS_TIMEOUT_MS,
+    ENV_GIT_TERMINAL_PROMPT, ERROR_CANNOT_MOVE_CURRENT, ERROR_FETCH_FAILED,
+    ERROR_LAYOUT_ADD_FAILED, ERROR_LAYOUT_MOVE_FAILED, ERROR_LAYOUT_RESET_FAILED,
+    ERROR_LOCK_CREATE, ERROR_LOCK_EXISTS, ERROR_MOVE_FAILED, ERROR_MOVE_TARGET_EXISTS,
+    ERROR_NO_COMMITS, ERROR_NO_PARENT_BARE_REPO, ERROR_NO_PARENT_DIR, ERROR_NO_REPO_DIR,
+    ERROR_NO_REPO_WORKING_DIR, ERROR_NO_WORKING_DIR, ERROR_PRUNE_FAILED, ERROR_PR_BRANCH_DIVERGED,
+    ERROR_PR_NOT_FOUND, ERROR_PR_REFSPEC_NO_NUMBER, ERROR_WORKTREE_CREATE, ERROR_WORKTREE_DIRTY,
+    ERROR_WORKTREE_LOCKED, ERROR_WORKTREE_NOT_LOCKED, ERROR_WORKTREE_PATH_EXISTS, GIT_ADD,
+    GIT_BRANCH, GIT_BRANCH_NOT_FOUND_MSG, GIT_CANNOT_FIND_PARENT, GIT_CANNOT_RENAME_CURRENT,
     GIT_CANNOT_RENAME_DETACHED, GIT_CMD, GIT_COMMIT_AUTHOR_UNKNOWN, GIT_COMMIT_MESSAGE_NONE,
-    GIT_DEFAULT_MAIN_WORKTREE, GIT_DIR, GIT_GITDIR_PREFIX, GIT_GITDIR_SUFFIX, GIT_HEAD_INDEX,
-    GIT_NEW_NAME_NO_SPACES, GIT_OPT_BRANCH, GIT_OPT_GIT_COMMON_DIR, GIT_OPT_RENAME, GIT_ORIGIN,
-    GIT_REFS_REMOTES, GIT_REFS_TAGS, GIT_REPAIR, GIT_RESERVED_NAMES, GIT_REV_PARSE, GIT_WORKTREE,
-    LOCK_FILE_NAME, STALE_LOCK_TIMEOUT_SECS, TIME_FORMAT, WINDOW_FIRST_INDEX, WINDOW_SECOND_INDEX,
-    WINDOW_SIZE_PAIRS,
+    GIT_CONFIG_CORE_BARE, GIT_DEFAULT_MAIN_WORKTREE, GIT_DIR, GIT_FETCH, GIT_FILE_GITDIR,
+    GIT_GITDIR_PREFIX, GIT_GITDIR_SUFFIX, GIT_HEAD_INDEX, GIT_HEAD_REF, GIT_MOVE,
+    GIT_NEW_NAME_NO_SPACES, GIT_OPT_BRANCH, GIT_OPT_DEPTH, GIT_OPT_DRY_RUN, GIT_OPT_GIT_COMMON_DIR,
+    GIT_OPT_NO_CHECKOUT, GIT_OPT_QUIET, GIT_OPT_RENAME, GIT_OPT_VERBOSE, GIT_ORIGIN, GIT_PRUNE,
+    GIT_REFS_HEADS, GIT_REFS_REMOTES, GIT_REFS_TAGS, GIT_REMOTE_REF_NOT_FOUND,
+    GIT_RENAME_ROLLBACK_FAILED, GIT_RENAME_ROLLED_BACK, GIT_REPAIR, GIT_RESERVED_NAMES, GIT_RESET,
+    GIT_REV_PARSE, GIT_WORKTREE, GIT_WORKTREE_DIR, LOCK_FILE_NAME, PORCELAIN_BARE,
+    PORCELAIN_BRANCH, PORCELAIN_DETACHED, PORCELAIN_HEAD, PORCELAIN_LOCKED, PORCELAIN_WORKTREE,
+    PR_BRANCH_PREFIX, PR_NUMBER_PLACEHOLDER, PR_TRACKING_NAMESPACE, REMOTE_ORIGIN,
+    STALE_LOCK_TIMEOUT_SECS, TIME_FORMAT, WARNING_RENAME_REPAIR_RECOVERED, WINDOW_FIRST_INDEX,
+    WINDOW_SECOND_INDEX, WINDOW_SIZE_PAIRS, WORKTREE_CREATED_MARKER,
 };
+use super::super::core::GitWorkersError;
+use super::super::utils::debug_timer;
 use super::filesystem::FileSystem;
+use super::state::WorktreeRef;
 
 // Create Duration from constant for stale lock timeout
 const STALE_LOCK_TIMEOUT: Duration = Duration::from_secs(STALE_LOCK_TIMEOUT_SECS);
 
+/// Lock file of the [`WorktreeLock`] held by this process, if any
+///
+/// Read by the Ctrl-C handler, which exits without running destructors.
+static ACTIVE_LOCK: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/// Locks `slot`, recovering it if a panicking thread poisoned it
+fn lock_slot(slot: &Mutex<Option<PathBuf>>) -> MutexGuard<'_, Option<PathBuf>> {
+    slot.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Takes the lock path out of `slot` and removes its file
+fn remove_lock_in(slot: &Mutex<Option<PathBuf>>) -> Option<PathBuf> {
+    let lock_path = lock_slot(slot).take()?;
+    let _ = fs::remove_file(&lock_path);
+    Some(lock_path)
+}
+
+/// Removes the lock file of the [`WorktreeLock`] this process holds
+///
+/// Called from the Ctrl-C/SIGTERM handler before exiting, since `Drop`
+/// does not run when the process exits from a signal. Returns the removed
+/// lock path, or `None` when no lock was held.
+pub fn release_active_lock() -> Option<PathBuf> {
+    remove_lock_in(&ACTIVE_LOCK)
+}
+
 /// Simple lock structure for worktree operations
 pub struct WorktreeLock {
     lock_path: PathBuf,
@@ -89,12 +135,13 @@ impl WorktreeLock {
             .open(&lock_path)
             .map_err(|e| {
                 if e.kind() == std::io::ErrorKind::AlreadyExists {
-                    anyhow!(ERROR_LOCK_EXISTS)
+                    anyhow::Error::from(GitWorkersError::LockHeld(ERROR_LOCK_EXISTS.to_string()))
                 } else {
                     anyhow!("{}", ERROR_LOCK_CREATE.replace("{}", &e.to_string()))
                 }
             })?;
 
+        *lock_slot(&ACTIVE_LOCK) = Some(lock_path.clone());
         Ok(WorktreeLock {
             lock_path,
             _file: Some(file),
@@ -105,6 +152,10 @@ impl WorktreeLock {
 impl Drop for WorktreeLock {
     fn drop(&mut self) {
         // Clean up lock file when lock is released
+        let mut active = lock_slot(&ACTIVE_LOCK);
+        if active.as_deref() == Some(self.lock_path.as_path()) {
+            *active = None;
+        }
         let _ = fs::remove_file(&self.lock_path);
     }
 }
@@ -124,6 +175,8 @@ impl Drop for WorktreeLock {
 /// * `Some(PathBuf)` - The common parent directory if all worktrees share one
 /// * `None` - If worktrees don't share a common parent or the list is empty
 ///
+/// The main worktree is skipped: it is not placed by the layout.
+///
 /// # Example
 ///
 /// If worktrees are at:
@@ -131,13 +184,10 @@ impl Drop for WorktreeLock {
 /// - `/home/user/projects/myrepo/feature2`
 ///
 /// Returns: `Some("/home/user/projects/myrepo")`
-fn find_common_parent(worktrees: &[WorktreeInfo]) -> Option<PathBuf> {
-    if worktrees.is_empty() {
-        return None;
-    }
-
+pub fn find_common_parent(worktrees: &[WorktreeInfo]) -> Option<PathBuf> {
     let parent_dirs: Vec<_> = worktrees
         .iter()
+        .filter(|w| !w.is_main)
         .filter_map(|w| w.path.parent())
         .map(|p| p.to_path_buf())
         .collect();
@@ -153,6 +203,210 @@ fn find_common_parent(worktrees: &[WorktreeInfo]) -> Option<PathBuf> {
     }
 }
 
+/// Keeps `is_current` only on the innermost worktree containing the cwd
+///
+/// Linked worktrees can live inside the main worktree (the `worktrees/`
+/// subdirectory layout), so the cwd can be under several worktree roots.
+/// Like [`crate::commands::find_owning_worktree`], the root with the most
+/// path components owns it.
+pub fn keep_innermost_current(worktrees: &mut [WorktreeInfo]) {
+    let innermost = worktrees
+        .iter()
+        .enumerate()
+        .filter(|(_, w)| w.is_current)
+        .max_by_key(|(_, w)| {
+            let root = w.path.canonicalize().unwrap_or_else(|_| w.path.clone());
+            root.components().count()
+        })
+        .map(|(index, _)| index);
+    for (index, worktree) in worktrees.iter_mut().enumerate() {
+        worktree.is_current = Some(index) == innermost;
+    }
+}
+
+/// Worktrees that do not follow the dominant directory layout
+#[derive(Debug, Clone)]
+pub struct LayoutInconsistency {
+    /// The parent directory shared by most worktrees
+    pub expected_parent: PathBuf,
+    /// Worktrees located somewhere else
+    pub outliers: Vec<WorktreeInfo>,
+}
+
+/// Detects worktrees whose parent directory differs from the usual location
+///
+/// When [`find_common_parent`] finds no shared parent, the parent used by the
+/// most worktrees is treated as the expected location (ties go to the first
+/// worktree in the list) and every worktree elsewhere is reported. The main
+/// worktree is never reported.
+///
+/// # Returns
+///
+/// * `Some(LayoutInconsistency)` - The layout is scattered
+/// * `None` - All worktrees share a parent, or there are none
+pub fn find_layout_outliers(worktrees: &[WorktreeInfo]) -> Option<LayoutInconsistency> {
+    if find_common_parent(worktrees).is_some() {
+        return None;
+    }
+
+    let linked = || worktrees.iter().filter(|w| !w.is_main);
+    let parents: Vec<&Path> = linked().filter_map(|w| w.path.parent()).collect();
+    let expected_parent = parents
+        .iter()
+        .copied()
+        .max_by_key(|candidate| {
+            let count = parents.iter().filter(|p| *p == candidate).count();
+            let first_seen = parents.iter().position(|p| p == candidate).unwrap_or(0);
+            (count, std::cmp::Reverse(first_seen))
+        })?
+        .to_path_buf();
+
+    let outliers: Vec<WorktreeInfo> = linked()
+        .filter(|w| w.path.parent() != Some(expected_parent.as_path()))
+        .cloned()
+        .collect();
+
+    if outliers.is_empty() {
+        None
+    } else {
+        Some(LayoutInconsistency {
+            expected_parent,
+            outliers,
+        })
+    }
+}
+
+/// Reads the branch checked out in the worktree at `path`
+///
+/// # Returns
+///
+/// * `Some(branch)` - The short name of the checked out branch
+/// * `None` - The worktree is in detached HEAD state
+///
+/// # Errors
+///
+/// Returns an error if `path` is not a Git worktree or HEAD cannot be read.
+pub fn worktree_head_branch(path: &Path) -> Result<Option<String>> {
+    let repo = Repository::open(path)?;
+    if repo.head_detached()? {
+        return Ok(None);
+    }
+    let head = repo.head()?;
+    Ok(head.shorthand().map(|name| name.to_string()))
+}
+
+/// What the HEAD of a worktree points at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HeadState {
+    /// On an existing branch (short name)
+    Branch(String),
+    /// Detached at a commit
+    Detached,
+    /// On a branch whose ref no longer exists, e.g. deleted while checked out
+    MissingBranch(String),
+}
+
+/// Reads what the HEAD of the worktree at `path` points at
+///
+/// Unlike [`worktree_head_branch`], a branch that was deleted while checked
+/// out is reported as [`HeadState::MissingBranch`] instead of an error. The
+/// unborn branch of a repository without commits counts as a branch.
+///
+/// # Errors
+///
+/// Returns an error if `path` is not a Git worktree or HEAD cannot be read.
+pub fn worktree_head_state(path: &Path) -> Result<HeadState> {
+    let repo = Repository::open(path)?;
+    if repo.head_detached()? {
+        return Ok(HeadState::Detached);
+    }
+    let head = repo.find_reference(GIT_HEAD_REF)?;
+    let target = head.symbolic_target().unwrap_or_default().to_string();
+    let branch = target
+        .strip_prefix(GIT_REFS_HEADS)
+        .unwrap_or(&target)
+        .to_string();
+    if repo.find_reference(&target).is_ok() || repo.is_empty()? {
+        Ok(HeadState::Branch(branch))
+    } else {
+        Ok(HeadState::MissingBranch(branch))
+    }
+}
+
+/// A worktree record from `git worktree list --porcelain`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PorcelainWorktree {
+    /// Absolute path of the worktree
+    pub path: PathBuf,
+    /// Commit checked out in the worktree
+    pub head: Option<String>,
+    /// Full name of the checked out branch (e.g. `refs/heads/main`)
+    pub branch: Option<String>,
+    /// The entry is the bare repository itself
+    pub is_bare: bool,
+    /// The worktree has a detached HEAD
+    pub is_detached: bool,
+    /// Lock reason; `Some("")` when locked without a reason
+    pub locked: Option<String>,
+}
+
+/// Parses the output of `git worktree list --porcelain`
+///
+/// Accepts both the newline-terminated form and the NUL-terminated form
+/// produced with `-z`. Each record starts with a `worktree <path>` line;
+/// everything after the label is taken verbatim, so paths containing
+/// spaces or the word "worktree" are preserved. Unknown attributes are
+/// ignored so newer Git versions keep working.
+pub fn parse_worktree_porcelain(output: &str) -> Vec<PorcelainWorktree> {
+    let separator = if output.contains('\0') { '\0' } else { '\n' };
+    let mut worktrees = Vec::new();
+    let mut current: Option<PorcelainWorktree> = None;
+
+    for line in output.split(separator) {
+        let line = if separator == '\n' {
+            line.strip_suffix('\r').unwrap_or(line)
+        } else {
+            line
+        };
+
+        if let Some(path) = line.strip_prefix(PORCELAIN_WORKTREE) {
+            worktrees.extend(current.take());
+            current = Some(PorcelainWorktree {
+                path: PathBuf::from(path),
+                ..PorcelainWorktree::default()
+            });
+            continue;
+        }
+
+        let worktree = match current.as_mut() {
+            Some(worktree) => worktree,
+            None => continue,
+        };
+
+        if line.is_empty() {
+            worktrees.extend(current.take());
+        } else if let Some(head) = line.strip_prefix(PORCELAIN_HEAD) {
+            worktree.head = Some(head.to_string());
+        } else if let Some(branch) = line.strip_prefix(PORCELAIN_BRANCH) {
+            worktree.branch = Some(branch.to_string());
+        } else if line == PORCELAIN_BARE {
+            worktree.is_bare = true;
+        } else if line == PORCELAIN_DETACHED {
+            worktree.is_detached = true;
+        } else if line == PORCELAIN_LOCKED {
+            worktree.locked = Some(String::new());
+        } else if let Some(reason) = line
+            .strip_prefix(PORCELAIN_LOCKED)
+            .and_then(|rest| rest.strip_prefix(' '))
+        {
+            worktree.locked = Some(reason.to_string());
+        }
+    }
+    worktrees.extend(current);
+
+    worktrees
+}
+
 /// High-level Git worktree manager
 ///
 /// Provides a convenient interface for common worktree operations,
@@ -172,6 +426,21 @@ fn find_common_parent(worktrees: &[WorktreeInfo]) -> Option<PathBuf> {
 /// operations for worktree management.
 pub struct GitWorktreeManager {
     pub(crate) repo: Repository,
+    /// Snapshot returned by [`GitWorktreeManager::list_worktrees_cached`]
+    worktree_cache: RefCell<Option<Vec<WorktreeInfo>>>,
+}
+
+/// Clears the worktree snapshot when a mutating operation returns
+///
+/// Taken at the start of every operation that adds, removes, moves, renames
+/// or locks a worktree, so reads inside it still see the snapshot and
+/// everything after it scans again.
+struct CacheInvalidation<'a>(&'a RefCell<Option<Vec<WorktreeInfo>>>);
+
+impl Drop for CacheInvalidation<'_> {
+    fn drop(&mut self) {
+        self.0.take();
+    }
 }
 
 impl GitWorktreeManager {
@@ -195,7 +464,7 @@ impl GitWorktreeManager {
     /// ```
     pub fn new() -> Result<Self> {
         let repo = Repository::open_from_env()?;
-        Ok(Self { repo })
+        Ok(Self::from_repo(repo))
     }
 
     /// Creates a new GitWorktreeManager from a specific path
@@ -205,7 +474,14 @@ impl GitWorktreeManager {
     #[allow(dead_code)]
     pub fn new_from_path(path: &Path) -> Result<Self> {
         let repo = Repository::open(path)?;
-        Ok(Self { repo })
+        Ok(Self::from_repo(repo))
+    }
+
+    fn from_repo(repo: Repository) -> Self {
+        Self {
+            repo,
+            worktree_cache: RefCell::default(),
+        }
     }
 
     /// Returns a reference to the underlying git2::Repository
@@ -213,6 +489,53 @@ impl GitWorktreeManager {
         &self.repo
     }
 
+    /// Returns the git directory shared by all worktrees of the repository
+    pub fn get_common_dir(&self) -> &Path {
+        self.repo.commondir()
+    }
+
+    /// Lists the worktree lock files left in the repository, with their age
+    ///
+    /// [`WorktreeLock`] is taken in the Git directory of the worktree `gw`
+    /// runs in, so the common directory and every linked worktree's metadata
+    /// directory are checked.
+    pub fn worktree_lock_files(&self) -> Vec<(PathBuf, Duration)> {
+        let common = self.repo.commondir();
+        let mut git_dirs = vec![common.to_path_buf()];
+        if let Ok(entries) = fs::read_dir(common.join(GIT_WORKTREE_DIR)) {
+            git_dirs.extend(entries.flatten().map(|entry| entry.path()));
+        }
+
+        git_dirs
+            .into_iter()
+            .map(|dir| dir.join(LOCK_FILE_NAME))
+            .filter_map(|path| {
+                let modified = path.metadata().ok()?.modified().ok()?;
+                Some((path, modified.elapsed().unwrap_or_default()))
+            })
+            .collect()
+    }
+
+    /// Returns the working directory of the main worktree
+    ///
+    /// Returns `None` for bare repositories, which have no main worktree.
+    pub fn main_worktree_path(&self) -> Option<PathBuf> {
+        let common = Repository::open(self.repo.commondir()).ok()?;
+        common.workdir().map(|dir| dir.to_path_buf())
+    }
+
+    /// Returns the worktree `gw` is currently running in
+    ///
+    /// Returns `None` for bare repositories, which have no working directory.
+    pub fn current_worktree_ref(&self) -> Option<WorktreeRef> {
+        let workdir = self.repo.workdir()?;
+        let path = workdir
+            .canonicalize()
+            .unwrap_or_else(|_| workdir.to_path_buf());
+        let name = path.file_name()?.to_string_lossy().to_string();
+        Some(WorktreeRef { name, path })
+    }
+
     /// Gets the directory to use as working directory for git commands
     ///
     /// For bare repositories, returns the repository path itself.
@@ -281,7 +604,8 @@ impl GitWorktreeManager {
     ///
     /// # Returns
     ///
-    /// A vector of [`WorktreeInfo`] structs sorted alphabetically by name
+    /// A vector of [`WorktreeInfo`] structs: the main worktree first (absent
+    /// in bare repositories), then the linked worktrees sorted by name
     ///
     /// # Errors
     ///
@@ -293,18 +617,116 @@ impl GitWorktreeManager {
     ///
     /// This method spawns one thread per worktree for parallel processing.
     /// For repositories with many worktrees, this significantly reduces the
-    /// total time compared to sequential processing.
+    /// total time compared to sequential processing. Threads that have not
+    /// reported within `[performance] status_timeout_ms` are left behind and
+    /// their worktrees are marked with [`WorktreeInfo::status_unknown`]; the
+    /// name, branch and current-worktree detection are always filled in.
     pub fn list_worktrees(&self) -> Result<Vec<WorktreeInfo>> {
+        self.list_worktrees_with_ahead_behind(false)
+    }
+
+    /// Lists all worktrees, reusing the scan of an earlier call
+    ///
+    /// The first call runs [`GitWorktreeManager::list_worktrees`] and keeps
+    /// the result; later calls return it until [`GitWorktreeManager::refresh`]
+    /// or an operation that changes worktrees clears it. Meant for the steps
+    /// of a single command, which otherwise rescan every worktree each time.
+    pub fn list_worktrees_cached(&self) -> Result<Vec<WorktreeInfo>> {
+        if let Some(worktrees) = self.worktree_cache.borrow().as_ref() {
+            return Ok(worktrees.clone());
+        }
+        let worktrees = self.list_worktrees()?;
+        *self.worktree_cache.borrow_mut() = Some(worktrees.clone());
+        Ok(worktrees)
+    }
+
+    /// Drops the snapshot kept by [`GitWorktreeManager::list_worktrees_cached`]
+    ///
+    /// Needed after changing worktrees outside this manager, e.g. by
+    /// running `git worktree` directly.
+    pub fn refresh(&self) {
+        self.worktree_cache.take();
+    }
+
+    /// Clears the worktree snapshot once the calling operation returns
+    fn invalidate_cache_on_return(&self) -> CacheInvalidation<'_> {
+        CacheInvalidation(&self.worktree_cache)
+    }
+
+    /// Lists all worktrees, optionally counting commits ahead/behind upstream
+    ///
+    /// Same as [`GitWorktreeManager::list_worktrees`], but fills in
+    /// [`WorktreeInfo::ahead_behind`] for worktrees whose branch has an
+    /// upstream when `include_ahead_behind` is `true`. This walks the commit
+    /// graph of every worktree, so it is only done when asked for.
+    pub fn list_worktrees_with_ahead_behind(
+        &self,
+        include_ahead_behind: bool,
+    ) -> Result<Vec<WorktreeInfo>> {
+        let mut timer = debug_timer("list_worktrees");
         let mut worktrees = Vec::new();
         let worktree_names = self.repo.worktrees()?;
+        let (status_tx, status_rx) = mpsc::channel();
+
+        // The main worktree is not among the linked worktrees git2 reports
+        if let Some(main) = self.main_worktree_path() {
+            // The working directory ends with a separator; drop it
+            let path: PathBuf = main.components().collect();
+            let name = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or(GIT_DEFAULT_MAIN_WORKTREE)
+                .to_string();
+            let branch = match worktree_head_branch(&path) {
+                Ok(Some(branch)) => branch,
+                Ok(None) => String::from(DEFAULT_BRANCH_DETACHED),
+                Err(_) => String::from(DEFAULT_BRANCH_UNKNOWN),
+            };
+
+            let status_tx = status_tx.clone();
+            let status_path = path.clone();
+            thread::spawn(move || {
+                let status = get_worktree_status(&status_path, include_ahead_behind);
+                let _ = status_tx.send((0, status));
+            });
+
+            worktrees.push(WorktreeInfo {
+                name: name.clone(),
+                git_name: name,
+                is_current: self.is_current_worktree(&path),
+                created_at: worktree_created_at(self.repo.commondir(), &path),
+                path,
+                branch,
+                is_locked: false,
+                lock_reason: None,
+                has_changes: false,
+                last_commit: None,
+                ahead_behind: None,
+                is_prunable: false,
+                status_unknown: true,
+                is_main: true,
+            });
+        }
 
         for name in worktree_names.iter().flatten() {
             if let Ok(worktree) = self.repo.find_worktree(name) {
                 let path = worktree.path();
                 let is_current = self.is_current_worktree(path);
-                let is_locked = worktree.is_locked().is_ok();
+                let (is_locked, lock_reason) = match worktree.is_locked() {
+                    Ok(git2::WorktreeLockStatus::Locked(reason)) => {
+                        // `git worktree lock --reason` stores a trailing newline
+                        let reason = reason.map(|r| r.trim().to_string());
+                        (true, reason.filter(|r| !r.is_empty()))
+                    }
+                    _ => (false, None),
+                };
 
-                let branch = if let Ok(wt_repo) = Repository::open(path) {
+                // A worktree whose directory was deleted without pruning only has metadata
+                let is_prunable = !path.exists();
+
+                let branch = if is_prunable {
+                    String::from(DEFAULT_BRANCH_UNKNOWN)
+                } else if let Ok(wt_repo) = Repository::open(path) {
                     if let Ok(head) = wt_repo.head() {
                         if let Some(shorthand) = head.shorthand() {
                             shorthand.to_string()
@@ -318,8 +740,19 @@ impl GitWorktreeManager {
                     String::from(DEFAULT_BRANCH_UNKNOWN)
                 };
 
-                // Get additional status info for the worktree
-                let worktree_status = get_worktree_status(path);
+                // Gather status in the background (nothing to open if it is missing)
+                if !is_prunable {
+                    let status_tx = status_tx.clone();
+                    let index = worktrees.len();
+                    let path = path.to_path_buf();
+                    thread::spawn(move || {
+                        let status = get_worktree_status(&path, include_ahead_behind);
+                        let _ = status_tx.send((index, status));
+                    });
+                }
+
+                let metadata_dir = self.repo.commondir().join(GIT_WORKTREE_DIR).join(name);
+                let created_at = worktree_created_at(&metadata_dir, path);
 
                 // Use the actual directory name as the display name
                 let display_name = path
@@ -334,19 +767,44 @@ impl GitWorktreeManager {
                     path: path.to_path_buf(),
                     branch,
                     is_locked,
+                    lock_reason,
                     is_current,
-                    has_changes: worktree_status.has_changes,
-                    last_commit: worktree_status.last_commit,
-                    ahead_behind: worktree_status.ahead_behind,
+                    has_changes: false,
+                    last_commit: None,
+                    ahead_behind: None,
+                    is_prunable,
+                    created_at,
+                    status_unknown: !is_prunable,
+                    is_main: false,
                 };
 
                 worktrees.push(info);
             }
         }
+        drop(status_tx);
+
+        // Wait for the status threads, but never longer than the timeout
+        let deadline = Instant::now() + status_timeout();
+        while worktrees.iter().any(|w| w.status_unknown) {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match status_rx.recv_timeout(remaining) {
+                Ok((index, status)) => {
+                    let info: &mut WorktreeInfo = &mut worktrees[index];
+                    info.has_changes = status.has_changes();
+                    info.last_commit = status.last_commit;
+                    info.ahead_behind = status.ahead_behind;
+                    info.status_unknown = false;
+                }
+                Err(_) => break,
+            }
+        }
+
+        keep_innermost_current(&mut worktrees);
 
-        // Sort by name for consistent ordering
-        worktrees.sort_by(|a, b| a.name.cmp(&b.name));
+        // Main worktree first, then by name for consistent ordering
+        worktrees.sort_by(|a, b| b.is_main.cmp(&a.is_main).then_with(|| a.name.cmp(&b.name)));
 
+        timer.set_detail(format!("for {} worktrees", worktrees.len()));
         Ok(worktrees)
     }
 
@@ -546,30 +1004,12 @@ impl GitWorktreeManager {
     /// let path = manager.create_worktree("../sibling", None).unwrap();
     /// ```
     pub fn create_worktree(&self, name: &str, branch: Option<&str>) -> Result<PathBuf> {
+        let _invalidate = self.invalidate_cache_on_return();
+        let _timer = debug_timer("create_worktree");
         // Acquire lock to prevent concurrent worktree creation
         let _lock = WorktreeLock::acquire(self.repo.path())?;
 
-        let base_path = self.determine_worktree_base_path()?;
-
-        // Handle different path patterns
-        let worktree_path = if name.starts_with("../") {
-            // Relative path from repository (e.g., "../feature")
-            // This creates worktrees at the same level as the repository
-            let repo_dir = self
-                .repo
-                .workdir()
-                .or_else(|| self.repo.path().parent())
-                .ok_or_else(|| anyhow!(ERROR_NO_REPO_DIR))?;
-            repo_dir.join(name)
-        } else if name.contains('/') {
-            // Name includes a path pattern (e.g., "worktrees/feature")
-            // This is for subdirectory pattern - use repository directory as base
-            let repo_dir = self.repo.workdir().unwrap_or_else(|| self.repo.path());
-            repo_dir.join(name)
-        } else {
-            // Simple name - use existing pattern detection
-            base_path.join(name)
-        };
+        let worktree_path = self.resolve_worktree_path(name)?;
 
         // Ensure parent directories exist
         if let Some(parent) = worktree_path.parent() {
@@ -577,10 +1017,10 @@ impl GitWorktreeManager {
         }
 
         if worktree_path.exists() {
-            return Err(anyhow!(
-                "{}",
-                ERROR_WORKTREE_PATH_EXISTS.replace("{}", &worktree_path.display().to_string())
-            ));
+            return Err(GitWorkersError::WorktreeExists(
+                ERROR_WORKTREE_PATH_EXISTS.replace("{}", &worktree_path.display().to_string()),
+            )
+            .into());
         }
 
         // Extract the actual worktree name (last component)
@@ -604,14 +1044,17 @@ impl GitWorktreeManager {
             .unwrap_or_else(|_| worktree_path.clone());
 
         // Create worktree with git2
-        if let Some(branch_name) = branch {
+        let path = if let Some(branch_name) = branch {
             // Use git CLI for branch-based worktree creation
             // (git2's worktree API has limitations)
-            self.create_worktree_with_branch(&canonical_path, branch_name)
+            self.create_worktree_with_branch(&canonical_path, branch_name)?
         } else {
             // Create worktree from current HEAD
-            self.create_worktree_from_head(&canonical_path, worktree_name)
-        }
+            self.create_worktree_from_head(&canonical_path, worktree_name)?
+        };
+
+        write_created_marker(&path);
+        Ok(path)
     }
 
     /// Creates a worktree with a new branch from a base branch
@@ -663,25 +1106,12 @@ impl GitWorktreeManager {
         new_branch: &str,
         base_branch: &str,
     ) -> Result<PathBuf> {
+        let _invalidate = self.invalidate_cache_on_return();
+        let _timer = debug_timer("create_worktree_with_new_branch");
         // Acquire lock to prevent concurrent worktree creation
         let _lock = WorktreeLock::acquire(self.repo.path())?;
 
-        let base_path = self.determine_worktree_base_path()?;
-
-        // Handle different path patterns (same as create_worktree)
-        let worktree_path = if name.starts_with("../") {
-            let repo_dir = self
-                .repo
-                .workdir()
-                .or_else(|| self.repo.path().parent())
-                .ok_or_else(|| anyhow!(ERROR_NO_REPO_DIR))?;
-            repo_dir.join(name)
-        } else if name.contains('/') {
-            let repo_dir = self.repo.workdir().unwrap_or_else(|| self.repo.path());
-            repo_dir.join(name)
-        } else {
-            base_path.join(name)
-        };
+        let worktree_path = self.resolve_worktree_path(name)?;
 
         // Ensure parent directories exist
         if let Some(parent) = worktree_path.parent() {
@@ -689,10 +1119,10 @@ impl GitWorktreeManager {
         }
 
         if worktree_path.exists() {
-            return Err(anyhow!(
-                "{}",
-                ERROR_WORKTREE_PATH_EXISTS.replace("{}", &worktree_path.display().to_string())
-            ));
+            return Err(GitWorkersError::WorktreeExists(
+                ERROR_WORKTREE_PATH_EXISTS.replace("{}", &worktree_path.display().to_string()),
+            )
+            .into());
         }
 
         // Extract the actual worktree name (unused but kept for consistency)
@@ -733,6 +1163,8 @@ impl GitWorktreeManager {
             ));
         }
 
+        write_created_marker(&canonical_path);
+
         // Return the canonicalized path
         canonical_path
             .canonicalize()
@@ -745,10 +1177,14 @@ impl GitWorktreeManager {
     /// including both the main worktree (repository itself) and all linked worktrees.
     /// This is useful for preventing multiple checkouts of the same branch.
     ///
+    /// A checked-out branch is also keyed by the full name of its upstream
+    /// (e.g. "upstream/feature"), whatever the remote is called, so remote
+    /// branches show which worktree already tracks them.
+    ///
     /// # Returns
     ///
     /// A HashMap where:
-    /// - Key: Branch name (e.g., "main", "feature-x", "origin/remote-branch")
+    /// - Key: Branch name (e.g., "main", "feature-x", "upstream/remote-branch")
     /// - Value: Worktree name (e.g., "git-workers", "feature-worktree")
     ///
     /// # Example
@@ -764,12 +1200,17 @@ impl GitWorktreeManager {
     pub fn get_branch_worktree_map(&self) -> Result<std::collections::HashMap<String, String>> {
         let mut map = std::collections::HashMap::new();
 
-        // First, check the main worktree (the repository itself)
-        if let Ok(head) = self.repo.head() {
+        // First, check the main worktree (the repository itself), which is
+        // not the repository gw runs in when started from a linked worktree
+        let main_repo = self
+            .main_worktree_path()
+            .and_then(|path| Repository::open(path).ok());
+        let repo = main_repo.as_ref().unwrap_or(&self.repo);
+        if let Ok(head) = repo.head() {
             if head.is_branch() {
                 if let Some(branch_name) = head.shorthand() {
                     // For main worktree, use the repository path as the name
-                    let main_worktree_name = if let Some(workdir) = self.repo.workdir() {
+                    let main_worktree_name = if let Some(workdir) = repo.workdir() {
                         workdir
                             .file_name()
                             .and_then(|n| n.to_str())
@@ -778,10 +1219,11 @@ impl GitWorktreeManager {
                     } else {
                         GIT_DEFAULT_MAIN_WORKTREE.to_string()
                     };
+                    insert_upstream(&mut map, repo, branch_name, &main_worktree_name);
                     map.insert(branch_name.to_string(), main_worktree_name);
                 }
             } else if let Some(shorthand) = head.shorthand() {
-                let main_worktree_name = if let Some(workdir) = self.repo.workdir() {
+                let main_worktree_name = if let Some(workdir) = repo.workdir() {
                     workdir
                         .file_name()
                         .and_then(|n| n.to_str())
@@ -804,11 +1246,12 @@ impl GitWorktreeManager {
                         if head.is_branch() {
                             // It's a local branch
                             if let Some(branch_name) = head.shorthand() {
+                                insert_upstream(&mut map, &repo, branch_name, name);
                                 map.insert(branch_name.to_string(), name.to_string());
                             }
                         } else if let Some(shorthand) = head.shorthand() {
                             // It might be a remote tracking branch or detached HEAD
-                            // For remote tracking branches, the shorthand will be like "origin/branch"
+                            // For remote tracking branches, the shorthand will be like "upstream/branch"
                             // We'll include these in the map as well
                             map.insert(shorthand.to_string(), name.to_string());
                         }
@@ -820,6 +1263,65 @@ impl GitWorktreeManager {
         Ok(map)
     }
 
+    /// Finds the worktree that has `branch` checked out
+    ///
+    /// `branch` is a local branch name or a remote one such as
+    /// `origin/feature`, which also matches a worktree on local `feature`.
+    /// The main worktree is returned with `is_main` set.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(WorktreeInfo)` - The worktree the branch is checked out in
+    /// * `None` - The branch is not checked out in any worktree
+    pub fn worktree_for_branch(&self, branch: &str) -> Result<Option<WorktreeInfo>> {
+        let map = self.get_branch_worktree_map()?;
+        let remotes = self.remote_names();
+        let found = map.get_key_value(branch).or_else(|| {
+            strip_remote_prefix(branch, &remotes).and_then(|local| map.get_key_value(local))
+        });
+        let Some((_, holder)) = found else {
+            return Ok(None);
+        };
+
+        Ok(self
+            .list_worktrees_cached()?
+            .into_iter()
+            .find(|worktree| &worktree.git_name == holder))
+    }
+
+    /// Resolves the directory a worktree named `name` would be created in
+    ///
+    /// Follows the same rules as [`create_worktree`](Self::create_worktree):
+    /// - `../name` is placed next to the repository
+    /// - names containing `/` (e.g. `worktrees/name`) are relative to the repository
+    /// - simple names follow the pattern of the existing worktrees
+    ///
+    /// The result is not normalized and may still contain `..` components.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository directory cannot be determined
+    pub fn resolve_worktree_path(&self, name: &str) -> Result<PathBuf> {
+        if name.starts_with("../") {
+            // Relative path from repository (e.g., "../feature")
+            // This creates worktrees at the same level as the repository
+            let repo_dir = self
+                .repo
+                .workdir()
+                .or_else(|| self.repo.path().parent())
+                .ok_or_else(|| anyhow!(ERROR_NO_REPO_DIR))?;
+            Ok(repo_dir.join(name))
+        } else if name.contains('/') {
+            // Name includes a path pattern (e.g., "worktrees/feature")
+            // This is for subdirectory pattern - use repository directory as base
+            let repo_dir = self.repo.workdir().unwrap_or_else(|| self.repo.path());
+            Ok(repo_dir.join(name))
+        } else {
+            // Simple name - use existing pattern detection
+            Ok(self.determine_worktree_base_path()?.join(name))
+        }
+    }
+
     /// Determines the base path for creating new worktrees
     ///
     /// This method implements the pattern detection logic:
@@ -835,7 +1337,7 @@ impl GitWorktreeManager {
     ///
     /// Returns an error if the default base path cannot be determined
     fn determine_worktree_base_path(&self) -> Result<PathBuf> {
-        if let Ok(existing_worktrees) = self.list_worktrees() {
+        if let Ok(existing_worktrees) = self.list_worktrees_cached() {
             if let Some(common_parent) = find_common_parent(&existing_worktrees) {
                 return Ok(common_parent);
             }
@@ -867,6 +1369,7 @@ impl GitWorktreeManager {
     ///
     /// Returns an error if the git command fails
     pub fn create_worktree_with_branch(&self, path: &Path, branch_name: &str) -> Result<PathBuf> {
+        let _invalidate = self.invalidate_cache_on_return();
         use std::process::Command;
 
         let mut cmd = Command::new(GIT_CMD);
@@ -884,10 +1387,10 @@ impl GitWorktreeManager {
                 .arg(GIT_ADD)
                 .arg(path)
                 .arg(branch_name);
-        } else if branch_name.starts_with(GIT_ORIGIN) {
+        } else if let Some(remote_branch) = self.find_remote_branch(branch_name) {
             // For remote branches, we need to create a local branch
-            // Extract the branch name without "origin/" prefix
-            let local_branch_name = branch_name.strip_prefix(GIT_ORIGIN).unwrap_or(branch_name);
+            // named after the branch without its remote prefix
+            let local_branch_name = remote_branch.branch.as_str();
 
             // Check if a local branch with this name already exists
             let local_exists = self
@@ -982,9 +1485,14 @@ impl GitWorktreeManager {
     ///
     /// Returns an error if:
     /// - The current directory cannot be determined
-    /// - The git command fails (e.g., path already exists, no commits)
+    /// - The repository has no commits yet
+    /// - The git command fails (e.g., path already exists)
     /// - Path canonicalization fails after creation
     pub fn create_worktree_from_head(&self, path: &Path, _name: &str) -> Result<PathBuf> {
+        if !self.has_commits() {
+            return Err(anyhow!(ERROR_NO_COMMITS));
+        }
+        let _invalidate = self.invalidate_cache_on_return();
         use std::process::Command;
 
         // Convert to absolute path to ensure consistent interpretation by git command
@@ -1031,84 +1539,476 @@ impl GitWorktreeManager {
     /// - Cannot remove the current worktree (checked by caller)
     /// - Removes all files in the worktree directory
     ///
+    /// - Refuses worktrees with uncommitted changes, like `git worktree remove`
+    ///   (see [`GitWorktreeManager::remove_worktree_force`])
+    ///
     /// # Errors
     ///
     /// Returns an error if:
     /// - The worktree doesn't exist
     /// - The worktree is locked
+    /// - The worktree has uncommitted or untracked changes
     /// - File system operations fail
     pub fn remove_worktree(&self, name: &str) -> Result<()> {
+        let _invalidate = self.invalidate_cache_on_return();
         let worktree = self.repo.find_worktree(name)?;
-        worktree.prune(Some(
-            &mut git2::WorktreePruneOptions::new()
-                .valid(true)
-                .working_tree(true),
-        ))?;
-        Ok(())
+        if worktree.path().exists() && has_uncommitted_changes(worktree.path()) {
+            return Err(anyhow!(ERROR_WORKTREE_DIRTY.replace("{}", name)));
+        }
+        prune_worktree(&worktree)
     }
 
-    /// Lists all branches (local and remote) in the repository
-    ///
-    /// This method provides a comprehensive list of all branches, separated by type.
-    /// Remote branch names have the "origin/" prefix stripped for cleaner display,
-    /// and HEAD references are excluded from the remote branches list.
+    /// Removes a worktree by name, discarding uncommitted changes
     ///
-    /// # Returns
+    /// Same as [`GitWorktreeManager::remove_worktree`] without the check for
+    /// uncommitted changes. Callers must get explicit confirmation first.
+    pub fn remove_worktree_force(&self, name: &str) -> Result<()> {
+        let _invalidate = self.invalidate_cache_on_return();
+        let worktree = self.repo.find_worktree(name)?;
+        prune_worktree(&worktree)
+    }
+
+    /// Locks a worktree so that it cannot be removed or pruned
     ///
-    /// A tuple containing:
-    /// - `Vec<String>` of local branch names (sorted alphabetically)
-    /// - `Vec<String>` of remote branch names without "origin/" prefix (sorted alphabetically)
+    /// Same as `git worktree lock [--reason <reason>] <name>`. `name` is the
+    /// Git name of the worktree ([`WorktreeInfo::git_name`]).
     ///
     /// # Errors
     ///
-    /// Returns an error if branch enumeration fails
+    /// Returns an error if the worktree doesn't exist or is already locked.
+    pub fn lock_worktree(&self, name: &str, reason: Option<&str>) -> Result<()> {
+        let _invalidate = self.invalidate_cache_on_return();
+        self.repo.find_worktree(name)?.lock(reason)?;
+        Ok(())
+    }
+
+    /// Unlocks a worktree locked with [`GitWorktreeManager::lock_worktree`]
     ///
-    /// # Example
+    /// # Errors
     ///
-    /// ```no_run
-    /// # use git_workers::git::GitWorktreeManager;
-    /// # let manager = GitWorktreeManager::new().unwrap();
-    /// let (local_branches, remote_branches) = manager.list_all_branches().unwrap();
-    /// println!("Local branches: {:?}", local_branches);
-    /// println!("Remote branches: {:?}", remote_branches);
-    /// ```
-    pub fn list_all_branches(&self) -> Result<(Vec<String>, Vec<String>)> {
-        let mut local_branches = Vec::new();
-        let mut remote_branches = Vec::new();
-
-        // Get local branches
-        let local_iter = self.repo.branches(Some(BranchType::Local))?;
-        for (branch, _) in local_iter.flatten() {
-            if let Some(name) = branch.name()? {
-                local_branches.push(name.to_string());
-            }
-        }
-
-        // Get remote branches
-        let remote_iter = self.repo.branches(Some(BranchType::Remote))?;
-        for (branch, _) in remote_iter.flatten() {
-            if let Some(name) = branch.name()? {
-                // Remove "origin/" prefix for cleaner display
-                let clean_name = name.strip_prefix(GIT_ORIGIN).unwrap_or(name);
-                // Skip HEAD references
-                if clean_name != GIT_RESERVED_NAMES[GIT_HEAD_INDEX] {
-                    remote_branches.push(clean_name.to_string());
-                }
-            }
+    /// Returns an error if the worktree doesn't exist or is not locked.
+    pub fn unlock_worktree(&self, name: &str) -> Result<()> {
+        let _invalidate = self.invalidate_cache_on_return();
+        let worktree = self.repo.find_worktree(name)?;
+        if !matches!(worktree.is_locked()?, git2::WorktreeLockStatus::Locked(_)) {
+            return Err(anyhow!(ERROR_WORKTREE_NOT_LOCKED.replace("{}", name)));
         }
-
-        local_branches.sort();
-        remote_branches.sort();
-
-        Ok((local_branches, remote_branches))
+        worktree.unlock()?;
+        Ok(())
     }
 
-    /// Lists all tags in the repository
+    /// Moves a worktree to `new_path`, which may be in another directory
     ///
-    /// This method retrieves all tags, including both lightweight and annotated tags.
-    /// For annotated tags, it includes the tag message if available.
+    /// Same as `git worktree move <path> <new_path>`; missing parent
+    /// directories of `new_path` are created first. `name` is the Git name
+    /// of the worktree ([`WorktreeInfo::git_name`]). Git keeps tracking the
+    /// worktree under that name, so only its path changes.
     ///
-    /// # Returns
+    /// # Errors
+    ///
+    /// Returns an error if the worktree doesn't exist, is the current or a
+    /// locked worktree, `new_path` already exists, or Git refuses the move.
+    pub fn move_worktree(&self, name: &str, new_path: &Path) -> Result<PathBuf> {
+        let _invalidate = self.invalidate_cache_on_return();
+        use std::process::Command;
+
+        let worktree = self.repo.find_worktree(name)?;
+        let old_path = worktree.path().to_path_buf();
+
+        if new_path.exists() {
+            return Err(anyhow!(
+                ERROR_MOVE_TARGET_EXISTS.replace("{}", &new_path.display().to_string())
+            ));
+        }
+        if self.is_current_worktree(&old_path) {
+            return Err(anyhow!(ERROR_CANNOT_MOVE_CURRENT));
+        }
+        if matches!(worktree.is_locked()?, git2::WorktreeLockStatus::Locked(_)) {
+            return Err(anyhow!(ERROR_WORKTREE_LOCKED.replace("{}", name)));
+        }
+
+        if let Some(parent) = new_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let output = Command::new(GIT_CMD)
+            .current_dir(self.get_git_dir()?)
+            .args([GIT_WORKTREE, GIT_MOVE])
+            .arg(&old_path)
+            .arg(new_path)
+            .output()?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow!(ERROR_MOVE_FAILED.replace("{}", stderr.trim())));
+        }
+        Ok(new_path.to_path_buf())
+    }
+
+    /// Removes stale entries for worktrees whose directory no longer exists
+    ///
+    /// Runs `git worktree prune --verbose` and returns the lines Git printed,
+    /// one per pruned entry. An empty list means there was nothing to prune.
+    /// With `dry_run` Git only reports what it would prune (`--dry-run`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if Git cannot be run or exits with a failure.
+    pub fn prune_worktrees(&self, dry_run: bool) -> Result<Vec<String>> {
+        let _invalidate = self.invalidate_cache_on_return();
+        use std::process::Command;
+
+        let mut cmd = Command::new(GIT_CMD);
+        cmd.current_dir(self.get_git_dir()?)
+            .args([GIT_WORKTREE, GIT_PRUNE, GIT_OPT_VERBOSE]);
+        if dry_run {
+            cmd.arg(GIT_OPT_DRY_RUN);
+        }
+        let output = cmd.output()?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow!(ERROR_PRUNE_FAILED.replace("{}", stderr.trim())));
+        }
+
+        // Git reports pruned entries on stderr; accept stdout too
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Ok(stdout
+            .lines()
+            .chain(stderr.lines())
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect())
+    }
+
+    /// Fetches from `remote`, only `refspec` when given
+    ///
+    /// Runs `git fetch -q [--depth <depth>] <remote> [<refspec>]`, which also
+    /// updates the remote-tracking ref of a fetched branch. A depth makes the
+    /// repository shallow if it was not. Git never prompts for credentials,
+    /// so an unreachable remote fails instead of hanging.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if Git cannot be run or the fetch fails (e.g. offline).
+    pub fn fetch_remote(
+        &self,
+        remote: &str,
+        refspec: Option<&str>,
+        depth: Option<u32>,
+    ) -> Result<()> {
+        use std::process::{Command, Stdio};
+
+        let depth = depth.map(|depth| depth.to_string());
+        let output = Command::new(GIT_CMD)
+            .current_dir(self.repo.commondir())
+            .args([GIT_FETCH, GIT_OPT_QUIET])
+            .args(
+                depth
+                    .iter()
+                    .flat_map(|depth| [GIT_OPT_DEPTH, depth.as_str()]),
+            )
+            .arg(remote)
+            .args(refspec)
+            .env(ENV_GIT_TERMINAL_PROMPT, "0")
+            .stdin(Stdio::null())
+            .output()?;
+
+        if !output.status.success() {
+            // The first line says why; the rest is Git's generic advice
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let reason = stderr.lines().next().unwrap_or_default().trim();
+            return Err(anyhow!(ERROR_FETCH_FAILED
+                .replacen("{}", remote, 1)
+                .replacen("{}", reason, 1)));
+        }
+        Ok(())
+    }
+
+    /// Fetches pull request `number` from `origin` for the local branch `pr-<number>`
+    ///
+    /// `template` is the remote ref with a `{{number}}` placeholder
+    /// (`[create] pr_refspec`, GitHub's `pull/{{number}}/head` by default).
+    /// The pull request is fetched into `refs/remotes/origin/pr/<number>`.
+    /// A missing branch is created there and an existing one is only
+    /// fast-forwarded, so commits made on it locally are never overwritten;
+    /// a branch checked out in a worktree is left alone. Returns the branch
+    /// name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the template has no placeholder, the pull request
+    /// ref does not exist (e.g. a wrong number), the fetch fails, or the
+    /// branch has commits that are not in the pull request.
+    pub fn fetch_pr(&self, number: u32, template: &str, depth: Option<u32>) -> Result<String> {
+        let (source, branch) = pr_refspec(template, number)?;
+        let tracking = format!("{GIT_REFS_REMOTES}{REMOTE_ORIGIN}/{PR_TRACKING_NAMESPACE}{number}");
+        // Forcing is safe: only the remote-tracking ref is rewritten
+        let refspec = format!("+{source}:{tracking}");
+        self.fetch_remote(REMOTE_ORIGIN, Some(&refspec), depth)
+            .map_err(|e| {
+                if e.to_string().contains(GIT_REMOTE_REF_NOT_FOUND) {
+                    anyhow!(ERROR_PR_NOT_FOUND
+                        .replacen("{}", &number.to_string(), 1)
+                        .replacen("{}", REMOTE_ORIGIN, 1)
+                        .replacen("{}", &source, 1))
+                } else {
+                    e
+                }
+            })?;
+
+        let target = self.repo.refname_to_id(&tracking)?;
+        let mut local = match self.repo.find_branch(&branch, BranchType::Local) {
+            Ok(local) => local,
+            Err(_) => {
+                self.repo
+                    .branch(&branch, &self.repo.find_commit(target)?, false)?;
+                return Ok(branch);
+            }
+        };
+        let Some(current) = local.get().target() else {
+            return Ok(branch);
+        };
+        let contains_pr = current == target || self.repo.graph_descendant_of(current, target)?;
+        if contains_pr || self.get_branch_worktree_map()?.contains_key(&branch) {
+            return Ok(branch);
+        }
+        if !self.repo.graph_descendant_of(target, current)? {
+            return Err(anyhow!(ERROR_PR_BRANCH_DIVERGED
+                .replacen("{}", &branch, 1)
+                .replacen("{}", &number.to_string(), 1)));
+        }
+        local
+            .get_mut()
+            .set_target(target, &format!("gw create --pr {number}: fast-forward"))?;
+        Ok(branch)
+    }
+
+    /// Moves the main worktree's files into a new linked worktree at `target`
+    ///
+    /// The repository is made bare (`core.bare = true`) so that `branch` can
+    /// be checked out again, `target` is registered with
+    /// `git worktree add --no-checkout`, and every entry of the main worktree
+    /// except `.git` and the top-level directory containing `target` is moved
+    /// there. The index is then rebuilt from `branch`, so ignored and
+    /// untracked files keep their content.
+    ///
+    /// Callers must make sure the main worktree is clean and on `branch`.
+    /// The manager still describes the old layout afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository has no main worktree, `target` is
+    /// not inside it, or a Git or file system step fails. If registering the
+    /// worktree fails the repository is made non-bare again.
+    pub fn move_main_worktree(&self, target: &Path, branch: &str) -> Result<PathBuf> {
+        let _invalidate = self.invalidate_cache_on_return();
+        use std::process::Command;
+
+        let root = self
+            .main_worktree_path()
+            .ok_or_else(|| anyhow!(ERROR_NO_REPO_WORKING_DIR))?;
+        let keep = target
+            .strip_prefix(&root)
+            .ok()
+            .and_then(|relative| relative.components().next())
+            .map(|first| first.as_os_str().to_os_string())
+            .ok_or_else(|| anyhow!(ERROR_NO_PARENT_DIR))?;
+
+        let mut config = Repository::open(self.repo.commondir())?.config()?;
+        config.set_bool(GIT_CONFIG_CORE_BARE, true)?;
+
+        let output = Command::new(GIT_CMD)
+            .current_dir(self.repo.commondir())
+            .arg(GIT_WORKTREE)
+            .arg(GIT_ADD)
+            .arg(GIT_OPT_NO_CHECKOUT)
+            .arg(target)
+            .arg(branch)
+            .output()?;
+        if !output.status.success() {
+            let _ = config.set_bool(GIT_CONFIG_CORE_BARE, false);
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow!(ERROR_LAYOUT_ADD_FAILED
+                .replacen("{}", &target.display().to_string(), 1)
+                .replacen("{}", stderr.trim(), 1)));
+        }
+
+        for entry in fs::read_dir(&root)? {
+            let entry = entry?;
+            let name = entry.file_name();
+            if name == GIT_DIR || name == keep {
+                continue;
+            }
+            fs::rename(entry.path(), target.join(&name)).map_err(|e| {
+                anyhow!(ERROR_LAYOUT_MOVE_FAILED
+                    .replacen("{}", &name.to_string_lossy(), 1)
+                    .replacen("{}", &e.to_string(), 1))
+            })?;
+        }
+
+        let output = Command::new(GIT_CMD)
+            .current_dir(target)
+            .args([GIT_RESET, GIT_OPT_QUIET])
+            .output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow!(
+                ERROR_LAYOUT_RESET_FAILED.replace("{}", stderr.trim())
+            ));
+        }
+
+        Ok(target.to_path_buf())
+    }
+
+    /// Checks whether a local branch named `name` exists
+    ///
+    /// Names that are not valid branch names do not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the branch cannot be looked up for another reason.
+    pub fn branch_exists(&self, name: &str) -> Result<bool> {
+        match self.repo.find_branch(name, BranchType::Local) {
+            Ok(_) => Ok(true),
+            Err(e) if matches!(e.code(), ErrorCode::NotFound | ErrorCode::InvalidSpec) => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Lists all branches (local and remote) in the repository
+    ///
+    /// This method provides a comprehensive list of all branches, separated by type.
+    /// Remote branches keep the name of their remote, so `origin/main` and
+    /// `upstream/main` are both listed, and HEAD references are excluded.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing:
+    /// - `Vec<String>` of local branch names (sorted alphabetically)
+    /// - `Vec<RemoteBranch>` of remote branches, grouped by remote and sorted
+    ///   alphabetically within each remote
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if branch enumeration fails
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use git_workers::git::GitWorktreeManager;
+    /// # let manager = GitWorktreeManager::new().unwrap();
+    /// let (local_branches, remote_branches) = manager.list_all_branches().unwrap();
+    /// println!("Local branches: {:?}", local_branches);
+    /// println!("Remote branches: {:?}", remote_branches);
+    /// ```
+    pub fn list_all_branches(&self) -> Result<(Vec<String>, Vec<RemoteBranch>)> {
+        let mut local_branches = Vec::new();
+        let mut remote_branches = Vec::new();
+
+        // Get local branches
+        let local_iter = self.repo.branches(Some(BranchType::Local))?;
+        for (branch, _) in local_iter.flatten() {
+            if let Some(name) = branch.name()? {
+                local_branches.push(name.to_string());
+            }
+        }
+
+        // Get remote branches
+        let remote_iter = self.repo.branches(Some(BranchType::Remote))?;
+        for (branch, _) in remote_iter.flatten() {
+            if let Some(remote_branch) = self.remote_branch(&branch) {
+                // Skip HEAD references
+                if remote_branch.branch != GIT_RESERVED_NAMES[GIT_HEAD_INDEX] {
+                    remote_branches.push(remote_branch);
+                }
+            }
+        }
+
+        local_branches.sort();
+        remote_branches.sort();
+
+        Ok((local_branches, remote_branches))
+    }
+
+    /// Orders local branches by the time of their tip commit, newest first
+    ///
+    /// Used for `[create] branch_sort = "committerdate"`; see
+    /// [`sort_by_commit_time`].
+    pub fn sort_branches_by_commit_time(&self, branches: &mut [String]) {
+        sort_by_commit_time(branches, |name| {
+            let branch = self.repo.find_branch(name, BranchType::Local).ok()?;
+            let commit = branch.get().peel_to_commit().ok()?;
+            Some(commit.time().seconds())
+        });
+    }
+
+    /// Splits a remote-tracking branch into its remote and branch name
+    ///
+    /// The remote is the one git2 resolves for the reference, so remotes
+    /// whose name contains a slash are handled; refs of a remote that is no
+    /// longer configured fall back to the part before the first slash.
+    fn remote_branch(&self, branch: &git2::Branch) -> Option<RemoteBranch> {
+        let name = branch.name().ok()??;
+        let remote = branch
+            .get()
+            .name()
+            .and_then(|refname| self.repo.branch_remote_name(refname).ok())
+            .and_then(|remote| remote.as_str().map(str::to_string))
+            .or_else(|| name.split_once('/').map(|(remote, _)| remote.to_string()))?;
+        let branch = name.strip_prefix(&format!("{remote}/"))?.to_string();
+        Some(RemoteBranch { remote, branch })
+    }
+
+    /// Looks up `name` (e.g. `upstream/main`) as a remote-tracking branch
+    pub fn find_remote_branch(&self, name: &str) -> Option<RemoteBranch> {
+        let branch = self.repo.find_branch(name, BranchType::Remote).ok()?;
+        self.remote_branch(&branch)
+    }
+
+    /// Returns true if HEAD resolves to a commit
+    ///
+    /// A repository fresh from `git init` has none, and worktrees cannot be
+    /// created from it yet.
+    pub fn has_commits(&self) -> bool {
+        self.repo.head().is_ok_and(|head| head.target().is_some())
+    }
+
+    /// Returns the names of the configured remotes
+    pub fn remote_names(&self) -> Vec<String> {
+        self.repo
+            .remotes()
+            .map(|remotes| remotes.iter().flatten().map(str::to_string).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns true if the local branch of the same name points at the same
+    /// commit as `remote_branch`
+    ///
+    /// Returns false if either branch is missing.
+    pub fn is_remote_branch_in_sync(&self, remote_branch: &RemoteBranch) -> bool {
+        let target = |name: &str, branch_type| {
+            self.repo
+                .find_branch(name, branch_type)
+                .ok()
+                .and_then(|b| b.get().target())
+        };
+        match (
+            target(&remote_branch.branch, BranchType::Local),
+            target(&remote_branch.full_name(), BranchType::Remote),
+        ) {
+            (Some(local), Some(remote)) => local == remote,
+            _ => false,
+        }
+    }
+
+    /// Lists all tags in the repository
+    ///
+    /// This method retrieves all tags, including both lightweight and annotated tags.
+    /// For annotated tags, it includes the tag message if available.
+    ///
+    /// # Returns
     ///
     /// A vector of tuples containing:
     /// - Tag name (`String`)
@@ -1183,6 +2083,7 @@ impl GitWorktreeManager {
     /// - The branch doesn't exist
     /// - The branch cannot be deleted (e.g., currently checked out)
     pub fn delete_branch(&self, branch_name: &str) -> Result<()> {
+        let _invalidate = self.invalidate_cache_on_return();
         match self.repo.find_branch(branch_name, BranchType::Local) {
             Ok(mut branch) => {
                 branch.delete()?;
@@ -1248,6 +2149,7 @@ impl GitWorktreeManager {
     /// - The new branch name already exists
     /// - The branch is currently checked out (in some Git versions)
     pub fn rename_branch(&self, old_name: &str, new_name: &str) -> Result<()> {
+        let _invalidate = self.invalidate_cache_on_return();
         use std::process::Command;
 
         // Use git CLI for more robust branch renaming
@@ -1315,7 +2217,8 @@ impl GitWorktreeManager {
             .format(TIME_FORMAT)
             .to_string();
 
-        let id = head.id().to_string()[..COMMIT_ID_SHORT_LENGTH].to_string();
+        let full_id = head.id().to_string();
+        let id = full_id[..COMMIT_ID_SHORT_LENGTH].to_string();
         let message = head
             .summary()
             .unwrap_or(GIT_COMMIT_MESSAGE_NONE)
@@ -1328,6 +2231,7 @@ impl GitWorktreeManager {
 
         Ok(CommitInfo {
             id,
+            full_id,
             message,
             author,
             time,
@@ -1360,11 +2264,14 @@ impl GitWorktreeManager {
     /// - The worktree is currently active
     /// - The worktree has a detached HEAD
     /// - Any file system operations fail
+    /// - The metadata cannot be updated after the move, in which case the
+    ///   rename is rolled back (see [`RenameRepairError`])
     ///
     /// # Note
     ///
     /// Branch renaming is handled separately by the caller if needed.
     pub fn rename_worktree(&self, old_name: &str, new_name: &str) -> Result<PathBuf> {
+        let _invalidate = self.invalidate_cache_on_return();
         self.rename_worktree_with_fs(
             old_name,
             new_name,
@@ -1373,12 +2280,28 @@ impl GitWorktreeManager {
     }
 
     /// Internal implementation of rename_worktree with filesystem abstraction
+    ///
+    /// If the metadata cannot be updated after the directory was moved, it
+    /// is rewritten directly and checked; when that fails too, the rename is
+    /// rolled back and a [`RenameRepairError`] is returned.
     pub fn rename_worktree_with_fs(
         &self,
         old_name: &str,
         new_name: &str,
         fs: &dyn FileSystem,
     ) -> Result<PathBuf> {
+        self.rename_worktree_with_repair(old_name, new_name, fs, &run_worktree_repair)
+    }
+
+    /// rename_worktree_with_fs with the `git worktree repair` step injected
+    fn rename_worktree_with_repair(
+        &self,
+        old_name: &str,
+        new_name: &str,
+        fs: &dyn FileSystem,
+        repair: &dyn Fn(&Path) -> Result<()>,
+    ) -> Result<PathBuf> {
+        let _invalidate = self.invalidate_cache_on_return();
         use std::process::Command;
 
         // Validate new name
@@ -1455,179 +2378,454 @@ impl GitWorktreeManager {
             .join(crate::constants::WORKTREES_SUBDIR)
             .join(old_name); // Keep using old name!
 
-        if worktree_git_dir.exists() {
-            // Update the gitdir file to point to the new path
-            let gitdir_file = worktree_git_dir.join("gitdir");
-            if gitdir_file.exists() {
-                let new_path_str = new_path.display();
-                let new_content = format!("{new_path_str}{GIT_GITDIR_SUFFIX}");
-                fs.write(&gitdir_file, &new_content)?;
-            }
-        }
-
-        // Step 3: Update the .git file in the worktree
-        let git_file_path = new_path.join(GIT_DIR);
-        if git_file_path.exists() {
-            // Point back to the original metadata directory name
-            let git_dir_str = worktree_git_dir.display();
-            let git_file_content = format!("{GIT_GITDIR_PREFIX}{git_dir_str}\n");
-            fs.write(&git_file_path, &git_file_content)?;
-        }
+        // Keep the original metadata so a failed rename can be rolled back
+        let gitdir_file = worktree_git_dir.join(GIT_FILE_GITDIR);
+        let original_gitdir = fs.read_to_string(&gitdir_file).ok();
+        let original_git_file = fs.read_to_string(&new_path.join(GIT_DIR)).ok();
 
+        // Steps 2 and 3: Point the gitdir file and the worktree's .git file
+        // at each other again
         // Step 4: Run git worktree repair to update Git's internal tracking
         // Note: This won't rename the worktree in Git's tracking, but will ensure
         // the paths are correct
-        let repair_output = Command::new(GIT_CMD)
-            .current_dir(self.get_git_dir()?)
-            .args([GIT_WORKTREE, GIT_REPAIR])
-            .output()?;
+        let updated = write_rename_metadata(fs, &worktree_git_dir, &new_path)
+            .and_then(|()| repair(self.get_git_dir()?));
+
+        if let Err(e) = updated {
+            // Re-derive the metadata from the paths and check it took
+            let fixed = write_rename_metadata(fs, &worktree_git_dir, &new_path)
+                .and_then(|()| verify_rename_metadata(fs, &worktree_git_dir, &new_path));
+            if fixed.is_ok() {
+                eprintln!(
+                    "{}",
+                    WARNING_RENAME_REPAIR_RECOVERED.replace("{}", &e.to_string())
+                );
+                return Ok(new_path);
+            }
 
-        if !repair_output.status.success() {
-            eprintln!(
-                "Warning: git worktree repair failed: {}",
-                String::from_utf8_lossy(&repair_output.stderr)
-            );
+            let rolled_back = fs
+                .rename(&new_path, &old_path)
+                .and_then(|()| match &original_gitdir {
+                    Some(content) => fs.write(&gitdir_file, content),
+                    None => Ok(()),
+                })
+                .and_then(|()| match &original_git_file {
+                    Some(content) => fs.write(&old_path.join(GIT_DIR), content),
+                    None => Ok(()),
+                })
+                .is_ok();
+            return Err(RenameRepairError {
+                old_path,
+                new_path,
+                reason: e.to_string(),
+                rolled_back,
+            }
+            .into());
         }
 
         // Branch renaming is handled separately by the caller
 
         Ok(new_path)
     }
+}
 
-    /// Gets the ahead/behind count relative to the upstream branch
-    ///
-    /// # Arguments
-    ///
-    /// * `repo` - The repository to check
-    ///
-    /// # Returns
-    ///
-    /// A tuple of (ahead, behind) counts:
-    /// - `ahead`: Number of commits ahead of upstream
-    /// - `behind`: Number of commits behind upstream
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if:
-    /// - Not on a branch (detached HEAD)
-    /// - No upstream branch is configured
-    /// - Cannot compute the graph difference
-    #[allow(dead_code)]
-    fn get_ahead_behind(&self, repo: &Repository) -> Result<(usize, usize)> {
-        let head = repo.head()?;
-        if !head.is_branch() {
-            return Err(anyhow!("Not on a branch"));
-        }
-
-        let local_oid = head.target().ok_or_else(|| anyhow!("No target"))?;
-        let branch_name = head.shorthand().ok_or_else(|| anyhow!("No branch name"))?;
+/// A worktree rename whose Git metadata could not be brought up to date
+///
+/// Returned by [`GitWorktreeManager::rename_worktree`] after the directory
+/// was moved but neither `git worktree repair` nor rewriting the metadata
+/// directly worked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenameRepairError {
+    /// Path of the worktree before the rename
+    pub old_path: PathBuf,
+    /// Path the worktree was renamed to
+    pub new_path: PathBuf,
+    /// Why the metadata could not be updated
+    pub reason: String,
+    /// Whether the directory and metadata are back at `old_path`
+    pub rolled_back: bool,
+}
 
-        // Try to find upstream branch
-        let upstream_name = format!("{GIT_ORIGIN}{branch_name}");
-        if let Ok(upstream) = repo.find_reference(&format!("{GIT_REFS_REMOTES}{upstream_name}")) {
-            let upstream_oid = upstream.target().ok_or_else(|| anyhow!("No target"))?;
-            let (ahead, behind) = repo.graph_ahead_behind(local_oid, upstream_oid)?;
-            Ok((ahead, behind))
+impl std::fmt::Display for RenameRepairError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = if self.rolled_back {
+            GIT_RENAME_ROLLED_BACK
+                .replacen("{}", &self.reason, 1)
+                .replacen("{}", &self.old_path.display().to_string(), 1)
         } else {
-            Err(anyhow!("No upstream branch"))
-        }
+            GIT_RENAME_ROLLBACK_FAILED
+                .replacen("{}", &self.reason, 1)
+                .replacen("{}", &self.new_path.display().to_string(), 1)
+        };
+        write!(f, "{message}")
     }
 }
 
-/// Status information for a worktree
-///
-/// This struct is used internally to collect status information
-/// about a worktree in a single pass.
-pub(crate) struct WorktreeStatus {
-    has_changes: bool,
-    last_commit: Option<CommitInfo>,
-    ahead_behind: Option<(usize, usize)>,
+impl std::error::Error for RenameRepairError {}
+
+/// Runs `git worktree repair` from `git_dir`
+fn run_worktree_repair(git_dir: &Path) -> Result<()> {
+    let output = std::process::Command::new(GIT_CMD)
+        .current_dir(git_dir)
+        .args([GIT_WORKTREE, GIT_REPAIR])
+        .output()?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "git worktree repair failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
 }
 
-/// Gets the status information for a worktree
-///
-/// This function opens the worktree repository and collects various
-/// status information. It's designed to be called from multiple threads
-/// in parallel.
-///
-/// # Arguments
-///
-/// * `path` - The filesystem path to the worktree
-///
-/// # Returns
-///
-/// A [`WorktreeStatus`] struct with the collected information.
-/// If the repository cannot be opened, returns a status with all
-/// fields set to their default/empty values.
-///
-/// # Performance
+/// Points the metadata of a renamed worktree at `new_path`
 ///
-/// This function is optimized for speed over completeness. Some
-/// expensive operations (like ahead/behind calculation) are skipped.
-fn get_worktree_status(path: &Path) -> WorktreeStatus {
-    if let Ok(repo) = Repository::open(path) {
-        let has_changes = repo
-            .statuses(Some(
-                git2::StatusOptions::new()
-                    .include_untracked(true)
-                    .include_ignored(false),
-            ))
-            .map(|s| !s.is_empty())
-            .unwrap_or(false);
+/// Writes `<metadata>/gitdir` with the new location and the worktree's
+/// `.git` file with the metadata directory, which keeps its old name.
+fn write_rename_metadata(
+    fs: &dyn FileSystem,
+    worktree_git_dir: &Path,
+    new_path: &Path,
+) -> Result<()> {
+    // Update the gitdir file to point to the new path
+    let gitdir_file = worktree_git_dir.join(GIT_FILE_GITDIR);
+    if fs.exists(&gitdir_file) {
+        let new_path_str = new_path.display();
+        fs.write(&gitdir_file, &format!("{new_path_str}{GIT_GITDIR_SUFFIX}"))?;
+    }
 
-        let last_commit = repo
-            .head()
-            .ok()
-            .and_then(|h| h.peel_to_commit().ok())
-            .map(|commit| {
-                let time = chrono::DateTime::from_timestamp(commit.time().seconds(), 0)
-                    .unwrap_or_default()
-                    .format(TIME_FORMAT)
-                    .to_string();
+    // Point the .git file back to the original metadata directory name
+    let git_file_path = new_path.join(GIT_DIR);
+    if fs.exists(&git_file_path) {
+        let git_dir_str = worktree_git_dir.display();
+        fs.write(
+            &git_file_path,
+            &format!("{GIT_GITDIR_PREFIX}{git_dir_str}\n"),
+        )?;
+    }
+    Ok(())
+}
 
-                CommitInfo {
-                    id: commit.id().to_string()[..COMMIT_ID_SHORT_LENGTH].to_string(),
-                    message: commit.summary().unwrap_or(DEFAULT_MESSAGE_NONE).to_string(),
-                    author: commit
-                        .author()
-                        .name()
-                        .unwrap_or(DEFAULT_AUTHOR_UNKNOWN)
-                        .to_string(),
-                    time,
-                }
-            });
+/// Checks that the metadata of a renamed worktree is consistent
+///
+/// Both files must point at each other and the worktree must open as a
+/// repository at `new_path`.
+fn verify_rename_metadata(
+    fs: &dyn FileSystem,
+    worktree_git_dir: &Path,
+    new_path: &Path,
+) -> Result<()> {
+    let gitdir = fs.read_to_string(&worktree_git_dir.join(GIT_FILE_GITDIR))?;
+    if Path::new(gitdir.trim()) != new_path.join(GIT_DIR) {
+        return Err(anyhow!("{GIT_FILE_GITDIR} points to {}", gitdir.trim()));
+    }
+    let git_file = fs.read_to_string(&new_path.join(GIT_DIR))?;
+    if git_file
+        .trim()
+        .strip_prefix(GIT_GITDIR_PREFIX)
+        .map(Path::new)
+        != Some(worktree_git_dir)
+    {
+        return Err(anyhow!("{GIT_DIR} points to {}", git_file.trim()));
+    }
+    Repository::open(new_path)?;
+    Ok(())
+}
+
+/// Uncommitted changes in a worktree, counted per file
+///
+/// A file staged and then modified again counts as both staged and
+/// unstaged. Ignored files are not counted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChangeCounts {
+    /// Files with changes in the index
+    pub staged: usize,
+    /// Tracked files with changes not in the index, including conflicts
+    pub unstaged: usize,
+    /// Files Git does not track yet
+    pub untracked: usize,
+}
+
+impl ChangeCounts {
+    /// Pure business logic for counting changes from `git status` entries
+    pub fn from_statuses(statuses: impl IntoIterator<Item = git2::Status>) -> Self {
+        let staged = git2::Status::INDEX_NEW
+            | git2::Status::INDEX_MODIFIED
+            | git2::Status::INDEX_DELETED
+            | git2::Status::INDEX_RENAMED
+            | git2::Status::INDEX_TYPECHANGE;
+        let unstaged = git2::Status::WT_MODIFIED
+            | git2::Status::WT_DELETED
+            | git2::Status::WT_RENAMED
+            | git2::Status::WT_TYPECHANGE
+            | git2::Status::CONFLICTED;
+
+        let mut counts = Self::default();
+        for status in statuses {
+            if status.intersects(staged) {
+                counts.staged += 1;
+            }
+            if status.intersects(unstaged) {
+                counts.unstaged += 1;
+            }
+            if status.contains(git2::Status::WT_NEW) {
+                counts.untracked += 1;
+            }
+        }
+        counts
+    }
+
+    /// Whether there is nothing to commit
+    pub fn is_clean(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Status information for a worktree
+///
+/// Collected in a single pass by [`get_worktree_status`].
+#[derive(Debug, Default)]
+pub struct WorktreeStatus {
+    /// Staged, unstaged and untracked file counts
+    pub changes: ChangeCounts,
+    /// Information about the commit checked out
+    pub last_commit: Option<CommitInfo>,
+    /// Number of commits ahead and behind the upstream branch
+    pub ahead_behind: Option<(usize, usize)>,
+}
+
+impl WorktreeStatus {
+    /// Whether the worktree has uncommitted changes, including untracked files
+    pub fn has_changes(&self) -> bool {
+        !self.changes.is_clean()
+    }
+}
+
+/// Returns how long to wait for worktree status, from `[performance]`
+pub(crate) fn status_timeout() -> Duration {
+    Config::load()
+        .map(|config| config.performance.status_timeout())
+        .unwrap_or_else(|_| Duration::from_millis(DEFAULT_STATUS_TIMEOUT_MS))
+}
+
+/// Prunes a worktree's metadata and working directory
+fn prune_worktree(worktree: &git2::Worktree) -> Result<()> {
+    worktree.prune(Some(
+        &mut git2::WorktreePruneOptions::new()
+            .valid(true)
+            .working_tree(true),
+    ))?;
+    Ok(())
+}
+
+/// Checks a repository for uncommitted changes, including untracked files
+fn repo_has_changes(repo: &Repository) -> bool {
+    repo.statuses(Some(
+        git2::StatusOptions::new()
+            .include_untracked(true)
+            .include_ignored(false),
+    ))
+    .map(|s| !s.is_empty())
+    .unwrap_or(false)
+}
+
+/// Checks the worktree at `path` for uncommitted changes
+fn has_uncommitted_changes(path: &Path) -> bool {
+    Repository::open(path)
+        .map(|repo| repo_has_changes(&repo))
+        .unwrap_or(false)
+}
+
+/// Counts the uncommitted changes of a repository
+fn repo_change_counts(repo: &Repository) -> ChangeCounts {
+    repo.statuses(Some(
+        git2::StatusOptions::new()
+            .include_untracked(true)
+            .recurse_untracked_dirs(true)
+            .include_ignored(false),
+    ))
+    .map(|statuses| ChangeCounts::from_statuses(statuses.iter().map(|entry| entry.status())))
+    .unwrap_or_default()
+}
+
+/// Checks the worktree at `path` for changes outside the `subdir` directory
+///
+/// Untracked files count as changes; ignored files do not. Used before
+/// moving a checkout whose `subdir` already holds linked worktrees.
+pub fn has_changes_outside(path: &Path, subdir: &str) -> bool {
+    let prefix = format!("{subdir}/");
+    Repository::open(path)
+        .and_then(|repo| {
+            let statuses = repo.statuses(Some(
+                git2::StatusOptions::new()
+                    .include_untracked(true)
+                    .include_ignored(false),
+            ))?;
+            Ok(statuses
+                .iter()
+                .any(|entry| !entry.path().is_some_and(|p| p.starts_with(&prefix))))
+        })
+        .unwrap_or(false)
+}
+
+/// Gets the status information for a worktree
+///
+/// This function opens the worktree repository and collects various
+/// status information. It's designed to be called from multiple threads
+/// in parallel.
+///
+/// # Arguments
+///
+/// * `path` - The filesystem path to the worktree
+/// * `include_ahead_behind` - Whether to count commits ahead/behind upstream
+///
+/// # Returns
+///
+/// A [`WorktreeStatus`] struct with the collected information.
+/// If the repository cannot be opened, returns a status with all
+/// fields set to their default/empty values.
+///
+/// # Performance
+///
+/// This function is optimized for speed over completeness. The
+/// ahead/behind calculation is skipped unless requested.
+pub fn get_worktree_status(path: &Path, include_ahead_behind: bool) -> WorktreeStatus {
+    if let Ok(repo) = Repository::open(path) {
+        let changes = repo_change_counts(&repo);
+
+        let last_commit = repo
+            .head()
+            .ok()
+            .and_then(|h| h.peel_to_commit().ok())
+            .map(|commit| commit_info(&commit));
+
+        let ahead_behind = if include_ahead_behind {
+            get_ahead_behind(&repo).ok()
+        } else {
+            None
+        };
 
         WorktreeStatus {
-            has_changes,
+            changes,
             last_commit,
-            ahead_behind: None, // Skip for performance
+            ahead_behind,
         }
     } else {
-        WorktreeStatus {
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
-        }
+        WorktreeStatus::default()
+    }
+}
+
+/// Builds the [`CommitInfo`] shown for `commit`
+fn commit_info(commit: &git2::Commit) -> CommitInfo {
+    let time = chrono::DateTime::from_timestamp(commit.time().seconds(), 0)
+        .unwrap_or_default()
+        .format(TIME_FORMAT)
+        .to_string();
+    let full_id = commit.id().to_string();
+
+    CommitInfo {
+        id: full_id[..COMMIT_ID_SHORT_LENGTH].to_string(),
+        full_id,
+        message: commit.summary().unwrap_or(DEFAULT_MESSAGE_NONE).to_string(),
+        author: commit
+            .author()
+            .name()
+            .unwrap_or(DEFAULT_AUTHOR_UNKNOWN)
+            .to_string(),
+        time,
+    }
+}
+
+/// Lists the last `limit` commits reachable from the HEAD of the worktree
+/// at `path`, newest first
+///
+/// # Errors
+///
+/// Returns an error if `path` is not a Git worktree or its history cannot
+/// be walked. A worktree without commits yields an empty list.
+pub fn recent_commits(path: &Path, limit: usize) -> Result<Vec<CommitInfo>> {
+    let repo = Repository::open(path)?;
+    let head = match repo.head() {
+        Ok(head) => head.peel_to_commit()?,
+        Err(_) => return Ok(Vec::new()),
+    };
+
+    let mut revwalk = repo.revwalk()?;
+    revwalk.push(head.id())?;
+    revwalk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::TIME)?;
+    revwalk
+        .take(limit)
+        .map(|oid| Ok(commit_info(&repo.find_commit(oid?)?)))
+        .collect()
+}
+
+/// Gets the ahead/behind count relative to the upstream branch
+///
+/// Uses the branch's configured upstream, falling back to the branch of the
+/// same name on `origin`.
+///
+/// # Arguments
+///
+/// * `repo` - The repository to check
+///
+/// # Returns
+///
+/// A tuple of (ahead, behind) counts:
+/// - `ahead`: Number of commits ahead of upstream
+/// - `behind`: Number of commits behind upstream
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - Not on a branch (detached HEAD)
+/// - No upstream branch is configured
+/// - Cannot compute the graph difference
+fn get_ahead_behind(repo: &Repository) -> Result<(usize, usize)> {
+    let head = repo.head()?;
+    if !head.is_branch() {
+        return Err(anyhow!("Not on a branch"));
     }
+
+    let local_oid = head.target().ok_or_else(|| anyhow!("No target"))?;
+    let branch_name = head.shorthand().ok_or_else(|| anyhow!("No branch name"))?;
+
+    // Prefer the configured upstream, then origin/<branch>
+    let upstream_oid = repo
+        .find_branch(branch_name, BranchType::Local)
+        .and_then(|branch| branch.upstream())
+        .ok()
+        .and_then(|upstream| upstream.get().target())
+        .or_else(|| {
+            repo.find_reference(&format!("{GIT_REFS_REMOTES}{GIT_ORIGIN}{branch_name}"))
+                .ok()
+                .and_then(|reference| reference.target())
+        })
+        .ok_or_else(|| anyhow!("No upstream branch"))?;
+
+    let (ahead, behind) = repo.graph_ahead_behind(local_oid, upstream_oid)?;
+    Ok((ahead, behind))
 }
 
 /// Information about a Git worktree
 ///
 /// This struct contains all the relevant information about a worktree
-/// that is displayed in the UI or used for decision making.
-#[derive(Debug, Clone)]
+/// that is displayed in the UI or used for decision making. It serializes
+/// to the object printed by `gw list --json`.
+#[derive(Debug, Clone, Serialize)]
 pub struct WorktreeInfo {
     /// The display name of the worktree (derived from the directory name)
     pub name: String,
     /// The internal Git name of the worktree (from .git/worktrees/)
+    #[serde(skip)]
     pub git_name: String,
     /// The absolute filesystem path to the worktree
     pub path: PathBuf,
     /// The current branch name or "detached" if in detached HEAD state
     pub branch: String,
     /// Whether the worktree is locked (prevents deletion)
-    #[allow(dead_code)]
     pub is_locked: bool,
+    /// Reason given when the worktree was locked, if any
+    pub lock_reason: Option<String>,
     /// Whether this is the currently active worktree
     pub is_current: bool,
     /// Whether the worktree has uncommitted changes
@@ -1637,25 +2835,150 @@ pub struct WorktreeInfo {
     pub last_commit: Option<CommitInfo>,
     /// Number of commits ahead and behind the upstream branch
     #[allow(dead_code)]
+    #[serde(skip)]
     pub ahead_behind: Option<(usize, usize)>, // (ahead, behind)
+    /// Whether the worktree directory is missing on disk
+    ///
+    /// Git still has metadata for the worktree; `git worktree prune`
+    /// removes it.
+    pub is_prunable: bool,
+    /// When the worktree was created
+    ///
+    /// Read from the marker written by git-workers on creation, or the
+    /// directory's modification time for worktrees created otherwise.
+    pub created_at: Option<DateTime<Utc>>,
+    /// Whether gathering the status timed out
+    ///
+    /// `has_changes`, `last_commit` and `ahead_behind` are unset in that
+    /// case; see `[performance] status_timeout_ms`.
+    pub status_unknown: bool,
+    /// Whether this is the main worktree (the repository's own working directory)
+    ///
+    /// The main worktree is never offered for deletion or renaming, even
+    /// from another worktree: removing it breaks every linked worktree.
+    pub is_main: bool,
+}
+
+impl WorktreeInfo {
+    /// Creates a worktree named `name` for tests
+    ///
+    /// The path is `/tmp/<name>`, the branch is `name` and every flag is
+    /// off. Set the fields a test cares about with struct update syntax:
+    /// `WorktreeInfo { is_current: true, ..WorktreeInfo::test("main") }`.
+    pub fn test(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            git_name: name.to_string(),
+            path: PathBuf::from(format!("/tmp/{name}")),
+            branch: name.to_string(),
+            is_locked: false,
+            lock_reason: None,
+            is_current: false,
+            has_changes: false,
+            last_commit: None,
+            ahead_behind: None,
+            is_prunable: false,
+            created_at: None,
+            status_unknown: false,
+            is_main: false,
+        }
+    }
+}
+
+/// A remote-tracking branch, e.g. `upstream/main`
+///
+/// Ordered by remote first, so a sorted list is grouped by remote.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RemoteBranch {
+    /// Name of the remote, e.g. `origin` or `upstream`
+    pub remote: String,
+    /// Branch name without the remote prefix
+    pub branch: String,
+}
+
+impl RemoteBranch {
+    /// The name as git shows it, e.g. `upstream/main`
+    pub fn full_name(&self) -> String {
+        format!("{}/{}", self.remote, self.branch)
+    }
+}
+
+/// Keys the upstream of local `branch` in `repo`, e.g. `upstream/feature`
+///
+/// The name is the one git2 reports, so it carries the actual remote name.
+/// A key that is already taken (a local branch of that name, or another
+/// branch tracking the same upstream) is left alone.
+fn insert_upstream(
+    map: &mut std::collections::HashMap<String, String>,
+    repo: &Repository,
+    branch: &str,
+    worktree_name: &str,
+) {
+    let Ok(upstream) = repo
+        .find_branch(branch, BranchType::Local)
+        .and_then(|branch| branch.upstream())
+    else {
+        return;
+    };
+    if let Ok(Some(upstream_name)) = upstream.name() {
+        map.entry(upstream_name.to_string())
+            .or_insert_with(|| worktree_name.to_string());
+    }
+}
+
+/// Pure business logic for removing a known remote prefix from a branch name
+///
+/// Returns the branch name when `name` starts with `<remote>/` for one of
+/// `remotes`, e.g. `upstream/main` → `main`.
+pub fn strip_remote_prefix<'a>(name: &'a str, remotes: &[String]) -> Option<&'a str> {
+    remotes.iter().find_map(|remote| {
+        name.strip_prefix(remote.as_str())
+            .and_then(|rest| rest.strip_prefix('/'))
+    })
+}
+
+/// Pure business logic for the remote ref and local branch of a pull request
+///
+/// Fills `{{number}}` in `template` (e.g. `merge-requests/{{number}}/head`
+/// for GitLab) and names the branch `pr-<number>`.
+///
+/// # Errors
+///
+/// Returns an error if `template` has no `{{number}}` placeholder.
+pub fn pr_refspec(template: &str, number: u32) -> Result<(String, String)> {
+    if !template.contains(PR_NUMBER_PLACEHOLDER) {
+        return Err(anyhow!(ERROR_PR_REFSPEC_NO_NUMBER));
+    }
+    let number = number.to_string();
+    Ok((
+        template.replace(PR_NUMBER_PLACEHOLDER, &number),
+        format!("{PR_BRANCH_PREFIX}{number}"),
+    ))
+}
+
+/// Pure business logic for ordering branches by commit time, newest first
+///
+/// `commit_time` returns the time of a branch's tip commit in seconds, and
+/// is called once per branch. Branches without a time go last; ties keep
+/// their current order.
+pub fn sort_by_commit_time(branches: &mut [String], commit_time: impl Fn(&str) -> Option<i64>) {
+    branches.sort_by_cached_key(|name| std::cmp::Reverse(commit_time(name)));
 }
 
 /// Information about a Git commit
 ///
 /// Contains basic information about a commit for display purposes.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CommitInfo {
     /// Short commit ID (first 8 characters)
-    #[allow(dead_code)]
     pub id: String,
+    /// Full commit ID
+    pub full_id: String,
     /// First line of the commit message
-    #[allow(dead_code)]
     pub message: String,
     /// Commit author name
-    #[allow(dead_code)]
     pub author: String,
     /// Formatted commit time (YYYY-MM-DD HH:MM)
-    #[allow(dead_code)]
     pub time: String,
 }
 
@@ -1689,6 +3012,37 @@ pub struct CommitInfo {
 /// }
 /// ```
 #[allow(dead_code)]
+/// Records the creation time of a new worktree in its Git metadata directory
+///
+/// Best effort: without the marker the age falls back to the directory's
+/// modification time.
+fn write_created_marker(worktree_path: &Path) {
+    if let Ok(repo) = Repository::open(worktree_path) {
+        let _ = fs::write(
+            repo.path().join(WORKTREE_CREATED_MARKER),
+            Utc::now().to_rfc3339(),
+        );
+    }
+}
+
+/// Reads when a worktree was created
+///
+/// Uses the ISO-8601 marker in `metadata_dir` (`.git/worktrees/<name>`) and
+/// falls back to the modification time of `path`. Returns `None` if neither
+/// can be read, e.g. when the worktree directory is missing.
+pub fn worktree_created_at(metadata_dir: &Path, path: &Path) -> Option<DateTime<Utc>> {
+    fs::read_to_string(metadata_dir.join(WORKTREE_CREATED_MARKER))
+        .ok()
+        .and_then(|marker| DateTime::parse_from_rfc3339(marker.trim()).ok())
+        .map(|time| time.with_timezone(&Utc))
+        .or_else(|| {
+            fs::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .ok()
+                .map(DateTime::<Utc>::from)
+        })
+}
+
 pub fn list_worktrees() -> Result<Vec<String>> {
     let manager = GitWorktreeManager::new()?;
     let worktrees = manager.list_worktrees()?;
@@ -1702,7 +3056,8 @@ pub fn list_worktrees() -> Result<Vec<String>> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::path::PathBuf;
+    use crate::constants::DEFAULT_PR_REFSPEC;
+    use tempfile::TempDir;
 
     #[test]
     fn test_find_common_parent_empty() {
@@ -1714,15 +3069,9 @@ mod tests {
     #[test]
     fn test_find_common_parent_single() {
         let worktree = WorktreeInfo {
-            name: "main".to_string(),
-            git_name: "main".to_string(),
             path: PathBuf::from("/home/user/project"),
-            branch: "main".to_string(),
             is_current: true,
-            is_locked: false,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
+            ..WorktreeInfo::test("main")
         };
 
         let worktrees = vec![worktree];
@@ -1736,26 +3085,13 @@ mod tests {
     fn test_find_common_parent_multiple() {
         let worktrees = vec![
             WorktreeInfo {
-                name: "main".to_string(),
-                git_name: "main".to_string(),
                 path: PathBuf::from("/home/user/project"),
-                branch: "main".to_string(),
                 is_current: true,
-                is_locked: false,
-                has_changes: false,
-                last_commit: None,
-                ahead_behind: None,
+                ..WorktreeInfo::test("main")
             },
             WorktreeInfo {
-                name: "feature".to_string(),
-                git_name: "feature".to_string(),
                 path: PathBuf::from("/home/user/project-feature"),
-                branch: "feature".to_string(),
-                is_current: false,
-                is_locked: false,
-                has_changes: false,
-                last_commit: None,
-                ahead_behind: None,
+                ..WorktreeInfo::test("feature")
             },
         ];
 
@@ -1764,22 +3100,742 @@ mod tests {
         assert_eq!(result.unwrap(), PathBuf::from("/home/user"));
     }
 
+    fn worktree_at(name: &str, path: &str) -> WorktreeInfo {
+        WorktreeInfo {
+            path: PathBuf::from(path),
+            ..WorktreeInfo::test(name)
+        }
+    }
+
+    #[test]
+    fn test_find_layout_outliers_consistent() {
+        let worktrees = vec![
+            worktree_at("a", "/repo/worktrees/a"),
+            worktree_at("b", "/repo/worktrees/b"),
+        ];
+        assert!(find_layout_outliers(&worktrees).is_none());
+        assert!(find_layout_outliers(&[]).is_none());
+    }
+
+    #[test]
+    fn test_find_layout_outliers_scattered() {
+        let worktrees = vec![
+            worktree_at("a", "/repo/worktrees/a"),
+            worktree_at("b", "/repo/worktrees/b"),
+            worktree_at("c", "/c"),
+            worktree_at("d", "/repo/custom/d"),
+        ];
+
+        let report = find_layout_outliers(&worktrees).unwrap();
+        assert_eq!(report.expected_parent, PathBuf::from("/repo/worktrees"));
+        let names: Vec<&str> = report.outliers.iter().map(|w| w.name.as_str()).collect();
+        assert_eq!(names, vec!["c", "d"]);
+    }
+
+    #[test]
+    fn test_find_layout_outliers_tie_prefers_first() {
+        let worktrees = vec![
+            worktree_at("a", "/same-level/a"),
+            worktree_at("b", "/repo/worktrees/b"),
+        ];
+
+        let report = find_layout_outliers(&worktrees).unwrap();
+        assert_eq!(report.expected_parent, PathBuf::from("/same-level"));
+        assert_eq!(report.outliers.len(), 1);
+        assert_eq!(report.outliers[0].name, "b");
+    }
+
+    #[test]
+    fn test_get_ahead_behind_against_origin() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let repo = Repository::init(temp_dir.path())?;
+        let sig = git2::Signature::now("Test", "test@example.com")?;
+        let tree_id = repo.index()?.write_tree()?;
+        let tree = repo.find_tree(tree_id)?;
+        let base = repo.commit(Some("HEAD"), &sig, &sig, "base", &tree, &[])?;
+        let base_commit = repo.find_commit(base)?;
+        repo.commit(Some("HEAD"), &sig, &sig, "local", &tree, &[&base_commit])?;
+
+        // Without an upstream there is nothing to compare against
+        assert!(get_ahead_behind(&repo).is_err());
+        assert!(get_worktree_status(temp_dir.path(), true)
+            .ahead_behind
+            .is_none());
+
+        let branch = repo.head()?.shorthand().unwrap().to_string();
+        repo.reference(
+            &format!("{GIT_REFS_REMOTES}{GIT_ORIGIN}{branch}"),
+            base,
+            true,
+            "test upstream",
+        )?;
+        assert_eq!(get_ahead_behind(&repo)?, (1, 0));
+        assert_eq!(
+            get_worktree_status(temp_dir.path(), true).ahead_behind,
+            Some((1, 0))
+        );
+        assert!(get_worktree_status(temp_dir.path(), false)
+            .ahead_behind
+            .is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_recent_commits() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let repo = Repository::init(temp_dir.path())?;
+        assert!(recent_commits(temp_dir.path(), 5)?.is_empty());
+
+        let sig = git2::Signature::now("Test", "test@example.com")?;
+        let tree_id = repo.index()?.write_tree()?;
+        let tree = repo.find_tree(tree_id)?;
+        let mut parent = None;
+        for n in 1..=7 {
+            let parents: Vec<git2::Commit> = parent.iter().cloned().collect();
+            let parents: Vec<&git2::Commit> = parents.iter().collect();
+            let id = repo.commit(
+                Some("HEAD"),
+                &sig,
+                &sig,
+                &format!("commit {n}"),
+                &tree,
+                &parents,
+            )?;
+            parent = Some(repo.find_commit(id)?);
+        }
+
+        let commits = recent_commits(temp_dir.path(), 5)?;
+        let messages: Vec<&str> = commits.iter().map(|c| c.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec!["commit 7", "commit 6", "commit 5", "commit 4", "commit 3"]
+        );
+        assert_eq!(commits[0].full_id, parent.unwrap().id().to_string());
+        assert!(commits[0].full_id.starts_with(&commits[0].id));
+        Ok(())
+    }
+
+    #[test]
+    fn test_worktree_created_at_marker_and_fallback() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let metadata_dir = temp_dir.path().join("meta");
+        let worktree_dir = temp_dir.path().join("worktree");
+        fs::create_dir_all(&metadata_dir)?;
+        fs::create_dir_all(&worktree_dir)?;
+
+        // Falls back to the directory's modification time
+        assert!(worktree_created_at(&metadata_dir, &worktree_dir).is_some());
+        assert!(worktree_created_at(&metadata_dir, &temp_dir.path().join("gone")).is_none());
+
+        fs::write(
+            metadata_dir.join(WORKTREE_CREATED_MARKER),
+            "2024-01-02T03:04:05+00:00\n",
+        )?;
+        let created = worktree_created_at(&metadata_dir, &worktree_dir).unwrap();
+        assert_eq!(created.to_rfc3339(), "2024-01-02T03:04:05+00:00");
+        Ok(())
+    }
+
+    /// Real filesystem whose writes fail when the contents mention `path`
+    struct FailWritesMentioning {
+        path: String,
+        real: crate::filesystem::RealFileSystem,
+    }
+
+    impl FileSystem for FailWritesMentioning {
+        fn create_dir_all(&self, path: &Path) -> Result<()> {
+            self.real.create_dir_all(path)
+        }
+        fn remove_file(&self, path: &Path) -> Result<()> {
+            self.real.remove_file(path)
+        }
+        fn remove_dir_all(&self, path: &Path) -> Result<()> {
+            self.real.remove_dir_all(path)
+        }
+        fn read_to_string(&self, path: &Path) -> Result<String> {
+            self.real.read_to_string(path)
+        }
+        fn write(&self, path: &Path, contents: &str) -> Result<()> {
+            if contents.contains(&self.path) {
+                return Err(anyhow!("Permission denied"));
+            }
+            self.real.write(path, contents)
+        }
+        fn copy(&self, from: &Path, to: &Path) -> Result<u64> {
+            self.real.copy(from, to)
+        }
+        fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+            self.real.rename(from, to)
+        }
+        fn read_dir(&self, path: &Path) -> Result<Vec<fs::DirEntry>> {
+            self.real.read_dir(path)
+        }
+        fn exists(&self, path: &Path) -> bool {
+            self.real.exists(path)
+        }
+        fn is_file(&self, path: &Path) -> bool {
+            self.real.is_file(path)
+        }
+        fn is_dir(&self, path: &Path) -> bool {
+            self.real.is_dir(path)
+        }
+        fn open_with_options(&self, path: &Path, options: &OpenOptions) -> Result<File> {
+            self.real.open_with_options(path, options)
+        }
+        fn metadata(&self, path: &Path) -> Result<fs::Metadata> {
+            self.real.metadata(path)
+        }
+        fn symlink_metadata(&self, path: &Path) -> Result<fs::Metadata> {
+            self.real.symlink_metadata(path)
+        }
+    }
+
+    /// A repository with one commit and a linked worktree named `feature`
+    fn repo_with_feature_worktree(temp_dir: &Path) -> Result<(GitWorktreeManager, PathBuf)> {
+        let repo_path = temp_dir.join("repo");
+        let repo = Repository::init(&repo_path)?;
+        let sig = git2::Signature::now("Test", "test@example.com")?;
+        let tree_id = repo.index()?.write_tree()?;
+        let tree = repo.find_tree(tree_id)?;
+        repo.commit(Some("HEAD"), &sig, &sig, "initial", &tree, &[])?;
+        let worktree_path = temp_dir.join("feature");
+        repo.worktree("feature", &worktree_path, None)?;
+        Ok((
+            GitWorktreeManager::new_from_path(&repo_path)?,
+            worktree_path,
+        ))
+    }
+
+    fn failed_repair(_: &Path) -> Result<()> {
+        Err(anyhow!("git worktree repair failed: injected"))
+    }
+
+    #[test]
+    fn test_rename_recovers_from_failed_repair() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let (manager, _) = repo_with_feature_worktree(temp_dir.path())?;
+        let fs = crate::filesystem::RealFileSystem::new();
+
+        let new_path =
+            manager.rename_worktree_with_repair("feature", "renamed", &fs, &failed_repair)?;
+
+        // The metadata was rewritten directly, so Git follows the new path
+        assert_eq!(new_path, temp_dir.path().join("renamed"));
+        assert!(Repository::open(&new_path).is_ok());
+        let worktree = manager.repo.find_worktree("feature")?;
+        assert_eq!(worktree.path(), new_path.as_path());
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename_rolls_back_when_metadata_cannot_be_fixed() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let (manager, old_path) = repo_with_feature_worktree(temp_dir.path())?;
+        let new_path = temp_dir.path().join("renamed");
+        let fs = FailWritesMentioning {
+            path: new_path.display().to_string(),
+            real: crate::filesystem::RealFileSystem::new(),
+        };
+
+        let error = manager
+            .rename_worktree_with_repair("feature", "renamed", &fs, &failed_repair)
+            .unwrap_err();
+        let error = error.downcast_ref::<RenameRepairError>().unwrap();
+        assert!(error.rolled_back);
+        assert_eq!(error.old_path, old_path);
+
+        // Everything is back where it was
+        assert!(!new_path.exists());
+        assert!(Repository::open(&old_path).is_ok());
+        let worktree = manager.repo.find_worktree("feature")?;
+        assert_eq!(worktree.path(), old_path.as_path());
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_worktrees_cached_until_invalidated() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let repo_path = temp_dir.path().join("repo");
+        let repo = Repository::init(&repo_path)?;
+        let sig = git2::Signature::now("Test", "test@example.com")?;
+        let tree_id = repo.index()?.write_tree()?;
+        let tree = repo.find_tree(tree_id)?;
+        repo.commit(Some("HEAD"), &sig, &sig, "initial", &tree, &[])?;
+        let manager = GitWorktreeManager::new_from_path(&repo_path)?;
+        let names = |worktrees: Vec<WorktreeInfo>| -> Vec<String> {
+            worktrees
+                .into_iter()
+                .filter(|w| !w.is_main)
+                .map(|w| w.name)
+                .collect()
+        };
+
+        repo.worktree("alpha", &temp_dir.path().join("alpha"), None)?;
+        assert_eq!(names(manager.list_worktrees_cached()?), vec!["alpha"]);
+
+        // Changes made behind the manager's back need an explicit refresh
+        repo.worktree("beta", &temp_dir.path().join("beta"), None)?;
+        assert_eq!(names(manager.list_worktrees_cached()?), vec!["alpha"]);
+        assert_eq!(names(manager.list_worktrees()?), vec!["alpha", "beta"]);
+        manager.refresh();
+        assert_eq!(
+            names(manager.list_worktrees_cached()?),
+            vec!["alpha", "beta"]
+        );
+
+        // Operations through the manager invalidate the snapshot themselves
+        manager.remove_worktree("alpha")?;
+        assert_eq!(names(manager.list_worktrees_cached()?), vec!["beta"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_worktrees_reads_created_marker() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let repo_path = temp_dir.path().join("repo");
+        let repo = Repository::init(&repo_path)?;
+        let sig = git2::Signature::now("Test", "test@example.com")?;
+        let tree_id = repo.index()?.write_tree()?;
+        let tree = repo.find_tree(tree_id)?;
+        repo.commit(Some("HEAD"), &sig, &sig, "initial", &tree, &[])?;
+
+        let worktree_path = temp_dir.path().join("feature");
+        repo.worktree("feature", &worktree_path, None)?;
+        let before = Utc::now() - chrono::TimeDelta::seconds(1);
+        write_created_marker(&worktree_path);
+
+        let marker = repo_path
+            .join(GIT_DIR)
+            .join(GIT_WORKTREE_DIR)
+            .join("feature")
+            .join(WORKTREE_CREATED_MARKER);
+        assert!(marker.exists());
+
+        let manager = GitWorktreeManager::new_from_path(&repo_path)?;
+        let created = manager.list_worktrees()?[0].created_at.unwrap();
+        assert!(created >= before);
+        Ok(())
+    }
+
+    #[test]
+    fn test_remote_branches_of_every_remote() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let repo_path = temp_dir.path().join("repo");
+        let repo = Repository::init(&repo_path)?;
+        let sig = git2::Signature::now("Test", "test@example.com")?;
+        let tree_id = repo.index()?.write_tree()?;
+        let tree = repo.find_tree(tree_id)?;
+        let commit = repo.commit(Some("HEAD"), &sig, &sig, "initial", &tree, &[])?;
+
+        repo.remote("origin", "https://example.com/me/project.git")?;
+        repo.remote("upstream", "https://example.com/team/project.git")?;
+        for name in ["origin/main", "upstream/main", "upstream/feature/login"] {
+            repo.reference(&format!("{GIT_REFS_REMOTES}{name}"), commit, false, "test")?;
+        }
+        repo.reference_symbolic(
+            &format!("{GIT_REFS_REMOTES}origin/HEAD"),
+            &format!("{GIT_REFS_REMOTES}origin/main"),
+            false,
+            "test",
+        )?;
+
+        let manager = GitWorktreeManager::new_from_path(&repo_path)?;
+        let (_, remote_branches) = manager.list_all_branches()?;
+        let names: Vec<String> = remote_branches
+            .iter()
+            .map(RemoteBranch::full_name)
+            .collect();
+        assert_eq!(
+            names,
+            vec!["origin/main", "upstream/feature/login", "upstream/main"]
+        );
+
+        let login = manager
+            .find_remote_branch("upstream/feature/login")
+            .unwrap();
+        assert_eq!(login.remote, "upstream");
+        assert_eq!(login.branch, "feature/login");
+        assert!(manager.find_remote_branch("feature/login").is_none());
+        assert_eq!(manager.remote_names(), vec!["origin", "upstream"]);
+
+        // A new local branch named after the remote branch is created
+        let worktree_path = temp_dir.path().join("login");
+        manager.create_worktree_with_branch(&worktree_path, "upstream/feature/login")?;
+        assert_eq!(
+            worktree_head_branch(&worktree_path)?.as_deref(),
+            Some("feature/login")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_remote_updates_tracking_ref() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let upstream_path = temp_dir.path().join("upstream");
+        let upstream = Repository::init(&upstream_path)?;
+        let sig = git2::Signature::now("Test", "test@example.com")?;
+        let tree_id = upstream.index()?.write_tree()?;
+        let tree = upstream.find_tree(tree_id)?;
+        let commit = upstream.commit(Some("HEAD"), &sig, &sig, "initial", &tree, &[])?;
+        upstream.branch("feature", &upstream.find_commit(commit)?, false)?;
+
+        let repo_path = temp_dir.path().join("repo");
+        let repo = Repository::init(&repo_path)?;
+        repo.remote("origin", &upstream_path.to_string_lossy())?;
+
+        let manager = GitWorktreeManager::new_from_path(&repo_path)?;
+        assert!(manager.find_remote_branch("origin/feature").is_none());
+        manager.fetch_remote("origin", Some("feature"), None)?;
+        assert!(manager.find_remote_branch("origin/feature").is_some());
+
+        assert!(manager.fetch_remote("nowhere", None, None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_remote_with_depth() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let upstream_path = temp_dir.path().join("upstream");
+        let upstream = Repository::init(&upstream_path)?;
+        let sig = git2::Signature::now("Test", "test@example.com")?;
+        let tree_id = upstream.index()?.write_tree()?;
+        let tree = upstream.find_tree(tree_id)?;
+        let first = upstream.commit(Some("HEAD"), &sig, &sig, "first", &tree, &[])?;
+        let first = upstream.find_commit(first)?;
+        let second = upstream.commit(Some("HEAD"), &sig, &sig, "second", &tree, &[&first])?;
+        upstream.branch("feature", &upstream.find_commit(second)?, false)?;
+
+        // Shallow fetches need a URL; plain paths ignore --depth
+        let repo_path = temp_dir.path().join("repo");
+        let repo = Repository::init(&repo_path)?;
+        let url = format!("file://{}", upstream_path.display());
+        repo.remote("origin", &url)?;
+
+        let manager = GitWorktreeManager::new_from_path(&repo_path)?;
+        manager.fetch_remote("origin", Some("feature"), Some(1))?;
+        assert!(manager.find_remote_branch("origin/feature").is_some());
+        assert!(repo.is_shallow());
+        assert!(repo.find_commit(first.id()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_pr_refspec() {
+        assert_eq!(
+            pr_refspec(DEFAULT_PR_REFSPEC, 123).unwrap(),
+            ("pull/123/head".to_string(), "pr-123".to_string())
+        );
+        assert_eq!(
+            pr_refspec("merge-requests/{{number}}/head", 7).unwrap().0,
+            "merge-requests/7/head"
+        );
+        assert!(pr_refspec("pull/head", 7).is_err());
+    }
+
+    #[test]
+    fn test_fetch_pr() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let upstream_path = temp_dir.path().join("upstream");
+        let upstream = Repository::init(&upstream_path)?;
+        let sig = git2::Signature::now("Test", "test@example.com")?;
+        let tree_id = upstream.index()?.write_tree()?;
+        let tree = upstream.find_tree(tree_id)?;
+        let commit = upstream.commit(Some("HEAD"), &sig, &sig, "initial", &tree, &[])?;
+        upstream.reference("refs/pull/7/head", commit, false, "pull request")?;
+
+        let repo_path = temp_dir.path().join("repo");
+        let repo = Repository::init(&repo_path)?;
+        repo.remote(REMOTE_ORIGIN, &upstream_path.to_string_lossy())?;
+
+        let manager = GitWorktreeManager::new_from_path(&repo_path)?;
+        assert_eq!(manager.fetch_pr(7, DEFAULT_PR_REFSPEC, None)?, "pr-7");
+        let branch = repo.find_branch("pr-7", BranchType::Local)?;
+        assert_eq!(branch.get().target(), Some(commit));
+
+        // New commits on the pull request fast-forward the branch
+        let parent = upstream.find_commit(commit)?;
+        let update = upstream.commit(None, &sig, &sig, "update", &tree, &[&parent])?;
+        upstream.reference("refs/pull/7/head", update, true, "pull request")?;
+        manager.fetch_pr(7, DEFAULT_PR_REFSPEC, None)?;
+        let branch = repo.find_branch("pr-7", BranchType::Local)?;
+        assert_eq!(branch.get().target(), Some(update));
+
+        // Local commits are never overwritten by a rewritten pull request
+        let local = repo.find_commit(update)?;
+        let mine = repo.commit(
+            Some("refs/heads/pr-7"),
+            &sig,
+            &sig,
+            "mine",
+            &repo.find_tree(tree_id)?,
+            &[&local],
+        )?;
+        let rewritten = upstream.commit(None, &sig, &sig, "rewritten", &tree, &[&parent])?;
+        upstream.reference("refs/pull/7/head", rewritten, true, "pull request")?;
+        let error = manager
+            .fetch_pr(7, DEFAULT_PR_REFSPEC, None)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.contains("has commits that are not in pull request #7"),
+            "{error}"
+        );
+        let branch = repo.find_branch("pr-7", BranchType::Local)?;
+        assert_eq!(branch.get().target(), Some(mine));
+
+        let error = manager
+            .fetch_pr(8, DEFAULT_PR_REFSPEC, None)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("Pull request #8 not found"), "{error}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_by_commit_time() {
+        let mut branches: Vec<String> = ["alpha", "beta", "gamma", "orphan", "zeta"]
+            .iter()
+            .map(|b| b.to_string())
+            .collect();
+        let times = [("alpha", 100), ("beta", 300), ("gamma", 200), ("zeta", 300)];
+        sort_by_commit_time(&mut branches, |name| {
+            times.iter().find(|(b, _)| *b == name).map(|(_, t)| *t)
+        });
+        // Newest first, ties keep their order, unreadable branches go last
+        assert_eq!(branches, ["beta", "zeta", "gamma", "alpha", "orphan"]);
+    }
+
+    #[test]
+    fn test_strip_remote_prefix() {
+        let remotes = vec!["origin".to_string(), "upstream".to_string()];
+        assert_eq!(strip_remote_prefix("upstream/main", &remotes), Some("main"));
+        assert_eq!(
+            strip_remote_prefix("origin/feature/x", &remotes),
+            Some("feature/x")
+        );
+        assert_eq!(strip_remote_prefix("originals/main", &remotes), None);
+        assert_eq!(strip_remote_prefix("feature/x", &remotes), None);
+    }
+
+    #[test]
+    fn test_list_worktrees_reports_main() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let repo_path = temp_dir.path().join("repo");
+        let repo = Repository::init(&repo_path)?;
+        let sig = git2::Signature::now("Test", "test@example.com")?;
+        let tree_id = repo.index()?.write_tree()?;
+        let tree = repo.find_tree(tree_id)?;
+        repo.commit(Some("HEAD"), &sig, &sig, "initial", &tree, &[])?;
+
+        let worktree_path = temp_dir.path().join("feature");
+        repo.worktree("feature", &worktree_path, None)?;
+
+        // Seen from the linked worktree, the main checkout is listed first
+        let manager = GitWorktreeManager::new_from_path(&worktree_path)?;
+        let worktrees = manager.list_worktrees()?;
+        assert_eq!(worktrees.len(), 2);
+        assert!(worktrees[0].is_main);
+        assert_eq!(worktrees[0].name, "repo");
+        assert_eq!(worktrees[0].path.canonicalize()?, repo_path.canonicalize()?);
+        assert!(!worktrees[0].is_current);
+        assert!(!worktrees[1].is_main);
+        assert_eq!(worktrees[1].name, "feature");
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_worktree_is_prunable() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let repo_path = temp_dir.path().join("repo");
+        let repo = Repository::init(&repo_path)?;
+        let sig = git2::Signature::now("Test", "test@example.com")?;
+        let tree_id = repo.index()?.write_tree()?;
+        let tree = repo.find_tree(tree_id)?;
+        repo.commit(Some("HEAD"), &sig, &sig, "initial", &tree, &[])?;
+
+        let worktree_path = temp_dir.path().join("gone");
+        repo.worktree("gone", &worktree_path, None)?;
+        let manager = GitWorktreeManager::new_from_path(&repo_path)?;
+        assert!(!manager.list_worktrees()?[1].is_prunable);
+
+        fs::remove_dir_all(&worktree_path)?;
+        let worktrees = manager.list_worktrees()?;
+        assert_eq!(worktrees.len(), 2);
+        assert!(!worktrees[0].is_prunable);
+        assert!(worktrees[1].is_prunable);
+        assert_eq!(worktrees[1].branch, DEFAULT_BRANCH_UNKNOWN);
+
+        assert!(!manager.prune_worktrees(false)?.is_empty());
+        assert_eq!(manager.list_worktrees()?.len(), 1);
+        assert!(manager.prune_worktrees(false)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_branch_exists() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let repo = Repository::init(temp_dir.path())?;
+        let sig = git2::Signature::now("Test", "test@example.com")?;
+        let tree_id = repo.index()?.write_tree()?;
+        let tree = repo.find_tree(tree_id)?;
+        let commit = repo.commit(Some("HEAD"), &sig, &sig, "initial", &tree, &[])?;
+        repo.branch("feature/login", &repo.find_commit(commit)?, false)?;
+        let manager = GitWorktreeManager::new_from_path(temp_dir.path())?;
+
+        assert!(manager.branch_exists("feature/login")?);
+        assert!(!manager.branch_exists("feature/logn")?);
+        assert!(!manager.branch_exists("bad..name")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_move_worktree() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let repo_path = temp_dir.path().join("repo");
+        let repo = Repository::init(&repo_path)?;
+        let sig = git2::Signature::now("Test", "test@example.com")?;
+        let tree_id = repo.index()?.write_tree()?;
+        let tree = repo.find_tree(tree_id)?;
+        repo.commit(Some("HEAD"), &sig, &sig, "initial", &tree, &[])?;
+        repo.worktree("feature", &temp_dir.path().join("feature"), None)?;
+        let manager = GitWorktreeManager::new_from_path(&repo_path)?;
+
+        let target = temp_dir.path().join("archive").join("feature");
+        assert_eq!(manager.move_worktree("feature", &target)?, target);
+        let worktrees = manager.list_worktrees()?;
+        assert_eq!(worktrees.len(), 2);
+        assert_eq!(worktrees[1].git_name, "feature");
+        assert_eq!(worktrees[1].path.canonicalize()?, target.canonicalize()?);
+        assert_eq!(worktrees[1].branch, "feature");
+
+        // Taken targets and locked worktrees are refused
+        assert!(manager.move_worktree("feature", &repo_path).is_err());
+        manager.lock_worktree("feature", None)?;
+        let elsewhere = temp_dir.path().join("elsewhere");
+        assert!(manager.move_worktree("feature", &elsewhere).is_err());
+        assert!(!elsewhere.exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_lock_in_cleans_up_held_lock() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let lock_path = temp_dir.path().join(LOCK_FILE_NAME);
+
+        // A process exiting on a signal never runs Drop
+        std::mem::forget(WorktreeLock::acquire(temp_dir.path())?);
+        assert!(lock_path.exists());
+
+        // The signal handler removes the file it was told about, once
+        let slot = Mutex::new(Some(lock_path.clone()));
+        assert_eq!(remove_lock_in(&slot), Some(lock_path.clone()));
+        assert!(!lock_path.exists());
+        assert_eq!(remove_lock_in(&slot), None);
+
+        // So the next operation does not wait for the lock to go stale
+        drop(WorktreeLock::acquire(temp_dir.path())?);
+        assert!(!lock_path.exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_worktree_head_state_and_lock_files() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let repo_path = temp_dir.path().join("repo");
+        let repo = Repository::init(&repo_path)?;
+        assert!(matches!(
+            worktree_head_state(&repo_path)?,
+            HeadState::Branch(_)
+        ));
+        let sig = git2::Signature::now("Test", "test@example.com")?;
+        let tree_id = repo.index()?.write_tree()?;
+        let tree = repo.find_tree(tree_id)?;
+        let commit = repo.commit(Some("HEAD"), &sig, &sig, "initial", &tree, &[])?;
+
+        let on_branch = temp_dir.path().join("feature");
+        repo.worktree("feature", &on_branch, None)?;
+        assert_eq!(
+            worktree_head_state(&on_branch)?,
+            HeadState::Branch("feature".to_string())
+        );
+        repo.find_reference("refs/heads/feature")?.delete()?;
+        assert_eq!(
+            worktree_head_state(&on_branch)?,
+            HeadState::MissingBranch("feature".to_string())
+        );
+
+        let detached = temp_dir.path().join("detached");
+        repo.worktree("detached", &detached, None)?;
+        Repository::open(&detached)?.set_head_detached(commit)?;
+        assert_eq!(worktree_head_state(&detached)?, HeadState::Detached);
+
+        let manager = GitWorktreeManager::new_from_path(&repo_path)?;
+        assert!(manager.worktree_lock_files().is_empty());
+        let lock_path = manager
+            .get_common_dir()
+            .join(GIT_WORKTREE_DIR)
+            .join("feature");
+        fs::write(lock_path.join(LOCK_FILE_NAME), "")?;
+        let locks = manager.worktree_lock_files();
+        assert_eq!(locks.len(), 1);
+        assert!(locks[0].0.starts_with(&lock_path));
+        Ok(())
+    }
+
     #[test]
     fn test_get_worktree_status_nonexistent() {
         let non_existent = PathBuf::from("/nonexistent/path");
-        let status = get_worktree_status(&non_existent);
+        let status = get_worktree_status(&non_existent, false);
 
         // Should return clean status for non-existent paths
-        assert!(!status.has_changes);
+        assert!(!status.has_changes());
         assert!(status.last_commit.is_none());
         assert!(status.ahead_behind.is_none());
     }
 
+    #[test]
+    fn test_get_worktree_status_counts_changes() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let repo = Repository::init(temp_dir.path())?;
+        let sig = git2::Signature::now("Test", "test@example.com")?;
+        fs::write(temp_dir.path().join("a.txt"), "a")?;
+        fs::write(temp_dir.path().join("b.txt"), "b")?;
+        let mut index = repo.index()?;
+        index.add_path(Path::new("a.txt"))?;
+        index.add_path(Path::new("b.txt"))?;
+        index.write()?;
+        let tree = repo.find_tree(index.write_tree()?)?;
+        repo.commit(Some("HEAD"), &sig, &sig, "Initial commit", &tree, &[])?;
+        assert!(!get_worktree_status(temp_dir.path(), false).has_changes());
+
+        // a.txt: staged, then modified again; b.txt: unstaged; two untracked
+        fs::write(temp_dir.path().join("a.txt"), "staged")?;
+        index.add_path(Path::new("a.txt"))?;
+        index.write()?;
+        fs::write(temp_dir.path().join("a.txt"), "modified again")?;
+        fs::write(temp_dir.path().join("b.txt"), "modified")?;
+        fs::create_dir(temp_dir.path().join("new"))?;
+        fs::write(temp_dir.path().join("new/c.txt"), "c")?;
+        fs::write(temp_dir.path().join("new/d.txt"), "d")?;
+
+        let status = get_worktree_status(temp_dir.path(), false);
+        assert!(status.has_changes());
+        assert_eq!(
+            status.changes,
+            ChangeCounts {
+                staged: 1,
+                unstaged: 2,
+                untracked: 2,
+            }
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_get_worktree_status_basic() -> Result<()> {
         // Test basic worktree status with current directory
         let current_dir = std::env::current_dir()?;
-        let status = get_worktree_status(&current_dir);
+        let status = get_worktree_status(&current_dir, false);
 
         // Status should have working fields (actual content may vary)
         // Just check that the function doesn't panic and returns a status
@@ -1792,7 +3848,7 @@ mod tests {
     fn test_get_worktree_status_path_validation() -> Result<()> {
         // Test with non-existent path validation
         let non_existent = std::path::Path::new("/tmp/test-path");
-        let status = get_worktree_status(non_existent);
+        let status = get_worktree_status(non_existent, false);
 
         // Should return clean status for test paths
         assert!(status.last_commit.is_none());
@@ -1838,4 +3894,64 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_parse_worktree_porcelain_full_field_set() {
+        let output = "worktree /repo.git\nbare\n\n\
+                      worktree /repo/main\nHEAD 1234abcd\nbranch refs/heads/main\n\n\
+                      worktree /repo/detached\nHEAD 5678ef00\ndetached\nlocked\n\n\
+                      worktree /repo/feature\nHEAD 9abc0000\nbranch refs/heads/feature\nlocked on usb drive\nprunable gitdir file points to non-existent location\n";
+
+        let worktrees = parse_worktree_porcelain(output);
+        assert_eq!(worktrees.len(), 4);
+
+        assert_eq!(worktrees[0].path, PathBuf::from("/repo.git"));
+        assert!(worktrees[0].is_bare);
+        assert_eq!(worktrees[0].head, None);
+
+        assert_eq!(worktrees[1].head.as_deref(), Some("1234abcd"));
+        assert_eq!(worktrees[1].branch.as_deref(), Some("refs/heads/main"));
+        assert!(!worktrees[1].is_detached);
+        assert_eq!(worktrees[1].locked, None);
+
+        assert!(worktrees[2].is_detached);
+        assert_eq!(worktrees[2].branch, None);
+        assert_eq!(worktrees[2].locked.as_deref(), Some(""));
+
+        assert_eq!(worktrees[3].locked.as_deref(), Some("on usb drive"));
+    }
+
+    #[test]
+    fn test_parse_worktree_porcelain_tricky_paths() {
+        let output =
+            "worktree /tmp/my worktree/worktree feature\nHEAD abc\nbranch refs/heads/x\n\n\
+                      worktree /tmp/日本語/wt\r\nHEAD def\r\ndetached\r\n";
+
+        let worktrees = parse_worktree_porcelain(output);
+        assert_eq!(worktrees.len(), 2);
+        assert_eq!(
+            worktrees[0].path,
+            PathBuf::from("/tmp/my worktree/worktree feature")
+        );
+        assert_eq!(worktrees[1].path, PathBuf::from("/tmp/日本語/wt"));
+        assert!(worktrees[1].is_detached);
+    }
+
+    #[test]
+    fn test_parse_worktree_porcelain_nul_terminated() {
+        let output = "worktree /tmp/line\nbreak\0HEAD abc\0branch refs/heads/main\0\0\
+                      worktree /tmp/other\0HEAD def\0detached\0locked\0\0";
+
+        let worktrees = parse_worktree_porcelain(output);
+        assert_eq!(worktrees.len(), 2);
+        assert_eq!(worktrees[0].path, PathBuf::from("/tmp/line\nbreak"));
+        assert_eq!(worktrees[0].branch.as_deref(), Some("refs/heads/main"));
+        assert!(worktrees[1].is_detached);
+        assert_eq!(worktrees[1].locked.as_deref(), Some(""));
+    }
+
+    #[test]
+    fn test_parse_worktree_porcelain_empty() {
+        assert!(parse_worktree_porcelain("").is_empty());
+    }
 }
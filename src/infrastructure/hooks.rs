@@ -11,30 +11,113 @@
 //!
 //! ```toml
 //! [hooks]
+//! pre-create = ["git fetch origin"]
 //! post-create = ["npm install", "cp .env.example .env"]
 //! pre-remove = ["rm -rf node_modules"]
+//! post-remove = ["docker volume rm {{worktree_name}}-data"]
 //! post-switch = ["echo 'Switched to {{worktree_name}}'"]
 //! ```
 //!
 //! # Hook Types
 //!
+//! - `pre-create`: Executed before a worktree is created
 //! - `post-create`: Executed after a worktree is created
 //! - `pre-remove`: Executed before a worktree is removed
+//! - `post-remove`: Executed after a worktree is removed
 //! - `post-switch`: Executed after switching to a different worktree
 //!
+//! Hooks run with the worktree as the working directory, except `pre-create`
+//! and `post-remove`: the worktree does not exist yet or is already gone, so
+//! those hooks run in the repository root (the main worktree, or the
+//! repository directory for bare repositories) while `{{worktree_path}}`
+//! refers to the planned or former path.
+//!
+//! A hook can also be a table that sets its own working directory (relative
+//! to the directory above) and environment variables:
+//!
+//! ```toml
+//! [hooks]
+//! post-create = [
+//!     { command = "npm install", workdir = "frontend", env = { NODE_ENV = "development" } },
+//! ]
+//! ```
+//!
 //! # Template Variables
 //!
 //! Hook commands support template variables:
 //! - `{{worktree_name}}`: The name of the worktree
 //! - `{{worktree_path}}`: The absolute path to the worktree
+//! - `{{previous_worktree_name}}`: The worktree switched away from (`post-switch` only)
+//! - `{{previous_worktree_path}}`: The path of the worktree switched away from (`post-switch` only)
+//!
+//! The worktree name and path are also exported as `GW_WORKTREE_NAME` and
+//! `GW_WORKTREE_PATH`, for scripts called from a hook.
+//!
+//! # Tasks
+//!
+//! `[tasks]` holds named command lists in the same format, run on request
+//! with [`run_task`] (e.g. `gw create --task setup`) instead of on an event.
 
-use anyhow::Result;
-use std::path::PathBuf;
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
-use super::super::config::Config;
+use super::super::config::{Config, HookEntry};
 use super::super::constants::*;
 use super::super::ui::UserInterface;
+use super::super::utils::debug_timer;
+use super::git::GitWorktreeManager;
+
+/// A hook command that did not succeed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HookFailure {
+    /// The hook type, e.g. `post-create`
+    pub hook_type: String,
+    /// The command after template expansion
+    pub command: String,
+    /// Exit code, or `None` if the command could not be run or was killed
+    pub exit_code: Option<i32>,
+}
+
+impl std::fmt::Display for HookFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let status = match self.exit_code {
+            Some(code) => HOOK_FAILURE_EXIT_CODE.replace("{}", &code.to_string()),
+            None => HOOK_FAILURE_NOT_RUN.to_string(),
+        };
+        write!(
+            f,
+            "{}",
+            HOOK_FAILURE_FORMAT
+                .replacen("{}", &self.hook_type, 1)
+                .replacen("{}", &self.command, 1)
+                .replacen("{}", &status, 1)
+        )
+    }
+}
+
+/// Outcome of running the hooks for one event
+///
+/// Hooks that were skipped (none configured, or declined at the prompt)
+/// count as successful.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HookReport {
+    /// Failed commands in the order they ran
+    pub failures: Vec<HookFailure>,
+}
+
+impl HookReport {
+    /// Returns `true` if every hook command succeeded
+    pub fn is_success(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Returns the first failed command, if any
+    pub fn first_failure(&self) -> Option<&HookFailure> {
+        self.failures.first()
+    }
+}
 
 /// Context information passed to hook commands
 ///
@@ -52,6 +135,8 @@ use super::super::ui::UserInterface;
 /// let context = HookContext {
 ///     worktree_name: "feature-auth".to_string(),
 ///     worktree_path: PathBuf::from("/home/user/project/feature-auth"),
+///     previous_worktree_name: None,
+///     previous_worktree_path: None,
 /// };
 /// ```
 ///
@@ -69,13 +154,105 @@ pub struct HookContext {
     /// This is the absolute path where the worktree files are located,
     /// used as the working directory when executing hook commands
     pub worktree_path: PathBuf,
+    /// The name of the worktree that was active before a switch
+    ///
+    /// Only set for `post-switch` hooks; `None` for other events or
+    /// when no previous worktree has been recorded yet
+    pub previous_worktree_name: Option<String>,
+    /// The path of the worktree that was active before a switch
+    pub previous_worktree_path: Option<PathBuf>,
+}
+
+/// Replaces template placeholders in a hook command with context values
+///
+/// Placeholders for values missing from the context expand to an empty string.
+pub fn expand_template(cmd: &str, context: &HookContext) -> String {
+    let previous_name = context.previous_worktree_name.as_deref().unwrap_or("");
+    let previous_path = context
+        .previous_worktree_path
+        .as_ref()
+        .map(|p| p.display().to_string())
+        .unwrap_or_default();
+
+    cmd.replace(TEMPLATE_WORKTREE_NAME, &context.worktree_name)
+        .replace(
+            TEMPLATE_WORKTREE_PATH,
+            &context.worktree_path.display().to_string(),
+        )
+        .replace(TEMPLATE_PREVIOUS_WORKTREE_NAME, previous_name)
+        .replace(TEMPLATE_PREVIOUS_WORKTREE_PATH, &previous_path)
+}
+
+/// Pure business logic for hooks that run without a worktree directory
+///
+/// `pre-create` runs before the worktree exists and `post-remove` after it
+/// is gone.
+pub fn runs_in_repository_root(hook_type: &str) -> bool {
+    hook_type == HOOK_PRE_CREATE || hook_type == HOOK_POST_REMOVE
+}
+
+/// Pure business logic for choosing the directory a hook runs in
+///
+/// Hooks run in the worktree, except `pre-create` and `post-remove` hooks,
+/// which run in `repository_root` because the worktree directory does not
+/// exist (see [`runs_in_repository_root`]).
+pub fn hook_working_dir(
+    hook_type: &str,
+    context: &HookContext,
+    repository_root: Option<&Path>,
+) -> PathBuf {
+    match repository_root {
+        Some(root) if runs_in_repository_root(hook_type) => root.to_path_buf(),
+        _ => context.worktree_path.clone(),
+    }
+}
+
+/// Pure business logic for the directory a single hook command runs in
+///
+/// A `workdir` set on the hook is expanded like the command and resolved
+/// against `base`; absolute paths are used as-is.
+pub fn hook_command_dir(base: &Path, hook: &HookEntry, context: &HookContext) -> PathBuf {
+    match hook.workdir() {
+        Some(workdir) => base.join(expand_template(workdir, context)),
+        None => base.to_path_buf(),
+    }
+}
+
+/// Pure business logic for the environment variables every hook gets
+///
+/// `gw exec` passes the same variables to its command.
+pub fn context_env(context: &HookContext) -> Vec<(String, String)> {
+    vec![
+        (
+            ENV_GW_WORKTREE_NAME.to_string(),
+            context.worktree_name.clone(),
+        ),
+        (
+            ENV_GW_WORKTREE_PATH.to_string(),
+            context.worktree_path.display().to_string(),
+        ),
+    ]
+}
+
+/// Returns the main worktree, or the repository directory if it is bare
+fn repository_root() -> Option<PathBuf> {
+    let manager = GitWorktreeManager::new().ok()?;
+    Some(
+        manager
+            .main_worktree_path()
+            .unwrap_or_else(|| manager.get_common_dir().to_path_buf()),
+    )
 }
 
 /// Executes configured hooks for a specific event type with user confirmation
 ///
 /// This function loads the configuration, looks up hooks for the specified
 /// event type, asks for user confirmation, and executes them in order.
-/// Each command is run in a shell with the worktree directory as the working directory.
+/// Each command is run in a shell with the worktree directory as the working
+/// directory, or the repository root for `pre-create` and `post-remove`
+/// hooks (see
+/// [`hook_working_dir`]). Table-form hooks may change that directory with
+/// `workdir` and add environment variables with `env`.
 ///
 /// # Arguments
 ///
@@ -85,8 +262,10 @@ pub struct HookContext {
 ///
 /// # Hook Types
 ///
+/// - `pre-create`: Run before a worktree is created
 /// - `post-create`: Run after a worktree is created
 /// - `pre-remove`: Run before a worktree is removed
+/// - `post-remove`: Run after a worktree is removed
 /// - `post-switch`: Run after switching to a worktree
 ///
 /// # Template Placeholders
@@ -94,6 +273,8 @@ pub struct HookContext {
 /// Commands can include the following placeholders:
 /// - `{{worktree_name}}`: Replaced with the worktree name
 /// - `{{worktree_path}}`: Replaced with the full worktree path
+/// - `{{previous_worktree_name}}` / `{{previous_worktree_path}}`: Replaced with
+///   the worktree switched away from, or an empty string
 ///
 /// # Example
 ///
@@ -105,6 +286,8 @@ pub struct HookContext {
 /// let context = HookContext {
 ///     worktree_name: "feature-branch".to_string(),
 ///     worktree_path: PathBuf::from("/path/to/worktree"),
+///     previous_worktree_name: None,
+///     previous_worktree_path: None,
 /// };
 /// let ui = DialoguerUI;
 ///
@@ -126,18 +309,21 @@ pub struct HookContext {
 ///
 /// Command execution errors (spawn failures) are also handled gracefully,
 /// allowing other hooks to continue even if one command fails to start.
+/// Every failed command is listed in the returned [`HookReport`], so the
+/// caller can decide how to react (see `[hooks] on_failure`).
 pub fn execute_hooks_with_ui(
     hook_type: &str,
     context: &HookContext,
     ui: &dyn UserInterface,
-) -> Result<()> {
+) -> Result<HookReport> {
     // Always load config from the current directory where the command is executed,
     // not from the newly created worktree which doesn't have a config yet
     let config = Config::load()?;
+    let mut report = HookReport::default();
 
     if let Some(commands) = config.hooks.get(hook_type) {
         if commands.is_empty() {
-            return Ok(());
+            return Ok(report);
         }
 
         // Ask for confirmation before running hooks
@@ -146,14 +332,12 @@ pub fn execute_hooks_with_ui(
             "{} {hook_type} hooks found:",
             INFO_RUNNING_HOOKS.replace("{}", "").trim()
         );
-        for cmd in commands {
-            let expanded_cmd = cmd
-                .replace(TEMPLATE_WORKTREE_NAME, &context.worktree_name)
-                .replace(
-                    TEMPLATE_WORKTREE_PATH,
-                    &context.worktree_path.display().to_string(),
-                );
-            println!("  • {expanded_cmd}");
+        for hook in commands {
+            let expanded_cmd = expand_template(hook.command(), context);
+            match hook.workdir() {
+                Some(workdir) => println!("  • {expanded_cmd} (in {workdir})"),
+                None => println!("  • {expanded_cmd}"),
+            }
         }
 
         println!();
@@ -163,58 +347,151 @@ pub fn execute_hooks_with_ui(
 
         if !confirm {
             println!("Skipping {hook_type} hooks.");
-            return Ok(());
+            return Ok(report);
         }
 
+        let root = if runs_in_repository_root(hook_type) {
+            repository_root().or_else(|| std::env::current_dir().ok())
+        } else {
+            None
+        };
+        let working_dir = hook_working_dir(hook_type, context, root.as_deref());
+
+        let mut timer = debug_timer(format!("{hook_type} hooks"));
+        timer.set_detail(format!("for {} commands", commands.len()));
         println!();
-        for cmd in commands {
-            // Replace template placeholders with actual values
-            let expanded_cmd = cmd
-                .replace(TEMPLATE_WORKTREE_NAME, &context.worktree_name)
-                .replace(
-                    TEMPLATE_WORKTREE_PATH,
-                    &context.worktree_path.display().to_string(),
-                );
-
-            println!("{INFO_HOOK_COMMAND_PREFIX}{expanded_cmd}");
-
-            // Execute the command in a shell for maximum compatibility
-            // This allows complex commands with pipes, redirects, etc.
-            // Use spawn() and wait() to allow real-time output streaming
-            match Command::new(SHELL_CMD)
-                .arg(SHELL_OPT_COMMAND)
-                .arg(&expanded_cmd)
-                .current_dir(&context.worktree_path)
-                .stdout(std::process::Stdio::inherit())
-                .stderr(std::process::Stdio::inherit())
-                .spawn()
-            {
-                Ok(mut child) => {
-                    match child.wait() {
-                        Ok(status) => {
-                            if !status.success() {
-                                // Log hook failures but don't stop execution
-                                // This prevents a misconfigured hook from breaking worktree operations
-                                eprintln!(
-                                    "{}",
-                                    ERROR_HOOK_EXIT_CODE
-                                        .replace("{:?}", &format!("{:?}", status.code()))
-                                );
-                            }
-                        }
-                        Err(e) => {
-                            eprintln!("{ERROR_HOOK_WAIT_PREFIX}{e}");
+        report = run_hook_commands(hook_type, commands, &working_dir, context);
+    }
+
+    Ok(report)
+}
+
+/// Runs `commands` in order, each in a shell, and reports the failures
+///
+/// This is the execution part shared by hooks and tasks. `label` names the
+/// hook type or task in the reported failures. A failed command does not
+/// stop the ones after it.
+fn run_hook_commands(
+    label: &str,
+    commands: &[HookEntry],
+    working_dir: &Path,
+    context: &HookContext,
+) -> HookReport {
+    let mut report = HookReport::default();
+    for hook in commands {
+        // Replace template placeholders with actual values
+        let expanded_cmd = expand_template(hook.command(), context);
+        let command_dir = hook_command_dir(working_dir, hook, context);
+        // Hook-specific variables come last so they can override ours
+        let env: Vec<(String, String)> = context_env(context)
+            .into_iter()
+            .chain(
+                hook.env()
+                    .into_iter()
+                    .map(|(key, value)| (key.to_string(), expand_template(value, context))),
+            )
+            .collect();
+
+        println!("{INFO_HOOK_COMMAND_PREFIX}{expanded_cmd}");
+        let mut failure = |exit_code: Option<i32>| {
+            report.failures.push(HookFailure {
+                hook_type: label.to_string(),
+                command: expanded_cmd.clone(),
+                exit_code,
+            })
+        };
+
+        // Execute the command in a shell for maximum compatibility
+        // This allows complex commands with pipes, redirects, etc.
+        // Use spawn() and wait() to allow real-time output streaming
+        match Command::new(SHELL_CMD)
+            .arg(SHELL_OPT_COMMAND)
+            .arg(&expanded_cmd)
+            .current_dir(&command_dir)
+            .envs(env)
+            .stdout(std::process::Stdio::inherit())
+            .stderr(std::process::Stdio::inherit())
+            .spawn()
+        {
+            Ok(mut child) => {
+                match child.wait() {
+                    Ok(status) => {
+                        if !status.success() {
+                            // Log hook failures but don't stop execution
+                            // This prevents a misconfigured hook from breaking worktree operations
+                            eprintln!(
+                                "{}",
+                                ERROR_HOOK_EXIT_CODE
+                                    .replace("{:?}", &format!("{:?}", status.code()))
+                            );
+                            failure(status.code());
                         }
                     }
-                }
-                Err(e) => {
-                    eprintln!("{ERROR_HOOK_EXECUTE_PREFIX}{e}");
+                    Err(e) => {
+                        eprintln!("{ERROR_HOOK_WAIT_PREFIX}{e}");
+                        failure(None);
+                    }
                 }
             }
+            Err(e) => {
+                eprintln!("{ERROR_HOOK_EXECUTE_PREFIX}{e}");
+                failure(None);
+            }
         }
     }
 
-    Ok(())
+    report
+}
+
+/// Pure business logic for looking up a task in `[tasks]`
+///
+/// # Errors
+///
+/// Returns an error listing the available tasks if `name` is not one of
+/// them, or saying that none are configured.
+pub fn find_task<'a>(
+    tasks: &'a HashMap<String, Vec<HookEntry>>,
+    name: &str,
+) -> Result<&'a [HookEntry]> {
+    if let Some(commands) = tasks.get(name) {
+        return Ok(commands);
+    }
+    if tasks.is_empty() {
+        return Err(anyhow!(ERROR_NO_TASKS));
+    }
+    let mut names: Vec<&str> = tasks.keys().map(String::as_str).collect();
+    names.sort();
+    Err(anyhow!(ERROR_UNKNOWN_TASK
+        .replacen("{}", name, 1)
+        .replacen("{}", &names.join(", "), 1)))
+}
+
+/// Runs the named task from `[tasks]` in the worktree
+///
+/// Tasks are opt-in recipes run on request, e.g. `gw create --task setup`,
+/// unlike hooks, which run on every event. Their commands use the same
+/// format as hooks, including templates, `workdir` and `env`, and run
+/// without a confirmation prompt in `context.worktree_path`.
+///
+/// # Errors
+///
+/// Returns an error if the configuration cannot be loaded or has no task
+/// called `name`. Failed commands are listed in the returned
+/// [`HookReport`].
+pub fn run_task(name: &str, context: &HookContext) -> Result<HookReport> {
+    let config = Config::load()?;
+    let commands = find_task(&config.tasks, name)?;
+
+    let mut timer = debug_timer(format!("{name} task"));
+    timer.set_detail(format!("for {} commands", commands.len()));
+    println!();
+    println!("{}", INFO_RUNNING_TASK.replace("{}", name));
+    Ok(run_hook_commands(
+        name,
+        commands,
+        &context.worktree_path,
+        context,
+    ))
 }
 
 /// Executes configured hooks for a specific event type (legacy interface)
@@ -236,12 +513,14 @@ pub fn execute_hooks_with_ui(
 /// let context = HookContext {
 ///     worktree_name: "feature-branch".to_string(),
 ///     worktree_path: PathBuf::from("/path/to/worktree"),
+///     previous_worktree_name: None,
+///     previous_worktree_path: None,
 /// };
 ///
 /// // Execute post-create hooks
 /// execute_hooks("post-create", &context).ok();
 /// ```
-pub fn execute_hooks(hook_type: &str, context: &HookContext) -> Result<()> {
+pub fn execute_hooks(hook_type: &str, context: &HookContext) -> Result<HookReport> {
     use super::super::ui::DialoguerUI;
     let ui = DialoguerUI;
     execute_hooks_with_ui(hook_type, context, &ui)
@@ -258,12 +537,98 @@ mod tests {
         let context = HookContext {
             worktree_name: "test-worktree".to_string(),
             worktree_path: PathBuf::from("/test/path"),
+            previous_worktree_name: None,
+            previous_worktree_path: None,
         };
 
         assert_eq!(context.worktree_name, "test-worktree");
         assert_eq!(context.worktree_path, PathBuf::from("/test/path"));
     }
 
+    #[test]
+    fn test_hook_working_dir() {
+        let context = HookContext {
+            worktree_name: "feature".to_string(),
+            worktree_path: PathBuf::from("/repo/worktrees/feature"),
+            previous_worktree_name: None,
+            previous_worktree_path: None,
+        };
+        let root = Path::new("/repo");
+
+        assert_eq!(
+            hook_working_dir(HOOK_POST_CREATE, &context, Some(root)),
+            context.worktree_path
+        );
+        assert_eq!(
+            hook_working_dir(HOOK_PRE_REMOVE, &context, Some(root)),
+            context.worktree_path
+        );
+        assert_eq!(
+            hook_working_dir(HOOK_POST_REMOVE, &context, Some(root)),
+            root
+        );
+        assert_eq!(
+            hook_working_dir(HOOK_PRE_CREATE, &context, Some(root)),
+            root
+        );
+        assert!(runs_in_repository_root(HOOK_PRE_CREATE));
+        assert!(!runs_in_repository_root(HOOK_POST_CREATE));
+    }
+
+    #[test]
+    fn test_context_env() {
+        let context = HookContext {
+            worktree_name: "feature".to_string(),
+            worktree_path: PathBuf::from("/repo/worktrees/feature"),
+            previous_worktree_name: None,
+            previous_worktree_path: None,
+        };
+        assert_eq!(
+            context_env(&context),
+            vec![
+                ("GW_WORKTREE_NAME".to_string(), "feature".to_string()),
+                (
+                    "GW_WORKTREE_PATH".to_string(),
+                    "/repo/worktrees/feature".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hook_command_dir() {
+        let context = HookContext {
+            worktree_name: "feature".to_string(),
+            worktree_path: PathBuf::from("/repo/worktrees/feature"),
+            previous_worktree_name: None,
+            previous_worktree_path: None,
+        };
+        let base = context.worktree_path.clone();
+
+        let plain = HookEntry::from("npm install");
+        assert_eq!(hook_command_dir(&base, &plain, &context), base);
+
+        let nested = HookEntry::Detailed {
+            command: "npm install".to_string(),
+            workdir: Some("web/{{worktree_name}}".to_string()),
+            env: Default::default(),
+        };
+        assert_eq!(
+            hook_command_dir(&base, &nested, &context),
+            base.join("web/feature")
+        );
+
+        let absolute = HookEntry::Detailed {
+            command: "make".to_string(),
+            workdir: Some("/tmp/build".to_string()),
+            env: Default::default(),
+        };
+        assert_eq!(
+            hook_command_dir(&base, &absolute, &context),
+            PathBuf::from("/tmp/build")
+        );
+    }
+
     #[test]
     fn test_template_variable_replacement() {
         // Test the template variable replacement logic used in execute_hooks
@@ -287,6 +652,8 @@ mod tests {
         let context = HookContext {
             worktree_name: "test".to_string(),
             worktree_path: temp_dir.path().to_path_buf(),
+            previous_worktree_name: None,
+            previous_worktree_path: None,
         };
 
         // Test that PathBuf can be displayed as string
@@ -328,6 +695,8 @@ mod tests {
         let context = HookContext {
             worktree_name: "test".to_string(),
             worktree_path: PathBuf::from("/test/path"),
+            previous_worktree_name: None,
+            previous_worktree_path: None,
         };
 
         // Test with confirmation accepted
@@ -347,6 +716,8 @@ mod tests {
         let context = HookContext {
             worktree_name: "feature-xyz".to_string(),
             worktree_path: PathBuf::from("/workspace/feature-xyz"),
+            previous_worktree_name: None,
+            previous_worktree_path: None,
         };
 
         // Mock UI that rejects confirmation
@@ -355,4 +726,75 @@ mod tests {
         // In real usage, this would show hook commands before asking
         let _result = execute_hooks_with_ui("post-create", &context, &ui);
     }
+
+    #[test]
+    fn test_hook_report_failures() {
+        let mut report = HookReport::default();
+        assert!(report.is_success());
+        assert!(report.first_failure().is_none());
+
+        report.failures.push(HookFailure {
+            hook_type: "post-create".to_string(),
+            command: "npm install".to_string(),
+            exit_code: Some(3),
+        });
+        report.failures.push(HookFailure {
+            hook_type: "post-create".to_string(),
+            command: "missing-tool".to_string(),
+            exit_code: None,
+        });
+        assert!(!report.is_success());
+
+        let failure = report.first_failure().unwrap();
+        assert_eq!(
+            failure.to_string(),
+            "post-create hook 'npm install' exited with code 3"
+        );
+        assert_eq!(
+            report.failures[1].to_string(),
+            "post-create hook 'missing-tool' could not be run"
+        );
+    }
+
+    #[test]
+    fn test_find_task() {
+        let mut tasks = HashMap::new();
+        let error = find_task(&tasks, "setup").unwrap_err().to_string();
+        assert!(error.starts_with("No tasks configured"), "{error}");
+
+        tasks.insert("setup".to_string(), vec![HookEntry::from("npm ci")]);
+        tasks.insert("build".to_string(), vec![HookEntry::from("npm run build")]);
+        assert_eq!(find_task(&tasks, "setup").unwrap()[0].command(), "npm ci");
+
+        let error = find_task(&tasks, "test").unwrap_err().to_string();
+        assert_eq!(error, "Unknown task 'test'. Available tasks: build, setup");
+    }
+
+    #[test]
+    fn test_run_hook_commands_in_working_dir() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let context = HookContext {
+            worktree_name: "feature".to_string(),
+            worktree_path: temp_dir.path().to_path_buf(),
+            previous_worktree_name: None,
+            previous_worktree_path: None,
+        };
+        let commands = vec![
+            HookEntry::from("echo {{worktree_name}} > name.txt"),
+            HookEntry::from("exit 3"),
+            HookEntry::from("touch after.txt"),
+        ];
+
+        let report = run_hook_commands("setup", &commands, temp_dir.path(), &context);
+        assert_eq!(
+            std::fs::read_to_string(temp_dir.path().join("name.txt"))?.trim(),
+            "feature"
+        );
+        // A failure does not stop the commands after it
+        assert!(temp_dir.path().join("after.txt").exists());
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.failures[0].hook_type, "setup");
+        assert_eq!(report.failures[0].exit_code, Some(3));
+        Ok(())
+    }
 }
@@ -5,17 +5,32 @@
 //! - File system operations
 //! - External process execution
 //! - Hook system for lifecycle events
+//! - Persistent per-repository state
 
 pub mod file_copy;
 pub mod filesystem;
 pub mod git;
 pub mod hooks;
+pub mod state;
 
 // Re-export commonly used items
-pub use file_copy::copy_configured_files;
+pub use file_copy::{
+    copy_configured_files, copy_configured_files_from_ref, expand_source_path, is_copy_excluded,
+    resolve_copy_plan, FileCopyReport, SkipReason,
+};
 pub use filesystem::{FileSystem, RealFileSystem};
-pub use git::{GitWorktreeManager, WorktreeInfo};
-pub use hooks::{execute_hooks, execute_hooks_with_ui, HookContext};
+pub use git::{
+    find_layout_outliers, get_worktree_status, keep_innermost_current, parse_worktree_porcelain,
+    pr_refspec, recent_commits, release_active_lock, strip_remote_prefix, worktree_created_at,
+    worktree_head_branch, worktree_head_state, ChangeCounts, GitWorktreeManager, HeadState,
+    LayoutInconsistency, PorcelainWorktree, RemoteBranch, RenameRepairError, WorktreeInfo,
+    WorktreeStatus,
+};
+pub use hooks::{
+    context_env, execute_hooks, execute_hooks_with_ui, find_task, run_task, HookContext,
+    HookFailure, HookReport,
+};
+pub use state::{FocusState, State, WorktreeRef};
 
 // Re-export FilesConfig from config module
 pub use super::config::FilesConfig;
@@ -0,0 +1,295 @@
+//! Persistent per-repository state
+//!
+//! Small pieces of information that need to survive between `gw` runs: the
+//! worktree that was active before the last switch, the recently used
+//! worktrees, whether the config setup offer was dismissed, the focus set
+//! and the favorite worktrees. The state is stored as TOML in the common git
+//! directory so that every worktree of a repository sees the same values.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::super::constants::{STATE_FILE_NAME, SWITCH_HISTORY_LIMIT};
+
+/// A worktree remembered by name and path
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct WorktreeRef {
+    pub name: String,
+    pub path: PathBuf,
+}
+
+/// State shared by all worktrees of a repository
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct State {
+    /// The worktree that was active before the most recent switch
+    #[serde(default)]
+    pub previous_worktree: Option<WorktreeRef>,
+
+    /// Worktrees switched away from, most recent first
+    ///
+    /// Holds at most [`SWITCH_HISTORY_LIMIT`] entries without duplicates.
+    #[serde(default)]
+    pub recent_worktrees: Vec<WorktreeRef>,
+
+    /// The user asked not to be offered configuration setup after
+    /// creating the first worktree
+    #[serde(default)]
+    pub dismissed_config_setup: bool,
+
+    /// Worktrees shown by list, switch and search while focus mode is on
+    #[serde(default)]
+    pub focus: FocusState,
+
+    /// Names of the favorite worktrees, sorted right after the current one
+    #[serde(default)]
+    pub favorites: Vec<String>,
+}
+
+/// The focus set and whether focus mode is on
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FocusState {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Names of the focused worktrees
+    #[serde(default)]
+    pub worktrees: Vec<String>,
+}
+
+impl State {
+    /// Loads the state stored in `dir`
+    ///
+    /// A missing or unreadable state file yields the default state, since
+    /// nothing in the state is required for `gw` to work.
+    pub fn load(dir: &Path) -> Self {
+        fs::read_to_string(dir.join(STATE_FILE_NAME))
+            .ok()
+            .and_then(|content| toml::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Writes the state to `dir`, replacing any previous state file
+    pub fn save(&self, dir: &Path) -> Result<()> {
+        let content = toml::to_string(self)?;
+        fs::write(dir.join(STATE_FILE_NAME), content)?;
+        Ok(())
+    }
+
+    /// Pure business logic for recording a switch between worktrees
+    ///
+    /// Remembers `from` as the previous worktree and at the top of the switch
+    /// history unless the switch stays in the same place, and returns the
+    /// previous worktree to report to hooks.
+    pub fn record_switch(&mut self, from: WorktreeRef, to: &Path) -> Option<WorktreeRef> {
+        if from.path != to {
+            self.recent_worktrees
+                .retain(|recent| recent.path != from.path);
+            self.recent_worktrees.insert(0, from.clone());
+            self.recent_worktrees.truncate(SWITCH_HISTORY_LIMIT);
+            self.previous_worktree = Some(from);
+        }
+        self.previous_worktree.clone()
+    }
+
+    /// Pure business logic for following a worktree that was moved
+    ///
+    /// Switch history entries at `from`'s path now point at `to`, and a
+    /// focused or favorite `from` stays so under its new name. Returns
+    /// whether anything changed, so callers only save when needed.
+    pub fn record_move(&mut self, from: &WorktreeRef, to: &WorktreeRef) -> bool {
+        let mut changed = false;
+        let entries = self
+            .previous_worktree
+            .iter_mut()
+            .chain(self.recent_worktrees.iter_mut());
+        for entry in entries.filter(|entry| entry.path == from.path) {
+            *entry = to.clone();
+            changed = true;
+        }
+        let names = self
+            .focus
+            .worktrees
+            .iter_mut()
+            .chain(self.favorites.iter_mut());
+        for name in names {
+            if *name == from.name && from.name != to.name {
+                *name = to.name.clone();
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Pure business logic for marking a worktree as a favorite
+    ///
+    /// Returns false if it already was one.
+    pub fn add_favorite(&mut self, name: &str) -> bool {
+        if self.favorites.iter().any(|favorite| favorite == name) {
+            return false;
+        }
+        self.favorites.push(name.to_string());
+        true
+    }
+
+    /// Pure business logic for removing a worktree from the favorites
+    ///
+    /// Returns false if it was not one.
+    pub fn remove_favorite(&mut self, name: &str) -> bool {
+        let count = self.favorites.len();
+        self.favorites.retain(|favorite| favorite != name);
+        self.favorites.len() != count
+    }
+
+    /// Pure business logic for choosing where `gw switch --last` goes
+    ///
+    /// Returns the most recent worktree in the switch history that is not
+    /// `current` and for which `exists` holds, so deleted worktrees are
+    /// skipped. State files written before the history existed fall back to
+    /// the previous worktree.
+    pub fn last_worktree(
+        &self,
+        current: Option<&Path>,
+        exists: impl Fn(&Path) -> bool,
+    ) -> Option<&WorktreeRef> {
+        self.recent_worktrees
+            .iter()
+            .chain(self.previous_worktree.as_ref())
+            .find(|recent| Some(recent.path.as_path()) != current && exists(&recent.path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn worktree_ref(name: &str) -> WorktreeRef {
+        WorktreeRef {
+            name: name.to_string(),
+            path: PathBuf::from(format!("/repo/{name}")),
+        }
+    }
+
+    #[test]
+    fn test_load_missing_state_is_default() {
+        let temp_dir = TempDir::new().unwrap();
+        assert_eq!(State::load(temp_dir.path()), State::default());
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let state = State {
+            previous_worktree: Some(worktree_ref("feature")),
+            recent_worktrees: vec![worktree_ref("feature"), worktree_ref("main")],
+            dismissed_config_setup: true,
+            focus: FocusState {
+                enabled: true,
+                worktrees: vec!["feature".to_string()],
+            },
+            favorites: vec!["main".to_string()],
+        };
+        state.save(temp_dir.path())?;
+        assert_eq!(State::load(temp_dir.path()), state);
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_switch_to_same_worktree_keeps_previous() {
+        let mut state = State {
+            previous_worktree: Some(worktree_ref("main")),
+            ..State::default()
+        };
+        let previous = state.record_switch(worktree_ref("feature"), Path::new("/repo/feature"));
+        assert_eq!(previous, Some(worktree_ref("main")));
+        assert!(state.recent_worktrees.is_empty());
+    }
+
+    #[test]
+    fn test_record_switch_keeps_short_unique_history() {
+        let mut state = State::default();
+        for name in ["a", "b", "a", "c", "d", "e", "f"] {
+            state.record_switch(worktree_ref(name), Path::new("/repo/elsewhere"));
+        }
+        let names: Vec<&str> = state
+            .recent_worktrees
+            .iter()
+            .map(|recent| recent.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["f", "e", "d", "c", "a"]);
+    }
+
+    #[test]
+    fn test_record_move_follows_the_worktree() {
+        let mut state = State {
+            previous_worktree: Some(worktree_ref("feature")),
+            recent_worktrees: vec![worktree_ref("feature"), worktree_ref("main")],
+            focus: FocusState {
+                enabled: true,
+                worktrees: vec!["feature".to_string()],
+            },
+            favorites: vec!["main".to_string(), "feature".to_string()],
+            ..State::default()
+        };
+        let moved = WorktreeRef {
+            name: "feature-moved".to_string(),
+            path: PathBuf::from("/elsewhere/feature-moved"),
+        };
+
+        assert!(state.record_move(&worktree_ref("feature"), &moved));
+        assert_eq!(state.previous_worktree, Some(moved.clone()));
+        assert_eq!(
+            state.recent_worktrees,
+            vec![moved.clone(), worktree_ref("main")]
+        );
+        assert_eq!(state.focus.worktrees, vec!["feature-moved"]);
+        assert_eq!(state.favorites, vec!["main", "feature-moved"]);
+        assert!(!state.record_move(&worktree_ref("other"), &moved));
+    }
+
+    #[test]
+    fn test_add_and_remove_favorite() {
+        let mut state = State::default();
+        assert!(state.add_favorite("feature"));
+        assert!(!state.add_favorite("feature"));
+        assert!(state.add_favorite("main"));
+        assert_eq!(state.favorites, vec!["feature", "main"]);
+
+        assert!(state.remove_favorite("feature"));
+        assert!(!state.remove_favorite("feature"));
+        assert_eq!(state.favorites, vec!["main"]);
+    }
+
+    #[test]
+    fn test_last_worktree() {
+        let state = State {
+            recent_worktrees: vec![
+                worktree_ref("main"),
+                worktree_ref("gone"),
+                worktree_ref("b"),
+            ],
+            ..State::default()
+        };
+        let exists = |path: &Path| !path.ends_with("gone");
+
+        let last = state.last_worktree(Some(Path::new("/repo/b")), exists);
+        assert_eq!(last, Some(&worktree_ref("main")));
+
+        // Bouncing back from main skips the deleted worktree
+        let last = state.last_worktree(Some(Path::new("/repo/main")), exists);
+        assert_eq!(last, Some(&worktree_ref("b")));
+
+        assert_eq!(State::default().last_worktree(None, exists), None);
+
+        let legacy = State {
+            previous_worktree: Some(worktree_ref("main")),
+            ..State::default()
+        };
+        assert_eq!(
+            legacy.last_worktree(None, exists),
+            Some(&worktree_ref("main"))
+        );
+    }
+}
@@ -188,6 +188,40 @@ pub fn input_esc_with_default_raw(prompt: &str, default: &str) -> Option<String>
     input_with_esc_support_raw(prompt, Some(default))
 }
 
+/// Checks, without blocking, whether ESC was pressed since the last check
+///
+/// Drains any pending key events while the terminal is briefly switched to
+/// raw mode. This lets long-running loops poll for cancellation between
+/// steps without competing with prompts shown during each step.
+///
+/// # Returns
+///
+/// * `true` - ESC was pressed
+/// * `false` - No ESC pending, or the terminal could not be put in raw mode
+///   (e.g. when not attached to a TTY)
+pub fn escape_pressed() -> bool {
+    use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+    use std::time::Duration;
+
+    if crossterm::terminal::enable_raw_mode().is_err() {
+        return false;
+    }
+
+    let mut pressed = false;
+    while let Ok(true) = event::poll(Duration::ZERO) {
+        match event::read() {
+            Ok(Event::Key(key)) if key.code == KeyCode::Esc && key.kind == KeyEventKind::Press => {
+                pressed = true;
+            }
+            Ok(_) => {}
+            Err(_) => break,
+        }
+    }
+
+    let _ = crossterm::terminal::disable_raw_mode();
+    pressed
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
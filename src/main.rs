@@ -32,24 +32,37 @@
 //! the `GW_SWITCH_FILE` environment variable. The shell wrapper then reads this
 //! file and executes the `cd` command.
 //!
+//! Without `GW_SWITCH_FILE`, a `SWITCH_TO:<path>` line is printed instead, on
+//! stdout by default or on stderr with `[shell] switch_channel = "stderr"`.
+//!
 //! # Exit Codes
 //!
 //! - `0`: Successful execution
 //! - `1`: Error during execution (displayed to user)
+//! - `2`: Invalid input, such as a rejected worktree name or path
+//! - `3`: Not inside a Git repository
+//! - `4`: The worktree path already exists
+//! - `5`: Another git-workers process holds the worktree lock
+//! - `6`: A hook command failed
 
 use anyhow::Result;
-use clap::Parser;
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, Subcommand};
 use colored::*;
 use console::Term;
 use std::env;
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
+use std::path::PathBuf;
 
+use git_workers::core::exit_code_for;
+use git_workers::infrastructure::release_active_lock;
 use git_workers::{commands, constants, menu, repository_info};
 
-use constants::header_separator;
+use constants::{header_separator, HEADER_DETAILS_SEPARATOR};
 use git_workers::ui::{DialoguerUI, UserInterface};
+use git_workers::utils::output::{self, Verbosity};
 use menu::MenuItem;
-use repository_info::get_repository_info;
+use repository_info::get_repository_header;
 
 /// Command-line arguments for Git Workers
 ///
@@ -58,6 +71,7 @@ use repository_info::get_repository_info;
 #[derive(Parser)]
 #[command(name = "gw")]
 #[command(about = "Interactive Git Worktree Manager", long_about = None)]
+#[command(after_long_help = constants::HELP_EXIT_CODES)]
 struct Cli {
     /// Print version information and exit
     ///
@@ -65,6 +79,219 @@ struct Cli {
     /// without entering the interactive mode.
     #[arg(short, long)]
     version: bool,
+
+    /// Only print results and errors: no headers, previews, icons or spinners
+    #[arg(short, long, global = true)]
+    quiet: bool,
+
+    /// Show what the menu's delete, batch delete, cleanup and prune would
+    /// remove without deleting anything or running hooks
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Non-interactive command to run instead of the menu
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+/// Subcommands that run directly without entering the interactive menu
+#[derive(Subcommand)]
+enum Command {
+    /// Create a worktree without prompts
+    Create {
+        /// Name of the new worktree
+        #[arg(long)]
+        name: Option<String>,
+        /// Existing branch to check out (use origin/<branch> for remote branches)
+        #[arg(long)]
+        branch: Option<String>,
+        /// Remote to take --branch from, e.g. upstream
+        #[arg(long, requires = "branch")]
+        from: Option<String>,
+        /// Create a new branch from the current HEAD
+        #[arg(long)]
+        from_head: bool,
+        /// Directory for the first worktree (the name is appended)
+        #[arg(long)]
+        path: Option<String>,
+        /// Switch to the new worktree afterwards
+        #[arg(long)]
+        switch: bool,
+        /// Run a task from [tasks] in the new worktree
+        #[arg(long)]
+        task: Option<String>,
+        /// Fetch the remote --branch first, only its last N commits
+        #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+        depth: Option<u32>,
+        /// Fetch a pull request from origin into pr-<N> and create the worktree on it
+        #[arg(long, value_name = "N", conflicts_with_all = ["branch", "from_head"])]
+        pr: Option<u32>,
+    },
+    /// Delete a worktree by name (confirm with --yes)
+    Delete {
+        /// Name of the worktree to delete
+        name: String,
+        /// Answer yes to every confirmation: the deletion, the branch and uncommitted changes
+        #[arg(short, long)]
+        yes: bool,
+        /// Allow deleting the main worktree
+        #[arg(long)]
+        allow_main: bool,
+        /// Show what --yes would delete without deleting anything or running hooks
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// List worktrees
+    List {
+        /// Print JSON instead of a table (implied when stdout is not a terminal)
+        #[arg(long)]
+        json: bool,
+        /// Print one tab-separated line per worktree in a stable format
+        #[arg(long, conflicts_with = "json")]
+        porcelain: bool,
+        /// Show the last commit (id, summary, author, age) under each worktree
+        #[arg(short, long, conflicts_with_all = ["json", "porcelain"])]
+        verbose: bool,
+        /// Sort by name, branch, modified or commit-time
+        #[arg(long, value_name = "KEY")]
+        sort: Option<commands::ListSortKey>,
+        /// Reverse the sort order
+        #[arg(long)]
+        desc: bool,
+        /// Keep the current worktree at the top when sorting with --sort
+        #[arg(long)]
+        pin_current: bool,
+        /// Show each worktree's size on disk (slow on large worktrees)
+        #[arg(long, conflicts_with_all = ["json", "porcelain"])]
+        size: bool,
+        /// Only list worktrees whose name or branch matches, e.g. 'feature/*' or login
+        #[arg(long, value_name = "PATTERN", default_value = "")]
+        filter: String,
+    },
+    /// Hook configuration helpers
+    Hooks {
+        #[command(subcommand)]
+        action: HooksAction,
+    },
+    /// Configuration file helpers
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+    /// Check the repository for common worktree problems
+    Doctor,
+    /// Convert a plain clone to the worktrees/ subdirectory layout
+    InitLayout,
+    /// Remove stale entries of worktrees whose directory was deleted
+    Prune {
+        /// Only show the entries that would be removed
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Switch to a worktree by name (requires the shell integration)
+    Switch {
+        /// Name of the worktree to switch to
+        #[arg(required_unless_present_any = ["last", "filter"])]
+        name: Option<String>,
+        /// Switch back to the worktree used before the current one
+        #[arg(long, conflicts_with = "name")]
+        last: bool,
+        /// Pick from the worktrees whose name or branch matches, e.g. 'feature/*'
+        #[arg(long, value_name = "PATTERN", conflicts_with_all = ["name", "last"])]
+        filter: Option<String>,
+    },
+    /// Open a worktree in the editor ($EDITOR or [editor] command)
+    Open {
+        /// Name of the worktree to open
+        name: String,
+    },
+    /// Print the full and short HEAD commit id of a worktree
+    Sha {
+        /// Name of the worktree
+        name: String,
+    },
+    /// Mark a worktree as a favorite, listed right after the current one
+    Favorite {
+        /// Name of the worktree
+        name: String,
+    },
+    /// Remove a worktree from the favorites
+    Unfavorite {
+        /// Name of the worktree
+        name: String,
+    },
+    /// Add or remove the files copied into new worktrees ([files] copy)
+    Files,
+    /// Show the disk usage of each worktree, largest first
+    Du,
+    /// Show staged, unstaged and untracked changes, ahead/behind and locks of every worktree
+    Status,
+    /// Run a command inside a worktree without switching to it
+    Exec {
+        /// Name of the worktree
+        name: String,
+        /// Command and arguments to run, after `--`
+        #[arg(last = true, required = true)]
+        command: Vec<String>,
+    },
+    /// Rename a worktree without prompts
+    Rename {
+        /// Current name of the worktree
+        old: String,
+        /// New name of the worktree
+        new: String,
+        /// Rename the branch too (it must follow the worktree name)
+        #[arg(long)]
+        with_branch: bool,
+    },
+    /// Move a worktree to another directory (path relative to the repository)
+    Move {
+        /// Name of the worktree to move
+        name: String,
+        /// New path, e.g. ../archive/<name>
+        path: String,
+    },
+    /// Print version and build information (commit, date, rustc)
+    Version {
+        /// Print JSON for tooling
+        #[arg(long)]
+        json: bool,
+    },
+    /// Show which worktree a path belongs to, or has a branch checked out
+    Which {
+        /// Path to look up (defaults to the current directory)
+        #[arg(default_value = ".")]
+        path: PathBuf,
+        /// Look up the worktree a branch is checked out in instead (origin/<branch> also works)
+        #[arg(long, conflicts_with = "path")]
+        branch: Option<String>,
+    },
+}
+
+/// Actions available under `gw hooks`
+#[derive(Subcommand)]
+enum HooksAction {
+    /// Detect project files and suggest hooks and files to copy
+    Suggest,
+    /// Open .git-workers.toml in the editor
+    Edit {
+        /// Editor command to use, e.g. 'code --wait' (overrides [editor] command, $EDITOR and $VISUAL)
+        #[arg(long, value_name = "CMD")]
+        editor: Option<String>,
+    },
+}
+
+/// Actions available under `gw config`
+#[derive(Subcommand)]
+enum ConfigAction {
+    /// Check .git-workers.toml for syntax errors, unknown keys and bad values
+    Validate,
+    /// Print the configuration file in use and whether it exists
+    Path {
+        /// List every location checked, in order
+        #[arg(long)]
+        explain: bool,
+    },
 }
 
 /// Main entry point for Git Workers
@@ -87,20 +314,71 @@ struct Cli {
 ///
 /// # Errors
 ///
-/// Returns an error if:
+/// Exits with a non-zero code if:
 /// - Terminal operations fail (rare)
 /// - Command execution encounters an unrecoverable error
 ///
 /// Most errors are handled gracefully within the loop and displayed to the user.
-fn main() -> Result<()> {
-    let cli = Cli::parse();
+/// Errors that escape are printed and mapped to an exit code with
+/// [`exit_code_for`].
+fn main() {
+    let cli = Cli::try_parse().unwrap_or_else(|e| exit_on_usage_error(e));
+    if cli.dry_run && cli.command.is_some() {
+        exit_on_usage_error(Cli::command().error(
+            ErrorKind::ArgumentConflict,
+            constants::ERROR_DRY_RUN_BEFORE_SUBCOMMAND,
+        ));
+    }
+    install_signal_handler();
 
+    if let Err(e) = run(cli) {
+        eprintln!("Error: {e:?}");
+        std::process::exit(exit_code_for(&e));
+    }
+}
+
+/// Prints a command-line error and exits
+///
+/// Bad arguments are invalid input, so they exit with
+/// `EXIT_VALIDATION_ERROR` instead of clap's default 2, which is reserved
+/// for "not inside a Git repository". `--help` still exits with 0.
+fn exit_on_usage_error(error: clap::Error) -> ! {
+    if !error.use_stderr() {
+        error.exit();
+    }
+    let _ = error.print();
+    std::process::exit(constants::EXIT_VALIDATION_ERROR);
+}
+
+/// Releases the worktree lock when the process is interrupted
+///
+/// Exiting on SIGINT or SIGTERM skips destructors, so without this a lock
+/// taken during creation or deletion would block other `gw` processes until
+/// it goes stale.
+fn install_signal_handler() {
+    let _ = ctrlc::set_handler(|| {
+        release_active_lock();
+        std::process::exit(constants::EXIT_CODE_INTERRUPTED);
+    });
+}
+
+/// Runs the requested subcommand or the interactive menu
+fn run(cli: Cli) -> Result<()> {
     if cli.version {
         let version = env!("CARGO_PKG_VERSION");
         println!("git-workers v{version}");
         return Ok(());
     }
 
+    if cli.quiet {
+        output::set_verbosity(Verbosity::Quiet);
+    }
+
+    if let Some(command) = cli.command {
+        setup_terminal_config();
+        return run_command(command);
+    }
+
     // Terminal check removed - we'll handle errors gracefully when they occur
     // Some terminal environments may not be detected correctly by is_terminal()
 
@@ -118,7 +396,7 @@ fn main() -> Result<()> {
         let _ = io::stdout().flush();
 
         // Print clean header with repository info
-        let repo_info = get_repository_info();
+        let repo_header = get_repository_header();
 
         println!();
         let version = env!("CARGO_PKG_VERSION");
@@ -129,20 +407,42 @@ fn main() -> Result<()> {
         let separator = header_separator();
         println!("{separator}");
         let label = "Repository:".bright_white();
-        let info = repo_info.bright_yellow().bold();
-        println!("{label} {info}");
+        let info = repo_header.info.bright_yellow().bold();
+        match repo_header.details() {
+            Some(details) => {
+                let details = format!("{HEADER_DETAILS_SEPARATOR}{details}").bright_black();
+                println!("{label} {info}{details}");
+            }
+            None => println!("{label} {info}"),
+        }
+        if let Some(focus) = commands::focus_status_line() {
+            let focus = focus.bright_magenta();
+            println!("{focus}");
+        }
+        if cli.dry_run {
+            let dry_run = constants::MSG_DRY_RUN_MODE.bright_yellow();
+            println!("{dry_run}");
+        }
         println!();
 
         // Build menu items
         let menu_items = [
             MenuItem::ListWorktrees,
             MenuItem::SwitchWorktree,
+            MenuItem::SwitchToPrevious,
+            MenuItem::OpenInEditor,
+            MenuItem::ShowCommitId,
             MenuItem::SearchWorktrees,
             MenuItem::CreateWorktree,
+            MenuItem::BatchCreate,
             MenuItem::DeleteWorktree,
             MenuItem::BatchDelete,
             MenuItem::CleanupOldWorktrees,
             MenuItem::RenameWorktree,
+            MenuItem::MoveWorktree,
+            MenuItem::PruneWorktrees,
+            MenuItem::LockWorktree,
+            MenuItem::FocusMode,
             MenuItem::EditHooks,
             MenuItem::Exit,
         ];
@@ -169,7 +469,7 @@ fn main() -> Result<()> {
 
         let selected_item = &menu_items[selection];
 
-        match handle_menu_item(selected_item, &term)? {
+        match handle_menu_item(selected_item, &term, cli.dry_run)? {
             MenuAction::Continue => continue,
             MenuAction::Exit => {
                 clear_screen(&term);
@@ -214,6 +514,7 @@ enum MenuAction {
 ///
 /// * `item` - The selected menu item to execute
 /// * `term` - Terminal instance for screen operations
+/// * `dry_run` - Only show what delete, batch delete, cleanup and prune would remove
 ///
 /// # Returns
 ///
@@ -226,7 +527,7 @@ enum MenuAction {
 ///
 /// Propagates any errors from the command execution. These are typically
 /// handled by displaying an error message to the user.
-fn handle_menu_item(item: &MenuItem, term: &Term) -> Result<MenuAction> {
+fn handle_menu_item(item: &MenuItem, term: &Term, dry_run: bool) -> Result<MenuAction> {
     clear_screen(term);
 
     match item {
@@ -237,22 +538,39 @@ fn handle_menu_item(item: &MenuItem, term: &Term) -> Result<MenuAction> {
                 return Ok(MenuAction::ExitAfterSwitch);
             }
         }
-        MenuItem::DeleteWorktree => commands::delete_worktree()?,
+        MenuItem::BatchCreate => commands::create_worktrees_batch()?,
+        MenuItem::DeleteWorktree => commands::delete_worktree(dry_run)?,
         MenuItem::SwitchWorktree => {
             if commands::switch_worktree()? {
                 // User switched worktree - exit to apply the change
                 return Ok(MenuAction::ExitAfterSwitch);
             }
         }
+        MenuItem::SwitchToPrevious => {
+            if commands::switch_to_previous_worktree()? {
+                return Ok(MenuAction::ExitAfterSwitch);
+            }
+            println!();
+            git_workers::utils::press_any_key_to_continue()?;
+        }
+        MenuItem::OpenInEditor => commands::open_worktree_in_editor()?,
+        MenuItem::ShowCommitId => commands::show_commit_id()?,
         MenuItem::SearchWorktrees => {
             if commands::search_worktrees()? {
                 // User switched worktree via search
                 return Ok(MenuAction::ExitAfterSwitch);
             }
         }
-        MenuItem::BatchDelete => commands::batch_delete_worktrees()?,
-        MenuItem::CleanupOldWorktrees => commands::cleanup_old_worktrees()?,
+        MenuItem::BatchDelete => commands::batch_delete_worktrees(dry_run)?,
+        MenuItem::CleanupOldWorktrees => commands::cleanup_old_worktrees(dry_run)?,
         MenuItem::RenameWorktree => commands::rename_worktree()?,
+        MenuItem::MoveWorktree => commands::move_worktree()?,
+        MenuItem::PruneWorktrees => {
+            commands::prune_worktrees(dry_run)?;
+            git_workers::utils::press_any_key_to_continue()?;
+        }
+        MenuItem::LockWorktree => commands::toggle_worktree_lock()?,
+        MenuItem::FocusMode => commands::focus_mode()?,
         MenuItem::EditHooks => commands::edit_hooks()?,
         MenuItem::Exit => return Ok(MenuAction::Exit),
     }
@@ -260,6 +578,113 @@ fn handle_menu_item(item: &MenuItem, term: &Term) -> Result<MenuAction> {
     Ok(MenuAction::Continue)
 }
 
+/// Runs a subcommand given on the command line
+///
+/// Subcommands bypass the interactive menu and return as soon as the
+/// requested operation is complete.
+fn run_command(command: Command) -> Result<()> {
+    match command {
+        Command::Create {
+            name,
+            branch,
+            from,
+            from_head,
+            path,
+            switch,
+            task,
+            depth,
+            pr,
+        } => commands::create_worktree_from_options(&commands::CreateOptions {
+            name,
+            branch,
+            from,
+            from_head,
+            path,
+            switch,
+            task,
+            depth,
+            pr,
+        }),
+        Command::Delete {
+            name,
+            yes,
+            allow_main,
+            dry_run,
+        } => commands::delete_worktree_by_name(&name, yes, allow_main, dry_run),
+        Command::List {
+            json,
+            porcelain,
+            verbose,
+            sort,
+            desc,
+            pin_current,
+            size,
+            filter,
+        } => {
+            let sort = commands::ListSort {
+                key: sort.unwrap_or_default(),
+                ascending: !desc,
+                pin_current: sort.is_none() || pin_current,
+            };
+            if porcelain {
+                commands::list_worktrees_porcelain(sort, &filter)
+            } else if json || !io::stdout().is_terminal() {
+                commands::list_worktrees_json(sort, &filter)
+            } else {
+                commands::list_worktrees_sorted(verbose, size, sort, &filter)
+            }
+        }
+        Command::Hooks {
+            action: HooksAction::Suggest,
+        } => commands::suggest_hooks(),
+        Command::Hooks {
+            action: HooksAction::Edit { editor },
+        } => commands::edit_hooks_with_editor(editor.as_deref()),
+        Command::Config {
+            action: ConfigAction::Validate,
+        } => commands::validate_config(),
+        Command::Config {
+            action: ConfigAction::Path { explain },
+        } => commands::config_path(explain),
+        Command::Doctor => commands::doctor(),
+        Command::InitLayout => commands::init_layout(),
+        Command::Prune { dry_run } => commands::prune_worktrees(dry_run),
+        Command::Switch { last: true, .. } => commands::switch_to_previous_worktree().map(|_| ()),
+        Command::Switch {
+            filter: Some(filter),
+            ..
+        } => commands::switch_worktree_matching(&filter).map(|_| ()),
+        Command::Switch { name, .. } => {
+            commands::switch_to_worktree(name.as_deref().unwrap_or_default())
+        }
+        Command::Open { name } => commands::open_worktree_by_name(&name),
+        Command::Sha { name } => commands::show_commit_id_by_name(&name),
+        Command::Favorite { name } => commands::favorite_worktree(&name),
+        Command::Unfavorite { name } => commands::unfavorite_worktree(&name),
+        Command::Files => commands::edit_copy_files(),
+        Command::Du => commands::disk_usage(),
+        Command::Status => commands::status_all(),
+        Command::Exec { name, command } => {
+            let code = commands::exec_in_worktree(&name, &command)?;
+            if code != 0 {
+                std::process::exit(code);
+            }
+            Ok(())
+        }
+        Command::Rename {
+            old,
+            new,
+            with_branch,
+        } => commands::rename_worktree_by_name(&old, &new, with_branch),
+        Command::Move { name, path } => commands::move_worktree_by_name(&name, &path),
+        Command::Version { json } => commands::show_version(json),
+        Command::Which { path, branch } => match branch {
+            Some(branch) => commands::which_branch(&branch),
+            None => commands::which_worktree(&path),
+        },
+    }
+}
+
 /// Clears the terminal screen with proper error handling
 ///
 /// This function wraps the terminal clear operation to gracefully handle
@@ -391,7 +816,7 @@ mod tests {
     fn test_handle_menu_item_exit() -> Result<()> {
         // Test handling of Exit menu item
         let term = Term::stdout();
-        let result = handle_menu_item(&MenuItem::Exit, &term)?;
+        let result = handle_menu_item(&MenuItem::Exit, &term, false)?;
 
         match result {
             MenuAction::Exit => { /* expected */ }
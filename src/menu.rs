@@ -42,6 +42,8 @@ pub enum MenuItem {
     SearchWorktrees,
     /// Create a new worktree
     CreateWorktree,
+    /// Create a worktree for each of several branches
+    BatchCreate,
     /// Delete a single worktree
     DeleteWorktree,
     /// Delete multiple worktrees at once
@@ -50,8 +52,22 @@ pub enum MenuItem {
     CleanupOldWorktrees,
     /// Switch to a different worktree (changes directory)
     SwitchWorktree,
+    /// Switch back to the worktree used before the current one
+    SwitchToPrevious,
+    /// Open a worktree in the editor
+    OpenInEditor,
+    /// Print the HEAD commit id of a worktree
+    ShowCommitId,
     /// Rename an existing worktree
     RenameWorktree,
+    /// Move a worktree to another directory
+    MoveWorktree,
+    /// Remove stale entries of worktrees deleted outside git-workers
+    PruneWorktrees,
+    /// Lock a worktree against removal, or unlock it
+    LockWorktree,
+    /// Limit list, switch and search to a chosen set of worktrees
+    FocusMode,
     /// Edit hooks configuration
     EditHooks,
     /// Exit the application
@@ -70,11 +86,19 @@ impl fmt::Display for MenuItem {
     /// - `•` List - Bullet point for viewing items
     /// - `?` Search - Question mark for queries
     /// - `+` Create - Plus sign for adding
+    /// - `⊕` Batch create - Circled plus for adding several at once
     /// - `-` Delete - Minus sign for removing
     /// - `=` Batch - Equals sign for multiple items
     /// - `~` Cleanup - Tilde for maintenance tasks
     /// - `→` Switch - Arrow for navigation
+    /// - `←` Previous - Arrow back to the last worktree
+    /// - `@` Open - At sign for opening a worktree in the editor
+    /// - `^` Commit id - Caret as in `HEAD^`
     /// - `*` Rename - Asterisk for modification
+    /// - `»` Move - Double arrow for relocating
+    /// - `%` Prune - Percent for trimming stale entries
+    /// - `#` Lock - Hash for pinning a worktree in place
+    /// - `◎` Focus - Target for narrowing the view
     /// - `⚙` Settings - Gear for configuration
     /// - `x` Exit - X for closing
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -82,11 +106,19 @@ impl fmt::Display for MenuItem {
             MenuItem::ListWorktrees => write!(f, "{MENU_LIST_WORKTREES}"),
             MenuItem::SearchWorktrees => write!(f, "{MENU_SEARCH_WORKTREES}"),
             MenuItem::CreateWorktree => write!(f, "{MENU_CREATE_WORKTREE}"),
+            MenuItem::BatchCreate => write!(f, "{MENU_BATCH_CREATE}"),
             MenuItem::DeleteWorktree => write!(f, "{MENU_DELETE_WORKTREE}"),
             MenuItem::BatchDelete => write!(f, "{MENU_BATCH_DELETE}"),
             MenuItem::CleanupOldWorktrees => write!(f, "{MENU_CLEANUP_OLD}"),
             MenuItem::SwitchWorktree => write!(f, "{MENU_SWITCH_WORKTREE}"),
+            MenuItem::SwitchToPrevious => write!(f, "{MENU_SWITCH_PREVIOUS}"),
+            MenuItem::OpenInEditor => write!(f, "{MENU_OPEN_IN_EDITOR}"),
+            MenuItem::ShowCommitId => write!(f, "{MENU_SHOW_COMMIT_ID}"),
             MenuItem::RenameWorktree => write!(f, "{MENU_RENAME_WORKTREE}"),
+            MenuItem::MoveWorktree => write!(f, "{MENU_MOVE_WORKTREE}"),
+            MenuItem::PruneWorktrees => write!(f, "{MENU_PRUNE_WORKTREES}"),
+            MenuItem::LockWorktree => write!(f, "{MENU_LOCK_WORKTREE}"),
+            MenuItem::FocusMode => write!(f, "{MENU_FOCUS_MODE}"),
             MenuItem::EditHooks => write!(f, "{MENU_EDIT_HOOKS}"),
             MenuItem::Exit => write!(f, "{MENU_EXIT}"),
         }
@@ -121,6 +153,14 @@ mod tests {
         assert!(formatted.contains(MENU_CREATE_WORKTREE));
     }
 
+    #[test]
+    fn test_fmt_batch_create() {
+        let item = MenuItem::BatchCreate;
+        let formatted = format!("{item}");
+        assert!(!formatted.is_empty());
+        assert!(formatted.contains(MENU_BATCH_CREATE));
+    }
+
     #[test]
     fn test_fmt_delete_worktree() {
         let item = MenuItem::DeleteWorktree;
@@ -153,6 +193,22 @@ mod tests {
         assert!(formatted.contains(MENU_SWITCH_WORKTREE));
     }
 
+    #[test]
+    fn test_fmt_switch_to_previous() {
+        let item = MenuItem::SwitchToPrevious;
+        let formatted = format!("{item}");
+        assert!(!formatted.is_empty());
+        assert!(formatted.contains(MENU_SWITCH_PREVIOUS));
+    }
+
+    #[test]
+    fn test_fmt_open_in_editor() {
+        let item = MenuItem::OpenInEditor;
+        let formatted = format!("{item}");
+        assert!(!formatted.is_empty());
+        assert!(formatted.contains(MENU_OPEN_IN_EDITOR));
+    }
+
     #[test]
     fn test_fmt_rename_worktree() {
         let item = MenuItem::RenameWorktree;
@@ -161,6 +217,46 @@ mod tests {
         assert!(formatted.contains(MENU_RENAME_WORKTREE));
     }
 
+    #[test]
+    fn test_fmt_prune_worktrees() {
+        let item = MenuItem::PruneWorktrees;
+        let formatted = format!("{item}");
+        assert!(!formatted.is_empty());
+        assert!(formatted.contains(MENU_PRUNE_WORKTREES));
+    }
+
+    #[test]
+    fn test_fmt_move_worktree() {
+        let item = MenuItem::MoveWorktree;
+        let formatted = format!("{item}");
+        assert!(!formatted.is_empty());
+        assert!(formatted.contains(MENU_MOVE_WORKTREE));
+    }
+
+    #[test]
+    fn test_fmt_lock_worktree() {
+        let item = MenuItem::LockWorktree;
+        let formatted = format!("{item}");
+        assert!(!formatted.is_empty());
+        assert!(formatted.contains(MENU_LOCK_WORKTREE));
+    }
+
+    #[test]
+    fn test_fmt_show_commit_id() {
+        let item = MenuItem::ShowCommitId;
+        let formatted = format!("{item}");
+        assert!(!formatted.is_empty());
+        assert!(formatted.contains(MENU_SHOW_COMMIT_ID));
+    }
+
+    #[test]
+    fn test_fmt_focus_mode() {
+        let item = MenuItem::FocusMode;
+        let formatted = format!("{item}");
+        assert!(!formatted.is_empty());
+        assert!(formatted.contains(MENU_FOCUS_MODE));
+    }
+
     #[test]
     fn test_fmt_edit_hooks() {
         let item = MenuItem::EditHooks;
@@ -188,7 +284,14 @@ mod tests {
             MenuItem::BatchDelete,
             MenuItem::CleanupOldWorktrees,
             MenuItem::SwitchWorktree,
+            MenuItem::SwitchToPrevious,
+            MenuItem::OpenInEditor,
+            MenuItem::ShowCommitId,
             MenuItem::RenameWorktree,
+            MenuItem::MoveWorktree,
+            MenuItem::PruneWorktrees,
+            MenuItem::LockWorktree,
+            MenuItem::FocusMode,
             MenuItem::EditHooks,
             MenuItem::Exit,
         ];
@@ -12,14 +12,222 @@
 //! - **Main worktrees**: The primary working directory with worktrees
 //! - **Worktrees**: Secondary working directories linked to a main repository
 //! - **Standard repositories**: Regular Git repositories without worktrees
+//! - **Submodules**: Repositories checked out inside a superproject
 //! - **Non-Git directories**: Fallback for directories outside Git control
 
-use crate::constants::{MAIN_SUFFIX, UNKNOWN_VALUE};
+use crate::constants::{
+    BARE_SUFFIX, DEFAULT_BRANCH_DETACHED, GIT_DIR, GIT_SUFFIX, HEADER_DETAILS_SEPARATOR,
+    MAIN_SUFFIX, REMOTE_ORIGIN, REMOTE_SHORTENED_HOSTS, REPO_INFO_BARE, REPO_INFO_SUBMODULE,
+    REPO_INFO_WORKTREE_OF, UNKNOWN_VALUE,
+};
 #[cfg(not(test))]
 use crate::git::GitWorktreeManager;
+use git2::Repository;
 use std::env;
+use std::path::Path;
 use std::process::Command;
 
+/// Repository context shown in the main menu header
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RepositoryHeader {
+    /// Repository and worktree name, as returned by [`get_repository_info`]
+    pub info: String,
+    /// Remotes as (name, URL), `origin` first and the others by name
+    pub remotes: Vec<(String, String)>,
+    /// Branch checked out in the current worktree (`None` in bare repositories)
+    pub branch: Option<String>,
+}
+
+impl RepositoryHeader {
+    /// The remote shown in the header: `origin`, or the first one otherwise
+    pub fn primary_remote(&self) -> Option<&(String, String)> {
+        self.remotes.first()
+    }
+
+    /// Returns the remote and branch joined for display, if any is known
+    ///
+    /// The primary remote's URL is shortened to `owner/repo` for GitHub and
+    /// GitLab.
+    pub fn details(&self) -> Option<String> {
+        let remote = self
+            .primary_remote()
+            .map(|(_, url)| shorten_remote_url(url));
+        let details: Vec<&str> = [remote.as_deref(), self.branch.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect();
+        if details.is_empty() {
+            None
+        } else {
+            Some(details.join(HEADER_DETAILS_SEPARATOR))
+        }
+    }
+}
+
+/// Pure business logic for shortening a remote URL for display
+///
+/// GitHub and GitLab URLs in HTTPS, SSH or scp-like form become
+/// `owner/repo` (or `group/subgroup/repo`). Other URLs are returned as-is.
+pub fn shorten_remote_url(url: &str) -> String {
+    let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let without_user = without_scheme
+        .split_once('@')
+        .map_or(without_scheme, |(_, rest)| rest);
+
+    for host in REMOTE_SHORTENED_HOSTS {
+        let path = without_user
+            .strip_prefix(host)
+            .and_then(|rest| rest.strip_prefix([':', '/']));
+        if let Some(path) = path {
+            let path = path.trim_end_matches('/');
+            let path = path.strip_suffix(GIT_SUFFIX).unwrap_or(path);
+            if !path.is_empty() {
+                return path.to_string();
+            }
+        }
+    }
+    url.to_string()
+}
+
+/// The kind of repository a directory belongs to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RepositoryKind {
+    /// Not inside a Git repository
+    NotARepository,
+    /// A regular repository or its main worktree
+    Standard,
+    /// A bare repository (or a directory inside one)
+    Bare,
+    /// A linked worktree of the repository named `main`
+    LinkedWorktree { main: String },
+    /// A submodule of the superproject around it, with its submodule name
+    Submodule { name: String },
+}
+
+/// Detects the kind of repository containing `path`
+///
+/// Uses [`Repository::discover`]; linked worktrees are recognized with
+/// [`Repository::is_worktree`] and submodules by looking the directory up
+/// in the submodules of the enclosing repository.
+pub fn detect_repository_kind(path: &Path) -> RepositoryKind {
+    let repo = match Repository::discover(path) {
+        Ok(repo) => repo,
+        Err(_) => return RepositoryKind::NotARepository,
+    };
+
+    if repo.is_bare() {
+        RepositoryKind::Bare
+    } else if repo.is_worktree() {
+        RepositoryKind::LinkedWorktree {
+            main: repository_name_from_common_dir(repo.commondir())
+                .unwrap_or_else(|| UNKNOWN_VALUE.to_string()),
+        }
+    } else if let Some(name) = submodule_name(&repo) {
+        RepositoryKind::Submodule { name }
+    } else {
+        RepositoryKind::Standard
+    }
+}
+
+/// Pure business logic for naming a repository after its common directory
+///
+/// `/src/app/.git` is `app`; bare repositories drop a `.bare` or `.git`
+/// suffix, so `/src/app.bare` is `app` too.
+pub fn repository_name_from_common_dir(common_dir: &Path) -> Option<String> {
+    let name = common_dir.file_name()?.to_str()?;
+    if name == GIT_DIR {
+        return common_dir
+            .parent()?
+            .file_name()?
+            .to_str()
+            .map(str::to_string);
+    }
+    let name = name
+        .strip_suffix(BARE_SUFFIX)
+        .or_else(|| name.strip_suffix(GIT_SUFFIX))
+        .unwrap_or(name);
+    Some(name.to_string())
+}
+
+/// Returns the submodule name of `repo` if the repository around it has it
+/// as a submodule
+fn submodule_name(repo: &Repository) -> Option<String> {
+    let workdir = repo.workdir()?;
+    let superproject = Repository::discover(workdir.parent()?).ok()?;
+    let relative = workdir.strip_prefix(superproject.workdir()?).ok()?;
+    let submodules = superproject.submodules().ok()?;
+    let submodule = submodules.iter().find(|s| s.path() == relative)?;
+    Some(
+        submodule
+            .name()
+            .map(str::to_string)
+            .unwrap_or_else(|| relative.display().to_string()),
+    )
+}
+
+/// Gets the repository context for the header, including remote and branch
+///
+/// Anything that cannot be read (no remote, unborn branch, not a
+/// repository) is left out instead of failing.
+pub fn get_repository_header() -> RepositoryHeader {
+    let current_dir = env::current_dir().unwrap_or_else(|_| UNKNOWN_VALUE.into());
+    get_repository_header_at_path(&current_dir)
+}
+
+/// Gets the header context for the repository containing `path`
+pub fn get_repository_header_at_path(path: &Path) -> RepositoryHeader {
+    let info = get_repository_info_at_path(path);
+    let repo = match Repository::discover(path) {
+        Ok(repo) => repo,
+        Err(_) => {
+            return RepositoryHeader {
+                info,
+                ..RepositoryHeader::default()
+            }
+        }
+    };
+
+    let remotes = repository_remotes(&repo);
+    let branch = if repo.is_bare() {
+        None
+    } else {
+        repo.head().ok().map(|head| {
+            if head.is_branch() {
+                head.shorthand()
+                    .unwrap_or(DEFAULT_BRANCH_DETACHED)
+                    .to_string()
+            } else {
+                DEFAULT_BRANCH_DETACHED.to_string()
+            }
+        })
+    };
+
+    RepositoryHeader {
+        info,
+        remotes,
+        branch,
+    }
+}
+
+/// Lists the remotes of `repo` as (name, URL), `origin` first
+///
+/// Remotes without a URL are left out.
+fn repository_remotes(repo: &Repository) -> Vec<(String, String)> {
+    let Ok(names) = repo.remotes() else {
+        return Vec::new();
+    };
+    let mut remotes: Vec<(String, String)> = names
+        .iter()
+        .flatten()
+        .filter_map(|name| {
+            let remote = repo.find_remote(name).ok()?;
+            Some((name.to_string(), remote.url()?.to_string()))
+        })
+        .collect();
+    remotes.sort_by(|(a, _), (b, _)| (a != REMOTE_ORIGIN, a).cmp(&(b != REMOTE_ORIGIN, b)));
+    remotes
+}
+
 /// Get repository name using git directory analysis
 ///
 /// Uses `git rev-parse --git-dir` to find the git directory and traces back
@@ -112,30 +320,60 @@ fn get_repo_name_from_git() -> Option<String> {
 /// Gets a formatted string representing the current repository context
 ///
 /// This function determines whether we're in a bare repository, a worktree,
-/// or the main working directory and formats the information accordingly.
+/// a submodule or the main working directory and formats the information
+/// accordingly.
 ///
 /// # Return Format
 ///
-/// - For bare repositories: `"repo-name.bare"`
-/// - For worktrees: `"parent-repo (worktree-name)"`
+/// - For bare repositories: `"repo-name (bare)"`
+/// - For linked worktrees: `"worktree-name (worktree of repo-name)"`
+/// - For submodules: `"submodule: submodule-name"`
 /// - For main worktree: `"repo-name (main)"`
 /// - For non-git directories: `"directory-name"`
 ///
 /// # Example Output
 ///
 /// ```text
-/// my-project.bare              // Bare repository
-/// my-project (feature-branch)  // Worktree
-/// my-project (main)           // Main worktree with other worktrees
-/// my-project                  // Regular repository without worktrees
+/// my-project (bare)                        // Bare repository
+/// feature-branch (worktree of my-project)  // Linked worktree
+/// submodule: vendor/lib                    // Submodule
+/// my-project (main)                        // Main worktree with other worktrees
+/// my-project                               // Regular repository without worktrees
 /// ```
 pub fn get_repository_info() -> String {
     get_repository_info_at_path(&env::current_dir().unwrap_or_else(|_| UNKNOWN_VALUE.into()))
 }
 
-/// Gets repository info for a specific path (internal, used for testing)
+/// Gets repository info for a specific path
+///
+/// The repository name comes from the layout of the Git directory; the
+/// label from [`detect_repository_kind`].
+pub fn get_repository_info_at_path(path: &Path) -> String {
+    let name = repository_name_at_path(path);
+    match detect_repository_kind(path) {
+        RepositoryKind::Bare => REPO_INFO_BARE.replace("{}", &name),
+        RepositoryKind::LinkedWorktree { main } => {
+            let worktree_name = Repository::discover(path)
+                .ok()
+                .and_then(|repo| {
+                    repo.workdir()
+                        .and_then(|workdir| workdir.file_name())
+                        .and_then(|name| name.to_str())
+                        .map(str::to_string)
+                })
+                .unwrap_or(name);
+            REPO_INFO_WORKTREE_OF
+                .replacen("{}", &worktree_name, 1)
+                .replacen("{}", &main, 1)
+        }
+        RepositoryKind::Submodule { name } => REPO_INFO_SUBMODULE.replace("{}", &name),
+        RepositoryKind::Standard | RepositoryKind::NotARepository => name,
+    }
+}
+
+/// Gets the repository name for a specific path (internal, used for testing)
 #[cfg(test)]
-pub fn get_repository_info_at_path(path: &std::path::Path) -> String {
+fn repository_name_at_path(path: &std::path::Path) -> String {
     use std::process::Stdio;
 
     // Run git commands in the specified directory
@@ -235,9 +473,9 @@ pub fn get_repository_info_at_path(path: &std::path::Path) -> String {
         .to_string()
 }
 
-/// Gets repository info for production (non-test) use
+/// Gets the repository name for production (non-test) use
 #[cfg(not(test))]
-pub fn get_repository_info_at_path(_path: &std::path::Path) -> String {
+fn repository_name_at_path(_path: &std::path::Path) -> String {
     // Try to get Git repository information
     if let Ok(manager) = GitWorktreeManager::new() {
         let repo = manager.repo();
@@ -323,6 +561,110 @@ mod tests {
         // Should return some directory name
     }
 
+    #[test]
+    fn test_shorten_remote_url() {
+        let cases = [
+            (
+                "https://github.com/wasabeef/git-workers.git",
+                "wasabeef/git-workers",
+            ),
+            (
+                "git@github.com:wasabeef/git-workers.git",
+                "wasabeef/git-workers",
+            ),
+            (
+                "ssh://git@github.com/wasabeef/git-workers",
+                "wasabeef/git-workers",
+            ),
+            (
+                "https://gitlab.com/group/sub/project.git",
+                "group/sub/project",
+            ),
+            (
+                "https://example.com/owner/repo.git",
+                "https://example.com/owner/repo.git",
+            ),
+            ("/srv/git/repo.git", "/srv/git/repo.git"),
+        ];
+        for (url, expected) in cases {
+            assert_eq!(shorten_remote_url(url), expected, "{url}");
+        }
+    }
+
+    #[test]
+    fn test_repository_header_with_remote_and_branch() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let repo = Repository::init(temp_dir.path()).unwrap();
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        let commit = repo
+            .commit(Some("HEAD"), &sig, &sig, "initial", &tree, &[])
+            .unwrap();
+        let commit = repo.find_commit(commit).unwrap();
+        repo.branch("develop", &commit, false).unwrap();
+        repo.set_head("refs/heads/develop").unwrap();
+        repo.remote("fork", "git@github.com:me/repo.git").unwrap();
+        repo.remote(REMOTE_ORIGIN, "git@github.com:owner/repo.git")
+            .unwrap();
+
+        let header = get_repository_header_at_path(temp_dir.path());
+        assert_eq!(
+            header.remotes,
+            vec![
+                (
+                    REMOTE_ORIGIN.to_string(),
+                    "git@github.com:owner/repo.git".to_string()
+                ),
+                ("fork".to_string(), "git@github.com:me/repo.git".to_string()),
+            ]
+        );
+        assert_eq!(header.branch.as_deref(), Some("develop"));
+        assert_eq!(header.details().unwrap(), "owner/repo · develop");
+    }
+
+    #[test]
+    fn test_repository_header_bare_without_remote() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let bare_path = temp_dir.path().join("repo.bare");
+        Repository::init_bare(&bare_path).unwrap();
+
+        let header = get_repository_header_at_path(&bare_path);
+        assert_eq!(header.info, "repo (bare)");
+        assert!(header.remotes.is_empty());
+        assert_eq!(header.branch, None);
+        assert_eq!(header.details(), None);
+    }
+
+    #[test]
+    fn test_repository_name_from_common_dir() {
+        use std::path::PathBuf;
+
+        let name = |path: &str| repository_name_from_common_dir(&PathBuf::from(path));
+        assert_eq!(name("/src/app/.git").as_deref(), Some("app"));
+        assert_eq!(name("/src/app/.git/").as_deref(), Some("app"));
+        assert_eq!(name("/src/app.bare").as_deref(), Some("app"));
+        assert_eq!(name("/srv/git/app.git").as_deref(), Some("app"));
+        assert_eq!(name("/srv/git/app").as_deref(), Some("app"));
+    }
+
+    #[test]
+    fn test_detect_repository_kind() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        assert_eq!(
+            detect_repository_kind(temp_dir.path()),
+            RepositoryKind::NotARepository
+        );
+
+        let repo_path = temp_dir.path().join("app");
+        Repository::init(&repo_path).unwrap();
+        assert_eq!(detect_repository_kind(&repo_path), RepositoryKind::Standard);
+
+        let bare_path = temp_dir.path().join("app.bare");
+        Repository::init_bare(&bare_path).unwrap();
+        assert_eq!(detect_repository_kind(&bare_path), RepositoryKind::Bare);
+    }
+
     #[test]
     fn test_constants_are_used() {
         // Test that our constants are defined and accessible
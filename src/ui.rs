@@ -5,7 +5,8 @@
 
 use anyhow::Result;
 use dialoguer::{Confirm, FuzzySelect, MultiSelect, Select};
-use std::collections::VecDeque;
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
 
 use crate::input_esc_raw::{input_esc_raw, input_esc_with_default_raw};
 use crate::utils::get_theme;
@@ -20,6 +21,9 @@ const ERROR_NO_MORE_SELECTIONS: &str = "No more selections configured for MockUI
 const ERROR_NO_MORE_INPUTS: &str = "No more inputs configured for MockUI";
 const ERROR_NO_MORE_CONFIRMATIONS: &str = "No more confirmations configured for MockUI";
 const ERROR_NO_MORE_MULTISELECTS: &str = "No more multiselects configured for MockUI";
+const ERROR_NON_INTERACTIVE_PROMPT: &str =
+    "'{}' needs an answer that was not given on the command line";
+const ERROR_NON_INTERACTIVE_NO_MATCH: &str = "No option '{}' for '{}'";
 
 // Test constants
 #[cfg(test)]
@@ -70,6 +74,14 @@ pub trait UserInterface {
     /// Display a multi-selection menu and return selected indices
     #[allow(dead_code)]
     fn multiselect(&self, prompt: &str, items: &[String]) -> Result<Vec<usize>>;
+
+    /// Whether a person is answering the prompts
+    ///
+    /// Commands skip "press any key" pauses and report failures as errors
+    /// instead of messages when this is `false`.
+    fn is_interactive(&self) -> bool {
+        true
+    }
 }
 
 /// Production implementation using dialoguer
@@ -134,6 +146,170 @@ impl UserInterface for DialoguerUI {
     }
 }
 
+/// An answer prepared for a selection prompt
+enum Choice {
+    /// Pick the item at this index
+    Index(usize),
+    /// Pick the item whose first word is this label
+    Label(String),
+    /// Accept the default offered by the prompt
+    Default,
+}
+
+/// Non-interactive implementation that answers prompts from command-line values
+///
+/// Answers are keyed by prompt text. A prompt without a prepared answer
+/// fails instead of blocking, and the first such prompt is remembered so
+/// the caller can report it with [`NonInteractiveUI::unanswered`]. With
+/// [`NonInteractiveUI::assume_yes`], yes/no prompts without a prepared
+/// answer are answered with yes (`gw delete --yes`).
+#[derive(Default)]
+pub struct NonInteractiveUI {
+    choices: HashMap<String, Choice>,
+    inputs: HashMap<String, String>,
+    confirms: HashMap<String, bool>,
+    assume_yes: bool,
+    unanswered: RefCell<Option<String>>,
+}
+
+impl NonInteractiveUI {
+    /// Create a NonInteractiveUI with no prepared answers
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answer a selection prompt with an item index
+    pub fn with_choice(mut self, prompt: &str, index: usize) -> Self {
+        self.choices
+            .insert(prompt.to_string(), Choice::Index(index));
+        self
+    }
+
+    /// Answer a selection prompt with the item labelled `label`
+    ///
+    /// Items match when their first word containing a letter or digit equals
+    /// `label`, so icons and trailing notes such as "(in use by ...)" are
+    /// ignored.
+    pub fn with_label(mut self, prompt: &str, label: impl Into<String>) -> Self {
+        self.choices
+            .insert(prompt.to_string(), Choice::Label(label.into()));
+        self
+    }
+
+    /// Answer a selection prompt with its default item
+    pub fn with_default(mut self, prompt: &str) -> Self {
+        self.choices.insert(prompt.to_string(), Choice::Default);
+        self
+    }
+
+    /// Answer a text prompt
+    pub fn with_input(mut self, prompt: &str, input: impl Into<String>) -> Self {
+        self.inputs.insert(prompt.to_string(), input.into());
+        self
+    }
+
+    /// Answer a yes/no prompt
+    pub fn with_confirm(mut self, prompt: &str, confirm: bool) -> Self {
+        self.confirms.insert(prompt.to_string(), confirm);
+        self
+    }
+
+    /// Answer every yes/no prompt without a prepared answer with yes
+    pub fn assume_yes(mut self, assume_yes: bool) -> Self {
+        self.assume_yes = assume_yes;
+        self
+    }
+
+    /// Returns the first prompt that could not be answered, if any
+    pub fn unanswered(&self) -> Option<String> {
+        self.unanswered.borrow().clone()
+    }
+
+    /// Records an unanswerable prompt and returns the error for it
+    fn fail(&self, message: String) -> anyhow::Error {
+        self.unanswered
+            .borrow_mut()
+            .get_or_insert_with(|| message.clone());
+        anyhow::anyhow!(message)
+    }
+
+    fn choose(&self, prompt: &str, items: &[String], default: Option<usize>) -> Result<usize> {
+        let missing = || ERROR_NON_INTERACTIVE_PROMPT.replace("{}", prompt);
+        match self.choices.get(prompt) {
+            Some(Choice::Index(index)) if *index < items.len() => Ok(*index),
+            Some(Choice::Label(label)) => items
+                .iter()
+                .position(|item| {
+                    item.split_whitespace()
+                        .find(|word| word.chars().any(char::is_alphanumeric))
+                        == Some(label.as_str())
+                })
+                .ok_or_else(|| {
+                    self.fail(
+                        ERROR_NON_INTERACTIVE_NO_MATCH
+                            .replacen("{}", label, 1)
+                            .replacen("{}", prompt, 1),
+                    )
+                }),
+            Some(Choice::Default) => default.ok_or_else(|| self.fail(missing())),
+            _ => Err(self.fail(missing())),
+        }
+    }
+}
+
+impl UserInterface for NonInteractiveUI {
+    fn select(&self, prompt: &str, items: &[String]) -> Result<usize> {
+        self.choose(prompt, items, None)
+    }
+
+    fn select_with_default(&self, prompt: &str, items: &[String], default: usize) -> Result<usize> {
+        self.choose(prompt, items, Some(default))
+    }
+
+    fn fuzzy_select(&self, prompt: &str, items: &[String]) -> Result<usize> {
+        self.choose(prompt, items, None)
+    }
+
+    fn input(&self, prompt: &str) -> Result<String> {
+        self.inputs
+            .get(prompt)
+            .cloned()
+            .ok_or_else(|| self.fail(ERROR_NON_INTERACTIVE_PROMPT.replace("{}", prompt)))
+    }
+
+    fn input_with_default(&self, prompt: &str, default: &str) -> Result<String> {
+        Ok(self
+            .inputs
+            .get(prompt)
+            .cloned()
+            .unwrap_or_else(|| default.to_string()))
+    }
+
+    fn confirm(&self, prompt: &str) -> Result<bool> {
+        match self.confirms.get(prompt) {
+            Some(confirm) => Ok(*confirm),
+            None if self.assume_yes => Ok(true),
+            None => Err(self.fail(ERROR_NON_INTERACTIVE_PROMPT.replace("{}", prompt))),
+        }
+    }
+
+    fn confirm_with_default(&self, prompt: &str, default: bool) -> Result<bool> {
+        Ok(self
+            .confirms
+            .get(prompt)
+            .copied()
+            .unwrap_or(self.assume_yes || default))
+    }
+
+    fn multiselect(&self, prompt: &str, _items: &[String]) -> Result<Vec<usize>> {
+        Err(self.fail(ERROR_NON_INTERACTIVE_PROMPT.replace("{}", prompt)))
+    }
+
+    fn is_interactive(&self) -> bool {
+        false
+    }
+}
+
 /// Mock implementation for testing
 ///
 /// Uses interior mutability to allow mutable access through immutable references,
@@ -288,6 +464,57 @@ mod tests {
         assert_eq!(mock_ui.multiselects.borrow().len(), 1);
     }
 
+    #[test]
+    fn test_non_interactive_ui_answers_by_prompt() -> Result<()> {
+        let items = vec![
+            "💻 main (in use by 'repo')".to_string(),
+            "💻 feature".to_string(),
+            "⛅️ origin/main".to_string(),
+        ];
+        let ui = NonInteractiveUI::new()
+            .with_input(TEST_PROMPT, TEST_INPUT_BRANCH)
+            .with_label(TEST_OPTION_A, "main")
+            .with_label(TEST_OPTION_B, "origin/main");
+
+        assert_eq!(ui.input(TEST_PROMPT)?, TEST_INPUT_BRANCH);
+        assert_eq!(ui.fuzzy_select(TEST_OPTION_A, &items)?, 0);
+        assert_eq!(ui.select(TEST_OPTION_B, &items)?, 2);
+        assert!(ui.confirm_with_default(TEST_PROMPT, true)?);
+        assert!(!ui.is_interactive());
+        assert_eq!(ui.unanswered(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_non_interactive_ui_records_unanswered_prompt() {
+        let items = vec![TEST_OPTION_A.to_string()];
+        let ui = NonInteractiveUI::new().with_label(TEST_PROMPT, "missing");
+
+        assert!(ui.select(TEST_PROMPT, &items).is_err());
+        assert!(ui.input(TEST_OPTION_B).is_err());
+
+        // Only the first failure is kept
+        let unanswered = ui.unanswered().unwrap();
+        assert!(unanswered.contains("missing"));
+    }
+
+    #[test]
+    fn test_non_interactive_ui_assume_yes() -> Result<()> {
+        let ui = NonInteractiveUI::new();
+        assert!(!ui.confirm_with_default(TEST_PROMPT, false)?);
+        assert!(ui.confirm(TEST_PROMPT).is_err());
+
+        let ui = NonInteractiveUI::new()
+            .assume_yes(true)
+            .with_confirm(TEST_OPTION_B, false);
+        assert!(ui.confirm_with_default(TEST_PROMPT, false)?);
+        assert!(ui.confirm(TEST_OPTION_A)?);
+        // Prepared answers still win
+        assert!(!ui.confirm_with_default(TEST_OPTION_B, true)?);
+        assert_eq!(ui.unanswered(), None);
+        Ok(())
+    }
+
     #[test]
     fn test_mock_ui_exhaustion_check() {
         let mock_ui = MockUI::new();
@@ -11,13 +11,20 @@
 //! - **Color Coding**: Green for success, red for errors, yellow for warnings/progress
 //! - **Line Overwriting**: Progress messages can be overwritten by results
 //! - **Immediate Feedback**: All output is flushed immediately
+//! - **Quiet Mode**: With `--quiet` (see [`output`]) progress messages are
+//!   dropped and results are printed without icons
 
+pub mod output;
+
+use crate::config::SwitchChannel;
 use crate::constants::*;
+use chrono::{DateTime, Utc};
 use colored::*;
 use console::Term;
 use dialoguer::theme::ColorfulTheme;
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 /// Displays a progress message with a spinning hourglass emoji
 ///
@@ -39,6 +46,9 @@ use std::path::{Path, PathBuf};
 /// // Then call print_success or print_error to overwrite
 /// ```
 pub fn print_progress(message: &str) {
+    if output::is_quiet() {
+        return;
+    }
     let spinner = ICON_SPINNER.yellow();
     print!("{spinner} {message}");
     io::stdout().flush().unwrap();
@@ -64,6 +74,10 @@ pub fn print_progress(message: &str) {
 /// print_success("Worktree created successfully!");
 /// ```
 pub fn print_success(message: &str) {
+    if output::is_quiet() {
+        println!("{message}");
+        return;
+    }
     let checkmark = ICON_SUCCESS.green();
     println!("\r{checkmark} {message}");
 }
@@ -87,6 +101,10 @@ pub fn print_success(message: &str) {
 /// print_error("Failed to create worktree: permission denied");
 /// ```
 pub fn print_error(message: &str) {
+    if output::is_quiet() {
+        println!("{QUIET_PREFIX_ERROR}{message}");
+        return;
+    }
     let cross = ICON_ERROR.red();
     println!("\r{cross} {message}");
 }
@@ -108,6 +126,10 @@ pub fn print_error(message: &str) {
 /// print_warning("Hook execution failed: command not found");
 /// ```
 pub fn print_warning(message: &str) {
+    if output::is_quiet() {
+        println!("{QUIET_PREFIX_WARNING}{message}");
+        return;
+    }
     let warning = ICON_WARNING.yellow();
     println!("{warning} {message}");
 }
@@ -201,6 +223,155 @@ pub fn press_any_key_to_continue() -> io::Result<()> {
     Ok(())
 }
 
+/// Copies `text` to the system clipboard
+///
+/// Only available with the `clipboard` cargo feature. On Linux the text is
+/// kept after `gw` exits only if a clipboard manager is running.
+#[cfg(feature = "clipboard")]
+pub fn copy_to_clipboard(text: &str) -> anyhow::Result<()> {
+    arboard::Clipboard::new()?.set_text(text)?;
+    Ok(())
+}
+
+/// Returns the editor command to use
+///
+/// `editor_override` (e.g. `--editor`) wins, then `[editor] command`, then
+/// `$EDITOR`, `$VISUAL`, and finally `notepad` on Windows and `vi`
+/// elsewhere. The result may contain arguments; split it with
+/// [`split_command_line`].
+pub fn resolve_editor(editor_override: Option<&str>) -> String {
+    let configured = crate::config::Config::load()
+        .ok()
+        .and_then(|config| config.editor.command);
+    choose_editor([
+        editor_override.map(str::to_string),
+        configured,
+        std::env::var(ENV_EDITOR).ok(),
+        std::env::var(ENV_VISUAL).ok(),
+    ])
+}
+
+/// Pure business logic for choosing the editor command
+///
+/// The first non-blank candidate wins; without one the platform default is
+/// used.
+pub fn choose_editor(candidates: impl IntoIterator<Item = Option<String>>) -> String {
+    candidates
+        .into_iter()
+        .flatten()
+        .find(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| {
+            if cfg!(target_os = "windows") {
+                DEFAULT_EDITOR_WINDOWS.to_string()
+            } else {
+                DEFAULT_EDITOR_UNIX.to_string()
+            }
+        })
+}
+
+/// Pure business logic for splitting a command line into arguments
+///
+/// Arguments are separated by whitespace. Single quotes keep everything up
+/// to the closing quote; inside double quotes and unquoted text a backslash
+/// escapes the next character. So `"/opt/My Editor/bin/edit" --wait` is
+/// two arguments.
+pub fn split_command_line(command: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_arg = false;
+    let mut quote: Option<char> = None;
+    let mut chars = command.chars();
+
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some('\''), '\'') | (Some('"'), '"') => quote = None,
+            (Some('\''), _) => current.push(c),
+            (_, '\\') => {
+                if let Some(escaped) = chars.next() {
+                    current.push(escaped);
+                }
+                in_arg = true;
+            }
+            (Some(_), _) => current.push(c),
+            (None, '\'' | '"') => {
+                quote = Some(c);
+                in_arg = true;
+            }
+            (None, c) if c.is_whitespace() => {
+                if in_arg {
+                    args.push(std::mem::take(&mut current));
+                    in_arg = false;
+                }
+            }
+            (None, _) => {
+                current.push(c);
+                in_arg = true;
+            }
+        }
+    }
+    if in_arg {
+        args.push(current);
+    }
+    args
+}
+
+/// Whether `GW_DEBUG` is set to a non-empty value
+pub fn debug_enabled() -> bool {
+    std::env::var_os(ENV_GW_DEBUG).is_some_and(|value| !value.is_empty())
+}
+
+/// Guard returned by [`debug_timer`] that reports the elapsed time on drop
+pub struct DebugTimer {
+    label: String,
+    detail: Option<String>,
+    start: Option<Instant>,
+}
+
+impl DebugTimer {
+    /// Adds context printed after the time, e.g. `for 23 worktrees`
+    pub fn set_detail(&mut self, detail: impl Into<String>) {
+        if self.start.is_some() {
+            self.detail = Some(detail.into());
+        }
+    }
+}
+
+impl Drop for DebugTimer {
+    fn drop(&mut self) {
+        if let Some(start) = self.start {
+            let line = format_debug_timing(&self.label, start.elapsed(), self.detail.as_deref());
+            eprintln!("{line}");
+        }
+    }
+}
+
+/// Starts timing an operation for `GW_DEBUG` diagnostics
+///
+/// When `GW_DEBUG` is set, the returned guard prints
+/// `<label>: <ms>ms [detail]` to stderr when it is dropped; otherwise it
+/// does nothing.
+pub fn debug_timer(label: impl Into<String>) -> DebugTimer {
+    let start = debug_enabled().then(Instant::now);
+    DebugTimer {
+        label: if start.is_some() {
+            label.into()
+        } else {
+            String::new()
+        },
+        detail: None,
+        start,
+    }
+}
+
+/// Pure business logic for a timing line, e.g. `list_worktrees: 412ms for 23 worktrees`
+pub fn format_debug_timing(label: &str, elapsed: Duration, detail: Option<&str>) -> String {
+    let ms = elapsed.as_millis();
+    match detail {
+        Some(detail) => format!("{label}: {ms}ms {detail}"),
+        None => format!("{label}: {ms}ms"),
+    }
+}
+
 /// Writes the worktree path for shell integration
 ///
 /// This function handles the logic for communicating with the shell wrapper
@@ -214,7 +385,8 @@ pub fn press_any_key_to_continue() -> io::Result<()> {
 ///
 /// Two methods are supported:
 /// 1. File-based: Writes to file specified by `GW_SWITCH_FILE` environment variable
-/// 2. Stdout marker: Prints `SWITCH_TO:` prefix followed by the path
+/// 2. Marker: Prints `SWITCH_TO:` prefix followed by the path, on stdout or,
+///    with `[shell] switch_channel = "stderr"`, on stderr
 ///
 /// # Example
 ///
@@ -227,12 +399,83 @@ pub fn press_any_key_to_continue() -> io::Result<()> {
 /// ```
 pub fn write_switch_path(path: &std::path::Path) {
     if let Ok(switch_file) = std::env::var(ENV_GW_SWITCH_FILE) {
-        if let Err(e) = std::fs::write(&switch_file, path.display().to_string()) {
+        let result = switch_file_path(&switch_file).and_then(|switch_file| {
+            std::fs::write(&switch_file, path.display().to_string()).map_err(Into::into)
+        });
+        if let Err(e) = result {
             eprintln!("{MSG_SWITCH_FILE_WARNING_PREFIX}{e}");
         }
     } else {
-        let path_display = path.display();
-        println!("{SWITCH_TO_PREFIX}{path_display}");
+        let channel = crate::config::Config::load()
+            .map(|config| config.shell.switch_channel)
+            .unwrap_or_default();
+        let marker = switch_marker(path);
+        match channel {
+            SwitchChannel::Stderr => eprintln!("{marker}"),
+            SwitchChannel::File | SwitchChannel::Stdout => println!("{marker}"),
+        }
+    }
+}
+
+/// Pure business logic for formatting the `SWITCH_TO:` marker
+pub fn switch_marker(path: &Path) -> String {
+    let path_display = path.display();
+    format!("{SWITCH_TO_PREFIX}{path_display}")
+}
+
+/// Resolves and validates the `GW_SWITCH_FILE` value for the current process
+///
+/// # Errors
+///
+/// Returns an error if the path is empty, names a directory, or its parent
+/// directory does not exist.
+pub fn switch_file_path(raw: &str) -> anyhow::Result<PathBuf> {
+    let home = std::env::var_os(ENV_HOME).map(PathBuf::from);
+    let cwd = std::env::current_dir()?;
+    let path = resolve_switch_file(raw, home.as_deref(), &cwd)?;
+
+    if path.is_dir() {
+        return Err(anyhow::anyhow!(
+            ERROR_SWITCH_FILE_IS_DIR.replace("{}", &path.display().to_string())
+        ));
+    }
+    match path.parent() {
+        Some(parent) if parent.is_dir() => Ok(path),
+        _ => Err(anyhow::anyhow!(
+            ERROR_SWITCH_FILE_NO_PARENT.replace("{}", &path.display().to_string())
+        )),
+    }
+}
+
+/// Pure business logic for normalizing a switch file path
+///
+/// Expands a leading `~` to `home` and resolves relative paths against
+/// `cwd`, so the shell wrapper and `gw` agree on where the file lives even
+/// when `gw` runs from a different directory.
+///
+/// # Errors
+///
+/// Returns an error if `raw` is blank or starts with `~` while no home
+/// directory is known.
+pub fn resolve_switch_file(raw: &str, home: Option<&Path>, cwd: &Path) -> anyhow::Result<PathBuf> {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return Err(anyhow::anyhow!(ERROR_SWITCH_FILE_EMPTY));
+    }
+
+    let expanded = if raw == TILDE || raw.starts_with(TILDE_PREFIX) {
+        match home {
+            Some(home) => home.join(raw[TILDE.len()..].trim_start_matches('/')),
+            None => return Err(anyhow::anyhow!(ERROR_SWITCH_FILE_NO_HOME.replace("{}", raw))),
+        }
+    } else {
+        PathBuf::from(raw)
+    };
+
+    if expanded.is_absolute() {
+        Ok(expanded)
+    } else {
+        Ok(cwd.join(expanded))
     }
 }
 
@@ -307,12 +550,216 @@ pub fn find_default_branch_directory(dir: &Path, default_branch: &str) -> Option
     None
 }
 
+/// Formats a timestamp relative to now, e.g. `3 days ago`
+///
+/// For display only; machine-readable output keeps
+/// [`TIME_FORMAT`](crate::constants::TIME_FORMAT).
+pub fn format_relative_time(dt: DateTime<Utc>) -> String {
+    format_relative_time_since(dt, Utc::now())
+}
+
+/// Pure business logic for [`format_relative_time`] at a given `now`
+///
+/// Uses the largest whole unit: minutes below an hour, hours below a day,
+/// days below 30 days, months (of 30 days) below a year, then years.
+/// Anything under a minute, including times in the future, is `just now`.
+pub fn format_relative_time_since(dt: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let elapsed = now.signed_duration_since(dt);
+    let days = elapsed.num_days();
+    let (count, one, many) = if elapsed.num_minutes() < 1 {
+        return TIME_JUST_NOW.to_string();
+    } else if elapsed.num_hours() < 1 {
+        (elapsed.num_minutes(), TIME_MINUTE_AGO, TIME_MINUTES_AGO)
+    } else if days < 1 {
+        (elapsed.num_hours(), TIME_HOUR_AGO, TIME_HOURS_AGO)
+    } else if days < DAYS_PER_MONTH {
+        (days, TIME_DAY_AGO, TIME_DAYS_AGO)
+    } else if days < DAYS_PER_YEAR {
+        (days / DAYS_PER_MONTH, TIME_MONTH_AGO, TIME_MONTHS_AGO)
+    } else {
+        (days / DAYS_PER_YEAR, TIME_YEAR_AGO, TIME_YEARS_AGO)
+    };
+
+    if count == 1 {
+        one.to_string()
+    } else {
+        many.replace("{}", &count.to_string())
+    }
+}
+
+/// Sums the sizes of all files below `path`
+///
+/// Symlinks are counted as links and not followed. The `.git` file (or
+/// directory, in the main worktree) directly in `path` is skipped, so a
+/// worktree's size is its checked-out files.
+///
+/// # Errors
+///
+/// Returns an error if `path` or one of its subdirectories cannot be read.
+pub fn dir_size(path: &Path) -> io::Result<u64> {
+    fn walk(dir: &Path, skip_git: bool) -> io::Result<u64> {
+        let mut total = 0;
+        for entry in std::fs::read_dir(dir)? {
+            let entry = entry?;
+            if skip_git && entry.file_name() == GIT_DIR {
+                continue;
+            }
+            let metadata = entry.metadata()?;
+            total += if metadata.is_dir() {
+                walk(&entry.path(), false)?
+            } else {
+                metadata.len()
+            };
+        }
+        Ok(total)
+    }
+    walk(path, true)
+}
+
+/// Pure business logic for a human-readable size, e.g. `12.3 MB`
+///
+/// Uses binary units and [`FILE_SIZE_DECIMAL_PLACES`] decimals; sizes
+/// below a kilobyte are shown in whole bytes.
+pub fn format_size(bytes: u64) -> String {
+    let (unit, suffix) = if bytes >= BYTES_PER_GB {
+        (BYTES_PER_GB, FILE_SIZE_GB_SUFFIX)
+    } else if bytes >= BYTES_PER_MB {
+        (BYTES_PER_MB, FILE_SIZE_MB_SUFFIX)
+    } else if bytes >= BYTES_PER_KB {
+        (BYTES_PER_KB, FILE_SIZE_KB_SUFFIX)
+    } else {
+        return format!("{bytes}{FILE_SIZE_BYTES_SUFFIX}");
+    };
+    let size = bytes as f64 / unit as f64;
+    format!("{size:.FILE_SIZE_DECIMAL_PLACES$}{suffix}")
+}
+
+/// Pure business logic for the number of single-character edits between two strings
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Pure business logic for suggesting the candidate a misspelled word was meant to be
+///
+/// Returns the closest candidate within a third of the word's length (at
+/// least two edits), or `None` if nothing is that close.
+pub fn closest_match<'a>(word: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let limit = (word.chars().count() / 3).max(2);
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(word, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= limit)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::Duration;
     use std::fs;
     use tempfile::TempDir;
 
+    #[test]
+    fn test_dir_size() -> anyhow::Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        fs::write(root.join("a.txt"), [0u8; 100])?;
+        fs::create_dir_all(root.join("src/nested"))?;
+        fs::write(root.join("src/nested/b.rs"), [0u8; 20])?;
+        // The worktree's .git file is not part of its size
+        fs::write(root.join(".git"), "gitdir: /repo/.git/worktrees/x\n")?;
+        assert_eq!(dir_size(root)?, 120);
+
+        assert!(dir_size(&root.join("missing")).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(0), "0 B");
+        assert_eq!(format_size(1023), "1023 B");
+        assert_eq!(format_size(1536), "1.5 KB");
+        assert_eq!(format_size(5 * BYTES_PER_MB), "5.0 MB");
+        assert_eq!(format_size(3 * BYTES_PER_GB / 2), "1.5 GB");
+    }
+
+    #[test]
+    fn test_closest_match() {
+        let events = ["post-create", "pre-remove", "post-switch"];
+        assert_eq!(closest_match("post-creat", &events), Some("post-create"));
+        assert_eq!(closest_match("post_create", &events), Some("post-create"));
+        assert_eq!(closest_match("pre-remov", &events), Some("pre-remove"));
+        assert_eq!(closest_match("on-deploy", &events), None);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_choose_editor() {
+        let some = |value: &str| Some(value.to_string());
+        assert_eq!(choose_editor([some("nvim"), some("code")]), "nvim");
+        assert_eq!(choose_editor([None, some("code")]), "code");
+        assert_eq!(choose_editor([some(""), some("code")]), "code");
+        // --editor > [editor] command > $EDITOR > $VISUAL
+        assert_eq!(
+            choose_editor([some("code --wait"), some("subl -w"), some("vim"), None]),
+            "code --wait"
+        );
+        assert_eq!(
+            choose_editor([None, some("subl -w"), some("vim"), some("code")]),
+            "subl -w"
+        );
+        let fallback = if cfg!(target_os = "windows") {
+            DEFAULT_EDITOR_WINDOWS
+        } else {
+            DEFAULT_EDITOR_UNIX
+        };
+        assert_eq!(choose_editor([None, None]), fallback);
+    }
+
+    #[test]
+    fn test_split_command_line() {
+        assert_eq!(split_command_line("code --wait"), vec!["code", "--wait"]);
+        assert_eq!(split_command_line("  vim  "), vec!["vim"]);
+        assert_eq!(
+            split_command_line(r#""/opt/My Editor/edit" -w 'a b' c\ d"#),
+            vec!["/opt/My Editor/edit", "-w", "a b", "c d"]
+        );
+        assert_eq!(split_command_line(r#"edit "" x"#), vec!["edit", "", "x"]);
+        assert!(split_command_line("   ").is_empty());
+    }
+
+    #[test]
+    fn test_format_relative_time_boundaries() {
+        let now = Utc::now();
+        let ago = |duration: Duration| format_relative_time_since(now - duration, now);
+
+        assert_eq!(ago(Duration::seconds(59)), "just now");
+        assert_eq!(ago(Duration::seconds(60)), "1 minute ago");
+        assert_eq!(ago(Duration::minutes(5)), "5 minutes ago");
+        assert_eq!(ago(Duration::minutes(60)), "1 hour ago");
+        assert_eq!(ago(Duration::hours(23)), "23 hours ago");
+        assert_eq!(ago(Duration::hours(24)), "1 day ago");
+        assert_eq!(ago(Duration::days(2)), "2 days ago");
+        assert_eq!(ago(Duration::days(29)), "29 days ago");
+        assert_eq!(ago(Duration::days(30)), "1 month ago");
+        assert_eq!(ago(Duration::days(95)), "3 months ago");
+        assert_eq!(ago(Duration::days(365)), "1 year ago");
+        assert_eq!(ago(Duration::days(800)), "2 years ago");
+        assert_eq!(ago(Duration::minutes(-5)), "just now");
+    }
+
     #[test]
     fn test_get_theme_creation() {
         // Test that we can create a theme instance
@@ -340,6 +787,72 @@ mod tests {
         std::env::remove_var(ENV_GW_SWITCH_FILE);
     }
 
+    #[test]
+    fn test_format_debug_timing() {
+        assert_eq!(
+            format_debug_timing(
+                "list_worktrees",
+                std::time::Duration::from_micros(412_900),
+                Some("for 23 worktrees")
+            ),
+            "list_worktrees: 412ms for 23 worktrees"
+        );
+        assert_eq!(
+            format_debug_timing("create_worktree", std::time::Duration::from_secs(2), None),
+            "create_worktree: 2000ms"
+        );
+    }
+
+    #[test]
+    fn test_switch_marker() {
+        assert_eq!(
+            switch_marker(Path::new("/test/path")),
+            "SWITCH_TO:/test/path"
+        );
+    }
+
+    #[test]
+    fn test_resolve_switch_file_expands_tilde() {
+        let home = Path::new("/home/user");
+        let cwd = Path::new("/work");
+
+        let path = resolve_switch_file("~/.gw/switch", Some(home), cwd).unwrap();
+        assert_eq!(path, PathBuf::from("/home/user/.gw/switch"));
+
+        let path = resolve_switch_file("~", Some(home), cwd).unwrap();
+        assert_eq!(path, PathBuf::from("/home/user"));
+
+        assert!(resolve_switch_file("~/switch", None, cwd).is_err());
+    }
+
+    #[test]
+    fn test_resolve_switch_file_relative_to_cwd() {
+        let cwd = Path::new("/work/repo");
+
+        let path = resolve_switch_file("tmp/switch", None, cwd).unwrap();
+        assert_eq!(path, PathBuf::from("/work/repo/tmp/switch"));
+
+        let path = resolve_switch_file("/tmp/switch", None, cwd).unwrap();
+        assert_eq!(path, PathBuf::from("/tmp/switch"));
+
+        // "~user" is not a home reference and stays relative
+        let path = resolve_switch_file("~user/switch", Some(Path::new("/home/me")), cwd).unwrap();
+        assert_eq!(path, PathBuf::from("/work/repo/~user/switch"));
+
+        assert!(resolve_switch_file("  ", None, cwd).is_err());
+    }
+
+    #[test]
+    fn test_switch_file_path_rejects_missing_parent() {
+        let temp_dir = TempDir::new().unwrap();
+        let missing = temp_dir.path().join("missing").join("switch");
+        assert!(switch_file_path(missing.to_str().unwrap()).is_err());
+        assert!(switch_file_path(temp_dir.path().to_str().unwrap()).is_err());
+
+        let valid = temp_dir.path().join("switch");
+        assert_eq!(switch_file_path(valid.to_str().unwrap()).unwrap(), valid);
+    }
+
     #[test]
     fn test_find_config_in_default_branches_main() {
         let temp_dir = TempDir::new().unwrap();
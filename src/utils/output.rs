@@ -0,0 +1,95 @@
+//! Output verbosity
+//!
+//! `gw --quiet` keeps the result lines (created, deleted, warnings, errors)
+//! and drops headers, blank lines, previews, tips, icons and spinners, so
+//! the output reads well in logs. The level is set once in `main` and read
+//! by the helpers below, so commands do not have to pass it around.
+
+use indicatif::{ProgressBar, ProgressStyle};
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::time::Duration;
+
+use crate::constants::{section_header, PROGRESS_BAR_TICK_MILLIS, SPINNER_TEMPLATE};
+
+/// How much `gw` prints
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    /// Only result lines and errors (`--quiet`)
+    Quiet,
+    /// Everything, including headers, previews and spinners
+    Normal,
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(Verbosity::Normal as u8);
+
+/// Sets the verbosity for the rest of the process
+pub fn set_verbosity(verbosity: Verbosity) {
+    VERBOSITY.store(verbosity as u8, Ordering::Relaxed);
+}
+
+/// Returns the current verbosity
+pub fn verbosity() -> Verbosity {
+    if VERBOSITY.load(Ordering::Relaxed) == Verbosity::Quiet as u8 {
+        Verbosity::Quiet
+    } else {
+        Verbosity::Normal
+    }
+}
+
+/// Whether decorative output is suppressed
+pub fn is_quiet() -> bool {
+    verbosity() == Verbosity::Quiet
+}
+
+/// Prints decorative text such as previews, hints and tips
+pub fn decoration(text: impl Display) {
+    if !is_quiet() {
+        println!("{text}");
+    }
+}
+
+/// Prints an empty line used for spacing
+pub fn blank_line() {
+    if !is_quiet() {
+        println!();
+    }
+}
+
+/// Prints a section header with a blank line above and below
+pub fn print_header(title: &str) {
+    blank_line();
+    decoration(section_header(title));
+    blank_line();
+}
+
+/// Creates a spinner showing `message`, hidden when quiet
+pub fn spinner(message: &str) -> ProgressBar {
+    if is_quiet() {
+        return ProgressBar::hidden();
+    }
+    let pb = ProgressBar::new_spinner();
+    pb.set_style(
+        ProgressStyle::default_spinner()
+            .template(SPINNER_TEMPLATE)
+            .unwrap(),
+    );
+    pb.set_message(message.to_string());
+    pb.enable_steady_tick(Duration::from_millis(PROGRESS_BAR_TICK_MILLIS));
+    pb
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verbosity_round_trip() {
+        assert_eq!(verbosity(), Verbosity::Normal);
+        set_verbosity(Verbosity::Quiet);
+        assert!(is_quiet());
+        assert!(spinner("working").is_hidden());
+        set_verbosity(Verbosity::Normal);
+        assert!(!is_quiet());
+    }
+}
@@ -25,6 +25,7 @@ fn test_custom_path_appends_worktree_name() -> Result<()> {
         .with_selection(2) // custom path option
         .with_input("branch/") // directory path
         .with_selection(0) // create from HEAD
+        .with_confirmation(true) // create although .env is missing
         .with_confirmation(false); // don't switch
 
     let result = create_worktree_with_ui(&manager, &ui)?;
@@ -52,6 +53,7 @@ fn test_dot_slash_creates_in_project_root() -> Result<()> {
         .with_selection(2) // custom path option
         .with_input("./") // current directory
         .with_selection(0) // create from HEAD
+        .with_confirmation(true) // create although .env is missing
         .with_confirmation(false); // don't switch
 
     let result = create_worktree_with_ui(&manager, &ui)?;
@@ -81,6 +83,7 @@ fn test_parent_directory_creates_outside_project() -> Result<()> {
         .with_selection(2) // custom path option
         .with_input("../") // parent directory
         .with_selection(0) // create from HEAD
+        .with_confirmation(true) // create although .env is missing
         .with_confirmation(false); // don't switch
 
     let result = create_worktree_with_ui(&manager, &ui)?;
@@ -114,6 +117,7 @@ fn test_nested_directory_paths() -> Result<()> {
         .with_selection(2) // custom path option
         .with_input("features/ui/") // nested directory
         .with_selection(0) // create from HEAD
+        .with_confirmation(true) // create although .env is missing
         .with_confirmation(false); // don't switch
 
     let result = create_worktree_with_ui(&manager, &ui)?;
@@ -141,6 +145,7 @@ fn test_path_without_trailing_slash_treated_as_directory() -> Result<()> {
         .with_selection(2) // custom path option
         .with_input("hotfix") // no trailing slash
         .with_selection(0) // create from HEAD
+        .with_confirmation(true) // create although .env is missing
         .with_confirmation(false); // don't switch
 
     let result = create_worktree_with_ui(&manager, &ui)?;
@@ -208,7 +213,7 @@ fn test_path_validation_prevents_dangerous_paths() -> Result<()> {
 
     // Verify no worktrees were created
     let worktrees = manager.list_worktrees()?;
-    assert_eq!(worktrees.len(), 0);
+    assert!(worktrees.iter().all(|w| w.is_main));
 
     Ok(())
 }
@@ -225,6 +230,7 @@ fn test_single_slash_becomes_worktree_name() -> Result<()> {
         .with_selection(2) // custom path option
         .with_input("/") // just a slash
         .with_selection(0) // create from HEAD
+        .with_confirmation(true) // create although .env is missing
         .with_confirmation(false); // don't switch
 
     let result = create_worktree_with_ui(&manager, &ui)?;
@@ -253,6 +259,7 @@ fn test_custom_path_with_branch_selection() -> Result<()> {
         .with_input("branches/") // directory for branches
         .with_selection(1) // select branch
         .with_selection(0) // select first branch (test-branch)
+        .with_confirmation(true) // create although .env is missing
         .with_confirmation(false); // don't switch
 
     let result = create_worktree_with_ui(&manager, &ui)?;
@@ -292,6 +299,7 @@ fn test_ui_examples_are_accurate() -> Result<()> {
             .with_selection(2) // custom path option
             .with_input(input) // directory path
             .with_selection(0) // create from HEAD
+            .with_confirmation(true) // create although .env is missing
             .with_confirmation(false); // don't switch
 
         let result = create_worktree_with_ui(&manager, &ui)?;
@@ -326,6 +334,7 @@ fn test_custom_path_for_subsequent_worktrees() -> Result<()> {
         .with_selection(2) // custom path
         .with_input("work/")
         .with_selection(0)
+        .with_confirmation(true) // create although .env is missing
         .with_confirmation(false);
 
     create_worktree_with_ui(&manager, &ui)?;
@@ -336,6 +345,7 @@ fn test_custom_path_for_subsequent_worktrees() -> Result<()> {
         .with_selection(2) // custom path should still be available
         .with_input("work/") // same directory
         .with_selection(0)
+        .with_confirmation(true) // create although .env is missing
         .with_confirmation(false);
 
     let result = create_worktree_with_ui(&manager, &ui)?;
@@ -361,6 +371,7 @@ fn test_single_dot_behaves_like_dot_slash() -> Result<()> {
         .with_selection(2) // custom path
         .with_input(".") // just a dot
         .with_selection(0)
+        .with_confirmation(true) // create although .env is missing
         .with_confirmation(false);
 
     create_worktree_with_ui(&manager, &ui)?;
@@ -428,6 +439,7 @@ mod validation_tests {
             .with_selection(2)
             .with_input("path/with/trailing/////") // Multiple trailing slashes
             .with_selection(0)
+            .with_confirmation(true) // create although .env is missing
             .with_confirmation(false);
 
         let result = create_worktree_with_ui(&manager, &ui).unwrap();
@@ -438,3 +450,68 @@ mod validation_tests {
         assert!(worktree.path.ends_with("path/with/trailing/test"));
     }
 }
+
+/// Test that the non-interactive UI drives creation from prepared answers
+#[test]
+fn test_non_interactive_custom_path_with_branch() -> Result<()> {
+    use git_workers::constants::{
+        BRANCH_OPTION_SELECT_BRANCH, PROMPT_CUSTOM_PATH, PROMPT_SELECT_BRANCH,
+        PROMPT_SELECT_BRANCH_OPTION, PROMPT_SELECT_WORKTREE_LOCATION,
+        PROMPT_SWITCH_TO_NEW_WORKTREE, PROMPT_WORKTREE_NAME, WORKTREE_LOCATION_CUSTOM_PATH,
+    };
+    use git_workers::ui::NonInteractiveUI;
+
+    let temp_dir = TempDir::new()?;
+    let test_repo = TestRepo::new(&temp_dir)?;
+    let manager = test_repo.manager()?;
+    test_repo.create_branch("test-branch")?;
+
+    let ui = NonInteractiveUI::new()
+        .with_input(PROMPT_WORKTREE_NAME, "scripted")
+        .with_choice(
+            PROMPT_SELECT_WORKTREE_LOCATION,
+            WORKTREE_LOCATION_CUSTOM_PATH,
+        )
+        .with_input(PROMPT_CUSTOM_PATH, "branches/")
+        .with_choice(PROMPT_SELECT_BRANCH_OPTION, BRANCH_OPTION_SELECT_BRANCH)
+        .with_label(PROMPT_SELECT_BRANCH, "test-branch")
+        .with_confirm(PROMPT_SWITCH_TO_NEW_WORKTREE, false);
+
+    let result = create_worktree_with_ui(&manager, &ui)?;
+    assert!(!result);
+    assert_eq!(ui.unanswered(), None);
+
+    let worktrees = manager.list_worktrees()?;
+    let created = worktrees.iter().find(|w| w.name == "scripted").unwrap();
+    assert!(created.path.ends_with("branches/scripted"));
+    assert_eq!(created.branch, "test-branch");
+
+    Ok(())
+}
+
+/// Test that a missing answer cancels creation instead of prompting
+#[test]
+fn test_non_interactive_unknown_branch_is_unanswered() -> Result<()> {
+    use git_workers::constants::{
+        BRANCH_OPTION_SELECT_BRANCH, PROMPT_SELECT_BRANCH, PROMPT_SELECT_BRANCH_OPTION,
+        PROMPT_SELECT_WORKTREE_LOCATION, PROMPT_WORKTREE_NAME,
+    };
+    use git_workers::ui::NonInteractiveUI;
+
+    let temp_dir = TempDir::new()?;
+    let test_repo = TestRepo::new(&temp_dir)?;
+    let manager = test_repo.manager()?;
+
+    let ui = NonInteractiveUI::new()
+        .with_input(PROMPT_WORKTREE_NAME, "scripted")
+        .with_default(PROMPT_SELECT_WORKTREE_LOCATION)
+        .with_choice(PROMPT_SELECT_BRANCH_OPTION, BRANCH_OPTION_SELECT_BRANCH)
+        .with_label(PROMPT_SELECT_BRANCH, "no-such-branch");
+
+    let result = create_worktree_with_ui(&manager, &ui)?;
+    assert!(!result);
+    assert!(ui.unanswered().unwrap().contains("no-such-branch"));
+    assert!(manager.list_worktrees()?.iter().all(|w| w.is_main));
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+//! Integration tests for the "bare + worktrees" layout
+//!
+//! The repository is cloned into `<root>/.bare`, `<root>/.git` points at it,
+//! and every branch is a worktree under `<root>`:
+//!
+//! ```text
+//! root/
+//! ├── .bare/
+//! ├── .git          (gitdir: ./.bare)
+//! ├── main/
+//! └── feature/
+//! ```
+
+use anyhow::{ensure, Result};
+use git_workers::commands::shared::find_config_file_path_internal;
+use git_workers::config::{find_bare_layout_config, Config};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+
+fn git(dir: &Path, args: &[&str]) -> Result<()> {
+    let output = Command::new("git").args(args).current_dir(dir).output()?;
+    ensure!(
+        output.status.success(),
+        "git {args:?} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    Ok(())
+}
+
+/// Builds the layout in `temp/root` from a source repository with
+/// `main_config` committed on `main`, and returns the layout root
+fn bare_layout(temp: &Path, main_config: Option<&str>) -> Result<PathBuf> {
+    let source = temp.join("source");
+    fs::create_dir(&source)?;
+    git(&source, &["init", "-q", "-b", "main"])?;
+    git(&source, &["config", "user.email", "test@example.com"])?;
+    git(&source, &["config", "user.name", "Test User"])?;
+    fs::write(source.join("README.md"), "# Test")?;
+    if let Some(content) = main_config {
+        fs::write(source.join(".git-workers.toml"), content)?;
+    }
+    git(&source, &["add", "."])?;
+    git(&source, &["commit", "-q", "-m", "Initial commit"])?;
+
+    let root = temp.join("root");
+    fs::create_dir(&root)?;
+    git(&root, &["clone", "-q", "--bare", "../source", ".bare"])?;
+    fs::write(root.join(".git"), "gitdir: ./.bare\n")?;
+    git(&root, &["worktree", "add", "-q", "main"])?;
+    git(
+        &root,
+        &["worktree", "add", "-q", "-b", "feature", "feature"],
+    )?;
+    Ok(root)
+}
+
+/// The single `post-create` hook of the configuration loaded from `dir`
+fn post_create_hook(dir: &Path) -> Result<Option<String>> {
+    let config = Config::load_from_path(dir)?;
+    Ok(config
+        .hooks
+        .get("post-create")
+        .and_then(|hooks| hooks.first())
+        .map(|hook| hook.command().to_string()))
+}
+
+#[test]
+fn test_bare_layout_finds_config_in_layout_root() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let root = bare_layout(
+        temp_dir.path(),
+        Some("[hooks]\npost-create = [\"echo main\"]\n"),
+    )?;
+    fs::write(
+        root.join(".git-workers.toml"),
+        "[hooks]\npost-create = [\"echo root\"]\n",
+    )?;
+
+    // The layout root wins over the default branch's worktree, from anywhere
+    for dir in [root.clone(), root.join("main"), root.join("feature")] {
+        assert_eq!(post_create_hook(&dir)?.as_deref(), Some("echo root"));
+        let repo = git2::Repository::discover(&dir)?;
+        assert_eq!(
+            find_config_file_path_internal(&repo)?,
+            root.join(".git-workers.toml")
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_bare_layout_finds_config_in_default_branch_worktree() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let root = bare_layout(
+        temp_dir.path(),
+        Some("[hooks]\npost-create = [\"echo main\"]\n"),
+    )?;
+    let main_config = root.join("main").join(".git-workers.toml");
+
+    for dir in [root.clone(), root.join("feature")] {
+        assert_eq!(post_create_hook(&dir)?.as_deref(), Some("echo main"));
+        let repo = git2::Repository::discover(&dir)?;
+        assert_eq!(find_bare_layout_config(&repo), Some(main_config.clone()));
+        assert_eq!(find_config_file_path_internal(&repo)?, main_config);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_bare_layout_without_config() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let root = bare_layout(temp_dir.path(), None)?;
+
+    let repo = git2::Repository::discover(root.join("feature"))?;
+    assert_eq!(find_bare_layout_config(&repo), None);
+    // A new file goes to the layout root
+    assert_eq!(
+        find_config_file_path_internal(&repo)?,
+        root.join(".git-workers.toml")
+    );
+    Ok(())
+}
@@ -94,39 +94,12 @@ mod tests {
     #[test]
     fn test_worktree_sorting() {
         let mut worktrees = vec![
+            WorktreeInfo::test("zebra"),
             WorktreeInfo {
-                name: "zebra".to_string(),
-                git_name: "zebra".to_string(),
-                path: PathBuf::from("/tmp/zebra"),
-                branch: "zebra".to_string(),
-                is_current: false,
-                has_changes: false,
-                last_commit: None,
-                ahead_behind: None,
-                is_locked: false,
-            },
-            WorktreeInfo {
-                name: "alpha".to_string(),
-                git_name: "alpha".to_string(),
-                path: PathBuf::from("/tmp/alpha"),
-                branch: "alpha".to_string(),
                 is_current: true,
-                has_changes: false,
-                last_commit: None,
-                ahead_behind: None,
-                is_locked: false,
-            },
-            WorktreeInfo {
-                name: "beta".to_string(),
-                git_name: "beta".to_string(),
-                path: PathBuf::from("/tmp/beta"),
-                branch: "beta".to_string(),
-                is_current: false,
-                has_changes: false,
-                last_commit: None,
-                ahead_behind: None,
-                is_locked: false,
+                ..WorktreeInfo::test("alpha")
             },
+            WorktreeInfo::test("beta"),
         ];
 
         // Apply the same sorting logic as list_worktrees
@@ -152,26 +125,14 @@ mod tests {
     fn test_column_width_calculation() {
         let worktrees = vec![
             WorktreeInfo {
-                name: "short".to_string(),
-                git_name: "short".to_string(),
-                path: PathBuf::from("/tmp/short"),
                 branch: "main".to_string(),
-                is_current: false,
-                has_changes: false,
-                last_commit: None,
-                ahead_behind: None,
-                is_locked: false,
+                ..WorktreeInfo::test("short")
             },
             WorktreeInfo {
-                name: "very-long-worktree-name".to_string(),
-                git_name: "very-long-worktree-name".to_string(),
                 path: PathBuf::from("/tmp/very-long"),
                 branch: "feature-with-long-name".to_string(),
                 is_current: true,
-                has_changes: false,
-                last_commit: None,
-                ahead_behind: None,
-                is_locked: false,
+                ..WorktreeInfo::test("very-long-worktree-name")
             },
         ];
 
@@ -200,37 +161,20 @@ mod tests {
         // This test verifies the complete display format is correct
         let worktrees = vec![
             WorktreeInfo {
-                name: "main".to_string(),
-                git_name: "main".to_string(),
                 path: PathBuf::from("/Users/test/project/main"),
-                branch: "main".to_string(),
                 is_current: true,
-                has_changes: false,
-                last_commit: None,
-                ahead_behind: None,
-                is_locked: false,
+                ..WorktreeInfo::test("main")
             },
             WorktreeInfo {
-                name: "feature-x".to_string(),
-                git_name: "feature-x".to_string(),
                 path: PathBuf::from("/Users/test/project/feature-x"),
                 branch: "feature/new-ui".to_string(),
-                is_current: false,
                 has_changes: true,
-                last_commit: None,
-                ahead_behind: None,
-                is_locked: false,
+                ..WorktreeInfo::test("feature-x")
             },
             WorktreeInfo {
-                name: "bugfix".to_string(),
-                git_name: "bugfix".to_string(),
                 path: PathBuf::from("/Users/test/project/bugfix"),
                 branch: "fix/critical-bug".to_string(),
-                is_current: false,
-                has_changes: false,
-                last_commit: None,
-                ahead_behind: None,
-                is_locked: false,
+                ..WorktreeInfo::test("bugfix")
             },
         ];
 
@@ -153,8 +153,8 @@ fn test_bare_repository_root_display() -> Result<()> {
 
         let info = get_repository_info_at_path(&bare_repo_path);
         assert_eq!(
-            info, "test-repo",
-            "Bare repository root should show just repo name without .bare suffix"
+            info, "test-repo (bare)",
+            "Bare repository root should show the repo name without .bare suffix, labeled bare"
         );
 
         Ok(())
@@ -179,8 +179,8 @@ fn test_bare_repository_subdirectory_display() -> Result<()> {
 
         let info = get_repository_info_at_path(&subdir);
         assert_eq!(
-            info, "test-repo",
-            "Bare repository subdirectory should show just repo name"
+            info, "test-repo (bare)",
+            "Bare repository subdirectory should show the repo name, labeled bare"
         );
 
         Ok(())
@@ -227,8 +227,8 @@ fn test_worktree_simulation_display() -> Result<()> {
 
         let info = get_repository_info_at_path(&worktree_path);
         assert_eq!(
-            info, "test-repo (feature-x)",
-            "Simulated worktree should show repo name with worktree name"
+            info, "feature-x (worktree of test-repo)",
+            "Simulated worktree should show worktree name and its repository"
         );
 
         Ok(())
@@ -314,14 +314,97 @@ fn test_non_bare_worktree_simulation_display() -> Result<()> {
         env::set_current_dir(&worktree_path)?;
         let info = get_repository_info_at_path(&worktree_path);
         assert_eq!(
-            info, "test-repo (feature-branch)",
-            "Worktree should show repo name with worktree name"
+            info, "feature-branch (worktree of test-repo)",
+            "Worktree should show worktree name and its repository"
         );
 
         Ok(())
     })
 }
 
+/// Runs git in `dir` with a fixed identity, failing on errors
+fn git(dir: &std::path::Path, args: &[&str]) -> Result<()> {
+    let output = std::process::Command::new("git")
+        .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+        .args(["-c", "protocol.file.allow=always"])
+        .args(args)
+        .current_dir(dir)
+        .env_remove("GIT_DIR")
+        .env_remove("GIT_WORK_TREE")
+        .output()?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {args:?} failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    Ok(())
+}
+
+/// Test repository info display for a bare repository with a real linked worktree
+#[test]
+fn test_bare_repository_with_worktree_display() -> Result<()> {
+    run_isolated_test(|| {
+        let temp_dir = TempDir::new()?;
+        let bare_repo_path = temp_dir.path().join("project.bare");
+        let repo = init_isolated_repo(&bare_repo_path, true)?;
+        create_initial_commit(&repo)?;
+
+        let worktree_path = temp_dir.path().join("main");
+        git(
+            &bare_repo_path,
+            &["worktree", "add", worktree_path.to_str().unwrap()],
+        )?;
+
+        env::set_current_dir(&worktree_path)?;
+        let info = get_repository_info_at_path(&worktree_path);
+        assert_eq!(info, "main (worktree of project)");
+
+        env::set_current_dir(&bare_repo_path)?;
+        let info = get_repository_info_at_path(&bare_repo_path);
+        assert_eq!(info, "project (bare)");
+
+        Ok(())
+    })
+}
+
+/// Test repository info display inside a submodule
+#[test]
+fn test_submodule_display() -> Result<()> {
+    run_isolated_test(|| {
+        let temp_dir = TempDir::new()?;
+
+        let library_path = temp_dir.path().join("library");
+        let library = init_isolated_repo(&library_path, false)?;
+        create_initial_commit(&library)?;
+
+        let app_path = temp_dir.path().join("app");
+        let app = init_isolated_repo(&app_path, false)?;
+        create_initial_commit(&app)?;
+        git(
+            &app_path,
+            &[
+                "submodule",
+                "add",
+                "-q",
+                library_path.to_str().unwrap(),
+                "vendor/lib",
+            ],
+        )?;
+
+        let submodule_path = app_path.join("vendor").join("lib");
+        env::set_current_dir(&submodule_path)?;
+        let info = get_repository_info_at_path(&submodule_path);
+        assert_eq!(info, "submodule: vendor/lib");
+
+        // The superproject itself is a regular repository
+        env::set_current_dir(&app_path)?;
+        assert_eq!(get_repository_info_at_path(&app_path), "app");
+
+        Ok(())
+    })
+}
+
 /// Test edge cases for repository name extraction
 #[test]
 fn test_repository_name_edge_cases() -> Result<()> {
@@ -364,17 +447,20 @@ fn test_critical_display_patterns() -> Result<()> {
         env::set_current_dir(&regular_repo)?;
         assert_eq!(get_repository_info_at_path(&regular_repo), "regular-repo");
 
-        // Pattern 2: Bare repository root → "repo-name" (without .bare suffix)
+        // Pattern 2: Bare repository root → "repo-name (bare)" (without .bare suffix)
         let bare_repo = temp_dir.path().join("bare-repo.bare");
         init_isolated_repo(&bare_repo, true)?;
         env::set_current_dir(&bare_repo)?;
-        assert_eq!(get_repository_info_at_path(&bare_repo), "bare-repo");
+        assert_eq!(get_repository_info_at_path(&bare_repo), "bare-repo (bare)");
 
-        // Pattern 3: Bare repository subdirectory → "repo-name"
+        // Pattern 3: Bare repository subdirectory → "repo-name (bare)"
         let bare_subdir = bare_repo.join("subdir");
         fs::create_dir(&bare_subdir)?;
         env::set_current_dir(&bare_subdir)?;
-        assert_eq!(get_repository_info_at_path(&bare_subdir), "bare-repo");
+        assert_eq!(
+            get_repository_info_at_path(&bare_subdir),
+            "bare-repo (bare)"
+        );
 
         // Pattern 4: Non-git directory → "directory-name"
         let non_git = temp_dir.path().join("not-a-repo");
@@ -428,11 +514,12 @@ fn test_fixed_incorrect_patterns() -> Result<()> {
 fn test_documented_patterns() -> Result<()> {
     // These are the specific patterns we've fixed in this session:
 
-    // 1. Bare repository root: "wasabeef" (not "git (wasabeef.bare)")
-    // 2. Bare repository subdirectory: "wasabeef" (not "git (branch)")
-    // 3. Bare repository worktree: "wasabeef (main)"
+    // 1. Bare repository root: "wasabeef (bare)" (not "git (wasabeef.bare)")
+    // 2. Bare repository subdirectory: "wasabeef (bare)" (not "git (branch)")
+    // 3. Bare repository worktree: "main (worktree of wasabeef)"
     // 4. Non-bare repository: "yank-for-claude.nvim"
-    // 5. Non-bare worktree: "yank-for-claude.nvim (ccc)"
+    // 5. Non-bare worktree: "ccc (worktree of yank-for-claude.nvim)"
+    // 6. Submodule: "submodule: vendor/lib"
 
     // All these patterns are tested in the tests above
     // This test serves as documentation of the patterns we're testing
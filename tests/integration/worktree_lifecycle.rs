@@ -115,8 +115,9 @@ fn test_complete_worktree_lifecycle() -> Result<()> {
     assert!(worktree.is_some());
     assert!(worktree.unwrap().has_changes);
 
-    // Step 4: Remove the worktree
-    manager.remove_worktree(worktree_name)?;
+    // Step 4: Remove the worktree (it has staged changes, so it needs force)
+    assert!(manager.remove_worktree(worktree_name).is_err());
+    manager.remove_worktree_force(worktree_name)?;
 
     // Verify worktree was removed
     assert!(!worktree_path.exists());
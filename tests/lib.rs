@@ -12,6 +12,7 @@ mod unit {
 
 #[cfg(test)]
 mod integration {
+    mod bare_layout;
     mod git_flow;
     mod list_ui_display_test;
     mod multi_repo;
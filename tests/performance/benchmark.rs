@@ -202,8 +202,11 @@ copy = [".env", "config.json"]
 
         // Simulate file copying with FilesConfig
         let config = git_workers::config::FilesConfig {
-            copy: vec![".env".to_string(), "config.json".to_string()],
+            copy: vec![".env".into(), "config.json".into()],
             source: Some(repo_root.to_str().unwrap().to_string()),
+            max_size_mb: None,
+            source_ref: None,
+            copy_exclude: Vec::new(),
         };
 
         git_workers::infrastructure::file_copy::copy_configured_files(
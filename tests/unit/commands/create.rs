@@ -136,3 +136,53 @@ fn test_validate_worktree_creation() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_create_with_mock_git() -> Result<()> {
+    use git_workers::commands::create_worktree_with_ui;
+    use git_workers::constants::{
+        PROMPT_SELECT_BRANCH_OPTION, PROMPT_SELECT_WORKTREE_LOCATION,
+        PROMPT_SWITCH_TO_NEW_WORKTREE, PROMPT_WORKTREE_NAME, WORKTREE_LOCATION_SUBDIRECTORY,
+    };
+    use git_workers::git_interface::mock::MockGitOperations;
+    use git_workers::git_interface::GitReadOperations;
+    use git_workers::ui::NonInteractiveUI;
+
+    let temp_dir = TempDir::new()?;
+    let root = temp_dir.path().join("repo");
+    let root_str = root.to_str().unwrap();
+    let git = MockGitOperations::new()
+        .with_repository_root(root_str)
+        .with_worktree("repo", root_str, Some("main"))
+        .with_main_worktree("repo")
+        .with_current_worktree("repo");
+
+    // The first worktree asks for a location; HEAD is the default source
+    let ui = NonInteractiveUI::new()
+        .with_input(PROMPT_WORKTREE_NAME, "feature")
+        .with_choice(
+            PROMPT_SELECT_WORKTREE_LOCATION,
+            WORKTREE_LOCATION_SUBDIRECTORY,
+        )
+        .with_choice(PROMPT_SELECT_BRANCH_OPTION, 0)
+        .with_confirm(PROMPT_SWITCH_TO_NEW_WORKTREE, false);
+    assert!(!create_worktree_with_ui(&git, &ui)?);
+
+    assert_eq!(git.created_worktrees(), vec!["feature"]);
+    let feature = git
+        .list_worktrees()?
+        .into_iter()
+        .find(|w| w.name == "feature")
+        .unwrap();
+    assert_eq!(feature.path, root.join("worktrees/feature"));
+
+    // A taken name is refused without a terminal, and nothing is added
+    let ui = NonInteractiveUI::new()
+        .with_input(PROMPT_WORKTREE_NAME, "feature")
+        .with_choice(PROMPT_SELECT_BRANCH_OPTION, 0)
+        .with_confirm(PROMPT_SWITCH_TO_NEW_WORKTREE, false);
+    assert!(create_worktree_with_ui(&git, &ui).is_err());
+    assert_eq!(git.created_worktrees(), vec!["feature"]);
+
+    Ok(())
+}
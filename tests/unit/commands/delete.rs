@@ -17,6 +17,7 @@ fn test_worktree_delete_config_creation() {
         path: PathBuf::from("/tmp/feature"),
         branch: "feature".to_string(),
         delete_branch: true,
+        force: false,
     };
 
     assert_eq!(config.name, "feature");
@@ -74,20 +75,202 @@ fn test_worktree_deletion_simulation() -> Result<()> {
 
 #[test]
 fn test_worktree_info_for_deletion() {
-    let worktree = WorktreeInfo {
-        name: "feature".to_string(),
-        git_name: "feature".to_string(),
-        path: PathBuf::from("/tmp/feature"),
-        branch: "feature".to_string(),
-        is_current: false,
-        is_locked: false,
-        has_changes: false,
-        last_commit: None,
-        ahead_behind: None,
-    };
+    let worktree = WorktreeInfo::test("feature");
 
     // Test that we can validate deletion for this worktree
     assert!(!worktree.name.is_empty());
     assert!(!worktree.is_current);
     assert!(!worktree.is_locked);
 }
+
+/// Creates a repository with one linked worktree named `feature`
+fn setup_repo_with_worktree(temp_dir: &TempDir) -> Result<(PathBuf, PathBuf)> {
+    let repo_path = temp_dir.path().join("repo");
+    fs::create_dir_all(&repo_path)?;
+    let git = |args: &[&str]| {
+        std::process::Command::new("git")
+            .args(args)
+            .current_dir(&repo_path)
+            .output()
+    };
+    git(&["init"])?;
+    git(&["config", "user.email", "test@example.com"])?;
+    git(&["config", "user.name", "Test User"])?;
+    fs::write(repo_path.join("README.md"), "# Test")?;
+    git(&["add", "."])?;
+    git(&["commit", "-m", "Initial commit"])?;
+
+    let worktree_path = temp_dir.path().join("feature");
+    git(&[
+        "worktree",
+        "add",
+        worktree_path.to_str().unwrap(),
+        "-b",
+        "feature",
+    ])?;
+    Ok((repo_path, worktree_path))
+}
+
+#[test]
+fn test_non_interactive_delete_requires_yes() -> Result<()> {
+    use git_workers::commands::delete_worktree_with_ui;
+    use git_workers::constants::PROMPT_SELECT_WORKTREE_TO_DELETE;
+    use git_workers::infrastructure::git::GitWorktreeManager;
+    use git_workers::ui::NonInteractiveUI;
+
+    let temp_dir = TempDir::new()?;
+    let (repo_path, worktree_path) = setup_repo_with_worktree(&temp_dir)?;
+    let manager = GitWorktreeManager::new_from_path(&repo_path)?;
+
+    // Without --yes nothing is deleted and the caller gets an error
+    let ui = NonInteractiveUI::new().with_label(PROMPT_SELECT_WORKTREE_TO_DELETE, "feature");
+    assert!(delete_worktree_with_ui(&manager, &ui).is_err());
+    assert!(worktree_path.exists());
+
+    // Unknown names are reported instead of silently ignored
+    let ui = NonInteractiveUI::new()
+        .with_label(PROMPT_SELECT_WORKTREE_TO_DELETE, "missing")
+        .assume_yes(true);
+    assert!(delete_worktree_with_ui(&manager, &ui).is_err());
+
+    // Uncommitted changes are discarded too once everything is confirmed
+    fs::write(worktree_path.join("wip.txt"), "work in progress")?;
+    let ui = NonInteractiveUI::new()
+        .with_label(PROMPT_SELECT_WORKTREE_TO_DELETE, "feature")
+        .assume_yes(true);
+    delete_worktree_with_ui(&manager, &ui)?;
+    assert!(!worktree_path.exists());
+    assert!(manager.list_worktrees()?.iter().all(|w| w.is_main));
+
+    Ok(())
+}
+
+#[test]
+fn test_delete_with_mock_git_requires_confirmation() {
+    use git_workers::commands::{analyze_deletion, delete_worktree_with_ui};
+    use git_workers::constants::PROMPT_SELECT_WORKTREE_TO_DELETE;
+    use git_workers::git_interface::mock::MockGitOperations;
+    use git_workers::git_interface::GitReadOperations;
+    use git_workers::ui::NonInteractiveUI;
+
+    let git = MockGitOperations::new()
+        .with_worktree("main", "/repo/main", Some("main"))
+        .with_worktree("feature", "/repo/feature", Some("feature"))
+        .with_branch("feature", false)
+        .with_current_worktree("main");
+
+    let worktrees = git.list_worktrees().unwrap();
+    let feature = worktrees.iter().find(|w| w.name == "feature").unwrap();
+    assert!(analyze_deletion(feature, &git).unwrap().is_branch_unique);
+
+    // Without --yes nothing is confirmed, so nothing is removed
+    let ui = NonInteractiveUI::new().with_label(PROMPT_SELECT_WORKTREE_TO_DELETE, "feature");
+    assert!(delete_worktree_with_ui(&git, &ui).is_err());
+    assert!(git.removed_worktrees().is_empty());
+    assert!(git.deleted_branches().is_empty());
+
+    // The current worktree is never offered
+    let ui = NonInteractiveUI::new().with_label(PROMPT_SELECT_WORKTREE_TO_DELETE, "main");
+    assert!(delete_worktree_with_ui(&git, &ui).is_err());
+    assert!(git.worktree_exists("main").unwrap());
+}
+
+#[test]
+fn test_delete_keeps_branch_checked_out_in_another_worktree() {
+    use git_workers::commands::{
+        analyze_deletion, branch_checked_out_in, execute_deletion, WorktreeDeleteConfig,
+    };
+    use git_workers::git_interface::mock::MockGitOperations;
+    use git_workers::git_interface::GitReadOperations;
+
+    let git = MockGitOperations::new()
+        .with_worktree("main", "/repo/main", Some("main"))
+        .with_worktree("first", "/repo/first", Some("shared"))
+        .with_worktree("second", "/repo/second", Some("shared"))
+        .with_branch("shared", false)
+        .with_current_worktree("main");
+    let worktrees = git.list_worktrees().unwrap();
+    let first = worktrees.iter().find(|w| w.name == "first").unwrap();
+    assert!(!analyze_deletion(first, &git).unwrap().is_branch_unique);
+
+    let config = |name: &str| WorktreeDeleteConfig {
+        name: name.to_string(),
+        path: std::path::PathBuf::from(format!("/repo/{name}")),
+        branch: "shared".to_string(),
+        delete_branch: true,
+        force: false,
+    };
+
+    // The worktree goes, but the branch stays while "second" has it checked out
+    execute_deletion(&config("first"), &git).unwrap();
+    assert_eq!(git.removed_worktrees(), vec!["first"]);
+    assert!(git.deleted_branches().is_empty());
+    assert_eq!(
+        branch_checked_out_in(&git, "shared").unwrap().as_deref(),
+        Some("second")
+    );
+
+    // Deleting the last worktree on the branch deletes it
+    execute_deletion(&config("second"), &git).unwrap();
+    assert_eq!(git.deleted_branches(), vec!["shared"]);
+}
+
+#[test]
+fn test_delete_never_offers_main_worktree() {
+    use git_workers::commands::{delete_worktree_with_ui, get_deletable_worktrees};
+    use git_workers::constants::PROMPT_SELECT_WORKTREE_TO_DELETE;
+    use git_workers::git_interface::mock::MockGitOperations;
+    use git_workers::git_interface::GitReadOperations;
+    use git_workers::ui::NonInteractiveUI;
+
+    // Running from a linked worktree: main is not current but still protected
+    let git = MockGitOperations::new()
+        .with_worktree("main", "/repo/main", Some("main"))
+        .with_worktree("feature", "/repo/feature", Some("feature"))
+        .with_main_worktree("main")
+        .with_current_worktree("feature");
+
+    let worktrees = git.list_worktrees().unwrap();
+    assert!(get_deletable_worktrees(&worktrees, false).is_empty());
+    assert_eq!(get_deletable_worktrees(&worktrees, true).len(), 1);
+
+    let ui = NonInteractiveUI::new()
+        .with_label(PROMPT_SELECT_WORKTREE_TO_DELETE, "main")
+        .assume_yes(true);
+    assert!(delete_worktree_with_ui(&git, &ui).is_err());
+    assert!(git.removed_worktrees().is_empty());
+}
+
+#[test]
+fn test_delete_refuses_main_worktree_of_real_repo() -> Result<()> {
+    use git_workers::commands::{delete_worktree_with_ui, get_deletable_worktrees};
+    use git_workers::constants::PROMPT_SELECT_WORKTREE_TO_DELETE;
+    use git_workers::infrastructure::git::GitWorktreeManager;
+    use git_workers::ui::NonInteractiveUI;
+
+    let temp_dir = TempDir::new()?;
+    let (repo_path, worktree_path) = setup_repo_with_worktree(&temp_dir)?;
+    let manager = GitWorktreeManager::new_from_path(&worktree_path)?;
+
+    // Seen from the linked worktree, the listing still reports the main one
+    let worktrees = manager.list_worktrees()?;
+    let main = worktrees.iter().find(|w| w.is_main).unwrap();
+    assert_eq!(main.name, "repo");
+    assert_eq!(main.path.canonicalize()?, repo_path.canonicalize()?);
+    assert_eq!(worktrees.iter().filter(|w| w.is_main).count(), 1);
+
+    let deletable = get_deletable_worktrees(&worktrees, false);
+    assert!(deletable.iter().all(|w| !w.is_main));
+    assert!(get_deletable_worktrees(&worktrees, true)
+        .iter()
+        .any(|w| w.is_main));
+
+    let ui = NonInteractiveUI::new()
+        .with_label(PROMPT_SELECT_WORKTREE_TO_DELETE, "repo")
+        .assume_yes(true);
+    assert!(delete_worktree_with_ui(&manager, &ui).is_err());
+    assert!(repo_path.join(".git").is_dir());
+    assert_eq!(manager.list_worktrees()?.len(), 2);
+
+    Ok(())
+}
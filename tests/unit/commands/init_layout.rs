@@ -0,0 +1,142 @@
+//! Unit tests for adopting the subdirectory worktree layout
+
+use anyhow::Result;
+use git_workers::commands::init_layout_with_ui;
+use git_workers::constants::{PROMPT_LAYOUT_CONFIRM, PROMPT_LAYOUT_MODE};
+use git_workers::core::GitWorkersError;
+use git_workers::infrastructure::git::GitWorktreeManager;
+use git_workers::ui::NonInteractiveUI;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+
+fn git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git").args(args).current_dir(dir).output()?;
+    assert!(output.status.success(), "git {args:?} failed");
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Creates a clone-like repository on `main` with an ignored `.env` and a
+/// linked worktree already in `worktrees/feature`
+fn setup_plain_repo() -> Result<(TempDir, PathBuf)> {
+    let temp_dir = TempDir::new()?;
+    let repo_path = temp_dir.path().join("app");
+    fs::create_dir(&repo_path)?;
+    git(&repo_path, &["init", "-q", "-b", "main"])?;
+    git(&repo_path, &["config", "user.email", "test@example.com"])?;
+    git(&repo_path, &["config", "user.name", "Test"])?;
+    fs::write(repo_path.join("README.md"), "# App")?;
+    fs::write(repo_path.join(".gitignore"), ".env\n")?;
+    fs::write(repo_path.join(".env"), "SECRET=1")?;
+    git(&repo_path, &["add", "README.md", ".gitignore"])?;
+    git(&repo_path, &["commit", "-q", "-m", "Initial commit"])?;
+    git(
+        &repo_path,
+        &[
+            "worktree",
+            "add",
+            "-q",
+            "worktrees/feature",
+            "-b",
+            "feature",
+        ],
+    )?;
+    Ok((temp_dir, repo_path.canonicalize()?))
+}
+
+#[test]
+fn test_init_layout_keeps_checkout() -> Result<()> {
+    let (_temp_dir, repo_path) = setup_plain_repo()?;
+    let manager = GitWorktreeManager::new_from_path(&repo_path)?;
+
+    let ui = NonInteractiveUI::new()
+        .with_choice(PROMPT_LAYOUT_MODE, 0)
+        .with_confirm(PROMPT_LAYOUT_CONFIRM, true);
+    init_layout_with_ui(&manager, &ui)?;
+
+    assert!(repo_path.join("README.md").exists());
+    assert!(repo_path.join(".git-workers.toml").exists());
+    let exclude = fs::read_to_string(repo_path.join(".git/info/exclude"))?;
+    assert!(exclude.lines().any(|line| line == "/worktrees/"));
+    assert_eq!(
+        git(&repo_path, &["status", "--porcelain"])?,
+        "?? .git-workers.toml\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_init_layout_moves_checkout() -> Result<()> {
+    let (_temp_dir, repo_path) = setup_plain_repo()?;
+    let manager = GitWorktreeManager::new_from_path(&repo_path)?;
+
+    let ui = NonInteractiveUI::new()
+        .with_choice(PROMPT_LAYOUT_MODE, 1)
+        .with_confirm(PROMPT_LAYOUT_CONFIRM, true);
+    init_layout_with_ui(&manager, &ui)?;
+
+    let mut root_entries: Vec<String> = fs::read_dir(&repo_path)?
+        .map(|entry| entry.map(|e| e.file_name().to_string_lossy().to_string()))
+        .collect::<std::io::Result<_>>()?;
+    root_entries.sort();
+    assert_eq!(root_entries, vec![".git", "worktrees"]);
+
+    let main_path = repo_path.join("worktrees/main");
+    assert!(main_path.join("README.md").exists());
+    assert!(main_path.join(".env").exists());
+    assert!(main_path.join(".git-workers.toml").exists());
+    assert_eq!(
+        git(&main_path, &["status", "--porcelain"])?,
+        "?? .git-workers.toml\n"
+    );
+    assert_eq!(
+        git(&main_path, &["branch", "--show-current"])?.trim(),
+        "main"
+    );
+    assert!(repo_path.join("worktrees/feature/README.md").exists());
+
+    Ok(())
+}
+
+#[test]
+fn test_init_layout_declined_changes_nothing() -> Result<()> {
+    let (_temp_dir, repo_path) = setup_plain_repo()?;
+    let manager = GitWorktreeManager::new_from_path(&repo_path)?;
+
+    let ui = NonInteractiveUI::new()
+        .with_choice(PROMPT_LAYOUT_MODE, 1)
+        .with_confirm(PROMPT_LAYOUT_CONFIRM, false);
+    assert!(init_layout_with_ui(&manager, &ui).is_err());
+
+    assert!(repo_path.join("README.md").exists());
+    assert!(!repo_path.join("worktrees/main").exists());
+    assert!(!repo_path.join(".git-workers.toml").exists());
+
+    Ok(())
+}
+
+#[test]
+fn test_init_layout_refuses_worktrees_outside_subdir() -> Result<()> {
+    let (temp_dir, repo_path) = setup_plain_repo()?;
+    git(
+        &repo_path,
+        &["worktree", "add", "-q", "../outside", "-b", "outside"],
+    )?;
+    let manager = GitWorktreeManager::new_from_path(&repo_path)?;
+
+    let ui = NonInteractiveUI::new()
+        .with_choice(PROMPT_LAYOUT_MODE, 0)
+        .with_confirm(PROMPT_LAYOUT_CONFIRM, true);
+    let err = init_layout_with_ui(&manager, &ui).unwrap_err();
+
+    assert!(matches!(
+        err.downcast_ref::<GitWorkersError>(),
+        Some(GitWorkersError::Validation(_))
+    ));
+    assert!(temp_dir.path().join("outside").exists());
+    assert!(!repo_path.join(".git-workers.toml").exists());
+
+    Ok(())
+}
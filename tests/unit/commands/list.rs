@@ -4,21 +4,10 @@
 //! and display formatting.
 
 use git_workers::infrastructure::git::{CommitInfo, WorktreeInfo};
-use std::path::PathBuf;
 
 #[test]
 fn test_worktree_info_creation_basic() {
-    let worktree = WorktreeInfo {
-        name: "feature".to_string(),
-        git_name: "feature".to_string(),
-        path: PathBuf::from("/tmp/feature"),
-        branch: "feature".to_string(),
-        is_current: false,
-        is_locked: false,
-        has_changes: false,
-        last_commit: None,
-        ahead_behind: None,
-    };
+    let worktree = WorktreeInfo::test("feature");
 
     assert_eq!(worktree.name, "feature");
     assert_eq!(worktree.branch, "feature");
@@ -32,21 +21,16 @@ fn test_worktree_info_creation_basic() {
 fn test_worktree_info_with_commit() {
     let commit = CommitInfo {
         id: "abc123".to_string(),
+        full_id: "abc1230000000000000000000000000000000000".to_string(),
         message: "Test commit".to_string(),
         author: "Test Author".to_string(),
         time: "2024-01-01 12:00".to_string(),
     };
 
     let worktree = WorktreeInfo {
-        name: "feature".to_string(),
-        git_name: "feature".to_string(),
-        path: PathBuf::from("/tmp/feature"),
-        branch: "feature".to_string(),
-        is_current: false,
-        is_locked: false,
-        has_changes: false,
         last_commit: Some(commit),
         ahead_behind: Some((2, 3)),
+        ..WorktreeInfo::test("feature")
     };
 
     assert!(worktree.last_commit.is_some());
@@ -58,6 +42,7 @@ fn test_worktree_info_with_commit() {
 fn test_commit_info_creation() {
     let commit = CommitInfo {
         id: "abc123".to_string(),
+        full_id: "abc1230000000000000000000000000000000000".to_string(),
         message: "Test commit message".to_string(),
         author: "Test Author".to_string(),
         time: "2024-01-01 12:00".to_string(),
@@ -68,3 +53,22 @@ fn test_commit_info_creation() {
     assert_eq!(commit.author, "Test Author");
     assert_eq!(commit.time, "2024-01-01 12:00");
 }
+
+#[test]
+fn test_load_list_worktrees_with_mock_git() {
+    use git_workers::commands::{load_list_worktrees, ListSort};
+    use git_workers::git_interface::mock::MockGitOperations;
+
+    let git = MockGitOperations::new()
+        .with_worktree("zeta", "/repo/zeta", Some("zeta"))
+        .with_worktree("alpha", "/repo/alpha", Some("alpha"))
+        .with_worktree("main", "/repo/main", Some("main"))
+        .with_current_worktree("main");
+
+    let names: Vec<String> = load_list_worktrees(&git, false, ListSort::default())
+        .unwrap()
+        .into_iter()
+        .map(|w| w.name)
+        .collect();
+    assert_eq!(names, vec!["main", "alpha", "zeta"]);
+}
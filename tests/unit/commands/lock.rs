@@ -0,0 +1,52 @@
+//! Unit tests for locking and unlocking worktrees
+
+use anyhow::Result;
+use git2::Repository;
+use git_workers::infrastructure::git::GitWorktreeManager;
+use tempfile::TempDir;
+
+/// Creates a repository with one commit and a linked worktree called `usb`
+fn setup_repo_with_worktree() -> Result<(TempDir, GitWorktreeManager)> {
+    let temp_dir = TempDir::new()?;
+    let repo_path = temp_dir.path().join("repo");
+    let repo = Repository::init(&repo_path)?;
+    let sig = git2::Signature::now("Test", "test@example.com")?;
+    let tree_id = repo.index()?.write_tree()?;
+    let tree = repo.find_tree(tree_id)?;
+    repo.commit(Some("HEAD"), &sig, &sig, "Initial commit", &tree, &[])?;
+
+    repo.worktree("usb", &temp_dir.path().join("usb"), None)?;
+    let manager = GitWorktreeManager::new_from_path(&repo_path)?;
+    Ok((temp_dir, manager))
+}
+
+#[test]
+fn test_lock_and_unlock_worktree() -> Result<()> {
+    let (_temp_dir, manager) = setup_repo_with_worktree()?;
+
+    manager.lock_worktree("usb", Some("on usb drive"))?;
+    let worktree = &manager.list_worktrees()?[1];
+    assert!(worktree.is_locked);
+    assert_eq!(worktree.lock_reason.as_deref(), Some("on usb drive"));
+    assert!(manager.lock_worktree("usb", None).is_err());
+
+    manager.unlock_worktree("usb")?;
+    let worktree = &manager.list_worktrees()?[1];
+    assert!(!worktree.is_locked);
+    assert_eq!(worktree.lock_reason, None);
+    assert!(manager.unlock_worktree("usb").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_lock_worktree_without_reason() -> Result<()> {
+    let (_temp_dir, manager) = setup_repo_with_worktree()?;
+
+    manager.lock_worktree("usb", None)?;
+    let worktree = &manager.list_worktrees()?[1];
+    assert!(worktree.is_locked);
+    assert_eq!(worktree.lock_reason, None);
+
+    Ok(())
+}
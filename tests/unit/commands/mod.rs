@@ -5,14 +5,20 @@
 
 mod create;
 mod delete;
+mod init_layout;
 mod list;
+mod lock;
+mod prune;
 mod rename;
 mod switch;
 
 use anyhow::Result;
-use git_workers::commands::{find_config_file_path, get_worktree_icon, validate_custom_path};
+use git_workers::commands::{
+    create_worktree_with_ui, find_config_file_path, get_worktree_icon, validate_custom_path,
+};
 use git_workers::constants;
 use git_workers::infrastructure::git::{GitWorktreeManager, WorktreeInfo};
+use git_workers::ui::NonInteractiveUI;
 use std::fs;
 use tempfile::TempDir;
 
@@ -76,15 +82,9 @@ fn test_get_worktree_icon_current_worktree() -> Result<()> {
     fs::create_dir_all(&worktree_path)?;
 
     let worktree = WorktreeInfo {
-        name: "main".to_string(),
-        git_name: "main".to_string(),
         path: worktree_path.clone(),
-        branch: "main".to_string(),
         is_current: true,
-        is_locked: false,
-        has_changes: false,
-        last_commit: None,
-        ahead_behind: None,
+        ..WorktreeInfo::test("main")
     };
 
     let icon = get_worktree_icon(&worktree);
@@ -101,15 +101,9 @@ fn test_get_worktree_icon_with_changes() -> Result<()> {
     fs::create_dir_all(&worktree_path)?;
 
     let worktree = WorktreeInfo {
-        name: "feature".to_string(),
-        git_name: "feature".to_string(),
         path: worktree_path,
-        branch: "feature".to_string(),
-        is_current: false,
-        is_locked: false,
         has_changes: true,
-        last_commit: None,
-        ahead_behind: None,
+        ..WorktreeInfo::test("feature")
     };
 
     let icon = get_worktree_icon(&worktree);
@@ -127,15 +121,9 @@ fn test_get_worktree_icon_locked() -> Result<()> {
     fs::create_dir_all(&worktree_path)?;
 
     let worktree = WorktreeInfo {
-        name: "locked".to_string(),
-        git_name: "locked".to_string(),
         path: worktree_path,
-        branch: "locked".to_string(),
-        is_current: false,
         is_locked: true,
-        has_changes: false,
-        last_commit: None,
-        ahead_behind: None,
+        ..WorktreeInfo::test("locked")
     };
 
     let icon = get_worktree_icon(&worktree);
@@ -144,6 +132,29 @@ fn test_get_worktree_icon_locked() -> Result<()> {
     Ok(())
 }
 
+// ============================================================================
+// Repository Without Commits Tests
+// ============================================================================
+
+#[test]
+fn test_create_worktree_without_commits() -> Result<()> {
+    let (temp_dir, manager) = setup_test_repo()?;
+    assert!(!manager.has_commits());
+
+    let error = manager
+        .create_worktree_from_head(&temp_dir.path().join("feature"), "feature")
+        .unwrap_err();
+    assert_eq!(error.to_string(), constants::ERROR_NO_COMMITS);
+    assert!(!temp_dir.path().join("feature").exists());
+
+    // The interactive flow stops before asking anything
+    let ui = NonInteractiveUI::new();
+    assert!(!create_worktree_with_ui(&manager, &ui)?);
+    assert_eq!(ui.unanswered(), None);
+
+    Ok(())
+}
+
 // ============================================================================
 // Configuration Discovery Tests
 // ============================================================================
@@ -1,34 +1,83 @@
-//! Unit tests for the prune command (future implementation)
+//! Unit tests for the prune command
 //!
-//! The prune command will remove worktrees that no longer exist on disk
-//! or have been deleted outside of git-workers.
+//! The prune command removes the Git metadata of worktrees that no longer
+//! exist on disk or have been deleted outside of git-workers.
 
 use anyhow::Result;
+use git2::Repository;
+use git_workers::infrastructure::git::GitWorktreeManager;
+use std::fs;
+use tempfile::TempDir;
+
+/// Creates a repository with one commit and a linked worktree called `gone`
+fn setup_repo_with_worktree() -> Result<(TempDir, GitWorktreeManager, git2::Worktree)> {
+    let temp_dir = TempDir::new()?;
+    let repo_path = temp_dir.path().join("repo");
+    let repo = Repository::init(&repo_path)?;
+    let sig = git2::Signature::now("Test", "test@example.com")?;
+    let tree_id = repo.index()?.write_tree()?;
+    let tree = repo.find_tree(tree_id)?;
+    repo.commit(Some("HEAD"), &sig, &sig, "Initial commit", &tree, &[])?;
+
+    let worktree = repo.worktree("gone", &temp_dir.path().join("gone"), None)?;
+    let manager = GitWorktreeManager::new_from_path(&repo_path)?;
+    Ok((temp_dir, manager, worktree))
+}
 
 #[test]
-#[ignore = "Prune command not yet implemented"]
 fn test_prune_missing_worktrees() -> Result<()> {
-    // TODO: Test pruning worktrees that exist in git but not on disk
+    let (temp_dir, manager, _worktree) = setup_repo_with_worktree()?;
+
+    // Nothing to prune while the directory exists
+    assert!(manager.prune_worktrees(false)?.is_empty());
+    assert_eq!(manager.list_worktrees()?.len(), 2);
+
+    fs::remove_dir_all(temp_dir.path().join("gone"))?;
+    let pruned = manager.prune_worktrees(false)?;
+    assert_eq!(pruned.len(), 1);
+    assert!(pruned[0].contains("gone"));
+    assert!(manager.list_worktrees()?.iter().all(|w| w.is_main));
+
     Ok(())
 }
 
 #[test]
-#[ignore = "Prune command not yet implemented"]
+#[ignore = "Prune runs without confirmation"]
 fn test_prune_with_confirmation() -> Result<()> {
     // TODO: Test interactive confirmation before pruning
     Ok(())
 }
 
 #[test]
-#[ignore = "Prune command not yet implemented"]
 fn test_prune_dry_run() -> Result<()> {
-    // TODO: Test dry-run mode that shows what would be pruned
+    let (temp_dir, manager, _worktree) = setup_repo_with_worktree()?;
+    fs::remove_dir_all(temp_dir.path().join("gone"))?;
+
+    // A dry run reports the entry but keeps it
+    let pruned = manager.prune_worktrees(true)?;
+    assert_eq!(pruned.len(), 1);
+    assert!(pruned[0].contains("gone"));
+    assert_eq!(manager.list_worktrees()?.len(), 2);
+
+    assert_eq!(manager.prune_worktrees(false)?.len(), 1);
+    assert!(manager.list_worktrees()?.iter().all(|w| w.is_main));
+
     Ok(())
 }
 
 #[test]
-#[ignore = "Prune command not yet implemented"]
 fn test_prune_locked_worktrees() -> Result<()> {
-    // TODO: Test that locked worktrees are not pruned
+    let (temp_dir, manager, worktree) = setup_repo_with_worktree()?;
+
+    worktree.lock(Some("on a removable drive"))?;
+    fs::remove_dir_all(temp_dir.path().join("gone"))?;
+
+    // Git keeps locked worktrees even when their directory is missing
+    assert!(manager.prune_worktrees(false)?.is_empty());
+    let worktrees = manager.list_worktrees()?;
+    assert_eq!(worktrees.len(), 2);
+    assert!(worktrees[1].is_prunable);
+    assert!(worktrees[1].is_locked);
+
     Ok(())
-}
\ No newline at end of file
+}
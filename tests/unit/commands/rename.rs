@@ -15,6 +15,7 @@ fn test_worktree_rename_config() {
         new_path: PathBuf::from("/tmp/new"),
         old_branch: "old".to_string(),
         new_branch: Some("new".to_string()),
+        rename_worktree: true,
         rename_branch: true,
     };
 
@@ -12,6 +12,7 @@ fn test_worktree_switch_config() {
         target_name: "feature".to_string(),
         target_path: PathBuf::from("/tmp/feature"),
         target_branch: "feature".to_string(),
+        previous_worktree: None,
     };
 
     assert_eq!(config.target_name, "feature");
@@ -62,3 +63,56 @@ fn test_switch_worktree_names() {
         assert!(name.is_empty() || name.contains(char::is_whitespace));
     }
 }
+
+#[test]
+fn test_main_is_not_current_inside_nested_worktree() -> anyhow::Result<()> {
+    use assert_cmd::Command;
+    use git_workers::constants::{ENV_GW_SWITCH_FILE, LIST_PORCELAIN_CURRENT};
+
+    // The default subdirectory layout puts linked worktrees inside main
+    let temp_dir = tempfile::TempDir::new()?;
+    let repo_path = temp_dir.path().join("repo");
+    let repo = git2::Repository::init(&repo_path)?;
+    let sig = git2::Signature::now("Test", "test@example.com")?;
+    let tree_id = repo.index()?.write_tree()?;
+    repo.commit(
+        Some("HEAD"),
+        &sig,
+        &sig,
+        "Initial commit",
+        &repo.find_tree(tree_id)?,
+        &[],
+    )?;
+    std::fs::create_dir(repo_path.join("worktrees"))?;
+    let nested = repo_path.join("worktrees").join("feat");
+    repo.worktree("feat", &nested, None)?;
+
+    let output = Command::cargo_bin("gw")?
+        .args(["list", "--porcelain"])
+        .current_dir(&nested)
+        .output()?;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout)?;
+    let current: Vec<&str> = stdout
+        .lines()
+        .filter(|line| {
+            line.split('\t')
+                .any(|field| field == LIST_PORCELAIN_CURRENT)
+        })
+        .collect();
+    assert_eq!(current.len(), 1, "{stdout}");
+    assert!(current[0].starts_with("feat\t"));
+
+    // Switching to main from the nested worktree is a real switch
+    let switch_file = temp_dir.path().join("switch");
+    let output = Command::cargo_bin("gw")?
+        .args(["switch", "repo"])
+        .current_dir(&nested)
+        .env(ENV_GW_SWITCH_FILE, &switch_file)
+        .output()?;
+    assert!(output.status.success());
+    let target = PathBuf::from(std::fs::read_to_string(&switch_file)?);
+    assert_eq!(target.canonicalize()?, repo_path.canonicalize()?);
+
+    Ok(())
+}
@@ -149,24 +149,15 @@ fn test_determine_worktree_path_errors() {
 fn test_validate_worktree_creation_errors() {
     let existing_worktrees = vec![
         WorktreeInfo {
-            name: "existing".to_string(),
             path: PathBuf::from("/existing/path"),
             branch: "main".to_string(),
-            is_current: false,
-            is_locked: false,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
+            ..WorktreeInfo::test("existing")
         },
         WorktreeInfo {
-            name: "another".to_string(),
             path: PathBuf::from("/another/path"),
             branch: "feature".to_string(),
             is_current: true,
-            is_locked: false,
-            has_changes: false,
-            last_commit: None,
-            ahead_behind: None,
+            ..WorktreeInfo::test("another")
         },
     ];
 
@@ -406,6 +397,8 @@ post-create = ["non-existent-command --invalid-flag"]
     let context = HookContext {
         worktree_name: "test".to_string(),
         worktree_path: repo_path.clone(),
+        previous_worktree_name: None,
+        previous_worktree_path: None,
     };
 
     // Test that invalid hook commands are handled gracefully
@@ -558,6 +551,8 @@ post-create = ["this-command-does-not-exist"]
     let context = HookContext {
         worktree_name: "test".to_string(),
         worktree_path: repo_path.clone(),
+        previous_worktree_name: None,
+        previous_worktree_path: None,
     };
 
     // Test hook execution with non-existent command
@@ -627,6 +622,8 @@ post-create = ["{failing_command}"]
     let context = HookContext {
         worktree_name: "test".to_string(),
         worktree_path: repo_path.clone(),
+        previous_worktree_name: None,
+        previous_worktree_path: None,
     };
 
     // Test hook execution with failing command
@@ -4,7 +4,7 @@
 //! Eliminates duplication and provides comprehensive file copy functionality tests
 
 use anyhow::Result;
-use git_workers::config::FilesConfig;
+use git_workers::config::{CopyEntry, FilesConfig};
 use git_workers::file_copy;
 use git_workers::git::GitWorktreeManager;
 use std::fs;
@@ -110,11 +110,14 @@ fn test_file_copy_basic() -> Result<()> {
     let worktree_path = create_test_worktree(&repo_path)?;
 
     let files_config = FilesConfig {
-        copy: vec![".env".to_string(), ".env.local".to_string()],
+        copy: vec![".env".into(), ".env.local".into()],
         source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: Vec::new(),
     };
 
-    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?;
+    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?.copied;
 
     // Verify files were copied
     assert_eq!(copied.len(), 2);
@@ -141,11 +144,14 @@ fn test_file_copy_with_subdirectories() -> Result<()> {
     let worktree_path = create_test_worktree(&repo_path)?;
 
     let files_config = FilesConfig {
-        copy: vec!["config/local.json".to_string()],
+        copy: vec!["config/local.json".into()],
         source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: Vec::new(),
     };
 
-    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?;
+    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?.copied;
 
     // Verify files were copied
     assert_eq!(copied.len(), 1);
@@ -175,11 +181,14 @@ fn test_special_filenames() -> Result<()> {
     let worktree_path = create_test_worktree(&repo_path)?;
 
     let files_config = FilesConfig {
-        copy: special_names.iter().map(|s| s.to_string()).collect(),
+        copy: special_names.iter().map(|s| (*s).into()).collect(),
         source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: Vec::new(),
     };
 
-    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?;
+    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?.copied;
 
     // Verify all files were copied
     assert_eq!(copied.len(), special_names.len());
@@ -212,14 +221,26 @@ fn test_directory_copy_recursive() -> Result<()> {
     let worktree_path = create_test_worktree(&repo_path)?;
 
     let files_config = FilesConfig {
-        copy: vec!["config".to_string()],
+        copy: vec!["config".into()],
         source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: Vec::new(),
     };
 
-    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?;
-
-    // Verify directory structure was copied
-    assert_eq!(copied.len(), 1);
+    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?.copied;
+
+    // Verify directory structure was copied and every file is reported
+    let mut copied = copied;
+    copied.sort();
+    assert_eq!(
+        copied,
+        vec![
+            "config/certs/cert.pem",
+            "config/env/dev/.env",
+            "config/settings.json"
+        ]
+    );
     assert!(worktree_path.join("config/env/dev/.env").exists());
     assert!(worktree_path.join("config/settings.json").exists());
     assert!(worktree_path.join("config/certs/cert.pem").exists());
@@ -227,6 +248,113 @@ fn test_directory_copy_recursive() -> Result<()> {
     Ok(())
 }
 
+/// Test that large files and symlinks inside a directory are skipped
+#[test]
+fn test_directory_copy_skips_large_files_and_symlinks() -> Result<()> {
+    let (_temp_dir, manager, dest_dir) = setup_test_repo_git2()?;
+    let repo_path = manager.repo().workdir().unwrap().to_path_buf();
+
+    let config_dir = repo_path.join("config");
+    fs::create_dir_all(config_dir.join("local"))?;
+    fs::write(config_dir.join("local/overrides.toml"), "debug = true")?;
+    fs::write(config_dir.join("dump.bin"), vec![0u8; 2 * 1024 * 1024])?;
+    #[cfg(unix)]
+    std::os::unix::fs::symlink("/etc/hosts", config_dir.join("hosts"))?;
+
+    let config = FilesConfig {
+        copy: vec!["config".into()],
+        source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: Some(1),
+        source_ref: None,
+        copy_exclude: Vec::new(),
+    };
+
+    let copied = file_copy::copy_configured_files(&config, dest_dir.path(), &manager)?.copied;
+
+    assert_eq!(copied, vec!["config/local/overrides.toml"]);
+    assert!(!dest_dir.path().join("config/dump.bin").exists());
+    assert!(!dest_dir.path().join("config/hosts").exists());
+
+    Ok(())
+}
+
+/// Test glob expansion of `*.env`
+#[test]
+fn test_glob_copy_env_files() -> Result<()> {
+    let (_temp_dir, manager, dest_dir) = setup_test_repo_git2()?;
+    let repo_path = manager.repo().workdir().unwrap().to_path_buf();
+
+    fs::write(repo_path.join("app.env"), "APP=1")?;
+    fs::write(repo_path.join("db.env"), "DB=1")?;
+    fs::write(repo_path.join("notes.txt"), "not copied")?;
+
+    let config = FilesConfig {
+        copy: vec!["*.env".into()],
+        source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: Vec::new(),
+    };
+
+    let copied = file_copy::copy_configured_files(&config, dest_dir.path(), &manager)?.copied;
+
+    assert_eq!(copied, vec!["app.env", "db.env"]);
+    assert!(!dest_dir.path().join("notes.txt").exists());
+
+    Ok(())
+}
+
+/// Test glob expansion inside a subdirectory
+#[test]
+fn test_glob_copy_nested_pattern() -> Result<()> {
+    let (_temp_dir, manager, dest_dir) = setup_test_repo_git2()?;
+    let repo_path = manager.repo().workdir().unwrap().to_path_buf();
+
+    fs::create_dir(repo_path.join("config"))?;
+    fs::write(repo_path.join("config/app.json"), "{}")?;
+    fs::write(repo_path.join("config/db.json"), "{}")?;
+    fs::write(repo_path.join("config/readme.md"), "not copied")?;
+
+    let config = FilesConfig {
+        copy: vec!["config/*.json".into()],
+        source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: Vec::new(),
+    };
+
+    let copied = file_copy::copy_configured_files(&config, dest_dir.path(), &manager)?.copied;
+
+    assert_eq!(copied, vec!["config/app.json", "config/db.json"]);
+    assert!(dest_dir.path().join("config/app.json").exists());
+    assert!(!dest_dir.path().join("config/readme.md").exists());
+
+    Ok(())
+}
+
+/// Test that a glob matching nothing is not an error
+#[test]
+fn test_glob_copy_no_matches() -> Result<()> {
+    let (_temp_dir, manager, dest_dir) = setup_test_repo_git2()?;
+    let repo_path = manager.repo().workdir().unwrap().to_path_buf();
+
+    fs::write(repo_path.join(".env"), "A=1")?;
+
+    let config = FilesConfig {
+        copy: vec!["*.secret".into(), ".env".into(), "../*.env".into()],
+        source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: Vec::new(),
+    };
+
+    let copied = file_copy::copy_configured_files(&config, dest_dir.path(), &manager)?.copied;
+
+    assert_eq!(copied, vec![".env"]);
+
+    Ok(())
+}
+
 /// Test copying empty directories
 #[test]
 fn test_empty_directory_copy() -> Result<()> {
@@ -251,11 +379,14 @@ fn test_empty_directory_copy() -> Result<()> {
     let worktree_path = create_test_worktree(&repo_path)?;
 
     let files_config = FilesConfig {
-        copy: vec!["empty_dir".to_string()],
+        copy: vec!["empty_dir".into()],
         source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: Vec::new(),
     };
 
-    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?;
+    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?.copied;
 
     // Verify empty directory was copied (1 file: .gitkeep)
     assert_eq!(copied.len(), 1);
@@ -281,14 +412,17 @@ fn test_file_copy_directory_size_calculation() -> Result<()> {
     fs::write(sub.join("file2.txt"), "b".repeat(2000))?; // 2KB
 
     let config = FilesConfig {
-        copy: vec!["nested".to_string()],
+        copy: vec!["nested".into()],
         source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: Vec::new(),
     };
 
-    let copied = file_copy::copy_configured_files(&config, dest_dir.path(), &manager)?;
+    let copied = file_copy::copy_configured_files(&config, dest_dir.path(), &manager)?.copied;
 
     // Should copy the entire directory
-    assert_eq!(copied.len(), 1);
+    assert_eq!(copied.len(), 2);
     assert!(dest_dir.path().join("nested/file1.txt").exists());
     assert!(dest_dir.path().join("nested/sub/file2.txt").exists());
 
@@ -310,11 +444,14 @@ fn test_file_copy_with_small_files() -> Result<()> {
     fs::write(repo_path.join("config.json"), "{\"small\": true}")?;
 
     let config = FilesConfig {
-        copy: vec![".env".to_string(), "config.json".to_string()],
+        copy: vec![".env".into(), "config.json".into()],
         source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: Vec::new(),
     };
 
-    let copied = file_copy::copy_configured_files(&config, dest_dir.path(), &manager)?;
+    let copied = file_copy::copy_configured_files(&config, dest_dir.path(), &manager)?.copied;
 
     // Verify files were copied
     assert_eq!(copied.len(), 2);
@@ -338,11 +475,14 @@ fn test_file_copy_skip_large_file() -> Result<()> {
     fs::write(repo_path.join("small.txt"), "small content")?;
 
     let config = FilesConfig {
-        copy: vec!["large.bin".to_string(), "small.txt".to_string()],
+        copy: vec!["large.bin".into(), "small.txt".into()],
         source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: Vec::new(),
     };
 
-    let copied = file_copy::copy_configured_files(&config, dest_dir.path(), &manager)?;
+    let copied = file_copy::copy_configured_files(&config, dest_dir.path(), &manager)?.copied;
 
     // Only small file should be copied
     assert_eq!(copied.len(), 1);
@@ -353,6 +493,63 @@ fn test_file_copy_skip_large_file() -> Result<()> {
     Ok(())
 }
 
+/// Test the section-wide size limit from `[files] max_size_mb`
+#[test]
+fn test_file_copy_global_max_size() -> Result<()> {
+    let (_temp_dir, manager, dest_dir) = setup_test_repo_git2()?;
+    let repo_path = manager.repo().workdir().unwrap().to_path_buf();
+
+    fs::write(repo_path.join("data.bin"), vec![0u8; 2 * 1024 * 1024])?; // 2MB
+    fs::write(repo_path.join("small.txt"), "small content")?;
+
+    let config = FilesConfig {
+        copy: vec!["data.bin".into(), "small.txt".into()],
+        source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: Some(1),
+        source_ref: None,
+        copy_exclude: Vec::new(),
+    };
+
+    let copied = file_copy::copy_configured_files(&config, dest_dir.path(), &manager)?.copied;
+
+    assert_eq!(copied, vec!["small.txt"]);
+    assert!(!dest_dir.path().join("data.bin").exists());
+
+    Ok(())
+}
+
+/// Test that a per-entry `max_size_mb` overrides the section-wide limit
+#[test]
+fn test_file_copy_per_entry_max_size() -> Result<()> {
+    let (_temp_dir, manager, dest_dir) = setup_test_repo_git2()?;
+    let repo_path = manager.repo().workdir().unwrap().to_path_buf();
+
+    fs::write(repo_path.join("data.bin"), vec![0u8; 2 * 1024 * 1024])?; // 2MB
+    fs::write(repo_path.join("other.bin"), vec![0u8; 2 * 1024 * 1024])?; // 2MB
+
+    let config = FilesConfig {
+        copy: vec![
+            CopyEntry::Detailed {
+                path: "data.bin".to_string(),
+                max_size_mb: Some(3),
+            },
+            "other.bin".into(),
+        ],
+        source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: Some(1),
+        source_ref: None,
+        copy_exclude: Vec::new(),
+    };
+
+    let copied = file_copy::copy_configured_files(&config, dest_dir.path(), &manager)?.copied;
+
+    assert_eq!(copied, vec!["data.bin"]);
+    assert!(dest_dir.path().join("data.bin").exists());
+    assert!(!dest_dir.path().join("other.bin").exists());
+
+    Ok(())
+}
+
 /// Test total size limit handling (simulated with small files)
 #[test]
 fn test_file_copy_total_size_limit() -> Result<()> {
@@ -368,11 +565,14 @@ fn test_file_copy_total_size_limit() -> Result<()> {
     fs::write(repo_path.join("file2.txt"), "content2")?;
 
     let config = FilesConfig {
-        copy: vec!["file1.txt".to_string(), "file2.txt".to_string()],
+        copy: vec!["file1.txt".into(), "file2.txt".into()],
         source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: Vec::new(),
     };
 
-    let copied = file_copy::copy_configured_files(&config, dest_dir.path(), &manager)?;
+    let copied = file_copy::copy_configured_files(&config, dest_dir.path(), &manager)?.copied;
 
     // Should copy both small files
     assert_eq!(copied.len(), 2);
@@ -393,14 +593,17 @@ fn test_file_copy_security() -> Result<()> {
     // Test file copying with unsafe paths
     let files_config = FilesConfig {
         copy: vec![
-            "../../../etc/passwd".to_string(),
-            "/etc/hosts".to_string(),
-            "~/sensitive".to_string(),
+            "../../../etc/passwd".into(),
+            "/etc/hosts".into(),
+            "~/sensitive".into(),
         ],
         source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: Vec::new(),
     };
 
-    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?;
+    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?.copied;
 
     // Verify no files were copied due to security checks
     assert_eq!(copied.len(), 0);
@@ -429,11 +632,15 @@ fn test_path_traversal_detailed() -> Result<()> {
 
     for path in dangerous_paths {
         let files_config = FilesConfig {
-            copy: vec![path.to_string()],
+            copy: vec![path.into()],
             source: Some(repo_path.to_str().unwrap().to_string()),
+            max_size_mb: None,
+            source_ref: None,
+            copy_exclude: Vec::new(),
         };
 
-        let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?;
+        let copied =
+            file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?.copied;
         assert_eq!(copied.len(), 0, "Path '{path}' should not be copied");
     }
 
@@ -453,14 +660,17 @@ fn test_file_copy_missing_files() -> Result<()> {
     // Test file copying with non-existent files
     let files_config = FilesConfig {
         copy: vec![
-            ".env".to_string(),            // doesn't exist
-            "nonexistent.txt".to_string(), // doesn't exist
+            ".env".into(),            // doesn't exist
+            "nonexistent.txt".into(), // doesn't exist
         ],
         source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: Vec::new(),
     };
 
     // Should not panic, just warn
-    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?;
+    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?.copied;
 
     // Verify no files were copied
     assert_eq!(copied.len(), 0);
@@ -483,11 +693,14 @@ fn test_file_copy_skip_symlinks() -> Result<()> {
         symlink("original.txt", repo_path.join("link.txt"))?;
 
         let config = FilesConfig {
-            copy: vec!["link.txt".to_string(), "original.txt".to_string()],
+            copy: vec!["link.txt".into(), "original.txt".into()],
             source: Some(repo_path.to_str().unwrap().to_string()),
+            max_size_mb: None,
+            source_ref: None,
+            copy_exclude: Vec::new(),
         };
 
-        let copied = file_copy::copy_configured_files(&config, dest_dir.path(), &manager)?;
+        let copied = file_copy::copy_configured_files(&config, dest_dir.path(), &manager)?.copied;
 
         // Only original file should be copied, not the symlink
         assert_eq!(copied.len(), 1);
@@ -499,6 +712,104 @@ fn test_file_copy_skip_symlinks() -> Result<()> {
     Ok(())
 }
 
+/// Test copying committed files from a git ref instead of the working tree
+#[test]
+fn test_file_copy_from_source_ref() -> Result<()> {
+    let (_temp_dir, manager, dest_dir) = setup_test_repo_git2()?;
+    let repo = manager.repo();
+    let repo_path = repo.workdir().unwrap().to_path_buf();
+
+    // Commit config files, then modify one locally
+    fs::create_dir_all(repo_path.join("config"))?;
+    fs::write(repo_path.join("config/app.toml"), "mode = \"committed\"")?;
+    fs::write(repo_path.join("config/db.toml"), "host = \"localhost\"")?;
+    let mut index = repo.index()?;
+    index.add_path(Path::new("config/app.toml"))?;
+    index.add_path(Path::new("config/db.toml"))?;
+    index.write()?;
+    let tree = repo.find_tree(index.write_tree()?)?;
+    let sig = git2::Signature::now("Test User", "test@example.com")?;
+    let parent = repo.head()?.peel_to_commit()?;
+    repo.commit(Some("HEAD"), &sig, &sig, "Add config", &tree, &[&parent])?;
+    fs::write(repo_path.join("config/app.toml"), "mode = \"local\"")?;
+
+    let config = FilesConfig {
+        copy: vec!["config/app.toml".into(), "config/*.toml".into()],
+        source: None,
+        max_size_mb: None,
+        source_ref: Some("HEAD".to_string()),
+        copy_exclude: Vec::new(),
+    };
+
+    let copied = file_copy::copy_configured_files(&config, dest_dir.path(), &manager)?.copied;
+    assert_eq!(copied.len(), 3);
+    assert_eq!(
+        fs::read_to_string(dest_dir.path().join("config/app.toml"))?,
+        "mode = \"committed\""
+    );
+    assert!(dest_dir.path().join("config/db.toml").exists());
+
+    // An unknown ref is an error rather than a silent filesystem copy
+    let config = FilesConfig {
+        source_ref: Some("no-such-ref".to_string()),
+        ..config
+    };
+    assert!(file_copy::copy_configured_files(&config, dest_dir.path(), &manager).is_err());
+
+    Ok(())
+}
+
+/// Test that copy_exclude prunes directory copies but not explicit entries
+#[test]
+fn test_file_copy_with_exclusions() -> Result<()> {
+    let (_temp_dir, manager, dest_dir) = setup_test_repo_git2()?;
+    let repo_path = manager.repo().workdir().unwrap().to_path_buf();
+
+    fs::create_dir_all(repo_path.join("web/node_modules/pkg"))?;
+    fs::write(repo_path.join("web/node_modules/pkg/index.js"), "module")?;
+    fs::write(repo_path.join("web/app.js"), "app")?;
+    fs::write(repo_path.join("web/debug.log"), "log")?;
+    fs::write(repo_path.join("server.log"), "log")?;
+
+    let config = FilesConfig {
+        copy: vec!["web".into(), "server.log".into()],
+        source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: vec!["node_modules".to_string(), "*.log".to_string()],
+    };
+
+    let report = file_copy::copy_configured_files(&config, dest_dir.path(), &manager)?;
+    assert_eq!(report.copied, vec!["web/app.js", "server.log"]);
+    assert_eq!(report.excluded, 2);
+    assert!(!dest_dir.path().join("web/node_modules").exists());
+    assert!(!dest_dir.path().join("web/debug.log").exists());
+
+    // The same patterns apply when copying from a ref
+    let repo = manager.repo();
+    let mut index = repo.index()?;
+    index.add_path(Path::new("web/app.js"))?;
+    index.add_path(Path::new("web/debug.log"))?;
+    index.add_path(Path::new("web/node_modules/pkg/index.js"))?;
+    index.write()?;
+    let tree = repo.find_tree(index.write_tree()?)?;
+    let sig = git2::Signature::now("Test User", "test@example.com")?;
+    let parent = repo.head()?.peel_to_commit()?;
+    repo.commit(Some("HEAD"), &sig, &sig, "Add web", &tree, &[&parent])?;
+
+    let ref_dest = TempDir::new()?;
+    let config = FilesConfig {
+        copy: vec!["web".into()],
+        source_ref: Some("HEAD".to_string()),
+        ..config
+    };
+    let report = file_copy::copy_configured_files(&config, ref_dest.path(), &manager)?;
+    assert_eq!(report.copied, vec!["web/app.js"]);
+    assert_eq!(report.excluded, 2);
+
+    Ok(())
+}
+
 // =============================================================================
 // Advanced scenario tests
 // =============================================================================
@@ -516,14 +827,17 @@ fn test_file_copy_with_directory() -> Result<()> {
     fs::write(config_dir.join("db.json"), "{\"db\": true}")?;
 
     let config = FilesConfig {
-        copy: vec!["config".to_string()],
+        copy: vec!["config".into()],
         source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: Vec::new(),
     };
 
-    let copied = file_copy::copy_configured_files(&config, dest_dir.path(), &manager)?;
+    let copied = file_copy::copy_configured_files(&config, dest_dir.path(), &manager)?.copied;
 
-    // Directory should be copied
-    assert_eq!(copied.len(), 1);
+    // Directory should be copied, one entry per file
+    assert_eq!(copied.len(), 2);
     assert!(dest_dir.path().join("config").exists());
     assert!(dest_dir.path().join("config/app.json").exists());
     assert!(dest_dir.path().join("config/db.json").exists());
@@ -547,15 +861,14 @@ fn test_file_copy_mixed_content() -> Result<()> {
     let worktree_path = create_test_worktree(&repo_path)?;
 
     let files_config = FilesConfig {
-        copy: vec![
-            ".env".to_string(),
-            "standalone.txt".to_string(),
-            "config".to_string(),
-        ],
+        copy: vec![".env".into(), "standalone.txt".into(), "config".into()],
         source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: Vec::new(),
     };
 
-    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?;
+    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?.copied;
 
     // Verify all items were copied
     assert_eq!(copied.len(), 3);
@@ -603,11 +916,14 @@ fn test_file_copy_permission_errors() -> Result<()> {
 
     // Create config
     let files_config = FilesConfig {
-        copy: vec!["protected-file.txt".to_string()],
+        copy: vec!["protected-file.txt".into()],
         source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: Vec::new(),
     };
 
-    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?;
+    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?.copied;
 
     println!("Testing copy with permission errors");
 
@@ -637,11 +953,14 @@ fn test_file_copy_disk_space_simulation() -> Result<()> {
 
     // Create config
     let files_config = FilesConfig {
-        copy: vec!["large-file.txt".to_string()],
+        copy: vec!["large-file.txt".into()],
         source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: Vec::new(),
     };
 
-    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?;
+    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?.copied;
 
     println!("Testing copy with large file");
 
@@ -680,11 +999,15 @@ fn test_file_copy_invalid_symlinks() -> Result<()> {
 
         // Create config
         let files_config = FilesConfig {
-            copy: vec!["broken-symlink".to_string()],
+            copy: vec!["broken-symlink".into()],
             source: Some(repo_path.to_str().unwrap().to_string()),
+            max_size_mb: None,
+            source_ref: None,
+            copy_exclude: Vec::new(),
         };
 
-        let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?;
+        let copied =
+            file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?.copied;
 
         println!("Testing copy with broken symlink");
 
@@ -716,11 +1039,15 @@ fn test_file_copy_circular_symlinks() -> Result<()> {
 
         // Create config
         let files_config = FilesConfig {
-            copy: vec!["symlink-a".to_string(), "symlink-b".to_string()],
+            copy: vec!["symlink-a".into(), "symlink-b".into()],
             source: Some(repo_path.to_str().unwrap().to_string()),
+            max_size_mb: None,
+            source_ref: None,
+            copy_exclude: Vec::new(),
         };
 
-        let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?;
+        let copied =
+            file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?.copied;
 
         println!("Testing copy with circular symlinks");
 
@@ -754,11 +1081,14 @@ fn test_file_copy_deeply_nested_directories() -> Result<()> {
     // Create config with deeply nested path
     let relative_path = nested_file.strip_prefix(&repo_path)?.to_string_lossy();
     let files_config = FilesConfig {
-        copy: vec![relative_path.to_string()],
+        copy: vec![relative_path.to_string().into()],
         source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: Vec::new(),
     };
 
-    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?;
+    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?.copied;
 
     println!("Testing copy with deeply nested directories");
 
@@ -794,11 +1124,14 @@ fn test_file_copy_special_characters() -> Result<()> {
 
     // Create config
     let files_config = FilesConfig {
-        copy: special_files.iter().map(|s| s.to_string()).collect(),
+        copy: special_files.iter().map(|s| (*s).into()).collect(),
         source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: Vec::new(),
     };
 
-    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?;
+    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?.copied;
 
     println!("Testing copy with special characters in filenames");
 
@@ -832,13 +1165,17 @@ fn test_file_copy_concurrent_access() -> Result<()> {
 
     // Create config
     let files_config = FilesConfig {
-        copy: vec!["concurrent-file.txt".to_string()],
+        copy: vec!["concurrent-file.txt".into()],
         source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: Vec::new(),
     };
 
     // Test concurrent access by copying to multiple destinations
     for worktree_path in &worktree_paths {
-        let copied = file_copy::copy_configured_files(&files_config, worktree_path, &manager)?;
+        let copied =
+            file_copy::copy_configured_files(&files_config, worktree_path, &manager)?.copied;
         assert_eq!(copied.len(), 1);
         assert!(worktree_path.join("concurrent-file.txt").exists());
     }
@@ -868,11 +1205,15 @@ fn test_file_copy_filesystem_limits() -> Result<()> {
             let worktree_path = create_test_worktree(&repo_path)?;
 
             let files_config = FilesConfig {
-                copy: vec![long_filename.clone()],
+                copy: vec![long_filename.clone().into()],
                 source: Some(repo_path.to_str().unwrap().to_string()),
+                max_size_mb: None,
+                source_ref: None,
+                copy_exclude: Vec::new(),
             };
 
-            let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?;
+            let copied =
+                file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?.copied;
 
             println!("Testing copy with maximum filename length");
             assert!(long_file.exists());
@@ -902,11 +1243,14 @@ fn test_file_copy_zero_byte_files() -> Result<()> {
 
     // Create config
     let files_config = FilesConfig {
-        copy: vec!["empty.txt".to_string()],
+        copy: vec!["empty.txt".into()],
         source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: Vec::new(),
     };
 
-    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?;
+    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?.copied;
 
     println!("Testing copy with zero-byte files");
 
@@ -936,11 +1280,14 @@ fn test_file_copy_binary_files() -> Result<()> {
 
     // Create config
     let files_config = FilesConfig {
-        copy: vec!["binary.bin".to_string()],
+        copy: vec!["binary.bin".into()],
         source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: Vec::new(),
     };
 
-    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?;
+    let copied = file_copy::copy_configured_files(&files_config, &worktree_path, &manager)?.copied;
 
     println!("Testing copy with binary files");
 
@@ -961,3 +1308,82 @@ fn test_file_copy_binary_files() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_expand_source_path() -> Result<()> {
+    let lookup = |name: &str| match name {
+        "HOME" => Some("/home/dev".to_string()),
+        "TEMPLATES" => Some("shared/templates".to_string()),
+        _ => None,
+    };
+
+    assert_eq!(file_copy::expand_source_path("~/x", lookup)?, "/home/dev/x");
+    assert_eq!(
+        file_copy::expand_source_path("$HOME/x", lookup)?,
+        "/home/dev/x"
+    );
+    assert_eq!(
+        file_copy::expand_source_path("${HOME}/${TEMPLATES}", lookup)?,
+        "/home/dev/shared/templates"
+    );
+    // Relative paths and paths without variables are left alone
+    assert_eq!(
+        file_copy::expand_source_path("config/dev", lookup)?,
+        "config/dev"
+    );
+    assert_eq!(file_copy::expand_source_path("cost$/x", lookup)?, "cost$/x");
+
+    let err = file_copy::expand_source_path("${MISSING}/x", lookup).unwrap_err();
+    assert!(err.to_string().contains("MISSING"));
+    assert!(file_copy::expand_source_path("${HOME/x", lookup).is_err());
+    assert!(file_copy::expand_source_path("~/x", |_| None).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_copy_report_groups_outcomes() -> Result<()> {
+    let (_temp_dir, manager, dest_dir) = setup_test_repo_git2()?;
+    let repo_path = manager.repo().workdir().unwrap().to_path_buf();
+
+    fs::write(repo_path.join(".env"), "KEY=value")?;
+    fs::write(repo_path.join("data.bin"), "large enough")?;
+
+    let config = FilesConfig {
+        copy: vec![
+            ".env".into(),
+            "missing.txt".into(),
+            "../escape.txt".into(),
+            "*.nomatch".into(),
+            "[unclosed".into(),
+            CopyEntry::Detailed {
+                path: "data.bin".to_string(),
+                max_size_mb: Some(0),
+            },
+        ],
+        source: Some(repo_path.to_str().unwrap().to_string()),
+        max_size_mb: None,
+        source_ref: None,
+        copy_exclude: Vec::new(),
+    };
+
+    let report = file_copy::copy_configured_files(&config, dest_dir.path(), &manager)?;
+    assert_eq!(report.copied, vec![".env"]);
+    assert_eq!(
+        report.skipped,
+        vec![
+            ("missing.txt".to_string(), file_copy::SkipReason::NotFound),
+            (
+                "../escape.txt".to_string(),
+                file_copy::SkipReason::UnsafePath
+            ),
+            ("*.nomatch".to_string(), file_copy::SkipReason::NotFound),
+            ("data.bin".to_string(), file_copy::SkipReason::TooLarge),
+        ]
+    );
+    assert_eq!(report.failed.len(), 1);
+    assert_eq!(report.failed[0].0, "[unclosed");
+    assert_eq!(file_copy::SkipReason::TooLarge.to_string(), "too large");
+
+    Ok(())
+}
@@ -27,41 +27,46 @@ fn setup_bare_repo() -> Result<(TempDir, GitWorktreeManager)> {
 }
 
 /// Create a non-bare test repository with initial commit
+///
+/// The repository sits one level inside the temp dir so same-level
+/// worktrees are created inside it and removed with it.
 fn setup_repo_with_commit() -> Result<(TempDir, GitWorktreeManager)> {
     let temp_dir = TempDir::new()?;
+    let repo_path = temp_dir.path().join("test-repo");
+    fs::create_dir(&repo_path)?;
 
     // Initialize repository
     std::process::Command::new("git")
         .arg("init")
-        .current_dir(temp_dir.path())
+        .current_dir(&repo_path)
         .output()?;
 
     // Set git config
     std::process::Command::new("git")
         .args(["config", "user.email", "test@example.com"])
-        .current_dir(temp_dir.path())
+        .current_dir(&repo_path)
         .output()?;
 
     std::process::Command::new("git")
         .args(["config", "user.name", "Test User"])
-        .current_dir(temp_dir.path())
+        .current_dir(&repo_path)
         .output()?;
 
     // Create initial commit
-    fs::write(temp_dir.path().join("README.md"), "# Test Repository")?;
+    fs::write(repo_path.join("README.md"), "# Test Repository")?;
     std::process::Command::new("git")
         .arg("add")
         .arg(".")
-        .current_dir(temp_dir.path())
+        .current_dir(&repo_path)
         .output()?;
     std::process::Command::new("git")
         .arg("commit")
         .arg("-m")
         .arg("Initial commit")
-        .current_dir(temp_dir.path())
+        .current_dir(&repo_path)
         .output()?;
 
-    let manager = GitWorktreeManager::new_from_path(temp_dir.path())?;
+    let manager = GitWorktreeManager::new_from_path(&repo_path)?;
     Ok((temp_dir, manager))
 }
 
@@ -93,7 +98,7 @@ fn test_new_from_path_non_bare_repo() -> Result<()> {
     // So we canonicalize both paths before comparison
     assert_eq!(
         manager.get_git_dir()?.canonicalize()?,
-        temp_dir.path().canonicalize()?
+        temp_dir.path().join("test-repo").canonicalize()?
     );
 
     Ok(())
@@ -123,12 +128,16 @@ fn test_list_worktrees_bare_repo() -> Result<()> {
 
 #[test]
 fn test_list_worktrees_with_main() -> Result<()> {
-    let (_temp_dir, manager) = setup_repo_with_commit()?;
+    let (temp_dir, manager) = setup_repo_with_commit()?;
 
     let worktrees = manager.list_worktrees()?;
-    // In the current implementation, list_worktrees doesn't include the main worktree
-    // for non-bare repositories. It only lists linked worktrees.
-    assert_eq!(worktrees.len(), 0);
+    // Non-bare repositories list their own working directory as the main worktree
+    assert_eq!(worktrees.len(), 1);
+    assert!(worktrees[0].is_main);
+    assert_eq!(
+        worktrees[0].path.canonicalize()?,
+        temp_dir.path().join("test-repo").canonicalize()?
+    );
 
     Ok(())
 }
@@ -151,8 +160,9 @@ fn test_list_worktrees_multiple() -> Result<()> {
     manager.create_worktree_with_new_branch(&bugfix_name, &bugfix_name, "main")?;
 
     let worktrees = manager.list_worktrees()?;
-    // The main worktree is not included in list_worktrees for non-bare repos
-    assert_eq!(worktrees.len(), 2);
+    // The main worktree comes first, then the linked ones
+    assert_eq!(worktrees.len(), 3);
+    assert!(worktrees[0].is_main);
 
     let names: Vec<&str> = worktrees.iter().map(|w| w.name.as_str()).collect();
     assert!(names.contains(&feature_name.as_str()));
@@ -224,6 +234,70 @@ fn test_get_branch_worktree_map() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_get_branch_worktree_map_keys_upstreams() -> Result<()> {
+    let (_temp_dir, manager) = setup_repo_with_commit()?;
+    let repo = manager.repo();
+    repo.remote("origin", "https://example.com/me/repo.git")?;
+    repo.remote("upstream", "https://example.com/team/repo.git")?;
+    let main_branch = repo.head()?.shorthand().unwrap().to_string();
+    let head = repo.head()?.target().unwrap();
+    for remote_ref in ["refs/remotes/upstream/feature", "refs/remotes/origin/main"] {
+        repo.reference(remote_ref, head, true, "test remote")?;
+    }
+    let worktree_name = format!(
+        "upstream-wt-{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)?
+            .as_millis()
+    );
+    manager.create_worktree_with_new_branch(&worktree_name, "feature", &main_branch)?;
+    repo.find_branch("feature", git2::BranchType::Local)?
+        .set_upstream(Some("upstream/feature"))?;
+    repo.find_branch(&main_branch, git2::BranchType::Local)?
+        .set_upstream(Some("origin/main"))?;
+
+    let map = manager.get_branch_worktree_map()?;
+    assert_eq!(map.get("feature"), Some(&worktree_name));
+    assert_eq!(map.get("upstream/feature"), Some(&worktree_name));
+    assert_eq!(map.get("origin/main"), map.get(&main_branch));
+    assert!(!map.contains_key("origin/feature"));
+
+    // Found through its upstream, the main worktree reports its own branch
+    let found = manager.worktree_for_branch("origin/main")?.unwrap();
+    assert!(found.is_main);
+    assert_eq!(found.branch, main_branch);
+
+    Ok(())
+}
+
+#[test]
+fn test_worktree_for_branch() -> Result<()> {
+    let (_temp_dir, manager) = setup_repo_with_commit()?;
+    manager
+        .repo()
+        .remote("origin", "https://example.com/repo.git")?;
+    let main_branch = manager.repo().head()?.shorthand().unwrap().to_string();
+    let worktree_path =
+        manager.create_worktree_with_new_branch("feature-wt", "feature", &main_branch)?;
+
+    let found = manager.worktree_for_branch("feature")?.unwrap();
+    assert_eq!(found.name, "feature-wt");
+    assert!(!found.is_main);
+    // A remote name finds the worktree on the local branch
+    let found = manager.worktree_for_branch("origin/feature")?.unwrap();
+    assert_eq!(found.name, "feature-wt");
+    assert!(manager.worktree_for_branch("unused")?.is_none());
+
+    // The main worktree is found from inside a linked worktree as well
+    let linked = GitWorktreeManager::new_from_path(&worktree_path)?;
+    let found = linked.worktree_for_branch(&main_branch)?.unwrap();
+    assert!(found.is_main);
+    assert_eq!(found.branch, main_branch);
+
+    Ok(())
+}
+
 #[test]
 fn test_is_branch_unique_to_worktree() -> Result<()> {
     let (_temp_dir, manager) = setup_repo_with_commit()?;
@@ -451,8 +525,12 @@ fn test_remove_worktree_force() -> Result<()> {
     // Make changes
     fs::write(worktree_path.join("new-file.txt"), "changes")?;
 
+    // A plain remove refuses to discard the changes
+    assert!(manager.remove_worktree("with-changes").is_err());
+    assert!(worktree_path.exists());
+
     // Remove with force
-    manager.remove_worktree("with-changes")?;
+    manager.remove_worktree_force("with-changes")?;
 
     assert!(!worktree_path.exists());
 
@@ -0,0 +1,251 @@
+//! Conformance tests for the Git operation traits
+//!
+//! The same scenario is built twice: as a real repository driven through
+//! `GitWorktreeManager` and as a `MockGitOperations`. Everything the traits
+//! report must match, so commands tested against the mock behave the same
+//! on a real repository.
+
+use anyhow::{ensure, Result};
+use git_workers::git_interface::mock::MockGitOperations;
+use git_workers::git_interface::{GitReadOperations, GitWriteOperations};
+use git_workers::infrastructure::git::GitWorktreeManager;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+
+// ============================================================================
+// Scenario
+// ============================================================================
+
+/// Branch checked out in the main worktree
+const MAIN_BRANCH: &str = "main";
+/// Local branches without a worktree
+const LOCAL_BRANCHES: &[&str] = &["topic"];
+/// Remote-tracking branches of `origin`
+const REMOTE_BRANCHES: &[&str] = &["origin/main", "origin/release"];
+/// Linked worktrees as (name, branch, lock reason); `None` branch is detached
+const WORKTREES: &[(&str, Option<&str>, Option<&str>)] = &[
+    ("beta", Some("feature/beta"), Some("on a usb drive")),
+    ("alpha", Some("feature/alpha"), None),
+    ("scratch", None, None),
+];
+/// Branch names probed with `branch_exists`
+const PROBED_BRANCHES: &[&str] = &[
+    "main",
+    "topic",
+    "feature/alpha",
+    "origin/main",
+    "release",
+    "missing",
+];
+
+fn git(dir: &Path, args: &[&str]) -> Result<()> {
+    let output = Command::new("git").args(args).current_dir(dir).output()?;
+    ensure!(
+        output.status.success(),
+        "git {args:?} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    Ok(())
+}
+
+/// Builds the scenario as a real repository in `root/repo`
+fn real_scenario(root: &Path) -> Result<GitWorktreeManager> {
+    let repo = root.join("repo");
+    fs::create_dir(&repo)?;
+    git(&repo, &["init", "-q", "-b", MAIN_BRANCH])?;
+    git(&repo, &["config", "user.email", "test@example.com"])?;
+    git(&repo, &["config", "user.name", "Test User"])?;
+    fs::write(repo.join("README.md"), "# Test")?;
+    git(&repo, &["add", "."])?;
+    git(&repo, &["commit", "-q", "-m", "Initial commit"])?;
+
+    for branch in LOCAL_BRANCHES {
+        git(&repo, &["branch", branch])?;
+    }
+    git(
+        &repo,
+        &["remote", "add", "origin", "https://example.com/repo.git"],
+    )?;
+    for branch in REMOTE_BRANCHES {
+        git(
+            &repo,
+            &["update-ref", &format!("refs/remotes/{branch}"), "HEAD"],
+        )?;
+    }
+    for (name, branch, lock_reason) in WORKTREES {
+        let path = root.join(name).display().to_string();
+        match branch {
+            Some(branch) => git(&repo, &["worktree", "add", "-q", "-b", branch, &path])?,
+            None => git(&repo, &["worktree", "add", "-q", "--detach", &path])?,
+        }
+        if let Some(reason) = lock_reason {
+            git(&repo, &["worktree", "lock", "--reason", reason, name])?;
+        }
+    }
+
+    GitWorktreeManager::new_from_path(&repo)
+}
+
+/// Builds the same scenario as a mock rooted at `root/repo`
+fn mock_scenario(root: &Path) -> MockGitOperations {
+    let repo = root.join("repo").display().to_string();
+    let mut mock = MockGitOperations::new()
+        .with_repository_root(&repo)
+        .with_current_branch(MAIN_BRANCH)
+        .with_branch(MAIN_BRANCH, false)
+        .with_worktree("repo", &repo, Some(MAIN_BRANCH))
+        .with_main_worktree("repo");
+    for branch in LOCAL_BRANCHES {
+        mock = mock.with_branch(branch, false);
+    }
+    for branch in REMOTE_BRANCHES {
+        mock = mock.with_branch(branch, true);
+    }
+    for (name, branch, lock_reason) in WORKTREES {
+        mock = mock.with_worktree(name, &root.join(name).display().to_string(), *branch);
+        if lock_reason.is_some() {
+            mock = mock.with_locked_worktree(name, *lock_reason);
+        }
+    }
+    mock
+}
+
+// ============================================================================
+// Observations
+// ============================================================================
+
+/// What the read operations report, in a comparable form
+#[derive(Debug, PartialEq)]
+struct Observation {
+    /// (name, git name, branch, path, locked, lock reason)
+    worktrees: Vec<(String, String, String, PathBuf, bool, Option<String>)>,
+    /// (name, is remote)
+    branches: Vec<(String, bool)>,
+    branch_exists: Vec<(String, bool)>,
+    branch_worktree_map: HashMap<String, String>,
+}
+
+fn observe(git: &dyn GitReadOperations) -> Result<Observation> {
+    let worktrees = git
+        .list_worktrees()?
+        .into_iter()
+        .map(|w| {
+            let path = w.path.canonicalize().unwrap_or(w.path);
+            (
+                w.name,
+                w.git_name,
+                w.branch,
+                path,
+                w.is_locked,
+                w.lock_reason,
+            )
+        })
+        .collect();
+    let branches = git
+        .list_branches()?
+        .into_iter()
+        .map(|b| (b.name, b.is_remote))
+        .collect();
+    let branch_exists = PROBED_BRANCHES
+        .iter()
+        .map(|name| Ok((name.to_string(), git.branch_exists(name)?)))
+        .collect::<Result<_>>()?;
+
+    Ok(Observation {
+        worktrees,
+        branches,
+        branch_exists,
+        branch_worktree_map: git.get_branch_worktree_map()?,
+    })
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[test]
+fn test_mock_matches_real_read_operations() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let real = real_scenario(temp_dir.path())?;
+    let mock = mock_scenario(temp_dir.path());
+
+    let expected = observe(&real)?;
+    assert_eq!(observe(&mock)?, expected);
+
+    // Spot-check the scenario itself so a broken setup cannot pass silently
+    assert_eq!(expected.worktrees.len(), WORKTREES.len() + 1);
+    assert_eq!(
+        expected.branch_worktree_map.get(MAIN_BRANCH),
+        Some(&"repo".to_string())
+    );
+
+    for (name, _, _) in WORKTREES {
+        assert!(mock.worktree_exists(name)?);
+        assert_eq!(real.worktree_exists(name)?, mock.worktree_exists(name)?);
+    }
+    assert_eq!(
+        GitReadOperations::is_branch_unique_to_worktree(&real, "feature/alpha", "alpha")?,
+        mock.is_branch_unique_to_worktree("feature/alpha", "alpha")?
+    );
+    Ok(())
+}
+
+#[test]
+fn test_mock_matches_real_write_operations() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let real = real_scenario(temp_dir.path())?;
+    let mock = mock_scenario(temp_dir.path());
+
+    // Refused by both: a locked worktree, a checked-out branch, unknown names
+    for git in [&real as &dyn GitWriteOperations, &mock] {
+        assert!(git.remove_worktree("beta").is_err());
+        assert!(git.remove_worktree_force("beta").is_err());
+        assert!(git.delete_branch("feature/alpha").is_err());
+        assert!(git.delete_branch(MAIN_BRANCH).is_err());
+        assert!(git.delete_branch("missing").is_err());
+        assert!(git.remove_worktree("missing").is_err());
+    }
+    assert_eq!(observe(&mock)?, observe(&real)?);
+
+    // Removing a worktree frees its branch for deletion
+    for git in [&real as &dyn GitWriteOperations, &mock] {
+        git.remove_worktree("alpha")?;
+        git.delete_branch("feature/alpha")?;
+        git.delete_branch("topic")?;
+    }
+    assert_eq!(observe(&mock)?, observe(&real)?);
+    assert_eq!(mock.removed_worktrees(), vec!["alpha"]);
+    assert_eq!(mock.deleted_branches(), vec!["feature/alpha", "topic"]);
+    Ok(())
+}
+
+#[test]
+fn test_mock_matches_real_create_operations() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let real = real_scenario(temp_dir.path())?;
+    let mock = mock_scenario(temp_dir.path());
+
+    // Both place a simple name next to the existing worktrees
+    assert_eq!(
+        GitReadOperations::resolve_worktree_path(&mock, "gamma")?,
+        GitReadOperations::resolve_worktree_path(&real, "gamma")?
+    );
+
+    // Refused by both: a taken path, a branch checked out elsewhere
+    for git in [&real as &dyn GitWriteOperations, &mock] {
+        assert!(git.create_worktree("alpha", None).is_err());
+        assert!(git.create_worktree("other", Some("feature/alpha")).is_err());
+    }
+    assert_eq!(observe(&mock)?, observe(&real)?);
+
+    for git in [&real as &dyn GitWriteOperations, &mock] {
+        git.create_worktree_with_new_branch("gamma", "feature/gamma", MAIN_BRANCH)?;
+        git.create_worktree("delta", Some("topic"))?;
+    }
+    assert_eq!(observe(&mock)?, observe(&real)?);
+    assert_eq!(mock.created_worktrees(), vec!["gamma", "delta"]);
+    Ok(())
+}
@@ -19,6 +19,8 @@ fn test_hook_context_creation() {
     let context = HookContext {
         worktree_name: "feature".to_string(),
         worktree_path: PathBuf::from("/tmp/feature"),
+        previous_worktree_name: None,
+        previous_worktree_path: None,
     };
 
     assert_eq!(context.worktree_name, "feature");
@@ -30,6 +32,8 @@ fn test_hook_context_with_complex_names() {
     let context = HookContext {
         worktree_name: "feature-auth-123".to_string(),
         worktree_path: PathBuf::from("/path/to/project/worktrees/feature-auth-123"),
+        previous_worktree_name: None,
+        previous_worktree_path: None,
     };
 
     assert_eq!(context.worktree_name, "feature-auth-123");
@@ -72,6 +76,8 @@ fn test_execute_hooks_with_no_config() -> Result<()> {
     let context = HookContext {
         worktree_name: "test".to_string(),
         worktree_path: temp_dir.path().to_path_buf(),
+        previous_worktree_name: None,
+        previous_worktree_path: None,
     };
 
     // This should succeed even without config
@@ -110,6 +116,8 @@ post-create = ["echo test"]
     let context = HookContext {
         worktree_name: "test-worktree".to_string(),
         worktree_path: temp_dir.path().to_path_buf(),
+        previous_worktree_name: None,
+        previous_worktree_path: None,
     };
 
     // Execute hooks
@@ -134,6 +142,8 @@ fn test_hook_types() {
     let _context = HookContext {
         worktree_name: "test".to_string(),
         worktree_path: PathBuf::from("/tmp/test"),
+        previous_worktree_name: None,
+        previous_worktree_path: None,
     };
 
     // These would normally require actual config files, but we're testing the API
@@ -174,6 +184,8 @@ post-create = ["false", "echo test"]
     let context = HookContext {
         worktree_name: "test".to_string(),
         worktree_path: temp_dir.path().to_path_buf(),
+        previous_worktree_name: None,
+        previous_worktree_path: None,
     };
 
     // Hook execution should not fail even if individual commands fail
@@ -197,6 +209,8 @@ fn test_hook_context_path_types() {
     let abs_context = HookContext {
         worktree_name: "main".to_string(),
         worktree_path: PathBuf::from("/absolute/path/to/worktree"),
+        previous_worktree_name: None,
+        previous_worktree_path: None,
     };
     assert!(abs_context.worktree_path.is_absolute());
 
@@ -204,6 +218,8 @@ fn test_hook_context_path_types() {
     let rel_context = HookContext {
         worktree_name: "feature".to_string(),
         worktree_path: PathBuf::from("relative/path"),
+        previous_worktree_name: None,
+        previous_worktree_path: None,
     };
     assert!(!rel_context.worktree_path.is_absolute());
 }
@@ -219,10 +235,14 @@ fn test_hook_execution_flow_simulation() -> Result<()> {
         HookContext {
             worktree_name: "feature-1".to_string(),
             worktree_path: PathBuf::from("/tmp/feature-1"),
+            previous_worktree_name: None,
+            previous_worktree_path: None,
         },
         HookContext {
             worktree_name: "hotfix-2".to_string(),
             worktree_path: PathBuf::from("/tmp/hotfix-2"),
+            previous_worktree_name: None,
+            previous_worktree_path: None,
         },
     ];
 
@@ -263,6 +283,8 @@ post-create = ["echo 'test hook'"]
     let context = HookContext {
         worktree_name: "test".to_string(),
         worktree_path: temp_dir.path().to_path_buf(),
+        previous_worktree_name: None,
+        previous_worktree_path: None,
     };
 
     // Mock UI that accepts confirmation
@@ -298,6 +320,8 @@ post-create = ["echo 'test hook'"]
     let context = HookContext {
         worktree_name: "test".to_string(),
         worktree_path: temp_dir.path().to_path_buf(),
+        previous_worktree_name: None,
+        previous_worktree_path: None,
     };
 
     // Mock UI that rejects confirmation
@@ -337,6 +361,8 @@ post-create = [
     let context = HookContext {
         worktree_name: "feature-xyz".to_string(),
         worktree_path: PathBuf::from("/workspace/feature-xyz"),
+        previous_worktree_name: None,
+        previous_worktree_path: None,
     };
 
     // Mock UI that accepts confirmation
@@ -374,6 +400,8 @@ post-switch = ["echo 'post-switch'"]
     let context = HookContext {
         worktree_name: "test".to_string(),
         worktree_path: temp_dir.path().to_path_buf(),
+        previous_worktree_name: None,
+        previous_worktree_path: None,
     };
 
     // Test each hook type with different confirmation responses
@@ -414,6 +442,8 @@ post-create = []
     let context = HookContext {
         worktree_name: "test".to_string(),
         worktree_path: temp_dir.path().to_path_buf(),
+        previous_worktree_name: None,
+        previous_worktree_path: None,
     };
 
     // Mock UI without any confirmations configured
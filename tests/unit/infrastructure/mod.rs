@@ -2,4 +2,5 @@
 
 mod file_copy;
 mod git;
+mod git_interface;
 mod hooks;